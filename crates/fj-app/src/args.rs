@@ -17,6 +17,17 @@ pub struct Args {
     #[arg(short, long)]
     pub export: Option<PathBuf>,
 
+    /// Simplify the exported mesh to at most this many triangles
+    ///
+    /// Sharp edges of the model are preserved. Useful for exporting
+    /// lightweight previews.
+    #[arg(long, requires = "export")]
+    pub decimate: Option<usize>,
+
+    /// Print the mass properties of the model and exit
+    #[arg(long)]
+    pub measure: bool,
+
     /// Parameters for the model, each in the form `key=value`
     #[arg(short, long, value_parser = parse_parameters)]
     pub parameters: Option<Parameters>,
@@ -24,6 +35,86 @@ pub struct Args {
     /// Model deviation tolerance
     #[arg(short, long, value_parser = parse_tolerance)]
     pub tolerance: Option<Tolerance>,
+
+    /// Maximum angle between neighboring segments of the approximation, in
+    /// degrees
+    ///
+    /// Makes the approximation adapt to the curvature of the model: Tightly
+    /// bent curves get more points, nearly straight ones fewer.
+    #[arg(long, value_parser = parse_max_angle)]
+    pub max_angle: Option<Scalar>,
+
+    /// Maximum angle between faces, in degrees, at which the edge between
+    /// them is rendered smooth
+    ///
+    /// Edges where faces meet at sharper angles stay hard. If not set, all
+    /// edges between faces stay hard.
+    #[arg(long, value_parser = parse_smoothing_angle)]
+    pub smoothing_angle: Option<Scalar>,
+
+    /// Triangulate faces using a constrained Delaunay triangulation
+    ///
+    /// Respects the edges of faces and their holes exactly, and produces
+    /// better-shaped triangles.
+    #[arg(long)]
+    pub constrained_delaunay: bool,
+
+    /// Cache the approximations of faces in this directory
+    ///
+    /// When the model is processed again, parts of the model that haven't
+    /// changed are loaded from the cache, which makes reloading large models
+    /// faster. The oldest entries are removed, once the cache grows larger
+    /// than 256 MiB.
+    #[arg(long)]
+    pub approx_cache: Option<PathBuf>,
+
+    /// Minimum distance between distinct objects, used during validation
+    ///
+    /// Objects that are closer to each other are considered identical.
+    #[arg(long)]
+    pub distinct_min_distance: Option<f64>,
+
+    /// Maximum distance between identical objects, used during validation
+    #[arg(long)]
+    pub identical_max_distance: Option<f64>,
+
+    /// Tolerance used to approximate curved geometry, when checking for
+    /// self-intersections during validation
+    #[arg(long, value_parser = parse_tolerance)]
+    pub intersection_tolerance: Option<Tolerance>,
+
+    /// Minimum length of an edge, used during validation
+    ///
+    /// Shorter edges are considered degenerate.
+    #[arg(long)]
+    pub min_edge_length: Option<f64>,
+
+    /// Minimum area of a face, used during validation
+    ///
+    /// Faces and cycles that enclose a smaller area are considered degenerate.
+    #[arg(long)]
+    pub min_face_area: Option<f64>,
+
+    /// Ratio of width to length, below which a face is reported as thin
+    ///
+    /// Thin faces are valid, but produce a warning.
+    #[arg(long)]
+    pub thin_face_ratio: Option<f64>,
+
+    /// Validation checks to skip, separated by commas
+    ///
+    /// Possible values are `coherence`, `degenerate`, `geometric`,
+    /// `orientation`, `uniqueness`, and `watertightness`.
+    #[arg(long, value_delimiter = ',')]
+    pub disable_checks: Vec<String>,
+
+    /// Snap vertices together that are closer than this, instead of failing
+    /// validation
+    ///
+    /// Fixes small numerical errors, as they are common after transforms.
+    /// Should be larger than the distinct minimum distance.
+    #[arg(long)]
+    pub snap_distance: Option<f64>,
 }
 
 impl Args {
@@ -66,3 +157,21 @@ fn parse_tolerance(input: &str) -> anyhow::Result<Tolerance> {
 
     Ok(tolerance)
 }
+
+fn parse_max_angle(input: &str) -> anyhow::Result<Scalar> {
+    let degrees = f64::from_str(input)?;
+    if degrees <= 0. {
+        return Err(anyhow!("Maximum angle must be above zero"));
+    }
+
+    Ok(Scalar::from_f64(degrees.to_radians()))
+}
+
+fn parse_smoothing_angle(input: &str) -> anyhow::Result<Scalar> {
+    let degrees = f64::from_str(input)?;
+    if !(0. ..=180.).contains(&degrees) {
+        return Err(anyhow!("Smoothing angle must be between 0 and 180"));
+    }
+
+    Ok(Scalar::from_f64(degrees.to_radians()))
+}
@@ -11,6 +11,27 @@ use serde::Deserialize;
 pub struct Config {
     pub default_path: Option<PathBuf>,
     pub default_model: Option<PathBuf>,
+
+    #[serde(default)]
+    pub validation: Validation,
+}
+
+/// Overrides for the default validation configuration
+///
+/// Values that are not set keep their default.
+#[derive(Debug, Default, Deserialize)]
+pub struct Validation {
+    pub distinct_min_distance: Option<f64>,
+    pub identical_max_distance: Option<f64>,
+    pub intersection_tolerance: Option<f64>,
+    pub min_edge_length: Option<f64>,
+    pub min_face_area: Option<f64>,
+    pub thin_face_ratio: Option<f64>,
+    pub snap_distance: Option<f64>,
+
+    /// The names of validation checks that are not performed
+    #[serde(default)]
+    pub disabled_checks: Vec<String>,
 }
 
 impl Config {
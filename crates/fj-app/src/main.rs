@@ -18,10 +18,22 @@ mod config;
 use std::path::PathBuf;
 
 use anyhow::{anyhow, Context as _};
-use fj_export::export;
+use fj_export::{export, Body};
 use fj_host::{Model, Parameters};
-use fj_interop::status_report::StatusReport;
-use fj_operations::shape_processor::ShapeProcessor;
+use fj_interop::{
+    attributes::Attributes, mass_properties::MassProperties,
+    status_report::StatusReport,
+};
+use fj_kernel::algorithms::{
+    approx::Tolerance,
+    decimate::{decimate, DecimationConfig},
+    triangulate::Triangulator,
+    validate::ValidationConfig,
+};
+use fj_math::Scalar;
+use fj_operations::{
+    approx_cache::ApproxCache, shape_processor::ShapeProcessor,
+};
 use fj_window::run::run;
 use tracing_subscriber::fmt::format;
 use tracing_subscriber::EnvFilter;
@@ -45,11 +57,21 @@ fn main() -> anyhow::Result<()> {
 
     let args = Args::parse();
     let config = Config::load()?;
+    let validation_config = validation_config(&args, &config)?;
 
     let path = config.default_path.unwrap_or_else(|| PathBuf::from(""));
     let parameters = args.parameters.unwrap_or_else(Parameters::empty);
     let shape_processor = ShapeProcessor {
         tolerance: args.tolerance,
+        max_angle: args.max_angle,
+        triangulator: if args.constrained_delaunay {
+            Triangulator::ConstrainedDelaunay
+        } else {
+            Triangulator::Delaunay
+        },
+        smoothing_angle: args.smoothing_angle,
+        approx_cache: args.approx_cache.map(ApproxCache::new),
+        validation_config,
     };
 
     let model = if let Some(model) = args.model.or(config.default_model) {
@@ -62,8 +84,8 @@ fn main() -> anyhow::Result<()> {
         None
     };
 
-    if let Some(export_path) = args.export {
-        // export only mode. just load model, process, export and exit
+    if args.export.is_some() || args.measure {
+        // one-shot mode. just load model, process, export or measure and exit
 
         let model = model.ok_or_else(|| {
             anyhow!(
@@ -74,8 +96,73 @@ fn main() -> anyhow::Result<()> {
 
         let shape = model.load_once(&parameters, &mut status)?;
         let shape = shape_processor.process(&shape)?;
+        for warning in &shape.warnings {
+            eprintln!("Warning: {warning}");
+        }
 
-        export(&shape.mesh, &export_path)?;
+        if let Some(export_path) = args.export {
+            // An assembly is exported as one body per part.
+            let bodies = if shape.parts.is_empty() {
+                vec![Body {
+                    name: None,
+                    mesh: &shape.mesh,
+                    attributes: &shape.attributes,
+                }]
+            } else {
+                shape
+                    .parts
+                    .iter()
+                    .map(|part| Body {
+                        name: Some(&part.name),
+                        mesh: &part.mesh,
+                        attributes: &part.attributes,
+                    })
+                    .collect()
+            };
+
+            match args.decimate {
+                Some(target_triangles) => {
+                    let num_triangles = shape.mesh.triangles().count().max(1);
+
+                    // Each body gets a share of the target, in proportion to
+                    // its share of the triangles.
+                    let meshes = bodies
+                        .iter()
+                        .map(|body| {
+                            let config = DecimationConfig {
+                                target_triangles: target_triangles
+                                    * body.mesh.triangles().count()
+                                    / num_triangles,
+                                max_error: Scalar::MAX,
+                                // Edges of the model that are sharper than
+                                // this are preserved.
+                                feature_angle: Scalar::from_f64(
+                                    30_f64.to_radians(),
+                                ),
+                            };
+                            decimate(body.mesh, &config)
+                        })
+                        .collect::<Vec<_>>();
+                    let bodies = bodies
+                        .iter()
+                        .zip(&meshes)
+                        .map(|(body, mesh)| Body { mesh, ..*body })
+                        .collect::<Vec<_>>();
+
+                    export(&bodies, &export_path)?;
+                }
+                None => export(&bodies, &export_path)?,
+            }
+        }
+        if args.measure {
+            print_mass_properties(&shape.mass_properties, &shape.attributes);
+
+            for part in &shape.parts {
+                println!();
+                println!("Part `{}`:", part.name);
+                print_mass_properties(&part.mass_properties, &part.attributes);
+            }
+        }
 
         return Ok(());
     }
@@ -89,3 +176,85 @@ fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Build the validation configuration from the configuration file and the
+/// command-line arguments
+///
+/// Arguments take precedence over the configuration file.
+fn validation_config(
+    args: &Args,
+    config: &Config,
+) -> anyhow::Result<ValidationConfig> {
+    let mut validation_config = ValidationConfig::default();
+
+    let distinct_min_distance = args
+        .distinct_min_distance
+        .or(config.validation.distinct_min_distance);
+    if let Some(distance) = distinct_min_distance {
+        validation_config.distinct_min_distance = Scalar::from_f64(distance);
+    }
+
+    let identical_max_distance = args
+        .identical_max_distance
+        .or(config.validation.identical_max_distance);
+    if let Some(distance) = identical_max_distance {
+        validation_config.identical_max_distance = Scalar::from_f64(distance);
+    }
+
+    if let Some(tolerance) = args.intersection_tolerance {
+        validation_config.intersection_tolerance = tolerance;
+    } else if let Some(tolerance) = config.validation.intersection_tolerance {
+        validation_config.intersection_tolerance =
+            Tolerance::from_scalar(tolerance)
+                .context("Invalid intersection tolerance in configuration")?;
+    }
+
+    let min_edge_length =
+        args.min_edge_length.or(config.validation.min_edge_length);
+    if let Some(length) = min_edge_length {
+        validation_config.min_edge_length = Scalar::from_f64(length);
+    }
+
+    let min_face_area = args.min_face_area.or(config.validation.min_face_area);
+    if let Some(area) = min_face_area {
+        validation_config.min_face_area = Scalar::from_f64(area);
+    }
+
+    let thin_face_ratio =
+        args.thin_face_ratio.or(config.validation.thin_face_ratio);
+    if let Some(ratio) = thin_face_ratio {
+        validation_config.thin_face_ratio = Scalar::from_f64(ratio);
+    }
+
+    let snap_distance = args.snap_distance.or(config.validation.snap_distance);
+    validation_config.snap_distance = snap_distance.map(Scalar::from_f64);
+
+    for name in config
+        .validation
+        .disabled_checks
+        .iter()
+        .chain(&args.disable_checks)
+    {
+        validation_config.checks.set(name, false)?;
+    }
+
+    Ok(validation_config)
+}
+
+fn print_mass_properties(properties: &MassProperties, attributes: &Attributes) {
+    let [x, y, z] = properties.center_of_mass.coords.components;
+
+    if let Some(material) = &attributes.material {
+        println!("Material: {material}");
+    }
+    println!("Volume: {}", properties.volume);
+    if let Some(mass) = attributes.mass(properties) {
+        println!("Mass: {mass}");
+    }
+    println!("Surface area: {}", properties.surface_area);
+    println!("Center of mass: {} {} {}", x, y, z);
+    println!("Inertia tensor (relative to center of mass):");
+    for [a, b, c] in properties.inertia {
+        println!("    {} {} {}", a, b, c);
+    }
+}
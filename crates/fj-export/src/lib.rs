@@ -14,26 +14,41 @@
 
 #![warn(missing_docs)]
 
-use std::{fs::File, path::Path};
+use std::{
+    collections::HashMap,
+    fs::File,
+    hash::Hash,
+    io::{BufWriter, Write},
+    path::Path,
+};
 
 use thiserror::Error;
 
-use fj_interop::mesh::Mesh;
+use fj_interop::{attributes::Attributes, mesh::Mesh};
 use fj_math::{Point, Triangle};
 
-/// Export the provided mesh to the file at the given path.
+/// Export the provided bodies to the file at the given path.
 ///
 /// This function will create a file if it does not exist, and will truncate it if it does.
 ///
-/// Currently 3MF & STL file types are supported. The case insensitive file extension of
+/// Currently 3MF, OBJ & STL file types are supported. The case insensitive file extension of
 /// the provided path is used to switch between supported types.
-pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+///
+/// OBJ files contain one named object per body, and include the attributes of
+/// each body as comments. STL and 3MF files contain a single mesh, made up of
+/// all bodies. STL files include the name of the material in their header, if
+/// all bodies share it. 3MF files don't include the attributes.
+pub fn export(bodies: &[Body], path: &Path) -> Result<(), Error> {
     match path.extension() {
         Some(extension) if extension.to_ascii_uppercase() == "3MF" => {
-            export_3mf(mesh, path)
+            export_3mf(&merge(bodies).0, path)
+        }
+        Some(extension) if extension.to_ascii_uppercase() == "OBJ" => {
+            export_obj(bodies, path)
         }
         Some(extension) if extension.to_ascii_uppercase() == "STL" => {
-            export_stl(mesh, path)
+            let (mesh, attributes) = merge(bodies);
+            export_stl(&mesh, &attributes, path)
         }
         Some(extension) => Err(Error::InvalidExtension(
             extension.to_string_lossy().into_owned(),
@@ -42,6 +57,36 @@ pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     }
 }
 
+/// A mesh to export, along with its name and attributes
+pub struct Body<'r> {
+    /// The name of the body, if it has one
+    pub name: Option<&'r str>,
+
+    /// The triangle mesh of the body
+    pub mesh: &'r Mesh<Point<3>>,
+
+    /// The attributes of the body, like its material
+    pub attributes: &'r Attributes,
+}
+
+/// Merge the meshes of all bodies, along with the attributes they share
+fn merge(bodies: &[Body]) -> (Mesh<Point<3>>, Attributes) {
+    let mut mesh = Mesh::new();
+    let mut attributes: Option<Attributes> = None;
+
+    for body in bodies {
+        mesh.extend(body.mesh.triangles());
+
+        let body_attributes = body.attributes.clone();
+        attributes = Some(match attributes {
+            Some(attributes) => attributes.combined(body_attributes),
+            None => body_attributes,
+        });
+    }
+
+    (mesh, attributes.unwrap_or_default())
+}
+
 fn export_3mf(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     let vertices = mesh.vertices().map(|vertex| vertex.into()).collect();
 
@@ -67,7 +112,142 @@ fn export_3mf(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     Ok(())
 }
 
-fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+fn export_obj(bodies: &[Body], path: &Path) -> Result<(), Error> {
+    let mut file = BufWriter::new(File::create(path)?);
+
+    let mut offsets = ObjOffsets::default();
+    for body in bodies {
+        write_obj_body(&mut file, body, &mut offsets)?;
+    }
+
+    file.flush()?;
+
+    Ok(())
+}
+
+/// The number of each kind of element that has been written to an OBJ file
+///
+/// OBJ indices refer to all elements in the file, not just to those of the
+/// current object.
+#[derive(Default)]
+struct ObjOffsets {
+    positions: usize,
+    uvs: usize,
+    normals: usize,
+}
+
+fn write_obj_body(
+    file: &mut impl Write,
+    body: &Body,
+    offsets: &mut ObjOffsets,
+) -> Result<(), Error> {
+    let Body {
+        name,
+        mesh,
+        attributes,
+    } = body;
+
+    if let Some(name) = name {
+        writeln!(file, "o {name}")?;
+    }
+    if let Some(material) = &attributes.material {
+        writeln!(file, "# material: {material}")?;
+    }
+    if let Some(density) = attributes.density {
+        writeln!(file, "# density: {density}")?;
+    }
+    for (key, value) in &attributes.metadata {
+        // A line break would end the comment.
+        let value = value.lines().collect::<Vec<_>>().join(" ");
+        writeln!(file, "# {key}: {value}")?;
+    }
+
+    // OBJ indices are 1-based.
+    let positions = mesh.vertices().collect::<Vec<_>>();
+    let position_indices = positions
+        .iter()
+        .enumerate()
+        .map(|(i, &position)| (position, offsets.positions + i + 1))
+        .collect::<HashMap<_, _>>();
+
+    let mut normals = Indexed::default();
+    let mut uvs = Indexed::default();
+    let mut faces = Vec::new();
+
+    for triangle in mesh.triangles() {
+        let points = triangle.inner.points();
+        let normal_indices =
+            triangle.normals.map(|normal| normals.index_of(normal));
+        let uv_indices =
+            triangle.uvs.map(|coords| coords.map(|uv| uvs.index_of(uv)));
+
+        let corners = [0, 1, 2].map(|i| {
+            let position = position_indices[&points[i]];
+            let normal = offsets.normals + normal_indices[i];
+            match uv_indices {
+                Some(uv) => {
+                    format!("{position}/{}/{normal}", offsets.uvs + uv[i])
+                }
+                None => format!("{position}//{normal}"),
+            }
+        });
+        faces.push(corners);
+    }
+
+    offsets.positions += positions.len();
+    offsets.uvs += uvs.values.len();
+    offsets.normals += normals.values.len();
+
+    for position in positions {
+        let [x, y, z] = position.coords.components;
+        writeln!(file, "v {x} {y} {z}")?;
+    }
+    for uv in uvs.values {
+        writeln!(file, "vt {} {}", uv.u, uv.v)?;
+    }
+    for normal in normals.values {
+        let [x, y, z] = normal.components;
+        writeln!(file, "vn {x} {y} {z}")?;
+    }
+    for [a, b, c] in faces {
+        writeln!(file, "f {a} {b} {c}")?;
+    }
+
+    Ok(())
+}
+
+/// A list of unique values, each with a 1-based index
+struct Indexed<T> {
+    values: Vec<T>,
+    indices: HashMap<T, usize>,
+}
+
+impl<T> Indexed<T>
+where
+    T: Copy + Eq + Hash,
+{
+    fn index_of(&mut self, value: T) -> usize {
+        *self.indices.entry(value).or_insert_with(|| {
+            self.values.push(value);
+            self.values.len()
+        })
+    }
+}
+
+impl<T> Default for Indexed<T> {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+}
+
+fn export_stl(
+    mesh: &Mesh<Point<3>>,
+    attributes: &Attributes,
+    path: &Path,
+) -> Result<(), Error> {
     let points = mesh
         .triangles()
         .map(|triangle| triangle.inner.points())
@@ -94,11 +274,21 @@ fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
         })
         .collect::<Vec<_>>();
 
+    // The header is free-form, except that it must not start with `solid`, or
+    // it would be mistaken for an ASCII STL file. Whatever doesn't fit is cut
+    // off.
+    let mut header = [0u8; 80];
+    if let Some(material) = &attributes.material {
+        let material = format!("material: {material}");
+        let len = material.len().min(header.len());
+        header[..len].copy_from_slice(&material.as_bytes()[..len]);
+    }
+
     let mut file = File::create(path)?;
 
     let binary_stl_file = stl::BinaryStlFile {
         header: stl::BinaryStlHeader {
-            header: [0u8; 80],
+            header,
             num_triangles: triangles
                 .len()
                 .try_into()
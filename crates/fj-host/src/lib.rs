@@ -30,7 +30,7 @@ use std::{
     thread,
 };
 
-use fj::abi;
+use fj::{abi, models::ModelMetadata};
 use notify::Watcher as _;
 use thiserror::Error;
 
@@ -85,6 +85,16 @@ impl Model {
         arguments: &Parameters,
         status: &mut StatusReport,
     ) -> Result<fj::Shape, Error> {
+        let (shape, _) = self.load(arguments, status)?;
+        Ok(shape)
+    }
+
+    /// Load the model once, returning its shape and its metadata
+    fn load(
+        &self,
+        arguments: &Parameters,
+        status: &mut StatusReport,
+    ) -> Result<(fj::Shape, ModelMetadata), Error> {
         let manifest_path = self.manifest_path.display().to_string();
 
         let mut command_root = Command::new("cargo");
@@ -140,7 +150,7 @@ impl Model {
         // I don't know of a way to fix this. We should take this as motivation
         // to switch to a better technique:
         // https://github.com/hannobraun/Fornjot/issues/71
-        let shape = unsafe {
+        let (shape, metadata) = unsafe {
             let lib = libloading::Library::new(&self.lib_path)?;
             let init: libloading::Symbol<abi::InitFunction> =
                 lib.get(abi::INIT_FUNCTION_NAME.as_bytes())?;
@@ -159,10 +169,14 @@ impl Model {
 
             let model = host.model.take().ok_or(Error::NoModelRegistered)?;
 
-            model.shape(&host).map_err(Error::Shape)?
+            let metadata = model.metadata();
+            arguments.validate(&metadata)?;
+            let shape = model.shape(&host).map_err(Error::Shape)?;
+
+            (shape, metadata)
         };
 
-        Ok(shape)
+        Ok((shape, metadata))
     }
 
     /// Load the model, then watch it for changes
@@ -244,7 +258,9 @@ impl Model {
             _watcher: Box::new(watcher),
             channel: rx,
             model: self,
+            metadata: None,
             parameters,
+            reload_requested: false,
         })
     }
 }
@@ -298,26 +314,80 @@ pub struct Watcher {
     _watcher: Box<dyn notify::Watcher>,
     channel: mpsc::Receiver<()>,
     model: Model,
+    metadata: Option<ModelMetadata>,
     parameters: Parameters,
+    reload_requested: bool,
 }
 
 impl Watcher {
+    /// Access the metadata of the model
+    ///
+    /// Returns `None`, if the model hasn't been loaded successfully yet.
+    pub fn metadata(&self) -> Option<&ModelMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Access the parameters that are passed to the model
+    pub fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+
+    /// Replace the parameters that are passed to the model
+    ///
+    /// Numeric values outside of the bounds that the model declares for them
+    /// are clamped to those bounds. See [`Parameters::clamp`].
+    ///
+    /// The model is reloaded with the new parameters on the next call to
+    /// [`Watcher::receive`].
+    pub fn set_parameters(&mut self, mut parameters: Parameters) {
+        if let Some(metadata) = &self.metadata {
+            parameters.clamp(metadata);
+        }
+
+        self.parameters = parameters;
+        self.reload_requested = true;
+    }
+
     /// Receive an updated shape that the reloaded model created
     ///
     /// Returns `None`, if the model has not changed since the last time this
     /// method was called.
-    pub fn receive(&self, status: &mut StatusReport) -> Option<fj::Shape> {
-        match self.channel.try_recv() {
+    pub fn receive(&mut self, status: &mut StatusReport) -> Option<fj::Shape> {
+        let event = if self.reload_requested {
+            self.reload_requested = false;
+            Ok(())
+        } else {
+            self.channel.try_recv()
+        };
+
+        match event {
             Ok(()) => {
-                let shape = match self.model.load_once(&self.parameters, status)
-                {
-                    Ok(shape) => shape,
+                let shape = match self.model.load(&self.parameters, status) {
+                    Ok((shape, metadata)) => {
+                        self.metadata = Some(metadata);
+                        shape
+                    }
                     Err(Error::Compile) => {
                         // An error is being displayed to the user via the
-                        // `StatusReport that is passed to `load_once` above, so
+                        // `StatusReport` that is passed to `load` above, so
                         // no need to do anything else here.
                         return None;
                     }
+                    Err(Error::Shape(err)) => {
+                        // This can be caused by parameters that the model
+                        // rejects, which the user can change at runtime. No
+                        // reason to give up on the model then.
+                        status.update_status(&format!(
+                            "Model failed to compute its shape: {err}"
+                        ));
+                        return None;
+                    }
+                    Err(err @ Error::InvalidParameter { .. }) => {
+                        // Same as above, except that we caught the problem
+                        // before passing the parameters to the model.
+                        status.update_status(&err.to_string());
+                        return None;
+                    }
                     Err(err) => {
                         panic!("Error reloading model: {:?}", err);
                     }
@@ -359,6 +429,37 @@ impl Parameters {
         self.0.insert(key.into(), value.to_string());
         self
     }
+
+    /// Check the parameters against the arguments that a model declares
+    ///
+    /// Only parameters for declared arguments are checked. Models are free to
+    /// read other parameters, so those are not rejected.
+    pub fn validate(&self, metadata: &ModelMetadata) -> Result<(), Error> {
+        for argument in &metadata.arguments {
+            if let Some(value) = self.get(&argument.name) {
+                argument.validate(value).map_err(|reason| {
+                    Error::InvalidParameter {
+                        name: argument.name.clone(),
+                        reason,
+                    }
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Clamp numeric parameters to the bounds that a model declares for them
+    ///
+    /// Only parameters for declared arguments are changed. See
+    /// [`fj::models::ArgumentMetadata::clamp`].
+    pub fn clamp(&mut self, metadata: &ModelMetadata) {
+        for argument in &metadata.arguments {
+            if let Some(value) = self.0.get_mut(&argument.name) {
+                *value = argument.clamp(value);
+            }
+        }
+    }
 }
 
 impl Deref for Parameters {
@@ -403,6 +504,15 @@ pub enum Error {
     #[error("Unable to determine the model's geometry")]
     Shape(#[source] fj::models::Error),
 
+    /// A parameter doesn't match the model's metadata for that argument
+    #[error("Invalid parameter `{name}`: {reason}")]
+    InvalidParameter {
+        /// The name of the parameter
+        name: String,
+        /// Why the value of the parameter is invalid
+        reason: fj::models::Error,
+    },
+
     /// Error while watching the model code for changes
     #[error("Error watching model for changes")]
     Notify(#[from] notify::Error),
@@ -446,3 +556,33 @@ impl<'a> fj::models::Context for Host<'a> {
         self.args.get(name).map(|s| s.as_str())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj::models::{ArgumentKind, ArgumentMetadata, ModelMetadata};
+
+    use super::Parameters;
+
+    #[test]
+    fn clamp_parameters() {
+        let metadata = ModelMetadata::new("spacer").with_argument(
+            ArgumentMetadata::new("outer")
+                .with_kind(ArgumentKind::Number)
+                .with_minimum("1.0")
+                .with_maximum("10.0"),
+        );
+
+        let mut parameters = Parameters::empty();
+        parameters.insert("outer", 12.5).insert("inner", 12.5);
+        parameters.clamp(&metadata);
+
+        assert_eq!(parameters.get("outer").map(String::as_str), Some("10"));
+        assert_eq!(parameters.get("inner").map(String::as_str), Some("12.5"));
+
+        let mut parameters = Parameters::empty();
+        parameters.insert("outer", 0.5);
+        parameters.clamp(&metadata);
+
+        assert_eq!(parameters.get("outer").map(String::as_str), Some("1"));
+    }
+}
@@ -0,0 +1,62 @@
+//! Attributes of a shape, like its material
+
+use std::collections::BTreeMap;
+
+use fj_math::Scalar;
+
+use crate::mass_properties::MassProperties;
+
+/// Attributes of a shape, like its material
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Attributes {
+    /// The name of the material, if known
+    pub material: Option<String>,
+
+    /// The density of the material, if known
+    ///
+    /// This is the mass per cubic model unit, in whatever unit of mass the
+    /// model chose.
+    pub density: Option<Scalar>,
+
+    /// Arbitrary attributes, as key-value pairs
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl Attributes {
+    /// Compute the mass of a shape with these attributes
+    ///
+    /// Returns `None`, if the density is not known.
+    pub fn mass(&self, mass_properties: &MassProperties) -> Option<Scalar> {
+        self.density.map(|density| mass_properties.volume * density)
+    }
+
+    /// Combine the attributes of two shapes that form a single shape
+    ///
+    /// The material and density are only kept, if both shapes agree on them.
+    /// Otherwise, they can't be attributed to the combined shape. Where both
+    /// shapes have a value for the same key, the one from `self` is kept.
+    pub fn combined(mut self, other: Self) -> Self {
+        if self.material != other.material {
+            self.material = None;
+        }
+        if self.density != other.density {
+            self.density = None;
+        }
+        for (key, value) in other.metadata {
+            self.metadata.entry(key).or_insert(value);
+        }
+
+        self
+    }
+
+    /// Override these attributes with those of an enclosing shape
+    ///
+    /// Values from `outer` take precedence over those of `self`.
+    pub fn overridden_by(mut self, outer: Self) -> Self {
+        self.material = outer.material.or(self.material);
+        self.density = outer.density.or(self.density);
+        self.metadata.extend(outer.metadata);
+
+        self
+    }
+}
@@ -14,7 +14,9 @@
 
 #![warn(missing_docs)]
 
+pub mod attributes;
 pub mod debug;
+pub mod mass_properties;
 pub mod mesh;
 pub mod processed_shape;
 pub mod status_report;
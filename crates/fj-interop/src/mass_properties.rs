@@ -0,0 +1,22 @@
+//! Mass properties of a shape
+
+use fj_math::{Point, Scalar};
+
+/// The mass properties of a shape
+///
+/// All properties assume a uniform density of `1`, meaning the mass of the
+/// shape is equal to its volume.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MassProperties {
+    /// The volume enclosed by the shape
+    pub volume: Scalar,
+
+    /// The area of the shape's surface
+    pub surface_area: Scalar,
+
+    /// The center of mass
+    pub center_of_mass: Point<3>,
+
+    /// The inertia tensor, relative to the center of mass
+    pub inertia: [[Scalar; 3]; 3],
+}
@@ -2,7 +2,10 @@
 
 use std::{collections::HashMap, hash::Hash};
 
-use fj_math::Point;
+use fj_math::{Point, Scalar, Vector};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// A triangle mesh
 #[derive(Debug)]
@@ -73,12 +76,41 @@ where
 
 impl Mesh<Point<3>> {
     /// Add a triangle to the mesh
+    ///
+    /// The normal of the triangle is used as the normal of all its vertices,
+    /// which makes the triangle look flat. Use
+    /// [`Mesh::push_triangle_with_normals`], to provide other normals.
     pub fn push_triangle(
         &mut self,
         triangle: impl Into<fj_math::Triangle<3>>,
         color: Color,
     ) {
         let triangle = triangle.into();
+        let normal = triangle.normal();
+
+        self.push_triangle_with_normals(triangle, [normal; 3], color);
+    }
+
+    /// Add a triangle to the mesh, with a normal for each of its vertices
+    pub fn push_triangle_with_normals(
+        &mut self,
+        triangle: impl Into<fj_math::Triangle<3>>,
+        normals: [Vector<3>; 3],
+        color: Color,
+    ) {
+        self.push_triangle_with_attributes(triangle, normals, None, color);
+    }
+
+    /// Add a triangle to the mesh, with a normal and optional surface
+    /// coordinates for each of its vertices
+    pub fn push_triangle_with_attributes(
+        &mut self,
+        triangle: impl Into<fj_math::Triangle<3>>,
+        normals: [Vector<3>; 3],
+        uvs: Option<[Point<2>; 3]>,
+        color: Color,
+    ) {
+        let triangle = triangle.into();
 
         for point in triangle.points() {
             self.push_vertex(point);
@@ -86,9 +118,61 @@ impl Mesh<Point<3>> {
 
         self.triangles.push(Triangle {
             inner: triangle,
+            normals,
+            uvs,
             color,
         });
     }
+
+    /// Smooth the normals across the edges between triangles
+    ///
+    /// Where triangles share a vertex, the normals they have there are
+    /// averaged. Normals that differ by more than `max_angle` (in radians) are
+    /// not averaged with each other, to keep the edges hard where faces meet at
+    /// sharp angles.
+    pub fn smooth_normals(&mut self, max_angle: impl Into<Scalar>) {
+        let min_cos = max_angle.into().cos();
+
+        let mut normals_by_vertex = HashMap::<_, Vec<_>>::new();
+        for triangle in &self.triangles {
+            for (point, normal) in
+                triangle.inner.points().into_iter().zip(triangle.normals)
+            {
+                normals_by_vertex.entry(point).or_default().push(normal);
+            }
+        }
+
+        for triangle in &mut self.triangles {
+            for (point, normal) in triangle
+                .inner
+                .points()
+                .into_iter()
+                .zip(&mut triangle.normals)
+            {
+                let sum = normals_by_vertex[&point]
+                    .iter()
+                    .filter(|other| normal.dot(other) >= min_cos)
+                    .fold(Vector::from([0., 0., 0.]), |sum, other| {
+                        sum + *other
+                    });
+
+                *normal = sum.normalize();
+            }
+        }
+    }
+}
+
+impl Extend<Triangle> for Mesh<Point<3>> {
+    fn extend<T: IntoIterator<Item = Triangle>>(&mut self, iter: T) {
+        for triangle in iter {
+            self.push_triangle_with_attributes(
+                triangle.inner,
+                triangle.normals,
+                triangle.uvs,
+                triangle.color,
+            );
+        }
+    }
 }
 
 // This needs to be a manual implementation. Deriving `Default` would require
@@ -115,12 +199,25 @@ pub struct Triangle {
     /// The points of the triangle
     pub inner: fj_math::Triangle<3>,
 
+    /// The normals at the points of the triangle, in the same order
+    pub normals: [Vector<3>; 3],
+
+    /// The surface coordinates at the points of the triangle, in the same
+    /// order
+    ///
+    /// These are the coordinates of the points within the surface of the face
+    /// that the triangle was created from. They can be used to apply textures,
+    /// or to visualize the parametrization of the surface. `None`, if the
+    /// triangle didn't come from a face.
+    pub uvs: Option<[Point<2>; 3]>,
+
     /// The color of the triangle
     pub color: Color,
 }
 
 /// RGBA color
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Color(pub [u8; 4]);
 
 impl Default for Color {
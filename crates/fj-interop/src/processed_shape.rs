@@ -1,8 +1,11 @@
 //! A processed shape
 
-use fj_math::{Aabb, Point};
+use fj_math::{Aabb, Bvh, Point, Triangle};
 
-use crate::{debug::DebugInfo, mesh::Mesh};
+use crate::{
+    attributes::Attributes, debug::DebugInfo, mass_properties::MassProperties,
+    mesh::Mesh,
+};
 
 /// A processed shape
 pub struct ProcessedShape {
@@ -12,6 +15,44 @@ pub struct ProcessedShape {
     /// The triangle mesh that approximates the original shape
     pub mesh: Mesh<Point<3>>,
 
+    /// A bounding volume hierarchy over the triangles of the mesh
+    ///
+    /// Speeds up queries like picking, that would otherwise have to check
+    /// every triangle.
+    pub triangles: Bvh<Triangle<3>>,
+
+    /// The mass properties of the shape, computed from the triangle mesh
+    pub mass_properties: MassProperties,
+
+    /// The attributes of the shape, like its material
+    pub attributes: Attributes,
+
+    /// The parts of the shape, if it is an assembly
+    ///
+    /// Empty, if the shape is not an assembly. Otherwise, [`Self::mesh`] is
+    /// made up of the meshes of the parts.
+    pub parts: Vec<ProcessedPart>,
+
     /// The debug info generated while processing the shape
     pub debug_info: DebugInfo,
+
+    /// Warnings about the shape, found while validating it
+    ///
+    /// Unlike errors, warnings don't prevent the shape from being processed.
+    pub warnings: Vec<String>,
+}
+
+/// A processed part of an assembly
+pub struct ProcessedPart {
+    /// The name of the part
+    pub name: String,
+
+    /// The triangle mesh that approximates the part
+    pub mesh: Mesh<Point<3>>,
+
+    /// The mass properties of the part, computed from the triangle mesh
+    pub mass_properties: MassProperties,
+
+    /// The attributes of the part, like its material
+    pub attributes: Attributes,
 }
@@ -9,15 +9,22 @@
 //! done, to give the caller (who knows the boundary anyway) more options on how
 //! to further process the approximation.
 
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, sync::Arc};
+
+use fj_math::{Point, Scalar, Vector};
+use parking_lot::Mutex;
 
 use crate::{
-    objects::{Curve, GlobalCurve},
+    geometry::{SurfaceGeometry, SweptCurve},
+    objects::{Curve, GlobalCurve, TrimmedCurve},
     path::{GlobalPath, SurfacePath},
     storage::{Handle, ObjectId},
 };
 
-use super::{path::RangeOnPath, Approx, ApproxPoint, Tolerance};
+use super::{
+    path::{subdivide_by_sampling, RangeOnPath},
+    Approx, ApproxPoint, Tolerance,
+};
 
 impl Approx for (&Handle<Curve>, RangeOnPath) {
     type Approximation = CurveApprox;
@@ -30,6 +37,11 @@ impl Approx for (&Handle<Curve>, RangeOnPath) {
     ) -> Self::Approximation {
         let (curve, range) = self;
 
+        // If the curve is periodic, ranges that are a multiple of the period
+        // apart result in the same approximation. Move the range into the
+        // first period, so the cache can be shared between all of them.
+        let (range, offset) = normalize_range(range, curve.period());
+
         let global_curve = curve.global_form().clone();
         let global_curve_approx = match cache.get(global_curve.clone(), range) {
             Some(approx) => approx,
@@ -41,35 +53,139 @@ impl Approx for (&Handle<Curve>, RangeOnPath) {
 
         CurveApprox::empty().with_points(
             global_curve_approx.points.into_iter().map(|point| {
+                let point_curve = point.local_form + offset;
                 let point_surface =
-                    curve.path().point_from_path_coords(point.local_form);
+                    curve.path().point_from_path_coords(point_curve);
 
                 ApproxPoint::new(point_surface, point.global_form)
-                    .with_source((curve.clone(), point.local_form))
+                    .with_source((curve.clone(), point_curve))
             }),
         )
     }
 }
 
+/// Move a range on a periodic curve, so it starts within the first period
+///
+/// Returns the moved range and the offset that needs to be added to points
+/// within it, to get back to the original range.
+fn normalize_range(
+    range: RangeOnPath,
+    period: Option<Scalar>,
+) -> (RangeOnPath, Vector<1>) {
+    let period = match period {
+        Some(period) => period,
+        None => return (range, Vector::from([Scalar::ZERO])),
+    };
+
+    let [a, b] = range.boundary;
+    let min = if a < b { a } else { b };
+
+    let offset = Vector::from([(min.t / period).floor() * period]);
+    let range = RangeOnPath {
+        boundary: range.boundary.map(|point| point - offset),
+    };
+
+    (range, offset)
+}
+
+impl Approx for &TrimmedCurve {
+    type Approximation = CurveApprox;
+    type Cache = CurveCache;
+
+    fn approx_with_cache(
+        self,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut Self::Cache,
+    ) -> Self::Approximation {
+        let range = RangeOnPath::from(self.boundary());
+        (self.curve(), range).approx_with_cache(tolerance, cache)
+    }
+}
+
 fn approx_global_curve(
     curve: &Curve,
     range: RangeOnPath,
     tolerance: impl Into<Tolerance>,
 ) -> GlobalCurveApprox {
-    // There are different cases of varying complexity. Circles are the hard
-    // part here, as they need to be approximated, while lines don't need to be.
+    let tolerance = tolerance.into();
+
+    let points = match curve.surface().geometry() {
+        SurfaceGeometry::SweptCurve(SweptCurve { u, .. }) => {
+            approx_curve_on_swept_curve(curve, u, range, tolerance)
+        }
+        SurfaceGeometry::Cylinder(cylinder) => {
+            // A cylinder is a special case of a swept circle, with the same
+            // coordinate system.
+            let u = GlobalPath::Circle(cylinder.base_circle());
+            approx_curve_on_swept_curve(curve, &u, range, tolerance)
+        }
+        SurfaceGeometry::SweptPath(_)
+        | SurfaceGeometry::Ruled(_)
+        | SurfaceGeometry::Revolution(_)
+        | SurfaceGeometry::Cone(_)
+        | SurfaceGeometry::Sphere(_)
+        | SurfaceGeometry::Torus(_)
+        | SurfaceGeometry::Nurbs(_) => {
+            approx_curve_by_sampling(curve, range, tolerance)
+        }
+    };
+
+    let points = points
+        .into_iter()
+        .map(|(point_curve, point_global)| {
+            ApproxPoint::new(point_curve, point_global)
+        })
+        .collect();
+    GlobalCurveApprox { points }
+}
+
+fn approx_curve_on_swept_curve(
+    curve: &Curve,
+    u: &GlobalPath,
+    range: RangeOnPath,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<3>)> {
+    // There are different cases of varying complexity. Circles, ellipses, and
+    // free-form curves are the hard part here, as they need to be
+    // approximated, while lines don't need to be.
     //
     // This will probably all be unified eventually, as `SurfacePath` and
     // `GlobalPath` grow APIs that are better suited to implementing this code
     // in a more abstract way.
-    let points = match (curve.path(), curve.surface().u()) {
-        (SurfacePath::Circle(_), GlobalPath::Circle(_)) => {
+    match (curve.path(), u) {
+        (
+            SurfacePath::Circle(_)
+            | SurfacePath::Ellipse(_)
+            | SurfacePath::Parabola(_)
+            | SurfacePath::Hyperbola(_)
+            | SurfacePath::Polyline(_)
+            | SurfacePath::Bezier(_)
+            | SurfacePath::Nurbs(_),
+            GlobalPath::Circle(_)
+            | GlobalPath::Ellipse(_)
+            | GlobalPath::Parabola(_)
+            | GlobalPath::Hyperbola(_)
+            | GlobalPath::Polyline(_)
+            | GlobalPath::Bezier(_)
+            | GlobalPath::Nurbs(_)
+            | GlobalPath::Helix(_),
+        ) => {
             todo!(
-                "Approximating a circle on a curved surface not supported yet."
+                "Approximating a conic section or free-form curve on a \
+                curved surface not supported yet."
             )
         }
-        (SurfacePath::Circle(_), GlobalPath::Line(_)) => {
-            (curve.path(), range)
+        (
+            SurfacePath::Circle(_)
+            | SurfacePath::Ellipse(_)
+            | SurfacePath::Parabola(_)
+            | SurfacePath::Hyperbola(_)
+            | SurfacePath::Polyline(_)
+            | SurfacePath::Bezier(_)
+            | SurfacePath::Nurbs(_),
+            GlobalPath::Line(_),
+        ) => {
+            (curve.path().clone(), range)
                 .approx_with_cache(tolerance, &mut ())
                 .into_iter()
                 .map(|(point_curve, point_surface)| {
@@ -101,8 +217,8 @@ fn approx_global_curve(
                     [curve.path().point_from_path_coords(point_curve).u]
                 }));
 
-            let approx_u = (curve.surface().u(), range_u)
-                .approx_with_cache(tolerance, &mut ());
+            let approx_u =
+                (u.clone(), range_u).approx_with_cache(tolerance, &mut ());
 
             let mut points = Vec::new();
             for (u, _) in approx_u {
@@ -115,15 +231,57 @@ fn approx_global_curve(
 
             points
         }
+    }
+}
+
+/// Approximate a curve by sampling it in global coordinates
+///
+/// This is used for curves on surfaces that are not swept curves. The surface
+/// can bend the curve in arbitrary ways, so the curve is split into segments
+/// of unit length in curve coordinates, each of which is subdivided until it
+/// is flat enough. Like the approximation of paths, this is independent of
+/// the range.
+fn approx_curve_by_sampling(
+    curve: &Curve,
+    range: RangeOnPath,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<3>)> {
+    let point_global = |t: Scalar| {
+        let point_surface = curve.path().point_from_path_coords([t]);
+        curve.surface().point_from_surface_coords(point_surface)
     };
 
-    let points = points
+    let [a, b] = range.boundary.map(|point| point.t);
+    let [min, max] = if a < b { [a, b] } else { [b, a] };
+
+    let mut coords = Vec::new();
+    let mut t = min.floor();
+    while t < max {
+        if t > min {
+            coords.push(t);
+        }
+        subdivide_by_sampling(
+            &point_global,
+            [t, t + 1.],
+            tolerance,
+            0,
+            &mut coords,
+        );
+        t += 1.;
+    }
+
+    // We can't generate a point exactly at the boundaries of the range as part
+    // of the approximation. Make sure we stay inside the range.
+    coords.retain(|&t| t > min && t < max);
+
+    if a > b {
+        coords.reverse();
+    }
+
+    coords
         .into_iter()
-        .map(|(point_curve, point_global)| {
-            ApproxPoint::new(point_curve, point_global)
-        })
-        .collect();
-    GlobalCurveApprox { points }
+        .map(|t| (Point::from([t]), point_global(t)))
+        .collect()
 }
 
 /// An approximation of a [`Curve`]
@@ -150,9 +308,13 @@ impl CurveApprox {
 }
 
 /// A cache for results of an approximation
-#[derive(Default)]
+///
+/// Clones of a cache share their contents. This allows faces to be
+/// approximated on multiple threads, while still approximating the curves they
+/// share in exactly the same way.
+#[derive(Clone, Default)]
 pub struct CurveCache {
-    inner: BTreeMap<(ObjectId, RangeOnPath), GlobalCurveApprox>,
+    inner: Arc<Mutex<BTreeMap<(ObjectId, RangeOnPath), GlobalCurveApprox>>>,
 }
 
 impl CurveCache {
@@ -162,14 +324,21 @@ impl CurveCache {
     }
 
     /// Insert the approximation of a [`GlobalCurve`]
+    ///
+    /// If another approximation for the same curve and range has been inserted
+    /// in the meantime (from another thread, for example), that one is kept
+    /// and returned instead, so all users of the cache agree on it.
     pub fn insert(
         &mut self,
         handle: Handle<GlobalCurve>,
         range: RangeOnPath,
         approx: GlobalCurveApprox,
     ) -> GlobalCurveApprox {
-        self.inner.insert((handle.id(), range), approx.clone());
-        approx
+        self.inner
+            .lock()
+            .entry((handle.id(), range))
+            .or_insert(approx)
+            .clone()
     }
 
     /// Access the approximation for the given [`GlobalCurve`], if available
@@ -178,7 +347,7 @@ impl CurveCache {
         handle: Handle<GlobalCurve>,
         range: RangeOnPath,
     ) -> Option<GlobalCurveApprox> {
-        self.inner.get(&(handle.id(), range)).cloned()
+        self.inner.lock().get(&(handle.id(), range)).cloned()
     }
 }
 
@@ -193,20 +362,21 @@ pub struct GlobalCurveApprox {
 mod tests {
     use std::f64::consts::TAU;
 
+    use fj_math::Scalar;
     use pretty_assertions::assert_eq;
 
     use crate::{
         algorithms::approx::{path::RangeOnPath, Approx, ApproxPoint},
-        objects::{Curve, Objects, Surface},
+        objects::{Curve, Objects, Surface, TrimmedCurve},
         partial::HasPartial,
         path::GlobalPath,
         storage::Handle,
     };
 
-    use super::CurveApprox;
+    use super::{CurveApprox, CurveCache};
 
     #[test]
-    fn approx_line_on_flat_surface() {
+    fn approx_line_on_flat_surface() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects
@@ -215,16 +385,19 @@ mod tests {
         let curve = Handle::<Curve>::partial()
             .with_surface(Some(surface))
             .as_line_from_points([[1., 1.], [2., 1.]])
-            .build(&objects);
+            .build(&objects)?;
         let range = RangeOnPath::from([[0.], [1.]]);
 
         let approx = (&curve, range).approx(1.);
 
-        assert_eq!(approx, CurveApprox::empty())
+        assert_eq!(approx, CurveApprox::empty());
+
+        Ok(())
     }
 
     #[test]
-    fn approx_line_on_curved_surface_but_not_along_curve() {
+    fn approx_line_on_curved_surface_but_not_along_curve() -> anyhow::Result<()>
+    {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::new(
@@ -234,24 +407,28 @@ mod tests {
         let curve = Handle::<Curve>::partial()
             .with_surface(Some(surface))
             .as_line_from_points([[1., 1.], [1., 2.]])
-            .build(&objects);
+            .build(&objects)?;
         let range = RangeOnPath::from([[0.], [1.]]);
 
         let approx = (&curve, range).approx(1.);
 
         assert_eq!(approx, CurveApprox::empty());
+
+        Ok(())
     }
 
     #[test]
-    fn approx_line_on_curved_surface_along_curve() {
+    fn approx_line_on_curved_surface_along_curve() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let path = GlobalPath::circle_from_radius(1.);
-        let surface = objects.surfaces.insert(Surface::new(path, [0., 0., 1.]));
+        let surface = objects
+            .surfaces
+            .insert(Surface::new(path.clone(), [0., 0., 1.]));
         let curve = Handle::<Curve>::partial()
             .with_surface(Some(surface.clone()))
             .as_line_from_points([[0., 1.], [1., 1.]])
-            .build(&objects);
+            .build(&objects)?;
 
         let range = RangeOnPath::from([[0.], [TAU]]);
         let tolerance = 1.;
@@ -270,10 +447,12 @@ mod tests {
             })
             .collect::<Vec<_>>();
         assert_eq!(approx.points, expected_approx);
+
+        Ok(())
     }
 
     #[test]
-    fn approx_circle_on_flat_surface() {
+    fn approx_circle_on_flat_surface() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects
@@ -282,13 +461,13 @@ mod tests {
         let curve = Handle::<Curve>::partial()
             .with_surface(Some(surface))
             .as_circle_from_radius(1.)
-            .build(&objects);
+            .build(&objects)?;
 
         let range = RangeOnPath::from([[0.], [TAU]]);
         let tolerance = 1.;
         let approx = (&curve, range).approx(tolerance);
 
-        let expected_approx = (curve.path(), range)
+        let expected_approx = (curve.path().clone(), range)
             .approx(tolerance)
             .into_iter()
             .map(|(_, point_surface)| {
@@ -298,5 +477,75 @@ mod tests {
             })
             .collect::<Vec<_>>();
         assert_eq!(approx.points, expected_approx);
+
+        Ok(())
+    }
+
+    #[test]
+    fn approx_trimmed_curve() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects
+            .surfaces
+            .insert(Surface::new(GlobalPath::x_axis(), [0., 0., 1.]));
+        let curve = Handle::<Curve>::partial()
+            .with_surface(Some(surface))
+            .as_circle_from_radius(1.)
+            .build(&objects)?;
+
+        let trimmed = TrimmedCurve::new(curve.clone(), [[0.], [TAU / 2.]]);
+        assert!(trimmed.contains([1.]));
+        assert!(trimmed.contains([1. + TAU]));
+        assert!(!trimmed.contains([4.]));
+        assert!(!trimmed.crosses_seam());
+
+        let tolerance = 0.1;
+        let range = RangeOnPath::from([[0.], [TAU / 2.]]);
+        assert_eq!(
+            trimmed.approx(tolerance),
+            (&curve, range).approx(tolerance)
+        );
+
+        // Reversing the trimmed curve reverses the approximation.
+        let mut reversed = trimmed.reverse().approx(tolerance).points;
+        reversed.reverse();
+        assert_eq!(reversed, (&curve, range).approx(tolerance).points);
+
+        Ok(())
+    }
+
+    #[test]
+    fn approx_across_seam() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects
+            .surfaces
+            .insert(Surface::new(GlobalPath::x_axis(), [0., 0., 1.]));
+        let curve = Handle::<Curve>::partial()
+            .with_surface(Some(surface))
+            .as_circle_from_radius(1.)
+            .build(&objects)?;
+
+        let a = TrimmedCurve::new(curve.clone(), [[-1.], [1.]]);
+        let b = TrimmedCurve::new(curve, [[TAU - 1.], [TAU + 1.]]);
+        assert!(a.crosses_seam());
+        assert!(b.crosses_seam());
+
+        // Both ranges cover the same part of the circle, so the second
+        // approximation can come from the cache.
+        let tolerance = 0.1;
+        let mut cache = CurveCache::new();
+        let approx_a = a.approx_with_cache(tolerance, &mut cache);
+        let approx_b = b.approx_with_cache(tolerance, &mut cache);
+
+        assert!(!approx_a.points.is_empty());
+        assert_eq!(approx_a.points.len(), approx_b.points.len());
+        for (a, b) in approx_a.points.iter().zip(&approx_b.points) {
+            let distance = (a.local_form - b.local_form).magnitude();
+            assert!(distance < Scalar::from(1e-12));
+            assert_eq!(a.global_form, b.global_form);
+        }
+
+        Ok(())
     }
 }
@@ -9,7 +9,6 @@ use crate::objects::HalfEdge;
 
 use super::{
     curve::{CurveApprox, CurveCache},
-    path::RangeOnPath,
     Approx, ApproxPoint, Tolerance,
 };
 
@@ -22,16 +21,14 @@ impl Approx for &HalfEdge {
         tolerance: impl Into<Tolerance>,
         cache: &mut Self::Cache,
     ) -> Self::Approximation {
-        let [a, b] = self.vertices();
-        let boundary = [a, b].map(|vertex| vertex.position());
-        let range = RangeOnPath { boundary };
+        let [a, _] = self.vertices();
 
         let first = ApproxPoint::new(
             a.surface_form().position(),
             a.global_form().position(),
         );
         let curve_approx =
-            (self.curve(), range).approx_with_cache(tolerance, cache);
+            self.trimmed_curve().approx_with_cache(tolerance, cache);
 
         HalfEdgeApprox {
             first,
@@ -61,3 +58,105 @@ impl HalfEdgeApprox {
         points
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::approx::Approx,
+        objects::{HalfEdge, Objects, Surface},
+        partial::HasPartial,
+    };
+
+    #[test]
+    fn approx_arc() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let half_edge = HalfEdge::partial()
+            .with_surface(Some(objects.surfaces.insert(Surface::xy_plane())))
+            .as_arc_from_points_and_radius([[1., 0.], [0., 1.]], 1.)
+            .build(&objects)?;
+
+        let approx = half_edge.approx(0.01);
+        let points = approx.points();
+
+        assert_eq!(points.first().unwrap().local_form, Point::from([1., 0.]));
+        assert!(points.len() > 2);
+
+        // The shorter arc from `[1, 0]` to `[0, 1]` is centered on the origin
+        // and stays within the first quadrant.
+        for point in points {
+            let [u, v] = point.local_form.coords.components;
+
+            assert!(
+                (point.local_form.coords.magnitude() - Scalar::ONE).abs()
+                    < Scalar::from(1e-10)
+            );
+            assert!(u >= -Scalar::from(1e-10) && v >= -Scalar::from(1e-10));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn approx_clockwise_arc() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let half_edge = HalfEdge::partial()
+            .with_surface(Some(objects.surfaces.insert(Surface::xy_plane())))
+            .as_clockwise_arc_from_points_and_radius([[0., 1.], [1., 0.]], 1.)
+            .build(&objects)?;
+
+        let approx = half_edge.approx(0.01);
+        let points = approx.points();
+
+        assert_eq!(points.first().unwrap().local_form, Point::from([0., 1.]));
+        assert!(points.len() > 2);
+
+        // This is the same arc as in `approx_arc`, going the other way.
+        for point in points {
+            let [u, v] = point.local_form.coords.components;
+
+            assert!(
+                (point.local_form.coords.magnitude() - Scalar::ONE).abs()
+                    < Scalar::from(1e-10)
+            );
+            assert!(u >= -Scalar::from(1e-10) && v >= -Scalar::from(1e-10));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn approx_bezier() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let half_edge = HalfEdge::partial()
+            .with_surface(Some(objects.surfaces.insert(Surface::xy_plane())))
+            .as_bezier_from_control_points([
+                [0., 0.],
+                [0., 1.],
+                [1., 1.],
+                [1., 0.],
+            ])
+            .build(&objects)?;
+
+        let approx = half_edge.approx(0.01);
+        let points = approx.points();
+
+        assert_eq!(points.first().unwrap().local_form, Point::from([0., 0.]));
+        assert!(points.len() > 2);
+
+        // The curve is contained in the convex hull of its control points,
+        // which is the unit square in this case.
+        for point in points {
+            let [u, v] = point.local_form.coords.components;
+
+            assert!(u >= Scalar::ZERO && u <= Scalar::ONE);
+            assert!(v >= Scalar::ZERO && v <= Scalar::ONE);
+        }
+
+        Ok(())
+    }
+}
@@ -2,13 +2,14 @@
 //!
 //! See [`FaceApprox`].
 
-use std::collections::BTreeSet;
+use std::{collections::BTreeSet, ops::Deref};
 
 use fj_interop::mesh::Color;
+use fj_math::Aabb;
 
 use crate::{
     algorithms::validate::ValidationConfig,
-    objects::{Face, Faces, Handedness},
+    objects::{Face, Faces, Handedness, Surface},
 };
 
 use super::{
@@ -26,11 +27,28 @@ impl Approx for &Faces {
     ) -> Self::Approximation {
         let tolerance = tolerance.into();
 
+        #[cfg(not(feature = "parallel"))]
         let approx = self
             .into_iter()
             .map(|face| face.approx_with_cache(tolerance, cache))
             .collect();
 
+        // Faces are approximated independently of each other, except for the
+        // curves they share. The clones of the cache that each thread gets
+        // make sure those are approximated the same way.
+        #[cfg(feature = "parallel")]
+        let approx = {
+            use rayon::prelude::*;
+
+            self.into_iter()
+                .collect::<Vec<_>>()
+                .into_par_iter()
+                .map_with(cache.clone(), |cache, face| {
+                    face.approx_with_cache(tolerance, cache)
+                })
+                .collect()
+        };
+
         let min_distance = ValidationConfig::default().distinct_min_distance;
         let mut all_points: BTreeSet<ApproxPoint<2>> = BTreeSet::new();
 
@@ -78,21 +96,22 @@ impl Approx for &Face {
     ) -> Self::Approximation {
         let tolerance = tolerance.into();
 
-        // Curved faces whose curvature is not fully defined by their edges
-        // are not supported yet. For that reason, we can fully ignore `face`'s
-        // `surface` field and just pass the edges to `Self::for_edges`.
-        //
-        // An example of a curved face that is supported, is the cylinder. Its
-        // curvature is fully defined be the edges (circles) that border it. The
-        // circle approximations are sufficient to triangulate the surface.
+        // The curvature of many faces is fully defined by their edges. An
+        // example of that is the cylinder, whose curvature is fully defined by
+        // the edges (circles) that border it. The circle approximations are
+        // sufficient to triangulate the surface.
         //
-        // An example of a curved face that is currently not supported, and thus
-        // doesn't need to be handled here, is a sphere. A spherical face would
-        // would need to provide its own approximation, as the edges that bound
-        // it have nothing to do with its curvature.
+        // Faces on other surfaces, like NURBS surfaces, can curve freely
+        // between their edges. Those surfaces provide additional points from
+        // the interior of the face.
 
         let exterior = self.exterior().approx_with_cache(tolerance, cache);
 
+        let region = Aabb::<2>::from_points(
+            exterior.points().into_iter().map(|point| point.local_form),
+        );
+        let surface_points = (self.surface().deref(), region).approx(tolerance);
+
         let mut interiors = BTreeSet::new();
         for cycle in self.interiors() {
             let cycle = cycle.approx_with_cache(tolerance, cache);
@@ -102,6 +121,8 @@ impl Approx for &Face {
         FaceApprox {
             exterior,
             interiors,
+            surface_points,
+            surface: self.surface().deref().clone(),
             color: self.color(),
             coord_handedness: self.coord_handedness(),
         }
@@ -117,6 +138,15 @@ pub struct FaceApprox {
     /// Approximations of the interior cycles
     pub interiors: BTreeSet<CycleApprox>,
 
+    /// Points from the interior of the face, provided by its surface
+    ///
+    /// These are empty, unless the surface can curve between the edges of the
+    /// face. Some of them might lie outside of the face, or within its holes.
+    pub surface_points: BTreeSet<ApproxPoint<2>>,
+
+    /// The surface of the approximated face
+    pub surface: Surface,
+
     /// The color of the approximated face
     pub color: Color,
 
@@ -30,7 +30,10 @@
 
 use std::iter;
 
-use fj_math::{Circle, Point, Scalar, Sign};
+use fj_math::{
+    Circle, CubicBezier, Ellipse, Helix, Hyperbola, NurbsCurve, Parabola,
+    Point, PolyChain, Scalar, Sign,
+};
 
 use crate::path::{GlobalPath, SurfacePath};
 
@@ -51,7 +54,25 @@ impl Approx for (SurfacePath, RangeOnPath) {
             SurfacePath::Circle(circle) => {
                 approx_circle(&circle, range, tolerance.into())
             }
+            SurfacePath::Ellipse(ellipse) => {
+                approx_ellipse(&ellipse, range, tolerance.into())
+            }
+            SurfacePath::Parabola(parabola) => {
+                approx_parabola(&parabola, range, tolerance.into())
+            }
+            SurfacePath::Hyperbola(hyperbola) => {
+                approx_hyperbola(&hyperbola, range, tolerance.into())
+            }
             SurfacePath::Line(_) => vec![],
+            SurfacePath::Polyline(polyline) => {
+                approx_polyline(&polyline, range)
+            }
+            SurfacePath::Bezier(bezier) => {
+                approx_bezier(&bezier, range, tolerance.into())
+            }
+            SurfacePath::Nurbs(nurbs) => {
+                approx_nurbs(&nurbs, range, tolerance.into())
+            }
         }
     }
 }
@@ -71,7 +92,26 @@ impl Approx for (GlobalPath, RangeOnPath) {
             GlobalPath::Circle(circle) => {
                 approx_circle(&circle, range, tolerance.into())
             }
+            GlobalPath::Ellipse(ellipse) => {
+                approx_ellipse(&ellipse, range, tolerance.into())
+            }
+            GlobalPath::Parabola(parabola) => {
+                approx_parabola(&parabola, range, tolerance.into())
+            }
+            GlobalPath::Hyperbola(hyperbola) => {
+                approx_hyperbola(&hyperbola, range, tolerance.into())
+            }
             GlobalPath::Line(_) => vec![],
+            GlobalPath::Polyline(polyline) => approx_polyline(&polyline, range),
+            GlobalPath::Bezier(bezier) => {
+                approx_bezier(&bezier, range, tolerance.into())
+            }
+            GlobalPath::Nurbs(nurbs) => {
+                approx_nurbs(&nurbs, range, tolerance.into())
+            }
+            GlobalPath::Helix(helix) => {
+                approx_helix(&helix, range, tolerance.into())
+            }
         }
     }
 }
@@ -115,7 +155,426 @@ fn approx_circle<const D: usize>(
     points
 }
 
-struct PathApproxParams {
+/// Approximate an ellipse
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the ellipse.
+fn approx_ellipse<const D: usize>(
+    ellipse: &Ellipse<D>,
+    range: impl Into<RangeOnPath>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let range = range.into();
+
+    let params = PathApproxParams::for_ellipse(ellipse, tolerance);
+    let mut points = Vec::new();
+
+    for point_curve in params.points(range) {
+        let point_global = ellipse.point_from_ellipse_coords(point_curve);
+        points.push((point_curve, point_global));
+    }
+
+    points
+}
+
+/// Approximate a parabola
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the parabola.
+fn approx_parabola<const D: usize>(
+    parabola: &Parabola<D>,
+    range: impl Into<RangeOnPath>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let range = range.into();
+
+    let params = PathApproxParams::for_parabola(parabola, tolerance);
+    let mut points = Vec::new();
+
+    for point_curve in params.points(range) {
+        let point_global = parabola.point_from_parabola_coords(point_curve);
+        points.push((point_curve, point_global));
+    }
+
+    points
+}
+
+/// Approximate a hyperbola
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the hyperbola.
+///
+/// The hyperbola bends most sharply at its vertex, but its coordinates grow
+/// exponentially away from it. The curve is split into segments of unit
+/// length in hyperbola coordinates, each of which is recursively split in half
+/// until it is flat enough. Like with Bézier curves, this makes the resulting
+/// points independent of the range.
+fn approx_hyperbola<const D: usize>(
+    hyperbola: &Hyperbola<D>,
+    range: impl Into<RangeOnPath>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let range = range.into();
+
+    let [a, b] = range.boundary.map(|point| point.t);
+    let [min, max] = if a < b { [a, b] } else { [b, a] };
+
+    let mut coords = Vec::new();
+
+    let mut t = min.floor();
+    while t < max {
+        coords.push(t);
+        subdivide_hyperbola(
+            hyperbola,
+            [t, t + Scalar::ONE],
+            tolerance,
+            0,
+            &mut coords,
+        );
+        t += Scalar::ONE;
+    }
+
+    // We can't generate a point exactly at the boundaries of the range as part
+    // of the approximation. Make sure we stay inside the range.
+    coords.retain(|&t| t > min && t < max);
+
+    if a > b {
+        coords.reverse();
+    }
+
+    coords
+        .into_iter()
+        .map(|t| {
+            let point_curve = Point::from([t]);
+            let point_global =
+                hyperbola.point_from_hyperbola_coords(point_curve);
+            (point_curve, point_global)
+        })
+        .collect()
+}
+
+/// Recursively split a segment of a hyperbola, until it is flat enough
+///
+/// Appends the coordinates of all split points to `coords`, in ascending
+/// order. The boundaries of the segment are not included.
+fn subdivide_hyperbola<const D: usize>(
+    hyperbola: &Hyperbola<D>,
+    [t0, t1]: [Scalar; 2],
+    tolerance: Tolerance,
+    depth: u32,
+    coords: &mut Vec<Scalar>,
+) {
+    // As for Bézier curves, the deviation from the chord is bounded by `h^2 /
+    // 8` times the maximum magnitude of the second derivative. For a
+    // hyperbola, the second derivative equals the vector from the center,
+    // whose magnitude is bounded by the following, at the coordinate farthest
+    // from the vertex.
+    let h = t1 - t0;
+    let (sinh, cosh) = t0.abs().max(t1.abs()).sinh_cosh();
+    let curvature =
+        hyperbola.a().magnitude() * cosh + hyperbola.b().magnitude() * sinh;
+    let deviation = h * h / 8. * curvature;
+
+    let t_mid = (t0 + t1) / 2.;
+    let is_flat = deviation <= tolerance.inner()
+        && !turns_too_much(
+            [t0, t_mid, t1].map(|t| hyperbola.point_from_hyperbola_coords([t])),
+            tolerance,
+        );
+
+    if is_flat || depth >= MAX_SUBDIVISION_DEPTH {
+        return;
+    }
+
+    subdivide_hyperbola(hyperbola, [t0, t_mid], tolerance, depth + 1, coords);
+    coords.push(t_mid);
+    subdivide_hyperbola(hyperbola, [t_mid, t1], tolerance, depth + 1, coords);
+}
+
+/// Approximate a polygonal chain
+///
+/// A polygonal chain is exactly represented by its points, so no tolerance is
+/// required. The approximation consists of those points that are within the
+/// range.
+fn approx_polyline<const D: usize>(
+    polyline: &PolyChain<D>,
+    range: impl Into<RangeOnPath>,
+) -> Vec<(Point<1>, Point<D>)> {
+    let range = range.into();
+
+    let [a, b] = range.boundary.map(|point| point.t);
+    let [min, max] = if a < b { [a, b] } else { [b, a] };
+
+    let mut points: Vec<_> = polyline
+        .points()
+        .iter()
+        .enumerate()
+        .map(|(i, &point)| (Point::from([Scalar::from_u64(i as u64)]), point))
+        .filter(|(point_curve, _)| point_curve.t > min && point_curve.t < max)
+        .collect();
+
+    if a > b {
+        points.reverse();
+    }
+
+    points
+}
+
+/// Approximate a helix
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the helix.
+fn approx_helix(
+    helix: &Helix,
+    range: impl Into<RangeOnPath>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<3>)> {
+    let range = range.into();
+
+    let params = PathApproxParams::for_helix(helix, tolerance);
+    let mut points = Vec::new();
+
+    for point_curve in params.points(range) {
+        let point_global = helix.point_from_helix_coords(point_curve);
+        points.push((point_curve, point_global));
+    }
+
+    points
+}
+
+/// The maximum number of times a curve segment is split in half
+///
+/// Limits the recursion depth when approximating free-form curves, to keep
+/// tiny tolerances from causing an absurd number of points.
+const MAX_SUBDIVISION_DEPTH: u32 = 16;
+
+/// Approximate a cubic Bézier curve
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the curve.
+///
+/// The curve is recursively split in half, until each piece is flat enough to
+/// be approximated by a straight line. This results in more points where the
+/// curve bends sharply, and fewer where it is almost straight. Since the
+/// splitting always starts from the full curve (coordinates `0.` to `1.`), the
+/// resulting points don't depend on the range.
+fn approx_bezier<const D: usize>(
+    bezier: &CubicBezier<D>,
+    range: impl Into<RangeOnPath>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let range = range.into();
+
+    let [a, b] = range.boundary.map(|point| point.t);
+    let [min, max] = if a < b { [a, b] } else { [b, a] };
+
+    let mut coords = Vec::new();
+    subdivide_bezier(
+        bezier,
+        [Scalar::ZERO, Scalar::ONE],
+        tolerance,
+        0,
+        &mut coords,
+    );
+
+    // We can't generate a point exactly at the boundaries of the range as part
+    // of the approximation. Make sure we stay inside the range.
+    coords.retain(|&t| t > min && t < max);
+
+    if a > b {
+        coords.reverse();
+    }
+
+    coords
+        .into_iter()
+        .map(|t| {
+            let point_curve = Point::from([t]);
+            let point_global = bezier.point_from_bezier_coords(point_curve);
+            (point_curve, point_global)
+        })
+        .collect()
+}
+
+/// Recursively split a segment of a Bézier curve, until it is flat enough
+///
+/// Appends the coordinates of all split points to `coords`, in ascending
+/// order. The boundaries of the segment are not included.
+fn subdivide_bezier<const D: usize>(
+    bezier: &CubicBezier<D>,
+    [t0, t1]: [Scalar; 2],
+    tolerance: Tolerance,
+    depth: u32,
+    coords: &mut Vec<Scalar>,
+) {
+    // The distance between a curve and the line connecting the curve's end
+    // points is bounded by `h^2 / 8` times the maximum magnitude of the
+    // curve's second derivative. For a cubic Bézier curve, the second
+    // derivative is linear, so its maximum magnitude on the segment is found
+    // at one of the segment's boundaries.
+    let h = t1 - t0;
+    let curvature = bezier
+        .second_derivative_at([t0])
+        .magnitude()
+        .max(bezier.second_derivative_at([t1]).magnitude());
+    let deviation = h * h / 8. * curvature;
+
+    let t_mid = (t0 + t1) / 2.;
+    let is_flat = deviation <= tolerance.inner()
+        && !turns_too_much(
+            [t0, t_mid, t1].map(|t| bezier.point_from_bezier_coords([t])),
+            tolerance,
+        );
+
+    if is_flat || depth >= MAX_SUBDIVISION_DEPTH {
+        return;
+    }
+
+    subdivide_bezier(bezier, [t0, t_mid], tolerance, depth + 1, coords);
+    coords.push(t_mid);
+    subdivide_bezier(bezier, [t_mid, t1], tolerance, depth + 1, coords);
+}
+
+/// Approximate a NURBS curve
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the curve.
+///
+/// The knots within the curve's domain are always part of the approximation,
+/// as the curve doesn't need to be smooth there. Between the knots, each span
+/// is recursively split in half, until it is flat enough to be approximated by
+/// a straight line (see [`subdivide_by_sampling`]). As with Bézier curves,
+/// this makes the resulting points independent of the range.
+fn approx_nurbs<const D: usize>(
+    nurbs: &NurbsCurve<D>,
+    range: impl Into<RangeOnPath>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let range = range.into();
+
+    let [a, b] = range.boundary.map(|point| point.t);
+    let [min, max] = if a < b { [a, b] } else { [b, a] };
+
+    let [domain_min, domain_max] = nurbs.domain();
+
+    let mut coords = Vec::new();
+    for span in nurbs.knots().windows(2) {
+        let [t0, t1] = [span[0], span[1]];
+
+        if t0 >= t1 || t0 < domain_min || t1 > domain_max {
+            continue;
+        }
+
+        if t0 > domain_min {
+            coords.push(t0);
+        }
+        subdivide_by_sampling(
+            &|t| nurbs.point_from_nurbs_coords([t]),
+            [t0, t1],
+            tolerance,
+            0,
+            &mut coords,
+        );
+    }
+
+    // We can't generate a point exactly at the boundaries of the range as part
+    // of the approximation. Make sure we stay inside the range.
+    coords.retain(|&t| t > min && t < max);
+
+    if a > b {
+        coords.reverse();
+    }
+
+    coords
+        .into_iter()
+        .map(|t| {
+            let point_curve = Point::from([t]);
+            let point_global = nurbs.point_from_nurbs_coords(point_curve);
+            (point_curve, point_global)
+        })
+        .collect()
+}
+
+/// Recursively split a segment of a curve, until it is flat enough
+///
+/// The curve is given as a function that maps curve coordinates to points.
+/// This is used for curves for which there's no cheap bound for their
+/// deviation from a chord, so segments are sampled instead. Checking multiple
+/// points makes sure we also catch segments that cross their chord.
+///
+/// Appends the coordinates of all split points to `coords`, in ascending
+/// order. The boundaries of the segment are not included.
+pub(super) fn subdivide_by_sampling<const D: usize>(
+    f: &impl Fn(Scalar) -> Point<D>,
+    [t0, t1]: [Scalar; 2],
+    tolerance: Tolerance,
+    depth: u32,
+    coords: &mut Vec<Scalar>,
+) {
+    let chord = [t0, t1].map(f);
+    let deviation = [0.25, 0.5, 0.75]
+        .into_iter()
+        .map(|s| distance_to_chord(f(t0 + (t1 - t0) * s), chord))
+        .fold(Scalar::ZERO, Scalar::max);
+
+    let t_mid = (t0 + t1) / 2.;
+    let is_flat = deviation <= tolerance.inner()
+        && !turns_too_much([t0, t_mid, t1].map(f), tolerance);
+
+    if is_flat || depth >= MAX_SUBDIVISION_DEPTH {
+        return;
+    }
+
+    subdivide_by_sampling(f, [t0, t_mid], tolerance, depth + 1, coords);
+    coords.push(t_mid);
+    subdivide_by_sampling(f, [t_mid, t1], tolerance, depth + 1, coords);
+}
+
+/// Determine whether a curve segment changes its direction too much
+///
+/// The segment is given by its boundaries and the point halfway between them.
+/// The change in direction is estimated from the angle between the two halves
+/// of the chord, which is about half of the total change for a segment with
+/// constant curvature.
+fn turns_too_much<const D: usize>(
+    [a, mid, b]: [Point<D>; 3],
+    tolerance: Tolerance,
+) -> bool {
+    if tolerance.max_angle().is_none() {
+        return false;
+    }
+
+    let [first, second] = [mid - a, b - mid];
+    let lengths = first.magnitude() * second.magnitude();
+    if lengths == Scalar::ZERO {
+        return false;
+    }
+
+    let cos = (first.dot(&second) / lengths)
+        .max(-Scalar::ONE)
+        .min(Scalar::ONE);
+    tolerance.exceeds_max_angle(cos.acos() * 2.)
+}
+
+/// Compute the distance between a point and a chord
+fn distance_to_chord<const D: usize>(
+    point: Point<D>,
+    [a, b]: [Point<D>; 2],
+) -> Scalar {
+    let chord = b - a;
+    let length_squared = chord.dot(&chord);
+
+    if length_squared == Scalar::ZERO {
+        return (point - a).magnitude();
+    }
+
+    let s = ((point - a).dot(&chord) / length_squared)
+        .max(Scalar::ZERO)
+        .min(Scalar::ONE);
+
+    (point - (a + chord * s)).magnitude()
+}
+
+pub(super) struct PathApproxParams {
     increment: Scalar,
 }
 
@@ -125,13 +584,63 @@ impl PathApproxParams {
         tolerance: impl Into<Tolerance>,
     ) -> Self {
         let radius = circle.a().magnitude();
+        Self::for_radius(radius, tolerance)
+    }
 
-        let num_vertices_to_approx_full_circle = Scalar::max(
-            Scalar::PI
-                / (Scalar::ONE - (tolerance.into().inner() / radius)).acos(),
+    pub fn for_ellipse<const D: usize>(
+        ellipse: &Ellipse<D>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        // An ellipse is an affine image of a circle, with the same coordinate
+        // system. Its chords deviate from it no more than those of a circle
+        // with the ellipse's major radius would, for the same increment.
+        let radius = ellipse.major_radius();
+        Self::for_radius(radius, tolerance)
+    }
+
+    pub fn for_parabola<const D: usize>(
+        parabola: &Parabola<D>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        // The second derivative of a parabola is constant, `2 * a`. The
+        // deviation of a chord of length `h` (in parabola coordinates) is
+        // bounded by `h^2 / 8` times its magnitude, which results in this
+        // increment.
+        let tolerance = tolerance.into();
+        let mut increment =
+            (tolerance.inner() * 4. / parabola.a().magnitude()).sqrt();
+
+        // The direction of a parabola changes most quickly at its vertex, by
+        // `2 * |a| / |b|` per unit of its coordinate.
+        if let Some(max_angle) = tolerance.max_angle() {
+            let turn_rate =
+                parabola.a().magnitude() * 2. / parabola.b().magnitude();
+            increment = increment.min(max_angle / turn_rate);
+        }
+
+        Self { increment }
+    }
+
+    pub fn for_helix(helix: &Helix, tolerance: impl Into<Tolerance>) -> Self {
+        // The advance along the axis is linear in the helix coordinate, so it
+        // doesn't contribute to how far a chord deviates from the helix. That
+        // leaves the circle that the helix winds around.
+        Self::for_radius(helix.radius(), tolerance)
+    }
+
+    pub fn for_radius(radius: Scalar, tolerance: impl Into<Tolerance>) -> Self {
+        let tolerance = tolerance.into();
+
+        let mut num_vertices_to_approx_full_circle = Scalar::max(
+            Scalar::PI / (Scalar::ONE - (tolerance.inner() / radius)).acos(),
             3.,
-        )
-        .ceil();
+        );
+        if let Some(max_angle) = tolerance.max_angle() {
+            num_vertices_to_approx_full_circle =
+                num_vertices_to_approx_full_circle.max(Scalar::TAU / max_angle);
+        }
+        let num_vertices_to_approx_full_circle =
+            num_vertices_to_approx_full_circle.ceil();
 
         let increment = Scalar::TAU / num_vertices_to_approx_full_circle;
 
@@ -185,11 +694,17 @@ impl PathApproxParams {
 mod tests {
     use std::f64::consts::TAU;
 
-    use fj_math::{Circle, Point, Scalar};
+    use fj_math::{
+        Circle, CubicBezier, Ellipse, Helix, Hyperbola, NurbsCurve, Parabola,
+        Point, PolyChain, Scalar, Vector,
+    };
 
     use crate::algorithms::approx::{path::RangeOnPath, Tolerance};
 
-    use super::PathApproxParams;
+    use super::{
+        approx_bezier, approx_hyperbola, approx_nurbs, approx_parabola,
+        approx_polyline, PathApproxParams,
+    };
 
     #[test]
     fn increment_for_circle() {
@@ -210,6 +725,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn increment_for_circle_with_max_angle() {
+        let tolerance = Tolerance::from_scalar(0.5)
+            .unwrap()
+            .with_max_angle(Scalar::TAU / 12.)
+            .unwrap();
+
+        // A small circle is approximated more densely than the tolerance
+        // requires, to keep the angle between segments small.
+        let circle = Circle::from_center_and_radius([0., 0.], 1.);
+        let params = PathApproxParams::for_circle(&circle, tolerance);
+        assert_eq!(params.increment(), Scalar::TAU / 12.);
+
+        // For a large circle, the tolerance is the stricter limit.
+        let circle = Circle::from_center_and_radius([0., 0.], 100.);
+        let params = PathApproxParams::for_circle(&circle, tolerance);
+        assert!(params.increment() < Scalar::TAU / 12.);
+    }
+
     #[test]
     fn points_for_circle() {
         // At the chosen values for radius and tolerance (see below), the
@@ -258,4 +792,189 @@ mod tests {
             assert_eq!(points, expected_points);
         }
     }
+
+    #[test]
+    fn increment_for_ellipse() {
+        let tolerance = 0.1;
+
+        let ellipse = Ellipse::from_center_and_radii([0., 0.], [1., 0.5]);
+        let circle = Circle::from_center_and_radius([0., 0.], 1.);
+
+        assert_eq!(
+            PathApproxParams::for_ellipse(&ellipse, tolerance).increment(),
+            PathApproxParams::for_circle(&circle, tolerance).increment(),
+        );
+    }
+
+    #[test]
+    fn increment_for_helix() {
+        let tolerance = 0.1;
+
+        let helix = Helix::from_axis_radius_and_pitch(
+            [0., 0., 0.],
+            Vector::unit_z(),
+            1.,
+            5.,
+            2.,
+        );
+        let circle = Circle::from_center_and_radius([0., 0.], 1.);
+
+        assert_eq!(
+            PathApproxParams::for_helix(&helix, tolerance).increment(),
+            PathApproxParams::for_circle(&circle, tolerance).increment(),
+        );
+    }
+
+    #[test]
+    fn points_for_parabola() {
+        let parabola = Parabola::new([0., 0.], [0., 1.], [1., 0.]);
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+
+        let points = approx_parabola(&parabola, [[-2.], [2.]], tolerance);
+        assert!(!points.is_empty());
+
+        // The midpoints of the segments between the points must be within the
+        // tolerance of the parabola.
+        for window in points.windows(2) {
+            let [(a, _), (b, _)] = [window[0], window[1]];
+
+            let t = (a.t + b.t) / 2.;
+            let [a, b] = [a, b].map(|t| parabola.point_from_parabola_coords(t));
+            let chord = a + (b - a) / 2.;
+            let deviation =
+                (parabola.point_from_parabola_coords([t]) - chord).magnitude();
+            assert!(deviation <= tolerance.inner() + Scalar::from(1e-12));
+        }
+    }
+
+    #[test]
+    fn points_for_hyperbola() {
+        let hyperbola = Hyperbola::new([0., 0.], [1., 0.], [0., 1.]);
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+
+        let full = approx_hyperbola(&hyperbola, [[-2.], [2.]], tolerance);
+
+        // The segments between the unit coordinates must be part of the
+        // approximation.
+        for t in [-1., 0., 1.] {
+            assert!(full.iter().any(|(point, _)| point.t == Scalar::from(t)));
+        }
+
+        // Approximating part of the curve must result in a subset of the
+        // points of the full approximation.
+        let partial = approx_hyperbola(&hyperbola, [[-0.5], [1.5]], tolerance);
+        assert!(!partial.is_empty());
+        assert!(partial.iter().all(|point| full.contains(point)));
+
+        let mut reversed =
+            approx_hyperbola(&hyperbola, [[2.], [-2.]], tolerance);
+        reversed.reverse();
+        assert_eq!(reversed, full);
+    }
+
+    #[test]
+    fn points_for_polyline() {
+        let polyline =
+            PolyChain::from_points([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+
+        assert_eq!(
+            approx_polyline(&polyline, [[0.], [3.]]),
+            vec![
+                (Point::from([1.]), Point::from([1., 0.])),
+                (Point::from([2.]), Point::from([1., 1.])),
+            ]
+        );
+        assert_eq!(
+            approx_polyline(&polyline, [[2.5], [0.5]]),
+            vec![
+                (Point::from([2.]), Point::from([1., 1.])),
+                (Point::from([1.]), Point::from([1., 0.])),
+            ]
+        );
+    }
+
+    #[test]
+    fn points_for_straight_bezier() {
+        let bezier = CubicBezier::from_control_points([
+            [0., 0.],
+            [1., 0.],
+            [2., 0.],
+            [3., 0.],
+        ]);
+        let tolerance = Tolerance::from_scalar(0.1).unwrap();
+
+        assert_eq!(approx_bezier(&bezier, [[0.], [1.]], tolerance), vec![]);
+    }
+
+    #[test]
+    fn points_for_curved_bezier() {
+        let bezier = CubicBezier::from_control_points([
+            [0., 0.],
+            [0., 1.],
+            [1., 1.],
+            [1., 0.],
+        ]);
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+
+        let full = approx_bezier(&bezier, [[0.], [1.]], tolerance);
+        assert!(!full.is_empty());
+
+        // Approximating part of the curve must result in a subset of the
+        // points of the full approximation.
+        let partial = approx_bezier(&bezier, [[0.25], [0.75]], tolerance);
+        assert!(!partial.is_empty());
+        assert!(partial.iter().all(|point| full.contains(point)));
+
+        // Reversing the range must reverse the points.
+        let mut reversed = approx_bezier(&bezier, [[1.], [0.]], tolerance);
+        reversed.reverse();
+        assert_eq!(reversed, full);
+    }
+
+    #[test]
+    fn points_for_bezier_with_max_angle() {
+        let bezier = CubicBezier::from_control_points([
+            [0., 0.],
+            [0., 1.],
+            [1., 1.],
+            [1., 0.],
+        ]);
+        let tolerance = Tolerance::from_scalar(0.1).unwrap();
+
+        let coarse = approx_bezier(&bezier, [[0.], [1.]], tolerance);
+        let adaptive = approx_bezier(
+            &bezier,
+            [[0.], [1.]],
+            tolerance.with_max_angle(0.1).unwrap(),
+        );
+        assert!(adaptive.len() > coarse.len());
+
+        // The curve turns by half a circle, so that's spread over enough
+        // points to keep each change in direction small.
+        assert!(adaptive.len() as f64 >= std::f64::consts::PI / 0.1 - 1.);
+    }
+
+    #[test]
+    fn points_for_nurbs() {
+        let nurbs = NurbsCurve::from_control_points(
+            2,
+            [[0., 0.], [1., 1.], [2., 0.], [3., 1.]],
+        );
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+
+        let full = approx_nurbs(&nurbs, [[0.], [1.]], tolerance);
+
+        // The inner knot must be part of the approximation.
+        assert!(full.iter().any(|(point, _)| point.t == Scalar::from(0.5)));
+
+        // Approximating part of the curve must result in a subset of the
+        // points of the full approximation.
+        let partial = approx_nurbs(&nurbs, [[0.3], [0.8]], tolerance);
+        assert!(!partial.is_empty());
+        assert!(partial.iter().all(|point| full.contains(point)));
+
+        let mut reversed = approx_nurbs(&nurbs, [[1.], [0.]], tolerance);
+        reversed.reverse();
+        assert_eq!(reversed, full);
+    }
 }
@@ -0,0 +1,627 @@
+//! Surface approximation
+//!
+//! The curvature of most surfaces is fully defined by the edges that bound a
+//! face on them. A plane has no curvature at all, and the curvature of a
+//! swept curve is captured by the approximation of the curves that bound
+//! the face. Those surfaces don't contribute any points to an approximation.
+//!
+//! Other surfaces, like NURBS surfaces, can curve freely within the
+//! boundaries of a face. They need to provide points in the interior of the
+//! face, so the resulting triangle mesh follows the surface.
+//!
+//! Like path approximation, surface approximation is deterministic for a given
+//! surface and tolerance: The points are taken from a grid that only depends
+//! on those, and the region that is approximated only controls which of its
+//! points are returned.
+
+use std::collections::BTreeSet;
+
+use fj_math::{Aabb, NurbsSurface, Point, Scalar, Sphere, Torus};
+
+use crate::{
+    geometry::{RevolutionSurface, RuledSurface, SurfaceGeometry, SweptPath},
+    objects::Surface,
+    path::GlobalPath,
+};
+
+use super::{
+    path::{subdivide_by_sampling, PathApproxParams, RangeOnPath},
+    Approx, ApproxPoint, Tolerance,
+};
+
+impl Approx for (&Surface, Aabb<2>) {
+    type Approximation = BTreeSet<ApproxPoint<2>>;
+    type Cache = ();
+
+    fn approx_with_cache(
+        self,
+        tolerance: impl Into<Tolerance>,
+        (): &mut Self::Cache,
+    ) -> Self::Approximation {
+        let (surface, region) = self;
+
+        match surface.geometry() {
+            // These surfaces are straight along their v-coordinate, so any
+            // curvature is already covered by the approximation of the face's
+            // edges.
+            SurfaceGeometry::SweptCurve(_)
+            | SurfaceGeometry::Cylinder(_)
+            | SurfaceGeometry::Cone(_) => BTreeSet::new(),
+            SurfaceGeometry::SweptPath(swept) => {
+                approx_swept_path(swept, region, tolerance.into())
+            }
+            SurfaceGeometry::Ruled(ruled) => {
+                approx_ruled_surface(ruled, region, tolerance.into())
+            }
+            SurfaceGeometry::Revolution(revolution) => {
+                approx_revolution_surface(revolution, region, tolerance.into())
+            }
+            SurfaceGeometry::Sphere(sphere) => {
+                approx_sphere(sphere, region, tolerance.into())
+            }
+            SurfaceGeometry::Torus(torus) => {
+                approx_torus(torus, region, tolerance.into())
+            }
+            SurfaceGeometry::Nurbs(nurbs) => {
+                approx_nurbs_surface(nurbs, region, tolerance.into())
+            }
+        }
+    }
+}
+
+fn approx_swept_path(
+    swept: &SweptPath,
+    region: Aabb<2>,
+    tolerance: Tolerance,
+) -> BTreeSet<ApproxPoint<2>> {
+    // The surface is a translation of one path along the other, so the
+    // approximations of both paths span a grid that approximates the surface.
+    // If one of them is a line, the surface is straight in that direction, and
+    // its curvature is covered by the edges.
+    let [coords_u, coords_v] =
+        [(&swept.u, 0), (&swept.v, 1)].map(|(path, i)| {
+            let range = RangeOnPath::from([
+                [region.min.coords.components[i]],
+                [region.max.coords.components[i]],
+            ]);
+
+            (path.clone(), range)
+                .approx(tolerance)
+                .into_iter()
+                .map(|(point, _)| point.t)
+                .collect::<Vec<_>>()
+        });
+
+    let mut points = BTreeSet::new();
+
+    for &u in &coords_u {
+        for &v in &coords_v {
+            let point_surface = Point::from([u, v]);
+            let point_global = swept.point_from_surface_coords(point_surface);
+
+            points.insert(ApproxPoint::new(point_surface, point_global));
+        }
+    }
+
+    points
+}
+
+fn approx_ruled_surface(
+    ruled: &RuledSurface,
+    region: Aabb<2>,
+    tolerance: Tolerance,
+) -> BTreeSet<ApproxPoint<2>> {
+    // The surface is straight along its v-coordinate, so its curvature is
+    // covered by the approximations of the two curves. Those are approximated
+    // independently though, and without further points, the triangulation
+    // could connect points of one curve to arbitrary points of the other.
+    //
+    // Adding a row of points halfway between the curves, at the u-coordinates
+    // of both approximations, makes sure that the triangles follow the
+    // straight lines of the surface.
+    let v = Scalar::ONE / 2.;
+    if v <= region.min.v || v >= region.max.v {
+        return BTreeSet::new();
+    }
+
+    let range = RangeOnPath::from([[region.min.u], [region.max.u]]);
+
+    let mut coords_u = Vec::new();
+    for path in [&ruled.a, &ruled.b] {
+        coords_u.extend(
+            (path.clone(), range)
+                .approx(tolerance)
+                .into_iter()
+                .map(|(point, _)| point.t),
+        );
+    }
+
+    coords_u
+        .into_iter()
+        .map(|u| {
+            let point_surface = Point::from([u, v]);
+            let point_global = ruled.point_from_surface_coords(point_surface);
+
+            ApproxPoint::new(point_surface, point_global)
+        })
+        .collect()
+}
+
+fn approx_revolution_surface(
+    revolution: &RevolutionSurface,
+    region: Aabb<2>,
+    tolerance: Tolerance,
+) -> BTreeSet<ApproxPoint<2>> {
+    // If the profile is a line, the surface is straight along the
+    // v-coordinate, like a cylinder or cone, and its curvature is covered by
+    // the edges.
+    if let GlobalPath::Line(_) = revolution.profile {
+        return BTreeSet::new();
+    }
+
+    // The v-coordinates are the ones that the approximation of the profile
+    // would use. Along each of them, the u-coordinate runs along a circle,
+    // whose radius determines the increment. That keeps the points
+    // independent of the region.
+    let range = RangeOnPath::from([[region.min.v], [region.max.v]]);
+    let coords_v = (revolution.profile.clone(), range)
+        .approx(tolerance)
+        .into_iter()
+        .map(|(point, _)| point.t);
+
+    let mut points = BTreeSet::new();
+
+    for v in coords_v {
+        let radius = revolution.radius_at(v);
+        if radius < tolerance.inner() {
+            // This part of the profile is so close to the axis, that it
+            // doesn't need any points.
+            continue;
+        }
+
+        let increment =
+            PathApproxParams::for_radius(radius, tolerance).increment();
+
+        for u in multiples_within(increment, region.min.u, region.max.u) {
+            let point_surface = Point::from([u, v]);
+            let point_global =
+                revolution.point_from_surface_coords(point_surface);
+
+            points.insert(ApproxPoint::new(point_surface, point_global));
+        }
+    }
+
+    points
+}
+
+fn approx_sphere(
+    sphere: &Sphere,
+    region: Aabb<2>,
+    tolerance: Tolerance,
+) -> BTreeSet<ApproxPoint<2>> {
+    // Both coordinates of the sphere are angles, and the circles along which
+    // they run have no larger radius than the sphere itself. The increment
+    // that is good enough for a great circle works for both of them.
+    let increment =
+        PathApproxParams::for_radius(sphere.radius(), tolerance).increment();
+
+    // The poles are single points, and not part of the grid.
+    let region = Aabb {
+        min: Point::from([region.min.u, region.min.v.max(-Scalar::PI / 2.)]),
+        max: Point::from([region.max.u, region.max.v.min(Scalar::PI / 2.)]),
+    };
+
+    approx_by_increments(
+        &|point| sphere.point_from_sphere_coords(point),
+        [increment, increment],
+        region,
+    )
+}
+
+fn approx_torus(
+    torus: &Torus,
+    region: Aabb<2>,
+    tolerance: Tolerance,
+) -> BTreeSet<ApproxPoint<2>> {
+    // The u-coordinate runs along circles around the axis, the largest of
+    // which is on the outside of the torus. The v-coordinate runs along the
+    // tube circle.
+    let increment_u = PathApproxParams::for_radius(
+        torus.major_radius() + torus.minor_radius(),
+        tolerance,
+    )
+    .increment();
+    let increment_v =
+        PathApproxParams::for_radius(torus.minor_radius(), tolerance)
+            .increment();
+
+    approx_by_increments(
+        &|point| torus.point_from_torus_coords(point),
+        [increment_u, increment_v],
+        region,
+    )
+}
+
+fn approx_nurbs_surface(
+    nurbs: &NurbsSurface,
+    region: Aabb<2>,
+    tolerance: Tolerance,
+) -> BTreeSet<ApproxPoint<2>> {
+    let [min, max] = nurbs.domain();
+    let breakpoints = [0, 1].map(|i| {
+        let mut knots: Vec<_> = nurbs.knots()[i]
+            .iter()
+            .copied()
+            .filter(|&knot| knot >= min.coords.components[i])
+            .filter(|&knot| knot <= max.coords.components[i])
+            .collect();
+        knots.dedup();
+        knots
+    });
+
+    approx_by_sampling(
+        &|point| nurbs.point_from_nurbs_coords(point),
+        breakpoints,
+        region,
+        tolerance,
+    )
+}
+
+/// Approximate a surface by sampling it on a grid
+///
+/// `breakpoints` are the surface coordinates, in u and v direction, that are
+/// always part of the grid. The grid is refined between them, until the
+/// surface deviates from it by no more than the tolerance.
+///
+/// Only grid points that are strictly within `region` are returned.
+pub(super) fn approx_by_sampling(
+    f: &impl Fn(Point<2>) -> Point<3>,
+    breakpoints: [Vec<Scalar>; 2],
+    region: Aabb<2>,
+    tolerance: Tolerance,
+) -> BTreeSet<ApproxPoint<2>> {
+    let [coords_u, coords_v] = refine_grid(f, breakpoints, tolerance);
+
+    let mut points = BTreeSet::new();
+
+    for &u in &coords_u {
+        if u <= region.min.u || u >= region.max.u {
+            continue;
+        }
+
+        for &v in &coords_v {
+            if v <= region.min.v || v >= region.max.v {
+                continue;
+            }
+
+            let point_surface = Point::from([u, v]);
+            let point_global = f(point_surface);
+
+            points.insert(ApproxPoint::new(point_surface, point_global));
+        }
+    }
+
+    points
+}
+
+/// Approximate a surface by sampling it on a uniform grid
+///
+/// The grid consists of all multiples of `increments` in u and v direction.
+/// Only grid points that are strictly within `region` are returned.
+fn approx_by_increments(
+    f: &impl Fn(Point<2>) -> Point<3>,
+    increments: [Scalar; 2],
+    region: Aabb<2>,
+) -> BTreeSet<ApproxPoint<2>> {
+    let [coords_u, coords_v] = [0, 1].map(|i| {
+        multiples_within(
+            increments[i],
+            region.min.coords.components[i],
+            region.max.coords.components[i],
+        )
+    });
+
+    let mut points = BTreeSet::new();
+
+    for &u in &coords_u {
+        for &v in &coords_v {
+            let point_surface = Point::from([u, v]);
+            let point_global = f(point_surface);
+
+            points.insert(ApproxPoint::new(point_surface, point_global));
+        }
+    }
+
+    points
+}
+
+/// Compute all multiples of `increment` that are strictly between `min` and
+/// `max`
+fn multiples_within(
+    increment: Scalar,
+    min: Scalar,
+    max: Scalar,
+) -> Vec<Scalar> {
+    let mut coords = Vec::new();
+
+    let mut k = (min / increment).floor() + 1.;
+    while increment * k < max {
+        coords.push(increment * k);
+        k += Scalar::ONE;
+    }
+
+    coords
+}
+
+fn refine_grid(
+    f: &impl Fn(Point<2>) -> Point<3>,
+    breakpoints: [Vec<Scalar>; 2],
+    tolerance: Tolerance,
+) -> [Vec<Scalar>; 2] {
+    // The grid lines in one direction are refined by sampling the iso-curves
+    // along them, at every breakpoint of the other direction and halfway
+    // between those.
+    let samples = breakpoints.clone().map(|breakpoints| {
+        let mut samples = Vec::new();
+
+        for window in breakpoints.windows(2) {
+            samples.push(window[0]);
+            samples.push((window[0] + window[1]) / 2.);
+        }
+        samples.extend(breakpoints.last());
+
+        samples
+    });
+
+    let [coords_u, coords_v] = [0, 1].map(|i| {
+        let mut coords = Vec::new();
+
+        for window in breakpoints[i].windows(2) {
+            for &other in &samples[1 - i] {
+                let iso_curve = |t: Scalar| {
+                    let mut point = Point::from([other, other]);
+                    point.coords.components[i] = t;
+                    f(point)
+                };
+
+                subdivide_by_sampling(
+                    &iso_curve,
+                    [window[0], window[1]],
+                    tolerance,
+                    0,
+                    &mut coords,
+                );
+            }
+        }
+
+        coords.extend(breakpoints[i].iter().copied());
+        coords.sort();
+        coords.dedup();
+
+        coords
+    });
+
+    [coords_u, coords_v]
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{
+        Aabb, Circle, Line, NurbsSurface, Point, Scalar, Sphere, Torus, Vector,
+    };
+
+    use crate::{
+        algorithms::approx::{path::RangeOnPath, Approx},
+        objects::Surface,
+        path::GlobalPath,
+    };
+
+    #[test]
+    fn approx_plane() {
+        let surface = Surface::xy_plane();
+        let region = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        assert!((&surface, region).approx(0.1).is_empty());
+    }
+
+    #[test]
+    fn approx_sphere() {
+        let sphere = Sphere::from_center_and_radius([0., 0., 0.], 1.);
+        let surface = Surface::from_sphere(sphere);
+        let region = Aabb {
+            min: Point::from([-10., -10.]),
+            max: Point::from([10., 10.]),
+        };
+
+        let approx = (&surface, region).approx(0.01);
+
+        assert!(!approx.is_empty());
+        for point in &approx {
+            // The poles must not be part of the approximation.
+            assert!(point.local_form.v.abs() < Scalar::PI / 2.);
+            assert_eq!(
+                point.global_form,
+                sphere.point_from_sphere_coords(point.local_form)
+            );
+        }
+    }
+
+    #[test]
+    fn approx_swept_path() {
+        let u = GlobalPath::circle_from_radius(1.);
+        let v = GlobalPath::bezier_from_control_points([
+            [0., 0., 0.],
+            [0., 0., 1.],
+            [1., 0., 2.],
+            [1., 0., 3.],
+        ]);
+        let surface = Surface::swept_along_path(u.clone(), v.clone());
+        let region = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([Scalar::TAU, Scalar::ONE]),
+        };
+
+        let approx = (&surface, region).approx(0.01);
+
+        assert!(!approx.is_empty());
+        for point in &approx {
+            assert!(region.contains(point.local_form));
+
+            // Every point is on the circle, translated along the curve.
+            let center = v.point_from_path_coords([point.local_form.v]);
+            let radius = (point.global_form - center).magnitude();
+            assert!((radius - 1.).abs() < Scalar::from(1e-12));
+        }
+
+        // Sweeping along a line doesn't require any points.
+        let surface = Surface::swept_along_path(
+            u,
+            GlobalPath::line_from_points([[0., 0., 0.], [0., 0., 1.]]),
+        );
+        assert!((&surface, region).approx(0.01).is_empty());
+    }
+
+    #[test]
+    fn approx_ruled_surface() {
+        let a = GlobalPath::circle_from_radius(1.);
+        let b = GlobalPath::Line(Line::from_origin_and_direction(
+            Point::from([0., 0., 1.]),
+            Vector::unit_x(),
+        ));
+        let surface = Surface::ruled(a.clone(), b);
+        let region = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([Scalar::PI, Scalar::ONE]),
+        };
+
+        let approx = (&surface, region).approx(0.01);
+
+        // Only the circle contributes points, which are placed halfway to the
+        // line.
+        let num_points_on_circle =
+            (a, RangeOnPath::from([[Scalar::ZERO], [Scalar::PI]]))
+                .approx(0.01)
+                .len();
+        assert_eq!(approx.len(), num_points_on_circle);
+
+        for point in &approx {
+            assert!(region.contains(point.local_form));
+            assert_eq!(point.local_form.v, Scalar::ONE / 2.);
+            assert!((point.global_form.z - 0.5).abs() < Scalar::from(1e-12));
+        }
+    }
+
+    #[test]
+    fn approx_revolution_surface() {
+        let profile = GlobalPath::Circle(Circle::new(
+            [2., 0., 0.],
+            [1., 0., 0.],
+            Vector::from([0., 0., 1.]),
+        ));
+        let axis =
+            Line::from_origin_and_direction(Point::origin(), Vector::unit_z());
+        let surface = Surface::revolved(profile, axis);
+        let region = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([Scalar::TAU, Scalar::TAU]),
+        };
+
+        let approx = (&surface, region).approx(0.01);
+
+        assert!(!approx.is_empty());
+        for point in &approx {
+            assert!(region.contains(point.local_form));
+
+            // Revolving the circle results in a torus, and all points must be
+            // on it.
+            let global = point.global_form;
+            let distance_from_axis =
+                (global.x * global.x + global.y * global.y).sqrt();
+            let distance_from_tube = ((distance_from_axis - 2.)
+                * (distance_from_axis - 2.)
+                + global.z * global.z)
+                .sqrt();
+            assert!((distance_from_tube - 1.).abs() < Scalar::from(1e-12));
+        }
+
+        // Revolving a line doesn't require any points.
+        let surface = Surface::revolved(
+            GlobalPath::line_from_points([[1., 0., 0.], [2., 0., 1.]]),
+            axis,
+        );
+        assert!((&surface, region).approx(0.01).is_empty());
+    }
+
+    #[test]
+    fn approx_torus() {
+        let torus = Torus::new(
+            [0., 0., 0.],
+            [0., 0., 1.],
+            [2., 0., 0.],
+            [1., 0., 0.],
+            [0., 0., 1.],
+        );
+        let surface = Surface::from_torus(torus);
+        let region = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([Scalar::TAU, Scalar::TAU]),
+        };
+
+        let approx = (&surface, region).approx(0.01);
+
+        assert!(!approx.is_empty());
+        for point in &approx {
+            assert!(region.contains(point.local_form));
+
+            // All points must be on the torus.
+            let global = point.global_form;
+            let distance_from_axis =
+                (global.x * global.x + global.y * global.y).sqrt();
+            let distance_from_tube = ((distance_from_axis - 2.)
+                * (distance_from_axis - 2.)
+                + global.z * global.z)
+                .sqrt();
+            assert!((distance_from_tube - 1.).abs() < Scalar::from(1e-12));
+        }
+    }
+
+    #[test]
+    fn approx_nurbs_surface() {
+        let nurbs = NurbsSurface::from_control_points(
+            [2, 2],
+            vec![
+                vec![[0., 0., 0.], [0., 1., 0.], [0., 2., 0.]],
+                vec![[1., 0., 0.], [1., 1., 1.], [1., 2., 0.]],
+                vec![[2., 0., 0.], [2., 1., 0.], [2., 2., 0.]],
+            ],
+        );
+        let surface = Surface::from_nurbs(nurbs.clone());
+        let region = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let tolerance = 0.01;
+        let approx = (&surface, region).approx(tolerance);
+
+        assert!(!approx.is_empty());
+        for point in &approx {
+            assert!(region.contains(point.local_form));
+            assert_eq!(
+                point.global_form,
+                nurbs.point_from_nurbs_coords(point.local_form)
+            );
+        }
+
+        // The approximation must not depend on the region.
+        let smaller_region = Aabb {
+            min: Point::from([0.25, 0.25]),
+            max: Point::from([0.75, 0.75]),
+        };
+        let smaller_approx = (&surface, smaller_region).approx(tolerance);
+        assert!(smaller_approx.is_subset(&approx));
+    }
+}
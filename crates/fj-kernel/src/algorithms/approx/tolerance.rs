@@ -12,6 +12,17 @@ use fj_math::Scalar;
 /// The `Tolerance` type enforces that the tolerance value is always larger than
 /// zero, which is an attribute that the approximation code relies on.
 ///
+/// # Curvature-adaptive approximation
+///
+/// By default, the tolerance value is the only limit. Where a curve bends
+/// gently, the approximation can then be very coarse, while small, tightly
+/// bent features can end up with very few points.
+///
+/// A maximum angle can be set in addition (see [`Tolerance::with_max_angle`]).
+/// The approximation then also limits how much the direction of a curve may
+/// change between two neighboring points, which makes the density of points
+/// follow the curvature of the shape.
+///
 /// # Failing [`From`]/[`Into`] implementation
 ///
 /// The [`From`]/[`Into`] implementations of tolerance are fallible, which goes
@@ -22,7 +33,10 @@ use fj_math::Scalar;
 /// documentation doesn't provide any actual reasoning for this requirement, I'm
 /// feeling free to just ignore it.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
-pub struct Tolerance(Scalar);
+pub struct Tolerance {
+    inner: Scalar,
+    max_angle: Option<Scalar>,
+}
 
 impl Tolerance {
     /// Construct a `Tolerance` from a [`Scalar`]
@@ -37,12 +51,51 @@ impl Tolerance {
             return Err(InvalidTolerance(scalar));
         }
 
-        Ok(Self(scalar))
+        Ok(Self {
+            inner: scalar,
+            max_angle: None,
+        })
+    }
+
+    /// Limit the angle between neighboring segments of an approximation
+    ///
+    /// `max_angle` is in radians. Returns an error, if it is not larger than
+    /// zero.
+    pub fn with_max_angle(
+        self,
+        max_angle: impl Into<Scalar>,
+    ) -> Result<Self, InvalidTolerance> {
+        let max_angle = max_angle.into();
+
+        if max_angle <= Scalar::ZERO {
+            return Err(InvalidTolerance(max_angle));
+        }
+
+        Ok(Self {
+            max_angle: Some(max_angle),
+            ..self
+        })
     }
 
     /// Return the [`Scalar`] that defines the tolerance
     pub fn inner(&self) -> Scalar {
-        self.0
+        self.inner
+    }
+
+    /// Return the maximum angle between neighboring segments, if any
+    pub fn max_angle(&self) -> Option<Scalar> {
+        self.max_angle
+    }
+
+    /// Determine whether the direction of a curve changes too much
+    ///
+    /// `angle` is the change in direction of a curve between two points of the
+    /// approximation. Always returns `false`, if no maximum angle is set.
+    pub fn exceeds_max_angle(&self, angle: Scalar) -> bool {
+        match self.max_angle {
+            Some(max_angle) => angle > max_angle,
+            None => false,
+        }
     }
 }
 
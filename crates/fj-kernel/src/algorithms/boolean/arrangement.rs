@@ -0,0 +1,328 @@
+//! Planar arrangement of line segments
+//!
+//! Used to split a face into the regions that are bounded by its edges and its
+//! intersections with other faces.
+
+use std::collections::BTreeSet;
+
+use fj_math::{Point, Scalar, Vector};
+
+use super::EPSILON;
+
+/// The arrangement of a set of line segments in a plane
+///
+/// Segments are split wherever they cross or touch each other, and segments
+/// that don't bound any region are discarded.
+pub struct Arrangement {
+    points: Vec<Point<2>>,
+    edges: BTreeSet<[usize; 2]>,
+}
+
+impl Arrangement {
+    /// Compute the arrangement of the provided segments
+    pub fn new(segments: impl IntoIterator<Item = [Point<2>; 2]>) -> Self {
+        let mut points = Vec::new();
+
+        let segments = segments
+            .into_iter()
+            .filter_map(|segment| {
+                let [a, b] = segment.map(|point| insert(&mut points, point));
+                (a != b).then_some([a, b])
+            })
+            .collect::<Vec<_>>();
+
+        let mut crossings = Vec::new();
+        for (i, &[a, b]) in segments.iter().enumerate() {
+            for &[c, d] in &segments[i + 1..] {
+                crossings.extend(crossing(
+                    [points[a], points[b]],
+                    [points[c], points[d]],
+                ));
+            }
+        }
+        for point in crossings {
+            insert(&mut points, point);
+        }
+
+        let mut edges = BTreeSet::new();
+        for [a, b] in segments {
+            let [start, end] = [points[a], points[b]];
+            let direction = end - start;
+
+            // Split the segment at every point that lies on it.
+            let mut points_on_segment = points
+                .iter()
+                .enumerate()
+                .filter_map(|(i, &point)| {
+                    if i == a {
+                        return Some((Scalar::ZERO, i));
+                    }
+                    if i == b {
+                        return Some((Scalar::ONE, i));
+                    }
+
+                    let t = (point - start).dot(&direction)
+                        / direction.dot(&direction);
+                    let distance = (start + direction * t - point).magnitude();
+
+                    (t > Scalar::ZERO
+                        && t < Scalar::ONE
+                        && distance < Scalar::from_f64(EPSILON))
+                    .then_some((t, i))
+                })
+                .collect::<Vec<_>>();
+            points_on_segment.sort();
+
+            for pair in points_on_segment.windows(2) {
+                let [(_, a), (_, b)] = [pair[0], pair[1]];
+                if a != b {
+                    edges.insert([a.min(b), a.max(b)]);
+                }
+            }
+        }
+
+        // Edges that end in a point that no other edge connects to can't bound
+        // any region. Remove them, until none are left.
+        loop {
+            let mut degrees = vec![0; points.len()];
+            for &[a, b] in &edges {
+                degrees[a] += 1;
+                degrees[b] += 1;
+            }
+
+            let num_edges = edges.len();
+            edges.retain(|&[a, b]| degrees[a] > 1 && degrees[b] > 1);
+
+            if edges.len() == num_edges {
+                break;
+            }
+        }
+
+        Self { points, edges }
+    }
+
+    /// Compute the regions that are bounded by the arrangement
+    ///
+    /// The unbounded region outside of the arrangement is not returned.
+    pub fn regions(&self) -> Vec<Region> {
+        // The neighbors of each point, sorted counter-clockwise.
+        let mut neighbors = vec![Vec::new(); self.points.len()];
+        for &[a, b] in &self.edges {
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+        for (i, neighbors) in neighbors.iter_mut().enumerate() {
+            let origin = self.points[i];
+            neighbors.sort_by_key(|&neighbor| {
+                let direction = self.points[neighbor] - origin;
+                direction.v.atan2(direction.u)
+            });
+        }
+
+        // Walk along the edges, always turning as far left as possible. This
+        // traces the boundaries of all regions, with each region on the left.
+        let mut visited = BTreeSet::new();
+        let mut cycles = Vec::new();
+        for &[a, b] in &self.edges {
+            for start in [[a, b], [b, a]] {
+                if visited.contains(&start) {
+                    continue;
+                }
+
+                let mut cycle = Vec::new();
+                let mut edge = start;
+                while visited.insert(edge) {
+                    let [from, to] = edge;
+                    cycle.push(from);
+
+                    let around = &neighbors[to];
+                    let i = around
+                        .iter()
+                        .position(|&neighbor| neighbor == from)
+                        .expect("Edge must be registered with its neighbors");
+                    let next = around[(i + around.len() - 1) % around.len()];
+
+                    edge = [to, next];
+                }
+
+                cycles.push(cycle);
+            }
+        }
+
+        // Counter-clockwise cycles bound regions. Clockwise ones are the outer
+        // boundaries of connected parts of the arrangement, and thus bound
+        // holes in the smallest region that contains them (if any).
+        let (exteriors, interiors): (Vec<_>, Vec<_>) = cycles
+            .into_iter()
+            .map(|cycle| {
+                let area = signed_area(&self.polygon(&cycle));
+                (cycle, area)
+            })
+            .partition(|&(_, area)| area > Scalar::ZERO);
+
+        let mut regions = exteriors
+            .iter()
+            .map(|(cycle, _)| Region {
+                exterior: self.polygon(cycle),
+                interiors: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+
+        for (interior, _) in interiors {
+            let point = self.points[interior[0]];
+
+            let container = exteriors
+                .iter()
+                .enumerate()
+                .filter(|(_, (exterior, _))| {
+                    exterior.iter().all(|i| !interior.contains(i))
+                })
+                .filter(|(i, _)| contains(&regions[*i].exterior, point))
+                .min_by_key(|(_, (_, area))| *area)
+                .map(|(i, _)| i);
+
+            if let Some(i) = container {
+                regions[i].interiors.push(self.polygon(&interior));
+            }
+        }
+
+        regions
+    }
+
+    fn polygon(&self, cycle: &[usize]) -> Vec<Point<2>> {
+        cycle.iter().map(|&i| self.points[i]).collect()
+    }
+}
+
+/// A region of a plane that is bounded by an [`Arrangement`]
+#[derive(Clone, Debug)]
+pub struct Region {
+    /// The exterior boundary of the region, wound counter-clockwise
+    pub exterior: Vec<Point<2>>,
+
+    /// The boundaries of the holes in the region, wound clockwise
+    pub interiors: Vec<Vec<Point<2>>>,
+}
+
+impl Region {
+    /// Determine whether the region contains the provided point
+    pub fn contains(&self, point: Point<2>) -> bool {
+        contains(&self.exterior, point)
+            && !self
+                .interiors
+                .iter()
+                .any(|interior| contains(interior, point))
+    }
+
+    /// Compute a point that lies within the region
+    ///
+    /// The point is placed just inside of the region's longest edge, where it
+    /// is least likely to be affected by numerical inaccuracy.
+    pub fn sample_point(&self) -> Point<2> {
+        let mut edges = self
+            .exterior
+            .iter()
+            .zip(self.exterior.iter().cycle().skip(1))
+            .map(|(&a, &b)| [a, b])
+            .collect::<Vec<_>>();
+        edges.sort_by_key(|&[a, b]| -(b - a).magnitude());
+
+        for [a, b] in &edges {
+            let direction = *b - *a;
+            let midpoint = *a + direction / 2.;
+
+            // Which side of the edge is inside depends on the winding of the
+            // region, which might have been reversed.
+            let normal = Vector::from([-direction.v, direction.u]);
+            for offset in [1e-2, 1e-4, 1e-6] {
+                for normal in [normal, -normal] {
+                    let point = midpoint + normal * offset;
+                    if self.contains(point) {
+                        return point;
+                    }
+                }
+            }
+        }
+
+        // We should never get here for a valid region. But the midpoint of its
+        // longest edge is at least close.
+        let [a, b] = edges[0];
+        a + (b - a) / 2.
+    }
+
+    /// Reverse the winding of the region's boundaries
+    pub fn reverse(mut self) -> Self {
+        for cycle in
+            std::iter::once(&mut self.exterior).chain(&mut self.interiors)
+        {
+            cycle.reverse();
+        }
+        self
+    }
+}
+
+/// Determine whether a polygon contains a point
+///
+/// Points on the boundary of the polygon may or may not be considered to be
+/// contained.
+pub fn contains(polygon: &[Point<2>], point: Point<2>) -> bool {
+    let mut inside = false;
+
+    for (a, b) in polygon.iter().zip(polygon.iter().cycle().skip(1)) {
+        if (a.v > point.v) != (b.v > point.v) {
+            let u = a.u + (point.v - a.v) / (b.v - a.v) * (b.u - a.u);
+            if point.u < u {
+                inside = !inside;
+            }
+        }
+    }
+
+    inside
+}
+
+/// Insert a point, unless an identical one already exists
+///
+/// Returns the index of the point.
+fn insert(points: &mut Vec<Point<2>>, point: Point<2>) -> usize {
+    let existing = points.iter().position(|&existing| {
+        (existing - point).magnitude() < Scalar::from_f64(EPSILON)
+    });
+
+    existing.unwrap_or_else(|| {
+        points.push(point);
+        points.len() - 1
+    })
+}
+
+/// Compute the point where two segments cross, if any
+///
+/// Segments that are parallel are not considered to cross. Any points where
+/// they overlap are handled by splitting the segments at each other's end
+/// points instead.
+fn crossing([a, b]: [Point<2>; 2], [c, d]: [Point<2>; 2]) -> Option<Point<2>> {
+    let ab = b - a;
+    let cd = d - c;
+    let ac = c - a;
+
+    let denominator = ab.u * cd.v - ab.v * cd.u;
+    if denominator.abs()
+        < Scalar::from_f64(EPSILON) * ab.magnitude() * cd.magnitude()
+    {
+        return None;
+    }
+
+    let t = (ac.u * cd.v - ac.v * cd.u) / denominator;
+    let s = (ac.u * ab.v - ac.v * ab.u) / denominator;
+
+    let range = Scalar::ZERO..=Scalar::ONE;
+    (range.contains(&t) && range.contains(&s)).then_some(a + ab * t)
+}
+
+fn signed_area(polygon: &[Point<2>]) -> Scalar {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| a.u * b.v - b.u * a.v)
+        .fold(Scalar::ZERO, |sum, area| sum + area)
+        / 2.
+}
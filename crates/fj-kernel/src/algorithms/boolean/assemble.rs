@@ -0,0 +1,287 @@
+//! Assembly of face fragments into a shell
+
+use std::collections::BTreeMap;
+
+use fj_interop::mesh::Color;
+use fj_math::{Plane, Point, Scalar};
+
+use crate::{
+    objects::{
+        Cycle, Face, GlobalCurve, GlobalEdge, GlobalVertex, HalfEdge, Objects,
+        Shell, Surface, SurfaceVertex, Vertex,
+    },
+    partial::HasPartial,
+    storage::Handle,
+};
+
+use super::{arrangement::Region, PlanarFace};
+
+/// Assembles face fragments into a shell
+///
+/// Fragments are collected first, and only converted into faces once all of
+/// them are known. This way, fragments that touch each other can share their
+/// vertices and edges.
+pub struct Assembler {
+    fragments: Vec<Fragment>,
+}
+
+impl Assembler {
+    pub fn new() -> Self {
+        Self {
+            fragments: Vec::new(),
+        }
+    }
+
+    /// Add a region of a face to the result
+    pub(super) fn add(&mut self, face: &PlanarFace, region: Region) {
+        let surface = face.face.surface();
+
+        let cycles = std::iter::once(region.exterior)
+            .chain(region.interiors)
+            .map(|cycle| {
+                cycle
+                    .into_iter()
+                    .map(|point| surface.point_from_surface_coords(point))
+                    .collect()
+            })
+            .collect();
+
+        self.add_polygon(
+            surface.clone(),
+            face.plane,
+            face.face.color(),
+            cycles,
+        );
+    }
+
+    /// Add a polygon to the result
+    ///
+    /// `plane` must be the plane that `surface` represents. The first of the
+    /// `cycles` is the exterior of the polygon, the others are its interiors.
+    pub fn add_polygon(
+        &mut self,
+        surface: Handle<Surface>,
+        plane: Plane,
+        color: Color,
+        cycles: Vec<Vec<Point<3>>>,
+    ) {
+        let cycles = cycles
+            .into_iter()
+            .map(|cycle| {
+                cycle
+                    .into_iter()
+                    .map(|point| (point, plane.point_to_plane_coords(point)))
+                    .collect()
+            })
+            .collect();
+
+        self.add_polygon_in_surface(surface, color, cycles);
+    }
+
+    /// Add a polygon in an arbitrary surface to the result
+    ///
+    /// Each point of the `cycles` is provided in model and surface coordinates.
+    /// The edges between the points are straight lines in both, which is only
+    /// the case for some surfaces, like planes or ruled surfaces between lines.
+    pub fn add_polygon_in_surface(
+        &mut self,
+        surface: Handle<Surface>,
+        color: Color,
+        cycles: Vec<Vec<(Point<3>, Point<2>)>>,
+    ) {
+        self.fragments.push(Fragment {
+            surface,
+            color,
+            cycles,
+        });
+    }
+
+    /// Build a shell from all fragments that have been added
+    pub fn build(self, objects: &Objects) -> Shell {
+        // Vertices that are closer than this would not pass validation, so
+        // they are merged.
+        let tolerance = Scalar::from_f64(5e-7);
+
+        let mut points = Vec::new();
+        let fragments = self
+            .fragments
+            .into_iter()
+            .map(|fragment| {
+                let cycles = fragment
+                    .cycles
+                    .iter()
+                    .map(|cycle| {
+                        let mut cycle = cycle
+                            .iter()
+                            .map(|&(point, point_surface)| {
+                                let i = insert(&mut points, point, tolerance);
+                                (i, point_surface)
+                            })
+                            .collect::<Vec<_>>();
+                        cycle.dedup_by_key(|(i, _)| *i);
+                        while cycle.len() > 1
+                            && cycle.first().map(|(i, _)| i)
+                                == cycle.last().map(|(i, _)| i)
+                        {
+                            cycle.pop();
+                        }
+                        cycle
+                    })
+                    .collect::<Vec<_>>();
+
+                (fragment, cycles)
+            })
+            .collect::<Vec<_>>();
+
+        // Where a vertex of one fragment lies on the edge of another, that
+        // edge needs to be split, or the fragments wouldn't share their edges.
+        let fragments = fragments
+            .into_iter()
+            .map(|(fragment, cycles)| {
+                let cycles = cycles
+                    .into_iter()
+                    .map(|cycle| split_at_vertices(&cycle, &points, tolerance))
+                    .filter(|cycle| cycle.len() >= 3)
+                    .collect::<Vec<_>>();
+
+                (fragment, cycles)
+            })
+            .collect::<Vec<_>>();
+
+        let global_vertices = points
+            .iter()
+            .map(|&point| GlobalVertex::from_position(point, objects))
+            .collect::<Vec<_>>();
+        let mut global_curves = BTreeMap::new();
+
+        let mut faces = Vec::new();
+        for (fragment, cycles) in fragments {
+            let mut cycles = cycles.into_iter().map(|cycle| {
+                let half_edges =
+                    cycle.iter().zip(cycle.iter().cycle().skip(1)).map(
+                        |(&(a, a_surface), &(b, b_surface))| {
+                            let global_curve = global_curves
+                                .entry([a.min(b), a.max(b)])
+                                .or_insert_with(|| GlobalCurve::new(objects))
+                                .clone();
+
+                            let vertices = [(a, a_surface), (b, b_surface)]
+                                .map(|(i, position)| {
+                                    Vertex::partial().with_surface_form(Some(
+                                        SurfaceVertex::partial()
+                                            .with_surface(Some(
+                                                fragment.surface.clone(),
+                                            ))
+                                            .with_position(Some(position))
+                                            .with_global_form(Some(
+                                                global_vertices[i].clone(),
+                                            )),
+                                    ))
+                                });
+
+                            HalfEdge::partial()
+                                .with_surface(Some(fragment.surface.clone()))
+                                .with_global_form(Some(
+                                    GlobalEdge::partial()
+                                        .with_curve(Some(global_curve))
+                                        .with_vertices(Some([a, b].map(|i| {
+                                            global_vertices[i].clone()
+                                        }))),
+                                ))
+                                .with_vertices(Some(vertices))
+                                .as_line_segment()
+                                .and_then(|half_edge| half_edge.build(objects))
+                                .expect("Failed to build fragment edge")
+                        },
+                    );
+
+                Cycle::new(fragment.surface.clone(), half_edges)
+            });
+
+            // A fragment without an exterior has collapsed, due to the merging
+            // of vertices.
+            if let Some(exterior) = cycles.next() {
+                let face = Face::from_exterior(exterior)
+                    .with_interiors(cycles)
+                    .with_color(fragment.color);
+                faces.push(face);
+            }
+        }
+
+        Shell::new().with_faces(faces)
+    }
+}
+
+struct Fragment {
+    surface: Handle<Surface>,
+    color: Color,
+
+    /// The cycles of the fragment, starting with its exterior
+    ///
+    /// Each point is stored in model and surface coordinates.
+    cycles: Vec<Vec<(Point<3>, Point<2>)>>,
+}
+
+/// Insert a point, unless one within `tolerance` already exists
+///
+/// Returns the index of the point.
+fn insert(
+    points: &mut Vec<Point<3>>,
+    point: Point<3>,
+    tolerance: Scalar,
+) -> usize {
+    let existing = points
+        .iter()
+        .position(|&existing| (existing - point).magnitude() < tolerance);
+
+    existing.unwrap_or_else(|| {
+        points.push(point);
+        points.len() - 1
+    })
+}
+
+/// Insert all points into a cycle, that lie on its edges
+///
+/// The surface coordinates of the inserted points are interpolated along the
+/// edge they lie on.
+fn split_at_vertices(
+    cycle: &[(usize, Point<2>)],
+    points: &[Point<3>],
+    tolerance: Scalar,
+) -> Vec<(usize, Point<2>)> {
+    let mut split = Vec::new();
+
+    for (&(a, a_surface), &(b, b_surface)) in
+        cycle.iter().zip(cycle.iter().cycle().skip(1))
+    {
+        let start = points[a];
+        let direction = points[b] - start;
+
+        let mut points_on_edge = points
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &point)| {
+                if i == a || i == b {
+                    return None;
+                }
+
+                let t =
+                    (point - start).dot(&direction) / direction.dot(&direction);
+                let distance = (start + direction * t - point).magnitude();
+
+                (t > Scalar::ZERO && t < Scalar::ONE && distance < tolerance)
+                    .then_some((t, i))
+            })
+            .collect::<Vec<_>>();
+        points_on_edge.sort();
+
+        split.push((a, a_surface));
+        split.extend(
+            points_on_edge
+                .into_iter()
+                .map(|(t, i)| (i, a_surface + (b_surface - a_surface) * t)),
+        );
+    }
+
+    split
+}
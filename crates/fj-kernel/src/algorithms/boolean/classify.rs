@@ -0,0 +1,124 @@
+//! Classification of points relative to a shell
+
+use fj_math::{Bvh, Point, Scalar, Vector};
+
+use crate::algorithms::intersect::{
+    face_point::FacePointIntersection, Intersect,
+};
+
+use super::{aabb_around, PlanarFace, EPSILON};
+
+/// The location of a point relative to a shell
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Location {
+    /// The point is inside of the shell
+    Inside,
+
+    /// The point is outside of the shell
+    Outside,
+
+    /// The point is on a face of the shell that faces the same direction
+    OnSameSide,
+
+    /// The point is on a face of the shell that faces the opposite direction
+    OnOppositeSide,
+}
+
+/// Determine the location of a point on a face relative to a shell
+///
+/// `normal` is the normal of the face that the point is on. It is used to
+/// distinguish between the two kinds of location on the boundary of the shell.
+pub fn locate(
+    point: Point<3>,
+    normal: Vector<3>,
+    shell: &Bvh<&PlanarFace>,
+) -> Location {
+    for &face in shell.query_aabb(&aabb_around([point])) {
+        if face.distance_to(point) < Scalar::from_f64(EPSILON)
+            && contains(face, point)
+        {
+            return if face.normal().dot(&normal) > Scalar::ZERO {
+                Location::OnSameSide
+            } else {
+                Location::OnOppositeSide
+            };
+        }
+    }
+
+    // The point is not on the boundary of the shell. Cast a ray and count how
+    // often it crosses the boundary. If the ray hits an edge or grazes along a
+    // face, the result would be ambiguous, so try another direction.
+    let mut num_hits = 0;
+    for direction in RAY_DIRECTIONS {
+        if let Some(hits) = count_hits(point, Vector::from(direction), shell) {
+            num_hits = hits;
+            break;
+        }
+    }
+
+    if num_hits % 2 == 0 {
+        Location::Outside
+    } else {
+        Location::Inside
+    }
+}
+
+/// Count the faces that a ray hits
+///
+/// Returns `None`, if any of the hits is ambiguous.
+fn count_hits(
+    origin: Point<3>,
+    direction: Vector<3>,
+    shell: &Bvh<&PlanarFace>,
+) -> Option<usize> {
+    let mut num_hits = 0;
+
+    // Faces whose bounding boxes the ray misses can't be hit.
+    for &face in shell.query_ray(origin, direction) {
+        let (distance, normal) = face.plane.constant_normal_form();
+
+        let denominator = normal.dot(&direction);
+        if denominator.abs() < Scalar::from_f64(EPSILON) {
+            if face.distance_to(origin) < Scalar::from_f64(EPSILON) {
+                // The ray lies in the plane of the face.
+                return None;
+            }
+
+            continue;
+        }
+
+        let t = (distance - normal.dot(&origin.coords)) / denominator;
+        if t <= Scalar::ZERO {
+            continue;
+        }
+
+        let hit = origin + direction * t;
+        let hit = face.plane.point_to_plane_coords(hit);
+
+        match (face.face, &hit).intersect() {
+            Some(FacePointIntersection::PointIsInsideFace) => {
+                num_hits += 1;
+            }
+            Some(_) => return None,
+            None => {}
+        }
+    }
+
+    Some(num_hits)
+}
+
+fn contains(face: &PlanarFace, point: Point<3>) -> bool {
+    let point = face.plane.point_to_plane_coords(point);
+    (face.face, &point).intersect().is_some()
+}
+
+/// The directions of the rays used for classification
+///
+/// These are chosen to be unlikely to be aligned with any edges or faces of
+/// typical models.
+const RAY_DIRECTIONS: [[f64; 3]; 4] = [
+    [0.61, 0.37, 0.71],
+    [-0.43, 0.79, 0.53],
+    [0.29, -0.83, 0.47],
+    [-0.67, -0.23, -0.71],
+];
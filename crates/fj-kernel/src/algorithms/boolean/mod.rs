@@ -0,0 +1,630 @@
+//! Boolean operations on solids and sketches
+//!
+//! Both operands are split along their mutual intersections. Each fragment of
+//! a face is then classified as being inside or outside of the other operand,
+//! and the fragments that make up the result are assembled into a new shell.
+//!
+//! Sketches are handled the same way, except that all of their faces are
+//! required to be in the same surface. The regions that make up the result are
+//! merged, where they touch.
+//!
+//! # Implementation Note
+//!
+//! Only shapes that are bounded by planar faces with straight edges are
+//! supported so far. Other shapes will result in a panic. Callers that process
+//! user input should check the faces of both operands using
+//! [`Face::is_polygon`] first.
+//!
+//! The operands are expected to be closed ("watertight") shells. This is not
+//! checked.
+
+mod arrangement;
+pub(crate) mod assemble;
+mod classify;
+mod sketch;
+mod split;
+
+use fj_math::{Aabb, Bvh, Line, Plane, Point, Scalar, Vector};
+
+use crate::{
+    geometry::{SurfaceGeometry, SweptCurve},
+    objects::{Face, Handedness, Objects, Shell, Sketch, Solid},
+    path::{GlobalPath, SurfacePath},
+};
+
+use self::{arrangement::contains, assemble::Assembler, classify::Location};
+
+/// Compute the union of two shapes
+///
+/// The result contains all points that are contained in either of the shapes.
+/// Unlike [`fj::Group`], the shapes are allowed to touch or overlap. Faces
+/// that end up inside of the other shape are removed.
+///
+/// [`fj::Group`]: https://docs.rs/fj/latest/fj/struct.Group.html
+pub trait Union {
+    /// Compute the union of `self` and `other`
+    #[must_use]
+    fn union(self, other: Self, objects: &Objects) -> Self;
+}
+
+impl Union for Shell {
+    fn union(self, other: Self, objects: &Objects) -> Self {
+        Operation::Union.apply(&self, &other, objects)
+    }
+}
+
+impl Union for Solid {
+    fn union(self, other: Self, objects: &Objects) -> Self {
+        let [a, b] = [self, other].map(merge_shells);
+        Solid::new().with_shells([a.union(b, objects)])
+    }
+}
+
+/// Compute the difference of two shapes
+///
+/// The result contains all points of `self` that are not contained in
+/// `other`. Where `other` cuts into `self`, its faces become the new boundary
+/// of the result.
+pub trait Difference {
+    /// Subtract `other` from `self`
+    #[must_use]
+    fn difference(self, other: Self, objects: &Objects) -> Self;
+}
+
+impl Difference for Shell {
+    fn difference(self, other: Self, objects: &Objects) -> Self {
+        Operation::Difference.apply(&self, &other, objects)
+    }
+}
+
+impl Difference for Solid {
+    fn difference(self, other: Self, objects: &Objects) -> Self {
+        let [a, b] = [self, other].map(merge_shells);
+        Solid::new().with_shells([a.difference(b, objects)])
+    }
+}
+
+impl Union for Sketch {
+    fn union(self, other: Self, objects: &Objects) -> Self {
+        sketch::apply(Operation::Union, &self, &other, objects)
+    }
+}
+
+impl Difference for Sketch {
+    fn difference(self, other: Self, objects: &Objects) -> Self {
+        sketch::apply(Operation::Difference, &self, &other, objects)
+    }
+}
+
+/// Compute the intersection of two shapes
+///
+/// The result contains all points that are contained in both of the shapes.
+pub trait Intersection {
+    /// Compute the intersection of `self` and `other`
+    #[must_use]
+    fn intersection(self, other: Self, objects: &Objects) -> Self;
+}
+
+impl Intersection for Shell {
+    fn intersection(self, other: Self, objects: &Objects) -> Self {
+        Operation::Intersection.apply(&self, &other, objects)
+    }
+}
+
+impl Intersection for Solid {
+    fn intersection(self, other: Self, objects: &Objects) -> Self {
+        let [a, b] = [self, other].map(merge_shells);
+        Solid::new().with_shells([a.intersection(b, objects)])
+    }
+}
+
+impl Intersection for Sketch {
+    fn intersection(self, other: Self, objects: &Objects) -> Self {
+        sketch::apply(Operation::Intersection, &self, &other, objects)
+    }
+}
+
+/// A boolean operation
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Operation {
+    Union,
+    Difference,
+    Intersection,
+}
+
+impl Operation {
+    fn apply(self, a: &Shell, b: &Shell, objects: &Objects) -> Shell {
+        let [a, b] = [a, b].map(|shell| {
+            shell
+                .faces()
+                .into_iter()
+                .map(PlanarFace::new)
+                .collect::<Vec<_>>()
+        });
+        let [bvh_a, bvh_b] = [&a, &b].map(|faces| {
+            Bvh::new(faces.iter().map(|face| (face.aabb(), face)))
+        });
+
+        let mut assembler = Assembler::new();
+
+        for (faces, other, operand) in
+            [(&a, &bvh_b, Operand::A), (&b, &bvh_a, Operand::B)]
+        {
+            for face in faces {
+                for region in split::split_face(face, other) {
+                    let point = face
+                        .face
+                        .surface()
+                        .point_from_surface_coords(region.sample_point());
+                    let location =
+                        classify::locate(point, face.normal(), other);
+
+                    match self.select(operand, location) {
+                        Selection::Discard => {}
+                        Selection::Keep => assembler.add(face, region),
+                        Selection::KeepReversed => {
+                            assembler.add(face, region.reverse());
+                        }
+                    }
+                }
+            }
+        }
+
+        assembler.build(objects)
+    }
+
+    /// Determine what happens to a face fragment in the result
+    fn select(self, operand: Operand, location: Location) -> Selection {
+        match (self, operand, location) {
+            (Self::Union, _, Location::Outside) => Selection::Keep,
+            (Self::Union, _, Location::Inside) => Selection::Discard,
+            // Where the boundaries of both operands coincide, only one of the
+            // coincident fragments must be kept. If they face in opposite
+            // directions, the operands touch there, and the fragments end up
+            // inside of the result.
+            (Self::Union, Operand::A, Location::OnSameSide) => Selection::Keep,
+            (Self::Union, Operand::B, Location::OnSameSide) => {
+                Selection::Discard
+            }
+            (Self::Union, _, Location::OnOppositeSide) => Selection::Discard,
+
+            // Where `b` cuts into `a`, its faces bound the result, but they
+            // face into the opposite direction.
+            (Self::Difference, Operand::A, Location::Outside) => {
+                Selection::Keep
+            }
+            (Self::Difference, Operand::B, Location::Inside) => {
+                Selection::KeepReversed
+            }
+            // Where `b` touches `a` from the outside, the boundary of `a`
+            // remains. Where both boundaries face the same direction, the
+            // fragments are removed along with the material behind them.
+            (Self::Difference, Operand::A, Location::OnOppositeSide) => {
+                Selection::Keep
+            }
+            (Self::Difference, _, _) => Selection::Discard,
+
+            (Self::Intersection, _, Location::Inside) => Selection::Keep,
+            (Self::Intersection, Operand::A, Location::OnSameSide) => {
+                Selection::Keep
+            }
+            (Self::Intersection, _, _) => Selection::Discard,
+        }
+    }
+
+    /// Determine whether a point is part of the result
+    ///
+    /// Takes whether the point is contained in either operand.
+    fn contains(self, in_a: bool, in_b: bool) -> bool {
+        match self {
+            Self::Union => in_a || in_b,
+            Self::Difference => in_a && !in_b,
+            Self::Intersection => in_a && in_b,
+        }
+    }
+}
+
+/// What happens to a face fragment in the result of an operation
+enum Selection {
+    Discard,
+    Keep,
+    KeepReversed,
+}
+
+/// Identifies an operand of a boolean operation
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Operand {
+    A,
+    B,
+}
+
+/// A face that is bounded by straight edges and lies in a plane
+struct PlanarFace<'r> {
+    face: &'r Face,
+    plane: Plane,
+
+    /// The cycles of the face, as polygons in surface coordinates
+    cycles: Vec<Vec<Point<2>>>,
+}
+
+impl<'r> PlanarFace<'r> {
+    fn new(face: &'r Face) -> Self {
+        let plane = match face.surface().geometry() {
+            SurfaceGeometry::SweptCurve(SweptCurve {
+                u: GlobalPath::Line(line),
+                v,
+            }) => Plane::from_parametric(line.origin(), line.direction(), *v),
+            _ => todo!(
+                "Boolean operations only support faces on planar surfaces"
+            ),
+        };
+
+        let cycles = face
+            .all_cycles()
+            .map(|cycle| {
+                cycle
+                    .half_edges()
+                    .map(|half_edge| {
+                        if !matches!(
+                            half_edge.curve().path(),
+                            SurfacePath::Line(_)
+                        ) {
+                            todo!(
+                                "Boolean operations only support faces with \
+                                straight edges"
+                            );
+                        }
+
+                        let [vertex, _] = half_edge.vertices();
+                        vertex.surface_form().position()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            face,
+            plane,
+            cycles,
+        }
+    }
+
+    /// Compute the bounding box of the face
+    ///
+    /// The bounding box is enlarged by [`EPSILON`], so faces that are close
+    /// enough to be considered touching have intersecting bounding boxes.
+    fn aabb(&self) -> Aabb<3> {
+        aabb_around(
+            self.cycles.iter().flatten().map(|&point| {
+                self.face.surface().point_from_surface_coords(point)
+            }),
+        )
+    }
+
+    /// Compute the normal of the face's front side
+    fn normal(&self) -> Vector<3> {
+        match self.face.coord_handedness() {
+            Handedness::RightHanded => self.plane.normal(),
+            Handedness::LeftHanded => -self.plane.normal(),
+        }
+    }
+
+    /// Iterate over the edges of the face, as line segments
+    fn segments(&self) -> impl Iterator<Item = [Point<2>; 2]> + '_ {
+        self.cycles.iter().flat_map(|cycle| {
+            cycle
+                .iter()
+                .zip(cycle.iter().cycle().skip(1))
+                .map(|(&a, &b)| [a, b])
+        })
+    }
+
+    /// Determine the line in which the plane of the face intersects another
+    ///
+    /// Returns `None`, if the planes are parallel.
+    fn intersection_line(&self, other: &Self) -> Option<Line<3>> {
+        let (distance_a, normal_a) = self.plane.constant_normal_form();
+        let (distance_b, normal_b) = other.plane.constant_normal_form();
+
+        let direction = normal_a.cross(&normal_b);
+        let denominator = direction.dot(&direction);
+        if denominator < Scalar::from_f64(EPSILON) {
+            return None;
+        }
+
+        let origin = (normal_b * distance_a - normal_a * distance_b)
+            .cross(&direction)
+            / denominator;
+
+        Some(Line::from_origin_and_direction(
+            Point { coords: origin },
+            direction,
+        ))
+    }
+
+    /// Determine whether the face contains a point in surface coordinates
+    ///
+    /// Points on the boundary of the face may or may not be considered to be
+    /// contained.
+    fn contains(&self, point: Point<2>) -> bool {
+        let mut inside = false;
+
+        for cycle in &self.cycles {
+            if contains(cycle, point) {
+                inside = !inside;
+            }
+        }
+
+        inside
+    }
+
+    /// Compute the distance of a point from the plane of the face
+    fn distance_to(&self, point: Point<3>) -> Scalar {
+        let (distance, normal) = self.plane.constant_normal_form();
+        (normal.dot(&point.coords) - distance).abs()
+    }
+}
+
+/// Merge the shells of a solid into a single shell
+///
+/// The result is not a valid shell, if the solid has multiple shells. For
+/// the purposes of the boolean operations, which only care about the
+/// boundary of each operand, that doesn't matter.
+fn merge_shells(solid: Solid) -> Shell {
+    Shell::new().with_faces(solid.into_shells().flat_map(Shell::into_faces))
+}
+
+/// Compute a bounding box around points, that is enlarged by [`EPSILON`]
+fn aabb_around(points: impl IntoIterator<Item = Point<3>>) -> Aabb<3> {
+    let aabb = Aabb::<3>::from_points(points);
+    let margin = Vector::from([EPSILON; 3]);
+
+    Aabb {
+        min: aabb.min - margin,
+        max: aabb.max + margin,
+    }
+}
+
+/// The tolerance used for geometric comparisons within this module
+const EPSILON: f64 = 1e-9;
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Aabb;
+
+    use crate::{
+        algorithms::{
+            test_helpers::{bounding_volume, closed_shell, cuboid, square},
+            validate::Validate,
+        },
+        objects::{Objects, Shell, Sketch},
+    };
+
+    use super::{Difference, Intersection, Union};
+
+    #[test]
+    fn union_of_overlapping_cubes() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = cuboid([1., 1., 1.], [0., 0., 0.], &objects);
+        let b = cuboid([1., 1., 1.], [0.5, 0.5, 0.5], &objects);
+
+        let union = a.union(b, &objects);
+        let union = closed_shell(union, &objects)?;
+
+        // Each cube keeps all of its faces, but those that reach into the
+        // other cube are cut back.
+        assert_eq!(union.faces().into_iter().count(), 12);
+        assert_eq!(
+            bounding_volume(&union),
+            Aabb::<3>::from_points([[0., 0., 0.], [1.5, 1.5, 1.5]])
+        );
+
+        union.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn union_of_touching_cubes() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = cuboid([1., 1., 1.], [0., 0., 0.], &objects);
+        let b = cuboid([1., 1., 1.], [1., 0., 0.], &objects);
+
+        let union = a.union(b, &objects);
+        let union = closed_shell(union, &objects)?;
+
+        // The faces where the cubes touch end up inside of the result.
+        assert_eq!(union.faces().into_iter().count(), 10);
+        assert_eq!(
+            bounding_volume(&union),
+            Aabb::<3>::from_points([[0., 0., 0.], [2., 1., 1.]])
+        );
+
+        union.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn union_of_identical_cubes() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = cuboid([1., 1., 1.], [0., 0., 0.], &objects);
+        let b = a.clone();
+
+        let union = a.clone().union(b, &objects);
+        let union = closed_shell(union, &objects)?;
+
+        // Coincident faces must only be kept once.
+        assert_eq!(union.faces().into_iter().count(), 6);
+        assert_eq!(bounding_volume(&union), bounding_volume(&a));
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_of_overlapping_cubes() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = cuboid([1., 1., 1.], [0., 0., 0.], &objects);
+        let b = cuboid([1., 1., 1.], [0.5, 0.5, 0.5], &objects);
+
+        let difference = a.difference(b, &objects);
+        let difference = closed_shell(difference, &objects)?;
+
+        // A corner is cut out of the first cube, bounded by three faces of the
+        // second.
+        assert_eq!(difference.faces().into_iter().count(), 9);
+        assert_eq!(
+            bounding_volume(&difference),
+            Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]])
+        );
+
+        difference.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn difference_with_hole() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = cuboid([1., 1., 1.], [0., 0., 0.], &objects);
+        let b = cuboid([0.5, 0.5, 2.], [0.25, 0.25, -0.5], &objects);
+
+        let difference = a.difference(b, &objects);
+        let difference = closed_shell(difference, &objects)?;
+
+        // The top and bottom faces have a hole each, which is connected by the
+        // walls of the second cuboid.
+        let faces = difference.faces();
+        assert_eq!(faces.into_iter().count(), 10);
+        assert_eq!(
+            faces
+                .into_iter()
+                .filter(|face| face.interiors().count() == 1)
+                .count(),
+            2
+        );
+
+        difference.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn difference_of_identical_cubes() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = cuboid([1., 1., 1.], [0., 0., 0.], &objects);
+        let b = a.clone();
+
+        let difference = a.difference(b, &objects);
+        assert_eq!(difference.shells().flat_map(Shell::faces).count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersection_of_overlapping_cubes() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = cuboid([1., 1., 1.], [0., 0., 0.], &objects);
+        let b = cuboid([1., 1., 1.], [0.5, 0.5, 0.5], &objects);
+
+        let intersection = a.intersection(b, &objects);
+        let intersection = closed_shell(intersection, &objects)?;
+
+        assert_eq!(intersection.faces().into_iter().count(), 6);
+        assert_eq!(
+            bounding_volume(&intersection),
+            Aabb::<3>::from_points([[0.5, 0.5, 0.5], [1., 1., 1.]])
+        );
+
+        intersection.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn union_of_overlapping_squares() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = square([0., 0.], 1., &objects);
+        let b = square([0.5, 0.5], 1., &objects);
+
+        let union = a.union(b, &objects);
+
+        // The regions are merged into a single face.
+        assert_eq!(num_edges(&union), [8]);
+
+        union.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn union_of_touching_squares() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = square([0., 0.], 1., &objects);
+        let b = square([1., 0.], 1., &objects);
+
+        let union = a.union(b, &objects);
+        assert_eq!(num_edges(&union), [6]);
+
+        union.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn union_of_disjoint_squares() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = square([0., 0.], 1., &objects);
+        let b = square([2., 0.], 1., &objects);
+
+        let union = a.union(b, &objects);
+        assert_eq!(num_edges(&union), [4, 4]);
+
+        union.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn intersection_of_overlapping_squares() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = square([0., 0.], 1., &objects);
+        let b = square([0.5, 0.5], 1., &objects);
+
+        let intersection = a.intersection(b, &objects);
+        assert_eq!(num_edges(&intersection), [4]);
+        assert_eq!(
+            bounding_volume(&intersection),
+            Aabb::<3>::from_points([[0.5, 0.5, 0.], [1., 1., 0.]])
+        );
+
+        intersection.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn intersection_of_disjoint_squares() {
+        let objects = Objects::new();
+
+        let a = square([0., 0.], 1., &objects);
+        let b = square([2., 0.], 1., &objects);
+
+        let intersection = a.intersection(b, &objects);
+        assert!(num_edges(&intersection).is_empty());
+    }
+
+    /// Count the edges of each face of the sketch
+    fn num_edges(sketch: &Sketch) -> Vec<usize> {
+        sketch
+            .faces()
+            .into_iter()
+            .map(|face| {
+                assert_eq!(face.interiors().count(), 0);
+                face.exterior().half_edges().count()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,90 @@
+//! Boolean operations on sketches
+
+use std::{collections::BTreeMap, iter};
+
+use crate::objects::{Handedness, Objects, Sketch};
+
+use super::{
+    arrangement::Arrangement, assemble::Assembler, Operation, PlanarFace,
+};
+
+/// Apply a boolean operation to two sketches
+///
+/// # Panics
+///
+/// Panics, if the faces of the sketches are not all defined in the same
+/// surface, or if any of them has edges that are not straight.
+pub fn apply(
+    operation: Operation,
+    a: &Sketch,
+    b: &Sketch,
+    objects: &Objects,
+) -> Sketch {
+    let [a, b] = [a, b].map(|sketch| {
+        sketch
+            .faces()
+            .into_iter()
+            .map(PlanarFace::new)
+            .collect::<Vec<_>>()
+    });
+
+    // The faces of the result take their surface and color from the first
+    // face of the operands.
+    let template = match a.iter().chain(&b).next() {
+        Some(face) => face,
+        None => return Sketch::new(),
+    };
+    for face in a.iter().chain(&b) {
+        assert_eq!(
+            template.face.surface(),
+            face.face.surface(),
+            "Boolean operations on sketches require faces in the same surface"
+        );
+    }
+
+    let contains = |point| {
+        let [in_a, in_b] =
+            [&a, &b].map(|faces| faces.iter().any(|face| face.contains(point)));
+        operation.contains(in_a, in_b)
+    };
+
+    // Split both sketches into regions, wherever their edges cross, then
+    // select the regions that are part of the result.
+    let segments = a.iter().chain(&b).flat_map(PlanarFace::segments);
+    let regions = Arrangement::new(segments)
+        .regions()
+        .into_iter()
+        .filter(|region| contains(region.sample_point()));
+
+    // Edges that are shared by two selected regions are on the inside of the
+    // result. Only the other ones bound it.
+    let mut edges = BTreeMap::new();
+    for region in regions {
+        for cycle in iter::once(&region.exterior).chain(&region.interiors) {
+            for (&a, &b) in cycle.iter().zip(cycle.iter().cycle().skip(1)) {
+                let edge = if a < b { [a, b] } else { [b, a] };
+                *edges.entry(edge).or_insert(0) += 1;
+            }
+        }
+    }
+    let boundary = edges
+        .into_iter()
+        .filter(|&(_, num_regions)| num_regions == 1)
+        .map(|(edge, _)| edge);
+
+    let mut assembler = Assembler::new();
+    for region in Arrangement::new(boundary).regions() {
+        if !contains(region.sample_point()) {
+            continue;
+        }
+
+        let region = match template.face.coord_handedness() {
+            Handedness::RightHanded => region,
+            Handedness::LeftHanded => region.reverse(),
+        };
+
+        assembler.add(template, region);
+    }
+
+    Sketch::new().with_faces(assembler.build(objects).into_faces())
+}
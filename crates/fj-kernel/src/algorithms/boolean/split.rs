@@ -0,0 +1,127 @@
+//! Splitting of faces along their intersections with a shell
+
+use fj_math::{Bvh, Line, Point, Scalar};
+
+use crate::objects::Handedness;
+
+use super::{
+    arrangement::{Arrangement, Region},
+    PlanarFace, EPSILON,
+};
+
+/// Split a face along its intersections with the faces of a shell
+///
+/// Only the faces of the shell whose bounding boxes intersect that of the face
+/// are considered, as only those can intersect the face.
+///
+/// Returns the regions of the face that are bounded by its own edges and its
+/// intersections with `shell`, in the surface coordinates of the face. The
+/// boundaries of each region are wound like those of the face.
+pub fn split_face(face: &PlanarFace, shell: &Bvh<&PlanarFace>) -> Vec<Region> {
+    let mut segments = face.segments().collect::<Vec<_>>();
+
+    for &other in shell.query_aabb(&face.aabb()) {
+        match face.intersection_line(other) {
+            Some(line) => {
+                let [line_face, line_other] = [face, other].map(|face| {
+                    let [a, b] =
+                        [line.origin(), line.origin() + line.direction()].map(
+                            |point| face.plane.point_to_plane_coords(point),
+                        );
+                    Line::from_origin_and_direction(a, b - a)
+                });
+
+                let intervals = intersect_intervals(
+                    &intervals_within(face, &line_face),
+                    &intervals_within(other, &line_other),
+                );
+
+                segments.extend(intervals.into_iter().map(|interval| {
+                    interval.map(|t| line_face.point_from_line_coords([t]))
+                }));
+            }
+            None => {
+                let is_coplanar = other.distance_to(face.plane.origin())
+                    < Scalar::from_f64(EPSILON);
+
+                if is_coplanar {
+                    // The edges of a coplanar face split this one, where both
+                    // overlap.
+                    segments.extend(other.segments().map(|segment| {
+                        segment.map(|point| {
+                            let point = other
+                                .face
+                                .surface()
+                                .point_from_surface_coords(point);
+                            face.plane.point_to_plane_coords(point)
+                        })
+                    }));
+                }
+            }
+        }
+    }
+
+    Arrangement::new(segments)
+        .regions()
+        .into_iter()
+        .filter(|region| face.contains(region.sample_point()))
+        .map(|region| match face.face.coord_handedness() {
+            Handedness::RightHanded => region,
+            Handedness::LeftHanded => region.reverse(),
+        })
+        .collect()
+}
+
+/// Compute the intervals in which a line lies within a face
+///
+/// The intervals are returned in line coordinates, sorted.
+fn intervals_within(face: &PlanarFace, line: &Line<2>) -> Vec<[Scalar; 2]> {
+    let normal = [-line.direction().v, line.direction().u];
+    let side = |point: Point<2>| {
+        let [u, v] = (point - line.origin()).components;
+        u * normal[0] + v * normal[1]
+    };
+
+    // Find the points where the line crosses the boundary of the face. Points
+    // that lie exactly on the line are treated as being on one side of it
+    // consistently, which guarantees an even number of crossings.
+    let mut crossings = face
+        .segments()
+        .filter_map(|[a, b]| {
+            let [side_a, side_b] = [a, b].map(side);
+            if (side_a >= Scalar::ZERO) == (side_b >= Scalar::ZERO) {
+                return None;
+            }
+
+            let point = a + (b - a) * (side_a / (side_a - side_b));
+            Some(line.point_to_line_coords(point).t)
+        })
+        .collect::<Vec<_>>();
+    crossings.sort();
+
+    crossings
+        .chunks_exact(2)
+        .map(|interval| [interval[0], interval[1]])
+        .collect()
+}
+
+/// Compute the intersections of two lists of intervals
+fn intersect_intervals(
+    a: &[[Scalar; 2]],
+    b: &[[Scalar; 2]],
+) -> Vec<[Scalar; 2]> {
+    let mut intersections = Vec::new();
+
+    for &[a_start, a_end] in a {
+        for &[b_start, b_end] in b {
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+
+            if start < end {
+                intersections.push([start, end]);
+            }
+        }
+    }
+
+    intersections
+}
@@ -0,0 +1,158 @@
+//! Bounding volume hierarchies over the faces and edges of shapes
+//!
+//! See [`Bvh`] for the queries that a hierarchy supports.
+
+use fj_math::{Aabb, Bvh, Point, Vector};
+
+use crate::{
+    iter::ObjectIters,
+    objects::{Face, HalfEdge},
+};
+
+use super::approx::{Approx, Tolerance};
+
+/// Compute the bounding box of an object
+pub trait BoundingVolume {
+    /// Compute a bounding box that contains the object
+    ///
+    /// The bounding box is computed from an approximation of the object, and is
+    /// enlarged by `tolerance`, to make up for any deviation of the
+    /// approximation from the object.
+    fn aabb(self, tolerance: impl Into<Tolerance>) -> Aabb<3>;
+}
+
+impl BoundingVolume for &Face {
+    fn aabb(self, tolerance: impl Into<Tolerance>) -> Aabb<3> {
+        let tolerance = tolerance.into();
+        let approx = self.approx(tolerance);
+
+        let points = approx
+            .points()
+            .into_iter()
+            .chain(approx.surface_points)
+            .map(|point| point.global_form);
+
+        aabb_from_points(points, tolerance)
+    }
+}
+
+impl BoundingVolume for &HalfEdge {
+    fn aabb(self, tolerance: impl Into<Tolerance>) -> Aabb<3> {
+        let tolerance = tolerance.into();
+        let [_, last] = self.vertices();
+
+        let points = self
+            .approx(tolerance)
+            .points()
+            .into_iter()
+            .map(|point| point.global_form)
+            .chain([last.global_form().position()]);
+
+        aabb_from_points(points, tolerance)
+    }
+}
+
+/// Build a BVH over all faces of a shape
+pub fn face_bvh<'r>(
+    shape: &'r impl ObjectIters<'r>,
+    tolerance: impl Into<Tolerance>,
+) -> Bvh<&'r Face> {
+    let tolerance = tolerance.into();
+    Bvh::new(shape.face_iter().map(|face| (face.aabb(tolerance), face)))
+}
+
+/// Build a BVH over all half-edges of a shape
+pub fn half_edge_bvh<'r>(
+    shape: &'r impl ObjectIters<'r>,
+    tolerance: impl Into<Tolerance>,
+) -> Bvh<&'r HalfEdge> {
+    let tolerance = tolerance.into();
+    Bvh::new(
+        shape
+            .half_edge_iter()
+            .map(|half_edge| (half_edge.aabb(tolerance), half_edge)),
+    )
+}
+
+fn aabb_from_points(
+    points: impl IntoIterator<Item = Point<3>>,
+    tolerance: Tolerance,
+) -> Aabb<3> {
+    let aabb = Aabb::<3>::from_points(points);
+    let margin = Vector::from([tolerance.inner(); 3]);
+
+    Aabb {
+        min: aabb.min - margin,
+        max: aabb.max + margin,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{approx::Tolerance, test_helpers::cube},
+        objects::{HalfEdge, Objects, Surface},
+        partial::HasPartial,
+    };
+
+    use super::{face_bvh, half_edge_bvh, BoundingVolume};
+
+    #[test]
+    fn aabb_of_curved_edge() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let circle = HalfEdge::partial()
+            .with_surface(Some(surface))
+            .as_circle_from_radius(1.)?
+            .build(&objects)?;
+
+        // The circle has only a single vertex, so its bounding box must come
+        // from its approximation.
+        let aabb = circle.aabb(tolerance);
+
+        let expected = Aabb::<3>::from_points([[-1., -1., 0.], [1., 1., 0.]]);
+        for (actual, expected) in
+            [(aabb.min, expected.min), (aabb.max, expected.max)]
+        {
+            assert!(
+                (actual - expected).magnitude() < Scalar::from_f64(0.01),
+                "{actual:?} is not close to {expected:?}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn query_faces_and_edges() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cube = cube(1., &objects);
+
+        let faces = face_bvh(&cube, tolerance);
+        assert_eq!(faces.len(), 6);
+
+        // A ray from above passes through the top and bottom faces, but misses
+        // the side walls.
+        let hits = faces.query_ray(
+            Point::from([0.5, 0.5, 2.]),
+            Vector::from([0., 0., -1.]),
+        );
+        assert_eq!(hits.len(), 2);
+
+        // A small box around the corner at the origin only touches the edges
+        // there. Each of those is part of two faces.
+        let half_edges = half_edge_bvh(&cube, tolerance);
+        let corner =
+            Aabb::<3>::from_points([[-0.1, -0.1, -0.1], [0.1, 0.1, 0.1]]);
+        let hits = half_edges.query_aabb(&corner);
+        assert_eq!(hits.len(), 6);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,331 @@
+//! Chamfering of edges
+//!
+//! A chamfer replaces an edge between two planar faces with a planar bevel
+//! face. It is computed by cutting a prism along the edge from the solid (or
+//! adding one, if the edge is concave). The boolean operations take care of
+//! trimming the neighboring faces back to the bevel.
+//!
+//! # Implementation Note
+//!
+//! Only edges between planar faces are supported. Like the boolean operations
+//! this builds upon, this requires the solid to be bounded by planar faces with
+//! straight edges.
+//!
+//! The prism that cuts a convex edge extends a bit beyond its ends. If the
+//! faces at the ends of the edge are slanted by more than 45 degrees, the prism
+//! might cut into them further than expected.
+
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{
+        boolean::{Difference, Union},
+        sweep::Sweep,
+    },
+    geometry::{SurfaceGeometry, SweptCurve},
+    objects::{Face, HalfEdge, Handedness, Objects, Sketch, Solid, Surface},
+    path::GlobalPath,
+};
+
+/// Chamfer the edges of a shape
+pub trait Chamfer {
+    /// Chamfer the provided edges
+    ///
+    /// Each edge is identified by one of the half-edges that make it up. The
+    /// face that this half-edge is part of is the "first" face of the edge,
+    /// which [`ChamferSize`] refers to.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if an edge is not part of the shape, or if the faces it
+    /// connects are coplanar.
+    #[must_use]
+    fn chamfer(
+        self,
+        edges: &[HalfEdge],
+        size: ChamferSize,
+        objects: &Objects,
+    ) -> Self;
+}
+
+/// The size of a chamfer
+#[derive(Clone, Copy, Debug)]
+pub enum ChamferSize {
+    /// The bevel is at the same distance from the edge on both faces
+    Distance(Scalar),
+
+    /// The bevel is at a distance from the edge on the first face, and meets
+    /// that face at an angle (in radians)
+    DistanceAndAngle {
+        /// The distance of the bevel from the edge on the first face
+        distance: Scalar,
+
+        /// The angle between the first face and the bevel
+        angle: Scalar,
+    },
+}
+
+impl Chamfer for Solid {
+    fn chamfer(
+        self,
+        edges: &[HalfEdge],
+        size: ChamferSize,
+        objects: &Objects,
+    ) -> Self {
+        // The prisms are computed up front, as the half-edges are no longer
+        // part of the solid, once the first one has been applied.
+        let prisms = edges
+            .iter()
+            .map(|half_edge| Prism::new(&self, half_edge, size, objects))
+            .collect::<Vec<_>>();
+
+        prisms.into_iter().fold(self, |solid, prism| {
+            if prism.is_convex {
+                solid.difference(prism.solid, objects)
+            } else {
+                solid.union(prism.solid, objects)
+            }
+        })
+    }
+}
+
+/// The prism that is removed from (or added to) a solid to chamfer an edge
+struct Prism {
+    solid: Solid,
+    is_convex: bool,
+}
+
+impl Prism {
+    fn new(
+        solid: &Solid,
+        half_edge: &HalfEdge,
+        size: ChamferSize,
+        objects: &Objects,
+    ) -> Self {
+        let faces = solid.shells().flat_map(|shell| shell.faces());
+
+        let mut first = None;
+        let mut second = None;
+        for face in faces {
+            for other in face.all_cycles().flat_map(|cycle| cycle.half_edges())
+            {
+                if other == half_edge {
+                    first = Some(face);
+                } else if other.global_form().vertices()
+                    == half_edge.global_form().vertices()
+                {
+                    second = Some(face);
+                }
+            }
+        }
+        let (first, second) = match (first, second) {
+            (Some(first), Some(second)) => (first, second),
+            _ => panic!("Edge to chamfer is not part of the solid"),
+        };
+
+        let [start, end] = half_edge
+            .vertices()
+            .clone()
+            .map(|vertex| vertex.global_form().position());
+        let length = (end - start).magnitude();
+        let direction = (end - start) / length;
+
+        // The directions from the edge into the faces, perpendicular to it.
+        // The half-edges of a face run counter-clockwise around its front
+        // side, and the other face's half-edge runs in the opposite direction.
+        let u = normal(first).cross(&direction).normalize();
+        let v = normal(second).cross(&-direction).normalize();
+
+        let dot = normal(first).dot(&v);
+        assert!(
+            dot.abs() > Scalar::from_f64(1e-9),
+            "Can't chamfer edge between coplanar faces"
+        );
+        let is_convex = dot < Scalar::ZERO;
+
+        // The triangle of the bevel, with its corner on the edge.
+        let [distance_u, distance_v] = match size {
+            ChamferSize::Distance(distance) => [distance, distance],
+            ChamferSize::DistanceAndAngle { distance, angle } => {
+                let corner = u.dot(&v).acos();
+                let (sin_angle, _) = angle.sin_cos();
+                let (sin_opposite, _) = (corner + angle).sin_cos();
+
+                assert!(
+                    sin_opposite > Scalar::ZERO,
+                    "Chamfer angle is too large for the edge"
+                );
+
+                [distance, distance * sin_angle / sin_opposite]
+            }
+        };
+
+        // A convex edge's prism extends beyond its ends, so it cuts through the
+        // neighboring faces cleanly, instead of being coplanar with them. The
+        // prism of a concave edge adds material, so it must not do that.
+        let margin = if is_convex {
+            distance_u.max(distance_v)
+        } else {
+            Scalar::ZERO
+        };
+
+        // The triangle that is swept contains the edge at its centroid. Its
+        // side between the faces becomes the bevel, the other two are outside
+        // of the solid (or inside of it, if the edge is concave).
+        let origin = start - direction * margin;
+        let surface = objects.surfaces.insert(Surface::plane_from_points([
+            origin,
+            origin + u,
+            origin + v,
+        ]));
+        let triangle: [Point<2>; 3] = [
+            [distance_u, Scalar::ZERO].into(),
+            [Scalar::ZERO, distance_v].into(),
+            [-distance_u, -distance_v].into(),
+        ];
+
+        let solid = Sketch::builder(objects, surface)
+            .build_polygon_from_points(triangle)
+            .expect("Failed to build chamfer prism")
+            .sweep(direction * (length + margin * 2.), objects);
+
+        Self { solid, is_convex }
+    }
+}
+
+/// Compute the normal of a planar face's front side
+fn normal(face: &Face) -> Vector<3> {
+    let normal = match face.surface().geometry() {
+        SurfaceGeometry::SweptCurve(SweptCurve {
+            u: GlobalPath::Line(line),
+            v,
+        }) => line.direction().cross(v).normalize(),
+        _ => todo!("Chamfering only supports edges between planar faces"),
+    };
+
+    match face.coord_handedness() {
+        Handedness::RightHanded => normal,
+        Handedness::LeftHanded => -normal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            sweep::Sweep,
+            test_helpers::{closed_shell, has_vertex},
+            validate::Validate,
+        },
+        iter::ObjectIters,
+        objects::{HalfEdge, Objects, Sketch, Solid, Surface},
+    };
+
+    use super::{Chamfer, ChamferSize};
+
+    #[test]
+    fn chamfer_convex_edge() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let solid = prism(&[[0., 0.], [1., 0.], [1., 1.], [0., 1.]], &objects);
+        let edge = find_edge(&solid, [[1., 0., 0.], [1., 1., 0.]]);
+
+        let solid = solid.chamfer(
+            &[edge],
+            ChamferSize::Distance(Scalar::from_f64(0.25)),
+            &objects,
+        );
+        let shell = closed_shell(solid, &objects)?;
+
+        // The edge is replaced by a bevel face.
+        assert_eq!(shell.faces().into_iter().count(), 7);
+        assert!(!has_vertex(&shell, [1., 0., 0.]));
+        assert!(has_vertex(&shell, [0.75, 0., 0.]));
+        assert!(has_vertex(&shell, [1., 1., 0.25]));
+
+        shell.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn chamfer_with_distance_and_angle() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let solid = prism(&[[0., 0.], [1., 0.], [1., 1.], [0., 1.]], &objects);
+        let edge = find_edge(&solid, [[1., 0., 0.], [1., 1., 0.]]);
+
+        // The half-edge is part of the face at `x == 1`. At an angle of
+        // `atan(2)` to that face, the bevel reaches twice as far into the
+        // bottom face.
+        let solid = solid.chamfer(
+            &[edge],
+            ChamferSize::DistanceAndAngle {
+                distance: Scalar::from_f64(0.25),
+                angle: Scalar::from_f64(2_f64.atan()),
+            },
+            &objects,
+        );
+        let shell = closed_shell(solid, &objects)?;
+
+        assert_eq!(shell.faces().into_iter().count(), 7);
+        assert!(has_vertex(&shell, [1., 0., 0.25]));
+        assert!(has_vertex(&shell, [0.5, 0., 0.]));
+
+        shell.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn chamfer_concave_edge() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let solid = prism(
+            &[[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]],
+            &objects,
+        );
+        let edge = find_edge(&solid, [[1., 1., 0.], [1., 1., 1.]]);
+
+        let solid = solid.chamfer(
+            &[edge],
+            ChamferSize::Distance(Scalar::from_f64(0.25)),
+            &objects,
+        );
+        let shell = closed_shell(solid, &objects)?;
+
+        // Material is added along the edge, instead of being removed. The
+        // number of faces is not checked, as the boolean operations don't
+        // merge coplanar faces, which leaves the top and bottom fragmented.
+        assert!(has_vertex(&shell, [1.25, 1., 0.]));
+        assert!(has_vertex(&shell, [1., 1.25, 1.]));
+
+        shell.validate()?;
+        Ok(())
+    }
+
+    fn prism(polygon: &[[f64; 2]], objects: &Objects) -> Solid {
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        Sketch::builder(objects, surface)
+            .build_polygon_from_points(polygon.iter().copied())
+            .expect("Failed to build polygon")
+            .sweep([0., 0., 1.], objects)
+    }
+
+    /// Find the half-edge that runs from `a` to `b`
+    fn find_edge(solid: &Solid, [a, b]: [[f64; 3]; 2]) -> HalfEdge {
+        let [a, b] = [a, b].map(Point::from);
+
+        solid
+            .half_edge_iter()
+            .find(|half_edge| {
+                let [start, end] = half_edge
+                    .vertices()
+                    .clone()
+                    .map(|vertex| vertex.global_form().position());
+                start == a && end == b
+            })
+            .expect("Expected solid to have half-edge")
+            .clone()
+    }
+}
@@ -0,0 +1,262 @@
+//! Collision detection between solids
+//!
+//! Checks whether two solids overlap, for example to detect interference
+//! between the parts of an assembly. This is much cheaper than computing the
+//! actual overlap using boolean operations.
+//!
+//! The check is done on the triangulated form of the solids. Solids that only
+//! touch each other are not considered to be overlapping. Solids with curved
+//! faces that are closer to each other than the tolerance that is used for the
+//! triangulation might or might not be considered to be overlapping.
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Aabb, Bvh, Point, Scalar, Triangle, Vector};
+
+use crate::objects::Solid;
+
+use super::{approx::Tolerance, triangulate::Triangulate};
+
+/// Check whether a tuple of objects overlap
+pub trait Collide {
+    /// Determine whether the objects overlap each other
+    fn collides(self, tolerance: impl Into<Tolerance>) -> bool;
+}
+
+impl Collide for (&Solid, &Solid) {
+    fn collides(self, tolerance: impl Into<Tolerance>) -> bool {
+        let (a, b) = self;
+        let tolerance = tolerance.into();
+
+        let [a, b] = [a, b].map(|solid| triangle_bvh(solid, tolerance));
+        match (a.aabb(), b.aabb()) {
+            (Some(aabb_a), Some(aabb_b)) if aabb_a.intersects(&aabb_b) => {}
+            _ => return false,
+        }
+
+        // Where the boundaries of the solids pass through each other, they
+        // overlap.
+        let boundaries_cross = a
+            .overlapping_pairs(&b)
+            .into_iter()
+            .any(|(&a, &b)| !triangle_triangle(a, b).is_empty());
+        if boundaries_cross {
+            return true;
+        }
+
+        // Otherwise, one solid could still be completely inside the other.
+        is_inside(&a, &b) || is_inside(&b, &a)
+    }
+}
+
+fn triangle_bvh(solid: &Solid, tolerance: Tolerance) -> Bvh<Triangle<3>> {
+    let mesh: Mesh<Point<3>> = (solid, tolerance).triangulate();
+    Bvh::new(mesh.triangles().map(|triangle| {
+        let triangle = triangle.inner;
+        (Aabb::<3>::from_points(triangle.points()), triangle)
+    }))
+}
+
+/// Determine whether the solid bounded by `a` is inside the one bounded by `b`
+///
+/// Must only be called, if the boundaries don't cross each other. Then it's
+/// enough to check a single point of `a`, as long as that point is not on the
+/// boundary of `b`.
+fn is_inside(a: &Bvh<Triangle<3>>, b: &Bvh<Triangle<3>>) -> bool {
+    let aabb = match b.aabb() {
+        Some(aabb) => aabb,
+        None => return false,
+    };
+    let ray_length = (aabb.max - aabb.min).magnitude() * 2.;
+
+    for triangle in a.items() {
+        let [p, q, r] = triangle.points();
+        let point = p + ((q - p) + (r - p)) / 3.;
+
+        let is_on_boundary = b
+            .query_aabb(&Aabb {
+                min: point,
+                max: point,
+            })
+            .into_iter()
+            .any(|&triangle| {
+                let [a, b, c] = triangle.points();
+                orient3d(a, b, c, point) == 0.
+            });
+        if is_on_boundary {
+            continue;
+        }
+
+        // Count how often a ray crosses the boundary of `b`. The direction is
+        // chosen pretty arbitrarily, to make it unlikely that the ray hits an
+        // edge or vertex of the triangulation.
+        let direction = Vector::from([0.5773, 0.5774, 0.5775]).normalize();
+        let far = point + direction * ray_length;
+
+        let num_hits = b
+            .query_aabb(&Aabb::<3>::from_points([point, far]))
+            .into_iter()
+            .filter(|&&triangle| {
+                segment_triangle([point, far], triangle).is_some()
+            })
+            .count();
+
+        return num_hits % 2 == 1;
+    }
+
+    // All of `a` is on the boundary of `b`. The solids are the same.
+    !a.is_empty()
+}
+
+/// Find the points where the edges of two triangles pass through the other
+///
+/// Triangles that share an edge, or that only touch each other, are not
+/// considered to be intersecting.
+///
+/// # Implementation Note
+///
+/// Coplanar triangles that overlap each other are not detected.
+pub(super) fn triangle_triangle(
+    a: Triangle<3>,
+    b: Triangle<3>,
+) -> Vec<Point<3>> {
+    let shared_points = a
+        .points()
+        .into_iter()
+        .filter(|point| b.points().contains(point))
+        .count();
+    if shared_points >= 2 {
+        return Vec::new();
+    }
+
+    let mut points = Vec::new();
+    for (triangle, other) in [(a, b), (b, a)] {
+        let [p, q, r] = triangle.points();
+        for segment in [[p, q], [q, r], [r, p]] {
+            points.extend(segment_triangle(segment, other));
+        }
+    }
+
+    points
+}
+
+/// Find the point where a segment passes through a triangle
+///
+/// Only considers segments whose end points are strictly on different sides of
+/// the triangle. If the segment only touches the triangle, that doesn't count.
+fn segment_triangle(
+    segment: [Point<3>; 2],
+    triangle: Triangle<3>,
+) -> Option<Point<3>> {
+    let [p, q] = segment;
+    let [a, b, c] = triangle.points();
+
+    let o_p = orient3d(a, b, c, p);
+    let o_q = orient3d(a, b, c, q);
+    if o_p * o_q >= 0. {
+        return None;
+    }
+
+    let sides = [[a, b], [b, c], [c, a]].map(|[x, y]| orient3d(p, q, x, y));
+    let is_inside = sides.iter().all(|&side| side >= 0.)
+        || sides.iter().all(|&side| side <= 0.);
+    if !is_inside {
+        return None;
+    }
+
+    let t = o_p / (o_p - o_q);
+    Some(p + (q - p) * t)
+}
+
+fn orient3d(a: Point<3>, b: Point<3>, c: Point<3>, d: Point<3>) -> f64 {
+    let [a, b, c, d] =
+        [a, b, c, d].map(|point| point.coords.components.map(Scalar::into_f64));
+    robust_predicates::orient3d(&a, &b, &c, &d)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::{
+            approx::Tolerance, sweep::Sweep, test_helpers::cube,
+            transform::TransformObject,
+        },
+        objects::{Cycle, Face, HalfEdge, Objects, Sketch, Surface},
+        partial::HasPartial,
+    };
+
+    use super::Collide;
+
+    #[test]
+    fn separate_and_touching() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let a = cube(1., &objects);
+        let b = cube(1., &objects).translate([2., 0., 0.], &objects)?;
+        assert!(!(&a, &b).collides(tolerance));
+
+        // Solids that share a face don't overlap.
+        let c = cube(1., &objects).translate([1., 0., 0.], &objects)?;
+        assert!(!(&a, &c).collides(tolerance));
+
+        Ok(())
+    }
+
+    #[test]
+    fn overlapping() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let a = cube(1., &objects);
+        let b = cube(1., &objects).translate([0.5, 0.5, 0.5], &objects)?;
+        assert!((&a, &b).collides(tolerance));
+        assert!((&b, &a).collides(tolerance));
+
+        Ok(())
+    }
+
+    #[test]
+    fn contained() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let outer = cube(4., &objects);
+        let inner = cube(1., &objects).translate([1., 1., 1.], &objects)?;
+        assert!((&outer, &inner).collides(tolerance));
+        assert!((&inner, &outer).collides(tolerance));
+
+        // A solid in the corner of another touches its boundary.
+        let corner = cube(1., &objects);
+        assert!((&outer, &corner).collides(tolerance));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cylinders() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cylinder = |objects: &Objects| -> anyhow::Result<_> {
+            let surface = objects.surfaces.insert(Surface::xy_plane());
+            let circle = HalfEdge::partial()
+                .with_surface(Some(surface.clone()))
+                .as_circle_from_radius(1.)?
+                .build(objects)?;
+            Ok(Sketch::new()
+                .with_faces([Face::from_exterior(Cycle::new(
+                    surface,
+                    [circle],
+                ))])
+                .sweep([0., 0., 1.], objects))
+        };
+
+        let a = cylinder(&objects)?;
+        let b = cylinder(&objects)?.translate([1.5, 0., 0.], &objects)?;
+        let c = cylinder(&objects)?.translate([2.5, 0., 0.], &objects)?;
+        assert!((&a, &b).collides(tolerance));
+        assert!(!(&a, &c).collides(tolerance));
+
+        Ok(())
+    }
+}
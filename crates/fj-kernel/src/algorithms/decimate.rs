@@ -0,0 +1,537 @@
+//! Simplification of triangle meshes
+//!
+//! Reduces the number of triangles in a mesh, for example to export a
+//! lightweight preview of a model. The mesh is simplified by repeatedly
+//! collapsing an edge into one of its vertices, always picking the collapse
+//! that introduces the least error, as measured by the quadric error metric of
+//! Garland and Heckbert.
+
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap},
+};
+
+use fj_interop::mesh::{Color, Mesh};
+use fj_math::{Point, Scalar, Vector};
+
+/// Configuration for [`decimate`]
+#[derive(Clone, Copy, Debug)]
+pub struct DecimationConfig {
+    /// The number of triangles to reduce the mesh to
+    ///
+    /// Decimation stops, once the mesh has no more triangles than this. Set
+    /// this to zero, to simplify the mesh as far as `max_error` allows.
+    pub target_triangles: usize,
+
+    /// The maximum distance by which the simplified mesh may deviate from the
+    /// original one
+    pub max_error: Scalar,
+
+    /// The angle between neighboring triangles, above which the edge between
+    /// them is considered a sharp feature
+    ///
+    /// The angle is in radians. Sharp feature edges, edges on the boundary of
+    /// the mesh, and edges between triangles of different colors are
+    /// preserved. Only vertices in the middle of a chain of such edges are
+    /// removed, and only within the bounds of `max_error`.
+    pub feature_angle: Scalar,
+}
+
+/// Simplify a triangle mesh
+///
+/// Returns a new mesh, whose vertices are a subset of the original mesh's
+/// vertices. The triangles keep their colors, and the attributes of their
+/// vertices.
+pub fn decimate(
+    mesh: &Mesh<Point<3>>,
+    config: &DecimationConfig,
+) -> Mesh<Point<3>> {
+    let mut decimation = Decimation::new(mesh, config.feature_angle);
+
+    let mut candidates = BinaryHeap::new();
+    for (u, v) in decimation.edges() {
+        let cost = decimation.cost(u, v);
+        candidates.push(Reverse((cost, u, v)));
+    }
+
+    while decimation.num_triangles > config.target_triangles {
+        let Reverse((cost, u, v)) = match candidates.pop() {
+            Some(candidate) => candidate,
+            None => break,
+        };
+
+        if !decimation.has_edge(u, v) {
+            continue;
+        }
+
+        // Costs only ever grow, as the quadrics accumulate more planes. If the
+        // cost is out of date, the collapse goes back in line.
+        let current_cost = decimation.cost(u, v);
+        if current_cost != cost {
+            candidates.push(Reverse((current_cost, u, v)));
+            continue;
+        }
+
+        // The cheapest collapse is too expensive, so all others are too.
+        if cost > config.max_error {
+            break;
+        }
+
+        if !decimation.can_collapse(u, v) {
+            continue;
+        }
+
+        decimation.collapse(u, v);
+
+        for w in decimation.neighbors(v) {
+            for (a, b) in [(v, w), (w, v)] {
+                candidates.push(Reverse((decimation.cost(a, b), a, b)));
+            }
+        }
+    }
+
+    decimation.into_mesh()
+}
+
+struct Decimation {
+    positions: Vec<Point<3>>,
+    quadrics: Vec<Quadric>,
+    triangles: Vec<Option<([usize; 3], Color)>>,
+    normals: Vec<[Vector<3>; 3]>,
+    uvs: Vec<Option<[Point<2>; 3]>>,
+    triangles_by_vertex: Vec<BTreeSet<usize>>,
+    feature_edges: BTreeSet<[usize; 2]>,
+    num_triangles: usize,
+}
+
+impl Decimation {
+    fn new(mesh: &Mesh<Point<3>>, feature_angle: Scalar) -> Self {
+        let mut indices = HashMap::new();
+        let mut positions = Vec::new();
+        let mut triangles = Vec::new();
+        let mut normals = Vec::new();
+        let mut uvs = Vec::new();
+
+        for triangle in mesh.triangles() {
+            let vertices = triangle.inner.points().map(|point| {
+                *indices.entry(point).or_insert_with(|| {
+                    positions.push(point);
+                    positions.len() - 1
+                })
+            });
+            triangles.push(Some((vertices, triangle.color)));
+            normals.push(triangle.normals);
+            uvs.push(triangle.uvs);
+        }
+
+        let mut triangles_by_vertex = vec![BTreeSet::new(); positions.len()];
+        let mut triangles_by_edge = BTreeMap::<_, Vec<_>>::new();
+        for (i, triangle) in triangles.iter().enumerate() {
+            let (vertices, _) = triangle.expect("No triangles removed yet");
+            for vertex in vertices {
+                triangles_by_vertex[vertex].insert(i);
+            }
+            for edge in edges_of(vertices) {
+                triangles_by_edge.entry(edge).or_default().push(i);
+            }
+        }
+
+        let mut decimation = Self {
+            quadrics: vec![Quadric::default(); positions.len()],
+            positions,
+            triangles,
+            normals,
+            uvs,
+            triangles_by_vertex,
+            feature_edges: BTreeSet::new(),
+            num_triangles: mesh.triangles().count(),
+        };
+
+        let min_cos = feature_angle.cos();
+        for (edge, neighbors) in triangles_by_edge {
+            let normals = neighbors
+                .iter()
+                .map(|&i| decimation.normal(i))
+                .collect::<Vec<_>>();
+            let colors = neighbors
+                .iter()
+                .map(|&i| decimation.triangle(i).1)
+                .collect::<Vec<_>>();
+
+            let is_feature_edge = match (normals.as_slice(), colors.as_slice())
+            {
+                ([a, b], [color_a, color_b]) => {
+                    a.dot(b) < min_cos || color_a != color_b
+                }
+                _ => true,
+            };
+
+            if is_feature_edge {
+                decimation.feature_edges.insert(edge);
+
+                // Moving a vertex away from a feature edge must be expensive,
+                // so the edge is represented by planes that contain it, and
+                // are perpendicular to the neighboring triangles.
+                let [a, b] = edge.map(|i| decimation.positions[i]);
+                for normal in normals {
+                    let normal = (b - a).cross(&normal).normalize();
+                    let quadric = Quadric::from_plane(a, normal);
+                    for i in edge {
+                        decimation.quadrics[i] += quadric;
+                    }
+                }
+            }
+        }
+
+        for i in 0..decimation.triangles.len() {
+            let (vertices, _) = decimation.triangle(i);
+            let quadric = Quadric::from_plane(
+                decimation.positions[vertices[0]],
+                decimation.normal(i),
+            );
+            for vertex in vertices {
+                decimation.quadrics[vertex] += quadric;
+            }
+        }
+
+        decimation
+    }
+
+    fn triangle(&self, i: usize) -> ([usize; 3], Color) {
+        self.triangles[i].expect("Accessing removed triangle")
+    }
+
+    fn normal(&self, i: usize) -> Vector<3> {
+        let (vertices, _) = self.triangle(i);
+        let [a, b, c] = vertices.map(|vertex| self.positions[vertex]);
+        (b - a).cross(&(c - a)).normalize()
+    }
+
+    /// Iterate over all edges, in both directions
+    fn edges(&self) -> Vec<(usize, usize)> {
+        (0..self.positions.len())
+            .flat_map(|u| self.neighbors(u).into_iter().map(move |v| (u, v)))
+            .collect()
+    }
+
+    fn neighbors(&self, vertex: usize) -> BTreeSet<usize> {
+        self.triangles_by_vertex[vertex]
+            .iter()
+            .flat_map(|&i| self.triangle(i).0)
+            .filter(|&other| other != vertex)
+            .collect()
+    }
+
+    fn has_edge(&self, u: usize, v: usize) -> bool {
+        self.triangles_by_vertex[u]
+            .iter()
+            .any(|&i| self.triangle(i).0.contains(&v))
+    }
+
+    fn feature_edges_at(&self, vertex: usize) -> Vec<[usize; 2]> {
+        self.neighbors(vertex)
+            .into_iter()
+            .map(|other| edge(vertex, other))
+            .filter(|edge| self.feature_edges.contains(edge))
+            .collect()
+    }
+
+    /// The error that collapsing `u` into `v` would introduce
+    fn cost(&self, u: usize, v: usize) -> Scalar {
+        let quadric = self.quadrics[u] + self.quadrics[v];
+        quadric.evaluate(self.positions[v]).max(Scalar::ZERO).sqrt()
+    }
+
+    /// Determine whether `u` can be collapsed into `v`
+    fn can_collapse(&self, u: usize, v: usize) -> bool {
+        // A vertex on a sharp feature can only move along it. Corners, where
+        // more than two feature edges meet, can't move at all.
+        let feature_edges = self.feature_edges_at(u);
+        let can_move = match feature_edges.as_slice() {
+            [] => true,
+            [a, b] => [a, b].contains(&&edge(u, v)),
+            _ => false,
+        };
+        if !can_move {
+            return false;
+        }
+
+        // The neighbors that `u` and `v` have in common must be the opposite
+        // vertices of the triangles that share their edge. Otherwise, the
+        // collapse would change the topology of the mesh.
+        let shared_triangles = self.triangles_by_vertex[u]
+            .intersection(&self.triangles_by_vertex[v])
+            .count();
+        let shared_neighbors =
+            self.neighbors(u).intersection(&self.neighbors(v)).count();
+        if shared_neighbors != shared_triangles {
+            return false;
+        }
+
+        // No remaining triangle may be flipped or become degenerate.
+        for &i in &self.triangles_by_vertex[u] {
+            let (vertices, _) = self.triangle(i);
+            if vertices.contains(&v) {
+                continue;
+            }
+
+            let [a, b, c] = vertices.map(|vertex| {
+                self.positions[if vertex == u { v } else { vertex }]
+            });
+            let normal = (b - a).cross(&(c - a));
+            if normal.dot(&self.normal(i)) <= Scalar::ZERO {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn collapse(&mut self, u: usize, v: usize) {
+        let (removed, moved): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.triangles_by_vertex[u])
+                .into_iter()
+                .partition(|&i| self.triangle(i).0.contains(&v));
+
+        // The triangles that are removed still know the normals and surface
+        // coordinates at `v`. Those are used for the triangles that move there.
+        let corners_at_v = removed
+            .iter()
+            .map(|&i| {
+                let (vertices, _) = self.triangle(i);
+                let corner = vertices
+                    .iter()
+                    .position(|&vertex| vertex == v)
+                    .expect("Triangle contains `v`");
+                let uv = self.uvs[i].map(|uvs| uvs[corner]);
+                (self.normals[i][corner], uv)
+            })
+            .collect::<Vec<_>>();
+
+        for i in removed {
+            let (vertices, _) = self.triangle(i);
+            for vertex in vertices {
+                self.triangles_by_vertex[vertex].remove(&i);
+            }
+            self.triangles[i] = None;
+            self.num_triangles -= 1;
+        }
+
+        for i in moved {
+            let (vertices, color) = self.triangle(i);
+
+            if let Some(corner) =
+                vertices.iter().position(|&vertex| vertex == u)
+            {
+                // Pick the corner from the same side of any hard edge.
+                let normal = self.normals[i][corner];
+                let closest = corners_at_v
+                    .iter()
+                    .max_by_key(|(other, _)| normal.dot(other));
+
+                if let Some(&(normal, uv)) = closest {
+                    self.normals[i][corner] = normal;
+                    if let (Some(uvs), Some(uv)) = (&mut self.uvs[i], uv) {
+                        uvs[corner] = uv;
+                    }
+                }
+            }
+
+            let vertices =
+                vertices.map(|vertex| if vertex == u { v } else { vertex });
+            self.triangles[i] = Some((vertices, color));
+            self.triangles_by_vertex[v].insert(i);
+        }
+
+        for [a, b] in self.feature_edges.clone() {
+            if a == u || b == u {
+                self.feature_edges.remove(&[a, b]);
+
+                let other = if a == u { b } else { a };
+                if other != v {
+                    self.feature_edges.insert(edge(v, other));
+                }
+            }
+        }
+
+        let quadric = self.quadrics[u];
+        self.quadrics[v] += quadric;
+    }
+
+    fn into_mesh(self) -> Mesh<Point<3>> {
+        let mut mesh = Mesh::new();
+
+        let attributes = self.normals.into_iter().zip(self.uvs);
+        for (triangle, (normals, uvs)) in
+            self.triangles.into_iter().zip(attributes)
+        {
+            if let Some((vertices, color)) = triangle {
+                let points = vertices.map(|vertex| self.positions[vertex]);
+                mesh.push_triangle_with_attributes(points, normals, uvs, color);
+            }
+        }
+
+        mesh
+    }
+}
+
+/// The sum of squared distances to a set of planes
+#[derive(Clone, Copy, Default)]
+struct Quadric {
+    // The upper triangle of the symmetric 4x4 matrix, row by row.
+    inner: [Scalar; 10],
+}
+
+impl Quadric {
+    fn from_plane(point: Point<3>, normal: Vector<3>) -> Self {
+        let [a, b, c] = normal.components;
+        let d = -normal.dot(&point.coords);
+
+        Self {
+            inner: [
+                a * a,
+                a * b,
+                a * c,
+                a * d,
+                b * b,
+                b * c,
+                b * d,
+                c * c,
+                c * d,
+                d * d,
+            ],
+        }
+    }
+
+    fn evaluate(&self, point: Point<3>) -> Scalar {
+        let [x, y, z] = point.coords.components;
+        let [aa, ab, ac, ad, bb, bc, bd, cc, cd, dd] = self.inner;
+
+        aa * x * x
+            + bb * y * y
+            + cc * z * z
+            + (ab * x * y + ac * x * z + bc * y * z) * 2.
+            + (ad * x + bd * y + cd * z) * 2.
+            + dd
+    }
+}
+
+impl std::ops::Add for Quadric {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self::Output {
+        self += other;
+        self
+    }
+}
+
+impl std::ops::AddAssign for Quadric {
+    fn add_assign(&mut self, other: Self) {
+        for (a, b) in self.inner.iter_mut().zip(other.inner) {
+            *a += b;
+        }
+    }
+}
+
+fn edge(a: usize, b: usize) -> [usize; 2] {
+    if a < b {
+        [a, b]
+    } else {
+        [b, a]
+    }
+}
+
+fn edges_of([a, b, c]: [usize; 3]) -> [[usize; 2]; 3] {
+    [edge(a, b), edge(b, c), edge(c, a)]
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::{Color, Mesh};
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, measure::mass_properties, sweep::Sweep,
+            test_helpers::cube, triangulate::Triangulate,
+        },
+        objects::{Cycle, Face, HalfEdge, Objects, Surface},
+        partial::HasPartial,
+    };
+
+    use super::{decimate, DecimationConfig};
+
+    #[test]
+    fn flat_grid() {
+        let mut mesh = Mesh::new();
+        for i in 0..4 {
+            for j in 0..4 {
+                let [x, y] = [i, j].map(f64::from);
+                let a = Point::from([x, y, 0.]);
+                let b = Point::from([x + 1., y, 0.]);
+                let c = Point::from([x + 1., y + 1., 0.]);
+                let d = Point::from([x, y + 1., 0.]);
+
+                mesh.push_triangle([a, b, c], Color::default());
+                mesh.push_triangle([a, c, d], Color::default());
+            }
+        }
+
+        let decimated = decimate(&mesh, &config(0, 1e-9));
+
+        // All vertices but the corners can be removed without any error.
+        assert_eq!(decimated.triangles().count(), 2);
+        assert_eq!(decimated.vertices().count(), 4);
+    }
+
+    #[test]
+    fn cube_is_preserved() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let cube = cube(1., &objects);
+        let mesh = (&cube, Tolerance::from_scalar(0.01)?).triangulate();
+
+        // All vertices are corners, so nothing can be removed, no matter how
+        // large the error is allowed to be.
+        let decimated = decimate(&mesh, &config(0, f64::MAX));
+        assert_eq!(decimated.triangles().count(), 12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cylinder_to_target() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let circle = HalfEdge::partial()
+            .with_surface(Some(surface.clone()))
+            .as_circle_from_radius(1.)?
+            .build(&objects)?;
+        let cylinder = Face::from_exterior(Cycle::new(surface, [circle]))
+            .sweep([0., 0., 1.], &objects);
+        let mesh = (&cylinder, Tolerance::from_scalar(0.0001)?).triangulate();
+        assert!(mesh.triangles().count() > 200);
+
+        let decimated = decimate(&mesh, &config(100, f64::MAX));
+        assert!(decimated.triangles().count() <= 100);
+
+        // The rims are sharp features, so the vertices stay on them. The
+        // volume shrinks, but doesn't collapse.
+        for vertex in decimated.vertices() {
+            assert!(vertex.z == Scalar::ZERO || vertex.z == Scalar::ONE);
+        }
+        let volume = mass_properties(&decimated).volume;
+        assert!(volume > Scalar::from_f64(2.5) && volume < Scalar::PI);
+
+        Ok(())
+    }
+
+    fn config(target_triangles: usize, max_error: f64) -> DecimationConfig {
+        DecimationConfig {
+            target_triangles,
+            max_error: Scalar::from_f64(max_error),
+            feature_angle: Scalar::from_f64(30_f64.to_radians()),
+        }
+    }
+}
@@ -0,0 +1,388 @@
+//! Minimum distance between objects
+//!
+//! Computes the closest points between two objects, for example to check the
+//! clearance between parts of an assembly.
+//!
+//! # Implementation Note
+//!
+//! Only faces in planar surfaces with straight edges are supported so far.
+
+use fj_math::{Aabb, Bvh, Plane, Point, Scalar, Segment};
+
+use crate::{
+    algorithms::intersect::{solid_point::SolidPointIntersection, Intersect},
+    geometry::{SurfaceGeometry, SweptCurve},
+    iter::ObjectIters,
+    objects::{Face, HalfEdge, Solid},
+    path::{GlobalPath, SurfacePath},
+};
+
+/// Compute the minimum distance between a tuple of objects
+pub trait Distance {
+    /// Compute the closest points between the objects, and their distance
+    fn distance(self) -> ClosestPoints;
+}
+
+/// The closest points between two objects
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ClosestPoints {
+    /// The closest points, one on each object
+    pub points: [Point<3>; 2],
+
+    /// The distance between the closest points
+    pub distance: Scalar,
+}
+
+impl ClosestPoints {
+    fn new(points: [Point<3>; 2]) -> Self {
+        let [a, b] = points;
+        Self {
+            points,
+            distance: (b - a).magnitude(),
+        }
+    }
+
+    fn reverse(self) -> Self {
+        let [a, b] = self.points;
+        Self {
+            points: [b, a],
+            distance: self.distance,
+        }
+    }
+}
+
+impl Distance for (&Point<3>, &Face) {
+    fn distance(self) -> ClosestPoints {
+        let (point, face) = self;
+        PlanarFace::new(face).closest_to(*point)
+    }
+}
+
+impl Distance for (&HalfEdge, &HalfEdge) {
+    fn distance(self) -> ClosestPoints {
+        let (a, b) = self;
+        segment_segment(&segment_of(a), &segment_of(b))
+    }
+}
+
+impl Distance for (&Face, &Face) {
+    fn distance(self) -> ClosestPoints {
+        let (a, b) = self;
+        face_face(&PlanarFace::new(a), &PlanarFace::new(b))
+    }
+}
+
+impl Distance for (&Solid, &Solid) {
+    /// Compute the closest points between two solids
+    ///
+    /// If the solids overlap, the distance is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if either solid doesn't have any faces.
+    fn distance(self) -> ClosestPoints {
+        let (a, b) = self;
+
+        // If one solid contains a vertex of the other, they overlap. Otherwise,
+        // any overlap is detected by the faces intersecting each other.
+        for (solid, other) in [(a, b), (b, a)] {
+            for vertex in solid.global_vertex_iter() {
+                let position = vertex.position();
+                if let Some(SolidPointIntersection::PointIsInsideSolid) =
+                    (other, &position).intersect()
+                {
+                    return ClosestPoints::new([position, position]);
+                }
+            }
+        }
+
+        let [faces_a, faces_b] = [a, b].map(|solid| {
+            Bvh::new(
+                solid
+                    .shells()
+                    .flat_map(|shell| shell.faces().into_iter())
+                    .map(PlanarFace::new)
+                    .map(|face| (face.aabb(), face)),
+            )
+        });
+
+        faces_a
+            .closest_pair(&faces_b, |a, b| {
+                let closest = face_face(a, b);
+                (closest.distance, closest)
+            })
+            .expect("Can't compute distance to solid without faces")
+    }
+}
+
+/// A planar face, with its edges as segments in 3D
+struct PlanarFace<'r> {
+    face: &'r Face,
+    plane: Plane,
+    segments: Vec<Segment<3>>,
+}
+
+impl<'r> PlanarFace<'r> {
+    fn new(face: &'r Face) -> Self {
+        let plane = match face.surface().geometry() {
+            SurfaceGeometry::SweptCurve(SweptCurve {
+                u: GlobalPath::Line(line),
+                v,
+            }) => Plane::from_parametric(line.origin(), line.direction(), *v),
+            _ => todo!("Distance computation only supports planar faces"),
+        };
+
+        let segments = face
+            .all_cycles()
+            .flat_map(|cycle| cycle.half_edges())
+            .map(segment_of)
+            .collect();
+
+        Self {
+            face,
+            plane,
+            segments,
+        }
+    }
+
+    fn aabb(&self) -> Aabb<3> {
+        Aabb::<3>::from_points(
+            self.segments.iter().flat_map(|segment| segment.points()),
+        )
+    }
+
+    fn closest_to(&self, point: Point<3>) -> ClosestPoints {
+        let projected = self.plane.point_to_plane_coords(point);
+        if (self.face, &projected).intersect().is_some() {
+            let closest = self.plane.origin()
+                + self.plane.u() * projected.u
+                + self.plane.v() * projected.v;
+            return ClosestPoints::new([point, closest]);
+        }
+
+        // The point is not above the face, so the closest point is on its
+        // boundary.
+        self.segments
+            .iter()
+            .map(|segment| {
+                ClosestPoints::new([point, closest_on_segment(point, segment)])
+            })
+            .min_by_key(|closest| closest.distance)
+            .expect("Face has no edges")
+    }
+
+    /// Find a point where the segment passes through the face
+    fn crossing(&self, segment: &Segment<3>) -> Option<Point<3>> {
+        let (distance, normal) = self.plane.constant_normal_form();
+        let [a, b] = segment
+            .points()
+            .map(|point| normal.dot(&point.coords) - distance);
+
+        if a * b > Scalar::ZERO || a == b {
+            return None;
+        }
+
+        let [start, end] = segment.points();
+        let point = start + (end - start) * (a / (a - b));
+
+        let projected = self.plane.point_to_plane_coords(point);
+        (self.face, &projected).intersect().map(|_| point)
+    }
+}
+
+fn face_face(a: &PlanarFace, b: &PlanarFace) -> ClosestPoints {
+    // Where an edge of one face passes through the other, they intersect.
+    for segment in &a.segments {
+        if let Some(point) = b.crossing(segment) {
+            return ClosestPoints::new([point, point]);
+        }
+    }
+    for segment in &b.segments {
+        if let Some(point) = a.crossing(segment) {
+            return ClosestPoints::new([point, point]);
+        }
+    }
+
+    // Otherwise, the closest points are between a vertex and the other face,
+    // or between two edges.
+    let vertex_face = a
+        .segments
+        .iter()
+        .map(|segment| b.closest_to(segment.points()[0]))
+        .chain(
+            b.segments
+                .iter()
+                .map(|segment| a.closest_to(segment.points()[0]).reverse()),
+        );
+    let edge_edge = a
+        .segments
+        .iter()
+        .flat_map(|a| b.segments.iter().map(|b| segment_segment(a, b)));
+
+    vertex_face
+        .chain(edge_edge)
+        .min_by_key(|closest| closest.distance)
+        .expect("Face has no edges")
+}
+
+fn segment_of(half_edge: &HalfEdge) -> Segment<3> {
+    if !matches!(half_edge.curve().path(), SurfacePath::Line(_)) {
+        todo!("Distance computation only supports straight edges");
+    }
+
+    let [a, b] = half_edge.vertices();
+    Segment::from_points([a, b].map(|vertex| vertex.global_form().position()))
+}
+
+fn closest_on_segment(point: Point<3>, segment: &Segment<3>) -> Point<3> {
+    let [a, b] = segment.points();
+    let direction = b - a;
+
+    let t = (point - a).dot(&direction) / direction.dot(&direction);
+    a + direction * t.max(Scalar::ZERO).min(Scalar::ONE)
+}
+
+fn segment_segment(a: &Segment<3>, b: &Segment<3>) -> ClosestPoints {
+    // Algorithm from Real-Time Collision Detection by Christer Ericson. See
+    // section 5.1.9, Closest Points of Two Line Segments.
+    let [p1, q1] = a.points();
+    let [p2, q2] = b.points();
+
+    let d1 = q1 - p1;
+    let d2 = q2 - p2;
+    let r = p1 - p2;
+
+    let aa = d1.dot(&d1);
+    let ee = d2.dot(&d2);
+    let f = d2.dot(&r);
+    let c = d1.dot(&r);
+    let bb = d1.dot(&d2);
+
+    let clamp = |t: Scalar| t.max(Scalar::ZERO).min(Scalar::ONE);
+
+    // If the segments are parallel, any point on the first segment will do,
+    // so we start with its first point.
+    let denominator = aa * ee - bb * bb;
+    let s = if denominator > Scalar::ZERO {
+        clamp((bb * f - c * ee) / denominator)
+    } else {
+        Scalar::ZERO
+    };
+
+    let t = (bb * s + f) / ee;
+    let (s, t) = if t < Scalar::ZERO {
+        (clamp(-c / aa), Scalar::ZERO)
+    } else if t > Scalar::ONE {
+        (clamp((bb - c) / aa), Scalar::ONE)
+    } else {
+        (s, t)
+    };
+
+    ClosestPoints::new([p1 + d1 * s, p2 + d2 * t])
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            test_helpers::{cube, square_face},
+            transform::TransformObject,
+        },
+        objects::{Face, HalfEdge, Objects, Surface},
+        partial::HasPartial,
+    };
+
+    use super::{ClosestPoints, Distance};
+
+    #[test]
+    fn point_face() {
+        let objects = Objects::new();
+        let face = square_face([0., 0.], 1., &objects);
+
+        // Above the face, the closest point is right below.
+        let point = Point::from([0.5, 0.5, 2.]);
+        assert_eq!(
+            (&point, &face).distance(),
+            ClosestPoints {
+                points: [point, Point::from([0.5, 0.5, 0.])],
+                distance: Scalar::from_f64(2.),
+            }
+        );
+
+        // Next to the face, it's on the closest edge.
+        let point = Point::from([2., 0.5, 1.]);
+        assert_eq!(
+            (&point, &face).distance().points,
+            [point, Point::from([1., 0.5, 0.])],
+        );
+    }
+
+    #[test]
+    fn edge_edge() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let a = HalfEdge::partial()
+            .with_surface(Some(surface.clone()))
+            .as_line_segment_from_points([[-1., 0.], [1., 0.]])?
+            .build(&objects)?;
+        let b = HalfEdge::partial()
+            .with_surface(Some(surface))
+            .as_line_segment_from_points([[0., -1.], [0., 1.]])?
+            .build(&objects)?
+            .translate([2., 0., 1.], &objects)?;
+
+        // The second edge crosses over the first one's extension, so the
+        // closest point on the first one is its end.
+        assert_eq!(
+            (&a, &b).distance().points,
+            [Point::from([1., 0., 0.]), Point::from([2., 0., 1.])],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn face_face() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = square_face([0., 0.], 1., &objects);
+        let b = square_face([0., 0.], 1., &objects)
+            .translate([0.5, 0.5, 1.], &objects)?;
+        assert_eq!((&a, &b).distance().distance, Scalar::ONE);
+
+        // A face that passes through the other one.
+        let c = Face::builder(
+            &objects,
+            objects.surfaces.insert(Surface::xz_plane()),
+        )
+        .with_exterior_polygon_from_points([
+            [0.25, -1.],
+            [0.75, -1.],
+            [0.75, 1.],
+            [0.25, 1.],
+        ])
+        .build()?
+        .translate([0., 0.5, 0.], &objects)?;
+        assert_eq!((&a, &c).distance().distance, Scalar::ZERO);
+
+        Ok(())
+    }
+
+    #[test]
+    fn solid_solid() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = cube(1., &objects);
+        let b = cube(1., &objects).translate([2., 0.5, 0.], &objects)?;
+        assert_eq!((&a, &b).distance().distance, Scalar::ONE);
+
+        // Overlapping solids have no clearance.
+        let c = cube(1., &objects).translate([0.5, 0.5, 0.5], &objects)?;
+        assert_eq!((&a, &c).distance().distance, Scalar::ZERO);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,443 @@
+//! Healing of shells and solids
+//!
+//! Operations like booleans and imports can leave a shape with more faces than
+//! necessary. Where neighboring faces lie in the same plane and face in the
+//! same direction, they are merged into a single face, whose cycles are the
+//! combined boundary of the original faces. Vertices that end up in the middle
+//! of a straight edge are removed, unless another face still needs them.
+//!
+//! # Implementation Note
+//!
+//! Only faces on planar surfaces with straight edges are merged. Other faces
+//! are left as they are.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_math::{Plane, Point, Scalar, Vector};
+
+use crate::{
+    geometry::{SurfaceGeometry, SweptCurve},
+    objects::{Face, Handedness, Objects, Shell, Solid},
+    path::{GlobalPath, SurfacePath},
+};
+
+use super::boolean::assemble::Assembler;
+
+/// Heal an object
+pub trait Heal {
+    /// Merge neighboring faces that are coplanar
+    #[must_use]
+    fn heal(self, objects: &Objects) -> Self;
+}
+
+impl Heal for Shell {
+    fn heal(self, objects: &Objects) -> Self {
+        let faces = self.into_faces().into_iter().collect::<Vec<_>>();
+        let planar = faces.iter().map(PlanarFace::new).collect::<Vec<_>>();
+
+        // Faces that share an edge have that edge in opposite directions.
+        let mut face_of_edge = BTreeMap::new();
+        for (i, face) in planar.iter().enumerate() {
+            if let Some(face) = face {
+                for edge in face.edges() {
+                    face_of_edge.insert(edge, i);
+                }
+            }
+        }
+
+        let mut groups = Groups::new(faces.len());
+        for (i, face) in planar.iter().enumerate() {
+            let face = match face {
+                Some(face) => face,
+                None => continue,
+            };
+
+            for [a, b] in face.edges() {
+                if let Some(&j) = face_of_edge.get(&[b, a]) {
+                    let other = planar[j]
+                        .as_ref()
+                        .expect("Edge belongs to planar face");
+                    if face.can_merge_with(other) {
+                        groups.join(i, j);
+                    }
+                }
+            }
+        }
+
+        let mut merged = Vec::new();
+        let mut kept = Vec::new();
+        for group in groups.into_groups() {
+            let polygon = match group.as_slice() {
+                [_, _, ..] => merge(&group, &planar),
+                _ => None,
+            };
+            match polygon {
+                Some(polygon) => merged.push(polygon),
+                None => kept.extend(group),
+            }
+        }
+
+        // Points that are used by the faces that are kept, or that are corners
+        // of a merged face, are still needed. Others lie in the middle of
+        // straight edges, in all faces that use them.
+        let mut needed = kept
+            .iter()
+            .flat_map(|&i| faces[i].all_cycles())
+            .flat_map(|cycle| cycle.half_edges())
+            .flat_map(|half_edge| half_edge.vertices())
+            .map(|vertex| vertex.global_form().position())
+            .collect::<BTreeSet<_>>();
+        for polygon in &merged {
+            for cycle in &polygon.cycles {
+                needed.extend(corners(cycle));
+            }
+        }
+
+        let mut assembler = Assembler::new();
+        for polygon in merged {
+            let face = planar[polygon.face]
+                .as_ref()
+                .expect("Merged face is planar");
+            let cycles = polygon
+                .cycles
+                .into_iter()
+                .map(|cycle| {
+                    cycle
+                        .into_iter()
+                        .filter(|point| needed.contains(point))
+                        .collect()
+                })
+                .collect();
+
+            assembler.add_polygon(
+                face.face.surface().clone(),
+                face.plane,
+                face.face.color(),
+                cycles,
+            );
+        }
+
+        let mut faces = faces.into_iter().map(Some).collect::<Vec<_>>();
+        let kept = kept.into_iter().filter_map(|i| faces[i].take());
+        Shell::new()
+            .with_faces(kept.chain(assembler.build(objects).into_faces()))
+    }
+}
+
+impl Heal for Solid {
+    fn heal(self, objects: &Objects) -> Self {
+        Solid::new()
+            .with_shells(self.into_shells().map(|shell| shell.heal(objects)))
+    }
+}
+
+/// A face that lies in a plane and is bounded by straight edges
+struct PlanarFace<'r> {
+    face: &'r Face,
+    plane: Plane,
+
+    /// The normal of the face's front side
+    normal: Vector<3>,
+
+    /// The cycles of the face, as polygons in model space
+    cycles: Vec<Vec<Point<3>>>,
+}
+
+impl<'r> PlanarFace<'r> {
+    fn new(face: &'r Face) -> Option<Self> {
+        let plane = match face.surface().geometry() {
+            SurfaceGeometry::SweptCurve(SweptCurve {
+                u: GlobalPath::Line(line),
+                v,
+            }) => Plane::from_parametric(line.origin(), line.direction(), *v),
+            _ => return None,
+        };
+        let normal = match face.coord_handedness() {
+            Handedness::RightHanded => plane.normal(),
+            Handedness::LeftHanded => -plane.normal(),
+        };
+
+        let mut cycles = Vec::new();
+        for cycle in face.all_cycles() {
+            let mut points = Vec::new();
+            for half_edge in cycle.half_edges() {
+                if !matches!(half_edge.curve().path(), SurfacePath::Line(_)) {
+                    return None;
+                }
+
+                let [vertex, _] = half_edge.vertices();
+                points.push(vertex.global_form().position());
+            }
+            cycles.push(points);
+        }
+
+        Some(Self {
+            face,
+            plane,
+            normal,
+            cycles,
+        })
+    }
+
+    fn edges(&self) -> impl Iterator<Item = [Point<3>; 2]> + '_ {
+        self.cycles.iter().flat_map(|cycle| segments(cycle))
+    }
+
+    /// Determine whether the face can be merged with a neighbor
+    ///
+    /// That's the case, if both faces have the same color, and lie in the same
+    /// plane, facing the same direction.
+    fn can_merge_with(&self, other: &Self) -> bool {
+        let epsilon = Scalar::from_f64(EPSILON);
+
+        let (distance, normal) = self.plane.constant_normal_form();
+        let is_in_plane = other.cycles.iter().flatten().all(|point| {
+            (normal.dot(&point.coords) - distance).abs() < epsilon
+        });
+
+        self.face.color() == other.face.color()
+            && (self.normal - other.normal).magnitude() < epsilon
+            && is_in_plane
+    }
+}
+
+/// The combined boundary of a group of faces
+struct MergedPolygon {
+    /// The index of the face whose surface the merged face uses
+    face: usize,
+
+    /// The cycles of the merged face, starting with its exterior
+    cycles: Vec<Vec<Point<3>>>,
+}
+
+/// Merge a group of faces into a single polygon
+///
+/// Returns `None`, if the boundary of the group doesn't consist of a single
+/// exterior, plus any number of interiors.
+fn merge(
+    group: &[usize],
+    planar: &[Option<PlanarFace>],
+) -> Option<MergedPolygon> {
+    let faces = group
+        .iter()
+        .map(|&i| planar[i].as_ref().expect("Grouped face is planar"))
+        .collect::<Vec<_>>();
+    let normal = faces[0].normal;
+
+    // Edges that are shared between faces of the group are in the interior of
+    // the merged face. The remaining ones make up its boundary.
+    let edges = faces
+        .iter()
+        .flat_map(|face| face.edges())
+        .collect::<BTreeSet<_>>();
+    let mut next = BTreeMap::<_, Vec<_>>::new();
+    for &[a, b] in &edges {
+        if !edges.contains(&[b, a]) {
+            next.entry(a).or_default().push(b);
+        }
+    }
+
+    let mut exteriors = Vec::new();
+    let mut interiors = Vec::new();
+    while let Some(&start) = next.keys().next() {
+        let mut cycle = Vec::new();
+        let mut current = start;
+        loop {
+            cycle.push(current);
+
+            let points = next.get_mut(&current)?;
+            let point = points.pop()?;
+            if points.is_empty() {
+                next.remove(&current);
+            }
+
+            current = point;
+            if current == start {
+                break;
+            }
+        }
+
+        if signed_area(&cycle, normal) > Scalar::ZERO {
+            exteriors.push(cycle);
+        } else {
+            interiors.push(cycle);
+        }
+    }
+
+    if exteriors.len() != 1 {
+        return None;
+    }
+
+    Some(MergedPolygon {
+        face: group[0],
+        cycles: exteriors.into_iter().chain(interiors).collect(),
+    })
+}
+
+/// Groups of faces that are merged with each other
+///
+/// Implemented as a union-find structure over the indices of the faces.
+struct Groups {
+    parents: Vec<usize>,
+}
+
+impl Groups {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+        }
+    }
+
+    fn root(&mut self, mut i: usize) -> usize {
+        while self.parents[i] != i {
+            self.parents[i] = self.parents[self.parents[i]];
+            i = self.parents[i];
+        }
+        i
+    }
+
+    fn join(&mut self, a: usize, b: usize) {
+        let [a, b] = [a, b].map(|i| self.root(i));
+        self.parents[a.max(b)] = a.min(b);
+    }
+
+    fn into_groups(mut self) -> Vec<Vec<usize>> {
+        let mut groups = BTreeMap::<_, Vec<_>>::new();
+        for i in 0..self.parents.len() {
+            let root = self.root(i);
+            groups.entry(root).or_default().push(i);
+        }
+        groups.into_values().collect()
+    }
+}
+
+fn segments(cycle: &[Point<3>]) -> impl Iterator<Item = [Point<3>; 2]> + '_ {
+    cycle
+        .iter()
+        .zip(cycle.iter().cycle().skip(1))
+        .map(|(&a, &b)| [a, b])
+}
+
+/// Find the points of a cycle, where it doesn't continue in a straight line
+fn corners(cycle: &[Point<3>]) -> impl Iterator<Item = Point<3>> + '_ {
+    let previous = cycle.iter().cycle().skip(cycle.len() - 1);
+    let next = cycle.iter().cycle().skip(1);
+
+    cycle
+        .iter()
+        .zip(previous)
+        .zip(next)
+        .filter_map(|((&point, &a), &b)| {
+            let deviation =
+                (point - a).cross(&(b - a)).magnitude() / (b - a).magnitude();
+            (deviation >= Scalar::from_f64(EPSILON)).then_some(point)
+        })
+}
+
+/// Compute the area of a cycle, as seen from the side `normal` points to
+///
+/// The area is positive, if the cycle is counter-clockwise from that side.
+fn signed_area(cycle: &[Point<3>], normal: Vector<3>) -> Scalar {
+    let sum = segments(cycle)
+        .map(|[a, b]| a.coords.cross(&b.coords))
+        .fold(Vector::from([0., 0., 0.]), |sum, cross| sum + cross);
+    sum.dot(&normal) / 2.
+}
+
+/// The tolerance used for geometric comparisons within this module
+const EPSILON: f64 = 1e-9;
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Mesh;
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance,
+            boolean::Union,
+            measure::mass_properties,
+            test_helpers::{cube, cuboid},
+            triangulate::Triangulate,
+            validate::Validate,
+        },
+        objects::{Objects, Solid},
+    };
+
+    use super::Heal;
+
+    #[test]
+    fn touching_cubes() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let union = cube(1., &objects)
+            .union(cuboid([1.; 3], [1., 0., 0.], &objects), &objects)
+            .heal(&objects)
+            .validate()?
+            .into_inner();
+
+        // The faces of both cubes, that are in the same plane, are merged.
+        // Each of the resulting faces is a rectangle.
+        let faces = faces(&union);
+        assert_eq!(faces.len(), 6);
+        assert!(faces.iter().all(|&num_half_edges| num_half_edges == 4));
+        assert_volume(&union, 2.)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn l_shape() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let union = [[1., 0., 0.], [0., 1., 0.]]
+            .into_iter()
+            .fold(cube(1., &objects), |union, offset| {
+                union.union(cuboid([1.; 3], offset, &objects), &objects)
+            })
+            .heal(&objects)
+            .validate()?
+            .into_inner();
+
+        // Top and bottom are L-shaped, with 6 corners each. The vertices
+        // between the cubes are removed, as all faces that use them continue
+        // straight through them.
+        let mut faces = faces(&union);
+        faces.sort();
+        assert_eq!(faces, [4, 4, 4, 4, 4, 4, 6, 6]);
+        assert_volume(&union, 3.)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn cube_is_unchanged() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let cube = cube(1., &objects);
+        assert_eq!(cube.clone().heal(&objects), cube);
+
+        Ok(())
+    }
+
+    /// Count the half-edges of each face
+    fn faces(solid: &Solid) -> Vec<usize> {
+        solid
+            .shells()
+            .flat_map(|shell| shell.faces().into_iter())
+            .map(|face| {
+                face.all_cycles()
+                    .map(|cycle| cycle.half_edges().count())
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn assert_volume(solid: &Solid, expected: f64) -> anyhow::Result<()> {
+        let mesh: Mesh<Point<3>> =
+            (solid, Tolerance::from_scalar(0.001)?).triangulate();
+        let volume = mass_properties(&mesh).volume;
+        assert!((volume - expected).abs() < Scalar::from_f64(1e-12));
+        Ok(())
+    }
+}
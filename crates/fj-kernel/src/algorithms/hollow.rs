@@ -0,0 +1,222 @@
+//! Hollowing of solids
+//!
+//! Also known as the "shell" operation in other CAD applications. Every face
+//! of the solid is offset inwards by the wall thickness, to create the inner
+//! walls.
+//!
+//! # Implementation Note
+//!
+//! Only solids that are bounded by planar faces with straight edges are
+//! supported so far. Other solids will result in a panic.
+//!
+//! The topology of the offset faces is the same as that of the original ones.
+//! If the wall thickness is so large that faces would collapse or vertices
+//! would need to split, the result is invalid.
+
+use fj_math::Scalar;
+
+use crate::{
+    algorithms::{
+        boolean::assemble::Assembler,
+        offset::{offset_vertex, PolygonFace},
+    },
+    objects::{Face, Objects, Solid},
+};
+
+/// Hollow out a shape
+pub trait Hollow {
+    /// Hollow out the shape, leaving walls of the given thickness
+    ///
+    /// The walls are created on the inside of the shape. Faces that are
+    /// listed in `openings` are removed, leaving an opening into the cavity.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any face in `openings` has interiors.
+    #[must_use]
+    fn hollow(
+        self,
+        thickness: impl Into<Scalar>,
+        openings: &[Face],
+        objects: &Objects,
+    ) -> Self;
+}
+
+impl Hollow for Solid {
+    fn hollow(
+        self,
+        thickness: impl Into<Scalar>,
+        openings: &[Face],
+        objects: &Objects,
+    ) -> Self {
+        let thickness = thickness.into();
+
+        let (faces, is_opening): (Vec<_>, Vec<_>) = self
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .map(|face| (PolygonFace::new(face), openings.contains(face)))
+            .unzip();
+
+        // The planes of openings stay where they are, so the inner walls end
+        // where the outer ones do.
+        let offset = |point| {
+            offset_vertex(point, &faces, |i| {
+                if is_opening[i] {
+                    Scalar::ZERO
+                } else {
+                    -thickness
+                }
+            })
+        };
+
+        // Without openings, the cavity is bounded by a separate shell.
+        // Otherwise, the inner and outer walls are connected by the rims of
+        // the openings.
+        let has_openings = is_opening.contains(&true);
+
+        let mut outer = Assembler::new();
+        let mut inner = Assembler::new();
+
+        for (face, is_opening) in faces.iter().zip(&is_opening) {
+            let offset_cycles = face
+                .cycles
+                .iter()
+                .map(|cycle| {
+                    // The inner walls face the cavity, so they are reversed.
+                    cycle.iter().rev().map(|&point| offset(point)).collect()
+                })
+                .collect::<Vec<Vec<_>>>();
+
+            if *is_opening {
+                assert!(
+                    face.cycles.len() == 1,
+                    "Openings with interiors are not supported"
+                );
+
+                // The face is replaced by a rim around the opening.
+                let exterior = face.cycles[0].clone();
+                let [interior] = <[_; 1]>::try_from(offset_cycles)
+                    .expect("Checked that the face has exactly one cycle");
+                outer.add_polygon(
+                    face.surface.clone(),
+                    face.plane,
+                    face.color,
+                    vec![exterior, interior],
+                );
+                continue;
+            }
+
+            outer.add_polygon(
+                face.surface.clone(),
+                face.plane,
+                face.color,
+                face.cycles.clone(),
+            );
+
+            let (surface, plane) = face.offset_surface(-thickness, objects);
+
+            let assembler = if has_openings { &mut outer } else { &mut inner };
+            assembler.add_polygon(surface, plane, face.color, offset_cycles);
+        }
+
+        if has_openings {
+            Solid::new().with_shells([outer.build(objects)])
+        } else {
+            Solid::new().with_shells(
+                [outer, inner].map(|assembler| assembler.build(objects)),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Scalar};
+
+    use crate::{
+        algorithms::{
+            test_helpers::{bounding_volume, closed_shell, cube},
+            validate::Validate,
+        },
+        iter::ObjectIters,
+        objects::{Objects, Solid},
+    };
+
+    use super::Hollow;
+
+    #[test]
+    fn hollow_without_openings() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let solid = cube(1., &objects).hollow(0.1, &[], &objects);
+
+        // The cavity is bounded by a separate shell.
+        let shells = solid.shells().cloned().collect::<Vec<_>>();
+        assert_eq!(shells.len(), 2);
+
+        let [outer, inner] = [&shells[0], &shells[1]]
+            .map(|shell| Solid::new().with_shells([shell.clone()]))
+            .map(|solid| closed_shell(solid, &objects));
+        let [outer, inner] = [outer?, inner?];
+
+        assert_eq!(outer.faces().into_iter().count(), 6);
+        assert_eq!(inner.faces().into_iter().count(), 6);
+        assert_eq!(
+            bounding_volume(&outer),
+            Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]])
+        );
+        assert_eq!(
+            bounding_volume(&inner),
+            Aabb::<3>::from_points([[0.1, 0.1, 0.1], [0.9, 0.9, 0.9]])
+        );
+
+        solid.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn hollow_with_opening() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let solid = cube(1., &objects);
+        let top = solid
+            .face_iter()
+            .find(|face| {
+                face.all_cycles().flat_map(|cycle| cycle.half_edges()).all(
+                    |half_edge| {
+                        half_edge.vertices().iter().all(|vertex| {
+                            vertex.global_form().position().z == Scalar::ONE
+                        })
+                    },
+                )
+            })
+            .expect("Cube must have top face")
+            .clone();
+
+        let solid = solid.hollow(0.1, &[top], &objects);
+
+        // The walls are connected by a rim around the opening.
+        let shells = solid.shells().cloned().collect::<Vec<_>>();
+        assert_eq!(shells.len(), 1);
+
+        let shell = closed_shell(solid, &objects)?;
+        assert_eq!(shell.faces().into_iter().count(), 11);
+        assert_eq!(
+            bounding_volume(&shell),
+            Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]])
+        );
+
+        let rim = shell
+            .faces()
+            .into_iter()
+            .find(|face| face.interiors().count() > 0)
+            .expect("Shell must have rim");
+        assert_eq!(
+            bounding_volume(rim.interiors().next().unwrap()),
+            Aabb::<3>::from_points([[0.1, 0.1, 1.], [0.9, 0.9, 1.]])
+        );
+
+        shell.validate()?;
+        Ok(())
+    }
+}
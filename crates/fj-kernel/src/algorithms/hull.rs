@@ -0,0 +1,503 @@
+//! Convex hulls
+//!
+//! Computes the smallest convex solid that contains a set of points, or a
+//! shape.
+//!
+//! The hull is bounded by planar faces. Where neighboring triangles of the hull
+//! are exactly coplanar, they are merged into a single face. Shapes are hulled
+//! in their triangulated form, which means the hull of a curved shape is exact
+//! only up to the tolerance that is used for the triangulation.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_interop::mesh::{Color, Mesh};
+use fj_math::{Plane, Point, Scalar};
+
+use crate::objects::{Objects, Solid, Surface};
+
+use super::{
+    approx::Tolerance, boolean::assemble::Assembler, triangulate::Triangulate,
+};
+
+/// Compute the convex hull of an object
+pub trait Hull {
+    /// Create a solid that is the convex hull of the object
+    ///
+    /// # Panics
+    ///
+    /// Panics, if all points of the object lie in a common plane, as their
+    /// hull doesn't have a volume then.
+    fn hull(self, objects: &Objects) -> Solid;
+}
+
+impl Hull for Vec<Point<3>> {
+    fn hull(self, objects: &Objects) -> Solid {
+        hull_of_points(self, Color::default(), objects)
+    }
+}
+
+impl<T> Hull for (T, Tolerance)
+where
+    (T, Tolerance): Triangulate,
+{
+    fn hull(self, objects: &Objects) -> Solid {
+        let mesh: Mesh<Point<3>> = self.triangulate();
+
+        let color = mesh
+            .triangles()
+            .next()
+            .map(|triangle| triangle.color)
+            .unwrap_or_default();
+
+        hull_of_points(mesh.vertices().collect(), color, objects)
+    }
+}
+
+pub(super) fn hull_of_points(
+    points: Vec<Point<3>>,
+    color: Color,
+    objects: &Objects,
+) -> Solid {
+    let points = points
+        .into_iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let triangles = hull_triangles(&points);
+    let faces = merge_coplanar(&points, &triangles);
+
+    let mut assembler = Assembler::new();
+    for polygon in faces {
+        let [a, b, c] = spanning_points(&polygon);
+        let plane = Plane::from_parametric(a, b - a, c - a);
+        let surface = objects
+            .surfaces
+            .insert(Surface::plane_from_points([a, b, c]));
+
+        assembler.add_polygon(surface, plane, color, vec![polygon]);
+    }
+
+    Solid::new().with_shells([assembler.build(objects)])
+}
+
+/// Select three points of a convex polygon that span its plane well
+///
+/// Hulls of curved shapes have many faces with short edges. Using neighboring
+/// points to define the plane of such a face would make it inaccurate. The
+/// returned points are in the same order as in the polygon.
+fn spanning_points(polygon: &[Point<3>]) -> [Point<3>; 3] {
+    let a = 0;
+    let b = farthest(polygon, |point| (point - polygon[a]).magnitude());
+    let c = farthest(polygon, |point| {
+        (point - polygon[a])
+            .cross(&(polygon[b] - polygon[a]))
+            .magnitude()
+    });
+
+    let [b, c] = if b < c { [b, c] } else { [c, b] };
+    [a, b, c].map(|i| polygon[i])
+}
+
+/// Compute the triangles of the hull, wound counter-clockwise from the outside
+///
+/// Uses the Quickhull algorithm: Starting from a tetrahedron, the point that is
+/// farthest outside of a triangle of the hull so far replaces the triangles
+/// that it can see. Each remaining point is tracked by one of the triangles it
+/// is outside of. Points that aren't outside of any triangle are inside of the
+/// hull, and are dropped.
+fn hull_triangles(points: &[Point<3>]) -> Vec<[usize; 3]> {
+    let tetrahedron = initial_tetrahedron(points);
+    let [a, b, c, d] = tetrahedron;
+
+    let mut hull = HullTriangles::default();
+    let initial = [[a, b, c], [a, d, b], [b, d, c], [c, d, a]]
+        .map(|triangle| hull.insert(triangle));
+    hull.assign(
+        (0..points.len()).filter(|p| !tetrahedron.contains(p)),
+        &initial,
+        points,
+    );
+
+    while let Some(t) = hull.triangle_with_outside_points() {
+        // Points that are exactly in the plane of a triangle don't see it.
+        // That way, points on the hull so far are treated like those inside.
+        let triangle = hull.triangles[t].as_ref().expect("Triangle exists");
+        let p = triangle.farthest_outside(points);
+
+        let mut visible = vec![t];
+        let mut stack = vec![t];
+        while let Some(t) = stack.pop() {
+            for neighbor in hull.neighbors(t) {
+                if !visible.contains(&neighbor)
+                    && hull.is_visible(neighbor, points[p], points)
+                {
+                    visible.push(neighbor);
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        // The edges of the visible region are those, whose reverse edge is
+        // not part of the region.
+        let removed = visible
+            .into_iter()
+            .map(|t| hull.remove(t))
+            .collect::<Vec<_>>();
+        let edges = removed
+            .iter()
+            .flat_map(|triangle| triangle.edges())
+            .collect::<BTreeSet<_>>();
+        let added = edges
+            .iter()
+            .filter(|[a, b]| !edges.contains(&[*b, *a]))
+            .map(|&[a, b]| hull.insert([a, b, p]))
+            .collect::<Vec<_>>();
+
+        let outside = removed
+            .into_iter()
+            .flat_map(|triangle| triangle.outside)
+            .filter(|&q| q != p);
+        hull.assign(outside, &added, points);
+    }
+
+    hull.triangles
+        .into_iter()
+        .flatten()
+        .map(|triangle| triangle.points)
+        .collect()
+}
+
+#[derive(Default)]
+struct HullTriangles {
+    triangles: Vec<Option<HullTriangle>>,
+    triangle_of_edge: BTreeMap<[usize; 2], usize>,
+    next_with_outside_points: usize,
+}
+
+impl HullTriangles {
+    fn insert(&mut self, points: [usize; 3]) -> usize {
+        let t = self.triangles.len();
+        let triangle = HullTriangle {
+            points,
+            outside: Vec::new(),
+        };
+
+        for edge in triangle.edges() {
+            self.triangle_of_edge.insert(edge, t);
+        }
+        self.triangles.push(Some(triangle));
+
+        t
+    }
+
+    fn remove(&mut self, t: usize) -> HullTriangle {
+        let triangle = self.triangles[t].take().expect("Triangle exists");
+        for edge in triangle.edges() {
+            if self.triangle_of_edge.get(&edge) == Some(&t) {
+                self.triangle_of_edge.remove(&edge);
+            }
+        }
+        triangle
+    }
+
+    fn neighbors(&self, t: usize) -> Vec<usize> {
+        let triangle = self.triangles[t].as_ref().expect("Triangle exists");
+        triangle
+            .edges()
+            .into_iter()
+            .filter_map(|[a, b]| self.triangle_of_edge.get(&[b, a]).copied())
+            .collect()
+    }
+
+    fn is_visible(
+        &self,
+        t: usize,
+        point: Point<3>,
+        points: &[Point<3>],
+    ) -> bool {
+        let triangle = self.triangles[t].as_ref().expect("Triangle exists");
+        triangle.distance(point, points) < 0.
+    }
+
+    /// Find a triangle that still has points outside of it
+    ///
+    /// Points are only assigned to triangles when they are inserted, so the
+    /// search can continue where the last one left off.
+    fn triangle_with_outside_points(&mut self) -> Option<usize> {
+        while self.next_with_outside_points < self.triangles.len() {
+            let t = self.next_with_outside_points;
+            if let Some(triangle) = &self.triangles[t] {
+                if !triangle.outside.is_empty() {
+                    return Some(t);
+                }
+            }
+            self.next_with_outside_points += 1;
+        }
+
+        None
+    }
+
+    /// Assign points to the first of `candidates` that they are outside of
+    fn assign(
+        &mut self,
+        outside: impl IntoIterator<Item = usize>,
+        candidates: &[usize],
+        points: &[Point<3>],
+    ) {
+        for p in outside {
+            let t = candidates
+                .iter()
+                .copied()
+                .find(|&t| self.is_visible(t, points[p], points));
+            if let Some(t) = t {
+                self.triangles[t]
+                    .as_mut()
+                    .expect("Triangle exists")
+                    .outside
+                    .push(p);
+            }
+        }
+    }
+}
+
+struct HullTriangle {
+    points: [usize; 3],
+    outside: Vec<usize>,
+}
+
+impl HullTriangle {
+    fn edges(&self) -> [[usize; 2]; 3] {
+        let [a, b, c] = self.points;
+        [[a, b], [b, c], [c, a]]
+    }
+
+    /// Compute a value that is negative, if the point is outside
+    ///
+    /// The magnitude is proportional to the distance of the point from the
+    /// plane of the triangle.
+    fn distance(&self, point: Point<3>, points: &[Point<3>]) -> f64 {
+        let [a, b, c] = self.points.map(|i| points[i]);
+        orient3d(a, b, c, point)
+    }
+
+    fn farthest_outside(&self, points: &[Point<3>]) -> usize {
+        self.outside
+            .iter()
+            .copied()
+            .min_by(|&p, &q| {
+                let [p, q] = [p, q].map(|i| self.distance(points[i], points));
+                p.total_cmp(&q)
+            })
+            .expect("Triangle has outside points")
+    }
+}
+
+/// Find four points that span a tetrahedron, wound like the triangles of the
+/// hull
+fn initial_tetrahedron(points: &[Point<3>]) -> [usize; 4] {
+    let a = 0;
+
+    let b = farthest(points, |point| (point - points[a]).magnitude());
+    let c = farthest(points, |point| {
+        (point - points[a])
+            .cross(&(points[b] - points[a]))
+            .magnitude()
+    });
+    let d = farthest(points, |point| {
+        Scalar::from_f64(orient3d(points[a], points[b], points[c], point).abs())
+    });
+
+    let orientation = orient3d(points[a], points[b], points[c], points[d]);
+    assert!(orientation != 0., "Can't compute hull of coplanar points");
+
+    // The first triangle of the tetrahedron must face away from the last
+    // point.
+    if orientation < 0. {
+        [a, c, b, d]
+    } else {
+        [a, b, c, d]
+    }
+}
+
+fn farthest(
+    points: &[Point<3>],
+    distance: impl Fn(Point<3>) -> Scalar,
+) -> usize {
+    points
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, &point)| distance(point))
+        .map(|(i, _)| i)
+        .expect("Can't compute hull of no points")
+}
+
+/// Merge neighboring triangles that are exactly coplanar into polygons
+fn merge_coplanar(
+    points: &[Point<3>],
+    triangles: &[[usize; 3]],
+) -> Vec<Vec<Point<3>>> {
+    let mut triangle_of_edge = BTreeMap::new();
+    for (t, &[a, b, c]) in triangles.iter().enumerate() {
+        for edge in [[a, b], [b, c], [c, a]] {
+            triangle_of_edge.insert(edge, t);
+        }
+    }
+
+    // Assign each triangle to a group of coplanar triangles, by flooding
+    // across edges to coplanar neighbors.
+    let mut group_of_triangle = vec![None; triangles.len()];
+    let mut groups = Vec::new();
+    for start in 0..triangles.len() {
+        if group_of_triangle[start].is_some() {
+            continue;
+        }
+
+        let group = groups.len();
+        groups.push(Vec::new());
+        group_of_triangle[start] = Some(group);
+
+        let [a, b, c] = triangles[start].map(|i| points[i]);
+        let mut stack = vec![start];
+        while let Some(t) = stack.pop() {
+            groups[group].push(t);
+
+            let [p, q, r] = triangles[t];
+            for [x, y] in [[p, q], [q, r], [r, p]] {
+                let neighbor = triangle_of_edge[&[y, x]];
+                if group_of_triangle[neighbor].is_some() {
+                    continue;
+                }
+
+                let is_coplanar = triangles[neighbor]
+                    .iter()
+                    .all(|&i| orient3d(a, b, c, points[i]) == 0.);
+                if is_coplanar {
+                    group_of_triangle[neighbor] = Some(group);
+                    stack.push(neighbor);
+                }
+            }
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|group| {
+            // The boundary of the group consists of the edges, whose reverse
+            // edge is in another group. As the group is convex, they form a
+            // single loop.
+            let mut next = BTreeMap::new();
+            for &t in &group {
+                let [a, b, c] = triangles[t];
+                for [x, y] in [[a, b], [b, c], [c, a]] {
+                    let neighbor = triangle_of_edge[&[y, x]];
+                    if group_of_triangle[neighbor] != group_of_triangle[t] {
+                        next.insert(x, y);
+                    }
+                }
+            }
+
+            let start = *next.keys().next().expect("Group has no boundary");
+            let mut polygon = vec![points[start]];
+            let mut current = next[&start];
+            while current != start {
+                polygon.push(points[current]);
+                current = next[&current];
+            }
+
+            polygon
+        })
+        .collect()
+}
+
+fn orient3d(a: Point<3>, b: Point<3>, c: Point<3>, d: Point<3>) -> f64 {
+    let [a, b, c, d] =
+        [a, b, c, d].map(|point| point.coords.components.map(Scalar::into_f64));
+    robust_predicates::orient3d(&a, &b, &c, &d)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Mesh;
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, measure::mass_properties, sweep::Sweep,
+            transform::TransformObject, triangulate::Triangulate,
+            validate::Validate,
+        },
+        objects::{Cycle, Face, HalfEdge, Objects, Sketch, Solid, Surface},
+        partial::HasPartial,
+    };
+
+    use super::Hull;
+
+    #[test]
+    fn hull_of_points() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        // The corners of a cube, plus some points inside and on its boundary.
+        let mut points = Vec::new();
+        for x in [0., 1.] {
+            for y in [0., 1.] {
+                for z in [0., 1.] {
+                    points.push(Point::from([x, y, z]));
+                }
+            }
+        }
+        points.extend([
+            Point::from([0.5, 0.5, 0.5]),
+            Point::from([0.25, 0.75, 0.5]),
+            Point::from([0.5, 0.5, 1.]),
+        ]);
+
+        let cube = points.hull(&objects).validate()?.into_inner();
+
+        let faces = cube
+            .shells()
+            .flat_map(|shell| shell.faces().into_iter())
+            .count();
+        assert_eq!(faces, 6);
+
+        let mesh: Mesh<Point<3>> =
+            (&cube, Tolerance::from_scalar(0.001)?).triangulate();
+        let volume = mass_properties(&mesh).volume;
+        assert!((volume - Scalar::ONE).abs() < Scalar::from_f64(1e-12));
+
+        Ok(())
+    }
+
+    #[test]
+    fn hull_of_shapes() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let circle = HalfEdge::partial()
+            .with_surface(Some(surface.clone()))
+            .as_circle_from_radius(1.)?
+            .build(&objects)?;
+        let cylinder = Sketch::new()
+            .with_faces([Face::from_exterior(Cycle::new(surface, [circle]))])
+            .sweep([0., 0., 1.], &objects);
+
+        // Two cylinders next to each other are hulled into a rounded slot.
+        let shapes = Solid::new().with_shells(
+            [
+                cylinder.clone(),
+                cylinder.translate([3., 0., 0.], &objects)?,
+            ]
+            .into_iter()
+            .flat_map(|solid| solid.into_shells()),
+        );
+        let slot = (&shapes, tolerance).hull(&objects).validate()?.into_inner();
+
+        let mesh: Mesh<Point<3>> = (&slot, tolerance).triangulate();
+        let volume = mass_properties(&mesh).volume;
+        let expected = Scalar::PI + 6.;
+        assert!(volume < expected && volume > expected - 0.01);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,400 @@
+use fj_math::{Circle, Line, Point, Scalar, Vector};
+
+use crate::{algorithms::offset::displacement, path::GlobalPath};
+
+/// The intersection between two curves in 3D
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CurveCurveIntersection {
+    /// The curves intersect at isolated points
+    Points {
+        /// The intersection points, given as coordinates on both curves
+        ///
+        /// The points are ordered along the first curve.
+        points: Vec<[Point<1>; 2]>,
+    },
+
+    /// The curves are coincident
+    Coincident,
+}
+
+impl CurveCurveIntersection {
+    /// Compute the intersection
+    ///
+    /// Points count as an intersection, if they are closer to each other than
+    /// a small epsilon. This makes it possible to find intersections between
+    /// curves that only meet due to their construction, like the edges of
+    /// adjacent faces.
+    ///
+    /// # Panics
+    ///
+    /// Currently, only intersections between lines and circles can be
+    /// computed. Panics, if a different type of curve is passed.
+    pub fn compute(a: &GlobalPath, b: &GlobalPath) -> Option<Self> {
+        let intersection = match (a, b) {
+            (GlobalPath::Line(a), GlobalPath::Line(b)) => line_line(a, b),
+            (GlobalPath::Line(line), GlobalPath::Circle(circle)) => {
+                line_circle(line, circle).map(Self::from)
+            }
+            (GlobalPath::Circle(circle), GlobalPath::Line(line)) => {
+                let points = line_circle(line, circle)?;
+                Some(Self::Points {
+                    points: points
+                        .into_iter()
+                        .map(|[on_line, on_circle]| [on_circle, on_line])
+                        .collect(),
+                })
+            }
+            (GlobalPath::Circle(a), GlobalPath::Circle(b)) => {
+                circle_circle(a, b)
+            }
+            _ => todo!(
+                "Curve-curve intersection only supports lines and circles"
+            ),
+        }?;
+
+        let intersection = match intersection {
+            Self::Points { mut points } => {
+                points.sort();
+                Self::Points { points }
+            }
+            Self::Coincident => Self::Coincident,
+        };
+
+        Some(intersection)
+    }
+}
+
+impl From<Vec<[Point<1>; 2]>> for CurveCurveIntersection {
+    fn from(points: Vec<[Point<1>; 2]>) -> Self {
+        Self::Points { points }
+    }
+}
+
+const EPSILON: f64 = 1e-9;
+
+fn line_line(a: &Line<3>, b: &Line<3>) -> Option<CurveCurveIntersection> {
+    let [da, db] = [a.direction(), b.direction()];
+
+    if da.cross(&db).magnitude()
+        < Scalar::from_f64(EPSILON) * da.magnitude() * db.magnitude()
+    {
+        // The lines are parallel.
+        let offset = b.origin() - a.origin();
+        let distance = (offset - project(offset, da)).magnitude();
+
+        if distance < Scalar::from_f64(EPSILON) {
+            return Some(CurveCurveIntersection::Coincident);
+        }
+        return None;
+    }
+
+    // Find the points on both lines that are closest to each other. See
+    // Real-Time Collision Detection by Christer Ericson, section 5.1.8.
+    let r = a.origin() - b.origin();
+    let aa = da.dot(&da);
+    let ab = da.dot(&db);
+    let bb = db.dot(&db);
+    let ar = da.dot(&r);
+    let br = db.dot(&r);
+
+    let denominator = aa * bb - ab * ab;
+    let s = (ab * br - bb * ar) / denominator;
+    let t = (aa * br - ab * ar) / denominator;
+
+    let distance = (a.point_from_line_coords([s])
+        - b.point_from_line_coords([t]))
+    .magnitude();
+    if distance >= Scalar::from_f64(EPSILON) {
+        return None;
+    }
+
+    Some(vec![[Point::from([s]), Point::from([t])]].into())
+}
+
+/// Compute the intersection between a line and a circle
+///
+/// Returns the intersection points as coordinates on the line and the circle.
+fn line_circle(
+    line: &Line<3>,
+    circle: &Circle<3>,
+) -> Option<Vec<[Point<1>; 2]>> {
+    let normal = circle.a().cross(&circle.b()).normalize();
+    let direction = line.direction();
+
+    let offset = normal.dot(&(circle.center() - line.origin()));
+    let denominator = normal.dot(&direction);
+
+    let ts = if denominator.abs()
+        < Scalar::from_f64(EPSILON) * direction.magnitude()
+    {
+        // The line is parallel to the plane of the circle.
+        if offset.abs() >= Scalar::from_f64(EPSILON) {
+            return None;
+        }
+
+        // Find the point on the line that is closest to the center of the
+        // circle, then move along the line to the circle from there.
+        let to_center = circle.center() - line.origin();
+        let t = direction.dot(&to_center) / direction.dot(&direction);
+        let distance =
+            (line.point_from_line_coords([t]) - circle.center()).magnitude();
+
+        let radius = circle.radius();
+        if distance > radius + Scalar::from_f64(EPSILON) {
+            return None;
+        }
+        if (distance - radius).abs() < Scalar::from_f64(EPSILON) {
+            vec![t]
+        } else {
+            let half_chord = (radius * radius - distance * distance).sqrt()
+                / direction.magnitude();
+            vec![t - half_chord, t + half_chord]
+        }
+    } else {
+        // The line crosses the plane of the circle at a single point.
+        let t = offset / denominator;
+        let distance =
+            (line.point_from_line_coords([t]) - circle.center()).magnitude();
+
+        if (distance - circle.radius()).abs() >= Scalar::from_f64(EPSILON) {
+            return None;
+        }
+        vec![t]
+    };
+
+    Some(
+        ts.into_iter()
+            .map(|t| {
+                let point = line.point_from_line_coords([t]);
+                [Point::from([t]), circle_coords(circle, point)]
+            })
+            .collect(),
+    )
+}
+
+fn circle_circle(
+    a: &Circle<3>,
+    b: &Circle<3>,
+) -> Option<CurveCurveIntersection> {
+    let [na, nb] =
+        [a, b].map(|circle| circle.a().cross(&circle.b()).normalize());
+
+    if na.cross(&nb).magnitude() < Scalar::from_f64(EPSILON) {
+        // The circles are in parallel planes.
+        let offset = b.center() - a.center();
+        if na.dot(&offset).abs() >= Scalar::from_f64(EPSILON) {
+            return None;
+        }
+
+        let [ra, rb] = [a.radius(), b.radius()];
+        let distance = offset.magnitude();
+
+        if distance < Scalar::from_f64(EPSILON) {
+            if (ra - rb).abs() < Scalar::from_f64(EPSILON) {
+                return Some(CurveCurveIntersection::Coincident);
+            }
+            return None;
+        }
+        if distance > ra + rb + Scalar::from_f64(EPSILON)
+            || distance < (ra - rb).abs() - Scalar::from_f64(EPSILON)
+        {
+            return None;
+        }
+
+        // The intersection points are on the line that is perpendicular to
+        // the line between the centers, at distance `x` from the center of
+        // `a`.
+        let direction = offset / distance;
+        let x = (distance * distance + ra * ra - rb * rb) / (distance * 2.);
+        let height = (ra * ra - x * x).max(Scalar::ZERO).sqrt();
+
+        let foot = a.center() + direction * x;
+        let side = na.cross(&direction);
+
+        let heights = if height < Scalar::from_f64(EPSILON) {
+            vec![Scalar::ZERO]
+        } else {
+            vec![-height, height]
+        };
+
+        return Some(
+            heights
+                .into_iter()
+                .map(|height| {
+                    let point = foot + side * height;
+                    [circle_coords(a, point), circle_coords(b, point)]
+                })
+                .collect::<Vec<_>>()
+                .into(),
+        );
+    }
+
+    // The circles can only intersect on the line where their planes meet.
+    let origin = Point::origin()
+        + displacement(&[
+            (na, na.dot(&a.center().coords)),
+            (nb, nb.dot(&b.center().coords)),
+        ]);
+    let line = Line::from_origin_and_direction(origin, na.cross(&nb));
+
+    let points = line_circle(&line, a)?
+        .into_iter()
+        .filter_map(|[t, on_a]| {
+            let point = line.point_from_line_coords(t);
+            let distance = (point - b.center()).magnitude();
+
+            ((distance - b.radius()).abs() < Scalar::from_f64(EPSILON))
+                .then(|| [on_a, circle_coords(b, point)])
+        })
+        .collect::<Vec<_>>();
+
+    if points.is_empty() {
+        return None;
+    }
+
+    Some(points.into())
+}
+
+/// Convert a point on a circle into circle coordinates
+///
+/// Unlike [`Circle::point_to_circle_coords`], this works for circles that are
+/// not parallel to the xy-plane.
+fn circle_coords(circle: &Circle<3>, point: Point<3>) -> Point<1> {
+    let vector = point - circle.center();
+    let [u, v] = [circle.a(), circle.b()].map(|axis| vector.dot(&axis));
+
+    let angle = Scalar::atan2(v, u);
+    let angle = if angle < Scalar::ZERO {
+        angle + Scalar::TAU
+    } else {
+        angle
+    };
+
+    Point::from([angle])
+}
+
+fn project(vector: Vector<3>, onto: Vector<3>) -> Vector<3> {
+    onto * vector.dot(&onto) / onto.dot(&onto)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use fj_math::{Circle, Line, Point, Scalar, Vector};
+
+    use crate::path::GlobalPath;
+
+    use super::CurveCurveIntersection;
+
+    #[test]
+    fn line_line() {
+        let a = line([0., 0., 0.], [1., 0., 0.]);
+        let b = line([1., -1., 0.], [0., 2., 0.]);
+        let c = line([1., -1., 1.], [0., 2., 0.]);
+
+        assert_eq!(
+            CurveCurveIntersection::compute(&a, &b),
+            Some(points([[1., 0.5]]))
+        );
+        assert_eq!(CurveCurveIntersection::compute(&a, &c), None);
+    }
+
+    #[test]
+    fn line_line_coincident() {
+        let a = line([0., 0., 0.], [1., 0., 0.]);
+        let b = line([2., 0., 0.], [-2., 0., 0.]);
+        let c = line([0., 1., 0.], [1., 0., 0.]);
+
+        assert_eq!(
+            CurveCurveIntersection::compute(&a, &b),
+            Some(CurveCurveIntersection::Coincident)
+        );
+        assert_eq!(CurveCurveIntersection::compute(&a, &c), None);
+    }
+
+    #[test]
+    fn line_circle() {
+        let circle = circle([0., 0., 0.], 1.);
+
+        // A line in the plane of the circle, crossing it twice.
+        let a = line([-2., 0., 0.], [1., 0., 0.]);
+        assert_eq!(
+            CurveCurveIntersection::compute(&a, &circle),
+            Some(points([[1., PI], [3., 0.]]))
+        );
+
+        // A line that passes through the plane of the circle, on the circle.
+        let b = line([0., 1., -1.], [0., 0., 1.]);
+        assert_eq!(
+            CurveCurveIntersection::compute(&circle, &b),
+            Some(points([[FRAC_PI_2, 1.]]))
+        );
+
+        let c = line([0., 0., -1.], [0., 0., 1.]);
+        assert_eq!(CurveCurveIntersection::compute(&circle, &c), None);
+    }
+
+    #[test]
+    fn circle_circle_coplanar() {
+        let a = circle([0., 0., 0.], 1.);
+        let b = circle([1., 0., 0.], 1.);
+        let c = circle([2., 0., 0.], 1.);
+
+        let intersection = CurveCurveIntersection::compute(&a, &b).unwrap();
+        assert_eq!(num_points(&intersection), 2);
+
+        // Circles that touch each other at a single point.
+        assert_eq!(
+            CurveCurveIntersection::compute(&a, &c),
+            Some(points([[0., PI]]))
+        );
+
+        assert_eq!(
+            CurveCurveIntersection::compute(&a, &a.clone()),
+            Some(CurveCurveIntersection::Coincident)
+        );
+    }
+
+    #[test]
+    fn circle_circle_in_different_planes() {
+        let a = circle([0., 0., 0.], 1.);
+        let b = GlobalPath::Circle(Circle::new(
+            Point::from([0., 0., 0.]),
+            Vector::from([1., 0., 0.]),
+            Vector::from([0., 0., 1.]),
+        ));
+
+        assert_eq!(
+            CurveCurveIntersection::compute(&a, &b),
+            Some(points([[0., 0.], [PI, PI]]))
+        );
+    }
+
+    fn line(origin: [f64; 3], direction: [f64; 3]) -> GlobalPath {
+        GlobalPath::Line(Line::from_origin_and_direction(
+            Point::from(origin),
+            Vector::from(direction),
+        ))
+    }
+
+    fn circle(center: [f64; 3], radius: f64) -> GlobalPath {
+        GlobalPath::Circle(Circle::from_center_and_radius(center, radius))
+    }
+
+    fn points<const N: usize>(points: [[f64; 2]; N]) -> CurveCurveIntersection {
+        CurveCurveIntersection::Points {
+            points: points
+                .into_iter()
+                .map(|point| point.map(|t| Point::from([Scalar::from_f64(t)])))
+                .collect(),
+        }
+    }
+
+    fn num_points(intersection: &CurveCurveIntersection) -> usize {
+        match intersection {
+            CurveCurveIntersection::Points { points } => points.len(),
+            CurveCurveIntersection::Coincident => 0,
+        }
+    }
+}
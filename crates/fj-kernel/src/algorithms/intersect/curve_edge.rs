@@ -1,11 +1,11 @@
-use fj_math::{Point, Segment};
+use fj_math::{Ellipse, Line, Point, Scalar, Segment};
 
 use crate::{
     objects::{Curve, HalfEdge},
     path::SurfacePath,
 };
 
-use super::LineSegmentIntersection;
+use super::{LineEllipseIntersection, LineSegmentIntersection};
 
 /// The intersection between a [`Curve`] and a [`HalfEdge`]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -16,6 +16,15 @@ pub enum CurveEdgeIntersection {
         point_on_curve: Point<1>,
     },
 
+    /// The curve and edge intersect at two points
+    ///
+    /// This can only happen, if the curve or the edge is curved. If the curve
+    /// touches the edge, both points are the same.
+    TwoPoints {
+        /// The intersection points, in curve coordinates on the curve
+        points_on_curve: [Point<1>; 2],
+    },
+
     /// The edge lies on the curve
     Coincident {
         /// The end points of the edge, in curve coordinates on the curve
@@ -28,48 +37,153 @@ impl CurveEdgeIntersection {
     ///
     /// # Panics
     ///
-    /// Currently, only intersections between lines and line segments can be
-    /// computed. Panics, if a different type of [`Curve`] or [`HalfEdge`] is
+    /// Currently, only intersections between lines, circles, and ellipses can
+    /// be computed, and at least one of the curve and the edge must be
+    /// straight. Panics, if a different type of [`Curve`] or [`HalfEdge`] is
     /// passed.
     pub fn compute(curve: &Curve, half_edge: &HalfEdge) -> Option<Self> {
-        let curve_as_line = match curve.path() {
-            SurfacePath::Line(line) => line,
-            _ => todo!("Curve-edge intersection only supports lines"),
-        };
-
-        let edge_as_segment = {
-            let edge_curve_as_line = match half_edge.curve().path() {
-                SurfacePath::Line(line) => line,
-                _ => {
-                    todo!("Curve-edge intersection only supports line segments")
-                }
-            };
-
-            let edge_vertices = half_edge.vertices().clone().map(|vertex| {
-                edge_curve_as_line.point_from_line_coords(vertex.position())
-            });
-
-            Segment::from_points(edge_vertices)
-        };
-
-        let intersection =
-            LineSegmentIntersection::compute(&curve_as_line, &edge_as_segment)?;
-
-        let intersection = match intersection {
-            LineSegmentIntersection::Point { point_on_line } => Self::Point {
+        let boundary = half_edge.trimmed_curve().boundary();
+
+        match (curve.path(), half_edge.curve().path()) {
+            (SurfacePath::Line(line), SurfacePath::Line(edge_line)) => {
+                let edge_as_segment = Segment::from_points(
+                    boundary
+                        .map(|point| edge_line.point_from_line_coords(point)),
+                );
+
+                line_segment(line, &edge_as_segment)
+            }
+            (SurfacePath::Line(line), edge_path) => {
+                let ellipse = as_ellipse(edge_path).unwrap_or_else(|| {
+                    todo!(
+                        "Curve-edge intersection only supports edges that \
+                        are lines, circles, or ellipses"
+                    )
+                });
+
+                let points = LineEllipseIntersection::compute(line, &ellipse)
+                    .map(points_on_both)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|(_, on_edge)| is_within_arc(*on_edge, boundary))
+                    .map(|(on_curve, _)| on_curve);
+
+                Self::from_points(points)
+            }
+            (curve_path, SurfacePath::Line(edge_line)) => {
+                let ellipse = as_ellipse(curve_path).unwrap_or_else(|| {
+                    todo!(
+                        "Curve-edge intersection only supports curves that \
+                        are lines, circles, or ellipses"
+                    )
+                });
+
+                // A line through the end points of the edge, on which the
+                // edge is between the line coordinates `0` and `1`.
+                let edge_line = Line::from_points(
+                    boundary
+                        .map(|point| edge_line.point_from_line_coords(point)),
+                );
+
+                let points =
+                    LineEllipseIntersection::compute(&edge_line, &ellipse)
+                        .map(points_on_both)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter(|(on_edge, _)| {
+                            *on_edge >= Point::from([0.])
+                                && *on_edge <= Point::from([1.])
+                        })
+                        .map(|(_, on_curve)| on_curve);
+
+                Self::from_points(points)
+            }
+            _ => todo!(
+                "Curve-edge intersection between a curved curve and a curved \
+                edge is not supported"
+            ),
+        }
+    }
+
+    fn from_points(points: impl Iterator<Item = Point<1>>) -> Option<Self> {
+        match points.collect::<Vec<_>>().as_slice() {
+            [] => None,
+            &[point_on_curve] => Some(Self::Point { point_on_curve }),
+            &[a, b] => Some(Self::TwoPoints {
+                points_on_curve: [a, b],
+            }),
+            _ => unreachable!("A line intersects an ellipse at most twice"),
+        }
+    }
+}
+
+fn line_segment(
+    line: &Line<2>,
+    segment: &Segment<2>,
+) -> Option<CurveEdgeIntersection> {
+    let intersection = LineSegmentIntersection::compute(line, segment)?;
+
+    let intersection = match intersection {
+        LineSegmentIntersection::Point { point_on_line } => {
+            CurveEdgeIntersection::Point {
                 point_on_curve: point_on_line,
-            },
-            LineSegmentIntersection::Coincident { points_on_line } => {
-                Self::Coincident {
-                    points_on_curve: points_on_line,
-                }
             }
-        };
+        }
+        LineSegmentIntersection::Coincident { points_on_line } => {
+            CurveEdgeIntersection::Coincident {
+                points_on_curve: points_on_line,
+            }
+        }
+    };
+
+    Some(intersection)
+}
+
+fn as_ellipse(path: &SurfacePath) -> Option<Ellipse<2>> {
+    match path {
+        SurfacePath::Circle(circle) => Some((*circle).into()),
+        SurfacePath::Ellipse(ellipse) => Some(*ellipse),
+        _ => None,
+    }
+}
 
-        Some(intersection)
+/// Convert a line-ellipse intersection into pairs of points on both
+///
+/// If the line touches the ellipse, the point is returned twice. Either way,
+/// the line enters and leaves the ellipse there.
+fn points_on_both(
+    intersection: LineEllipseIntersection,
+) -> Vec<(Point<1>, Point<1>)> {
+    match intersection {
+        LineEllipseIntersection::Tangent {
+            point_on_line,
+            point_on_ellipse,
+        } => vec![(point_on_line, point_on_ellipse); 2],
+        LineEllipseIntersection::Secant {
+            points_on_line,
+            points_on_ellipse,
+        } => points_on_line.into_iter().zip(points_on_ellipse).collect(),
     }
 }
 
+/// Determine whether an ellipse coordinate is within the boundary of an arc
+///
+/// Ellipse coordinates are periodic, so the boundary can be outside of the
+/// range that [`Ellipse::point_to_ellipse_coords`] returns.
+fn is_within_arc(point: Point<1>, boundary: [Point<1>; 2]) -> bool {
+    let [start, end] = boundary.map(|point| point.t);
+    let (min, max) = if start <= end {
+        (start, end)
+    } else {
+        (end, start)
+    };
+
+    [-Scalar::TAU, Scalar::ZERO, Scalar::TAU]
+        .into_iter()
+        .map(|offset| point.t + offset)
+        .any(|t| t >= min && t <= max)
+}
+
 #[cfg(test)]
 mod tests {
     use fj_math::Point;
@@ -83,18 +197,18 @@ mod tests {
     use super::CurveEdgeIntersection;
 
     #[test]
-    fn compute_edge_in_front_of_curve_origin() {
+    fn compute_edge_in_front_of_curve_origin() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let curve = Handle::<Curve>::partial()
             .with_surface(Some(surface.clone()))
             .as_u_axis()
-            .build(&objects);
+            .build(&objects)?;
         let half_edge = HalfEdge::partial()
             .with_surface(Some(surface))
-            .as_line_segment_from_points([[1., -1.], [1., 1.]])
-            .build(&objects);
+            .as_line_segment_from_points([[1., -1.], [1., 1.]])?
+            .build(&objects)?;
 
         let intersection = CurveEdgeIntersection::compute(&curve, &half_edge);
 
@@ -104,21 +218,23 @@ mod tests {
                 point_on_curve: Point::from([1.])
             })
         );
+
+        Ok(())
     }
 
     #[test]
-    fn compute_edge_behind_curve_origin() {
+    fn compute_edge_behind_curve_origin() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let curve = Handle::<Curve>::partial()
             .with_surface(Some(surface.clone()))
             .as_u_axis()
-            .build(&objects);
+            .build(&objects)?;
         let half_edge = HalfEdge::partial()
             .with_surface(Some(surface))
-            .as_line_segment_from_points([[-1., -1.], [-1., 1.]])
-            .build(&objects);
+            .as_line_segment_from_points([[-1., -1.], [-1., 1.]])?
+            .build(&objects)?;
 
         let intersection = CurveEdgeIntersection::compute(&curve, &half_edge);
 
@@ -128,40 +244,44 @@ mod tests {
                 point_on_curve: Point::from([-1.])
             })
         );
+
+        Ok(())
     }
 
     #[test]
-    fn compute_edge_parallel_to_curve() {
+    fn compute_edge_parallel_to_curve() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let curve = Handle::<Curve>::partial()
             .with_surface(Some(surface.clone()))
             .as_u_axis()
-            .build(&objects);
+            .build(&objects)?;
         let half_edge = HalfEdge::partial()
             .with_surface(Some(surface))
-            .as_line_segment_from_points([[-1., -1.], [1., -1.]])
-            .build(&objects);
+            .as_line_segment_from_points([[-1., -1.], [1., -1.]])?
+            .build(&objects)?;
 
         let intersection = CurveEdgeIntersection::compute(&curve, &half_edge);
 
         assert!(intersection.is_none());
+
+        Ok(())
     }
 
     #[test]
-    fn compute_edge_on_curve() {
+    fn compute_edge_on_curve() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let curve = Handle::<Curve>::partial()
             .with_surface(Some(surface.clone()))
             .as_u_axis()
-            .build(&objects);
+            .build(&objects)?;
         let half_edge = HalfEdge::partial()
             .with_surface(Some(surface))
-            .as_line_segment_from_points([[-1., 0.], [1., 0.]])
-            .build(&objects);
+            .as_line_segment_from_points([[-1., 0.], [1., 0.]])?
+            .build(&objects)?;
 
         let intersection = CurveEdgeIntersection::compute(&curve, &half_edge);
 
@@ -171,5 +291,85 @@ mod tests {
                 points_on_curve: [Point::from([-1.]), Point::from([1.]),]
             })
         );
+
+        Ok(())
+    }
+
+    #[test]
+    fn compute_edge_is_circle() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let curve = Handle::<Curve>::partial()
+            .with_surface(Some(surface.clone()))
+            .as_u_axis()
+            .build(&objects)?;
+        let half_edge = HalfEdge::partial()
+            .with_surface(Some(surface))
+            .as_circle_from_radius(1.)?
+            .build(&objects)?;
+
+        let intersection = CurveEdgeIntersection::compute(&curve, &half_edge);
+
+        assert_eq!(
+            intersection,
+            Some(CurveEdgeIntersection::TwoPoints {
+                points_on_curve: [Point::from([-1.]), Point::from([1.])]
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn compute_edge_is_arc() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let half_edge = HalfEdge::partial()
+            .with_surface(Some(surface.clone()))
+            .as_arc_from_points_and_radius([[1., 0.], [-1., 0.]], 1.)
+            .build(&objects)?;
+
+        // The arc is the upper half of a circle, so only lines above its
+        // center intersect it.
+        for (v, expected) in [(0.5, true), (-0.5, false)] {
+            let curve = Handle::<Curve>::partial()
+                .with_surface(Some(surface.clone()))
+                .as_line_from_points([[0., v], [1., v]])
+                .build(&objects)?;
+
+            let intersection =
+                CurveEdgeIntersection::compute(&curve, &half_edge);
+            assert_eq!(intersection.is_some(), expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn compute_curve_is_circle() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let curve = Handle::<Curve>::partial()
+            .with_surface(Some(surface.clone()))
+            .as_circle_from_radius(1.)
+            .build(&objects)?;
+        let half_edge = HalfEdge::partial()
+            .with_surface(Some(surface))
+            .as_line_segment_from_points([[0., 0.], [2., 0.]])?
+            .build(&objects)?;
+
+        let intersection = CurveEdgeIntersection::compute(&curve, &half_edge);
+
+        assert_eq!(
+            intersection,
+            Some(CurveEdgeIntersection::Point {
+                point_on_curve: Point::from([0.])
+            })
+        );
+
+        Ok(())
     }
 }
@@ -1,10 +1,13 @@
 use std::vec;
 
-use fj_math::Point;
+use fj_math::{Point, Scalar};
 
-use crate::objects::{Curve, Face};
+use crate::{
+    objects::{Curve, Face},
+    path::SurfacePath,
+};
 
-use super::CurveEdgeIntersection;
+use super::{CurveEdgeIntersection, Intersect};
 
 /// The intersections between a [`Curve`] and a [`Face`], in curve coordinates
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -27,6 +30,9 @@ impl CurveFaceIntersection {
     }
 
     /// Compute the intersections between a [`Curve`] and a [`Face`]
+    ///
+    /// If the curve is closed, like a circle, the intervals are within a
+    /// single period of it, starting at `0.`.
     pub fn compute(curve: &Curve, face: &Face) -> Self {
         let half_edges = face.all_cycles().flat_map(|cycle| cycle.half_edges());
 
@@ -40,7 +46,8 @@ impl CurveFaceIntersection {
                     CurveEdgeIntersection::Point { point_on_curve } => {
                         intersections.push(point_on_curve);
                     }
-                    CurveEdgeIntersection::Coincident { points_on_curve } => {
+                    CurveEdgeIntersection::TwoPoints { points_on_curve }
+                    | CurveEdgeIntersection::Coincident { points_on_curve } => {
                         intersections.extend(points_on_curve);
                     }
                 }
@@ -51,6 +58,26 @@ impl CurveFaceIntersection {
 
         intersections.sort();
 
+        if let Some(period) = period_of(curve.path()) {
+            // The curve is closed, so it might start inside of the face. Then
+            // the first and last intersections are where it leaves and enters
+            // the face, and the start and end of the curve are boundaries of
+            // intervals too.
+            //
+            // We can find out by checking a point between the last and the
+            // first intersection, in the part of the curve that wraps around.
+            let gap = match (intersections.first(), intersections.last()) {
+                (Some(first), Some(last)) => (first.t + last.t + period) / 2.,
+                _ => Scalar::ZERO,
+            };
+            let point = curve.path().point_from_path_coords([gap % period]);
+
+            if (face, &point).intersect().is_some() {
+                intersections.insert(0, Point::from([Scalar::ZERO]));
+                intersections.push(Point::from([period]));
+            }
+        }
+
         // Can be cleaned up, once `array_chunks` is stable:
         // https://doc.rust-lang.org/std/primitive.slice.html#method.array_chunks
         let intervals = intersections
@@ -133,6 +160,16 @@ impl IntoIterator for CurveFaceIntersection {
     }
 }
 
+/// Compute the period of a closed path, in path coordinates
+///
+/// Returns `None`, if the path is not closed.
+fn period_of(path: &SurfacePath) -> Option<Scalar> {
+    match path {
+        SurfacePath::Circle(_) | SurfacePath::Ellipse(_) => Some(Scalar::TAU),
+        _ => None,
+    }
+}
+
 /// An intersection between a curve and a face
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct CurveFaceIntersectionInterval {
@@ -155,6 +192,8 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::f64::consts::{FRAC_PI_2, TAU};
+
     use crate::{
         objects::{Curve, Face, Objects, Surface},
         partial::HasPartial,
@@ -164,7 +203,7 @@ mod tests {
     use super::CurveFaceIntersection;
 
     #[test]
-    fn compute() {
+    fn compute() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
@@ -172,7 +211,7 @@ mod tests {
         let curve = Handle::<Curve>::partial()
             .with_surface(Some(surface.clone()))
             .as_line_from_points([[-3., 0.], [-2., 0.]])
-            .build(&objects);
+            .build(&objects)?;
 
         #[rustfmt::skip]
         let exterior = [
@@ -192,11 +231,13 @@ mod tests {
         let face = Face::builder(&objects, surface)
             .with_exterior_polygon_from_points(exterior)
             .with_interior_polygon_from_points(interior)
-            .build();
+            .build()?;
 
         let expected =
             CurveFaceIntersection::from_intervals([[[1.], [2.]], [[4.], [5.]]]);
         assert_eq!(CurveFaceIntersection::compute(&curve, &face), expected);
+
+        Ok(())
     }
 
     #[test]
@@ -268,4 +309,50 @@ mod tests {
         ]);
         assert_eq!(merged, expected);
     }
+
+    #[test]
+    fn compute_closed_curve() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+
+        let curve = Handle::<Curve>::partial()
+            .with_surface(Some(surface.clone()))
+            .as_circle_from_radius(1.)
+            .build(&objects)?;
+
+        // A face that contains the whole circle.
+        let face = Face::builder(&objects, surface.clone())
+            .with_exterior_polygon_from_points([
+                [-2., -2.],
+                [2., -2.],
+                [2., 2.],
+                [-2., 2.],
+            ])
+            .build()?;
+        assert_eq!(
+            CurveFaceIntersection::compute(&curve, &face),
+            CurveFaceIntersection::from_intervals([[[0.], [TAU]]])
+        );
+
+        // A face that contains the right half of the circle, where the
+        // circle starts and ends.
+        let face = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([
+                [0., -2.],
+                [2., -2.],
+                [2., 2.],
+                [0., 2.],
+            ])
+            .build()?;
+        assert_eq!(
+            CurveFaceIntersection::compute(&curve, &face),
+            CurveFaceIntersection::from_intervals([
+                [[0.], [FRAC_PI_2]],
+                [[FRAC_PI_2 * 3.], [TAU]]
+            ])
+        );
+
+        Ok(())
+    }
 }
@@ -24,37 +24,50 @@ pub struct FaceFaceIntersection {
 
 impl FaceFaceIntersection {
     /// Compute the intersections between two faces
-    pub fn compute(faces: [&Face; 2], objects: &Objects) -> Option<Self> {
+    ///
+    /// Two faces can intersect along multiple curves. Returns one
+    /// intersection per curve, in the order they are returned by
+    /// [`SurfaceSurfaceIntersection`].
+    pub fn compute(faces: [&Face; 2], objects: &Objects) -> Vec<Self> {
         let surfaces = faces.map(|face| face.surface().clone());
 
         let intersection_curves =
-            SurfaceSurfaceIntersection::compute(surfaces, objects)?
-                .intersection_curves;
-
-        // Can be cleaned up, once `zip` is stable:
-        // https://doc.rust-lang.org/std/primitive.array.html#method.zip
-        let curve_face_intersections = {
-            let [curve_a, curve_b] = &intersection_curves;
-            let [face_a, face_b] = faces;
-
-            [(curve_a, face_a), (curve_b, face_b)].map(|(curve, face)| {
-                CurveFaceIntersection::compute(curve, face)
+            match SurfaceSurfaceIntersection::compute(surfaces, objects) {
+                Some(intersection) => intersection.intersection_curves,
+                None => return Vec::new(),
+            };
+
+        intersection_curves
+            .into_iter()
+            .filter_map(|intersection_curves| {
+                // Can be cleaned up, once `zip` is stable:
+                // https://doc.rust-lang.org/std/primitive.array.html#method.zip
+                let curve_face_intersections = {
+                    let [curve_a, curve_b] = &intersection_curves;
+                    let [face_a, face_b] = faces;
+
+                    [(curve_a, face_a), (curve_b, face_b)].map(
+                        |(curve, face)| {
+                            CurveFaceIntersection::compute(curve, face)
+                        },
+                    )
+                };
+
+                let intersection_intervals = {
+                    let [a, b] = curve_face_intersections;
+                    a.merge(&b)
+                };
+
+                if intersection_intervals.is_empty() {
+                    return None;
+                }
+
+                Some(Self {
+                    intersection_curves,
+                    intersection_intervals,
+                })
             })
-        };
-
-        let intersection_intervals = {
-            let [a, b] = curve_face_intersections;
-            a.merge(&b)
-        };
-
-        if intersection_intervals.is_empty() {
-            return None;
-        }
-
-        Some(Self {
-            intersection_curves,
-            intersection_intervals,
-        })
+            .collect()
     }
 }
 
@@ -72,7 +85,7 @@ mod tests {
     use super::FaceFaceIntersection;
 
     #[test]
-    fn compute_no_intersection() {
+    fn compute_no_intersection() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         #[rustfmt::skip]
@@ -89,14 +102,17 @@ mod tests {
                     .with_exterior_polygon_from_points(points)
                     .build()
             });
+        let [a, b] = [a?, b?];
 
         let intersection = FaceFaceIntersection::compute([&a, &b], &objects);
 
-        assert!(intersection.is_none());
+        assert!(intersection.is_empty());
+
+        Ok(())
     }
 
     #[test]
-    fn compute_one_intersection() {
+    fn compute_one_intersection() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         #[rustfmt::skip]
@@ -113,23 +129,27 @@ mod tests {
                 .with_exterior_polygon_from_points(points)
                 .build()
         });
+        let [a, b] = [a?, b?];
 
         let intersection = FaceFaceIntersection::compute([&a, &b], &objects);
 
-        let expected_curves = surfaces.map(|surface| {
+        let [a, b] = surfaces.map(|surface| {
             Handle::<Curve>::partial()
                 .with_surface(Some(surface))
                 .as_line_from_points([[0., 0.], [1., 0.]])
                 .build(&objects)
         });
+        let expected_curves = [a?, b?];
         let expected_intervals =
             CurveFaceIntersection::from_intervals([[[-1.], [1.]]]);
         assert_eq!(
             intersection,
-            Some(FaceFaceIntersection {
+            vec![FaceFaceIntersection {
                 intersection_curves: expected_curves,
                 intersection_intervals: expected_intervals
-            })
+            }]
         );
+
+        Ok(())
     }
 }
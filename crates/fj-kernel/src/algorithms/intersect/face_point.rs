@@ -138,27 +138,29 @@ mod tests {
     };
 
     #[test]
-    fn point_is_outside_face() {
+    fn point_is_outside_face() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let face = Face::builder(&objects, surface)
             .with_exterior_polygon_from_points([[0., 0.], [1., 1.], [0., 2.]])
-            .build();
+            .build()?;
         let point = Point::from([2., 1.]);
 
         let intersection = (&face, &point).intersect();
         assert_eq!(intersection, None);
+
+        Ok(())
     }
 
     #[test]
-    fn ray_hits_vertex_while_passing_outside() {
+    fn ray_hits_vertex_while_passing_outside() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let face = Face::builder(&objects, surface)
             .with_exterior_polygon_from_points([[0., 0.], [2., 1.], [0., 2.]])
-            .build();
+            .build()?;
         let point = Point::from([1., 1.]);
 
         let intersection = (&face, &point).intersect();
@@ -166,16 +168,18 @@ mod tests {
             intersection,
             Some(FacePointIntersection::PointIsInsideFace)
         );
+
+        Ok(())
     }
 
     #[test]
-    fn ray_hits_vertex_at_cycle_seam() {
+    fn ray_hits_vertex_at_cycle_seam() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let face = Face::builder(&objects, surface)
             .with_exterior_polygon_from_points([[4., 2.], [0., 4.], [0., 0.]])
-            .build();
+            .build()?;
         let point = Point::from([1., 2.]);
 
         let intersection = (&face, &point).intersect();
@@ -183,10 +187,12 @@ mod tests {
             intersection,
             Some(FacePointIntersection::PointIsInsideFace)
         );
+
+        Ok(())
     }
 
     #[test]
-    fn ray_hits_vertex_while_staying_inside() {
+    fn ray_hits_vertex_while_staying_inside() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
@@ -197,7 +203,7 @@ mod tests {
                 [3., 0.],
                 [3., 4.],
             ])
-            .build();
+            .build()?;
         let point = Point::from([1., 1.]);
 
         let intersection = (&face, &point).intersect();
@@ -205,10 +211,13 @@ mod tests {
             intersection,
             Some(FacePointIntersection::PointIsInsideFace)
         );
+
+        Ok(())
     }
 
     #[test]
-    fn ray_hits_parallel_edge_and_leaves_face_at_vertex() {
+    fn ray_hits_parallel_edge_and_leaves_face_at_vertex() -> anyhow::Result<()>
+    {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
@@ -219,7 +228,7 @@ mod tests {
                 [3., 1.],
                 [0., 2.],
             ])
-            .build();
+            .build()?;
         let point = Point::from([1., 1.]);
 
         let intersection = (&face, &point).intersect();
@@ -227,10 +236,13 @@ mod tests {
             intersection,
             Some(FacePointIntersection::PointIsInsideFace)
         );
+
+        Ok(())
     }
 
     #[test]
-    fn ray_hits_parallel_edge_and_does_not_leave_face_there() {
+    fn ray_hits_parallel_edge_and_does_not_leave_face_there(
+    ) -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
@@ -242,7 +254,7 @@ mod tests {
                 [4., 0.],
                 [4., 5.],
             ])
-            .build();
+            .build()?;
         let point = Point::from([1., 1.]);
 
         let intersection = (&face, &point).intersect();
@@ -250,16 +262,18 @@ mod tests {
             intersection,
             Some(FacePointIntersection::PointIsInsideFace)
         );
+
+        Ok(())
     }
 
     #[test]
-    fn point_is_coincident_with_edge() {
+    fn point_is_coincident_with_edge() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let face = Face::builder(&objects, surface)
             .with_exterior_polygon_from_points([[0., 0.], [2., 0.], [0., 1.]])
-            .build();
+            .build()?;
         let point = Point::from([1., 0.]);
 
         let intersection = (&face, &point).intersect();
@@ -276,16 +290,18 @@ mod tests {
             intersection,
             Some(FacePointIntersection::PointIsOnEdge(edge.clone()))
         );
+
+        Ok(())
     }
 
     #[test]
-    fn point_is_coincident_with_vertex() {
+    fn point_is_coincident_with_vertex() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let face = Face::builder(&objects, surface)
             .with_exterior_polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])
-            .build();
+            .build()?;
         let point = Point::from([1., 0.]);
 
         let intersection = (&face, &point).intersect();
@@ -300,5 +316,7 @@ mod tests {
             intersection,
             Some(FacePointIntersection::PointIsOnVertex(vertex.clone()))
         );
+
+        Ok(())
     }
 }
@@ -0,0 +1,125 @@
+use fj_math::{Ellipse, Line, Point, Scalar};
+
+/// An intersection between a [`Line`] and an [`Ellipse`]
+#[derive(Debug, Eq, PartialEq)]
+pub enum LineEllipseIntersection {
+    /// Line touches the ellipse at a single point
+    Tangent {
+        /// The intersection point, given as a coordinate on the line
+        point_on_line: Point<1>,
+
+        /// The intersection point, given as a coordinate on the ellipse
+        point_on_ellipse: Point<1>,
+    },
+
+    /// Line crosses the ellipse at two points
+    Secant {
+        /// The intersection points, given as coordinates on the line
+        ///
+        /// The points are ordered along the line.
+        points_on_line: [Point<1>; 2],
+
+        /// The intersection points, given as coordinates on the ellipse
+        ///
+        /// The points are in the same order as `points_on_line`.
+        points_on_ellipse: [Point<1>; 2],
+    },
+}
+
+impl LineEllipseIntersection {
+    /// Determine the intersection between a [`Line`] and an [`Ellipse`]
+    ///
+    /// Circles can be passed by converting them into an [`Ellipse`] first.
+    pub fn compute(line: &Line<2>, ellipse: &Ellipse<2>) -> Option<Self> {
+        // Transform the line into the coordinate system of the ellipse, in
+        // which the ellipse becomes the unit circle.
+        let [x0, y0] =
+            ellipse.vector_to_components(line.origin() - ellipse.center());
+        let [x1, y1] = ellipse.vector_to_components(line.direction());
+
+        // Now we need to solve `(x0 + x1 * t)^2 + (y0 + y1 * t)^2 = 1`.
+        let qa = x1 * x1 + y1 * y1;
+        let qb = (x0 * x1 + y0 * y1) * 2.;
+        let qc = x0 * x0 + y0 * y0 - 1.;
+
+        let discriminant = qb * qb - qa * qc * 4.;
+
+        if discriminant < Scalar::ZERO {
+            return None;
+        }
+
+        let point_on_ellipse = |t: Scalar| {
+            ellipse.point_to_ellipse_coords(line.point_from_line_coords([t]))
+        };
+
+        if discriminant == Scalar::ZERO {
+            let t = -qb / (qa * 2.);
+
+            return Some(Self::Tangent {
+                point_on_line: Point::from([t]),
+                point_on_ellipse: point_on_ellipse(t),
+            });
+        }
+
+        let root = discriminant.sqrt();
+        let mut ts = [(-qb - root) / (qa * 2.), (-qb + root) / (qa * 2.)];
+        ts.sort();
+
+        Some(Self::Secant {
+            points_on_line: ts.map(|t| Point::from([t])),
+            points_on_ellipse: ts.map(point_on_ellipse),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use fj_math::{Ellipse, Line, Point, Vector};
+
+    use super::LineEllipseIntersection;
+
+    #[test]
+    fn compute_secant() {
+        let line =
+            Line::from_origin_and_direction(Point::origin(), Vector::unit_u());
+        let ellipse = Ellipse::from_center_and_radii([0., 0.], [2., 1.]);
+
+        assert_eq!(
+            LineEllipseIntersection::compute(&line, &ellipse),
+            Some(LineEllipseIntersection::Secant {
+                points_on_line: [Point::from([-2.]), Point::from([2.])],
+                points_on_ellipse: [Point::from([PI]), Point::from([0.])],
+            })
+        );
+    }
+
+    #[test]
+    fn compute_tangent() {
+        let line = Line::from_origin_and_direction(
+            Point::from([0., 1.]),
+            Vector::unit_u(),
+        );
+        let ellipse = Ellipse::from_center_and_radii([0., 0.], [2., 1.]);
+
+        assert_eq!(
+            LineEllipseIntersection::compute(&line, &ellipse),
+            Some(LineEllipseIntersection::Tangent {
+                point_on_line: Point::from([0.]),
+                point_on_ellipse: Point::from([FRAC_PI_2]),
+            })
+        );
+    }
+
+    #[test]
+    fn compute_no_intersection() {
+        let line = Line::from_origin_and_direction(
+            Point::from([0., 2.]),
+            Vector::unit_u(),
+        );
+        let ellipse = Ellipse::from_center_and_radii([0., 0.], [2., 1.]);
+
+        assert_eq!(LineEllipseIntersection::compute(&line, &ellipse), None);
+    }
+}
@@ -4,19 +4,25 @@ pub mod face_point;
 pub mod ray_edge;
 pub mod ray_face;
 pub mod ray_segment;
+pub mod ray_shell;
+pub mod solid_point;
 
+mod curve_curve;
 mod curve_edge;
 mod curve_face;
 mod face_face;
+mod line_ellipse;
 mod line_segment;
 mod surface_surface;
 
 use fj_math::{Point, Vector};
 
 pub use self::{
+    curve_curve::CurveCurveIntersection,
     curve_edge::CurveEdgeIntersection,
     curve_face::{CurveFaceIntersection, CurveFaceIntersectionInterval},
     face_face::FaceFaceIntersection,
+    line_ellipse::LineEllipseIntersection,
     line_segment::LineSegmentIntersection,
     surface_surface::SurfaceSurfaceIntersection,
 };
@@ -35,6 +41,32 @@ pub trait Intersect {
     fn intersect(self) -> Option<Self::Intersection>;
 }
 
+/// A ray that starts at a point and goes into a direction
+///
+/// The direction doesn't need to be of unit length. Distances along the ray are
+/// still measured in model units.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Ray<const D: usize> {
+    /// The point where the ray originates
+    pub origin: Point<D>,
+
+    /// The direction of the ray
+    pub direction: Vector<D>,
+}
+
+impl<const D: usize> Ray<D> {
+    /// Create a ray from its origin and direction
+    pub fn new(
+        origin: impl Into<Point<D>>,
+        direction: impl Into<Vector<D>>,
+    ) -> Self {
+        Self {
+            origin: origin.into(),
+            direction: direction.into(),
+        }
+    }
+}
+
 /// A horizontal ray that goes to the right
 ///
 /// For in-kernel use, we don't need anything more flexible, and being exactly
@@ -17,10 +17,28 @@ impl Intersect for (&HorizontalRayToTheRight<2>, &HalfEdge) {
         let (ray, edge) = self;
 
         let line = match edge.curve().path() {
-            SurfacePath::Line(line) => line,
+            SurfacePath::Line(line) => *line,
             SurfacePath::Circle(_) => {
                 todo!("Casting rays against circles is not supported yet")
             }
+            SurfacePath::Ellipse(_) => {
+                todo!("Casting rays against ellipses is not supported yet")
+            }
+            SurfacePath::Parabola(_) | SurfacePath::Hyperbola(_) => {
+                todo!(
+                    "Casting rays against parabolas or hyperbolas is not \
+                    supported yet"
+                )
+            }
+            SurfacePath::Polyline(_) => {
+                todo!("Casting rays against polylines is not supported yet")
+            }
+            SurfacePath::Bezier(_) => {
+                todo!("Casting rays against Bézier curves is not supported yet")
+            }
+            SurfacePath::Nurbs(_) => {
+                todo!("Casting rays against NURBS curves is not supported yet")
+            }
         };
 
         let points = edge.vertices().clone().map(|vertex| {
@@ -4,11 +4,12 @@ use fj_math::{Plane, Point, Scalar};
 
 use crate::{
     algorithms::intersect::face_point::FacePointIntersection,
+    geometry::{SurfaceGeometry, SweptCurve},
     objects::{Face, HalfEdge, Vertex},
     path::GlobalPath,
 };
 
-use super::{HorizontalRayToTheRight, Intersect};
+use super::{HorizontalRayToTheRight, Intersect, Ray};
 
 impl Intersect for (&HorizontalRayToTheRight<3>, &Face) {
     type Intersection = RayFaceIntersection;
@@ -16,16 +17,7 @@ impl Intersect for (&HorizontalRayToTheRight<3>, &Face) {
     fn intersect(self) -> Option<Self::Intersection> {
         let (ray, face) = self;
 
-        let plane = match face.surface().u() {
-            GlobalPath::Circle(_) => todo!(
-                "Casting a ray against a swept circle is not supported yet"
-            ),
-            GlobalPath::Line(line) => Plane::from_parametric(
-                line.origin(),
-                line.direction(),
-                face.surface().v(),
-            ),
-        };
+        let plane = plane_of(face);
 
         if plane.is_parallel_to_vector(&ray.direction()) {
             let a = plane.origin();
@@ -105,23 +97,99 @@ impl Intersect for (&HorizontalRayToTheRight<3>, &Face) {
             return None;
         }
 
-        let point = Point::from([u, v]);
-        let intersection = match (face, &point).intersect()? {
-            FacePointIntersection::PointIsInsideFace => {
-                RayFaceIntersection::RayHitsFace
-            }
-            FacePointIntersection::PointIsOnEdge(edge) => {
-                RayFaceIntersection::RayHitsEdge(edge)
-            }
-            FacePointIntersection::PointIsOnVertex(vertex) => {
-                RayFaceIntersection::RayHitsVertex(vertex)
+        locate(face, Point::from([u, v]))
+    }
+}
+
+impl Intersect for (&Ray<3>, &Face) {
+    type Intersection = RayFaceHit;
+
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (ray, face) = self;
+
+        let plane = plane_of(face);
+        let (distance, normal) = plane.constant_normal_form();
+
+        let denominator = normal.dot(&ray.direction);
+        if denominator == Scalar::ZERO {
+            let offset = distance - normal.dot(&ray.origin.coords);
+            if offset != Scalar::ZERO {
+                return None;
             }
-        };
 
-        Some(intersection)
+            return Some(RayFaceHit {
+                kind: RayFaceIntersection::RayHitsFaceAndAreParallel,
+                point: ray.origin,
+                distance: Scalar::ZERO,
+            });
+        }
+
+        let t = (distance - normal.dot(&ray.origin.coords)) / denominator;
+        if t < Scalar::ZERO {
+            // Ray points away from plane.
+            return None;
+        }
+
+        let point = ray.origin + ray.direction * t;
+        let kind = locate(face, plane.point_to_plane_coords(point))?;
+
+        Some(RayFaceHit {
+            kind,
+            point,
+            distance: ray.direction.magnitude() * t,
+        })
+    }
+}
+
+/// A hit between a ray and a face, including where it happens
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RayFaceHit {
+    /// What the ray hits
+    pub kind: RayFaceIntersection,
+
+    /// The point where the ray hits the face
+    ///
+    /// If the ray lies in the plane of the face, this is the origin of the ray.
+    pub point: Point<3>,
+
+    /// The distance from the origin of the ray to the hit
+    pub distance: Scalar,
+}
+
+/// Compute the plane of a face
+///
+/// # Panics
+///
+/// Panics, if the face is not planar.
+pub(super) fn plane_of(face: &Face) -> Plane {
+    match face.surface().geometry() {
+        SurfaceGeometry::SweptCurve(SweptCurve {
+            u: GlobalPath::Line(line),
+            v,
+        }) => Plane::from_parametric(line.origin(), line.direction(), *v),
+        _ => {
+            todo!("Casting a ray against a curved surface is not supported yet")
+        }
     }
 }
 
+/// Determine what part of the face, if any, a point in its surface is on
+fn locate(face: &Face, point: Point<2>) -> Option<RayFaceIntersection> {
+    let intersection = match (face, &point).intersect()? {
+        FacePointIntersection::PointIsInsideFace => {
+            RayFaceIntersection::RayHitsFace
+        }
+        FacePointIntersection::PointIsOnEdge(edge) => {
+            RayFaceIntersection::RayHitsEdge(edge)
+        }
+        FacePointIntersection::PointIsOnVertex(vertex) => {
+            RayFaceIntersection::RayHitsVertex(vertex)
+        }
+    };
+
+    Some(intersection)
+}
+
 /// A hit between a ray and a face
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[allow(clippy::large_enum_variant)]
@@ -141,13 +209,13 @@ pub enum RayFaceIntersection {
 
 #[cfg(test)]
 mod tests {
-    use fj_math::Point;
+    use fj_math::{Point, Scalar};
 
     use crate::{
         algorithms::{
             intersect::{
                 ray_face::RayFaceIntersection, HorizontalRayToTheRight,
-                Intersect,
+                Intersect, Ray,
             },
             transform::TransformObject,
         },
@@ -156,7 +224,7 @@ mod tests {
     };
 
     #[test]
-    fn ray_misses_whole_surface() {
+    fn ray_misses_whole_surface() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let ray = HorizontalRayToTheRight::from([0., 0., 0.]);
@@ -169,14 +237,16 @@ mod tests {
                 [1., 1.],
                 [-1., 1.],
             ])
-            .build()
-            .translate([-1., 0., 0.], &objects);
+            .build()?
+            .translate([-1., 0., 0.], &objects)?;
 
         assert_eq!((&ray, &face).intersect(), None);
+
+        Ok(())
     }
 
     #[test]
-    fn ray_hits_face() {
+    fn ray_hits_face() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let ray = HorizontalRayToTheRight::from([0., 0., 0.]);
@@ -189,17 +259,19 @@ mod tests {
                 [1., 1.],
                 [-1., 1.],
             ])
-            .build()
-            .translate([1., 0., 0.], &objects);
+            .build()?
+            .translate([1., 0., 0.], &objects)?;
 
         assert_eq!(
             (&ray, &face).intersect(),
             Some(RayFaceIntersection::RayHitsFace)
         );
+
+        Ok(())
     }
 
     #[test]
-    fn ray_hits_surface_but_misses_face() {
+    fn ray_hits_surface_but_misses_face() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let ray = HorizontalRayToTheRight::from([0., 0., 0.]);
@@ -212,14 +284,16 @@ mod tests {
                 [1., 1.],
                 [-1., 1.],
             ])
-            .build()
-            .translate([0., 0., 2.], &objects);
+            .build()?
+            .translate([0., 0., 2.], &objects)?;
 
         assert_eq!((&ray, &face).intersect(), None);
+
+        Ok(())
     }
 
     #[test]
-    fn ray_hits_edge() {
+    fn ray_hits_edge() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let ray = HorizontalRayToTheRight::from([0., 0., 0.]);
@@ -232,8 +306,8 @@ mod tests {
                 [1., 1.],
                 [-1., 1.],
             ])
-            .build()
-            .translate([1., 1., 0.], &objects);
+            .build()?
+            .translate([1., 1., 0.], &objects)?;
 
         let edge = face
             .half_edge_iter()
@@ -247,10 +321,12 @@ mod tests {
             (&ray, &face).intersect(),
             Some(RayFaceIntersection::RayHitsEdge(edge.clone()))
         );
+
+        Ok(())
     }
 
     #[test]
-    fn ray_hits_vertex() {
+    fn ray_hits_vertex() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let ray = HorizontalRayToTheRight::from([0., 0., 0.]);
@@ -263,8 +339,8 @@ mod tests {
                 [1., 1.],
                 [-1., 1.],
             ])
-            .build()
-            .translate([1., 1., 1.], &objects);
+            .build()?
+            .translate([1., 1., 1.], &objects)?;
 
         let vertex = face
             .vertex_iter()
@@ -276,10 +352,12 @@ mod tests {
             (&ray, &face).intersect(),
             Some(RayFaceIntersection::RayHitsVertex(vertex.clone()))
         );
+
+        Ok(())
     }
 
     #[test]
-    fn ray_is_parallel_to_surface_and_hits() {
+    fn ray_is_parallel_to_surface_and_hits() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let ray = HorizontalRayToTheRight::from([0., 0., 0.]);
@@ -292,16 +370,65 @@ mod tests {
                 [1., 1.],
                 [-1., 1.],
             ])
-            .build();
+            .build()?;
 
         assert_eq!(
             (&ray, &face).intersect(),
             Some(RayFaceIntersection::RayHitsFaceAndAreParallel)
-        )
+        );
+
+        Ok(())
     }
 
     #[test]
-    fn ray_is_parallel_to_surface_and_misses() {
+    fn ray_with_direction_hits_face() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let ray = Ray::new([0., 0., 0.], [1., 1., 0.]);
+
+        let surface = objects.surfaces.insert(Surface::yz_plane());
+        let face = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([
+                [0., -1.],
+                [2., -1.],
+                [2., 1.],
+                [0., 1.],
+            ])
+            .build()?
+            .translate([1., 0., 0.], &objects)?;
+
+        let hit = (&ray, &face).intersect().unwrap();
+        assert_eq!(hit.kind, RayFaceIntersection::RayHitsFace);
+        assert_eq!(hit.point, Point::from([1., 1., 0.]));
+        assert_eq!(hit.distance, Scalar::from_f64(2_f64.sqrt()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn ray_with_direction_points_away_from_face() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let ray = Ray::new([0., 0., 0.], [-1., 1., 0.]);
+
+        let surface = objects.surfaces.insert(Surface::yz_plane());
+        let face = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([
+                [0., -1.],
+                [2., -1.],
+                [2., 1.],
+                [0., 1.],
+            ])
+            .build()?
+            .translate([1., 0., 0.], &objects)?;
+
+        assert_eq!((&ray, &face).intersect(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ray_is_parallel_to_surface_and_misses() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let ray = HorizontalRayToTheRight::from([0., 0., 0.]);
@@ -314,9 +441,11 @@ mod tests {
                 [1., 1.],
                 [-1., 1.],
             ])
-            .build()
-            .translate([0., 0., 1.], &objects);
+            .build()?
+            .translate([0., 0., 1.], &objects)?;
+
+        assert_eq!((&ray, &face).intersect(), None);
 
-        assert_eq!((&ray, &face).intersect(), None)
+        Ok(())
     }
 }
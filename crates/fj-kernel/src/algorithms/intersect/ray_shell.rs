@@ -0,0 +1,104 @@
+//! Intersection between a ray and a shell, in 3D
+
+use crate::objects::{Face, Shell};
+
+use super::{ray_face::RayFaceHit, Intersect, Ray};
+
+impl Intersect for (&Ray<3>, &Shell) {
+    type Intersection = Vec<RayShellHit>;
+
+    /// Compute all hits between the ray and the faces of the shell
+    ///
+    /// The hits are sorted by their distance from the origin of the ray. A ray
+    /// that hits an edge or vertex is reported once for every face it touches
+    /// there.
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (ray, shell) = self;
+
+        let mut hits = shell
+            .faces()
+            .into_iter()
+            .filter_map(|face| {
+                let hit = (ray, face).intersect()?;
+                Some(RayShellHit {
+                    face: face.clone(),
+                    hit,
+                })
+            })
+            .collect::<Vec<_>>();
+        hits.sort_by_key(|hit| hit.hit.distance);
+
+        if hits.is_empty() {
+            return None;
+        }
+
+        Some(hits)
+    }
+}
+
+/// A hit between a ray and a face of a shell
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RayShellHit {
+    /// The face that the ray hits
+    pub face: Face,
+
+    /// The hit between the ray and the face
+    pub hit: RayFaceHit,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            intersect::{ray_face::RayFaceIntersection, Intersect, Ray},
+            test_helpers::cube_shell,
+        },
+        objects::Objects,
+    };
+
+    #[test]
+    fn ray_passes_through_shell() {
+        let objects = Objects::new();
+
+        let ray = Ray::new([-1., 0.5, 0.5], [1., 0., 0.]);
+        let hits = (&ray, &cube_shell(1., &objects)).intersect().unwrap();
+
+        let hits = hits
+            .iter()
+            .map(|hit| {
+                assert_eq!(hit.hit.kind, RayFaceIntersection::RayHitsFace);
+                (hit.hit.point, hit.hit.distance)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            hits,
+            [
+                (Point::from([0., 0.5, 0.5]), Scalar::ONE),
+                (Point::from([1., 0.5, 0.5]), Scalar::from_f64(2.)),
+            ]
+        );
+    }
+
+    #[test]
+    fn ray_from_inside_of_shell() {
+        let objects = Objects::new();
+
+        let ray = Ray::new([0.5, 0.5, 0.5], [0., 0., -1.]);
+        let hits = (&ray, &cube_shell(1., &objects)).intersect().unwrap();
+
+        // Only the bottom face is hit.
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].hit.point, Point::from([0.5, 0.5, 0.]));
+        assert_eq!(hits[0].hit.distance, Scalar::from_f64(0.5));
+    }
+
+    #[test]
+    fn ray_misses_shell() {
+        let objects = Objects::new();
+
+        let ray = Ray::new([-1., 0.5, 0.5], [-1., 0., 0.]);
+        assert_eq!((&ray, &cube_shell(1., &objects)).intersect(), None);
+    }
+}
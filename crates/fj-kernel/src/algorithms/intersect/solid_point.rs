@@ -0,0 +1,188 @@
+//! Intersection between solids and points in 3D
+
+use fj_math::{Point, Scalar, Vector};
+
+use crate::objects::{Face, HalfEdge, Solid, Vertex};
+
+use super::{
+    face_point::FacePointIntersection,
+    ray_face::{plane_of, RayFaceIntersection},
+    Intersect, Ray,
+};
+
+impl Intersect for (&Solid, &Point<3>) {
+    type Intersection = SolidPointIntersection;
+
+    /// Determine whether the point is inside, on, or outside of the solid
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the solid has any faces that are not planar. Neither
+    /// checking whether the point is on the boundary, nor casting rays against
+    /// curved surfaces is supported yet.
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (solid, point) = self;
+
+        let faces = solid
+            .shells()
+            .flat_map(|shell| shell.faces().into_iter())
+            .collect::<Vec<_>>();
+
+        for face in &faces {
+            let plane = plane_of(face);
+            let (distance, normal) = plane.constant_normal_form();
+
+            if (normal.dot(&point.coords) - distance).abs()
+                >= Scalar::from_f64(EPSILON)
+            {
+                continue;
+            }
+
+            let point_in_face = plane.point_to_plane_coords(*point);
+            let intersection = match (*face, &point_in_face).intersect() {
+                Some(FacePointIntersection::PointIsInsideFace) => {
+                    SolidPointIntersection::PointIsOnFace((*face).clone())
+                }
+                Some(FacePointIntersection::PointIsOnEdge(edge)) => {
+                    SolidPointIntersection::PointIsOnEdge(edge)
+                }
+                Some(FacePointIntersection::PointIsOnVertex(vertex)) => {
+                    SolidPointIntersection::PointIsOnVertex(vertex)
+                }
+                None => continue,
+            };
+
+            return Some(intersection);
+        }
+
+        // The point is not on the boundary of the solid. Cast a ray and count
+        // how often it crosses the boundary. If the ray hits an edge or vertex,
+        // or grazes along a face, the count would be ambiguous, so try another
+        // direction.
+        let mut num_hits = 0;
+        for direction in RAY_DIRECTIONS {
+            let ray = Ray::new(*point, Vector::from(direction));
+
+            let hits = faces
+                .iter()
+                .filter_map(|face| (&ray, *face).intersect())
+                .collect::<Vec<_>>();
+            num_hits = hits.len();
+
+            if hits
+                .iter()
+                .all(|hit| hit.kind == RayFaceIntersection::RayHitsFace)
+            {
+                break;
+            }
+        }
+
+        if num_hits % 2 == 0 {
+            return None;
+        }
+
+        Some(SolidPointIntersection::PointIsInsideSolid)
+    }
+}
+
+/// The intersection between a solid and a point
+///
+/// A point that is outside of the solid doesn't intersect it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum SolidPointIntersection {
+    /// The point is inside of the solid
+    PointIsInsideSolid,
+
+    /// The point is on a face of the solid
+    PointIsOnFace(Face),
+
+    /// The point is coincident with an edge
+    PointIsOnEdge(HalfEdge),
+
+    /// The point is coincident with a vertex
+    PointIsOnVertex(Vertex),
+}
+
+/// The distance from a face, below which a point counts as being on it
+const EPSILON: f64 = 1e-9;
+
+/// The directions of the rays used to determine whether a point is inside
+///
+/// These are chosen to be unlikely to be aligned with any edges or faces of
+/// typical models. If all of them result in ambiguous hits, the last count is
+/// used.
+const RAY_DIRECTIONS: [[f64; 3]; 4] = [
+    [0.61, 0.37, 0.71],
+    [-0.43, 0.79, 0.53],
+    [0.29, -0.83, 0.47],
+    [-0.67, -0.23, -0.71],
+];
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Vector};
+
+    use crate::{
+        algorithms::{
+            intersect::{solid_point::SolidPointIntersection, Intersect},
+            test_helpers::cube,
+        },
+        objects::Objects,
+    };
+
+    #[test]
+    fn point_is_inside_solid() {
+        let objects = Objects::new();
+        let solid = cube(1., &objects);
+
+        let point = Point::from([0.5, 0.5, 0.5]);
+        assert_eq!(
+            (&solid, &point).intersect(),
+            Some(SolidPointIntersection::PointIsInsideSolid)
+        );
+
+        let point = Point::from([1.5, 0.5, 0.5]);
+        assert_eq!((&solid, &point).intersect(), None);
+    }
+
+    #[test]
+    fn ray_through_edge_is_not_counted_twice() {
+        let objects = Objects::new();
+        let solid = cube(1., &objects);
+
+        // The first ray, cast from this point, passes through an edge of the
+        // cube. The point must still be classified correctly.
+        let direction = Vector::from([0.61, 0.37, 0.71]);
+        let point = Point::from([1., 0.5, 1.]) - direction * 0.25;
+
+        assert_eq!(
+            (&solid, &point).intersect(),
+            Some(SolidPointIntersection::PointIsInsideSolid)
+        );
+    }
+
+    #[test]
+    fn point_is_on_boundary_of_solid() {
+        let objects = Objects::new();
+        let solid = cube(1., &objects);
+
+        let on_face = Point::from([0.5, 0.5, 1.]);
+        assert!(matches!(
+            (&solid, &on_face).intersect(),
+            Some(SolidPointIntersection::PointIsOnFace(_))
+        ));
+
+        let on_edge = Point::from([0.5, 0., 1.]);
+        assert!(matches!(
+            (&solid, &on_edge).intersect(),
+            Some(SolidPointIntersection::PointIsOnEdge(_))
+        ));
+
+        let on_vertex = Point::from([1., 1., 1.]);
+        assert!(matches!(
+            (&solid, &on_vertex).intersect(),
+            Some(SolidPointIntersection::PointIsOnVertex(_))
+        ));
+    }
+}
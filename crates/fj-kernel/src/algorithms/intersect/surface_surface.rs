@@ -1,6 +1,10 @@
-use fj_math::{Line, Plane, Point, Scalar};
+use fj_math::{
+    Circle, Cone, Cylinder, Ellipse, Line, Plane, Point, PolyChain, Scalar,
+    Vector,
+};
 
 use crate::{
+    geometry::{SurfaceGeometry, SweptCurve},
     objects::{Curve, GlobalCurve, Objects, Surface},
     path::{GlobalPath, SurfacePath},
     storage::Handle,
@@ -10,7 +14,15 @@ use crate::{
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct SurfaceSurfaceIntersection {
     /// The intersection curves
-    pub intersection_curves: [Handle<Curve>; 2],
+    ///
+    /// Two surfaces can intersect in more than one curve. Each entry in this
+    /// list is one of those intersections, represented by two curves, one for
+    /// each of the input surfaces.
+    ///
+    /// The curves of an entry share their curve coordinates, meaning a given
+    /// curve coordinate refers to the same point in global coordinates on
+    /// both of them.
+    pub intersection_curves: Vec<[Handle<Curve>; 2]>,
 }
 
 impl SurfaceSurfaceIntersection {
@@ -19,84 +31,566 @@ impl SurfaceSurfaceIntersection {
         surfaces: [Handle<Surface>; 2],
         objects: &Objects,
     ) -> Option<Self> {
-        // Algorithm from Real-Time Collision Detection by Christer Ericson. See
-        // section 5.4.4, Intersection of Two Planes.
-        //
-        // Adaptations were made to get the intersection curves in local
-        // coordinates for each surface.
+        let [a, b] = surfaces.clone().map(|surface| Kind::of(&surface));
 
-        let surfaces_and_planes = surfaces.map(|surface| {
-            let plane = plane_from_surface(&surface);
-            (surface, plane)
-        });
-        let [a, b] = surfaces_and_planes.clone().map(|(_, plane)| plane);
-
-        let (a_distance, a_normal) = a.constant_normal_form();
-        let (b_distance, b_normal) = b.constant_normal_form();
-
-        let direction = a_normal.cross(&b_normal);
-
-        let denom = direction.dot(&direction);
-        if denom == Scalar::ZERO {
-            // Comparing `denom` against zero looks fishy. It's probably better
-            // to compare it against an epsilon value, but I don't know how
-            // large that epsilon should be.
-            //
-            // I'll just leave it like that, until we had the opportunity to
-            // collect some experience with this code.
-            // - @hannobraun
+        let paths = match (a, b) {
+            (Kind::Plane(a), Kind::Plane(b)) => {
+                plane_plane(&a, &b).into_iter().collect()
+            }
+            (Kind::Plane(plane), Kind::Cylinder(cylinder)) => {
+                plane_cylinder(&plane, &cylinder)
+            }
+            (Kind::Cylinder(cylinder), Kind::Plane(plane)) => {
+                plane_cylinder(&plane, &cylinder)
+                    .into_iter()
+                    .map(|[a, b]| [b, a])
+                    .collect()
+            }
+            (Kind::Cylinder(a), Kind::Cylinder(b)) => cylinder_cylinder(&a, &b),
+            (Kind::Plane(plane), Kind::Cone(cone)) => plane_cone(&plane, &cone),
+            (Kind::Cone(cone), Kind::Plane(plane)) => plane_cone(&plane, &cone)
+                .into_iter()
+                .map(|[a, b]| [b, a])
+                .collect(),
+            _ => todo!(
+                "Intersecting cones with anything but planes is not supported \
+                yet."
+            ),
+        };
+
+        if paths.is_empty() {
             return None;
         }
 
-        let origin = (b_normal * a_distance - a_normal * b_distance)
-            .cross(&direction)
-            / denom;
-        let origin = Point { coords: origin };
+        let intersection_curves = paths
+            .into_iter()
+            .map(|paths| {
+                // Can be cleaned up, once `zip` is stable:
+                // https://doc.rust-lang.org/std/primitive.array.html#method.zip
+                let [surface_a, surface_b] = surfaces.clone();
+                let [path_a, path_b] = paths;
 
-        let line = Line::from_origin_and_direction(origin, direction);
+                [(surface_a, path_a), (surface_b, path_b)].map(
+                    |(surface, path)| {
+                        let global_form = GlobalCurve::new(objects);
+                        Curve::new(surface, path, global_form, objects)
+                    },
+                )
+            })
+            .collect();
 
-        let curves = surfaces_and_planes.map(|(surface, plane)| {
-            let path = SurfacePath::Line(plane.project_line(&line));
-            let global_form = GlobalCurve::new(objects);
+        Some(Self {
+            intersection_curves,
+        })
+    }
+}
 
-            Curve::new(surface, path, global_form, objects)
-        });
+/// The kinds of surfaces that surface-surface intersection supports
+enum Kind {
+    Plane(Plane),
+    Cylinder(Cylinder),
+    Cone(Cone),
+}
 
-        Some(Self {
-            intersection_curves: curves,
+impl Kind {
+    fn of(surface: &Surface) -> Self {
+        match surface.geometry() {
+            SurfaceGeometry::SweptCurve(SweptCurve {
+                u: GlobalPath::Line(line),
+                v,
+            }) => Self::Plane(Plane::from_parametric(
+                line.origin(),
+                line.direction(),
+                *v,
+            )),
+            SurfaceGeometry::Cylinder(cylinder) => Self::Cylinder(*cylinder),
+            SurfaceGeometry::Cone(cone) => Self::Cone(*cone),
+            _ => todo!(
+                "Only planes, cylinders, and cones are currently supported by \
+                surface-surface intersection."
+            ),
+        }
+    }
+}
+
+fn plane_plane(a: &Plane, b: &Plane) -> Option<[SurfacePath; 2]> {
+    // Algorithm from Real-Time Collision Detection by Christer Ericson. See
+    // section 5.4.4, Intersection of Two Planes.
+    //
+    // Adaptations were made to get the intersection curves in local
+    // coordinates for each surface.
+
+    let (a_distance, a_normal) = a.constant_normal_form();
+    let (b_distance, b_normal) = b.constant_normal_form();
+
+    let direction = a_normal.cross(&b_normal);
+
+    let denom = direction.dot(&direction);
+    if denom == Scalar::ZERO {
+        // Comparing `denom` against zero looks fishy. It's probably better
+        // to compare it against an epsilon value, but I don't know how
+        // large that epsilon should be.
+        //
+        // I'll just leave it like that, until we had the opportunity to
+        // collect some experience with this code.
+        // - @hannobraun
+        return None;
+    }
+
+    let origin = (b_normal * a_distance - a_normal * b_distance)
+        .cross(&direction)
+        / denom;
+    let origin = Point { coords: origin };
+
+    let line = Line::from_origin_and_direction(origin, direction);
+
+    Some([a, b].map(|plane| SurfacePath::Line(plane.project_line(&line))))
+}
+
+/// Compute the intersection between a plane and a cylinder
+///
+/// Returns pairs of paths, the first on the plane, the second on the cylinder.
+fn plane_cylinder(plane: &Plane, cylinder: &Cylinder) -> Vec<[SurfacePath; 2]> {
+    let normal = plane.normal();
+    let axis = cylinder.axis().normalize();
+
+    if normal.cross(&axis).magnitude() < epsilon() {
+        // The plane is perpendicular to the cylinder axis. The intersection is
+        // a circle, which is a line of constant v-coordinate on the cylinder.
+        let v = (plane.origin() - cylinder.origin()).dot(&normal)
+            / cylinder.axis().dot(&normal);
+
+        let circle = Circle::new(
+            cylinder.origin() + cylinder.axis() * v,
+            cylinder.a(),
+            cylinder.b(),
+        );
+
+        let path_on_plane = circle_in_plane(plane, &circle);
+        let path_on_cylinder =
+            SurfacePath::Line(Line::from_origin_and_direction(
+                Point::from([Scalar::ZERO, v]),
+                Vector::unit_u(),
+            ));
+
+        return vec![[path_on_plane, path_on_cylinder]];
+    }
+
+    if normal.dot(&axis).abs() < epsilon() {
+        // The plane is parallel to the cylinder axis. The intersection
+        // consists of up to two lines that are parallel to the axis.
+        let distance = (cylinder.origin() - plane.origin()).dot(&normal);
+
+        return angles_of_lines(
+            [cylinder.a().dot(&normal), cylinder.b().dot(&normal)],
+            -distance,
+            cylinder.radius(),
+        )
+        .into_iter()
+        .map(|u| {
+            let origin = cylinder.point_from_cylinder_coords([u, Scalar::ZERO]);
+
+            let path_on_plane =
+                SurfacePath::Line(Line::from_origin_and_direction(
+                    plane.point_to_plane_coords(origin),
+                    plane.vector_to_plane_coords(cylinder.axis()),
+                ));
+            let path_on_cylinder =
+                SurfacePath::Line(Line::from_origin_and_direction(
+                    Point::from([u, Scalar::ZERO]),
+                    Vector::unit_v(),
+                ));
+
+            [path_on_plane, path_on_cylinder]
         })
+        .collect();
     }
+
+    // In the general case, the intersection is an ellipse. That is not a
+    // problem on the plane, but there's no path that could represent it on
+    // the cylinder. Both sides are approximated instead, by following the
+    // lines of constant u-coordinate on the cylinder to the plane.
+    let [ka, kb] = [cylinder.a(), cylinder.b()]
+        .map(|vector| normal.dot(&vector) / normal.dot(&cylinder.axis()));
+    let v0 = normal.dot(&(plane.origin() - cylinder.origin()))
+        / normal.dot(&cylinder.axis());
+
+    vec![approximate_closed_curve(|angle| {
+        let (sin, cos) = angle.sin_cos();
+        let point_on_cylinder = Point::from([angle, v0 - ka * cos - kb * sin]);
+        let point_on_plane = plane.point_to_plane_coords(
+            cylinder.point_from_cylinder_coords(point_on_cylinder),
+        );
+
+        [point_on_plane, point_on_cylinder]
+    })]
 }
 
-fn plane_from_surface(surface: &Surface) -> Plane {
-    let (line, path) = {
-        let line = match surface.u() {
-            GlobalPath::Line(line) => line,
-            _ => todo!("Only plane-plane intersection is currently supported."),
-        };
+/// Compute the intersection between a plane and a cone
+///
+/// Returns pairs of paths, the first on the plane, the second on the cone.
+fn plane_cone(plane: &Plane, cone: &Cone) -> Vec<[SurfacePath; 2]> {
+    let normal = plane.normal();
+    let axis = cone.axis().normalize();
+
+    if normal.cross(&axis).magnitude() < epsilon() {
+        // The plane is perpendicular to the cone axis. The intersection is a
+        // circle, which is a line of constant v-coordinate on the cone.
+        let v = (plane.origin() - cone.origin()).dot(&normal)
+            / cone.axis().dot(&normal);
+
+        let radius = cone.radius_at(v);
+        if radius.abs() < epsilon() {
+            // The plane touches the apex. The intersection is a single point,
+            // which is not a curve.
+            return Vec::new();
+        }
+
+        // Beyond the apex, the radius is negative. Scaling `a` and `b` by it
+        // keeps the circle coordinates identical to the u-coordinate of the
+        // cone.
+        let scale = radius / cone.base_radius();
+        let circle = Circle::new(
+            cone.origin() + cone.axis() * v,
+            cone.a() * scale,
+            cone.b() * scale,
+        );
+
+        let path_on_plane = circle_in_plane(plane, &circle);
+        let path_on_cone = SurfacePath::Line(Line::from_origin_and_direction(
+            Point::from([Scalar::ZERO, v]),
+            Vector::unit_u(),
+        ));
+
+        return vec![[path_on_plane, path_on_cone]];
+    }
+
+    if let Some(apex) = cone.apex() {
+        if (apex - plane.origin()).dot(&normal).abs() < epsilon() {
+            // The plane passes through the apex. The intersection consists of
+            // up to two lines of constant u-coordinate on the cone. Along
+            // those, the distance from the plane doesn't change, which gives
+            // us the equation to solve for u.
+            let scale = cone.slope() / cone.base_radius();
+
+            return angles_of_lines(
+                [cone.a().dot(&normal) * scale, cone.b().dot(&normal) * scale],
+                -cone.axis().dot(&normal),
+                cone.base_radius(),
+            )
+            .into_iter()
+            .map(|u| {
+                let origin = cone.point_from_cone_coords([u, Scalar::ZERO]);
+                let direction = cone.vector_from_cone_coords([u, Scalar::ONE])
+                    - cone.vector_from_cone_coords([u, Scalar::ZERO]);
+
+                let path_on_plane =
+                    SurfacePath::Line(Line::from_origin_and_direction(
+                        plane.point_to_plane_coords(origin),
+                        plane.vector_to_plane_coords(direction),
+                    ));
+                let path_on_cone =
+                    SurfacePath::Line(Line::from_origin_and_direction(
+                        Point::from([u, Scalar::ZERO]),
+                        Vector::unit_v(),
+                    ));
+
+                [path_on_plane, path_on_cone]
+            })
+            .collect();
+        }
+    }
+
+    // In the general case, the intersection is an ellipse, a parabola, or a
+    // hyperbola. Like with the oblique plane-cylinder intersection, there's no
+    // path that could represent those on the cone.
+    todo!(
+        "Intersecting a plane with a cone is only supported, if the plane is \
+        perpendicular to the axis, or passes through the apex."
+    )
+}
+
+/// Compute the intersection between two cylinders
+///
+/// Returns pairs of paths, the first on cylinder `a`, the second on `b`.
+fn cylinder_cylinder(a: &Cylinder, b: &Cylinder) -> Vec<[SurfacePath; 2]> {
+    let axis = a.axis().normalize();
+
+    if axis.cross(&b.axis().normalize()).magnitude() >= epsilon() {
+        return cylinder_cylinder_at_angle(a, b);
+    }
+
+    // The axes are parallel. The intersection consists of up to two lines
+    // that are parallel to both axes. We find those by intersecting the base
+    // circle of `a` with the cross-section of `b` in the same plane.
+    let offset = b.origin() - a.origin();
+    let offset = offset - axis * offset.dot(&axis);
+    let distance = offset.magnitude();
+
+    if distance == Scalar::ZERO {
+        // The cylinders are coaxial. They are either identical, or don't
+        // intersect at all. Neither case results in intersection curves.
+        return Vec::new();
+    }
+
+    // This is the equation of the radical line of the two circles, in the
+    // form `dot(x, direction) = c`, relative to the center of `a`.
+    let direction = offset / distance;
+    let c = (distance * distance + a.radius() * a.radius()
+        - b.radius() * b.radius())
+        / (distance * 2.);
+
+    let b_axis_scale = a.axis().dot(&b.axis()) / b.axis().dot(&b.axis());
+
+    angles_of_lines(
+        [a.a().dot(&direction), a.b().dot(&direction)],
+        c,
+        a.radius(),
+    )
+    .into_iter()
+    .map(|u| {
+        let point = a.point_from_cylinder_coords([u, Scalar::ZERO]);
+        let point_on_b = b.point_to_cylinder_coords(point);
+
+        let path_on_a = SurfacePath::Line(Line::from_origin_and_direction(
+            Point::from([u, Scalar::ZERO]),
+            Vector::unit_v(),
+        ));
+        let path_on_b = SurfacePath::Line(Line::from_origin_and_direction(
+            point_on_b,
+            Vector::from([Scalar::ZERO, b_axis_scale]),
+        ));
+
+        [path_on_a, path_on_b]
+    })
+    .collect()
+}
+
+/// Compute the intersection between two cylinders with non-parallel axes
+///
+/// In general, the intersection curves can't be represented exactly, so they
+/// are approximated.
+fn cylinder_cylinder_at_angle(
+    a: &Cylinder,
+    b: &Cylinder,
+) -> Vec<[SurfacePath; 2]> {
+    if let Some(paths) = cylinder_through_cylinder(a, b) {
+        return paths;
+    }
+    if let Some(paths) = cylinder_through_cylinder(b, a) {
+        return paths
+            .into_iter()
+            .map(|[path_on_b, path_on_a]| [path_on_a, path_on_b])
+            .collect();
+    }
+
+    todo!(
+        "Intersecting cylinders with non-parallel axes is only supported, if \
+        one of them passes all the way through the other."
+    )
+}
+
+/// Compute the intersection of cylinder `a` passing through cylinder `b`
+///
+/// Every line of constant u-coordinate on `a` crosses `b` twice. The
+/// intersection consists of two closed curves then, where `a` enters and
+/// leaves `b`. If none of the lines cross `b`, there is no intersection.
+///
+/// Returns `None`, if only some of those lines cross `b`.
+fn cylinder_through_cylinder(
+    a: &Cylinder,
+    b: &Cylinder,
+) -> Option<Vec<[SurfacePath; 2]>> {
+    let axis = b.axis().normalize();
+    let perpendicular = |vector: Vector<3>| vector - axis * vector.dot(&axis);
+
+    // Along the line with coordinate `u` on `a`, the squared distance to the
+    // axis of `b` is a quadratic function of the v-coordinate. These are the
+    // coefficients of `distance^2 - radius^2 = 0`.
+    let direction = perpendicular(a.axis());
+    let coefficients = |u: Scalar| {
+        let offset = perpendicular(
+            a.point_from_cylinder_coords([u, Scalar::ZERO]) - b.origin(),
+        );
+
+        let qa = direction.dot(&direction);
+        let qb = offset.dot(&direction) * 2.;
+        let qc = offset.dot(&offset) - b.radius() * b.radius();
+
+        [qa, qb, qc]
+    };
+    let discriminant = |[qa, qb, qc]: [Scalar; 3]| qb * qb - qa * qc * 4.;
+
+    let num_crossing = (0..num_segments())
+        .map(|i| Scalar::TAU * Scalar::from_u64(i) / num_segments() as f64)
+        .filter(|&u| discriminant(coefficients(u)) >= Scalar::ZERO)
+        .count();
+    if num_crossing == 0 {
+        return Some(Vec::new());
+    }
+    if num_crossing < num_segments() as usize {
+        return None;
+    }
+
+    let paths = [-Scalar::ONE, Scalar::ONE]
+        .map(|sign| {
+            // The u-coordinates of the curve on `b` can jump by a full turn,
+            // where they wrap around. Undo that, to keep the curve continuous.
+            let mut previous_on_b: Option<Scalar> = None;
+
+            approximate_closed_curve(|u| {
+                let [qa, qb, qc] = coefficients(u);
+                let root = discriminant([qa, qb, qc]).max(Scalar::ZERO).sqrt();
+                let v = (-qb + root * sign) / (qa * 2.);
+
+                let point_on_a = Point::from([u, v]);
+                let mut point_on_b = b.point_to_cylinder_coords(
+                    a.point_from_cylinder_coords(point_on_a),
+                );
+
+                if let Some(previous) = previous_on_b {
+                    while point_on_b.u - previous > Scalar::PI {
+                        point_on_b.u -= Scalar::TAU;
+                    }
+                    while previous - point_on_b.u > Scalar::PI {
+                        point_on_b.u += Scalar::TAU;
+                    }
+                }
+                previous_on_b = Some(point_on_b.u);
+
+                [point_on_a, point_on_b]
+            })
+        })
+        .into_iter()
+        .collect();
+
+    Some(paths)
+}
+
+/// Approximate a closed intersection curve by polygonal chains
+///
+/// `point_at` returns the point on the curve at the given angle, in the
+/// coordinates of both surfaces. It is sampled over a full turn.
+fn approximate_closed_curve(
+    mut point_at: impl FnMut(Scalar) -> [Point<2>; 2],
+) -> [SurfacePath; 2] {
+    let points = (0..=num_segments())
+        .map(|i| {
+            point_at(Scalar::TAU * Scalar::from_u64(i) / num_segments() as f64)
+        })
+        .collect::<Vec<_>>();
+
+    [0, 1].map(|i| {
+        SurfacePath::Polyline(PolyChain::from_points(
+            points.iter().map(|points| points[i]),
+        ))
+    })
+}
+
+/// The number of segments used to approximate intersection curves
+///
+/// Like [`epsilon`], this value is rather arbitrary, and might need to become
+/// configurable.
+fn num_segments() -> u64 {
+    64
+}
+
+/// Find the u-coordinates of the lines on a cylinder or cone that satisfy an
+/// equation
+///
+/// The equation is `k[0] * cos(u) + k[1] * sin(u) = c`. Returns no, one, or two
+/// u-coordinates.
+fn angles_of_lines(
+    [ka, kb]: [Scalar; 2],
+    c: Scalar,
+    radius: Scalar,
+) -> Vec<Scalar> {
+    let amplitude = (ka * ka + kb * kb).sqrt();
+    let ratio = c / amplitude;
+
+    if ratio.abs() > Scalar::ONE + epsilon() {
+        return Vec::new();
+    }
 
-        (line, surface.v())
+    let phase = Scalar::atan2(kb, ka);
+    let normalize = |u: Scalar| {
+        let u = u % Scalar::TAU;
+        if u < Scalar::ZERO {
+            u + Scalar::TAU
+        } else {
+            u
+        }
     };
 
-    Plane::from_parametric(line.origin(), line.direction(), path)
+    // The radius is the natural scale for comparing distances on the
+    // surface, to decide whether we're dealing with a tangent.
+    if (ratio.abs() - Scalar::ONE).abs() * radius < epsilon() {
+        let u = if ratio > Scalar::ZERO {
+            phase
+        } else {
+            phase + Scalar::PI
+        };
+        return vec![normalize(u)];
+    }
+
+    let angle = ratio.min(Scalar::ONE).max(-Scalar::ONE).acos();
+    let mut us = vec![normalize(phase - angle), normalize(phase + angle)];
+    us.sort();
+
+    us
+}
+
+/// The epsilon used to detect special cases, like parallel axes
+///
+/// This value is rather arbitrary. It might need to become configurable, once
+/// we had the opportunity to collect some experience with this code.
+fn epsilon() -> Scalar {
+    Scalar::from(1e-12)
+}
+
+/// Represent a circle in the coordinates of a plane
+///
+/// The circle must lie within the plane. Depending on the coordinate system
+/// of the plane, the circle can show up as an ellipse.
+fn circle_in_plane(plane: &Plane, circle: &Circle<3>) -> SurfacePath {
+    let center = plane.point_to_plane_coords(circle.center());
+    let a = plane.vector_to_plane_coords(circle.a());
+    let b = plane.vector_to_plane_coords(circle.b());
+
+    if a.dot(&b).abs() >= epsilon() {
+        todo!(
+            "Representing a circle in a plane with a skewed coordinate \
+            system is not supported yet."
+        )
+    }
+
+    if (a.magnitude() - b.magnitude()).abs() < epsilon() {
+        let b = b.normalize() * a.magnitude();
+        return SurfacePath::Circle(Circle::new(center, a, b));
+    }
+
+    SurfacePath::Ellipse(Ellipse::new(center, a, b))
 }
 
 #[cfg(test)]
 mod tests {
-    use fj_math::Transform;
+    use std::f64::consts::PI;
+
+    use fj_math::{Circle, Cone, Cylinder, Line, Point, Transform, Vector};
     use pretty_assertions::assert_eq;
 
     use crate::{
         algorithms::transform::TransformObject,
         objects::{Curve, Objects, Surface},
         partial::HasPartial,
+        path::SurfacePath,
         storage::Handle,
     };
 
     use super::SurfaceSurfaceIntersection;
 
     #[test]
-    fn plane_plane() {
+    fn plane_plane() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let xy = objects.surfaces.insert(Surface::xy_plane());
@@ -110,7 +604,7 @@ mod tests {
                     xy.clone().transform(
                         &Transform::translation([0., 0., 1.],),
                         &objects
-                    )
+                    )?
                 ],
                 &objects
             ),
@@ -120,17 +614,302 @@ mod tests {
         let expected_xy = Handle::<Curve>::partial()
             .with_surface(Some(xy.clone()))
             .as_u_axis()
-            .build(&objects);
+            .build(&objects)?;
         let expected_xz = Handle::<Curve>::partial()
             .with_surface(Some(xz.clone()))
             .as_u_axis()
-            .build(&objects);
+            .build(&objects)?;
 
         assert_eq!(
             SurfaceSurfaceIntersection::compute([xy, xz], &objects),
             Some(SurfaceSurfaceIntersection {
-                intersection_curves: [expected_xy, expected_xz],
+                intersection_curves: vec![[expected_xy, expected_xz]],
             })
         );
+
+        Ok(())
+    }
+
+    #[test]
+    fn plane_cylinder() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let cylinder =
+            objects
+                .surfaces
+                .insert(Surface::from_cylinder(Cylinder::new(
+                    [0., 0., 0.],
+                    [1., 0., 0.],
+                    [0., 1., 0.],
+                    [0., 0., 2.],
+                )));
+
+        // A plane that is perpendicular to the axis intersects the cylinder
+        // in a circle.
+        let xy = objects
+            .surfaces
+            .insert(Surface::xy_plane())
+            .transform(&Transform::translation([0., 0., 1.]), &objects)?;
+        let intersection = SurfaceSurfaceIntersection::compute(
+            [xy, cylinder.clone()],
+            &objects,
+        )
+        .unwrap();
+
+        let [on_plane, on_cylinder] = intersection.intersection_curves[0]
+            .clone()
+            .map(|curve| curve.path().clone());
+        assert_eq!(
+            on_plane,
+            SurfacePath::Circle(Circle::from_center_and_radius([0., 0.], 1.))
+        );
+        assert_eq!(
+            on_cylinder,
+            SurfacePath::Line(Line::from_origin_and_direction(
+                Point::from([0., 0.5]),
+                Vector::unit_u(),
+            ))
+        );
+
+        // A plane that is parallel to the axis intersects the cylinder in two
+        // lines.
+        let yz = objects.surfaces.insert(Surface::yz_plane());
+        let intersection = SurfaceSurfaceIntersection::compute(
+            [cylinder.clone(), yz],
+            &objects,
+        )
+        .unwrap();
+        assert_eq!(intersection.intersection_curves.len(), 2);
+
+        for curves in &intersection.intersection_curves {
+            let [on_cylinder, on_plane] = curves;
+
+            for t in [0., 1.] {
+                let a = on_cylinder.surface().point_from_surface_coords(
+                    on_cylinder.path().point_from_path_coords([t]),
+                );
+                let b = on_plane.surface().point_from_surface_coords(
+                    on_plane.path().point_from_path_coords([t]),
+                );
+
+                assert!((a - b).magnitude() < 1e-12.into());
+                assert!(a.x.abs() < 1e-12.into());
+            }
+        }
+
+        // A plane that is parallel to the axis, but too far away, doesn't
+        // intersect the cylinder.
+        let yz = objects
+            .surfaces
+            .insert(Surface::yz_plane())
+            .transform(&Transform::translation([2., 0., 0.]), &objects)?;
+        assert_eq!(
+            SurfaceSurfaceIntersection::compute(
+                [cylinder.clone(), yz],
+                &objects
+            ),
+            None
+        );
+
+        // A plane at an oblique angle intersects the cylinder in an ellipse,
+        // which is approximated.
+        let oblique = objects.surfaces.insert(Surface::plane_from_points([
+            [0., 0., 0.],
+            [1., 0., 0.],
+            [0., 1., 1.],
+        ]));
+        let intersection =
+            SurfaceSurfaceIntersection::compute([oblique, cylinder], &objects)
+                .unwrap();
+        assert_eq!(intersection.intersection_curves.len(), 1);
+
+        let [on_plane, on_cylinder] = &intersection.intersection_curves[0];
+        for point in assert_vertices_coincide(on_plane, on_cylinder) {
+            assert!((point.z - point.y).abs() < 1e-12.into());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn plane_cone() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        // The apex of this cone is at `[0., 0., 1.]`.
+        let cone = objects.surfaces.insert(Surface::from_cone(Cone::new(
+            [0., 0., 0.],
+            [1., 0., 0.],
+            [0., 1., 0.],
+            [0., 0., 1.],
+            -1.,
+        )));
+
+        // A plane that is perpendicular to the axis intersects the cone in a
+        // circle, on either side of the apex.
+        for (z, radius) in [(0.5, 0.5), (2., 1.)] {
+            let xy = objects
+                .surfaces
+                .insert(Surface::xy_plane())
+                .transform(&Transform::translation([0., 0., z]), &objects)?;
+            let intersection = SurfaceSurfaceIntersection::compute(
+                [xy, cone.clone()],
+                &objects,
+            )
+            .unwrap();
+
+            let [on_plane, on_cone] = &intersection.intersection_curves[0];
+            match on_plane.path() {
+                SurfacePath::Circle(circle) => {
+                    assert!((circle.radius() - radius).abs() < 1e-12.into());
+                }
+                path => panic!("Expected circle, got {path:?}"),
+            }
+            assert_curves_coincide(on_plane, on_cone);
+        }
+
+        // A plane that passes through the apex intersects the cone in two
+        // lines.
+        let xz = objects.surfaces.insert(Surface::xz_plane());
+        let intersection =
+            SurfaceSurfaceIntersection::compute([cone, xz], &objects).unwrap();
+        assert_eq!(intersection.intersection_curves.len(), 2);
+
+        for [on_cone, on_plane] in &intersection.intersection_curves {
+            assert_curves_coincide(on_cone, on_plane);
+        }
+
+        Ok(())
+    }
+
+    fn assert_curves_coincide(a: &Curve, b: &Curve) {
+        for t in [0., 0.5, 1.] {
+            let point_a = a.surface().point_from_surface_coords(
+                a.path().point_from_path_coords([t]),
+            );
+            let point_b = b.surface().point_from_surface_coords(
+                b.path().point_from_path_coords([t]),
+            );
+
+            assert!((point_a - point_b).magnitude() < 1e-12.into());
+        }
+    }
+
+    /// Make sure the points of two polygonal chains coincide
+    ///
+    /// Returns those points.
+    fn assert_vertices_coincide(a: &Curve, b: &Curve) -> Vec<Point<3>> {
+        let [points_a, points_b] = [a, b].map(|curve| match curve.path() {
+            SurfacePath::Polyline(chain) => chain
+                .points()
+                .iter()
+                .map(|&point| curve.surface().point_from_surface_coords(point))
+                .collect::<Vec<_>>(),
+            path => panic!("Expected polygonal chain, got {path:?}"),
+        });
+
+        assert_eq!(points_a.len(), points_b.len());
+        for (point_a, point_b) in points_a.iter().zip(&points_b) {
+            assert!((*point_a - *point_b).magnitude() < 1e-12.into());
+        }
+
+        points_a
+    }
+
+    #[test]
+    fn cylinder_cylinder() {
+        let objects = Objects::new();
+
+        let [a, b] = [[0., 0., 0.], [1., 0., 0.]].map(|origin| {
+            objects
+                .surfaces
+                .insert(Surface::from_cylinder(Cylinder::new(
+                    origin,
+                    [1., 0., 0.],
+                    [0., 1., 0.],
+                    [0., 0., 1.],
+                )))
+        });
+
+        let intersection =
+            SurfaceSurfaceIntersection::compute([a, b], &objects).unwrap();
+        assert_eq!(intersection.intersection_curves.len(), 2);
+
+        for [on_a, on_b] in &intersection.intersection_curves {
+            let u = on_a.path().point_from_path_coords([0.]).u;
+            assert!(
+                (u - PI / 3.).abs() < 1e-12.into()
+                    || (u - PI * 5. / 3.).abs() < 1e-12.into()
+            );
+
+            for t in [0., 1.] {
+                let point_a = on_a.surface().point_from_surface_coords(
+                    on_a.path().point_from_path_coords([t]),
+                );
+                let point_b = on_b.surface().point_from_surface_coords(
+                    on_b.path().point_from_path_coords([t]),
+                );
+
+                assert!((point_a - point_b).magnitude() < 1e-12.into());
+                assert!((point_a.x - 0.5).abs() < 1e-12.into());
+            }
+        }
+
+        // Coaxial cylinders don't have intersection curves.
+        let c = objects
+            .surfaces
+            .insert(Surface::from_cylinder(Cylinder::new(
+                [0., 0., 0.],
+                [2., 0., 0.],
+                [0., 2., 0.],
+                [0., 0., 1.],
+            )));
+        let a = objects
+            .surfaces
+            .insert(Surface::from_cylinder(Cylinder::new(
+                [0., 0., 0.],
+                [1., 0., 0.],
+                [0., 1., 0.],
+                [0., 0., 1.],
+            )));
+        assert_eq!(SurfaceSurfaceIntersection::compute([a, c], &objects), None);
+    }
+
+    #[test]
+    fn cylinder_through_cylinder() {
+        let objects = Objects::new();
+
+        let a = objects
+            .surfaces
+            .insert(Surface::from_cylinder(Cylinder::new(
+                [0., 0., 0.],
+                [0.5, 0., 0.],
+                [0., 0.5, 0.],
+                [0., 0., 1.],
+            )));
+        let b = objects
+            .surfaces
+            .insert(Surface::from_cylinder(Cylinder::new(
+                [0., 0., 0.],
+                [0., 1., 0.],
+                [0., 0., 1.],
+                [1., 0., 0.],
+            )));
+
+        // The thinner cylinder enters and leaves the other one, no matter in
+        // which order they are passed.
+        for surfaces in [[a.clone(), b.clone()], [b, a]] {
+            let intersection =
+                SurfaceSurfaceIntersection::compute(surfaces, &objects)
+                    .unwrap();
+            assert_eq!(intersection.intersection_curves.len(), 2);
+
+            for [on_a, on_b] in &intersection.intersection_curves {
+                for point in assert_vertices_coincide(on_a, on_b) {
+                    let [x, y, z] = [point.x, point.y, point.z];
+                    assert!((x * x + y * y - 0.25).abs() < 1e-12.into());
+                    assert!((y * y + z * z - 1.).abs() < 1e-12.into());
+                }
+            }
+        }
     }
 }
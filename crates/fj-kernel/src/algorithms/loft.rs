@@ -0,0 +1,288 @@
+//! Lofting between sketches
+//!
+//! A loft connects a sequence of cross-sections with side faces, and caps the
+//! ends with the first and last cross-section.
+//!
+//! # Implementation Note
+//!
+//! Only sketches that consist of a single face without interiors, with
+//! straight edges in a planar surface, are supported so far. All sketches must
+//! have the same number of edges. The vertices of consecutive sketches are
+//! connected in order, starting with the first vertex of each.
+//!
+//! Consecutive sketches are connected by ruled surfaces. Where those happen
+//! to be planar, planes are used instead.
+
+use fj_interop::mesh::Color;
+use fj_math::{Line, Plane, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{boolean::assemble::Assembler, offset::PolygonFace},
+    objects::{Objects, Sketch, Solid, Surface},
+    path::GlobalPath,
+};
+
+/// Loft between a sequence of shapes
+pub trait Loft {
+    /// Create a solid that connects the shapes, in order
+    ///
+    /// # Panics
+    ///
+    /// Panics, if there are less than two shapes, or if the shapes have
+    /// different numbers of edges.
+    fn loft(self, objects: &Objects) -> Solid;
+}
+
+impl Loft for Vec<Sketch> {
+    fn loft(self, objects: &Objects) -> Solid {
+        assert!(self.len() >= 2, "Need at least two sketches to loft");
+
+        let sections = self
+            .iter()
+            .map(|sketch| {
+                let mut faces = sketch.faces().into_iter();
+                let face = match (faces.next(), faces.next()) {
+                    (Some(face), None) => face,
+                    _ => todo!("Lofting only supports sketches with one face"),
+                };
+                assert!(
+                    face.interiors().next().is_none(),
+                    "Lofting sketches with interiors is not supported"
+                );
+
+                PolygonFace::new(face)
+            })
+            .collect::<Vec<_>>();
+
+        let num_vertices = sections[0].cycles[0].len();
+        assert!(
+            sections
+                .iter()
+                .all(|section| section.cycles[0].len() == num_vertices),
+            "Sketches must have the same number of edges to loft"
+        );
+
+        // Each sketch is wound counter-clockwise around the direction in which
+        // the loft progresses. That way, the side faces end up facing outward.
+        let centroids = sections
+            .iter()
+            .map(|section| centroid(&section.cycles[0]))
+            .collect::<Vec<_>>();
+        let polygons = sections
+            .iter()
+            .enumerate()
+            .map(|(k, section)| {
+                let direction = if k + 1 < centroids.len() {
+                    centroids[k + 1] - centroids[k]
+                } else {
+                    centroids[k] - centroids[k - 1]
+                };
+
+                let mut polygon = section.cycles[0].clone();
+                if section.normal().dot(&direction) < Scalar::ZERO {
+                    reverse(&mut polygon);
+                }
+                polygon
+            })
+            .collect::<Vec<_>>();
+
+        let mut assembler = Assembler::new();
+
+        // The caps face away from the loft.
+        let last = sections.len() - 1;
+        for (k, is_first) in [(0, true), (last, false)] {
+            let section = &sections[k];
+
+            let mut polygon = polygons[k].clone();
+            if is_first {
+                reverse(&mut polygon);
+            }
+
+            assembler.add_polygon(
+                section.surface.clone(),
+                section.plane,
+                section.color,
+                vec![polygon],
+            );
+        }
+
+        for (k, [bottom, top]) in polygons
+            .windows(2)
+            .map(|pair| [&pair[0], &pair[1]])
+            .enumerate()
+        {
+            let color = sections[k].color;
+
+            for i in 0..num_vertices {
+                let j = (i + 1) % num_vertices;
+                let quad = [bottom[i], bottom[j], top[j], top[i]];
+
+                add_side_face(&mut assembler, quad, color, objects);
+            }
+        }
+
+        Solid::new().with_shells([assembler.build(objects)])
+    }
+}
+
+/// Add a side face between two edges of consecutive sketches
+///
+/// The first two points of `quad` are on the one sketch, the last two on the
+/// other.
+fn add_side_face(
+    assembler: &mut Assembler,
+    quad: [Point<3>; 4],
+    color: Color,
+    objects: &Objects,
+) {
+    let [a, b, c, d] = quad;
+
+    let plane = Plane::from_parametric(a, b - a, d - a);
+    let (distance, normal) = plane.constant_normal_form();
+    let is_planar = (normal.dot(&c.coords) - distance).abs()
+        < Scalar::from_f64(1e-9) * (b - a).magnitude().max((d - a).magnitude());
+
+    if is_planar {
+        let surface = Surface::plane_from_points([a, b, d]);
+        assembler.add_polygon(
+            objects.surfaces.insert(surface),
+            plane,
+            color,
+            vec![quad.to_vec()],
+        );
+        return;
+    }
+
+    // In the ruled surface, the edge on each sketch runs along the
+    // u-direction, and the v-direction connects the sketches.
+    let surface = Surface::ruled(
+        GlobalPath::Line(Line::from_points([a, b])),
+        GlobalPath::Line(Line::from_points([d, c])),
+    );
+    let points_surface = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+
+    assembler.add_polygon_in_surface(
+        objects.surfaces.insert(surface),
+        color,
+        vec![quad
+            .into_iter()
+            .zip(points_surface.map(Point::from))
+            .collect()],
+    );
+}
+
+/// Reverse the winding of a polygon, keeping its first vertex in place
+fn reverse(polygon: &mut [Point<3>]) {
+    polygon.reverse();
+    polygon.rotate_right(1);
+}
+
+fn centroid(polygon: &[Point<3>]) -> Point<3> {
+    let sum = polygon
+        .iter()
+        .fold(Vector::from([0., 0., 0.]), |sum, point| sum + point.coords);
+    Point::origin() + sum / polygon.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Aabb;
+
+    use crate::{
+        algorithms::{
+            test_helpers::{bounding_volume, closed_shell, square},
+            transform::TransformObject,
+            validate::Validate,
+        },
+        geometry::SurfaceGeometry,
+        objects::{Objects, Shell, Sketch, Surface},
+    };
+
+    use super::Loft;
+
+    #[test]
+    fn loft_between_two_sketches() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let bottom = square([0., 0.], 1., &objects);
+        let top = square([0.25, 0.25], 0.5, &objects)
+            .translate([0., 0., 1.], &objects)?;
+
+        let solid = vec![bottom, top].loft(&objects);
+        let shell = closed_shell(solid, &objects)?;
+
+        // The side faces of a frustum are planar.
+        assert_eq!(shell.faces().into_iter().count(), 6);
+        assert_eq!(num_ruled_faces(&shell), 0);
+        assert_eq!(
+            bounding_volume(&shell),
+            Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]])
+        );
+
+        shell.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn loft_between_three_sketches() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let sketches = vec![
+            square([0., 0.], 1., &objects),
+            square([0.25, 0.25], 0.5, &objects)
+                .translate([0., 0., 1.], &objects)?,
+            square([0., 0.], 1., &objects).translate([0., 0., 2.], &objects)?,
+        ];
+
+        let solid = sketches.loft(&objects);
+        let shell = closed_shell(solid, &objects)?;
+
+        assert_eq!(shell.faces().into_iter().count(), 10);
+        assert_eq!(
+            bounding_volume(&shell),
+            Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 2.]])
+        );
+
+        shell.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn loft_with_twist() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let bottom = square([0., 0.], 1., &objects);
+        let top =
+            {
+                let surface =
+                    objects.surfaces.insert(Surface::plane_from_points([
+                        [0., 0., 1.],
+                        [1., 0., 1.],
+                        [0., 1., 1.],
+                    ]));
+                Sketch::builder(&objects, surface).build_polygon_from_points(
+                    [[0.5, -0.2], [1.2, 0.5], [0.5, 1.2], [-0.2, 0.5]],
+                )?
+            };
+
+        let solid = vec![bottom, top].loft(&objects);
+        let shell = closed_shell(solid, &objects)?;
+
+        // Connecting the rotated edges requires curved side faces.
+        assert_eq!(shell.faces().into_iter().count(), 6);
+        assert_eq!(num_ruled_faces(&shell), 4);
+
+        shell.validate()?;
+        Ok(())
+    }
+
+    fn num_ruled_faces(shell: &Shell) -> usize {
+        shell
+            .faces()
+            .into_iter()
+            .filter(|face| {
+                matches!(face.surface().geometry(), SurfaceGeometry::Ruled(_))
+            })
+            .count()
+    }
+}
@@ -0,0 +1,184 @@
+//! Measurement of areas, volumes, and other mass properties
+//!
+//! All measurements are computed from the triangulation of a shape, which
+//! means they are exact for shapes that are bounded by planar faces with
+//! straight edges. Otherwise, their accuracy depends on the tolerance that is
+//! used for the triangulation.
+
+use fj_interop::{mass_properties::MassProperties, mesh::Mesh};
+use fj_math::{Point, Scalar, Vector};
+
+use crate::objects::Face;
+
+use super::{
+    approx::{Approx, Tolerance},
+    triangulate::Triangulate,
+};
+
+/// Measure the mass properties of a shape
+pub trait Measure {
+    /// Compute the mass properties of the shape
+    ///
+    /// The shape is expected to be closed. Otherwise, the enclosed volume is
+    /// meaningless.
+    fn measure(self) -> MassProperties;
+}
+
+impl<T> Measure for (T, Tolerance)
+where
+    (T, Tolerance): Triangulate,
+{
+    fn measure(self) -> MassProperties {
+        mass_properties(&self.triangulate())
+    }
+}
+
+/// Compute the area of a face
+pub fn face_area(face: &Face, tolerance: impl Into<Tolerance>) -> Scalar {
+    let mesh = face.approx(tolerance).triangulate();
+
+    mesh.triangles()
+        .map(|triangle| {
+            let [a, b, c] = triangle.inner.points();
+            (b - a).cross(&(c - a)).magnitude() / 2.
+        })
+        .fold(Scalar::ZERO, |sum, area| sum + area)
+}
+
+/// Compute the mass properties of a closed triangle mesh
+///
+/// The triangles of the mesh are expected to face outward.
+pub fn mass_properties(mesh: &Mesh<Point<3>>) -> MassProperties {
+    let mut surface_area = Scalar::ZERO;
+    let mut volume = Scalar::ZERO;
+    let mut first_moment = Vector::from([0., 0., 0.]);
+    let mut second_moment = [[Scalar::ZERO; 3]; 3];
+
+    // Each triangle forms a tetrahedron with the origin. Their signed volumes,
+    // and the integrals over them, add up to those of the enclosed volume.
+    for triangle in mesh.triangles() {
+        let [a, b, c] = triangle.inner.points().map(|point| point.coords);
+
+        surface_area += (b - a).cross(&(c - a)).magnitude() / 2.;
+
+        let tetrahedron = a.dot(&b.cross(&c)) / 6.;
+        volume += tetrahedron;
+
+        let sum = a + b + c;
+        first_moment = first_moment + sum * tetrahedron / 4.;
+
+        // The integral of `x_i * x_j` over a tetrahedron with one vertex at
+        // the origin.
+        for (i, row) in second_moment.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                let products = [a, b, c]
+                    .into_iter()
+                    .fold(sum.components[i] * sum.components[j], |acc, x| {
+                        acc + x.components[i] * x.components[j]
+                    });
+                *entry += products * tetrahedron / 20.;
+            }
+        }
+    }
+
+    let center_of_mass = if volume == Scalar::ZERO {
+        Point::origin()
+    } else {
+        Point::origin() + first_moment / volume
+    };
+
+    // Move the second moment to the center of mass, then convert it into the
+    // inertia tensor.
+    let center = center_of_mass.coords.components;
+    for (i, row) in second_moment.iter_mut().enumerate() {
+        for (j, entry) in row.iter_mut().enumerate() {
+            *entry -= volume * center[i] * center[j];
+        }
+    }
+    let trace = (0..3).fold(Scalar::ZERO, |sum, i| sum + second_moment[i][i]);
+
+    let mut inertia = [[Scalar::ZERO; 3]; 3];
+    for (i, row) in inertia.iter_mut().enumerate() {
+        for (j, entry) in row.iter_mut().enumerate() {
+            let diagonal = if i == j { trace } else { Scalar::ZERO };
+            *entry = diagonal - second_moment[i][j];
+        }
+    }
+
+    MassProperties {
+        volume,
+        surface_area,
+        center_of_mass,
+        inertia,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{approx::Tolerance, test_helpers::cuboid},
+        objects::{Face, Objects, Surface},
+    };
+
+    use super::{face_area, Measure};
+
+    #[test]
+    fn measure_cuboid() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let solid = cuboid([2., 1., 3.], [0.; 3], &objects);
+
+        let properties = (&solid, Tolerance::from_scalar(0.01)?).measure();
+
+        assert_close(properties.volume, 6.);
+        assert_close(properties.surface_area, 22.);
+        let center = properties.center_of_mass - Point::from([1., 0.5, 1.5]);
+        assert_close(center.magnitude(), 0.);
+
+        // The inertia tensor of a cuboid is `m / 12 * (b^2 + c^2)` on the
+        // diagonal, and zero elsewhere.
+        let [x, y, z] = [2., 1., 3.];
+        let expected = [y * y + z * z, x * x + z * z, x * x + y * y];
+        for (i, row) in properties.inertia.iter().enumerate() {
+            for (j, &entry) in row.iter().enumerate() {
+                let expected = if i == j { 6. / 12. * expected[i] } else { 0. };
+                assert_close(entry, expected);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn area_of_face() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let face = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([
+                [0., 0.],
+                [4., 0.],
+                [4., 4.],
+                [0., 4.],
+            ])
+            .with_interior_polygon_from_points([
+                [1., 1.],
+                [1., 2.],
+                [2., 2.],
+                [2., 1.],
+            ])
+            .build()?;
+
+        assert_close(face_area(&face, Tolerance::from_scalar(0.01)?), 15.);
+        Ok(())
+    }
+
+    fn assert_close(actual: Scalar, expected: f64) {
+        assert!(
+            (actual - expected).abs() < Scalar::from_f64(1e-9),
+            "Expected {expected}, got {actual:?}"
+        );
+    }
+}
@@ -0,0 +1,281 @@
+//! Minkowski sums
+//!
+//! The Minkowski sum of a solid and a convex shape consists of all points that
+//! can be reached by adding a point of the convex shape to a point of the
+//! solid. Adding a sphere rounds off all edges and corners of a solid, while
+//! offsetting its faces outward. This makes it an alternative to filleting and
+//! offsetting, that works for any solid.
+//!
+//! The sum is computed in the triangulated form of the solid, which means the
+//! result is bounded by planar faces. If the solid is convex, the sum is the
+//! convex hull of the sums of their points. Otherwise, each triangle of the
+//! solid is swept over the convex shape, and the results are merged with the
+//! solid, using a boolean union.
+//!
+//! # Implementation Note
+//!
+//! Summing a solid that is not convex requires one boolean union per triangle
+//! of the solid, which is slow for solids with many triangles.
+
+use fj_interop::mesh::{Color, Mesh};
+use fj_math::{Point, Scalar};
+
+use crate::objects::{Objects, Solid};
+
+use super::{
+    approx::Tolerance, boolean::Union, hull::hull_of_points,
+    triangulate::Triangulate,
+};
+
+/// A convex shape that can be added to a solid
+#[derive(Clone, Debug, PartialEq)]
+pub enum Convex {
+    /// A sphere that is centered on the origin
+    Sphere {
+        /// The radius of the sphere
+        radius: Scalar,
+    },
+
+    /// The convex hull of a set of points
+    Polyhedron(Vec<Point<3>>),
+}
+
+impl Convex {
+    /// Compute points, whose convex hull approximates the shape
+    ///
+    /// The points of a sphere are on its surface, so their hull is contained in
+    /// the sphere.
+    pub fn points(&self, tolerance: impl Into<Tolerance>) -> Vec<Point<3>> {
+        match self {
+            Self::Sphere { radius } => sphere_points(*radius, tolerance.into()),
+            Self::Polyhedron(points) => points.clone(),
+        }
+    }
+}
+
+/// Compute the Minkowski sum of a solid and a convex shape
+pub trait MinkowskiSum {
+    /// Add `other` to the solid
+    fn minkowski_sum(self, other: &Convex, objects: &Objects) -> Solid;
+}
+
+impl MinkowskiSum for (&Solid, Tolerance) {
+    fn minkowski_sum(self, other: &Convex, objects: &Objects) -> Solid {
+        let (solid, tolerance) = self;
+
+        let mesh: Mesh<Point<3>> = (solid, tolerance).triangulate();
+        let points = other.points(tolerance);
+
+        let color = mesh
+            .triangles()
+            .next()
+            .map(|triangle| triangle.color)
+            .unwrap_or_default();
+
+        if is_convex(&mesh) {
+            return sweep(mesh.vertices(), &points, color, objects);
+        }
+
+        mesh.triangles()
+            .map(|triangle| {
+                sweep(triangle.inner.points(), &points, color, objects)
+            })
+            .fold(solid.clone(), |sum, piece| sum.union(piece, objects))
+    }
+}
+
+/// Sweep a convex set of points over the points of a convex shape
+fn sweep(
+    points: impl IntoIterator<Item = Point<3>>,
+    other: &[Point<3>],
+    color: Color,
+    objects: &Objects,
+) -> Solid {
+    let sums = points
+        .into_iter()
+        .flat_map(|point| other.iter().map(move |&other| point + other.coords))
+        .collect();
+
+    hull_of_points(sums, color, objects)
+}
+
+/// Determine whether a closed mesh bounds a convex solid
+fn is_convex(mesh: &Mesh<Point<3>>) -> bool {
+    let points = mesh.vertices().collect::<Vec<_>>();
+
+    mesh.triangles().all(|triangle| {
+        let normal = triangle.inner.normal();
+        let [a, _, _] = triangle.inner.points();
+
+        // The tolerance allows for the points of faces that are not exactly
+        // planar, due to floating-point inaccuracies.
+        let epsilon = Scalar::from_f64(1e-9) * normal.magnitude();
+        points
+            .iter()
+            .all(|&point| normal.dot(&(point - a)) <= epsilon)
+    })
+}
+
+fn sphere_points(radius: Scalar, tolerance: Tolerance) -> Vec<Point<3>> {
+    // The number of segments is chosen, so the chords of each circle of
+    // latitude and longitude deviate from it by no more than the tolerance.
+    let num_segments = if tolerance.inner() < radius {
+        (Scalar::PI / (Scalar::ONE - tolerance.inner() / radius).acos())
+            .ceil()
+            .max(Scalar::from_f64(3.))
+    } else {
+        Scalar::from_f64(3.)
+    };
+    let num_segments = num_segments.into_f64() as usize;
+
+    let mut points =
+        vec![Point::from([0., 0., 1.]), Point::from([0., 0., -1.])];
+    for i in 1..num_segments {
+        let (sin_polar, cos_polar) =
+            (Scalar::PI * i as f64 / num_segments as f64).sin_cos();
+        for j in 0..num_segments * 2 {
+            let (sin_azimuth, cos_azimuth) =
+                (Scalar::PI * j as f64 / num_segments as f64).sin_cos();
+            points.push(Point::from([
+                sin_polar * cos_azimuth,
+                sin_polar * sin_azimuth,
+                cos_polar,
+            ]));
+        }
+    }
+
+    points
+        .into_iter()
+        .map(|point| Point::origin() + point.coords * radius)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Mesh;
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, measure::mass_properties, sweep::Sweep,
+            triangulate::Triangulate, validate::Validate,
+        },
+        objects::{Objects, Sketch, Solid, Surface},
+    };
+
+    use super::{Convex, MinkowskiSum};
+
+    #[test]
+    fn convex_solid_and_polyhedron() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cube = prism(&objects, [[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+        let small_cube = Convex::Polyhedron(
+            [
+                [-0.5, -0.5, -0.5],
+                [0.5, -0.5, -0.5],
+                [-0.5, 0.5, -0.5],
+                [0.5, 0.5, -0.5],
+                [-0.5, -0.5, 0.5],
+                [0.5, -0.5, 0.5],
+                [-0.5, 0.5, 0.5],
+                [0.5, 0.5, 0.5],
+            ]
+            .map(Point::from)
+            .to_vec(),
+        );
+
+        let sum = (&cube, tolerance)
+            .minkowski_sum(&small_cube, &objects)
+            .validate()?
+            .into_inner();
+
+        assert_eq!(volume(&sum, tolerance), Scalar::from_f64(8.));
+
+        Ok(())
+    }
+
+    #[test]
+    fn convex_solid_and_sphere() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let cube = prism(&objects, [[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+        let sphere = Convex::Sphere {
+            radius: Scalar::from_f64(0.25),
+        };
+
+        let sum = (&cube, tolerance)
+            .minkowski_sum(&sphere, &objects)
+            .validate()?
+            .into_inner();
+
+        // The cube grows by slabs on its faces, quarter cylinders on its
+        // edges, and eighths of a sphere on its corners.
+        let r = 0.25;
+        let expected = 1.
+            + 6. * r
+            + 12. * std::f64::consts::PI * r * r / 4.
+            + 4. / 3. * std::f64::consts::PI * r * r * r;
+        let volume = volume(&sum, tolerance);
+        assert!(volume < Scalar::from_f64(expected));
+        assert!(volume > Scalar::from_f64(expected - 0.05));
+
+        Ok(())
+    }
+
+    #[test]
+    fn concave_solid() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        // An L-shaped prism, that fills its notch when grown enough.
+        let l_shape = prism(
+            &objects,
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]],
+        );
+        let small_cube = Convex::Polyhedron(
+            [
+                [0., 0., 0.],
+                [0.5, 0., 0.],
+                [0., 0.5, 0.],
+                [0.5, 0.5, 0.],
+                [0., 0., 1.],
+                [0.5, 0., 1.],
+                [0., 0.5, 1.],
+                [0.5, 0.5, 1.],
+            ]
+            .map(Point::from)
+            .to_vec(),
+        );
+
+        let sum = (&l_shape, tolerance)
+            .minkowski_sum(&small_cube, &objects)
+            .validate()?
+            .into_inner();
+
+        // The sum is an L-shaped prism, 2.5 wide and 2 high, with a notch of
+        // 1 by 1.
+        let expected = (2.5 * 2.5 - 1. * 1.) * 2.;
+        assert_eq!(volume(&sum, tolerance), Scalar::from_f64(expected));
+
+        Ok(())
+    }
+
+    fn prism<const N: usize>(
+        objects: &Objects,
+        points: [[f64; 2]; N],
+    ) -> Solid {
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        Sketch::builder(objects, surface)
+            .build_polygon_from_points(points)
+            .expect("Failed to build polygon")
+            .sweep([0., 0., 1.], objects)
+    }
+
+    fn volume(solid: &Solid, tolerance: Tolerance) -> Scalar {
+        let mesh: Mesh<Point<3>> = (solid, tolerance).triangulate();
+        mass_properties(&mesh).volume
+    }
+}
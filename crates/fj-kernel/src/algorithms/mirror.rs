@@ -0,0 +1,157 @@
+//! Mirroring of objects across a plane
+//!
+//! A reflection turns objects inside out: The surface coordinates of each
+//! face stay the same, but the coordinate systems of the mirrored surfaces
+//! have the opposite handedness. Mirrored faces would face backwards, so they
+//! are reversed.
+
+use fj_math::{Plane, Transform};
+
+use crate::{
+    algorithms::{reverse::Reverse, transform::TransformObject},
+    objects::{Face, Faces, Objects, Shell, Sketch, Solid},
+    partial::PartialBuildError,
+};
+
+/// Mirror an object across a plane
+pub trait Mirror: Sized {
+    /// Mirror the object across the given plane
+    ///
+    /// Faces of the mirrored object keep facing the same way relative to the
+    /// object, meaning the faces of a mirrored shell still face outward.
+    ///
+    /// Returns an error, if the mirrored objects can't be built. See
+    /// [`TransformObject`].
+    fn mirror(
+        self,
+        plane: &Plane,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError>;
+}
+
+impl Mirror for Face {
+    fn mirror(
+        self,
+        plane: &Plane,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
+        Ok(self.transform(&reflection(plane), objects)?.reverse())
+    }
+}
+
+impl Mirror for Faces {
+    fn mirror(
+        self,
+        plane: &Plane,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
+        let mut faces = Faces::new();
+        for face in self {
+            faces.extend([face.mirror(plane, objects)?]);
+        }
+        Ok(faces)
+    }
+}
+
+impl Mirror for Shell {
+    fn mirror(
+        self,
+        plane: &Plane,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
+        let faces = self.into_faces().mirror(plane, objects)?;
+        Ok(Shell::new().with_faces(faces))
+    }
+}
+
+impl Mirror for Sketch {
+    fn mirror(
+        self,
+        plane: &Plane,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
+        let faces = self.into_faces().mirror(plane, objects)?;
+        Ok(Sketch::new().with_faces(faces))
+    }
+}
+
+impl Mirror for Solid {
+    fn mirror(
+        self,
+        plane: &Plane,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
+        let shells = self
+            .into_shells()
+            .map(|shell| shell.mirror(plane, objects))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Solid::new().with_shells(shells))
+    }
+}
+
+fn reflection(plane: &Plane) -> Transform {
+    let origin = plane.origin().coords;
+
+    Transform::translation(origin)
+        * Transform::reflection(plane.normal())
+        * Transform::translation(-origin)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Plane, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance,
+            test_helpers::{bounding_volume, closed_shell, cube},
+            triangulate::Triangulate,
+            validate::Validate,
+        },
+        objects::{Objects, Solid},
+    };
+
+    use super::Mirror;
+
+    #[test]
+    fn mirror_solid() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let solid = cube(1., &objects);
+
+        let plane = Plane::from_parametric(
+            Point::from([2., 0., 0.]),
+            Vector::unit_y(),
+            Vector::unit_z(),
+        );
+        let solid = solid.mirror(&plane, &objects)?;
+
+        assert_eq!(
+            bounding_volume(&solid),
+            Aabb::<3>::from_points([[3., 0., 0.], [4., 1., 1.]])
+        );
+
+        // The faces still face outward, so the mesh encloses a positive
+        // volume.
+        let volume = volume(&solid)?;
+        assert!((volume - Scalar::ONE).abs() < Scalar::from_f64(1e-9));
+
+        let shell = closed_shell(solid, &objects)?;
+        shell.validate()?;
+        Ok(())
+    }
+
+    /// Compute the signed volume that the triangulated solid encloses
+    fn volume(solid: &Solid) -> anyhow::Result<Scalar> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (solid, tolerance).triangulate();
+
+        Ok(mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                a.coords.dot(&b.coords.cross(&c.coords)) / 6.
+            })
+            .fold(Scalar::ZERO, |sum, volume| sum + volume))
+    }
+}
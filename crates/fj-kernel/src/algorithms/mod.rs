@@ -4,9 +4,34 @@
 //! on their respective purpose.
 
 pub mod approx;
+pub mod boolean;
+pub mod bvh;
+pub mod chamfer;
+pub mod collision;
+pub mod decimate;
+pub mod distance;
+pub mod heal;
+pub mod hollow;
+pub mod hull;
 pub mod intersect;
+pub mod loft;
+pub mod measure;
+pub mod minkowski;
+pub mod mirror;
+mod offset;
+pub mod orient;
+pub mod replace;
 pub mod reverse;
+pub mod sew;
+pub mod silhouette;
+pub mod slice;
+pub mod snap;
+pub mod split;
 pub mod sweep;
+pub mod thicken;
 pub mod transform;
 pub mod triangulate;
 pub mod validate;
+
+#[cfg(test)]
+mod test_helpers;
@@ -0,0 +1,187 @@
+//! Offsetting of planar faces along their normals
+//!
+//! Shared by the operations that create solids from offset copies of faces.
+//! The faces are offset as a whole, which moves each vertex to where the
+//! offset planes of the faces it is part of meet.
+
+use fj_interop::mesh::Color;
+use fj_math::{Plane, Point, Scalar, Vector};
+
+use crate::{
+    geometry::{SurfaceGeometry, SweptCurve},
+    objects::{Face, Handedness, Objects, Surface},
+    path::{GlobalPath, SurfacePath},
+    storage::Handle,
+};
+
+/// A planar face, with its cycles as polygons in 3D
+pub struct PolygonFace {
+    pub surface: Handle<Surface>,
+    pub plane: Plane,
+    pub color: Color,
+
+    /// The cycles of the face, starting with its exterior
+    pub cycles: Vec<Vec<Point<3>>>,
+
+    handedness: Handedness,
+}
+
+impl PolygonFace {
+    pub fn new(face: &Face) -> Self {
+        let plane = match face.surface().geometry() {
+            SurfaceGeometry::SweptCurve(SweptCurve {
+                u: GlobalPath::Line(line),
+                v,
+            }) => Plane::from_parametric(line.origin(), line.direction(), *v),
+            _ => todo!("Offsetting only supports faces on planar surfaces"),
+        };
+
+        let cycles = face
+            .all_cycles()
+            .map(|cycle| {
+                cycle
+                    .half_edges()
+                    .map(|half_edge| {
+                        if !matches!(
+                            half_edge.curve().path(),
+                            SurfacePath::Line(_)
+                        ) {
+                            todo!(
+                                "Offsetting only supports faces with straight \
+                                edges"
+                            );
+                        }
+
+                        let [vertex, _] = half_edge.vertices();
+                        vertex.global_form().position()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            surface: face.surface().clone(),
+            plane,
+            color: face.color(),
+            cycles,
+            handedness: face.coord_handedness(),
+        }
+    }
+
+    /// Compute the unit normal of the face's front side
+    pub fn normal(&self) -> Vector<3> {
+        let normal = self.plane.normal().normalize();
+
+        match self.handedness {
+            Handedness::RightHanded => normal,
+            Handedness::LeftHanded => -normal,
+        }
+    }
+
+    /// Compute the surface of the face, offset along its front side's normal
+    ///
+    /// Returns the surface, as well as the plane that it represents.
+    pub fn offset_surface(
+        &self,
+        distance: Scalar,
+        objects: &Objects,
+    ) -> (Handle<Surface>, Plane) {
+        let distance = match self.handedness {
+            Handedness::RightHanded => distance,
+            Handedness::LeftHanded => -distance,
+        };
+        let surface = self
+            .surface
+            .offset(distance)
+            .expect("Planar surfaces can always be offset");
+
+        let plane = match surface.geometry() {
+            SurfaceGeometry::SweptCurve(SweptCurve {
+                u: GlobalPath::Line(line),
+                v,
+            }) => Plane::from_parametric(line.origin(), line.direction(), *v),
+            _ => unreachable!("Offset of a plane must be a plane"),
+        };
+
+        (objects.surfaces.insert(surface), plane)
+    }
+}
+
+/// Offset a vertex of the provided faces
+///
+/// `distance` determines how far each face is offset along its normal. The
+/// vertex is moved to where the offset planes of all faces that it is part of
+/// meet.
+pub fn offset_vertex(
+    point: Point<3>,
+    faces: &[PolygonFace],
+    distance: impl Fn(usize) -> Scalar,
+) -> Point<3> {
+    let planes = faces
+        .iter()
+        .enumerate()
+        .filter(|(_, face)| face.cycles.iter().flatten().any(|p| *p == point))
+        .map(|(i, face)| (face.normal(), distance(i)))
+        .collect::<Vec<_>>();
+
+    point + displacement(&planes)
+}
+
+/// Compute the smallest displacement that moves a point by the given distances
+/// along the given unit normals
+///
+/// The displacement is a combination of the normals. Normals that depend on
+/// the others are ignored, which means the displacement might not satisfy
+/// their distances, if those are inconsistent.
+pub fn displacement(planes: &[(Vector<3>, Scalar)]) -> Vector<3> {
+    // Solve `G * a = d`, where `G` is the Gram matrix of the normals, using
+    // Gaussian elimination.
+    let n = planes.len();
+    let mut rows = planes
+        .iter()
+        .map(|(normal, distance)| {
+            let mut row = planes
+                .iter()
+                .map(|(other, _)| normal.dot(other))
+                .collect::<Vec<_>>();
+            row.push(*distance);
+            row
+        })
+        .collect::<Vec<_>>();
+
+    let mut pivots = Vec::new();
+    for column in 0..n {
+        let pivot = (pivots.len()..n)
+            .max_by_key(|&i| rows[i][column].abs())
+            .filter(|&i| rows[i][column].abs() > Scalar::from_f64(1e-9));
+        let pivot = match pivot {
+            Some(pivot) => pivot,
+            // The normal depends on the previous ones.
+            None => continue,
+        };
+
+        rows.swap(pivots.len(), pivot);
+        let pivot = pivots.len();
+
+        let pivot_row = rows[pivot].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != pivot {
+                let factor = row[column] / pivot_row[column];
+                for (value, &pivot_value) in row.iter_mut().zip(&pivot_row) {
+                    *value -= pivot_value * factor;
+                }
+            }
+        }
+
+        pivots.push(column);
+    }
+
+    pivots
+        .into_iter()
+        .enumerate()
+        .map(|(row, column)| {
+            let coefficient = rows[row][n] / rows[row][column];
+            planes[column].0 * coefficient
+        })
+        .fold(Vector::from([0., 0., 0.]), |sum, vector| sum + vector)
+}
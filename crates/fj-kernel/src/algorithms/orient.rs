@@ -0,0 +1,231 @@
+//! Fix the orientation of faces
+//!
+//! Faces that are created by hand, or imported from elsewhere, don't always
+//! face the same way as their neighbors. Orienting a shell reverses such faces,
+//! so that each edge is used by its faces in opposite directions, and the faces
+//! point outward.
+//!
+//! # Implementation Note
+//!
+//! The faces of a shell that can't be oriented consistently, like those of a
+//! Möbius strip, are left as they are, where they contradict each other.
+
+use std::collections::VecDeque;
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Point, Scalar};
+
+use crate::objects::{Face, Shell, Solid};
+
+use super::{
+    approx::Tolerance, measure::mass_properties, reverse::Reverse,
+    triangulate::Triangulate, validate::shell_edges,
+};
+
+/// Fix the orientation of an object's faces
+pub trait Orient {
+    /// Reverse faces, so all of them are oriented consistently
+    ///
+    /// Edges are matched, if their midpoints are within `tolerance` of each
+    /// other. Curved faces are approximated within `tolerance`, to determine
+    /// which way they point.
+    #[must_use]
+    fn orient(self, tolerance: Tolerance) -> Self;
+}
+
+impl Orient for Shell {
+    fn orient(self, tolerance: Tolerance) -> Self {
+        let shell = orient_consistently(self, tolerance);
+
+        if volume(&shell, tolerance) < Scalar::ZERO {
+            return reverse(shell);
+        }
+
+        shell
+    }
+}
+
+impl Orient for Solid {
+    /// Reverse faces, so all of them are oriented consistently
+    ///
+    /// The shell that encloses the largest volume is considered to be the
+    /// outside of the solid, and is oriented to point outward. All other shells
+    /// are considered to be cavities, and are oriented to point inward.
+    fn orient(self, tolerance: Tolerance) -> Self {
+        let shells = self
+            .into_shells()
+            .map(|shell| {
+                let shell = orient_consistently(shell, tolerance);
+                let volume = volume(&shell, tolerance);
+                (shell, volume)
+            })
+            .collect::<Vec<_>>();
+
+        let outer = shells
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, (_, volume))| volume.abs())
+            .map(|(i, _)| i);
+
+        Solid::new().with_shells(shells.into_iter().enumerate().map(
+            |(i, (shell, volume))| {
+                let should_be_positive = Some(i) == outer;
+                if (volume > Scalar::ZERO) != should_be_positive {
+                    return reverse(shell);
+                }
+                shell
+            },
+        ))
+    }
+}
+
+/// Reverse faces, so neighboring faces are oriented the same way
+///
+/// Starting from an arbitrary face, the orientation is passed on through the
+/// edges the faces share.
+fn orient_consistently(shell: Shell, tolerance: Tolerance) -> Shell {
+    let mut neighbors = vec![Vec::new(); shell.faces().into_iter().count()];
+    for edge in shell_edges(shell.faces(), tolerance.inner()) {
+        if let [a, b] = edge.as_slice() {
+            let consistent = a.runs_opposite_to(b, tolerance.inner());
+            neighbors[a.id].push((b.id, consistent));
+            neighbors[b.id].push((a.id, consistent));
+        }
+    }
+
+    let mut flip = vec![None; neighbors.len()];
+    for start in 0..neighbors.len() {
+        if flip[start].is_some() {
+            continue;
+        }
+
+        flip[start] = Some(false);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(face) = queue.pop_front() {
+            let flip_face = flip[face].expect("Queued faces have been visited");
+
+            for &(neighbor, consistent) in &neighbors[face] {
+                if flip[neighbor].is_none() {
+                    flip[neighbor] = Some(flip_face ^ !consistent);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+    }
+
+    let faces = shell
+        .into_faces()
+        .into_iter()
+        .zip(flip)
+        .map(|(face, flip)| {
+            if flip == Some(true) {
+                return face.reverse();
+            }
+            face
+        });
+
+    Shell::new().with_faces(faces)
+}
+
+fn reverse(shell: Shell) -> Shell {
+    Shell::new().with_faces(shell.into_faces().into_iter().map(Face::reverse))
+}
+
+fn volume(shell: &Shell, tolerance: Tolerance) -> Scalar {
+    let mesh: Mesh<Point<3>> = (shell, tolerance).triangulate();
+    mass_properties(&mesh).volume
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance,
+            hollow::Hollow,
+            reverse::Reverse,
+            test_helpers::{cube, cube_shell},
+            validate::{Validate, ValidationError},
+        },
+        objects::{Objects, Shell, Solid},
+    };
+
+    use super::{volume, Orient};
+
+    #[test]
+    fn flipped_face() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cube = cube_shell(1., &objects);
+
+        // Reversing one of the cube's faces makes it inconsistent with its
+        // neighbors.
+        let mut faces = cube.into_faces().into_iter();
+        let flipped = faces.next().expect("Cube has faces").reverse();
+        let shell = Shell::new().with_faces(faces.chain([flipped]));
+
+        let solid = Solid::new().with_shells([shell.clone()]);
+        assert!(matches!(
+            solid.validate(),
+            Err(ValidationError::Orientation(_))
+        ));
+
+        let shell = shell.orient(tolerance);
+        assert!(
+            (volume(&shell, tolerance) - Scalar::ONE).abs()
+                < Scalar::from_f64(1e-12)
+        );
+        Solid::new().with_shells([shell]).validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn inside_out() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cube = cube_shell(1., &objects);
+        let shell = Shell::new().with_faces(
+            cube.into_faces().into_iter().map(|face| face.reverse()),
+        );
+
+        let solid = Solid::new().with_shells([shell]);
+        match solid.clone().validate() {
+            Err(ValidationError::Orientation(issues)) => {
+                assert!(issues.inconsistent_edges.is_empty());
+                assert!(issues.inside_out);
+            }
+            result => panic!("Unexpected validation result: {result:?}"),
+        }
+
+        solid.orient(tolerance).validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn cavity() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        // Orienting the shells of a hollow solid keeps the cavity intact.
+        let hollow = cube(1., &objects).hollow(0.1, &[], &objects);
+        let oriented = hollow.clone().orient(tolerance);
+
+        let mut volumes = oriented
+            .shells()
+            .map(|shell| volume(shell, tolerance))
+            .collect::<Vec<_>>();
+        volumes.sort();
+        assert_eq!(volumes.len(), 2);
+        assert!(volumes[0] < Scalar::ZERO && volumes[1] > Scalar::ZERO);
+
+        oriented.validate()?;
+
+        Ok(())
+    }
+}
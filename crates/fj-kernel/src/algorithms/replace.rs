@@ -0,0 +1,276 @@
+//! Replacing objects and rebuilding the objects that refer to them
+//!
+//! Objects refer to each other through [`Handle`]s, and can't be changed after
+//! they have been created. To edit a shape, an object is replaced with a new
+//! one, and everything that refers to it, directly or indirectly, is rebuilt
+//! to refer to the replacement instead.
+//!
+//! Objects are matched by identity, not equality (see [`crate::objects`]). An
+//! object that is equal to the replaced one, but not identical, stays as it
+//! is.
+
+use std::collections::HashMap;
+
+use crate::{
+    objects::{
+        Curve, Cycle, Face, Faces, GlobalCurve, GlobalEdge, GlobalVertex,
+        HalfEdge, Objects, Shell, Sketch, Solid, Surface, SurfaceVertex,
+        Vertex,
+    },
+    storage::{Handle, ObjectId},
+};
+
+/// Replace an object and rebuild all objects that refer to it
+pub trait Replace<T> {
+    /// Replace `original` with `replacement`
+    ///
+    /// Returns the rebuilt shape. Objects that don't refer to `original` are
+    /// rebuilt too, but keep referring to the same stored objects. Objects
+    /// that are shared within the shape stay shared.
+    #[must_use]
+    fn replace(
+        self,
+        original: &Handle<T>,
+        replacement: Handle<T>,
+        objects: &Objects,
+    ) -> Self;
+}
+
+macro_rules! impl_replace {
+    ($($object:ident, $replacements:ident;)*) => {
+        $(
+            impl_replace!(
+                @shapes $object, $replacements;
+                Faces, faces;
+                Shell, shell;
+                Sketch, sketch;
+                Solid, solid;
+            );
+        )*
+    };
+    (
+        @shapes $object:ident, $replacements:ident;
+        $($shape:ident, $rebuild:ident;)*
+    ) => {
+        $(
+            impl Replace<$object> for $shape {
+                fn replace(
+                    self,
+                    original: &Handle<$object>,
+                    replacement: Handle<$object>,
+                    objects: &Objects,
+                ) -> Self {
+                    let mut replacer = Replacer::new(objects);
+                    replacer
+                        .$replacements
+                        .insert(original.id(), replacement);
+                    replacer.$rebuild(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_replace!(
+    Curve, curves;
+    GlobalCurve, global_curves;
+    GlobalVertex, global_vertices;
+    Surface, surfaces;
+);
+
+/// Rebuilds objects, using the replacements
+///
+/// Curves that have been rebuilt are recorded as replacements themselves, so
+/// every curve is rebuilt only once, and objects that shared the original
+/// still share the rebuilt one.
+struct Replacer<'r> {
+    curves: HashMap<ObjectId, Handle<Curve>>,
+    global_curves: HashMap<ObjectId, Handle<GlobalCurve>>,
+    global_vertices: HashMap<ObjectId, Handle<GlobalVertex>>,
+    surfaces: HashMap<ObjectId, Handle<Surface>>,
+    objects: &'r Objects,
+}
+
+impl<'r> Replacer<'r> {
+    fn new(objects: &'r Objects) -> Self {
+        Self {
+            curves: HashMap::new(),
+            global_curves: HashMap::new(),
+            global_vertices: HashMap::new(),
+            surfaces: HashMap::new(),
+            objects,
+        }
+    }
+
+    fn faces(&mut self, faces: Faces) -> Faces {
+        let mut rebuilt = Faces::new();
+        rebuilt.extend(faces.into_iter().map(|face| self.face(&face)));
+        rebuilt
+    }
+
+    fn shell(&mut self, shell: Shell) -> Shell {
+        Shell::new().with_faces(self.faces(shell.into_faces()))
+    }
+
+    fn sketch(&mut self, sketch: Sketch) -> Sketch {
+        Sketch::new().with_faces(self.faces(sketch.into_faces()))
+    }
+
+    fn solid(&mut self, solid: Solid) -> Solid {
+        let shells = solid
+            .into_shells()
+            .map(|shell| self.shell(shell))
+            .collect::<Vec<_>>();
+        Solid::new().with_shells(shells)
+    }
+
+    fn face(&mut self, face: &Face) -> Face {
+        let mut cycles = face
+            .all_cycles()
+            .map(|cycle| self.cycle(cycle))
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        let exterior = cycles.next().expect("Face has an exterior");
+        Face::from_exterior(exterior)
+            .with_interiors(cycles)
+            .with_color(face.color())
+    }
+
+    fn cycle(&mut self, cycle: &Cycle) -> Cycle {
+        let surface = self.surface(cycle.surface());
+        let half_edges = cycle
+            .half_edges()
+            .map(|half_edge| self.half_edge(half_edge))
+            .collect::<Vec<_>>();
+
+        Cycle::new(surface, half_edges)
+    }
+
+    fn half_edge(&mut self, half_edge: &HalfEdge) -> HalfEdge {
+        let [a, b] = half_edge.vertices();
+        let vertices = [self.vertex(a), self.vertex(b)];
+
+        // The global form must refer to the same global curve as the curve of
+        // the vertices, which might have been replaced along with it.
+        let global_curve = vertices[0].curve().global_form().clone();
+        let global_vertices = half_edge
+            .global_form()
+            .vertices()
+            .access_in_normalized_order()
+            .clone()
+            .map(|global_vertex| self.global_vertex(&global_vertex));
+
+        HalfEdge::new(vertices, GlobalEdge::new(global_curve, global_vertices))
+    }
+
+    fn vertex(&mut self, vertex: &Vertex) -> Vertex {
+        let curve = self.curve(vertex.curve());
+        let surface_form = self.surface_vertex(vertex.surface_form());
+
+        Vertex::new(vertex.position(), curve, surface_form)
+    }
+
+    fn surface_vertex(&self, surface_vertex: &SurfaceVertex) -> SurfaceVertex {
+        SurfaceVertex::new(
+            surface_vertex.position(),
+            self.surface(surface_vertex.surface()),
+            self.global_vertex(surface_vertex.global_form()),
+        )
+    }
+
+    fn curve(&mut self, curve: &Handle<Curve>) -> Handle<Curve> {
+        if let Some(replacement) = self.curves.get(&curve.id()) {
+            return replacement.clone();
+        }
+
+        let surface = self.surface(curve.surface());
+        let global_form = self.global_curve(curve.global_form());
+
+        let rebuilt = if surface.id() == curve.surface().id()
+            && global_form.id() == curve.global_form().id()
+        {
+            curve.clone()
+        } else {
+            Curve::new(surface, curve.path().clone(), global_form, self.objects)
+                .with_label(curve.label())
+        };
+
+        self.curves.insert(curve.id(), rebuilt.clone());
+        rebuilt
+    }
+
+    fn global_curve(
+        &self,
+        global_curve: &Handle<GlobalCurve>,
+    ) -> Handle<GlobalCurve> {
+        replacement_or_original(&self.global_curves, global_curve)
+    }
+
+    fn global_vertex(
+        &self,
+        global_vertex: &Handle<GlobalVertex>,
+    ) -> Handle<GlobalVertex> {
+        replacement_or_original(&self.global_vertices, global_vertex)
+    }
+
+    fn surface(&self, surface: &Handle<Surface>) -> Handle<Surface> {
+        replacement_or_original(&self.surfaces, surface)
+    }
+}
+
+fn replacement_or_original<T>(
+    replacements: &HashMap<ObjectId, Handle<T>>,
+    original: &Handle<T>,
+) -> Handle<T> {
+    replacements.get(&original.id()).unwrap_or(original).clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::validate::Validate,
+        iter::ObjectIters,
+        objects::{Face, Objects, Sketch, Surface},
+    };
+
+    use super::Replace;
+
+    #[test]
+    fn replace_surface() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let face = Face::builder(&objects, surface.clone())
+            .with_exterior_polygon_from_points([
+                [0., 0.],
+                [1., 0.],
+                [1., 1.],
+                [0., 1.],
+            ])
+            .build()?;
+        let sketch = Sketch::new().with_faces([face]);
+
+        // The replacement has the same geometry, but is a different object.
+        // Replacing a surface with one that has a different geometry would
+        // require replacing the global vertices too.
+        let replacement = objects.surfaces.insert(Surface::xy_plane());
+        assert_ne!(replacement.id(), surface.id());
+
+        let sketch = sketch.replace(&surface, replacement.clone(), &objects);
+
+        for half_edge in sketch.half_edge_iter() {
+            assert_eq!(half_edge.curve().surface().id(), replacement.id());
+        }
+        for face in sketch.face_iter() {
+            assert_eq!(face.surface().id(), replacement.id());
+        }
+
+        // Curves are rebuilt, but the vertices are still shared between the
+        // half-edges.
+        assert_eq!(sketch.global_vertex_iter().count(), 4);
+        sketch.validate()?;
+
+        Ok(())
+    }
+}
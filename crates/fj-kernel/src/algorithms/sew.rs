@@ -0,0 +1,342 @@
+//! Sewing of loose faces into shells
+//!
+//! Faces that are created independently of each other, for example by an
+//! import, don't share their edges and vertices, even where they touch. Sewing
+//! matches the edges of those faces, where they coincide, and rebuilds the
+//! faces, so matched edges refer to the same global objects.
+//!
+//! Two half-edges match, if they connect the same vertices in opposite
+//! directions, their midpoints coincide, and no other half-edge shares their
+//! edge. This is the same definition that [`find_edge_issues`] uses to
+//! validate shells. Faces that are connected by matched edges end up in the
+//! same shell.
+//!
+//! # Implementation Note
+//!
+//! The geometry of the faces is not changed. Vertices that are within the
+//! tolerance of each other are merged into a single global vertex, at the
+//! position of one of them. If the vertices of a face are moved that way by
+//! more than [`ValidationConfig::identical_max_distance`], the resulting shell
+//! won't pass validation.
+//!
+//! [`find_edge_issues`]: super::validate::find_edge_issues
+//! [`ValidationConfig::identical_max_distance`]: super::validate::ValidationConfig::identical_max_distance
+
+use std::collections::BTreeMap;
+
+use fj_math::{Point, Scalar};
+
+use crate::{
+    algorithms::validate::group_half_edges,
+    objects::{
+        Curve, Cycle, Face, Faces, GlobalCurve, GlobalEdge, GlobalVertex,
+        HalfEdge, Objects, Shell, SurfaceVertex, Vertex,
+    },
+    storage::Handle,
+};
+
+/// Sew loose faces into shells
+pub trait Sew {
+    /// Match the coincident edges of the faces, and build shells from them
+    ///
+    /// Vertices and edge midpoints that are within `tolerance` of each other
+    /// are considered to be coincident.
+    fn sew(self, tolerance: impl Into<Scalar>, objects: &Objects) -> Sewn;
+}
+
+impl Sew for Faces {
+    fn sew(self, tolerance: impl Into<Scalar>, objects: &Objects) -> Sewn {
+        let tolerance = tolerance.into();
+        let faces = self.into_iter().collect::<Vec<_>>();
+
+        // Merge vertices that are within the tolerance of each other.
+        let mut positions = Vec::new();
+        let mut half_edges = Vec::new();
+        let mut vertices = BTreeMap::new();
+        for (i, face) in faces.iter().enumerate() {
+            for (j, cycle) in face.all_cycles().enumerate() {
+                for (k, half_edge) in cycle.half_edges().enumerate() {
+                    let indices = half_edge.vertices().clone().map(|vertex| {
+                        let position = vertex.global_form().position();
+                        insert(&mut positions, position, tolerance)
+                    });
+                    vertices.insert([i, j, k], indices);
+                    half_edges.push((
+                        [i, j, k],
+                        half_edge,
+                        indices.map(|index| positions[index]),
+                    ));
+                }
+            }
+        }
+
+        let global_vertices = positions
+            .iter()
+            .map(|&position| GlobalVertex::from_position(position, objects))
+            .collect::<Vec<_>>();
+
+        // Match the half-edges of each edge, if that edge is shared by exactly
+        // two of them, running in opposite directions. This is the same
+        // definition of a closed shell that validation uses.
+        let mut matches = BTreeMap::new();
+        for edge in group_half_edges(half_edges, tolerance) {
+            if let [a, b] = edge.as_slice() {
+                if a.runs_opposite_to(b, tolerance) {
+                    matches.insert(a.id, b.id);
+                    matches.insert(b.id, a.id);
+                }
+            }
+        }
+
+        // Faces that are connected by matched edges end up in the same shell.
+        let mut shell_of_face = (0..faces.len()).collect::<Vec<_>>();
+        for (&[a, ..], &[b, ..]) in &matches {
+            let [a, b] = [a, b].map(|face| root(&mut shell_of_face, face));
+            shell_of_face[a.max(b)] = a.min(b);
+        }
+
+        let mut global_curves = BTreeMap::new();
+        let mut shells = BTreeMap::<_, Vec<_>>::new();
+        let mut open_edges = Vec::new();
+        for (i, face) in faces.iter().enumerate() {
+            let mut cycles = Vec::new();
+            for (j, cycle) in face.all_cycles().enumerate() {
+                let mut half_edges = Vec::new();
+                for (k, half_edge) in cycle.half_edges().enumerate() {
+                    let id = [i, j, k];
+
+                    // Matched half-edges share their global curve.
+                    let key = match matches.get(&id) {
+                        Some(&other) => id.min(other),
+                        None => id,
+                    };
+                    let global_curve = global_curves
+                        .entry(key)
+                        .or_insert_with(|| GlobalCurve::new(objects))
+                        .clone();
+
+                    let half_edge = rebuild(
+                        half_edge,
+                        vertices[&id].map(|v| global_vertices[v].clone()),
+                        global_curve,
+                        objects,
+                    );
+
+                    if !matches.contains_key(&id) {
+                        open_edges.push(half_edge.clone());
+                    }
+                    half_edges.push(half_edge);
+                }
+
+                cycles.push(Cycle::new(cycle.surface().clone(), half_edges));
+            }
+
+            let mut cycles = cycles.into_iter();
+            let exterior = cycles.next().expect("Face has an exterior");
+            let sewn = Face::from_exterior(exterior)
+                .with_interiors(cycles)
+                .with_color(face.color());
+
+            let shell = root(&mut shell_of_face, i);
+            shells.entry(shell).or_default().push(sewn);
+        }
+
+        Sewn {
+            shells: shells
+                .into_values()
+                .map(|faces| Shell::new().with_faces(faces))
+                .collect(),
+            open_edges,
+        }
+    }
+}
+
+/// The result of sewing faces
+#[derive(Clone, Debug)]
+pub struct Sewn {
+    /// The shells that were built from the faces
+    ///
+    /// Each shell consists of faces, that are connected to each other by
+    /// matched edges.
+    pub shells: Vec<Shell>,
+
+    /// The half-edges that couldn't be matched with another
+    ///
+    /// If this is empty, all shells are closed.
+    pub open_edges: Vec<HalfEdge>,
+}
+
+/// Rebuild a half-edge, using the provided global objects
+fn rebuild(
+    half_edge: &HalfEdge,
+    global_vertices: [Handle<GlobalVertex>; 2],
+    global_curve: Handle<GlobalCurve>,
+    objects: &Objects,
+) -> HalfEdge {
+    let curve = half_edge.curve();
+    let curve = Curve::new(
+        curve.surface().clone(),
+        curve.path().clone(),
+        global_curve.clone(),
+        objects,
+    );
+
+    let [a, b] = half_edge.vertices();
+    let vertices = [
+        (a, global_vertices[0].clone()),
+        (b, global_vertices[1].clone()),
+    ]
+    .map(|(vertex, global_form)| {
+        let surface_form = vertex.surface_form();
+        let surface_form = SurfaceVertex::new(
+            surface_form.position(),
+            surface_form.surface().clone(),
+            global_form,
+        );
+        Vertex::new(vertex.position(), curve.clone(), surface_form)
+    });
+
+    HalfEdge::new(vertices, GlobalEdge::new(global_curve, global_vertices))
+}
+
+/// Insert a point, unless one within `tolerance` already exists
+///
+/// Returns the index of the point.
+fn insert(
+    points: &mut Vec<Point<3>>,
+    point: Point<3>,
+    tolerance: Scalar,
+) -> usize {
+    let existing = points
+        .iter()
+        .position(|&existing| (existing - point).magnitude() <= tolerance);
+
+    existing.unwrap_or_else(|| {
+        points.push(point);
+        points.len() - 1
+    })
+}
+
+fn root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Mesh;
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, measure::mass_properties, sweep::Sweep,
+            test_helpers, transform::TransformObject, triangulate::Triangulate,
+            validate::Validate,
+        },
+        objects::{
+            Cycle, Face, Faces, HalfEdge, Objects, Shell, Solid, Surface,
+        },
+        partial::HasPartial,
+    };
+
+    use super::Sew;
+
+    #[test]
+    fn cube() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let cube = test_helpers::cube(1., &objects);
+        let faces =
+            loosen(cube.into_shells().flat_map(Shell::into_faces), &objects)?;
+
+        let sewn = faces.clone().sew(1e-9, &objects);
+        assert_eq!(sewn.shells.len(), 1);
+        assert!(sewn.open_edges.is_empty());
+
+        // Sewing and building shells agree on which shells are closed.
+        for shell in &sewn.shells {
+            Shell::builder(&objects)
+                .with_faces(shell.faces().clone())
+                .build()?;
+        }
+
+        let solid = Solid::new()
+            .with_shells(sewn.shells)
+            .validate()?
+            .into_inner();
+        assert!(
+            (volume(&solid)? - Scalar::ONE).abs() < Scalar::from_f64(1e-12)
+        );
+
+        // Without one of its faces, the cube is open.
+        let mut faces = faces.into_iter();
+        faces.next();
+        let mut open = Faces::new();
+        open.extend(faces);
+
+        let sewn = open.sew(1e-9, &objects);
+        assert_eq!(sewn.shells.len(), 1);
+        assert_eq!(sewn.open_edges.len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cylinder() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let circle = HalfEdge::partial()
+            .with_surface(Some(surface.clone()))
+            .as_circle_from_radius(1.)?
+            .build(&objects)?;
+        let cylinder = Face::from_exterior(Cycle::new(surface, [circle]))
+            .sweep([0., 0., 1.], &objects);
+
+        // A second cylinder that doesn't touch the first ends up in its own
+        // shell.
+        let faces = loosen(
+            [
+                cylinder.clone(),
+                cylinder.translate([3., 0., 0.], &objects)?,
+            ]
+            .into_iter()
+            .flat_map(Shell::into_faces),
+            &objects,
+        )?;
+
+        let sewn = faces.sew(1e-9, &objects);
+        assert_eq!(sewn.shells.len(), 2);
+        assert!(sewn.open_edges.is_empty());
+
+        for shell in sewn.shells {
+            let solid =
+                Solid::new().with_shells([shell]).validate()?.into_inner();
+            let volume = volume(&solid)?;
+            assert!(volume < Scalar::PI && volume > Scalar::PI - 0.01);
+        }
+
+        Ok(())
+    }
+
+    /// Recreate each face, so the faces don't share any objects
+    fn loosen(
+        faces: impl IntoIterator<Item = Face>,
+        objects: &Objects,
+    ) -> anyhow::Result<Faces> {
+        let mut loose = Faces::new();
+        for face in faces {
+            loose.extend([face.translate([0., 0., 0.], objects)?]);
+        }
+        Ok(loose)
+    }
+
+    fn volume(solid: &Solid) -> anyhow::Result<Scalar> {
+        let mesh: Mesh<Point<3>> =
+            (solid, Tolerance::from_scalar(0.001)?).triangulate();
+        Ok(mass_properties(&mesh).volume)
+    }
+}
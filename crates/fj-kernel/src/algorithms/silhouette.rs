@@ -0,0 +1,210 @@
+//! Silhouettes of shapes
+//!
+//! The silhouette of a shape, as seen from a view direction, consists of the
+//! edges where its surface turns away from the viewer. Together with the edges
+//! of open shapes, they form the outline of the shape, as it would appear in a
+//! 2D drawing.
+//!
+//! The silhouette is computed from the triangulated form of the shape. This
+//! means the silhouette of a curved shape runs along edges of its
+//! triangulation, and is only accurate up to the tolerance that is used for
+//! it. Whether the edges of the silhouette are hidden behind other parts of
+//! the shape is not considered.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Point, PolyChain, Scalar, Vector};
+
+use super::{approx::Tolerance, triangulate::Triangulate};
+
+/// Compute the silhouette of an object
+pub trait Silhouette {
+    /// Compute the silhouette, as seen when looking into `direction`
+    ///
+    /// Returns the silhouette as polygonal chains in model space. Chains that
+    /// are closed repeat their first point as their last. To get 2D curves
+    /// for a drawing, project the chains into a plane that is perpendicular to
+    /// `direction`.
+    fn silhouette(self, direction: impl Into<Vector<3>>) -> Vec<PolyChain<3>>;
+}
+
+impl<T> Silhouette for (T, Tolerance)
+where
+    (T, Tolerance): Triangulate,
+{
+    fn silhouette(self, direction: impl Into<Vector<3>>) -> Vec<PolyChain<3>> {
+        let mesh = self.triangulate();
+        silhouette_of_mesh(&mesh, direction.into())
+    }
+}
+
+fn silhouette_of_mesh(
+    mesh: &Mesh<Point<3>>,
+    direction: Vector<3>,
+) -> Vec<PolyChain<3>> {
+    let vertices = mesh.vertices().collect::<Vec<_>>();
+    let indices = mesh.indices().collect::<Vec<_>>();
+
+    // For each edge, whether the triangles next to it face the viewer.
+    let mut edges = BTreeMap::<_, Vec<_>>::new();
+    for (triangle, indices) in mesh.triangles().zip(indices.chunks(3)) {
+        let faces_viewer =
+            triangle.inner.normal().dot(&direction) < Scalar::ZERO;
+
+        for (i, j) in [(0, 1), (1, 2), (2, 0)] {
+            let [a, b] = [indices[i], indices[j]];
+            edges
+                .entry([a.min(b), a.max(b)])
+                .or_default()
+                .push(faces_viewer);
+        }
+    }
+
+    // Edges between a triangle that faces the viewer and one that doesn't are
+    // part of the silhouette, as are edges that belong to a single triangle.
+    let segments = edges
+        .into_iter()
+        .filter(|(_, faces_viewer)| match faces_viewer.as_slice() {
+            [a, b] => a != b,
+            _ => true,
+        })
+        .map(|([a, b], _)| [a, b].map(|i| vertices[i as usize]));
+
+    chain_segments(segments)
+}
+
+/// Connect segments that share points into polygonal chains
+fn chain_segments(
+    segments: impl IntoIterator<Item = [Point<3>; 2]>,
+) -> Vec<PolyChain<3>> {
+    let mut neighbors = BTreeMap::<_, BTreeSet<_>>::new();
+    for [a, b] in segments {
+        neighbors.entry(a).or_default().insert(b);
+        neighbors.entry(b).or_default().insert(a);
+    }
+
+    let mut chains = Vec::new();
+
+    // Chains that aren't closed need to start at one of their ends, or they
+    // would be split up. Closed chains can start anywhere.
+    loop {
+        let start = neighbors
+            .iter()
+            .find(|(_, neighbors)| neighbors.len() != 2)
+            .or_else(|| neighbors.iter().next())
+            .map(|(&point, _)| point);
+        let start = match start {
+            Some(start) => start,
+            None => break,
+        };
+
+        let mut points = vec![start];
+        let mut current = start;
+        while let Some(next) = take_neighbor(&mut neighbors, current) {
+            points.push(next);
+            if next == start {
+                break;
+            }
+            current = next;
+        }
+
+        if points.len() > 1 {
+            chains.push(PolyChain::from_points(points));
+        }
+    }
+
+    chains
+}
+
+/// Remove the segment from `point` to one of its neighbors, and return that
+/// neighbor
+fn take_neighbor(
+    neighbors: &mut BTreeMap<Point<3>, BTreeSet<Point<3>>>,
+    point: Point<3>,
+) -> Option<Point<3>> {
+    let next = *neighbors.get(&point)?.iter().next()?;
+
+    for [a, b] in [[point, next], [next, point]] {
+        if let Some(points) = neighbors.get_mut(&a) {
+            points.remove(&b);
+            if points.is_empty() {
+                neighbors.remove(&a);
+            }
+        }
+    }
+
+    Some(next)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Scalar, Vector};
+
+    use crate::{
+        algorithms::{approx::Tolerance, sweep::Sweep, test_helpers},
+        objects::{Cycle, Face, HalfEdge, Objects, Surface},
+        partial::HasPartial,
+    };
+
+    use super::Silhouette;
+
+    #[test]
+    fn cube() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let cube = test_helpers::cube(1., &objects);
+
+        // Looking from above, the outline is the top face. The side faces are
+        // seen edge-on, so they don't face the viewer.
+        let silhouette = (&cube, tolerance).silhouette([0., 0., -1.]);
+        assert_eq!(silhouette.len(), 1);
+        let points = silhouette[0].points();
+        assert_eq!(points.len(), 5);
+        assert_eq!(points.first(), points.last());
+        assert!(points.iter().all(|point| point.z == Scalar::ONE));
+
+        // Looking at a corner, the outline is a hexagon.
+        let silhouette = (&cube, tolerance).silhouette([-1., -1., -1.]);
+        assert_eq!(silhouette.len(), 1);
+        assert_eq!(silhouette[0].points().len(), 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cylinder() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let circle = HalfEdge::partial()
+            .with_surface(Some(surface.clone()))
+            .as_circle_from_radius(1.)?
+            .build(&objects)?;
+        let cylinder = Face::from_exterior(Cycle::new(surface, [circle]))
+            .sweep([0., 0., 1.], &objects);
+
+        // Looking from the side, the outline is a rectangle. It consists of
+        // the front halves of the circles at the top and bottom, and of lines
+        // on the sides of the cylinder, where its surface turns away from the
+        // viewer.
+        let direction = Vector::from([0., 1., 0.]);
+        let silhouette = (&cylinder, tolerance).silhouette(direction);
+        assert_eq!(silhouette.len(), 1);
+
+        let points = silhouette[0].points();
+        assert_eq!(points.first(), points.last());
+        for point in points {
+            assert!(point.y <= 0.1.into(), "{point:?} is on back side");
+        }
+
+        let xs = points.iter().map(|point| point.x).collect::<Vec<_>>();
+        let min = xs.iter().copied().min().unwrap();
+        let max = xs.iter().copied().max().unwrap();
+        assert!(min < (-0.99).into() && max > 0.99.into());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,468 @@
+//! Slicing of solids by a stack of parallel planes
+//!
+//! This is the foundation for preparing a model for 3D printing, or for
+//! exporting its cross-sections.
+//!
+//! Each layer can be converted into a [`Sketch`], to derive new shapes from the
+//! cross-sections of existing ones.
+//!
+//! The solid is sliced in its triangulated form. This means the loops are exact
+//! for solids that are bounded by planar faces with straight edges. Otherwise,
+//! their accuracy depends on the tolerance that is used for the triangulation.
+
+use std::collections::BTreeMap;
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Line, Plane, Point, PolyChain, Scalar, Vector};
+
+use crate::{
+    objects::{Face, Objects, Sketch, Surface},
+    partial::PartialBuildError,
+    path::GlobalPath,
+};
+
+use super::{approx::Tolerance, triangulate::Triangulate};
+
+/// Slice an object with a stack of parallel planes
+pub trait Slice {
+    /// Slice the object
+    ///
+    /// The planes are parallel to `plane`. `offsets` defines their distances
+    /// from `plane`, along its normal. Returns one layer per offset, in the
+    /// same order.
+    fn slice(
+        self,
+        plane: &Plane,
+        offsets: impl IntoIterator<Item = Scalar>,
+    ) -> Vec<Layer>;
+}
+
+impl<T> Slice for (T, Tolerance)
+where
+    (T, Tolerance): Triangulate,
+{
+    fn slice(
+        self,
+        plane: &Plane,
+        offsets: impl IntoIterator<Item = Scalar>,
+    ) -> Vec<Layer> {
+        let mesh = self.triangulate();
+        let normal = plane.normal().normalize();
+
+        offsets
+            .into_iter()
+            .map(|offset| {
+                let plane = Plane::from_parametric(
+                    plane.origin() + normal * offset,
+                    plane.u(),
+                    plane.v(),
+                );
+                slice_mesh(&mesh, plane)
+            })
+            .collect()
+    }
+}
+
+/// Compute the cross-section of an object in a plane
+pub trait Section {
+    /// Compute the cross-section
+    ///
+    /// Returns a sketch in `plane`, with one face for each region in which the
+    /// plane cuts the object. See [`Layer::to_sketch`].
+    fn section(
+        self,
+        plane: &Plane,
+        objects: &Objects,
+    ) -> Result<Sketch, PartialBuildError>;
+}
+
+impl<T> Section for (T, Tolerance)
+where
+    (T, Tolerance): Triangulate,
+{
+    fn section(
+        self,
+        plane: &Plane,
+        objects: &Objects,
+    ) -> Result<Sketch, PartialBuildError> {
+        let mesh = self.triangulate();
+        slice_mesh(&mesh, *plane).to_sketch(objects)
+    }
+}
+
+/// A layer of a sliced object
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Layer {
+    /// The plane in which the layer was sliced
+    pub plane: Plane,
+
+    /// The closed loops in which the plane cuts the object
+    ///
+    /// The loops are in the coordinates of `plane`, and the first point of each
+    /// loop is repeated as its last. Viewed against the normal of the plane,
+    /// loops around material are wound counter-clockwise, loops around holes
+    /// are wound clockwise.
+    pub loops: Vec<PolyChain<2>>,
+}
+
+impl Layer {
+    /// Convert the layer into a sketch
+    ///
+    /// The sketch is defined in a surface that represents the plane of the
+    /// layer. Each loop around material becomes the exterior of a face, and
+    /// each loop around a hole becomes an interior of the face that
+    /// immediately surrounds it.
+    ///
+    /// Returns an error, if the faces can't be built from the loops.
+    pub fn to_sketch(
+        &self,
+        objects: &Objects,
+    ) -> Result<Sketch, PartialBuildError> {
+        let surface = objects.surfaces.insert(Surface::new(
+            GlobalPath::Line(Line::from_origin_and_direction(
+                self.plane.origin(),
+                self.plane.u(),
+            )),
+            self.plane.v(),
+        ));
+
+        // The loops repeat their first point as their last one, which the
+        // faces don't need.
+        let polygons = self.loops.iter().map(|polygon| {
+            let points = polygon.points();
+            &points[..points.len() - 1]
+        });
+        let (exteriors, holes): (Vec<_>, Vec<_>) = polygons
+            .map(|polygon| (polygon, signed_area(polygon)))
+            .partition(|(_, area)| *area > Scalar::ZERO);
+
+        let mut interiors = vec![Vec::new(); exteriors.len()];
+        for (hole, _) in holes {
+            // Loops don't cross each other. If a point of the hole is within
+            // an exterior, all of it is. The smallest of those is the one that
+            // immediately surrounds the hole.
+            let exterior = exteriors
+                .iter()
+                .enumerate()
+                .filter(|(_, (exterior, _))| contains(exterior, hole[0]))
+                .min_by_key(|(_, (_, area))| *area)
+                .map(|(i, _)| i);
+
+            if let Some(i) = exterior {
+                interiors[i].push(hole);
+            }
+        }
+
+        let faces = exteriors.into_iter().zip(interiors).map(
+            |((exterior, _), interiors)| {
+                interiors.into_iter().fold(
+                    Face::builder(objects, surface.clone())
+                        .with_exterior_polygon_from_points(
+                            exterior.iter().copied(),
+                        ),
+                    |face, interior| {
+                        face.with_interior_polygon_from_points(
+                            interior.iter().copied(),
+                        )
+                    },
+                )
+            },
+        );
+
+        let faces = faces
+            .map(|face| face.build())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Sketch::new().with_faces(faces))
+    }
+}
+
+fn slice_mesh(mesh: &Mesh<Point<3>>, plane: Plane) -> Layer {
+    let (distance, normal) = plane.constant_normal_form();
+    let side = |point: Point<3>| normal.dot(&point.coords) - distance;
+
+    // Maps the start of each segment to the ends of all segments that start
+    // there.
+    let mut segments = BTreeMap::<_, Vec<_>>::new();
+
+    for triangle in mesh.triangles() {
+        let points = triangle.inner.points();
+
+        // Points that lie exactly in the plane are treated as if they were in
+        // front of it. This way, each triangle either doesn't cross the plane,
+        // or crosses it in exactly two of its edges.
+        let in_front = points.map(|point| side(point) >= Scalar::ZERO);
+
+        let mut crossings = Vec::new();
+        for (i, j) in [(0, 1), (1, 2), (2, 0)] {
+            if in_front[i] != in_front[j] {
+                crossings.push(crossing(points[i], points[j], side));
+            }
+        }
+
+        let [a, b] = match crossings.as_slice() {
+            &[a, b] => [a, b],
+            _ => continue,
+        };
+        if a == b {
+            continue;
+        }
+
+        // Orient the segment, so the material is on its left side, when
+        // viewed against the normal of the plane.
+        let direction = normal.cross(&triangle.inner.normal());
+        let [a, b] = if (b - a).dot(&direction) >= Scalar::ZERO {
+            [a, b]
+        } else {
+            [b, a]
+        };
+
+        segments.entry(a).or_default().push(b);
+    }
+
+    let mut loops = Vec::new();
+
+    while let Some(&start) = segments.keys().next() {
+        let mut points = Vec::new();
+        let mut is_closed = false;
+
+        let mut current = start;
+        while let Some(next) = take_segment(&mut segments, current) {
+            points.push(current);
+
+            if next == start {
+                is_closed = true;
+                break;
+            }
+            current = next;
+        }
+
+        // If the mesh is not closed, neither are the loops. These are ignored,
+        // as they don't bound anything.
+        if is_closed {
+            let points = points
+                .into_iter()
+                .map(|point| plane.point_to_plane_coords(point));
+            loops.push(PolyChain::from_points(points).close());
+        }
+    }
+
+    Layer { plane, loops }
+}
+
+/// Remove a segment that starts at `start`, and return its end
+fn take_segment(
+    segments: &mut BTreeMap<Point<3>, Vec<Point<3>>>,
+    start: Point<3>,
+) -> Option<Point<3>> {
+    let ends = segments.get_mut(&start)?;
+    let end = ends.pop();
+
+    if ends.is_empty() {
+        segments.remove(&start);
+    }
+
+    end
+}
+
+fn signed_area(polygon: &[Point<2>]) -> Scalar {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| a.u * b.v - b.u * a.v)
+        .fold(Scalar::ZERO, |sum, area| sum + area)
+        / 2.
+}
+
+/// Determine whether a polygon contains a point
+///
+/// Points on the boundary of the polygon may or may not be considered to be
+/// contained.
+fn contains(polygon: &[Point<2>], point: Point<2>) -> bool {
+    let mut inside = false;
+
+    for (a, b) in polygon.iter().zip(polygon.iter().cycle().skip(1)) {
+        if (a.v > point.v) != (b.v > point.v) {
+            let u = a.u + (point.v - a.v) / (b.v - a.v) * (b.u - a.u);
+            if point.u < u {
+                inside = !inside;
+            }
+        }
+    }
+
+    inside
+}
+
+/// Compute the point where the plane crosses the line segment between `a` and
+/// `b`
+///
+/// Returns the same point, regardless of the order of `a` and `b`, so the
+/// segments of neighboring triangles connect exactly.
+fn crossing(
+    a: Point<3>,
+    b: Point<3>,
+    side: impl Fn(Point<3>) -> Scalar,
+) -> Point<3> {
+    let [a, b] = if a < b { [a, b] } else { [b, a] };
+    let [side_a, side_b] = [a, b].map(side);
+
+    let direction: Vector<3> = b - a;
+    a + direction * (side_a / (side_a - side_b))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Plane, Point, PolyChain, Scalar, Vector};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, measure::face_area, sweep::Sweep,
+            test_helpers::cube, validate::Validate,
+        },
+        objects::{Cycle, Face, HalfEdge, Objects, Surface},
+        partial::HasPartial,
+    };
+
+    use super::{Section, Slice};
+
+    #[test]
+    fn slice_cube() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let cube = cube(1., &objects);
+
+        let layers = (&cube, Tolerance::from_scalar(0.01)?)
+            .slice(&xy_plane(), [0.25, 0.5, 2.].map(Scalar::from_f64));
+
+        assert_eq!(layers.len(), 3);
+        for layer in &layers[..2] {
+            assert_eq!(layer.loops.len(), 1);
+            assert_eq!(signed_area(&layer.loops[0]), Scalar::ONE);
+        }
+        assert_eq!(layers[1].plane.origin(), Point::from([0., 0., 0.5]));
+
+        // The last plane misses the cube.
+        assert!(layers[2].loops.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn slice_solid_with_hole() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let solid = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([
+                [0., 0.],
+                [4., 0.],
+                [4., 4.],
+                [0., 4.],
+            ])
+            .with_interior_polygon_from_points([
+                [1., 1.],
+                [1., 2.],
+                [2., 2.],
+                [2., 1.],
+            ])
+            .build()?
+            .sweep([0., 0., 1.], &objects);
+
+        let layers = (&solid, Tolerance::from_scalar(0.01)?)
+            .slice(&xy_plane(), [Scalar::from_f64(0.5)]);
+
+        let mut areas =
+            layers[0].loops.iter().map(signed_area).collect::<Vec<_>>();
+        areas.sort();
+        assert_eq!(areas, [-Scalar::ONE, Scalar::from_f64(16.)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn slice_cylinder() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let circle = HalfEdge::partial()
+            .with_surface(Some(surface.clone()))
+            .as_circle_from_radius(1.)?
+            .build(&objects)?;
+        let cylinder = Face::from_exterior(Cycle::new(surface, [circle]))
+            .sweep([0., 0., 1.], &objects);
+
+        let layers = (&cylinder, Tolerance::from_scalar(0.01)?)
+            .slice(&xy_plane(), [Scalar::from_f64(0.5)]);
+        assert_eq!(layers[0].loops.len(), 1);
+
+        // The loop approximates the circle from the inside.
+        let area = signed_area(&layers[0].loops[0]);
+        assert!(area < Scalar::PI && area > Scalar::PI - 0.1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn section_to_sketch() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let solid = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([
+                [0., 0.],
+                [4., 0.],
+                [4., 4.],
+                [0., 4.],
+            ])
+            .with_interior_polygon_from_points([
+                [1., 1.],
+                [1., 2.],
+                [2., 2.],
+                [2., 1.],
+            ])
+            .build()?
+            .sweep([0., 0., 1.], &objects);
+
+        let plane = Plane::from_parametric(
+            Point::from([0., 0., 0.5]),
+            Vector::unit_x(),
+            Vector::unit_y(),
+        );
+        let sketch = (&solid, Tolerance::from_scalar(0.01)?)
+            .section(&plane, &objects)?
+            .validate()?
+            .into_inner();
+
+        let faces = sketch.faces().into_iter().collect::<Vec<_>>();
+        assert_eq!(faces.len(), 1);
+        assert_eq!(faces[0].interiors().count(), 1);
+
+        // The sketch can be used to create new shapes.
+        let area = face_area(faces[0], Tolerance::from_scalar(0.01)?);
+        assert_eq!(area, Scalar::from_f64(15.));
+        let point = faces[0].surface().point_from_surface_coords([0., 0.]);
+        assert_eq!(point, Point::from([0., 0., 0.5]));
+
+        Ok(())
+    }
+
+    fn xy_plane() -> Plane {
+        Plane::from_parametric(
+            Point::origin(),
+            Vector::unit_x(),
+            Vector::unit_y(),
+        )
+    }
+
+    fn signed_area(polygon: &PolyChain<2>) -> Scalar {
+        polygon
+            .segments()
+            .into_iter()
+            .map(|segment| {
+                let [a, b] = segment.points();
+                a.u * b.v - b.u * a.v
+            })
+            .fold(Scalar::ZERO, |sum, area| sum + area)
+            / 2.
+    }
+}
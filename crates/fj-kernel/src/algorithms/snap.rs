@@ -0,0 +1,320 @@
+//! Snapping of nearly coincident vertices
+//!
+//! Transforms and other operations introduce small numerical errors. Vertices
+//! that should coincide can end up slightly apart, and the local forms of a
+//! vertex can drift away from its global form. Validation rejects both.
+//!
+//! Snapping merges global vertices that are within a maximum distance of each
+//! other, and rebuilds straight edges on planar surfaces, so they run exactly
+//! between their merged vertices.
+//!
+//! # Implementation Note
+//!
+//! Curved edges, and edges on curved surfaces, keep their local forms. Only
+//! their global vertices are replaced. If snapping moves those by more than
+//! [`ValidationConfig::identical_max_distance`], the result still won't pass
+//! validation.
+//!
+//! [`ValidationConfig::identical_max_distance`]: super::validate::ValidationConfig::identical_max_distance
+
+use fj_math::{Plane, Point, Scalar};
+
+use crate::{
+    geometry::{SurfaceGeometry, SweptCurve},
+    iter::ObjectIters,
+    objects::{
+        Curve, Cycle, Face, Faces, GlobalEdge, GlobalVertex, HalfEdge, Objects,
+        Shell, Sketch, Solid, SurfaceVertex, Vertex,
+    },
+    path::{GlobalPath, SurfacePath},
+    storage::Handle,
+};
+
+use super::validate::{Validate, Validated, ValidationConfig, ValidationError};
+
+/// Snap nearly coincident vertices together
+pub trait Snap: Sized {
+    /// Merge global vertices that are within `max_distance` of each other
+    #[must_use]
+    fn snap(self, max_distance: impl Into<Scalar>, objects: &Objects) -> Self;
+
+    /// Validate the object, snapping it first, if that's required and enabled
+    ///
+    /// If validation fails because of vertices that are too close to each
+    /// other, or whose local and global forms don't match, and
+    /// [`ValidationConfig::snap_distance`] is set, the object is snapped and
+    /// validated again.
+    fn validate_or_snap(
+        self,
+        config: &ValidationConfig,
+        objects: &Objects,
+    ) -> Result<Validated<Self>, ValidationError>
+    where
+        Self: Validate + Clone,
+    {
+        let snap_distance = match config.snap_distance {
+            Some(snap_distance) => snap_distance,
+            None => return self.validate_with_config(config),
+        };
+
+        match self.clone().validate_with_config(config) {
+            Err(
+                ValidationError::Coherence(_) | ValidationError::Uniqueness(_),
+            ) => self
+                .snap(snap_distance, objects)
+                .validate_with_config(config),
+            result => result,
+        }
+    }
+}
+
+impl Snap for Faces {
+    fn snap(self, max_distance: impl Into<Scalar>, objects: &Objects) -> Self {
+        let snapper = Snapper::new(&self, max_distance.into(), objects);
+
+        let mut faces = Faces::new();
+        faces.extend(self.into_iter().map(|face| snapper.face(&face)));
+        faces
+    }
+}
+
+impl Snap for Shell {
+    fn snap(self, max_distance: impl Into<Scalar>, objects: &Objects) -> Self {
+        let snapper = Snapper::new(&self, max_distance.into(), objects);
+
+        Shell::new().with_faces(
+            self.into_faces()
+                .into_iter()
+                .map(|face| snapper.face(&face)),
+        )
+    }
+}
+
+impl Snap for Sketch {
+    fn snap(self, max_distance: impl Into<Scalar>, objects: &Objects) -> Self {
+        let snapper = Snapper::new(&self, max_distance.into(), objects);
+
+        Sketch::new().with_faces(
+            self.into_faces()
+                .into_iter()
+                .map(|face| snapper.face(&face)),
+        )
+    }
+}
+
+impl Snap for Solid {
+    fn snap(self, max_distance: impl Into<Scalar>, objects: &Objects) -> Self {
+        let snapper = Snapper::new(&self, max_distance.into(), objects);
+
+        Solid::new().with_shells(self.into_shells().map(|shell| {
+            Shell::new().with_faces(
+                shell
+                    .into_faces()
+                    .into_iter()
+                    .map(|face| snapper.face(&face)),
+            )
+        }))
+    }
+}
+
+/// Rebuilds faces, using the merged global vertices
+struct Snapper<'r> {
+    positions: Vec<Point<3>>,
+    global_vertices: Vec<Handle<GlobalVertex>>,
+    max_distance: Scalar,
+    objects: &'r Objects,
+}
+
+impl<'r> Snapper<'r> {
+    fn new(
+        object: &impl for<'a> ObjectIters<'a>,
+        max_distance: Scalar,
+        objects: &'r Objects,
+    ) -> Self {
+        // Each vertex is merged into the first one within the maximum
+        // distance, so the result doesn't depend on anything but the order of
+        // the vertices.
+        let mut positions = Vec::new();
+        for global_vertex in object.global_vertex_iter() {
+            let position = global_vertex.position();
+            if !positions.iter().any(|&existing: &Point<3>| {
+                (existing - position).magnitude() <= max_distance
+            }) {
+                positions.push(position);
+            }
+        }
+
+        let global_vertices = positions
+            .iter()
+            .map(|&position| GlobalVertex::from_position(position, objects))
+            .collect();
+
+        Self {
+            positions,
+            global_vertices,
+            max_distance,
+            objects,
+        }
+    }
+
+    fn global_vertex(&self, position: Point<3>) -> Handle<GlobalVertex> {
+        let i = self
+            .positions
+            .iter()
+            .position(|&existing| {
+                (existing - position).magnitude() <= self.max_distance
+            })
+            .expect("Every position has been merged into another");
+
+        self.global_vertices[i].clone()
+    }
+
+    fn face(&self, face: &Face) -> Face {
+        let mut cycles = face.all_cycles().map(|cycle| {
+            Cycle::new(
+                cycle.surface().clone(),
+                cycle
+                    .half_edges()
+                    .map(|half_edge| self.half_edge(half_edge)),
+            )
+        });
+
+        let exterior = cycles.next().expect("Face has an exterior");
+        Face::from_exterior(exterior)
+            .with_interiors(cycles)
+            .with_color(face.color())
+    }
+
+    fn half_edge(&self, half_edge: &HalfEdge) -> HalfEdge {
+        let global_vertices = half_edge
+            .vertices()
+            .clone()
+            .map(|vertex| self.global_vertex(vertex.global_form().position()));
+
+        let curve = half_edge.curve();
+        let surface = curve.surface();
+
+        // Straight edges on planar surfaces are rebuilt from the merged
+        // vertices, so their local forms match them exactly.
+        let plane = match (curve.path(), surface.geometry()) {
+            (
+                SurfacePath::Line(_),
+                SurfaceGeometry::SweptCurve(SweptCurve {
+                    u: GlobalPath::Line(line),
+                    v,
+                }),
+            ) => Some(Plane::from_parametric(
+                line.origin(),
+                line.direction(),
+                *v,
+            )),
+            _ => None,
+        };
+        let [a, b] = &global_vertices;
+        let plane = plane.filter(|_| a.position() != b.position());
+
+        let vertices = match plane {
+            Some(plane) => {
+                let points_surface = global_vertices.clone().map(|vertex| {
+                    plane.point_to_plane_coords(vertex.position())
+                });
+                let curve = Curve::new(
+                    surface.clone(),
+                    SurfacePath::line_from_points(points_surface),
+                    curve.global_form().clone(),
+                    self.objects,
+                );
+
+                let [a, b] = global_vertices.clone();
+                [(0., points_surface[0], a), (1., points_surface[1], b)].map(
+                    |(position, point_surface, global_form)| {
+                        let surface_form = SurfaceVertex::new(
+                            point_surface,
+                            surface.clone(),
+                            global_form,
+                        );
+                        Vertex::new([position], curve.clone(), surface_form)
+                    },
+                )
+            }
+            None => {
+                let [a, b] = half_edge.vertices();
+                let [a_global, b_global] = global_vertices.clone();
+                [(a, a_global), (b, b_global)].map(|(vertex, global_form)| {
+                    let surface_form = SurfaceVertex::new(
+                        vertex.surface_form().position(),
+                        surface.clone(),
+                        global_form,
+                    );
+                    Vertex::new(vertex.position(), curve.clone(), surface_form)
+                })
+            }
+        };
+
+        HalfEdge::new(
+            vertices,
+            GlobalEdge::new(curve.global_form().clone(), global_vertices),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::{
+            transform::TransformObject,
+            validate::{Validate, ValidationConfig, ValidationError},
+        },
+        iter::ObjectIters,
+        objects::{Face, Faces, Objects, Surface},
+    };
+
+    use super::Snap;
+
+    #[test]
+    fn nearly_coincident_vertices() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let a = Face::builder(&objects, surface.clone())
+            .with_exterior_polygon_from_points([
+                [0., 0.],
+                [1., 0.],
+                [1., 1.],
+                [0., 1.],
+            ])
+            .build()?;
+        let b = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([
+                [1., 0.],
+                [2., 0.],
+                [2., 1.],
+                [1., 1.],
+            ])
+            .build()?
+            .translate([1e-9, 0., 0.], &objects)?;
+
+        let mut faces = Faces::new();
+        faces.extend([a, b]);
+
+        // The vertices of the shared edge are too close to be distinct.
+        let result = faces.clone().validate();
+        assert!(matches!(result, Err(ValidationError::Uniqueness(_))));
+
+        let snapped = faces.clone().snap(1e-6, &objects);
+        assert_eq!(snapped.global_vertex_iter().count(), 6);
+        snapped.validate()?;
+
+        // Snapping during validation is opt-in.
+        let config = ValidationConfig::default();
+        assert!(faces.clone().validate_or_snap(&config, &objects).is_err());
+
+        let config = ValidationConfig {
+            snap_distance: Some(1e-6.into()),
+            ..config
+        };
+        faces.validate_or_snap(&config, &objects)?;
+
+        Ok(())
+    }
+}
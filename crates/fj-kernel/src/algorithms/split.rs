@@ -0,0 +1,135 @@
+//! Splitting of solids by a plane
+//!
+//! # Implementation Note
+//!
+//! The solid is split by intersecting it with, and subtracting from it, a box
+//! that covers the half-space in front of the plane. This means the same
+//! limitations as for the [boolean operations](super::boolean) apply.
+
+use fj_math::{Aabb, Plane, Point, Scalar};
+
+use crate::{
+    algorithms::{
+        boolean::{Difference, Intersection},
+        sweep::Sweep,
+    },
+    iter::ObjectIters,
+    objects::{Objects, Shell, Sketch, Solid, Surface},
+};
+
+/// Split an object by a plane
+pub trait Split: Sized {
+    /// Split the object by the given plane
+    ///
+    /// Returns the part in front of the plane (in the direction of its normal)
+    /// first, and the part behind it second. Both parts are closed by a cap
+    /// in the plane. A part is `None`, if the object doesn't extend to that
+    /// side of the plane.
+    fn split(self, plane: &Plane, objects: &Objects) -> [Option<Self>; 2];
+}
+
+impl Split for Solid {
+    fn split(self, plane: &Plane, objects: &Objects) -> [Option<Self>; 2] {
+        let aabb = Aabb::<3>::from_points(
+            self.global_vertex_iter()
+                .map(|vertex| vertex.position())
+                .collect::<Vec<Point<3>>>(),
+        );
+        let half_space = half_space(plane, &aabb, objects);
+
+        let front = self.clone().intersection(half_space.clone(), objects);
+        let back = self.difference(half_space, objects);
+
+        [front, back].map(|part| {
+            let is_empty = part.shells().flat_map(Shell::faces).count() == 0;
+            (!is_empty).then_some(part)
+        })
+    }
+}
+
+/// Create a box that covers the part of `aabb` in front of `plane`
+fn half_space(plane: &Plane, aabb: &Aabb<3>, objects: &Objects) -> Solid {
+    let normal = plane.normal().normalize();
+
+    // The box is centered on the projection of the bounding volume's center
+    // into the plane, and is larger than the bounding volume in every
+    // direction.
+    let center = aabb.center();
+    let origin = center - normal * normal.dot(&(center - plane.origin()));
+    let size = aabb.size().magnitude() + Scalar::ONE;
+
+    let u = plane.u().normalize();
+    let v = normal.cross(&u);
+    let surface = objects.surfaces.insert(Surface::plane_from_points([
+        origin,
+        origin + u,
+        origin + v,
+    ]));
+
+    Sketch::builder(objects, surface)
+        .build_polygon_from_points([
+            [-size, -size],
+            [size, -size],
+            [size, size],
+            [-size, size],
+        ])
+        .expect("Failed to build half-space")
+        .sweep(normal * size * 2., objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Plane, Point, Vector};
+
+    use crate::{
+        algorithms::{
+            test_helpers::{bounding_volume, closed_shell, cube},
+            validate::Validate,
+        },
+        objects::Objects,
+    };
+
+    use super::Split;
+
+    #[test]
+    fn split_cube() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let plane = Plane::from_parametric(
+            Point::from([0.5, 0., 0.]),
+            Vector::unit_y(),
+            Vector::unit_z(),
+        );
+        let [front, back] = cube(1., &objects).split(&plane, &objects);
+
+        let front = closed_shell(front.unwrap(), &objects)?;
+        let back = closed_shell(back.unwrap(), &objects)?;
+
+        for (shell, aabb) in [
+            (front, [[0.5, 0., 0.], [1., 1., 1.]]),
+            (back, [[0., 0., 0.], [0.5, 1., 1.]]),
+        ] {
+            assert_eq!(shell.faces().into_iter().count(), 6);
+            assert_eq!(bounding_volume(&shell), Aabb::<3>::from_points(aabb));
+
+            shell.validate()?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_by_plane_that_misses_solid() {
+        let objects = Objects::new();
+
+        let plane = Plane::from_parametric(
+            Point::from([0., 0., 2.]),
+            Vector::unit_x(),
+            Vector::unit_y(),
+        );
+        let [front, back] = cube(1., &objects).split(&plane, &objects);
+
+        assert!(front.is_none());
+        assert!(back.is_some());
+    }
+}
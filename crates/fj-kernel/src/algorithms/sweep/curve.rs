@@ -1,6 +1,9 @@
-use fj_math::{Circle, Line, Vector};
+use fj_math::{
+    Circle, CubicBezier, Cylinder, Ellipse, Hyperbola, Line, Parabola, Vector,
+};
 
 use crate::{
+    geometry::{SurfaceGeometry, SweptCurve},
     objects::{Curve, Objects, Surface},
     path::{GlobalPath, SurfacePath},
     storage::Handle,
@@ -16,8 +19,15 @@ impl Sweep for Handle<Curve> {
         path: impl Into<Vector<3>>,
         objects: &Objects,
     ) -> Self::Swept {
-        match self.surface().u() {
-            GlobalPath::Circle(_) => {
+        match self.surface().geometry() {
+            SurfaceGeometry::SweptCurve(SweptCurve {
+                u: GlobalPath::Line(_),
+                ..
+            }) => {
+                // We're sweeping from a curve on a flat surface, which is
+                // supported. Carry on.
+            }
+            _ => {
                 // Sweeping a `Curve` creates a `Surface`. The u-axis of that
                 // `Surface` is a `GlobalPath`, which we are computing below.
                 // That computation might or might not work with an arbitrary
@@ -34,10 +44,6 @@ impl Sweep for Handle<Curve> {
                     not supported yet."
                 )
             }
-            GlobalPath::Line(_) => {
-                // We're sweeping from a curve on a flat surface, which is
-                // supported. Carry on.
-            }
         }
 
         let u = match self.path() {
@@ -51,6 +57,62 @@ impl Sweep for Handle<Curve> {
 
                 GlobalPath::Circle(circle)
             }
+            SurfacePath::Ellipse(ellipse) => {
+                let center =
+                    self.surface().point_from_surface_coords(ellipse.center());
+                let a = self.surface().vector_from_surface_coords(ellipse.a());
+                let b = self.surface().vector_from_surface_coords(ellipse.b());
+
+                let ellipse = Ellipse::new(center, a, b);
+
+                GlobalPath::Ellipse(ellipse)
+            }
+            SurfacePath::Parabola(parabola) => {
+                let vertex =
+                    self.surface().point_from_surface_coords(parabola.vertex());
+                let a = self.surface().vector_from_surface_coords(parabola.a());
+                let b = self.surface().vector_from_surface_coords(parabola.b());
+
+                GlobalPath::Parabola(Parabola::new(vertex, a, b))
+            }
+            SurfacePath::Hyperbola(hyperbola) => {
+                let center = self
+                    .surface()
+                    .point_from_surface_coords(hyperbola.center());
+                let a =
+                    self.surface().vector_from_surface_coords(hyperbola.a());
+                let b =
+                    self.surface().vector_from_surface_coords(hyperbola.b());
+
+                GlobalPath::Hyperbola(Hyperbola::new(center, a, b))
+            }
+            SurfacePath::Polyline(polyline) => {
+                let polyline = polyline.map_points(|point| {
+                    self.surface().point_from_surface_coords(point)
+                });
+
+                GlobalPath::Polyline(polyline)
+            }
+            SurfacePath::Bezier(bezier) => {
+                let control_points = bezier.control_points().map(|point| {
+                    self.surface().point_from_surface_coords(point)
+                });
+
+                let bezier = CubicBezier::from_control_points(control_points);
+
+                GlobalPath::Bezier(bezier)
+            }
+            SurfacePath::Nurbs(nurbs) => {
+                // The surface is flat, so the mapping from surface to global
+                // coordinates is affine. NURBS curves are invariant under
+                // affine transformations, so mapping the control points is
+                // enough.
+                let nurbs = nurbs.map_control_points(|point| {
+                    self.surface().point_from_surface_coords(point)
+                });
+
+                GlobalPath::Nurbs(nurbs)
+            }
             SurfacePath::Line(line) => {
                 let origin =
                     self.surface().point_from_surface_coords(line.origin());
@@ -63,6 +125,21 @@ impl Sweep for Handle<Curve> {
             }
         };
 
-        objects.surfaces.insert(Surface::new(u, path))
+        let path = path.into();
+
+        // Sweeping a circle along its normal results in a cylinder. Those
+        // have a dedicated representation, which gives us exact
+        // intersections.
+        let surface = match &u {
+            GlobalPath::Circle(circle) => {
+                match Cylinder::from_circle_and_axis(circle, path) {
+                    Some(cylinder) => Surface::from_cylinder(cylinder),
+                    None => Surface::new(u, path),
+                }
+            }
+            _ => Surface::new(u, path),
+        };
+
+        objects.surfaces.insert(surface).with_label(self.label())
     }
 }
@@ -0,0 +1,207 @@
+use fj_math::{Plane, Scalar, Vector};
+
+use crate::{
+    algorithms::{
+        boolean::assemble::Assembler,
+        offset::{displacement, PolygonFace},
+    },
+    objects::{Face, Objects, Shell, Sketch, Solid, Surface},
+};
+
+use super::SweepWithDraft;
+
+impl SweepWithDraft for Face {
+    type Swept = Shell;
+
+    fn sweep_with_draft(
+        self,
+        path: impl Into<Vector<3>>,
+        angle: impl Into<Scalar>,
+        objects: &Objects,
+    ) -> Self::Swept {
+        let path = path.into();
+        let angle = angle.into();
+
+        let face = PolygonFace::new(&self);
+
+        let height = face.normal().dot(&path);
+        assert!(
+            height != Scalar::ZERO,
+            "Can't sweep face along a path that is parallel to it"
+        );
+        let is_negative_sweep = height < Scalar::ZERO;
+
+        // The cycles are wound counter-clockwise around the direction of the
+        // sweep. Then the inside of the face is on the left of each edge, no
+        // matter which side of the face is its front.
+        let (normal, height) = if is_negative_sweep {
+            (-face.normal(), -height)
+        } else {
+            (face.normal(), height)
+        };
+        let cycles = face
+            .cycles
+            .iter()
+            .map(|cycle| {
+                let mut cycle = cycle.clone();
+                if is_negative_sweep {
+                    cycle.reverse();
+                }
+                cycle
+            })
+            .collect::<Vec<_>>();
+
+        let (sin, cos) = angle.sin_cos();
+        let inset = height * sin / cos;
+
+        // Each vertex at the top is moved, such that both of its edges end up
+        // moved inwards by the inset.
+        let top = cycles
+            .iter()
+            .map(|cycle| {
+                let n = cycle.len();
+                (0..n)
+                    .map(|i| {
+                        let [prev, point, next] =
+                            [(i + n - 1) % n, i, (i + 1) % n].map(|i| cycle[i]);
+                        let inward = [point - prev, next - point].map(|edge| {
+                            (normal.cross(&edge).normalize(), inset)
+                        });
+
+                        point + displacement(&inward) + path
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut assembler = Assembler::new();
+
+        // The bottom faces against the direction of the sweep, so it is
+        // reversed.
+        assembler.add_polygon(
+            face.surface.clone(),
+            face.plane,
+            face.color,
+            cycles
+                .iter()
+                .map(|cycle| cycle.iter().rev().copied().collect())
+                .collect(),
+        );
+
+        let origin = face.plane.origin() + path;
+        assembler.add_polygon(
+            objects.surfaces.insert(Surface::plane_from_points([
+                origin,
+                origin + face.plane.u(),
+                origin + face.plane.v(),
+            ])),
+            Plane::from_parametric(origin, face.plane.u(), face.plane.v()),
+            face.color,
+            top.clone(),
+        );
+
+        for (bottom, top) in cycles.iter().zip(&top) {
+            let n = bottom.len();
+            for i in 0..n {
+                let j = (i + 1) % n;
+                let polygon = [bottom[i], bottom[j], top[j], top[i]];
+
+                let surface = Surface::plane_from_points([
+                    polygon[0], polygon[1], polygon[3],
+                ]);
+                let plane = Plane::from_parametric(
+                    polygon[0],
+                    polygon[1] - polygon[0],
+                    polygon[3] - polygon[0],
+                );
+
+                assembler.add_polygon(
+                    objects.surfaces.insert(surface),
+                    plane,
+                    face.color,
+                    vec![polygon.to_vec()],
+                );
+            }
+        }
+
+        assembler.build(objects)
+    }
+}
+
+impl SweepWithDraft for Sketch {
+    type Swept = Solid;
+
+    fn sweep_with_draft(
+        self,
+        path: impl Into<Vector<3>>,
+        angle: impl Into<Scalar>,
+        objects: &Objects,
+    ) -> Self::Swept {
+        let path = path.into();
+        let angle = angle.into();
+
+        let mut shells = Vec::new();
+        for face in self.into_faces() {
+            let shell = face.sweep_with_draft(path, angle, objects);
+            shells.push(shell);
+        }
+
+        Solid::new().with_shells(shells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{
+            test_helpers::{closed_shell, has_vertex, square},
+            validate::Validate,
+        },
+        objects::Objects,
+    };
+
+    use super::SweepWithDraft;
+
+    #[test]
+    fn sweep_with_draft() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        // At this angle, the sides move inwards by half the height.
+        let angle = Scalar::from_f64(0.5_f64.atan());
+        let solid = square([0., 0.], 2., &objects).sweep_with_draft(
+            [0., 0., 1.],
+            angle,
+            &objects,
+        );
+        let shell = closed_shell(solid, &objects)?;
+
+        assert_eq!(shell.faces().into_iter().count(), 6);
+        assert!(has_vertex(&shell, [0.5, 0.5, 1.]));
+        assert!(has_vertex(&shell, [1.5, 1.5, 1.]));
+
+        shell.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_with_negative_draft_against_normal() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let angle = Scalar::from_f64(-0.5_f64.atan());
+        let solid = square([0., 0.], 2., &objects).sweep_with_draft(
+            [0., 0., -1.],
+            angle,
+            &objects,
+        );
+        let shell = closed_shell(solid, &objects)?;
+
+        assert_eq!(shell.faces().into_iter().count(), 6);
+        assert!(has_vertex(&shell, [-0.5, -0.5, -1.]));
+        assert!(has_vertex(&shell, [2.5, 2.5, -1.]));
+
+        shell.validate()?;
+        Ok(())
+    }
+}
@@ -104,7 +104,8 @@ impl Sweep for (HalfEdge, Color) {
                     .curve()
                     .global_form()
                     .clone()
-                    .translate(path, objects);
+                    .translate(path, objects)
+                    .expect("Translating a global curve can't fail");
 
                 // Please note that creating a line here is correct, even if the
                 // global curve is a circle. Projected into the side surface, it
@@ -184,6 +185,7 @@ impl Sweep for (HalfEdge, Color) {
 #[cfg(test)]
 mod tests {
     use fj_interop::mesh::Color;
+    use fj_math::{Point, Scalar};
     use pretty_assertions::assert_eq;
 
     use crate::{
@@ -193,13 +195,13 @@ mod tests {
     };
 
     #[test]
-    fn sweep() {
+    fn sweep() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let half_edge = HalfEdge::partial()
             .with_surface(Some(objects.surfaces.insert(Surface::xy_plane())))
-            .as_line_segment_from_points([[0., 0.], [1., 0.]])
-            .build(&objects);
+            .as_line_segment_from_points([[0., 0.], [1., 0.]])?
+            .build(&objects)?;
 
         let face = (half_edge, Color::default()).sweep([0., 0., 1.], &objects);
 
@@ -208,22 +210,22 @@ mod tests {
 
             let bottom = HalfEdge::partial()
                 .with_surface(Some(surface.clone()))
-                .as_line_segment_from_points([[0., 0.], [1., 0.]])
-                .build(&objects);
+                .as_line_segment_from_points([[0., 0.], [1., 0.]])?
+                .build(&objects)?;
             let top = HalfEdge::partial()
                 .with_surface(Some(surface.clone()))
-                .as_line_segment_from_points([[0., 1.], [1., 1.]])
-                .build(&objects)
+                .as_line_segment_from_points([[0., 1.], [1., 1.]])?
+                .build(&objects)?
                 .reverse();
             let left = HalfEdge::partial()
                 .with_surface(Some(surface.clone()))
-                .as_line_segment_from_points([[0., 0.], [0., 1.]])
-                .build(&objects)
+                .as_line_segment_from_points([[0., 0.], [0., 1.]])?
+                .build(&objects)?
                 .reverse();
             let right = HalfEdge::partial()
                 .with_surface(Some(surface.clone()))
-                .as_line_segment_from_points([[1., 0.], [1., 1.]])
-                .build(&objects);
+                .as_line_segment_from_points([[1., 0.], [1., 1.]])?
+                .build(&objects)?;
 
             let cycle = Cycle::new(surface, [bottom, right, top, left]);
 
@@ -231,5 +233,41 @@ mod tests {
         };
 
         assert_eq!(face, expected_face);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_arc() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let half_edge = HalfEdge::partial()
+            .with_surface(Some(objects.surfaces.insert(Surface::xy_plane())))
+            .as_arc_from_points_and_radius([[1., 0.], [0., 1.]], 1.)
+            .build(&objects)?;
+
+        let face = (half_edge, Color::default()).sweep([0., 0., 1.], &objects);
+
+        let mut corners = face
+            .exterior()
+            .half_edges()
+            .map(|half_edge| {
+                let [vertex, _] = half_edge.vertices();
+                vertex.global_form().position()
+            })
+            .collect::<Vec<_>>();
+        corners.sort();
+
+        let mut expected_corners =
+            [[1., 0., 0.], [0., 1., 0.], [0., 1., 1.], [1., 0., 1.]]
+                .map(Point::from)
+                .to_vec();
+        expected_corners.sort();
+
+        for (corner, expected) in corners.into_iter().zip(expected_corners) {
+            assert!((corner - expected).magnitude() < Scalar::from(1e-10));
+        }
+
+        Ok(())
     }
 }
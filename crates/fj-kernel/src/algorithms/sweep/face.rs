@@ -2,6 +2,7 @@ use fj_math::{Scalar, Vector};
 
 use crate::{
     algorithms::{reverse::Reverse, transform::TransformObject},
+    geometry::{SurfaceGeometry, SweptCurve},
     objects::{Face, Objects, Shell},
     path::GlobalPath,
 };
@@ -21,14 +22,16 @@ impl Sweep for Face {
         let mut faces = Vec::new();
 
         let is_negative_sweep = {
-            let u = match self.surface().u() {
-                GlobalPath::Circle(_) => todo!(
+            let (u, v) = match self.surface().geometry() {
+                SurfaceGeometry::SweptCurve(SweptCurve {
+                    u: GlobalPath::Line(line),
+                    v,
+                }) => (line.direction(), *v),
+                _ => todo!(
                     "Sweeping from faces defined in round surfaces is not \
                     supported"
                 ),
-                GlobalPath::Line(line) => line.direction(),
             };
-            let v = self.surface().v();
 
             let normal = u.cross(&v);
 
@@ -45,7 +48,12 @@ impl Sweep for Face {
         faces.push(bottom_face);
 
         let top_face = {
-            let mut face = self.clone().translate(path, objects);
+            // Translating a full face only fails, if build validation is
+            // enabled in `objects` and rejects the result.
+            let mut face = self
+                .clone()
+                .translate(path, objects)
+                .expect("Failed to translate face to top of sweep");
 
             if is_negative_sweep {
                 face = face.reverse();
@@ -92,82 +100,96 @@ mod tests {
     const DOWN: [f64; 3] = [0., 0., -1.];
 
     #[test]
-    fn sweep_up() {
+    fn sweep_up() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let solid = Sketch::builder(&objects, surface.clone())
-            .build_polygon_from_points(TRIANGLE)
+            .build_polygon_from_points(TRIANGLE)?
             .sweep(UP, &objects);
 
         let bottom = Face::builder(&objects, surface.clone())
             .with_exterior_polygon_from_points(TRIANGLE)
-            .build()
+            .build()?
             .reverse();
-        let top = Face::builder(&objects, surface.translate(UP, &objects))
+        let top = Face::builder(&objects, surface.translate(UP, &objects)?)
             .with_exterior_polygon_from_points(TRIANGLE)
-            .build();
+            .build()?;
 
         assert!(solid.find_face(&bottom).is_some());
         assert!(solid.find_face(&top).is_some());
 
-        let mut side_faces = TRIANGLE.windows(2).map(|window| {
-            // Can't panic, as we passed `2` to `windows`.
-            //
-            // Can be cleaned up, once `array_windows` is stable:
-            // https://doc.rust-lang.org/std/primitive.slice.html#method.array_windows
-            let [a, b] = [window[0], window[1]];
-
-            let half_edge = HalfEdge::partial()
-                .with_surface(Some(
-                    objects.surfaces.insert(Surface::xy_plane()),
-                ))
-                .as_line_segment_from_points([a, b])
-                .build(&objects);
-            (half_edge, Color::default()).sweep(UP, &objects)
-        });
-
-        assert!(side_faces.all(|face| solid.find_face(&face).is_some()));
+        let side_faces = TRIANGLE
+            .windows(2)
+            .map(|window| -> anyhow::Result<_> {
+                // Can't panic, as we passed `2` to `windows`.
+                //
+                // Can be cleaned up, once `array_windows` is stable:
+                // https://doc.rust-lang.org/std/primitive.slice.html#method.array_windows
+                let [a, b] = [window[0], window[1]];
+
+                let half_edge = HalfEdge::partial()
+                    .with_surface(Some(
+                        objects.surfaces.insert(Surface::xy_plane()),
+                    ))
+                    .as_line_segment_from_points([a, b])?
+                    .build(&objects)?;
+                Ok((half_edge, Color::default()).sweep(UP, &objects))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert!(side_faces
+            .iter()
+            .all(|face| solid.find_face(face).is_some()));
+
+        Ok(())
     }
 
     #[test]
-    fn sweep_down() {
+    fn sweep_down() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let solid = Sketch::builder(&objects, surface.clone())
-            .build_polygon_from_points(TRIANGLE)
+            .build_polygon_from_points(TRIANGLE)?
             .sweep(DOWN, &objects);
 
         let bottom =
-            Face::builder(&objects, surface.clone().translate(DOWN, &objects))
+            Face::builder(&objects, surface.clone().translate(DOWN, &objects)?)
                 .with_exterior_polygon_from_points(TRIANGLE)
-                .build()
+                .build()?
                 .reverse();
         let top = Face::builder(&objects, surface)
             .with_exterior_polygon_from_points(TRIANGLE)
-            .build();
+            .build()?;
 
         assert!(solid.find_face(&bottom).is_some());
         assert!(solid.find_face(&top).is_some());
 
-        let mut side_faces = TRIANGLE.windows(2).map(|window| {
-            // Can't panic, as we passed `2` to `windows`.
-            //
-            // Can be cleaned up, once `array_windows` is stable:
-            // https://doc.rust-lang.org/std/primitive.slice.html#method.array_windows
-            let [a, b] = [window[0], window[1]];
-
-            let half_edge = HalfEdge::partial()
-                .with_surface(Some(
-                    objects.surfaces.insert(Surface::xy_plane()),
-                ))
-                .as_line_segment_from_points([a, b])
-                .build(&objects)
-                .reverse();
-            (half_edge, Color::default()).sweep(DOWN, &objects)
-        });
-
-        assert!(side_faces.all(|face| solid.find_face(&face).is_some()));
+        let side_faces = TRIANGLE
+            .windows(2)
+            .map(|window| -> anyhow::Result<_> {
+                // Can't panic, as we passed `2` to `windows`.
+                //
+                // Can be cleaned up, once `array_windows` is stable:
+                // https://doc.rust-lang.org/std/primitive.slice.html#method.array_windows
+                let [a, b] = [window[0], window[1]];
+
+                let half_edge = HalfEdge::partial()
+                    .with_surface(Some(
+                        objects.surfaces.insert(Surface::xy_plane()),
+                    ))
+                    .as_line_segment_from_points([a, b])?
+                    .build(&objects)?
+                    .reverse();
+                Ok((half_edge, Color::default()).sweep(DOWN, &objects))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert!(side_faces
+            .iter()
+            .all(|face| solid.find_face(face).is_some()));
+
+        Ok(())
     }
 }
@@ -1,14 +1,19 @@
 //! Sweeping objects along a path to create new objects
 
 mod curve;
+mod draft;
 mod edge;
 mod face;
+mod path;
+mod revolve;
 mod sketch;
 mod vertex;
 
-use fj_math::Vector;
+use fj_math::{Line, Scalar, Vector};
 
-use crate::objects::Objects;
+use crate::{
+    algorithms::approx::path::RangeOnPath, objects::Objects, path::GlobalPath,
+};
 
 /// Sweep an object along a path to create another object
 pub trait Sweep {
@@ -22,3 +27,75 @@ pub trait Sweep {
         objects: &Objects,
     ) -> Self::Swept;
 }
+
+/// Sweep an object along a straight path, while tapering it
+pub trait SweepWithDraft {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along the given path, tapering its sides
+    ///
+    /// The side faces are inclined towards the inside of the object by
+    /// `angle` (in radians), relative to the normal of the object. Molded
+    /// parts need this, to be released from their mold. A negative angle
+    /// inclines the side faces outward.
+    ///
+    /// # Implementation Note
+    ///
+    /// Only objects in planar surfaces with straight edges are supported so
+    /// far. If the angle is so large that edges of the object would collapse,
+    /// the result is invalid.
+    fn sweep_with_draft(
+        self,
+        path: impl Into<Vector<3>>,
+        angle: impl Into<Scalar>,
+        objects: &Objects,
+    ) -> Self::Swept;
+}
+
+/// Sweep an object along a curved path to create another object
+pub trait SweepAlongPath {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along a section of the given path
+    ///
+    /// The object is moved from the start of `range` to its end. It keeps its
+    /// position and orientation relative to the path while doing so, which
+    /// means cross-sections that start out perpendicular to the path stay
+    /// perpendicular to it.
+    ///
+    /// # Implementation Note
+    ///
+    /// Only lines, circles, and helices are supported as paths so far.
+    fn sweep_along_path(
+        self,
+        path: &GlobalPath,
+        range: impl Into<RangeOnPath>,
+        objects: &Objects,
+    ) -> Self::Swept;
+}
+
+/// Revolve an object around an axis to create another object
+pub trait Revolve {
+    /// The object that is created by revolving the implementing object
+    type Revolved;
+
+    /// Revolve the object around the given axis
+    ///
+    /// The object is revolved by `angle` (in radians), following the
+    /// right-hand rule around the direction of the axis. Unless that is a full
+    /// revolution, the result is capped by the object at the start and end
+    /// angle.
+    ///
+    /// # Implementation Note
+    ///
+    /// Only objects in planar surfaces with straight edges are supported so
+    /// far. They must not touch the axis.
+    fn revolve(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        objects: &Objects,
+    ) -> Self::Revolved;
+}
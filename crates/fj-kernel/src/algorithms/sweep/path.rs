@@ -0,0 +1,197 @@
+use fj_math::Line;
+
+use crate::{
+    algorithms::approx::path::RangeOnPath,
+    objects::{Objects, Sketch, Solid},
+    path::GlobalPath,
+};
+
+use super::{revolve::sweep_around_axis, Revolve, Sweep, SweepAlongPath};
+
+impl SweepAlongPath for Sketch {
+    type Swept = Solid;
+
+    fn sweep_along_path(
+        self,
+        path: &GlobalPath,
+        range: impl Into<RangeOnPath>,
+        objects: &Objects,
+    ) -> Self::Swept {
+        let [start, end] = range.into().boundary;
+
+        match path {
+            GlobalPath::Line(_) => {
+                let path = path.point_from_path_coords(end)
+                    - path.point_from_path_coords(start);
+                self.sweep(path, objects)
+            }
+            GlobalPath::Circle(circle) => {
+                // Moving along a circle is a revolution around its axis. The
+                // circle coordinates are angles, so the angle of the
+                // revolution is the length of the range.
+                let axis = Line::from_origin_and_direction(
+                    circle.center(),
+                    circle.a().cross(&circle.b()),
+                );
+                let angle = end.t - start.t;
+
+                self.revolve(axis, angle, objects)
+            }
+            GlobalPath::Helix(helix) => {
+                // Moving along a helix is a revolution around its axis, while
+                // advancing along it. Like those of a circle, the helix
+                // coordinates are angles.
+                let axis = Line::from_origin_and_direction(
+                    helix.center(),
+                    helix.a().cross(&helix.b()),
+                );
+                let angle = end.t - start.t;
+                let pitch = helix.axis().dot(&axis.direction().normalize());
+
+                let mut shells = Vec::new();
+                for face in self.faces() {
+                    let shell =
+                        sweep_around_axis(face, axis, angle, pitch, objects);
+                    shells.push(shell);
+                }
+
+                Solid::new().with_shells(shells)
+            }
+            _ => todo!("Sweeping along {path:?} is not supported"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Circle, Helix, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance,
+            test_helpers::{
+                bounding_volume, closed_shell, has_vertex, square_on,
+            },
+            triangulate::Triangulate,
+            validate::Validate,
+        },
+        objects::{Objects, Sketch, Surface},
+        path::GlobalPath,
+    };
+
+    use super::SweepAlongPath;
+
+    #[test]
+    fn sweep_along_line() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let path = GlobalPath::line_from_points([[0., 0., 0.], [0., 2., 0.]]);
+        let solid =
+            square(&objects).sweep_along_path(&path, [[0.], [0.5]], &objects);
+        let shell = closed_shell(solid, &objects)?;
+
+        assert_eq!(shell.faces().into_iter().count(), 6);
+        assert_eq!(
+            bounding_volume(&shell),
+            Aabb::<3>::from_points([[1., 0., 0.], [2., 1., 1.]])
+        );
+
+        shell.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_along_arc() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let solid = square(&objects).sweep_along_path(
+            &circle(),
+            [[Scalar::ZERO], [Scalar::PI / 2.]],
+            &objects,
+        );
+        let shell = closed_shell(solid, &objects)?;
+
+        // Both caps, and a side face for each edge of the square.
+        assert_eq!(shell.faces().into_iter().count(), 6);
+        assert!(has_vertex(&shell, [2., 0., 1.]));
+        assert!(has_vertex(&shell, [0., 2., 1.]));
+        assert!(!has_vertex(&shell, [2., 2., 1.]));
+
+        shell.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_along_full_circle() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let solid = square(&objects).sweep_along_path(
+            &circle(),
+            [[Scalar::ZERO], [Scalar::TAU]],
+            &objects,
+        );
+        let shell = closed_shell(solid, &objects)?;
+
+        // There are no caps, and each side face is split into thirds.
+        assert_eq!(shell.faces().into_iter().count(), 12);
+
+        // The edges follow the circle, so the approximation stays within the
+        // swept volume.
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&shell, tolerance).triangulate();
+        assert!(mesh.vertices().count() > 8);
+        for vertex in mesh.vertices() {
+            let radius = Vector::from([vertex.x, vertex.y]).magnitude();
+            assert!(radius > Scalar::from_f64(1. - 0.01));
+            assert!(radius < Scalar::from_f64(2. + 0.01));
+        }
+
+        shell.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_along_helix() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let helix = GlobalPath::Helix(Helix::from_axis_radius_and_pitch(
+            Point::origin(),
+            Vector::unit_z(),
+            1.5,
+            2.,
+            2.,
+        ));
+        let solid = square(&objects).sweep_along_path(
+            &helix,
+            [[Scalar::ZERO], [Scalar::TAU * 2.]],
+            &objects,
+        );
+        let shell = closed_shell(solid, &objects)?;
+
+        // Both caps, and the side faces for two turns, split into thirds.
+        assert_eq!(shell.faces().into_iter().count(), 2 + 4 * 6);
+        assert!(has_vertex(&shell, [1., 0., 4.]));
+        assert!(has_vertex(&shell, [2., 0., 5.]));
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&shell, tolerance).triangulate();
+        for vertex in mesh.vertices() {
+            let radius = Vector::from([vertex.x, vertex.y]).magnitude();
+            assert!(radius > Scalar::from_f64(1. - 0.01));
+            assert!(radius < Scalar::from_f64(2. + 0.01));
+        }
+
+        shell.validate()?;
+        Ok(())
+    }
+
+    /// A square in the xz-plane, perpendicular to the path of [`circle`]
+    fn square(objects: &Objects) -> Sketch {
+        let surface = objects.surfaces.insert(Surface::xz_plane());
+        square_on(surface, [1., 0.], 1., objects)
+    }
+
+    fn circle() -> GlobalPath {
+        GlobalPath::Circle(Circle::from_center_and_radius(Point::origin(), 1.5))
+    }
+}
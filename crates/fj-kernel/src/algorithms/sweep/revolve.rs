@@ -0,0 +1,288 @@
+//! Revolving planar faces around an axis
+//!
+//! While revolving, a face can also advance along the axis, which results in a
+//! helical sweep. That is how threads and springs are created.
+//!
+//! # Implementation Note
+//!
+//! Only faces in planar surfaces with straight edges are supported so far.
+//! None of their vertices may touch the axis, as the side faces would
+//! degenerate there.
+//!
+//! The turns of a helical sweep must not touch each other. If the face extends
+//! further along the axis than the pitch, the result is invalid.
+
+use fj_math::{Helix, Line, Plane, Point, Scalar, Transform, Vector};
+
+use crate::{
+    algorithms::{boolean::assemble::Assembler, offset::PolygonFace},
+    objects::{Face, Objects, Shell, Sketch, Solid, Surface},
+    path::GlobalPath,
+};
+
+use super::Revolve;
+
+impl Revolve for Face {
+    type Revolved = Shell;
+
+    fn revolve(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        objects: &Objects,
+    ) -> Self::Revolved {
+        sweep_around_axis(&self, axis, angle.into(), Scalar::ZERO, objects)
+    }
+}
+
+impl Revolve for Sketch {
+    type Revolved = Solid;
+
+    fn revolve(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        objects: &Objects,
+    ) -> Self::Revolved {
+        let angle = angle.into();
+
+        let mut shells = Vec::new();
+        for face in self.into_faces() {
+            let shell = face.revolve(axis, angle, objects);
+            shells.push(shell);
+        }
+
+        Solid::new().with_shells(shells)
+    }
+}
+
+/// Sweep a face around an axis
+///
+/// The face is revolved by `angle` (in radians), following the right-hand
+/// rule around the direction of the axis. During every revolution, it advances
+/// along the direction of the axis by `pitch`.
+///
+/// A full revolution without any pitch results in a shell without caps.
+///
+/// # Panics
+///
+/// Panics, if the angle is zero, if it is larger than a full revolution while
+/// the pitch is zero, or if the face touches the axis.
+pub fn sweep_around_axis(
+    face: &Face,
+    axis: Line<3>,
+    angle: Scalar,
+    pitch: Scalar,
+    objects: &Objects,
+) -> Shell {
+    // A negative angle is the same as a positive one around the reversed axis.
+    // This reverses the direction of the pitch too, so it stays the same.
+    let (axis, angle) = if angle < Scalar::ZERO {
+        (axis.reverse(), -angle)
+    } else {
+        (axis, angle)
+    };
+    assert!(angle > Scalar::ZERO, "Can't revolve by an angle of zero");
+
+    let is_helical = pitch != Scalar::ZERO;
+    assert!(
+        is_helical || angle <= Scalar::TAU + Scalar::from_f64(1e-9),
+        "Can't revolve by more than a full revolution"
+    );
+    let is_full_revolution =
+        !is_helical && angle >= Scalar::TAU - Scalar::from_f64(1e-9);
+
+    let face = PolygonFace::new(face);
+    let direction = axis.direction().normalize();
+    let rotate_vector = |vector: Vector<3>, angle: Scalar| {
+        Transform::rotation(direction * angle).transform_vector(&vector)
+    };
+    let rotate = |point: Point<3>, angle: Scalar| {
+        axis.origin()
+            + rotate_vector(point - axis.origin(), angle)
+            + direction * pitch * angle / Scalar::TAU
+    };
+
+    // The point on the axis that is closest to each point.
+    let foot = |point: Point<3>| {
+        axis.origin() + direction * (point - axis.origin()).dot(&direction)
+    };
+
+    for &point in face.cycles.iter().flatten() {
+        if (point - foot(point)).magnitude() < Scalar::from_f64(1e-9) {
+            todo!("Revolving faces that touch the axis is not supported");
+        }
+    }
+
+    // The cycles are wound counter-clockwise around the direction in which the
+    // face moves. That way, the side faces end up facing outward.
+    let cycles = {
+        let exterior = &face.cycles[0];
+        let centroid = Point::origin()
+            + exterior
+                .iter()
+                .fold(Vector::from([0., 0., 0.]), |sum, point| {
+                    sum + point.coords
+                })
+                / exterior.len() as f64;
+        let tangent = direction.cross(&(centroid - axis.origin()))
+            + direction * pitch / Scalar::TAU;
+
+        let dot = face.normal().dot(&tangent);
+        assert!(
+            dot.abs() > Scalar::from_f64(1e-9),
+            "Can't revolve face that doesn't move out of its own plane"
+        );
+
+        face.cycles
+            .iter()
+            .map(|cycle| {
+                let mut cycle = cycle.clone();
+                if dot < Scalar::ZERO {
+                    cycle.reverse();
+                }
+                cycle
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut assembler = Assembler::new();
+
+    if !is_full_revolution {
+        // The cap at the start faces against the direction of movement, so it
+        // is reversed.
+        let start = cycles
+            .iter()
+            .map(|cycle| cycle.iter().rev().copied().collect())
+            .collect();
+        assembler.add_polygon(
+            face.surface.clone(),
+            face.plane,
+            face.color,
+            start,
+        );
+
+        let origin = rotate(face.plane.origin(), angle);
+        let [u, v] = [face.plane.u(), face.plane.v()]
+            .map(|vector| rotate_vector(vector, angle));
+        let end = cycles
+            .iter()
+            .map(|cycle| {
+                cycle.iter().map(|&point| rotate(point, angle)).collect()
+            })
+            .collect();
+        assembler.add_polygon(
+            objects.surfaces.insert(Surface::plane_from_points([
+                origin,
+                origin + u,
+                origin + v,
+            ])),
+            Plane::from_parametric(origin, u, v),
+            face.color,
+            end,
+        );
+    }
+
+    // Each side face spans at most a third of a revolution. Otherwise, the
+    // edges of a full revolution would connect the same vertices twice.
+    let num_segments = (angle / (Scalar::TAU / 3.)).ceil().into_u64().max(1);
+    let segments = (0..num_segments).map(|i| {
+        [i, i + 1].map(|i| {
+            angle * Scalar::from_u64(i) / Scalar::from_u64(num_segments)
+        })
+    });
+
+    for cycle in &cycles {
+        for (&a, &b) in cycle.iter().zip(cycle.iter().cycle().skip(1)) {
+            // The profile runs along the v-direction of the surface, and the
+            // u-direction is the angle of the revolution. If the face advances
+            // along the axis, its edges trace helices, with straight lines
+            // between them.
+            let surface = if is_helical {
+                let [a, b] = [a, b].map(|point| {
+                    let center = foot(point);
+                    let start = point - center;
+
+                    GlobalPath::Helix(Helix::new(
+                        center,
+                        start,
+                        direction.cross(&start),
+                        direction * pitch,
+                        angle / Scalar::TAU,
+                    ))
+                });
+
+                Surface::ruled(a, b)
+            } else {
+                Surface::revolved(
+                    GlobalPath::Line(Line::from_points([a, b])),
+                    axis,
+                )
+            };
+            let surface = objects.surfaces.insert(surface);
+
+            for [start, end] in segments.clone() {
+                let points = [
+                    (a, start, 0.),
+                    (b, start, 1.),
+                    (b, end, 1.),
+                    (a, end, 0.),
+                ]
+                .map(|(point, u, v)| {
+                    (rotate(point, u), Point::from([u, Scalar::from_f64(v)]))
+                });
+
+                assembler.add_polygon_in_surface(
+                    surface.clone(),
+                    face.color,
+                    vec![points.to_vec()],
+                );
+            }
+        }
+    }
+
+    assembler.build(objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Line, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{test_helpers::has_vertex, validate::Validate},
+        objects::{Face, Objects, Shell, Surface},
+    };
+
+    use super::Revolve;
+
+    #[test]
+    fn revolve_by_partial_angle() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xz_plane());
+        let face = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([
+                [1., 0.],
+                [2., 0.],
+                [2., 1.],
+                [1., 1.],
+            ])
+            .build()?;
+
+        // A negative angle revolves the face clockwise around the axis.
+        let axis =
+            Line::from_origin_and_direction(Point::origin(), Vector::unit_z());
+        let shell = face.revolve(axis, -Scalar::PI / 2., &objects);
+        let shell = Shell::builder(&objects)
+            .with_faces(shell.into_faces())
+            .build()?;
+
+        // Both caps, and a side face for each edge of the face.
+        assert_eq!(shell.faces().into_iter().count(), 6);
+        assert!(has_vertex(&shell, [1., 0., 0.]));
+        assert!(has_vertex(&shell, [0., -2., 1.]));
+
+        shell.validate()?;
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use fj_math::{Line, Point, Scalar, Vector};
 
 use crate::{
+    geometry::{SurfaceGeometry, SweptCurve},
     objects::{
         Curve, GlobalCurve, GlobalEdge, GlobalVertex, HalfEdge, Objects,
         Surface, SurfaceVertex, Vertex,
@@ -52,7 +53,12 @@ impl Sweep for (Vertex, Handle<Surface>) {
         // not, we have no way of knowing the surface coordinates of the input
         // `Vertex` on the `Surface`, and we're going to need to do that further
         // down. There's no way to check for that, unfortunately.
-        assert_eq!(path, surface.v());
+        let surface_path = match surface.geometry() {
+            SurfaceGeometry::SweptCurve(SweptCurve { v, .. }) => *v,
+            SurfaceGeometry::Cylinder(cylinder) => cylinder.axis(),
+            _ => panic!("Can't sweep vertex on a surface that is not swept"),
+        };
+        assert_eq!(path, surface_path);
 
         // With that out of the way, let's start by creating the `GlobalEdge`,
         // as that is the most straight-forward part of this operations, and
@@ -132,11 +138,12 @@ impl Sweep for Handle<GlobalVertex> {
         path: impl Into<Vector<3>>,
         objects: &Objects,
     ) -> Self::Swept {
-        let curve = GlobalCurve::new(objects);
+        let curve = GlobalCurve::new(objects).with_label(self.label());
 
         let a = self.clone();
         let b =
-            GlobalVertex::from_position(self.position() + path.into(), objects);
+            GlobalVertex::from_position(self.position() + path.into(), objects)
+                .with_label(self.label());
 
         let vertices = [a, b];
         let global_edge = GlobalEdge::new(curve, vertices.clone());
@@ -160,25 +167,27 @@ mod tests {
     };
 
     #[test]
-    fn vertex_surface() {
+    fn vertex_surface() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xz_plane());
         let curve = Handle::<Curve>::partial()
             .with_surface(Some(surface.clone()))
             .as_u_axis()
-            .build(&objects);
+            .build(&objects)?;
         let vertex = Vertex::partial()
             .with_position(Some([0.]))
             .with_curve(Some(curve))
-            .build(&objects);
+            .build(&objects)?;
 
         let half_edge = (vertex, surface.clone()).sweep([0., 0., 1.], &objects);
 
         let expected_half_edge = HalfEdge::partial()
             .with_surface(Some(surface))
-            .as_line_segment_from_points([[0., 0.], [0., 1.]])
-            .build(&objects);
+            .as_line_segment_from_points([[0., 0.], [0., 1.]])?
+            .build(&objects)?;
         assert_eq!(half_edge, expected_half_edge);
+
+        Ok(())
     }
 }
@@ -0,0 +1,101 @@
+//! Shapes and checks that are shared between the tests of the algorithms
+
+use fj_math::{Aabb, Point, Scalar};
+
+use crate::{
+    iter::ObjectIters,
+    objects::{Face, Objects, Shell, Sketch, Solid, Surface},
+    storage::Handle,
+};
+
+use super::{sweep::Sweep, transform::TransformObject};
+
+/// Create a cube with one corner at the origin
+pub fn cube(size: f64, objects: &Objects) -> Solid {
+    cuboid([size; 3], [0.; 3], objects)
+}
+
+/// Create the shell of a cube with one corner at the origin
+pub fn cube_shell(size: f64, objects: &Objects) -> Shell {
+    cube(size, objects)
+        .into_shells()
+        .next()
+        .expect("Cube has a shell")
+}
+
+/// Create a cuboid with one corner at `offset`
+pub fn cuboid(size: [f64; 3], offset: [f64; 3], objects: &Objects) -> Solid {
+    let [x, y, z] = size;
+
+    let surface = objects.surfaces.insert(Surface::xy_plane());
+    Sketch::builder(objects, surface)
+        .build_polygon_from_points([[0., 0.], [x, 0.], [x, y], [0., y]])
+        .expect("Failed to build rectangle")
+        .sweep([0., 0., z], objects)
+        .translate(offset, objects)
+        .expect("Failed to translate cuboid")
+}
+
+/// Create a square in the xy-plane, with one corner at `offset`
+pub fn square(offset: [f64; 2], size: f64, objects: &Objects) -> Sketch {
+    let surface = objects.surfaces.insert(Surface::xy_plane());
+    square_on(surface, offset, size, objects)
+}
+
+/// Create a square face in the xy-plane, with one corner at `offset`
+pub fn square_face(offset: [f64; 2], size: f64, objects: &Objects) -> Face {
+    square(offset, size, objects)
+        .into_faces()
+        .into_iter()
+        .next()
+        .expect("Square has a face")
+}
+
+/// Create a square on the given surface, with one corner at `offset`
+pub fn square_on(
+    surface: Handle<Surface>,
+    offset: [f64; 2],
+    size: f64,
+    objects: &Objects,
+) -> Sketch {
+    let [u, v] = offset;
+
+    Sketch::builder(objects, surface)
+        .build_polygon_from_points([
+            [u, v],
+            [u + size, v],
+            [u + size, v + size],
+            [u, v + size],
+        ])
+        .expect("Failed to build square")
+}
+
+/// Make sure the solid consists of a single closed shell
+pub fn closed_shell(solid: Solid, objects: &Objects) -> anyhow::Result<Shell> {
+    let shells = solid.into_shells().collect::<Vec<_>>();
+    assert_eq!(shells.len(), 1);
+
+    let faces = shells.into_iter().flat_map(Shell::into_faces);
+    Ok(Shell::builder(objects).with_faces(faces).build()?)
+}
+
+/// Compute the bounding volume of the vertices of a shape
+pub fn bounding_volume<'r>(shape: &'r impl ObjectIters<'r>) -> Aabb<3> {
+    Aabb::<3>::from_points(
+        shape
+            .global_vertex_iter()
+            .map(|vertex| vertex.position())
+            .collect::<Vec<Point<3>>>(),
+    )
+}
+
+/// Check whether the shape has a vertex at the given position
+pub fn has_vertex<'r>(
+    shape: &'r impl ObjectIters<'r>,
+    position: [f64; 3],
+) -> bool {
+    shape.global_vertex_iter().any(|vertex| {
+        let distance = (vertex.position() - Point::from(position)).magnitude();
+        distance < Scalar::from_f64(1e-9)
+    })
+}
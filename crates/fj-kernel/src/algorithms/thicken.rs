@@ -0,0 +1,264 @@
+//! Thickening of faces into solids
+//!
+//! The faces are offset along their normals, and the gap between the offset
+//! copies is closed by side faces along their boundary.
+//!
+//! # Implementation Note
+//!
+//! Only faces in planar surfaces with straight edges are supported so far.
+//! Other faces will result in a panic.
+//!
+//! Side faces are planar. Where the faces that are thickened meet at an angle,
+//! the vertices at the ends of a boundary edge might be offset in different
+//! directions, which would require a curved side face. The result is invalid
+//! in that case.
+
+use fj_math::{Plane, Point, Scalar};
+
+use crate::{
+    algorithms::{
+        boolean::assemble::Assembler,
+        offset::{offset_vertex, PolygonFace},
+    },
+    objects::{Face, Objects, Shell, Sketch, Solid, Surface},
+};
+
+/// Thicken a shape into a solid
+pub trait Thicken {
+    /// Thicken the shape by the given distance
+    ///
+    /// `direction` determines on which side of the shape the material is
+    /// added. The shape is expected to be open. The faces of a closed shell
+    /// would end up intersecting each other.
+    fn thicken(
+        self,
+        thickness: impl Into<Scalar>,
+        direction: ThickenDirection,
+        objects: &Objects,
+    ) -> Solid;
+}
+
+/// On which side of a shape material is added, when thickening it
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ThickenDirection {
+    /// In the direction of the normals of the shape's faces
+    Front,
+
+    /// Against the direction of the normals of the shape's faces
+    Back,
+
+    /// Half of the thickness on either side
+    Both,
+}
+
+impl Thicken for Face {
+    fn thicken(
+        self,
+        thickness: impl Into<Scalar>,
+        direction: ThickenDirection,
+        objects: &Objects,
+    ) -> Solid {
+        Shell::new()
+            .with_faces([self])
+            .thicken(thickness, direction, objects)
+    }
+}
+
+impl Thicken for Sketch {
+    fn thicken(
+        self,
+        thickness: impl Into<Scalar>,
+        direction: ThickenDirection,
+        objects: &Objects,
+    ) -> Solid {
+        Shell::new()
+            .with_faces(self.into_faces())
+            .thicken(thickness, direction, objects)
+    }
+}
+
+impl Thicken for Shell {
+    fn thicken(
+        self,
+        thickness: impl Into<Scalar>,
+        direction: ThickenDirection,
+        objects: &Objects,
+    ) -> Solid {
+        let thickness = thickness.into();
+        assert!(
+            thickness > Scalar::ZERO,
+            "Thickness must be positive, to create a valid solid"
+        );
+
+        let [back, front] = match direction {
+            ThickenDirection::Front => [Scalar::ZERO, thickness],
+            ThickenDirection::Back => [-thickness, Scalar::ZERO],
+            ThickenDirection::Both => [-thickness / 2., thickness / 2.],
+        };
+
+        let faces = self
+            .faces()
+            .into_iter()
+            .map(PolygonFace::new)
+            .collect::<Vec<_>>();
+        let offset =
+            |point, distance| offset_vertex(point, &faces, |_| distance);
+
+        let mut assembler = Assembler::new();
+
+        for face in &faces {
+            // The back face faces the other way, so it is reversed.
+            for (distance, reverse) in [(back, true), (front, false)] {
+                let cycles = face
+                    .cycles
+                    .iter()
+                    .map(|cycle| {
+                        let cycle = cycle.iter().map(|&p| offset(p, distance));
+                        if reverse {
+                            cycle.rev().collect()
+                        } else {
+                            cycle.collect()
+                        }
+                    })
+                    .collect::<Vec<Vec<_>>>();
+
+                let (surface, plane) = face.offset_surface(distance, objects);
+                assembler.add_polygon(surface, plane, face.color, cycles);
+            }
+        }
+
+        // Edges that only one face refers to bound the shape. Each of them
+        // gets a side face.
+        let edges = faces
+            .iter()
+            .flat_map(|face| {
+                face.cycles.iter().flat_map(move |cycle| {
+                    cycle
+                        .iter()
+                        .zip(cycle.iter().cycle().skip(1))
+                        .map(move |(&a, &b)| (face, [a, b]))
+                })
+            })
+            .collect::<Vec<_>>();
+        let is_boundary = |[a, b]: [Point<3>; 2]| {
+            edges
+                .iter()
+                .filter(|(_, edge)| *edge == [a, b] || *edge == [b, a])
+                .count()
+                == 1
+        };
+
+        for &(face, [a, b]) in &edges {
+            if !is_boundary([a, b]) {
+                continue;
+            }
+
+            // The front side of the face is on the left of its edges, so the
+            // side face is wound such, that it faces to the right.
+            let polygon = [
+                offset(a, back),
+                offset(b, back),
+                offset(b, front),
+                offset(a, front),
+            ];
+
+            let surface = Surface::plane_from_points([
+                polygon[0], polygon[1], polygon[3],
+            ]);
+            let plane = Plane::from_parametric(
+                polygon[0],
+                polygon[1] - polygon[0],
+                polygon[3] - polygon[0],
+            );
+
+            assembler.add_polygon(
+                objects.surfaces.insert(surface),
+                plane,
+                face.color,
+                vec![polygon.to_vec()],
+            );
+        }
+
+        Solid::new().with_shells([assembler.build(objects)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Aabb;
+
+    use crate::{
+        algorithms::{
+            test_helpers::{bounding_volume, closed_shell, square},
+            validate::Validate,
+        },
+        objects::{Objects, Shell, Sketch, Surface},
+    };
+
+    use super::{Thicken, ThickenDirection};
+
+    #[test]
+    fn thicken_sketch() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let sketch = square([0., 0.], 1., &objects);
+
+        let front =
+            sketch
+                .clone()
+                .thicken(1., ThickenDirection::Front, &objects);
+        let front = closed_shell(front, &objects)?;
+        assert_eq!(front.faces().into_iter().count(), 6);
+        assert_eq!(
+            bounding_volume(&front),
+            Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]])
+        );
+
+        let both = sketch.thicken(1., ThickenDirection::Both, &objects);
+        let both = closed_shell(both, &objects)?;
+        assert_eq!(both.faces().into_iter().count(), 6);
+        assert_eq!(
+            bounding_volume(&both),
+            Aabb::<3>::from_points([[0., 0., -0.5], [1., 1., 0.5]])
+        );
+
+        front.validate()?;
+        both.validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn thicken_open_shell() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        // Two faces that meet at a right angle, with their normals pointing
+        // into the corner between them.
+        let floor = square([0., 0.], 1., &objects);
+        let wall = objects.surfaces.insert(Surface::xz_plane());
+        let wall =
+            Sketch::builder(&objects, wall).build_polygon_from_points([
+                [0., 0.],
+                [0., 1.],
+                [1., 1.],
+                [1., 0.],
+            ])?;
+
+        let shell = Shell::new()
+            .with_faces(floor.into_faces())
+            .with_faces(wall.into_faces());
+
+        let solid = shell.thicken(0.1, ThickenDirection::Back, &objects);
+        let solid = closed_shell(solid, &objects)?;
+
+        // Both faces and their offset copies, plus a side face for each of
+        // the six edges that bound the shell.
+        assert_eq!(solid.faces().into_iter().count(), 10);
+        assert_eq!(
+            bounding_volume(&solid),
+            Aabb::<3>::from_points([[0., -0.1, -0.1], [1., 1., 1.]])
+        );
+
+        solid.validate()?;
+        Ok(())
+    }
+}
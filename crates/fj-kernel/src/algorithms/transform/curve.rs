@@ -2,14 +2,18 @@ use fj_math::Transform;
 
 use crate::{
     objects::{GlobalCurve, Objects},
-    partial::PartialCurve,
+    partial::{PartialBuildError, PartialCurve},
     storage::Handle,
 };
 
 use super::TransformObject;
 
 impl TransformObject for Handle<GlobalCurve> {
-    fn transform(self, _: &Transform, objects: &Objects) -> Self {
+    fn transform(
+        self,
+        _: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
         // `GlobalCurve` doesn't contain any internal geometry. If it did, that
         // would just be redundant with the geometry of other objects, and this
         // other geometry is already being transformed by other implementations
@@ -18,25 +22,32 @@ impl TransformObject for Handle<GlobalCurve> {
         // All we need to do here is create a new `GlobalCurve` instance, to
         // make sure the transformed `GlobalCurve` has a different identity than
         // the original one.
-        GlobalCurve::new(objects)
+        Ok(GlobalCurve::new(objects).with_label(self.label()))
     }
 }
 
 impl TransformObject for PartialCurve {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
         let surface = self
             .surface
-            .map(|surface| surface.transform(transform, objects));
+            .map(|surface| surface.transform(transform, objects))
+            .transpose()?;
         let global_form = self
             .global_form
-            .map(|global_form| global_form.0.transform(transform, objects));
+            .map(|global_form| global_form.0.transform(transform, objects))
+            .transpose()?;
 
         // Don't need to transform `self.path`, as that's defined in surface
         // coordinates, and thus transforming `surface` takes care of it.
-        Self {
+        Ok(Self {
             surface,
             path: self.path,
             global_form: global_form.map(Into::into),
-        }
+            label: self.label,
+        })
     }
 }
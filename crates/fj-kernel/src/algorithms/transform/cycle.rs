@@ -1,29 +1,38 @@
 use fj_math::Transform;
 
-use crate::{objects::Objects, partial::PartialCycle};
+use crate::{
+    objects::Objects,
+    partial::{PartialBuildError, PartialCycle},
+};
 
 use super::TransformObject;
 
 impl TransformObject for PartialCycle {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
         let surface = self
             .surface
             .clone()
-            .map(|surface| surface.transform(transform, objects));
+            .map(|surface| surface.transform(transform, objects))
+            .transpose()?;
         let half_edges = self
             .half_edges
             .into_iter()
             .map(|edge| {
-                edge.into_partial()
-                    .transform(transform, objects)
+                Ok(edge
+                    .into_partial()
+                    .transform(transform, objects)?
                     .with_surface(surface.clone())
-                    .into()
+                    .into())
             })
-            .collect();
+            .collect::<Result<_, PartialBuildError>>()?;
 
-        Self {
+        Ok(Self {
             surface,
             half_edges,
-        }
+        })
     }
 }
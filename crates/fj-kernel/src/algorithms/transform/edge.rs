@@ -1,65 +1,99 @@
 use fj_math::Transform;
 
 use crate::{
-    objects::{Curve, Objects},
-    partial::{MaybePartial, PartialGlobalEdge, PartialHalfEdge},
+    objects::{Curve, Objects, Vertex},
+    partial::{
+        MaybePartial, PartialBuildError, PartialGlobalEdge, PartialHalfEdge,
+    },
     storage::Handle,
 };
 
 use super::TransformObject;
 
 impl TransformObject for PartialHalfEdge {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
         let surface = self
             .surface
-            .map(|surface| surface.transform(transform, objects));
-        let curve = self.curve.clone().map(|curve| {
-            curve
-                .into_partial()
-                .transform(transform, objects)
-                .with_surface(surface.clone())
-                .into()
-        });
-        let vertices = self.vertices.clone().map(|vertices| {
-            vertices.map(|vertex| {
+            .map(|surface| surface.transform(transform, objects))
+            .transpose()?;
+        let curve = self
+            .curve
+            .clone()
+            .map(|curve| -> Result<_, PartialBuildError> {
+                Ok(curve
+                    .into_partial()
+                    .transform(transform, objects)?
+                    .with_surface(surface.clone())
+                    .into())
+            })
+            .transpose()?;
+        let transform_vertex = |vertex: MaybePartial<Vertex>| {
+            Ok::<_, PartialBuildError>(
                 vertex
                     .into_partial()
-                    .transform(transform, objects)
+                    .transform(transform, objects)?
                     .with_curve(curve.clone())
-                    .into()
+                    .into(),
+            )
+        };
+        let vertices = self
+            .vertices
+            .clone()
+            .map(|[a, b]| -> Result<_, PartialBuildError> {
+                Ok([transform_vertex(a)?, transform_vertex(b)?])
             })
-        });
-        let global_form = self.global_form.map(|global_form| {
-            global_form
-                .into_partial()
-                .transform(transform, objects)
-                .with_curve(curve.as_ref().and_then(
-                    |curve: &MaybePartial<Handle<Curve>>| curve.global_form(),
-                ))
-                .into()
-        });
+            .transpose()?;
+        let global_form = self
+            .global_form
+            .map(|global_form| -> Result<_, PartialBuildError> {
+                Ok(global_form
+                    .into_partial()
+                    .transform(transform, objects)?
+                    .with_curve(curve.as_ref().and_then(
+                        |curve: &MaybePartial<Handle<Curve>>| {
+                            curve.global_form()
+                        },
+                    ))
+                    .into())
+            })
+            .transpose()?;
 
-        Self {
+        Ok(Self {
             surface,
             curve,
             vertices,
             global_form,
-        }
+        })
     }
 }
 
 impl TransformObject for PartialGlobalEdge {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
         let curve = self
             .curve
-            .map(|curve| curve.0.transform(transform, objects));
-        let vertices = self.vertices.map(|vertices| {
-            vertices.map(|vertex| vertex.transform(transform, objects))
-        });
+            .map(|curve| curve.0.transform(transform, objects))
+            .transpose()?;
+        let vertices = self
+            .vertices
+            .map(|[a, b]| -> Result<_, PartialBuildError> {
+                Ok([
+                    a.transform(transform, objects)?,
+                    b.transform(transform, objects)?,
+                ])
+            })
+            .transpose()?;
 
-        Self {
+        Ok(Self {
             curve: curve.map(Into::into),
             vertices,
-        }
+        })
     }
 }
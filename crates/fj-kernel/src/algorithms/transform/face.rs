@@ -2,43 +2,93 @@ use fj_math::Transform;
 
 use crate::{
     objects::{Face, Faces, Objects},
-    partial::HasPartial,
+    partial::{HasPartial, PartialBuildError},
 };
 
 use super::TransformObject;
 
 impl TransformObject for Face {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
-        let surface = self.surface().clone().transform(transform, objects);
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
+        let surface = self.surface().clone().transform(transform, objects)?;
         let exterior = self
             .exterior()
             .to_partial()
-            .transform(transform, objects)
+            .transform(transform, objects)?
             .with_surface(Some(surface.clone()))
-            .build(objects);
-        let interiors = self.interiors().map(|cycle| {
-            cycle
-                .to_partial()
-                .transform(transform, objects)
-                .with_surface(Some(surface.clone()))
-                .build(objects)
-        });
+            .build(objects)?;
+        let interiors = self
+            .interiors()
+            .map(|cycle| {
+                cycle
+                    .to_partial()
+                    .transform(transform, objects)?
+                    .with_surface(Some(surface.clone()))
+                    .build(objects)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
         let color = self.color();
 
-        Face::from_exterior(exterior)
+        Ok(Face::from_exterior(exterior)
             .with_interiors(interiors)
-            .with_color(color)
+            .with_color(color))
     }
 }
 
 impl TransformObject for Faces {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
         let mut faces = Faces::new();
-        faces.extend(
-            self.into_iter()
-                .map(|face| face.transform(transform, objects)),
-        );
-        faces
+        for face in self {
+            faces.extend([face.transform(transform, objects)?]);
+        }
+        Ok(faces)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::validate::ValidationConfig,
+        objects::{Face, Objects, Surface},
+        partial::PartialBuildError,
+    };
+
+    use super::TransformObject;
+
+    #[test]
+    fn transform_invalid_face() -> anyhow::Result<()> {
+        // Without build validation, a bowtie is built, even though its edges
+        // cross each other.
+        let face = {
+            let objects = Objects::new();
+            let surface = objects.surfaces.insert(Surface::xy_plane());
+
+            Face::builder(&objects, surface)
+                .with_exterior_polygon_from_points([
+                    [0., 0.],
+                    [2., 2.],
+                    [2., 0.],
+                    [0., 2.],
+                ])
+                .build()?
+        };
+
+        // Transforming it into stores that validate built objects returns an
+        // error, instead of panicking.
+        let objects =
+            Objects::new().with_build_validation(ValidationConfig::default());
+        let result = face.translate([0., 0., 1.], &objects);
+
+        assert!(matches!(result, Err(PartialBuildError::Invalid(_))));
+
+        Ok(())
     }
 }
@@ -14,12 +14,30 @@ mod vertex;
 use fj_math::{Transform, Vector};
 
 use crate::{
+    iter::ObjectIters,
     objects::Objects,
-    partial::{HasPartial, MaybePartial, Partial},
+    partial::{HasPartial, MaybePartial, Partial, PartialBuildError},
 };
 
+/// Indicate whether all geometry of a shape can be transformed
+///
+/// Some geometry, like a helix or a cylinder, can't be represented anymore,
+/// once it has been scaled non-uniformly. Transforming a shape that contains
+/// such geometry by a transform that doesn't preserve angles panics.
+/// Operations that process user input can use this to return an error instead.
+pub fn supports_transform<'r>(
+    shape: &'r impl ObjectIters<'r>,
+    transform: &Transform,
+) -> bool {
+    transform.is_similarity()
+        || !shape.surface_iter().any(surface::requires_similarity)
+}
+
 /// Transform an object
 ///
+/// Transforming an object builds new objects from partial ones. Returns an
+/// error, if any of those can't be built.
+///
 /// # Implementation Note
 ///
 /// So far, a general `transform` method is available, along some convenience
@@ -29,30 +47,31 @@ use crate::{
 /// hasn't been done so far, is that no one has put in the work yet.
 pub trait TransformObject: Sized {
     /// Transform the object
-    #[must_use]
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self;
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError>;
 
     /// Translate the object
     ///
     /// Convenience wrapper around [`TransformObject::transform`].
-    #[must_use]
     fn translate(
         self,
         offset: impl Into<Vector<3>>,
         objects: &Objects,
-    ) -> Self {
+    ) -> Result<Self, PartialBuildError> {
         self.transform(&Transform::translation(offset), objects)
     }
 
     /// Rotate the object
     ///
     /// Convenience wrapper around [`TransformObject::transform`].
-    #[must_use]
     fn rotate(
         self,
         axis_angle: impl Into<Vector<3>>,
         objects: &Objects,
-    ) -> Self {
+    ) -> Result<Self, PartialBuildError> {
         self.transform(&Transform::rotation(axis_angle), objects)
     }
 }
@@ -62,9 +81,13 @@ where
     T: HasPartial,
     T::Partial: TransformObject,
 {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
         self.to_partial()
-            .transform(transform, objects)
+            .transform(transform, objects)?
             .build(objects)
     }
 }
@@ -74,12 +97,18 @@ where
     T: HasPartial + TransformObject,
     T::Partial: TransformObject,
 {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
-        match self {
-            Self::Full(full) => Self::Full(full.transform(transform, objects)),
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
+        let transformed = match self {
+            Self::Full(full) => Self::Full(full.transform(transform, objects)?),
             Self::Partial(partial) => {
-                Self::Partial(partial.transform(transform, objects))
+                Self::Partial(partial.transform(transform, objects)?)
             }
-        }
+        };
+
+        Ok(transformed)
     }
 }
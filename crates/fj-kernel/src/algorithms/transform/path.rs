@@ -1,16 +1,72 @@
 use fj_math::Transform;
 
-use crate::{objects::Objects, path::GlobalPath};
+use crate::{objects::Objects, partial::PartialBuildError, path::GlobalPath};
 
 use super::TransformObject;
 
 impl TransformObject for GlobalPath {
-    fn transform(self, transform: &Transform, _: &Objects) -> Self {
-        match self {
-            Self::Circle(curve) => {
+    fn transform(
+        self,
+        transform: &Transform,
+        _: &Objects,
+    ) -> Result<Self, PartialBuildError> {
+        let path = match self {
+            // A transform that doesn't preserve angles, like a non-uniform
+            // scaling, turns a circle into an ellipse. The coordinates of the
+            // points on the curve stay the same.
+            Self::Circle(curve) if transform.is_similarity() => {
                 Self::Circle(transform.transform_circle(&curve))
             }
+            Self::Circle(curve) => {
+                Self::Ellipse(transform.transform_ellipse(&curve.into()))
+            }
+            Self::Ellipse(curve) => {
+                Self::Ellipse(transform.transform_ellipse(&curve))
+            }
+            Self::Parabola(curve) => {
+                Self::Parabola(transform.transform_parabola(&curve))
+            }
+            Self::Hyperbola(curve) => {
+                Self::Hyperbola(transform.transform_hyperbola(&curve))
+            }
             Self::Line(curve) => Self::Line(transform.transform_line(&curve)),
-        }
+            Self::Polyline(curve) => Self::Polyline(
+                curve.map_points(|point| transform.transform_point(&point)),
+            ),
+            Self::Bezier(curve) => {
+                Self::Bezier(transform.transform_cubic_bezier(&curve))
+            }
+            Self::Nurbs(curve) => {
+                Self::Nurbs(transform.transform_nurbs(&curve))
+            }
+            Self::Helix(curve) => {
+                assert_similarity(transform, "helices");
+                Self::Helix(transform.transform_helix(&curve))
+            }
+        };
+
+        Ok(path)
     }
 }
+
+/// Indicate whether the path can only be transformed by a similarity
+///
+/// See [`assert_similarity`].
+pub(super) fn requires_similarity(path: &GlobalPath) -> bool {
+    matches!(path, GlobalPath::Helix(_))
+}
+
+/// Assert that the transform preserves angles
+///
+/// Some geometry, like a helix or a sphere, can't be represented anymore, once
+/// it has been scaled non-uniformly.
+///
+/// # Panics
+///
+/// Panics, if the transform doesn't preserve angles.
+pub(super) fn assert_similarity(transform: &Transform, geometry: &str) {
+    assert!(
+        transform.is_similarity(),
+        "Transforming {geometry} by a non-uniform scaling is not supported"
+    );
+}
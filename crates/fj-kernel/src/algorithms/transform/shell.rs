@@ -1,15 +1,24 @@
 use fj_math::Transform;
 
-use crate::objects::{Objects, Shell};
+use crate::{
+    objects::Objects,
+    partial::{PartialBuildError, PartialShell},
+};
 
 use super::TransformObject;
 
-impl TransformObject for Shell {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
+impl TransformObject for PartialShell {
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
         let faces = self
-            .into_faces()
+            .faces
             .into_iter()
-            .map(|face| face.transform(transform, objects));
-        Self::new().with_faces(faces)
+            .map(|face| face.transform(transform, objects))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { faces })
     }
 }
@@ -1,15 +1,24 @@
 use fj_math::Transform;
 
-use crate::objects::{Objects, Sketch};
+use crate::{
+    objects::Objects,
+    partial::{PartialBuildError, PartialSketch},
+};
 
 use super::TransformObject;
 
-impl TransformObject for Sketch {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
+impl TransformObject for PartialSketch {
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
         let faces = self
-            .into_faces()
+            .faces
             .into_iter()
-            .map(|face| face.transform(transform, objects));
-        Self::new().with_faces(faces)
+            .map(|face| face.transform(transform, objects))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { faces })
     }
 }
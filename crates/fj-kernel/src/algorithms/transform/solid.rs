@@ -1,14 +1,24 @@
 use fj_math::Transform;
 
-use crate::objects::{Objects, Solid};
+use crate::{
+    objects::Objects,
+    partial::{PartialBuildError, PartialSolid},
+};
 
 use super::TransformObject;
 
-impl TransformObject for Solid {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
-        let faces = self
-            .into_shells()
-            .map(|shell| shell.transform(transform, objects));
-        Self::new().with_shells(faces)
+impl TransformObject for PartialSolid {
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
+        let shells = self
+            .shells
+            .into_iter()
+            .map(|shell| shell.transform(transform, objects))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { shells })
     }
 }
@@ -1,17 +1,94 @@
 use fj_math::Transform;
 
 use crate::{
+    geometry::{
+        RevolutionSurface, RuledSurface, SurfaceGeometry, SweptCurve, SweptPath,
+    },
     objects::{Objects, Surface},
+    partial::PartialBuildError,
     storage::Handle,
 };
 
-use super::TransformObject;
+use super::{
+    path::{self, assert_similarity},
+    TransformObject,
+};
 
 impl TransformObject for Handle<Surface> {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
-        objects.surfaces.insert(Surface::new(
-            self.u().transform(transform, objects),
-            transform.transform_vector(&self.v()),
-        ))
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
+        let surface = match self.geometry() {
+            SurfaceGeometry::SweptCurve(SweptCurve { u, v }) => Surface::new(
+                u.clone().transform(transform, objects)?,
+                transform.transform_vector(v),
+            ),
+            SurfaceGeometry::SweptPath(SweptPath { u, v }) => {
+                Surface::swept_along_path(
+                    u.clone().transform(transform, objects)?,
+                    v.clone().transform(transform, objects)?,
+                )
+            }
+            SurfaceGeometry::Ruled(RuledSurface { a, b }) => Surface::ruled(
+                a.clone().transform(transform, objects)?,
+                b.clone().transform(transform, objects)?,
+            ),
+            SurfaceGeometry::Revolution(RevolutionSurface {
+                profile,
+                axis,
+            }) => {
+                assert_similarity(transform, "surfaces of revolution");
+                Surface::revolved(
+                    profile.clone().transform(transform, objects)?,
+                    transform.transform_line(axis),
+                )
+            }
+            SurfaceGeometry::Cylinder(cylinder) => {
+                assert_similarity(transform, "cylinders");
+                Surface::from_cylinder(transform.transform_cylinder(cylinder))
+            }
+            SurfaceGeometry::Cone(cone) => {
+                assert_similarity(transform, "cones");
+                Surface::from_cone(transform.transform_cone(cone))
+            }
+            SurfaceGeometry::Sphere(sphere) => {
+                assert_similarity(transform, "spheres");
+                Surface::from_sphere(transform.transform_sphere(sphere))
+            }
+            SurfaceGeometry::Torus(torus) => {
+                assert_similarity(transform, "tori");
+                Surface::from_torus(transform.transform_torus(torus))
+            }
+            SurfaceGeometry::Nurbs(nurbs) => {
+                Surface::from_nurbs(transform.transform_nurbs_surface(nurbs))
+            }
+        };
+
+        Ok(objects.surfaces.insert(surface).with_label(self.label()))
+    }
+}
+
+/// Indicate whether the surface can only be transformed by a similarity
+///
+/// See [`assert_similarity`].
+pub(super) fn requires_similarity(surface: &Surface) -> bool {
+    match surface.geometry() {
+        SurfaceGeometry::SweptCurve(SweptCurve { u, .. }) => {
+            path::requires_similarity(u)
+        }
+        SurfaceGeometry::SweptPath(SweptPath { u, v }) => {
+            path::requires_similarity(u) || path::requires_similarity(v)
+        }
+        SurfaceGeometry::Ruled(RuledSurface { a, b }) => {
+            path::requires_similarity(a) || path::requires_similarity(b)
+        }
+        SurfaceGeometry::Revolution(_)
+        | SurfaceGeometry::Cylinder(_)
+        | SurfaceGeometry::Cone(_)
+        | SurfaceGeometry::Sphere(_)
+        | SurfaceGeometry::Torus(_) => true,
+        SurfaceGeometry::Nurbs(_) => false,
     }
 }
@@ -2,60 +2,87 @@ use fj_math::Transform;
 
 use crate::{
     objects::Objects,
-    partial::{PartialGlobalVertex, PartialSurfaceVertex, PartialVertex},
+    partial::{
+        PartialBuildError, PartialGlobalVertex, PartialSurfaceVertex,
+        PartialVertex,
+    },
 };
 
 use super::TransformObject;
 
 impl TransformObject for PartialVertex {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
-        let curve = self.curve.map(|curve| curve.transform(transform, objects));
-        let surface_form = self.surface_form.map(|surface_form| {
-            surface_form
-                .into_partial()
-                .transform(transform, objects)
-                .into()
-        });
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
+        let curve = self
+            .curve
+            .map(|curve| curve.transform(transform, objects))
+            .transpose()?;
+        let surface_form = self
+            .surface_form
+            .map(|surface_form| -> Result<_, PartialBuildError> {
+                Ok(surface_form
+                    .into_partial()
+                    .transform(transform, objects)?
+                    .into())
+            })
+            .transpose()?;
         let global_form = self
             .global_form
-            .map(|global_form| global_form.transform(transform, objects));
+            .map(|global_form| global_form.transform(transform, objects))
+            .transpose()?;
 
         // Don't need to transform `self.position`, as that is in curve
         // coordinates and thus transforming the curve takes care of it.
-        Self {
+        Ok(Self {
             position: self.position,
             curve,
             surface_form,
             global_form,
-        }
+        })
     }
 }
 
 impl TransformObject for PartialSurfaceVertex {
-    fn transform(self, transform: &Transform, objects: &Objects) -> Self {
+    fn transform(
+        self,
+        transform: &Transform,
+        objects: &Objects,
+    ) -> Result<Self, PartialBuildError> {
         let surface = self
             .surface
-            .map(|surface| surface.transform(transform, objects));
+            .map(|surface| surface.transform(transform, objects))
+            .transpose()?;
         let global_form = self
             .global_form
-            .map(|global_form| global_form.transform(transform, objects));
+            .map(|global_form| global_form.transform(transform, objects))
+            .transpose()?;
 
         // Don't need to transform `self.position`, as that is in surface
         // coordinates and thus transforming the surface takes care of it.
-        Self {
+        Ok(Self {
             position: self.position,
             surface,
             global_form,
-        }
+        })
     }
 }
 
 impl TransformObject for PartialGlobalVertex {
-    fn transform(self, transform: &Transform, _: &Objects) -> Self {
+    fn transform(
+        self,
+        transform: &Transform,
+        _: &Objects,
+    ) -> Result<Self, PartialBuildError> {
         let position = self
             .position
             .map(|position| transform.transform_point(&position));
 
-        Self { position }
+        Ok(Self {
+            position,
+            label: self.label,
+        })
     }
 }
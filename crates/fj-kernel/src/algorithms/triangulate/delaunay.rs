@@ -13,6 +13,66 @@ pub fn triangulate(
     let triangulation = spade::DelaunayTriangulation::<_>::bulk_load(points)
         .expect("Inserted invalid values into triangulation");
 
+    triangles_of(&triangulation, coord_handedness)
+}
+
+/// Create a constrained Delaunay triangulation of all points
+///
+/// Each of the `cycles` is a closed chain of points, whose edges are guaranteed
+/// to be edges of the triangulation. Points of the cycles that are missing from
+/// `points` are inserted too.
+///
+/// Returns `None`, if edges of the cycles cross each other. Those can't all be
+/// edges of the triangulation.
+pub fn triangulate_constrained(
+    points: Vec<TriangulationPoint>,
+    cycles: Vec<Vec<TriangulationPoint>>,
+    coord_handedness: Handedness,
+) -> Option<Vec<[TriangulationPoint; 3]>> {
+    use spade::Triangulation as _;
+
+    let mut triangulation =
+        spade::ConstrainedDelaunayTriangulation::<TriangulationPoint>::new();
+
+    for point in points {
+        triangulation
+            .insert(point)
+            .expect("Inserted invalid values into triangulation");
+    }
+
+    for cycle in cycles {
+        let handles = cycle
+            .into_iter()
+            .map(|point| {
+                triangulation
+                    .insert(point)
+                    .expect("Inserted invalid values into triangulation")
+            })
+            .collect::<Vec<_>>();
+
+        let next = handles.iter().cycle().skip(1);
+        for (&from, &to) in handles.iter().zip(next) {
+            // Consecutive points of an approximation can be identical, for
+            // example at a singular point of the surface.
+            if from == to {
+                continue;
+            }
+
+            // Adding a constraint that crosses an existing one would panic.
+            if !triangulation.can_add_constraint(from, to) {
+                return None;
+            }
+            triangulation.add_constraint(from, to);
+        }
+    }
+
+    Some(triangles_of(&triangulation, coord_handedness))
+}
+
+fn triangles_of(
+    triangulation: &impl spade::Triangulation<Vertex = TriangulationPoint>,
+    coord_handedness: Handedness,
+) -> Vec<[TriangulationPoint; 3]> {
     let mut triangles = Vec::new();
     for triangle in triangulation.inner_faces() {
         let [v0, v1, v2] = triangle.vertices().map(|vertex| *vertex.data());
@@ -4,18 +4,25 @@ mod delaunay;
 mod polygon;
 
 use fj_interop::mesh::Mesh;
-use fj_math::Point;
+use fj_math::{Point, Scalar};
 
 use self::{delaunay::TriangulationPoint, polygon::Polygon};
 
+use crate::objects::Handedness;
+
 use super::approx::{face::FaceApprox, Approx, Tolerance};
 
 /// Triangulate a shape
 pub trait Triangulate: Sized {
-    /// Triangulate the shape
+    /// Triangulate the shape, using the default [`Triangulator`]
     fn triangulate(self) -> Mesh<Point<3>> {
+        self.triangulate_with(Triangulator::default())
+    }
+
+    /// Triangulate the shape, using the provided [`Triangulator`]
+    fn triangulate_with(self, triangulator: Triangulator) -> Mesh<Point<3>> {
         let mut mesh = Mesh::new();
-        self.triangulate_into_mesh(&mut mesh);
+        self.triangulate_into_mesh(&mut mesh, triangulator);
         mesh
     }
 
@@ -23,7 +30,33 @@ pub trait Triangulate: Sized {
     ///
     /// This is a low-level method, intended for implementation of
     /// `Triangulate`. Most callers should prefer [`Triangulate::triangulate`].
-    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>);
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        triangulator: Triangulator,
+    );
+}
+
+/// The algorithm that is used to triangulate faces
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Triangulator {
+    /// Delaunay triangulation of all points of the face
+    ///
+    /// Triangles that are not part of the face are removed afterwards. This
+    /// can go wrong for sharply concave faces, where the triangulation doesn't
+    /// contain the edges of the face.
+    #[default]
+    Delaunay,
+
+    /// Constrained Delaunay triangulation
+    ///
+    /// The edges of the face, including those of its holes, are always edges of
+    /// the triangulation. Within these constraints, the triangles are as
+    /// well-shaped as possible.
+    ///
+    /// If edges of the face cross each other, they can't all be edges of the
+    /// triangulation. Such faces are triangulated using [`Self::Delaunay`].
+    ConstrainedDelaunay,
 }
 
 impl<T> Triangulate for (T, Tolerance)
@@ -31,27 +64,66 @@ where
     T: Approx,
     T::Approximation: IntoIterator<Item = FaceApprox>,
 {
-    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>) {
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        triangulator: Triangulator,
+    ) {
         let (approx, tolerance) = self;
 
         let approx = approx.approx(tolerance);
 
+        #[cfg(not(feature = "parallel"))]
         for approx in approx {
-            approx.triangulate_into_mesh(mesh);
+            approx.triangulate_into_mesh(mesh, triangulator);
+        }
+
+        // Each face is triangulated into a mesh of its own. Merging them in
+        // order results in the same mesh as triangulating them one by one.
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+
+            let meshes = approx
+                .into_iter()
+                .collect::<Vec<_>>()
+                .into_par_iter()
+                .map(|approx| approx.triangulate_with(triangulator))
+                .collect::<Vec<_>>();
+
+            for triangle in meshes.iter().flat_map(|mesh| mesh.triangles()) {
+                mesh.push_triangle_with_attributes(
+                    triangle.inner,
+                    triangle.normals,
+                    triangle.uvs,
+                    triangle.color,
+                );
+            }
         }
     }
 }
 
 impl Triangulate for FaceApprox {
-    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>) {
-        let points: Vec<_> = self
-            .points()
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        triangulator: Triangulator,
+    ) {
+        let points = self.points();
+        let cycles = Some(&self.exterior)
             .into_iter()
-            .map(|point| TriangulationPoint {
-                point_surface: point.local_form,
-                point_global: point.global_form,
+            .chain(&self.interiors)
+            .map(|cycle| {
+                cycle
+                    .points()
+                    .into_iter()
+                    .map(|point| TriangulationPoint {
+                        point_surface: point.local_form,
+                        point_global: point.global_form,
+                    })
+                    .collect::<Vec<_>>()
             })
-            .collect();
+            .collect::<Vec<_>>();
         let face_as_polygon = Polygon::new()
             .with_exterior(
                 self.exterior
@@ -63,31 +135,111 @@ impl Triangulate for FaceApprox {
                 interior.points().into_iter().map(|point| point.local_form)
             }));
 
-        let mut triangles =
-            delaunay::triangulate(points, self.coord_handedness);
-        triangles.retain(|triangle| {
-            face_as_polygon
-                .contains_triangle(triangle.map(|point| point.point_surface))
+        // Only the surface points from within the face are needed. The
+        // others would result in triangles that are removed again below.
+        let points =
+            points
+                .into_iter()
+                .chain(self.surface_points.into_iter().filter(|point| {
+                    face_as_polygon.contains_point(point.local_form)
+                }));
+        let points: Vec<_> = points
+            .map(|point| TriangulationPoint {
+                point_surface: point.local_form,
+                point_global: point.global_form,
+            })
+            .collect();
+
+        let constrained = match triangulator {
+            Triangulator::Delaunay => None,
+            Triangulator::ConstrainedDelaunay => {
+                delaunay::triangulate_constrained(
+                    points.clone(),
+                    cycles,
+                    self.coord_handedness,
+                )
+                .map(|mut triangles| {
+                    // No triangle crosses an edge of the face, so each one is
+                    // either completely inside or completely outside of it.
+                    // Checking a single point is enough to tell which.
+                    triangles.retain(|triangle| {
+                        let [a, b, c] =
+                            triangle.map(|point| point.point_surface);
+                        let center = a + ((b - a) + (c - a)) / 3.;
+                        face_as_polygon.contains_point(center)
+                    });
+                    triangles
+                })
+            }
+        };
+
+        // If the edges of the face cross each other, they can't all be edges
+        // of a constrained triangulation. Fall back to the unconstrained one.
+        let triangles = constrained.unwrap_or_else(|| {
+            let mut triangles =
+                delaunay::triangulate(points, self.coord_handedness);
+            triangles.retain(|triangle| {
+                face_as_polygon.contains_triangle(
+                    triangle.map(|point| point.point_surface),
+                )
+            });
+            triangles
         });
 
+        // The normals of the surface point to the front side of the face, if
+        // its coordinate system is right-handed.
+        let orientation = match self.coord_handedness {
+            Handedness::RightHanded => Scalar::ONE,
+            Handedness::LeftHanded => -Scalar::ONE,
+        };
+
         for triangle in triangles {
             let points = triangle.map(|point| point.point_global);
-            mesh.push_triangle(points, self.color);
+
+            // Surfaces can have singular points, like the poles of a sphere,
+            // where different surface coordinates map to the same global
+            // point. Triangles that touch those can be degenerate, and there's
+            // no point in adding them to the mesh.
+            let [a, b, c] = points;
+            if a == b || b == c || c == a {
+                continue;
+            }
+
+            // Where the surface doesn't have a normal, the triangle's own
+            // normal has to do.
+            let normal = (b - a).cross(&(c - a)).normalize();
+            let normals = triangle.map(|point| {
+                self.surface
+                    .normal_at(point.point_surface)
+                    .map(|normal| normal * orientation)
+                    .unwrap_or(normal)
+            });
+
+            let uvs = triangle.map(|point| point.point_surface);
+
+            mesh.push_triangle_with_attributes(
+                points,
+                normals,
+                Some(uvs),
+                self.color,
+            );
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
+
     use fj_interop::mesh::Mesh;
-    use fj_math::{Point, Scalar};
+    use fj_math::{NurbsSurface, Point, Scalar, Sphere};
 
     use crate::{
-        algorithms::approx::{Approx, Tolerance},
-        objects::{Face, Objects, Surface},
+        algorithms::approx::{curve::CurveCache, Approx, Tolerance},
+        objects::{Face, Objects, Solid, Surface},
     };
 
-    use super::Triangulate;
+    use super::{Triangulate, Triangulator};
 
     #[test]
     fn simple() -> anyhow::Result<()> {
@@ -101,7 +253,7 @@ mod tests {
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let face = Face::builder(&objects, surface)
             .with_exterior_polygon_from_points([a, b, c, d])
-            .build();
+            .build()?;
 
         let a = Point::from(a).to_xyz();
         let b = Point::from(b).to_xyz();
@@ -136,7 +288,7 @@ mod tests {
         let face = Face::builder(&objects, surface.clone())
             .with_exterior_polygon_from_points([a, b, c, d])
             .with_interior_polygon_from_points([e, f, g, h])
-            .build();
+            .build()?;
 
         let triangles = triangulate(face)?;
 
@@ -195,7 +347,7 @@ mod tests {
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let face = Face::builder(&objects, surface.clone())
             .with_exterior_polygon_from_points([a, b, c, d, e])
-            .build();
+            .build()?;
 
         let triangles = triangulate(face)?;
 
@@ -214,8 +366,278 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn sharp_concave_shape_constrained() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        // The same shape as in `sharp_concave_shape`. The unconstrained
+        // triangulation contains the triangle `b, d, e`, which crosses the
+        // boundary of the face.
+        let a = [0., 0.];
+        let b = [0.4, 0.];
+        let c = [0.4, 1.0];
+        let d = [0.1, 0.1];
+        let e = [0., 0.8];
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let face = Face::builder(&objects, surface.clone())
+            .with_exterior_polygon_from_points([a, b, c, d, e])
+            .build()?;
+
+        let triangles = triangulate_constrained(face)?;
+
+        let a3 = surface.point_from_surface_coords(a);
+        let b3 = surface.point_from_surface_coords(b);
+        let c3 = surface.point_from_surface_coords(c);
+        let d3 = surface.point_from_surface_coords(d);
+        let e3 = surface.point_from_surface_coords(e);
+
+        assert!(triangles.contains_triangle([a3, b3, d3]));
+        assert!(triangles.contains_triangle([b3, c3, d3]));
+        assert!(triangles.contains_triangle([a3, d3, e3]));
+
+        assert!(!triangles.contains_triangle([b3, e3, d3]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn hole_constrained() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let face = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([
+                [0., 0.],
+                [4., 0.],
+                [4., 4.],
+                [0., 4.],
+            ])
+            .with_interior_polygon_from_points([
+                [1., 1.],
+                [1., 3.],
+                [3., 3.],
+                [3., 1.],
+            ])
+            .build()?;
+
+        let triangles = triangulate_constrained(face)?;
+
+        // The exterior square and the hole each have four vertices. Together,
+        // they bound a ring that is covered by exactly eight triangles.
+        assert_eq!(triangles.triangles().count(), 8);
+
+        let area = triangles
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                (b - a).cross(&(c - a)).magnitude() / 2.
+            })
+            .fold(Scalar::ZERO, |sum, area| sum + area);
+        assert_eq!(area, Scalar::from_f64(12.));
+
+        Ok(())
+    }
+
+    #[test]
+    fn nurbs_surface() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::from_nurbs(
+            NurbsSurface::from_control_points(
+                [2, 2],
+                vec![
+                    vec![[0., 0., 0.], [0., 1., 0.], [0., 2., 0.]],
+                    vec![[1., 0., 0.], [1., 1., 1.], [1., 2., 0.]],
+                    vec![[2., 0., 0.], [2., 1., 0.], [2., 2., 0.]],
+                ],
+            ),
+        ));
+        let face = Face::builder(&objects, surface.clone())
+            .with_exterior_polygon_from_points([
+                [0.1, 0.1],
+                [0.9, 0.1],
+                [0.9, 0.9],
+                [0.1, 0.9],
+            ])
+            .build()?;
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let triangles = face.approx(tolerance).triangulate();
+
+        // The top of the bump is not on any of the edges. It can only be part
+        // of the mesh, if the surface was approximated.
+        let top = surface.point_from_surface_coords([0.5, 0.5]);
+        assert!(triangles.vertices().any(|vertex| vertex == top));
+
+        Ok(())
+    }
+
+    #[test]
+    fn sphere() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let sphere = Sphere::from_center_and_radius([0., 0., 0.], 1.);
+        let surface = objects.surfaces.insert(Surface::from_sphere(sphere));
+        let face = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([
+                [0., 0.],
+                [1., 0.],
+                [1., 1.],
+                [0., 1.],
+            ])
+            .build()?;
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let triangles = face.approx(tolerance).triangulate();
+
+        // If the surface hadn't been approximated, there would be no vertices
+        // in the interior of the face.
+        let interior = triangles.vertices().filter(|vertex| {
+            let [u, v] =
+                sphere.point_to_sphere_coords(*vertex).coords.components;
+            u > Scalar::ZERO
+                && u < Scalar::ONE
+                && v > Scalar::ZERO
+                && v < Scalar::ONE
+        });
+        assert!(interior.count() > 0);
+
+        for vertex in triangles.vertices() {
+            let distance = (vertex - sphere.center()).magnitude();
+            assert!((distance - 1.).abs() < Scalar::from(1e-12));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn normals_follow_surface() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let sphere = Sphere::from_center_and_radius([0., 0., 0.], 1.);
+        let surface = objects.surfaces.insert(Surface::from_sphere(sphere));
+        let face = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([
+                [0., 0.],
+                [1., 0.],
+                [1., 1.],
+                [0., 1.],
+            ])
+            .build()?;
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let triangles = face.approx(tolerance).triangulate();
+
+        // The normals of a sphere point away from its center. Neighboring
+        // triangles share them, so the sphere doesn't look faceted.
+        for triangle in triangles.triangles() {
+            for (point, normal) in
+                triangle.inner.points().into_iter().zip(triangle.normals)
+            {
+                let expected = point - sphere.center();
+                assert!((normal - expected).magnitude() < Scalar::from(1e-6));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn uvs_are_surface_coords() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xz_plane());
+        let face = Face::builder(&objects, surface.clone())
+            .with_exterior_polygon_from_points([
+                [0., 0.],
+                [2., 0.],
+                [2., 2.],
+                [0., 1.],
+            ])
+            .build()?;
+
+        for triangle in triangulate(face)?.triangles() {
+            let uvs = triangle.uvs.expect("Triangle has surface coordinates");
+            let points = uvs.map(|uv| surface.point_from_surface_coords(uv));
+            assert_eq!(points, triangle.inner.points());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn constrained_with_crossing_edges() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        // Two edges of this face cross each other. They can't all be
+        // constraints, so the unconstrained triangulation is used. The face is
+        // not symmetric, as the winding of a symmetric one is undefined.
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let face = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([
+                [0., 0.],
+                [2., 2.],
+                [2., 0.],
+                [0., 3.],
+            ])
+            .build()?;
+
+        let constrained = triangulate_constrained(face.clone())?;
+        let unconstrained = triangulate(face)?;
+        assert_eq!(
+            constrained.triangles().collect::<Vec<_>>(),
+            unconstrained.triangles().collect::<Vec<_>>(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn same_mesh_with_and_without_parallel() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        let solid = Solid::builder(&objects).build_cube_from_edge_length(1.)?;
+
+        // Depending on the `parallel` feature, this triangulates the faces on
+        // multiple threads.
+        let mesh = (&solid, tolerance).triangulate();
+
+        // Approximate and triangulate the same faces one by one, on the current
+        // thread.
+        let mut cache = CurveCache::new();
+        let approx = solid
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .map(|face| face.approx_with_cache(tolerance, &mut cache))
+            .collect::<BTreeSet<_>>();
+        let mut expected = Mesh::new();
+        for approx in approx {
+            approx
+                .triangulate_into_mesh(&mut expected, Triangulator::default());
+        }
+
+        assert_eq!(
+            mesh.triangles().collect::<Vec<_>>(),
+            expected.triangles().collect::<Vec<_>>(),
+        );
+
+        Ok(())
+    }
+
     fn triangulate(face: impl Into<Face>) -> anyhow::Result<Mesh<Point<3>>> {
         let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
         Ok(face.into().approx(tolerance).triangulate())
     }
+
+    fn triangulate_constrained(
+        face: impl Into<Face>,
+    ) -> anyhow::Result<Mesh<Point<3>>> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+        Ok(face
+            .into()
+            .approx(tolerance)
+            .triangulate_with(Triangulator::ConstrainedDelaunay))
+    }
 }
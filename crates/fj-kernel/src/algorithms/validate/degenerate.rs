@@ -0,0 +1,180 @@
+use std::fmt;
+
+use fj_math::Scalar;
+
+use crate::{
+    algorithms::approx::{Approx, Tolerance},
+    objects::{Cycle, Face, HalfEdge},
+};
+
+use super::ValidationWarning;
+
+pub fn validate_half_edge(
+    half_edge: &HalfEdge,
+    min_edge_length: Scalar,
+    tolerance: Tolerance,
+    warnings: &mut Vec<ValidationWarning>,
+) -> Result<(), DegenerateIssues> {
+    let length = length(half_edge, tolerance);
+
+    if length < min_edge_length {
+        return Err(DegenerateIssues {
+            short_edge: Some(Box::new(half_edge.clone())),
+            ..DegenerateIssues::default()
+        });
+    }
+    if length < tolerance.inner() {
+        warnings.push(ValidationWarning::SmallerThanTolerance {
+            half_edge: Box::new(half_edge.clone()),
+            length,
+            tolerance: tolerance.inner(),
+        });
+    }
+
+    Ok(())
+}
+
+pub fn validate_cycle(
+    cycle: &Cycle,
+    min_face_area: Scalar,
+    tolerance: Tolerance,
+) -> Result<(), DegenerateIssues> {
+    if area(cycle, tolerance).abs() < min_face_area {
+        return Err(DegenerateIssues {
+            collapsed_cycle: Some(Box::new(cycle.clone())),
+            ..DegenerateIssues::default()
+        });
+    }
+
+    Ok(())
+}
+
+pub fn validate_face(
+    face: &Face,
+    min_face_area: Scalar,
+    thin_face_ratio: Scalar,
+    tolerance: Tolerance,
+    warnings: &mut Vec<ValidationWarning>,
+) -> Result<(), DegenerateIssues> {
+    let holes = face
+        .interiors()
+        .map(|cycle| area(cycle, tolerance).abs())
+        .fold(Scalar::ZERO, |holes, hole| holes + hole);
+    let area = area(face.exterior(), tolerance).abs() - holes;
+
+    if area < min_face_area {
+        return Err(DegenerateIssues {
+            zero_area_face: Some(Box::new(face.clone())),
+            ..DegenerateIssues::default()
+        });
+    }
+
+    // For a long, narrow face, half the perimeter is about its length, and
+    // area divided by that is about its width.
+    let perimeter = face
+        .all_cycles()
+        .flat_map(|cycle| cycle.half_edges())
+        .map(|half_edge| length(half_edge, tolerance))
+        .fold(Scalar::ZERO, |perimeter, length| perimeter + length);
+    let length = perimeter / 2.;
+    let width = area / length;
+
+    if width < length * thin_face_ratio {
+        warnings.push(ValidationWarning::ThinFace {
+            face: Box::new(face.clone()),
+            width,
+            length,
+        });
+    }
+
+    Ok(())
+}
+
+/// Compute the length of a half-edge, from its approximation
+fn length(half_edge: &HalfEdge, tolerance: Tolerance) -> Scalar {
+    let [_, b] = half_edge.vertices();
+
+    let mut points = half_edge
+        .approx(tolerance)
+        .points()
+        .into_iter()
+        .map(|point| point.global_form)
+        .collect::<Vec<_>>();
+    points.push(b.global_form().position());
+
+    points
+        .windows(2)
+        .map(|segment| (segment[1] - segment[0]).magnitude())
+        .fold(Scalar::ZERO, |length, segment| length + segment)
+}
+
+/// Compute the signed area enclosed by a cycle
+///
+/// The approximated cycle is split into a fan of triangles in surface
+/// coordinates. Each triangle contributes the area of its corresponding
+/// triangle in global coordinates, with the sign of its area in surface
+/// coordinates. On curved surfaces, this underestimates the area slightly, in
+/// the same way the approximation does.
+fn area(cycle: &Cycle, tolerance: Tolerance) -> Scalar {
+    let points = cycle.approx(tolerance).points();
+
+    let first = match points.first() {
+        Some(first) => first,
+        None => return Scalar::ZERO,
+    };
+
+    let mut area = Scalar::ZERO;
+    for triangle in points.windows(2) {
+        let [b, c] = [&triangle[0], &triangle[1]];
+
+        let local = (b.local_form - first.local_form)
+            .cross2d(&(c.local_form - first.local_form));
+        let global = (b.global_form - first.global_form)
+            .cross(&(c.global_form - first.global_form))
+            .magnitude();
+
+        area += local.sign().to_scalar() * global / 2.;
+    }
+
+    area
+}
+
+/// Degenerate geometry found during validation
+///
+/// Degenerate objects can't be triangulated reliably. Used by
+/// [`ValidationError`].
+///
+/// [`ValidationError`]: super::ValidationError
+#[derive(Debug, Default, thiserror::Error)]
+pub struct DegenerateIssues {
+    /// Half-edge that is shorter than the minimum edge length
+    pub short_edge: Option<Box<HalfEdge>>,
+
+    /// Cycle that encloses less than the minimum face area
+    pub collapsed_cycle: Option<Box<Cycle>>,
+
+    /// Face whose area is less than the minimum face area
+    pub zero_area_face: Option<Box<Face>>,
+}
+
+impl fmt::Display for DegenerateIssues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Degenerate geometry found:")?;
+
+        if let Some(short_edge) = &self.short_edge {
+            writeln!(f, "- Edge has (almost) zero length ({})", short_edge)?;
+        }
+        if let Some(collapsed_cycle) = &self.collapsed_cycle {
+            let half_edges = collapsed_cycle.half_edges().count();
+            writeln!(
+                f,
+                "- Cycle of {half_edges} half-edges encloses (almost) no area"
+            )?;
+        }
+        if self.zero_area_face.is_some() {
+            writeln!(f, "- Face has (almost) zero area")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+use std::fmt;
+
+use fj_math::{Aabb, Bvh, Point, Scalar};
+
+use crate::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        collision::triangle_triangle,
+        triangulate::Triangulate,
+    },
+    objects::{Cycle, Face, HalfEdge, Shell},
+};
+
+pub fn validate_half_edge(half_edge: &HalfEdge) -> Result<(), GeometricIssues> {
+    let trimmed_curve = half_edge.trimmed_curve();
+
+    // On a periodic curve, an edge that is longer than a period would overlap
+    // itself. An edge that is exactly one period long is fine though. That is
+    // just a closed edge, like a full circle.
+    if let Some(period) = trimmed_curve.curve().period() {
+        if trimmed_curve.length() > period {
+            return Err(GeometricIssues {
+                overlapping_edge: Some(Box::new(half_edge.clone())),
+                ..GeometricIssues::default()
+            });
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_cycle(
+    cycle: &Cycle,
+    tolerance: Tolerance,
+) -> Result<(), GeometricIssues> {
+    let half_edges = cycle.half_edges().collect::<Vec<_>>();
+    let approx = cycle.approx(tolerance);
+
+    // The approximation of each half-edge, in surface coordinates, including
+    // its last vertex, which is the first vertex of the next half-edge.
+    let num_half_edges = approx.half_edges.len();
+    let polylines = (0..num_half_edges)
+        .map(|i| {
+            let next = &approx.half_edges[(i + 1) % num_half_edges];
+            approx.half_edges[i]
+                .points()
+                .into_iter()
+                .chain([next.first.clone()])
+                .map(|point| point.local_form)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    for i in 0..num_half_edges {
+        for j in i + 1..num_half_edges {
+            let segments_i = polylines[i].windows(2).collect::<Vec<_>>();
+            let segments_j = polylines[j].windows(2).collect::<Vec<_>>();
+
+            for (k, a) in segments_i.iter().enumerate() {
+                for (l, b) in segments_j.iter().enumerate() {
+                    let a = [a[0], a[1]];
+                    let b = [b[0], b[1]];
+
+                    // Neighboring half-edges share a vertex. The segments
+                    // that touch there only intersect, if they fold back onto
+                    // each other.
+                    let i_then_j =
+                        j == i + 1 && k == segments_i.len() - 1 && l == 0;
+                    let j_then_i = i == 0
+                        && j == num_half_edges - 1
+                        && k == 0
+                        && l == segments_j.len() - 1;
+
+                    let intersection = if i_then_j {
+                        segments_fold_back(a, b)
+                    } else if j_then_i {
+                        segments_fold_back(b, a)
+                    } else {
+                        segment_segment(a, b)
+                    };
+
+                    if let Some(point) = intersection {
+                        let point =
+                            cycle.surface().point_from_surface_coords(point);
+
+                        return Err(GeometricIssues {
+                            self_intersecting_cycle: Some(Box::new(
+                                SelfIntersection {
+                                    objects: [
+                                        half_edges[i].clone(),
+                                        half_edges[j].clone(),
+                                    ],
+                                    point,
+                                },
+                            )),
+                            ..GeometricIssues::default()
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_shell(
+    shell: &Shell,
+    tolerance: Tolerance,
+) -> Result<(), GeometricIssues> {
+    let faces = shell.faces().into_iter().collect::<Vec<_>>();
+    let approxs = faces
+        .iter()
+        .map(|face| face.approx(tolerance))
+        .collect::<Vec<_>>();
+
+    // Neighboring faces might approximate the edge they share differently, so
+    // their triangles can overlap slightly along it. Intersections that are
+    // that close to the boundary of a face are ignored.
+    let boundaries = approxs
+        .iter()
+        .map(|approx| {
+            Some(&approx.exterior)
+                .into_iter()
+                .chain(&approx.interiors)
+                .flat_map(|cycle| cycle.segments())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let is_near_boundary = |point: Point<3>, face: usize| {
+        boundaries[face].iter().any(|segment| {
+            distance_to_segment(point, segment.points()) <= tolerance.inner()
+        })
+    };
+
+    let triangles =
+        Bvh::new(approxs.into_iter().enumerate().flat_map(|(i, approx)| {
+            approx
+                .triangulate()
+                .triangles()
+                .map(|triangle| {
+                    let triangle = triangle.inner;
+                    (Aabb::<3>::from_points(triangle.points()), (i, triangle))
+                })
+                .collect::<Vec<_>>()
+        }));
+
+    for (&(i, a), &(j, b)) in triangles.overlapping_pairs(&triangles) {
+        // Each pair shows up in both orders. Triangles of the same face can't
+        // intersect each other.
+        if i >= j {
+            continue;
+        }
+
+        let intersection = triangle_triangle(a, b).into_iter().find(|&point| {
+            !is_near_boundary(point, i) && !is_near_boundary(point, j)
+        });
+
+        if let Some(point) = intersection {
+            return Err(GeometricIssues {
+                self_intersecting_shell: Some(Box::new(SelfIntersection {
+                    objects: [faces[i].clone(), faces[j].clone()],
+                    point,
+                })),
+                ..GeometricIssues::default()
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Find a point where two segments intersect or touch
+fn segment_segment(a: [Point<2>; 2], b: [Point<2>; 2]) -> Option<Point<2>> {
+    let [p, q] = a;
+    let [r, s] = b;
+
+    let [o_p, o_q] = [p, q].map(|point| orient2d(r, s, point));
+    let [o_r, o_s] = [r, s].map(|point| orient2d(p, q, point));
+
+    if o_p * o_q < 0. && o_r * o_s < 0. {
+        let t = o_p / (o_p - o_q);
+        return Some(p + (q - p) * t);
+    }
+
+    // The segments don't cross each other, but they might still touch.
+    [(o_p, p, b), (o_q, q, b), (o_r, r, a), (o_s, s, a)]
+        .into_iter()
+        .find(|&(o, point, segment)| o == 0. && is_within(point, segment))
+        .map(|(_, point, _)| point)
+}
+
+/// Determine whether two segments that follow each other fold back
+///
+/// The end of `a` must be the start of `b`.
+fn segments_fold_back(a: [Point<2>; 2], b: [Point<2>; 2]) -> Option<Point<2>> {
+    let [p, shared] = a;
+    let [_, q] = b;
+
+    let is_collinear = orient2d(p, shared, q) == 0.;
+    let is_folded = (p - shared).dot(&(q - shared)) > Scalar::ZERO;
+
+    (is_collinear && is_folded).then_some(shared)
+}
+
+fn distance_to_segment(point: Point<3>, [a, b]: [Point<3>; 2]) -> Scalar {
+    let direction = b - a;
+    let t = (point - a).dot(&direction) / direction.dot(&direction);
+    let closest = a + direction * t.max(Scalar::ZERO).min(Scalar::ONE);
+
+    (point - closest).magnitude()
+}
+
+/// Determine whether a point that is collinear with a segment is within it
+fn is_within(point: Point<2>, [a, b]: [Point<2>; 2]) -> bool {
+    let [min_u, max_u] = if a.u < b.u { [a.u, b.u] } else { [b.u, a.u] };
+    let [min_v, max_v] = if a.v < b.v { [a.v, b.v] } else { [b.v, a.v] };
+
+    point.u >= min_u && point.u <= max_u && point.v >= min_v && point.v <= max_v
+}
+
+fn orient2d(a: Point<2>, b: Point<2>, c: Point<2>) -> f64 {
+    let [a, b, c] =
+        [a, b, c].map(|point| point.coords.components.map(Scalar::into_f64));
+    robust_predicates::orient2d(&a, &b, &c)
+}
+
+/// Geometric issues found during validation
+///
+/// Used by [`ValidationError`].
+///
+/// [`ValidationError`]: super::ValidationError
+#[derive(Debug, Default, thiserror::Error)]
+pub struct GeometricIssues {
+    /// Half-edge that spans more than one period of its curve
+    pub overlapping_edge: Option<Box<HalfEdge>>,
+
+    /// Half-edges of the same cycle that intersect each other
+    pub self_intersecting_cycle: Option<Box<SelfIntersection<HalfEdge>>>,
+
+    /// Faces of the same shell that intersect each other
+    pub self_intersecting_shell: Option<Box<SelfIntersection<Face>>>,
+}
+
+impl fmt::Display for GeometricIssues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Geometric issues found:")?;
+
+        if let Some(overlapping_edge) = &self.overlapping_edge {
+            writeln!(f, "- Edge overlaps itself ({})", overlapping_edge)?;
+        }
+        if let Some(intersection) = &self.self_intersecting_cycle {
+            let [a, b] = &intersection.objects;
+            writeln!(
+                f,
+                "- Cycle intersects itself at {:?} ({}, {})",
+                intersection.point, a, b
+            )?;
+        }
+        if let Some(intersection) = &self.self_intersecting_shell {
+            writeln!(
+                f,
+                "- Faces of shell intersect each other at {:?}",
+                intersection.point
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Two parts of an object that intersect each other
+#[derive(Clone, Debug)]
+pub struct SelfIntersection<T> {
+    /// The parts that intersect each other
+    pub objects: [T; 2],
+
+    /// A point where they intersect
+    pub point: Point<3>,
+}
@@ -1,32 +1,56 @@
 //! Infrastructure for validating shapes
 //!
 //! Validation enforces various constraints about shapes and the objects that
-//! constitute them. These constraints fall into 4 categories:
+//! constitute them. These constraints fall into 7 categories:
 //!
 //! - **Coherence:** Local forms of objects must be consistent with their
 //!   canonical forms.
+//! - **Degenerate:** Edges must have a length, and cycles and faces must
+//!   enclose an area.
 //! - **Geometric:** Comprises various object-specific constraints, for example
 //!   edges or faces might not be allowed to intersect.
+//! - **Orientation:** The faces of a shell must be oriented consistently, and
+//!   those of a solid must point outward.
 //! - **Structural:** All other objects that an object references must be part
 //!   of the same shape.
 //! - **Uniqueness:** Objects within a shape must be unique.
+//! - **Watertightness:** Every edge of a closed shell must be shared by
+//!   exactly two of its half-edges.
 //!
 //! Please note that not all of these validation categories are fully
 //! implemented, as of this writing.
+//!
+//! Besides errors, validation can find geometry that is valid, but likely to
+//! cause problems, like faces that are very thin. These are reported as
+//! warnings, which don't fail validation. See [`Validated::warnings`].
 
 mod coherence;
+mod degenerate;
+mod geometric;
+mod orientation;
 mod uniqueness;
+mod warning;
+mod watertightness;
 
 pub use self::{
     coherence::{CoherenceIssues, VertexCoherenceMismatch},
+    degenerate::DegenerateIssues,
+    geometric::{GeometricIssues, SelfIntersection},
+    orientation::OrientationIssues,
     uniqueness::UniquenessIssues,
+    warning::ValidationWarning,
+    watertightness::{find_edge_issues, EdgeIssues, WatertightnessIssues},
 };
 
-use std::{collections::HashSet, ops::Deref};
+pub(crate) use self::watertightness::{group_half_edges, shell_edges};
+
+use std::ops::Deref;
 
 use fj_math::Scalar;
 
-use crate::iter::ObjectIters;
+use crate::{iter::ObjectIters, objects::HalfEdge, partial::PartialBuildError};
+
+use super::approx::Tolerance;
 
 /// Validate an object
 pub trait Validate: Sized {
@@ -70,25 +94,179 @@ where
         self,
         config: &ValidationConfig,
     ) -> Result<Validated<Self>, ValidationError> {
-        let mut global_vertices = HashSet::new();
+        let checks = config.checks;
+        let mut warnings = Vec::new();
 
-        for global_vertex in self.global_vertex_iter() {
-            uniqueness::validate_vertex(
-                global_vertex,
-                &global_vertices,
-                config.distinct_min_distance,
-            )?;
+        if checks.uniqueness {
+            // Each vertex is compared to the ones before it, which makes the
+            // checks independent of each other.
+            let global_vertices =
+                self.global_vertex_iter().copied().collect::<Vec<_>>();
 
-            global_vertices.insert(*global_vertex);
+            check_each(
+                global_vertices.iter().enumerate().collect(),
+                &mut warnings,
+                |(i, global_vertex), _| {
+                    uniqueness::validate_vertex(
+                        global_vertex,
+                        &global_vertices[..*i],
+                        config.distinct_min_distance,
+                    )
+                },
+            )?;
         }
-        for vertex in self.vertex_iter() {
-            coherence::validate_vertex(vertex, config.identical_max_distance)?;
+        if checks.coherence {
+            check_each(
+                self.vertex_iter().collect(),
+                &mut warnings,
+                |vertex, _| {
+                    coherence::validate_vertex(
+                        vertex,
+                        config.identical_max_distance,
+                    )
+                },
+            )?;
+        }
+        if checks.geometric {
+            check_each(
+                self.half_edge_iter().collect(),
+                &mut warnings,
+                |half_edge, _| geometric::validate_half_edge(half_edge),
+            )?;
+            check_each(
+                self.cycle_iter().collect(),
+                &mut warnings,
+                |cycle, _| {
+                    geometric::validate_cycle(
+                        cycle,
+                        config.intersection_tolerance,
+                    )
+                },
+            )?;
+        }
+        // Self-intersecting cycles enclose an area that's hard to measure, so
+        // they are checked for first. Degenerate faces must be ruled out
+        // before the shell checks, which triangulate them.
+        if checks.degenerate {
+            check_each(
+                self.half_edge_iter().collect(),
+                &mut warnings,
+                |half_edge, warnings| {
+                    degenerate::validate_half_edge(
+                        half_edge,
+                        config.min_edge_length,
+                        config.intersection_tolerance,
+                        warnings,
+                    )
+                },
+            )?;
+            check_each(
+                self.cycle_iter().collect(),
+                &mut warnings,
+                |cycle, _| {
+                    degenerate::validate_cycle(
+                        cycle,
+                        config.min_face_area,
+                        config.intersection_tolerance,
+                    )
+                },
+            )?;
+            check_each(
+                self.face_iter().collect(),
+                &mut warnings,
+                |face, warnings| {
+                    degenerate::validate_face(
+                        face,
+                        config.min_face_area,
+                        config.thin_face_ratio,
+                        config.intersection_tolerance,
+                        warnings,
+                    )
+                },
+            )?;
+        }
+        check_each(self.shell_iter().collect(), &mut warnings, |shell, _| {
+            if checks.geometric {
+                geometric::validate_shell(
+                    shell,
+                    config.intersection_tolerance,
+                )?;
+            }
+            if checks.watertightness {
+                watertightness::validate_shell(
+                    shell,
+                    config.distinct_min_distance,
+                )?;
+            }
+            if checks.orientation {
+                orientation::validate_shell(
+                    shell,
+                    config.distinct_min_distance,
+                )?;
+            }
+
+            Ok::<_, ValidationError>(())
+        })?;
+        if checks.orientation {
+            check_each(
+                self.solid_iter().collect(),
+                &mut warnings,
+                |solid, _| {
+                    orientation::validate_solid(
+                        solid,
+                        config.intersection_tolerance,
+                    )
+                },
+            )?;
         }
 
-        Ok(Validated(self))
+        Ok(Validated {
+            object: self,
+            warnings,
+        })
     }
 }
 
+/// Run a check for each of the objects
+///
+/// If the `parallel` feature is enabled, the checks run in parallel. Either
+/// way, the error that is returned is the one of the first object that fails
+/// the check, and the warnings are in the order of the objects.
+fn check_each<T, E>(
+    objects: Vec<T>,
+    warnings: &mut Vec<ValidationWarning>,
+    check: impl Fn(&T, &mut Vec<ValidationWarning>) -> Result<(), E> + Sync,
+) -> Result<(), ValidationError>
+where
+    T: Sync,
+    E: Send,
+    ValidationError: From<E>,
+{
+    #[cfg(not(feature = "parallel"))]
+    for object in &objects {
+        check(object, warnings)?;
+    }
+
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+
+        let results = objects
+            .par_iter()
+            .map(|object| {
+                let mut object_warnings = Vec::new();
+                check(object, &mut object_warnings).map(|()| object_warnings)
+            })
+            .collect::<Vec<_>>();
+
+        for result in results {
+            warnings.extend(result?);
+        }
+    }
+
+    Ok(())
+}
+
 /// Configuration required for the validation process
 #[derive(Debug, Clone, Copy)]
 pub struct ValidationConfig {
@@ -105,6 +283,43 @@ pub struct ValidationConfig {
     /// that distance is less than the one defined in this field, can not be
     /// considered identical.
     pub identical_max_distance: Scalar,
+
+    /// The tolerance used to approximate curved geometry, when checking
+    /// objects for self-intersections
+    ///
+    /// Intersections between parts of an object that are closer to each other,
+    /// or to the boundary of a face, than this might be missed.
+    pub intersection_tolerance: Tolerance,
+
+    /// The minimum length of an edge
+    ///
+    /// Shorter edges are considered degenerate. Edges are approximated using
+    /// [`ValidationConfig::intersection_tolerance`] to measure them.
+    pub min_edge_length: Scalar,
+
+    /// The minimum area of a face, and of the cycles that bound it
+    ///
+    /// Cycles and faces that enclose a smaller area are considered degenerate.
+    /// Triangulating them might produce invalid triangles.
+    pub min_face_area: Scalar,
+
+    /// The ratio of width to length, below which a face is considered thin
+    ///
+    /// Thin faces are valid, but reported as a [`ValidationWarning`].
+    pub thin_face_ratio: Scalar,
+
+    /// The categories of checks that are performed
+    pub checks: ValidationChecks,
+
+    /// The maximum distance at which vertices are snapped together
+    ///
+    /// If this is set, objects that fail validation because of nearly
+    /// coincident vertices are snapped and validated again, instead of being
+    /// rejected. See [`Snap::validate_or_snap`]. Should be at least as large as
+    /// [`ValidationConfig::distinct_min_distance`].
+    ///
+    /// [`Snap::validate_or_snap`]: super::snap::Snap::validate_or_snap
+    pub snap_distance: Option<Scalar>,
 }
 
 impl Default for ValidationConfig {
@@ -117,20 +332,138 @@ impl Default for ValidationConfig {
             // false positives due to floating-point accuracy issues), we can
             // adjust it.
             identical_max_distance: Scalar::from_f64(5e-14),
+
+            intersection_tolerance: Tolerance::from_scalar(1e-3)
+                .expect("Tolerance is valid"),
+
+            // Edges shorter than this can't have vertices that are distinct.
+            min_edge_length: Scalar::from_f64(5e-7),
+
+            // The area of a square whose sides have the minimum edge length.
+            min_face_area: Scalar::from_f64(2.5e-13),
+
+            thin_face_ratio: Scalar::from_f64(1e-3),
+
+            checks: ValidationChecks::default(),
+            snap_distance: None,
+        }
+    }
+}
+
+/// The categories of checks that are performed during validation
+///
+/// All checks are enabled by default. Disabling checks makes validation
+/// faster, but shapes that don't pass them can cause problems later on, for
+/// example when they are exported.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ValidationChecks {
+    /// Check the coherence of local and canonical forms
+    pub coherence: bool,
+
+    /// Check for degenerate edges, cycles, and faces
+    pub degenerate: bool,
+
+    /// Check object-specific geometric constraints, like self-intersections
+    pub geometric: bool,
+
+    /// Check that faces are oriented consistently
+    pub orientation: bool,
+
+    /// Check that objects are unique
+    pub uniqueness: bool,
+
+    /// Check that shells are watertight
+    pub watertightness: bool,
+}
+
+impl ValidationChecks {
+    /// The names of the checks, as used by [`ValidationChecks::set`]
+    pub const NAMES: [&'static str; 6] = [
+        "coherence",
+        "degenerate",
+        "geometric",
+        "orientation",
+        "uniqueness",
+        "watertightness",
+    ];
+
+    /// Enable all checks
+    pub fn all() -> Self {
+        Self {
+            coherence: true,
+            degenerate: true,
+            geometric: true,
+            orientation: true,
+            uniqueness: true,
+            watertightness: true,
         }
     }
+
+    /// Disable all checks
+    pub fn none() -> Self {
+        Self {
+            coherence: false,
+            degenerate: false,
+            geometric: false,
+            orientation: false,
+            uniqueness: false,
+            watertightness: false,
+        }
+    }
+
+    /// Enable or disable a check by name
+    ///
+    /// Returns an error, if `name` is not one of [`ValidationChecks::NAMES`].
+    pub fn set(
+        &mut self,
+        name: &str,
+        enabled: bool,
+    ) -> Result<(), UnknownValidationCheck> {
+        let check = match name {
+            "coherence" => &mut self.coherence,
+            "degenerate" => &mut self.degenerate,
+            "geometric" => &mut self.geometric,
+            "orientation" => &mut self.orientation,
+            "uniqueness" => &mut self.uniqueness,
+            "watertightness" => &mut self.watertightness,
+            _ => return Err(UnknownValidationCheck(name.to_owned())),
+        };
+
+        *check = enabled;
+        Ok(())
+    }
+}
+
+impl Default for ValidationChecks {
+    fn default() -> Self {
+        Self::all()
+    }
 }
 
+/// The name passed to [`ValidationChecks::set`] doesn't refer to a check
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown validation check: `{0}`")]
+pub struct UnknownValidationCheck(pub String);
+
 /// Wrapper around an object that indicates the object has been validated
 ///
-/// Returned by implementations of `Validate`.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
-pub struct Validated<T>(T);
+/// Returned by implementations of `Validate`, together with any warnings that
+/// were found during validation.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Validated<T> {
+    object: T,
+    warnings: Vec<ValidationWarning>,
+}
 
 impl<T> Validated<T> {
+    /// Access the warnings that were found during validation
+    pub fn warnings(&self) -> &[ValidationWarning] {
+        &self.warnings
+    }
+
     /// Consume this instance of `Validated` and return the wrapped object
     pub fn into_inner(self) -> T {
-        self.0
+        self.object
     }
 }
 
@@ -138,7 +471,7 @@ impl<T> Deref for Validated<T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.object
     }
 }
 
@@ -146,17 +479,120 @@ impl<T> Deref for Validated<T> {
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, thiserror::Error)]
 pub enum ValidationError {
+    /// A partial object could not be built
+    ///
+    /// This happens, if an operation builds objects from incomplete partial
+    /// objects, or if build validation fails for them.
+    #[error("Failed to build object")]
+    Build(#[from] PartialBuildError),
+
     /// Coherence validation failed
     #[error("Coherence validation failed")]
     Coherence(#[from] CoherenceIssues),
 
+    /// Degenerate geometry validation failed
+    #[error("Degenerate geometry validation failed")]
+    Degenerate(#[from] DegenerateIssues),
+
     /// Geometric validation failed
     #[error("Geometric validation failed")]
-    Geometric,
+    Geometric(#[from] GeometricIssues),
+
+    /// Orientation validation failed
+    #[error("Orientation validation failed")]
+    Orientation(#[from] OrientationIssues),
 
     /// Uniqueness validation failed
     #[error("Uniqueness validation failed")]
     Uniqueness(#[from] UniquenessIssues),
+
+    /// Watertightness validation failed
+    #[error("Watertightness validation failed")]
+    Watertightness(#[from] WatertightnessIssues),
+
+    /// The shape is not supported by the operation that builds it
+    ///
+    /// This happens for valid input, that an operation doesn't support yet,
+    /// like hollowing a shape with curved faces.
+    #[error("Unsupported shape: {0}")]
+    Unsupported(String),
+
+    /// The parameters of the operation that builds the shape are invalid
+    ///
+    /// This happens for parameters that no shape can be built from, like an
+    /// offset that makes a sketch vanish.
+    #[error("Invalid parameters: {0}")]
+    InvalidParameters(String),
+
+    /// Validation failed for a part of a larger shape
+    #[error("Validation of {context} failed")]
+    Context {
+        /// Describes the part of the shape, for example "sketch 'base'"
+        context: String,
+
+        /// The error that occurred while validating that part
+        #[source]
+        source: Box<ValidationError>,
+    },
+}
+
+impl ValidationError {
+    /// Describe the part of a larger shape, for which validation failed
+    ///
+    /// Can be called multiple times, as the error propagates through the
+    /// operations that make up a shape. The outermost context is added last.
+    pub fn with_context(self, context: impl Into<String>) -> Self {
+        Self::Context {
+            context: context.into(),
+            source: Box::new(self),
+        }
+    }
+
+    /// Access the half-edges that caused the error
+    ///
+    /// Returns an empty `Vec`, if the error wasn't caused by specific
+    /// half-edges.
+    pub fn half_edges(&self) -> Vec<&HalfEdge> {
+        match self {
+            Self::Degenerate(issues) => issues
+                .short_edge
+                .iter()
+                .map(|half_edge| &**half_edge)
+                .chain(
+                    issues
+                        .collapsed_cycle
+                        .iter()
+                        .flat_map(|cycle| cycle.half_edges()),
+                )
+                .collect(),
+            Self::Geometric(issues) => issues
+                .overlapping_edge
+                .iter()
+                .map(|half_edge| &**half_edge)
+                .chain(
+                    issues
+                        .self_intersecting_cycle
+                        .iter()
+                        .flat_map(|intersection| &intersection.objects),
+                )
+                .collect(),
+            Self::Orientation(issues) => {
+                issues.inconsistent_edges.iter().collect()
+            }
+            Self::Watertightness(issues) => issues
+                .open_edges
+                .iter()
+                .chain(&issues.over_shared_edges)
+                .collect(),
+            Self::Build(PartialBuildError::Invalid(source))
+            | Self::Context { source, .. } => source.half_edges(),
+            Self::Build(_)
+            | Self::Coherence(_)
+            | Self::Uniqueness(_)
+            | Self::Unsupported(_)
+            | Self::InvalidParameters(_) => Vec::new(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -164,17 +600,100 @@ mod tests {
     use fj_math::{Point, Scalar};
 
     use crate::{
-        algorithms::validate::{Validate, ValidationConfig, ValidationError},
+        algorithms::{
+            test_helpers::cuboid,
+            transform::TransformObject,
+            validate::{
+                check_each, DegenerateIssues, Validate, ValidationChecks,
+                ValidationConfig, ValidationError, ValidationWarning,
+            },
+        },
         objects::{
-            Curve, GlobalCurve, GlobalEdge, GlobalVertex, HalfEdge, Objects,
-            Surface, SurfaceVertex, Vertex,
+            Curve, Face, GlobalCurve, GlobalEdge, GlobalVertex, HalfEdge,
+            Objects, Shell, Surface, SurfaceVertex, Vertex,
         },
-        partial::HasPartial,
+        partial::{HasPartial, PartialBuildError},
         path::SurfacePath,
+        storage::Handle,
     };
 
     #[test]
-    fn coherence_edge() {
+    fn build_validation() -> anyhow::Result<()> {
+        let objects =
+            Objects::new().with_build_validation(ValidationConfig::default());
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let curve = Handle::<Curve>::partial()
+            .with_surface(Some(surface.clone()))
+            .as_u_axis()
+            .build(&objects)?;
+
+        // Valid objects are built, as usual.
+        HalfEdge::partial()
+            .with_surface(Some(surface))
+            .as_line_segment_from_points([[0., 0.], [1., 0.]])?
+            .build(&objects)?;
+
+        // The global form of this vertex is nowhere near its curve. Building
+        // it fails right away, instead of when the shape is validated.
+        let result = Vertex::partial()
+            .with_position(Some([0.]))
+            .with_curve(Some(curve))
+            .with_global_form(Some(GlobalVertex::from_position(
+                [1., 1., 1.],
+                &objects,
+            )))
+            .build(&objects);
+        assert!(matches!(result, Err(PartialBuildError::Invalid(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_each_in_order() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let half_edge = HalfEdge::partial()
+            .with_surface(Some(surface))
+            .as_line_segment_from_points([[0., 0.], [1., 0.]])?
+            .build(&objects)?;
+        let warning = |i: usize| ValidationWarning::SmallerThanTolerance {
+            half_edge: Box::new(half_edge.clone()),
+            length: Scalar::from_f64(i as f64),
+            tolerance: Scalar::ONE,
+        };
+
+        // With the `parallel` feature, the checks can run in any order. The
+        // result must be the same either way.
+        let check = |i: &usize, warnings: &mut Vec<ValidationWarning>| {
+            warnings.push(warning(*i));
+
+            if i % 10 == 5 {
+                return Err(ValidationError::Context {
+                    context: i.to_string(),
+                    source: Box::new(DegenerateIssues::default().into()),
+                });
+            }
+            Ok(())
+        };
+
+        let mut warnings = Vec::new();
+        check_each((0..5).collect(), &mut warnings, check)?;
+        assert_eq!(warnings, (0..5).map(warning).collect::<Vec<_>>());
+
+        let mut warnings = Vec::new();
+        let result = check_each((0..100).collect(), &mut warnings, check);
+        assert!(matches!(
+            result,
+            Err(ValidationError::Context { context, .. }) if context == "5"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn coherence_edge() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
@@ -220,7 +739,7 @@ mod tests {
 
         let global_edge = GlobalEdge::partial()
             .from_curve_and_vertices(&curve, &vertices)
-            .build(&objects);
+            .build(&objects)?;
         let half_edge = HalfEdge::new(vertices, global_edge);
 
         let result =
@@ -235,6 +754,226 @@ mod tests {
             ..ValidationConfig::default()
         });
         assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn degenerate_edge() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let config = ValidationConfig {
+            min_edge_length: Scalar::from_f64(0.1),
+            ..ValidationConfig::default()
+        };
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let half_edge = |end| {
+            HalfEdge::partial()
+                .with_surface(Some(surface.clone()))
+                .as_line_segment_from_points([[0., 0.], end])?
+                .build(&objects)
+        };
+
+        assert!(half_edge([1., 0.])?.validate_with_config(&config).is_ok());
+
+        let result = half_edge([0.01, 0.])?.validate_with_config(&config);
+        assert!(matches!(
+            result,
+            Err(ValidationError::Degenerate(issues))
+                if issues.short_edge.is_some()
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn degenerate_cycle_and_face() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let config = ValidationConfig {
+            min_face_area: Scalar::from_f64(0.01),
+            ..ValidationConfig::default()
+        };
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let square = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+
+        let face = Face::builder(&objects, surface.clone())
+            .with_exterior_polygon_from_points(square)
+            .build()?;
+        assert!(face.validate_with_config(&config).is_ok());
+
+        // A sliver of a triangle collapses into a line.
+        let sliver = Face::builder(&objects, surface.clone())
+            .with_exterior_polygon_from_points([
+                [0., 0.],
+                [1., 0.],
+                [0.5, 1e-3],
+            ])
+            .build()?;
+        let result = sliver.validate_with_config(&config);
+        assert!(matches!(
+            result,
+            Err(ValidationError::Degenerate(issues))
+                if issues.collapsed_cycle.is_some()
+        ));
+
+        // Each cycle encloses enough area, but the hole leaves almost none.
+        let frame = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points(square)
+            .with_interior_polygon_from_points([
+                [0.001, 0.001],
+                [0.001, 0.999],
+                [0.999, 0.999],
+                [0.999, 0.001],
+            ])
+            .build()?;
+        let result = frame.validate_with_config(&config);
+        assert!(matches!(
+            result,
+            Err(ValidationError::Degenerate(issues))
+                if issues.zero_area_face.is_some()
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn degenerate_warnings() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let face = |points| {
+            Face::builder(&objects, surface.clone())
+                .with_exterior_polygon_from_points(points)
+                .build()
+        };
+
+        let square = face([[0., 0.], [1., 0.], [1., 1.], [0., 1.]])?;
+        assert!(square.validate()?.warnings().is_empty());
+
+        // A sliver is valid, but very thin, and narrower than the tolerance.
+        let sliver = face([[0., 0.], [1., 0.], [1., 1e-4], [0., 1e-4]])?;
+        let sliver = sliver.validate()?;
+        assert!(sliver.warnings().iter().any(|warning| matches!(
+            warning,
+            ValidationWarning::ThinFace { .. }
+        )));
+        assert!(sliver.warnings().iter().any(|warning| matches!(
+            warning,
+            ValidationWarning::SmallerThanTolerance { .. }
+        )));
+
+        Ok(())
+    }
+
+    #[test]
+    fn geometric_edge_on_periodic_curve() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let curve = Handle::<Curve>::partial()
+            .with_surface(Some(surface))
+            .as_circle_from_radius(1.)
+            .build(&objects)?;
+
+        let half_edge = |end| -> anyhow::Result<_> {
+            let [a, b] = [Scalar::ZERO, end].map(|t| {
+                Vertex::partial()
+                    .with_position(Some([t]))
+                    .with_curve(Some(curve.clone()))
+                    .build(&objects)
+            });
+            let vertices = [a?, b?];
+            let global_edge = GlobalEdge::partial()
+                .from_curve_and_vertices(&curve, &vertices)
+                .build(&objects)?;
+            Ok(HalfEdge::new(vertices, global_edge))
+        };
+
+        assert!(half_edge(Scalar::PI)?.validate().is_ok());
+
+        // An edge that spans more than the whole circle overlaps itself.
+        let result = half_edge(Scalar::TAU * 1.5)?.validate();
+        assert!(matches!(result, Err(ValidationError::Geometric(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn geometric_self_intersecting_cycle() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let face = |points| {
+            Face::builder(&objects, surface.clone())
+                .with_exterior_polygon_from_points(points)
+                .build()
+        };
+
+        let square = face([[0., 0.], [1., 0.], [1., 1.], [0., 1.]])?;
+        assert!(square.validate().is_ok());
+
+        // The edges of a bowtie cross each other in the middle.
+        let bowtie = face([[0., 0.], [1., 1.], [1., 0.], [0., 1.]])?;
+        let result = bowtie.validate();
+        assert!(matches!(
+            result,
+            Err(ValidationError::Geometric(issues))
+                if issues.self_intersecting_cycle.is_some()
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn geometric_self_intersecting_shell() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let a = Face::builder(
+            &objects,
+            objects.surfaces.insert(Surface::xy_plane()),
+        )
+        .with_exterior_polygon_from_points([
+            [0., 0.],
+            [1., 0.],
+            [1., 1.],
+            [0., 1.],
+        ])
+        .build()?;
+        let b = Face::builder(
+            &objects,
+            objects.surfaces.insert(Surface::xz_plane()),
+        )
+        .with_exterior_polygon_from_points([
+            [0.25, -1.],
+            [0.75, -1.],
+            [0.75, 1.],
+            [0.25, 1.],
+        ])
+        .build()?;
+
+        // Moved to the side, the faces don't touch each other. The shell is
+        // not closed, but that's not a geometric issue.
+        let shell = Shell::new().with_faces([
+            a.clone(),
+            b.clone().translate([0., 2., 0.], &objects)?,
+        ]);
+        assert!(!matches!(
+            shell.validate(),
+            Err(ValidationError::Geometric(_))
+        ));
+
+        // Moved into the middle, the second face passes through the first.
+        let shell =
+            Shell::new().with_faces([a, b.translate([0., 0.5, 0.], &objects)?]);
+        let result = shell.validate();
+        assert!(matches!(
+            result,
+            Err(ValidationError::Geometric(issues))
+                if issues.self_intersecting_shell.is_some()
+        ));
+
+        Ok(())
     }
 
     #[test]
@@ -260,9 +999,77 @@ mod tests {
 
         // Adding a second vertex that is considered identical should fail.
         shape.push(GlobalVertex::from_position(b, &objects));
-        let result = shape.validate_with_config(&config);
+        let result = shape.clone().validate_with_config(&config);
         assert!(matches!(result, Err(ValidationError::Uniqueness(_))));
 
+        // Unless the check is disabled.
+        let mut checks = ValidationChecks::default();
+        checks.set("uniqueness", false)?;
+        shape.validate_with_config(&ValidationConfig { checks, ..config })?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation_checks() {
+        let mut checks = ValidationChecks::all();
+        for name in ValidationChecks::NAMES {
+            let before = checks;
+            checks.set(name, false).expect("Check is known");
+            assert_ne!(checks, before, "`{name}` doesn't change any check");
+        }
+        assert_eq!(checks, ValidationChecks::none());
+
+        assert!(checks.set("unknown", true).is_err());
+        assert_eq!(checks, ValidationChecks::none());
+    }
+
+    #[test]
+    fn watertightness_shell() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let cube = |offset: [f64; 3]| {
+            cuboid([1.; 3], offset, &objects)
+                .into_shells()
+                .flat_map(Shell::into_faces)
+                .collect::<Vec<_>>()
+        };
+
+        let shell = Shell::new().with_faces(cube([0., 0., 0.]));
+        shell.validate()?;
+
+        // Without one of its faces, the edges around the opening are open.
+        let shell =
+            Shell::new().with_faces(cube([0., 0., 0.]).into_iter().skip(1));
+        let result = shell.clone().validate();
+        assert!(matches!(
+            result,
+            Err(ValidationError::Watertightness(issues))
+                if issues.open_edges.len() == 4
+                    && issues.over_shared_edges.is_empty()
+        ));
+
+        // The context of an error doesn't hide the half-edges that caused it.
+        let err = shell
+            .validate()
+            .expect_err("Shell is open")
+            .with_context("cube");
+        assert_eq!(err.to_string(), "Validation of cube failed");
+        assert_eq!(err.half_edges().len(), 4);
+
+        // Two cubes that touch along an edge share that edge between four
+        // faces.
+        let shell = Shell::new().with_faces(
+            cube([0., 0., 0.]).into_iter().chain(cube([1., 1., 0.])),
+        );
+        let result = shell.validate();
+        assert!(matches!(
+            result,
+            Err(ValidationError::Watertightness(issues))
+                if issues.open_edges.is_empty()
+                    && issues.over_shared_edges.len() == 1
+        ));
+
         Ok(())
     }
 }
@@ -0,0 +1,86 @@
+use std::fmt;
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Point, Scalar};
+
+use crate::{
+    algorithms::{
+        approx::Tolerance, measure::mass_properties, triangulate::Triangulate,
+    },
+    objects::{HalfEdge, Shell, Solid},
+};
+
+use super::watertightness::find_edge_issues;
+
+pub fn validate_shell(
+    shell: &Shell,
+    tolerance: Scalar,
+) -> Result<(), OrientationIssues> {
+    let inconsistent_edges = find_edge_issues(shell.faces(), tolerance)
+        .inconsistent_edges
+        .into_iter()
+        .map(|[a, _]| a)
+        .collect::<Vec<_>>();
+
+    if !inconsistent_edges.is_empty() {
+        return Err(OrientationIssues {
+            inconsistent_edges,
+            inside_out: false,
+        });
+    }
+
+    Ok(())
+}
+
+pub fn validate_solid(
+    solid: &Solid,
+    tolerance: Tolerance,
+) -> Result<(), OrientationIssues> {
+    // The shells of cavities face inward, so their volume is negative. Only the
+    // volume of the whole solid tells whether it is inside out.
+    let mesh: Mesh<Point<3>> = (solid, tolerance).triangulate();
+    if mass_properties(&mesh).volume < Scalar::ZERO {
+        return Err(OrientationIssues {
+            inconsistent_edges: Vec::new(),
+            inside_out: true,
+        });
+    }
+
+    Ok(())
+}
+
+/// Orientation issues found during validation
+///
+/// Used by [`ValidationError`].
+///
+/// [`ValidationError`]: super::ValidationError
+#[derive(Debug, Default, thiserror::Error)]
+pub struct OrientationIssues {
+    /// Half-edges that run in the same direction as the other half-edge of
+    /// their edge
+    ///
+    /// The faces on either side of such an edge are oriented inconsistently.
+    pub inconsistent_edges: Vec<HalfEdge>,
+
+    /// Whether the faces of a solid point inward, instead of outward
+    pub inside_out: bool,
+}
+
+impl fmt::Display for OrientationIssues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Faces are not oriented consistently:")?;
+
+        for half_edge in &self.inconsistent_edges {
+            let [a, b] = half_edge
+                .vertices()
+                .clone()
+                .map(|vertex| vertex.global_form().position());
+            writeln!(f, "- Edge from {a:?} to {b:?} runs the same way twice")?;
+        }
+        if self.inside_out {
+            writeln!(f, "- Solid is inside out")?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::{collections::HashSet, fmt};
+use std::fmt;
 
 use fj_math::Scalar;
 
@@ -6,7 +6,7 @@ use crate::objects::GlobalVertex;
 
 pub fn validate_vertex(
     vertex: &GlobalVertex,
-    vertices: &HashSet<GlobalVertex>,
+    vertices: &[GlobalVertex],
     min_distance: Scalar,
 ) -> Result<(), UniquenessIssues> {
     for existing in vertices {
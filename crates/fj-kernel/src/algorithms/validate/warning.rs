@@ -0,0 +1,69 @@
+use std::fmt;
+
+use fj_math::Scalar;
+
+use crate::objects::{Face, HalfEdge};
+
+/// A potential problem found during validation
+///
+/// Unlike a [`ValidationError`], a warning doesn't make an object invalid. It
+/// points out geometry that is valid, but might not turn out as intended, for
+/// example because it can't be approximated accurately.
+///
+/// Returned by [`Validated::warnings`].
+///
+/// [`ValidationError`]: super::ValidationError
+/// [`Validated::warnings`]: super::Validated::warnings
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum ValidationWarning {
+    /// A face is very thin, compared to its length
+    ///
+    /// See [`ValidationConfig::thin_face_ratio`].
+    ///
+    /// [`ValidationConfig::thin_face_ratio`]: super::ValidationConfig::thin_face_ratio
+    ThinFace {
+        /// The thin face
+        face: Box<Face>,
+
+        /// The approximate width of the face
+        width: Scalar,
+
+        /// The approximate length of the face
+        length: Scalar,
+    },
+
+    /// A half-edge is shorter than the tolerance used to approximate it
+    ///
+    /// The approximation of such a half-edge, and of the faces it bounds, can
+    /// deviate significantly from its actual shape.
+    SmallerThanTolerance {
+        /// The short half-edge
+        half_edge: Box<HalfEdge>,
+
+        /// The length of the half-edge
+        length: Scalar,
+
+        /// The tolerance
+        tolerance: Scalar,
+    },
+}
+
+impl fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ThinFace { width, length, .. } => write!(
+                f,
+                "Face is very thin (about {width} wide and {length} long)"
+            ),
+            Self::SmallerThanTolerance {
+                half_edge,
+                length,
+                tolerance,
+            } => write!(
+                f,
+                "Edge is shorter ({length}) than the tolerance ({tolerance}) \
+                ({half_edge})"
+            ),
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use std::{collections::BTreeMap, fmt};
+
+use fj_math::{Point, Scalar};
+
+use crate::objects::{Face, HalfEdge, Shell};
+
+pub fn validate_shell(
+    shell: &Shell,
+    tolerance: Scalar,
+) -> Result<(), WatertightnessIssues> {
+    let EdgeIssues {
+        open_edges,
+        over_shared_edges,
+        ..
+    } = find_edge_issues(shell.faces(), tolerance);
+
+    if !open_edges.is_empty() || !over_shared_edges.is_empty() {
+        return Err(WatertightnessIssues {
+            open_edges,
+            over_shared_edges,
+        });
+    }
+
+    Ok(())
+}
+
+/// Find the edges that keep faces from forming a closed shell
+///
+/// Faces form a closed shell, if each of their edges is shared by exactly two
+/// half-edges, which run in opposite directions. Half-edges share an edge, if
+/// they connect the same vertices, and their midpoints are within `tolerance`
+/// of each other.
+///
+/// This is the single definition of a closed shell, which building, validating,
+/// and sewing shells rely on.
+pub fn find_edge_issues<'r>(
+    faces: impl IntoIterator<Item = &'r Face>,
+    tolerance: Scalar,
+) -> EdgeIssues {
+    let mut issues = EdgeIssues::default();
+
+    for edge in shell_edges(faces, tolerance) {
+        match edge.as_slice() {
+            [half_edge] => issues.open_edges.push(half_edge.half_edge.clone()),
+            [a, b] => {
+                if !a.runs_opposite_to(b, tolerance) {
+                    issues
+                        .inconsistent_edges
+                        .push([a.half_edge.clone(), b.half_edge.clone()]);
+                }
+            }
+            [half_edge, ..] => {
+                issues.over_shared_edges.push(half_edge.half_edge.clone());
+            }
+            [] => unreachable!("Edge without half-edges"),
+        }
+    }
+
+    issues
+}
+
+/// The edges that keep faces from forming a closed shell
+///
+/// Returned by [`find_edge_issues`].
+#[derive(Debug, Default)]
+pub struct EdgeIssues {
+    /// Half-edges that don't have a counterpart
+    pub open_edges: Vec<HalfEdge>,
+
+    /// Half-edges that share their edge with more than one other half-edge
+    pub over_shared_edges: Vec<HalfEdge>,
+
+    /// Pairs of half-edges that run in the same direction along their edge
+    pub inconsistent_edges: Vec<[HalfEdge; 2]>,
+}
+
+impl EdgeIssues {
+    /// Indicate whether the faces form a closed shell
+    pub fn is_empty(&self) -> bool {
+        self.open_edges.is_empty()
+            && self.over_shared_edges.is_empty()
+            && self.inconsistent_edges.is_empty()
+    }
+}
+
+/// Group the half-edges of faces by the edge they belong to
+///
+/// Uses the positions of the global vertices, to determine which vertices the
+/// half-edges connect. See [`group_half_edges`].
+pub fn shell_edges<'r>(
+    faces: impl IntoIterator<Item = &'r Face>,
+    tolerance: Scalar,
+) -> Vec<Vec<ShellHalfEdge<'r>>> {
+    let mut half_edges = Vec::new();
+    for (i, face) in faces.into_iter().enumerate() {
+        for cycle in face.all_cycles() {
+            for half_edge in cycle.half_edges() {
+                let vertices = half_edge
+                    .vertices()
+                    .clone()
+                    .map(|vertex| vertex.global_form().position());
+                half_edges.push((i, half_edge, vertices));
+            }
+        }
+    }
+
+    group_half_edges(half_edges, tolerance)
+}
+
+/// Group half-edges by the edge they belong to
+///
+/// Each half-edge comes with an ID, which is passed through to the result, and
+/// the positions of its vertices. Half-edges belong to the same edge, if their
+/// vertices are at the same positions, and their midpoints are within
+/// `tolerance` of each other. In a closed shell, each group consists of two
+/// half-edges.
+///
+/// Distinct vertices are never closer than the minimum distance, so their
+/// positions are compared exactly. Callers that work with vertices that
+/// haven't been merged yet, need to merge them before calling this function.
+pub fn group_half_edges<'r, I>(
+    half_edges: impl IntoIterator<Item = (I, &'r HalfEdge, [Point<3>; 2])>,
+    tolerance: Scalar,
+) -> Vec<Vec<ShellHalfEdge<'r, I>>> {
+    let mut candidates = BTreeMap::<_, Vec<_>>::new();
+    for (id, half_edge, [a, b]) in half_edges {
+        candidates.entry([a.min(b), a.max(b)]).or_default().push(
+            ShellHalfEdge {
+                id,
+                half_edge,
+                samples: EdgeSamples::new(half_edge, [a, b]),
+            },
+        );
+    }
+
+    let mut edges = Vec::new();
+    for mut candidates in candidates.into_values() {
+        while let Some(half_edge) = candidates.pop() {
+            let (mut edge, rest) =
+                candidates.into_iter().partition::<Vec<_>, _>(|other| {
+                    (other.samples.midpoint() - half_edge.samples.midpoint())
+                        .magnitude()
+                        < tolerance
+                });
+            candidates = rest;
+
+            edge.insert(0, half_edge);
+            edges.push(edge);
+        }
+    }
+
+    edges
+}
+
+/// A half-edge of a shell
+pub struct ShellHalfEdge<'r, I = usize> {
+    /// The ID of the half-edge
+    ///
+    /// For [`shell_edges`], this is the index of the face the half-edge belongs
+    /// to, in the order in which the faces were passed.
+    pub id: I,
+
+    /// The half-edge
+    pub half_edge: &'r HalfEdge,
+
+    samples: EdgeSamples,
+}
+
+impl<I> ShellHalfEdge<'_, I> {
+    /// Determine whether two half-edges of the same edge run in opposite
+    /// directions
+    pub fn runs_opposite_to(&self, other: &Self, tolerance: Scalar) -> bool {
+        self.samples.is_reverse_of(&other.samples, tolerance)
+    }
+}
+
+/// Points along a half-edge, in model space
+///
+/// The points are the vertices of the half-edge, and the points at a quarter,
+/// half, and three quarters of its length. The points at a quarter and at
+/// three quarters tell the direction of closed edges, whose vertices are the
+/// same.
+struct EdgeSamples {
+    points: [Point<3>; 5],
+}
+
+impl EdgeSamples {
+    fn new(half_edge: &HalfEdge, vertices: [Point<3>; 2]) -> Self {
+        let curve = half_edge.curve();
+        let [a, b] =
+            half_edge.vertices().clone().map(|vertex| vertex.position());
+
+        let mut points = [0., 0.25, 0.5, 0.75, 1.].map(|t| {
+            let point_curve = a + (b - a) * t;
+            let point_surface =
+                curve.path().point_from_path_coords(point_curve);
+            curve.surface().point_from_surface_coords(point_surface)
+        });
+
+        // The vertices are compared exactly, so the positions provided by the
+        // caller are used.
+        let [a, b] = vertices;
+        points[0] = a;
+        points[4] = b;
+
+        Self { points }
+    }
+
+    fn midpoint(&self) -> Point<3> {
+        self.points[2]
+    }
+
+    fn is_reverse_of(&self, other: &Self, tolerance: Scalar) -> bool {
+        let mut reversed = other.points;
+        reversed.reverse();
+
+        self.points
+            .iter()
+            .zip(reversed)
+            .all(|(a, b)| (*a - b).magnitude() < tolerance)
+    }
+}
+
+/// Watertightness issues found during validation
+///
+/// Used by [`ValidationError`].
+///
+/// [`ValidationError`]: super::ValidationError
+#[derive(Debug, Default, thiserror::Error)]
+pub struct WatertightnessIssues {
+    /// Half-edges that don't have a counterpart in the same shell
+    pub open_edges: Vec<HalfEdge>,
+
+    /// Half-edges that share their edge with more than one other half-edge
+    pub over_shared_edges: Vec<HalfEdge>,
+}
+
+impl fmt::Display for WatertightnessIssues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Shell is not watertight:")?;
+
+        for (issue, half_edges) in [
+            ("Open edge", &self.open_edges),
+            ("Edge shared by too many faces", &self.over_shared_edges),
+        ] {
+            for half_edge in half_edges {
+                let [a, b] = half_edge
+                    .vertices()
+                    .clone()
+                    .map(|vertex| vertex.global_form().position());
+                writeln!(f, "- {issue} from {a:?} to {b:?}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -2,13 +2,17 @@ use fj_math::Point;
 
 use crate::{
     objects::{Cycle, Face, Objects, Surface},
-    partial::HasPartial,
+    partial::{CycleSegment, HasPartial, PartialBuildError, PartialCycle},
     storage::Handle,
 };
 
 /// API for building a [`Face`]
 ///
 /// Also see [`Face::builder`].
+///
+/// Building the cycles of the [`Face`] can fail. The `with_` methods don't
+/// return those errors directly, to keep them chainable. The first error is
+/// returned from [`FaceBuilder::build`] instead.
 pub struct FaceBuilder<'a> {
     /// The stores that the created objects are put in
     pub objects: &'a Objects,
@@ -24,6 +28,9 @@ pub struct FaceBuilder<'a> {
 
     /// The interior cycles that form holes in the [`Face`]
     pub interiors: Vec<Cycle>,
+
+    /// The first error that occurred while building a cycle
+    pub(crate) error: Option<PartialBuildError>,
 }
 
 impl<'a> FaceBuilder<'a> {
@@ -32,13 +39,10 @@ impl<'a> FaceBuilder<'a> {
         mut self,
         points: impl IntoIterator<Item = impl Into<Point<2>>>,
     ) -> Self {
-        self.exterior = Some(
-            Cycle::partial()
-                .with_surface(Some(self.surface.clone()))
-                .with_poly_chain_from_points(points)
-                .close_with_line_segment()
-                .build(self.objects),
-        );
+        let cycle = self.polygon_from_points(points);
+        if let Some(cycle) = self.record_error(cycle) {
+            self.exterior = Some(cycle);
+        }
         self
     }
 
@@ -47,21 +51,191 @@ impl<'a> FaceBuilder<'a> {
         mut self,
         points: impl IntoIterator<Item = impl Into<Point<2>>>,
     ) -> Self {
-        self.interiors.push(
-            Cycle::partial()
-                .with_surface(Some(self.surface.clone()))
-                .with_poly_chain_from_points(points)
-                .close_with_line_segment()
-                .build(self.objects),
-        );
+        let cycle = self.polygon_from_points(points);
+        if let Some(cycle) = self.record_error(cycle) {
+            self.interiors.push(cycle);
+        }
+        self
+    }
+
+    /// Build the [`Face`] with an exterior cycle from the provided segments
+    ///
+    /// See [`PartialCycle::with_segments`].
+    pub fn with_exterior_from_segments(
+        mut self,
+        segments: impl IntoIterator<Item = CycleSegment>,
+    ) -> Self {
+        let cycle = self.cycle_from_segments(segments);
+        if let Some(cycle) = self.record_error(cycle) {
+            self.exterior = Some(cycle);
+        }
+        self
+    }
+
+    /// Build the [`Face`] with an interior cycle from the provided segments
+    ///
+    /// See [`PartialCycle::with_segments`].
+    pub fn with_interior_from_segments(
+        mut self,
+        segments: impl IntoIterator<Item = CycleSegment>,
+    ) -> Self {
+        let cycle = self.cycle_from_segments(segments);
+        if let Some(cycle) = self.record_error(cycle) {
+            self.interiors.push(cycle);
+        }
         self
     }
 
-    /// Construct a polygon from a list of points
-    pub fn build(self) -> Face {
-        let exterior = self
-            .exterior
-            .expect("Can't build `Face` without exterior cycle");
-        Face::from_exterior(exterior).with_interiors(self.interiors)
+    fn polygon_from_points(
+        &self,
+        points: impl IntoIterator<Item = impl Into<Point<2>>>,
+    ) -> Result<Cycle, PartialBuildError> {
+        Cycle::partial()
+            .with_surface(Some(self.surface.clone()))
+            .with_poly_chain_from_points(points)
+            .and_then(PartialCycle::close_with_line_segment)
+            .and_then(|cycle| cycle.build(self.objects))
+    }
+
+    fn cycle_from_segments(
+        &self,
+        segments: impl IntoIterator<Item = CycleSegment>,
+    ) -> Result<Cycle, PartialBuildError> {
+        Cycle::partial()
+            .with_surface(Some(self.surface.clone()))
+            .with_segments(segments)
+            .and_then(|cycle| cycle.build(self.objects))
+    }
+
+    fn record_error(
+        &mut self,
+        cycle: Result<Cycle, PartialBuildError>,
+    ) -> Option<Cycle> {
+        match cycle {
+            Ok(cycle) => Some(cycle),
+            Err(err) => {
+                self.error.get_or_insert(err);
+                None
+            }
+        }
+    }
+
+    /// Build the [`Face`]
+    ///
+    /// Returns the first error that occurred in one of the `with_` methods.
+    /// Returns an error, if no exterior cycle has been provided.
+    pub fn build(self) -> Result<Face, PartialBuildError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        let exterior = self.exterior.ok_or_else(|| {
+            PartialBuildError::missing_field("Face", "exterior cycle")
+        })?;
+        Ok(Face::from_exterior(exterior).with_interiors(self.interiors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::validate::{Validate, ValidationConfig},
+        objects::{Face, Objects, Surface},
+        partial::{CycleSegment, PartialBuildError},
+    };
+
+    #[test]
+    fn exterior_from_segments() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let face = Face::builder(&objects, surface)
+            .with_exterior_from_segments([
+                CycleSegment::ArcTo {
+                    end: [-1., 0.].into(),
+                    radius: Scalar::ONE,
+                },
+                CycleSegment::BezierTo {
+                    control_points: [[-1., -1.].into(), [1., -1.].into()],
+                    end: [1., 0.].into(),
+                },
+            ])
+            .build()?;
+
+        let half_edges = face.exterior().half_edges().collect::<Vec<_>>();
+        for (a, b) in [(0, 1), (1, 0)] {
+            let [_, end] = half_edges[a].vertices();
+            let [start, _] = half_edges[b].vertices();
+            assert_eq!(
+                end.surface_form().position(),
+                start.surface_form().position()
+            );
+        }
+
+        face.validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn build_without_exterior() {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let result = Face::builder(&objects, surface).build();
+
+        assert!(matches!(
+            result,
+            Err(PartialBuildError::MissingField {
+                object: "Face",
+                field: "exterior cycle"
+            })
+        ));
+    }
+
+    #[test]
+    fn build_with_only_interior() {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let result = Face::builder(&objects, surface)
+            .with_interior_polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(PartialBuildError::MissingField {
+                object: "Face",
+                field: "exterior cycle"
+            })
+        ));
+    }
+
+    #[test]
+    fn build_returns_error_from_cycle() {
+        let objects =
+            Objects::new().with_build_validation(ValidationConfig::default());
+
+        // The edges of a bowtie cross each other, so building its cycle fails
+        // validation. The error is returned from `build`, even though a valid
+        // interior is provided afterwards.
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let result = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([
+                [0., 0.],
+                [2., 2.],
+                [2., 0.],
+                [0., 2.],
+            ])
+            .with_interior_polygon_from_points([
+                [0.5, 0.5],
+                [0.5, 1.],
+                [1., 1.],
+            ])
+            .build();
+
+        assert!(matches!(result, Err(PartialBuildError::Invalid(_))));
     }
 }
@@ -6,6 +6,8 @@ mod sketch;
 mod solid;
 
 pub use self::{
-    face::FaceBuilder, shell::ShellBuilder, sketch::SketchBuilder,
+    face::FaceBuilder,
+    shell::{ShellBuilder, ShellBuilderError},
+    sketch::SketchBuilder,
     solid::SolidBuilder,
 };
@@ -1,12 +1,12 @@
 use fj_math::Scalar;
 
 use crate::{
-    algorithms::transform::TransformObject,
+    algorithms::{transform::TransformObject, validate::find_edge_issues},
     objects::{
         Curve, Cycle, Face, HalfEdge, Objects, Shell, Surface, SurfaceVertex,
         Vertex,
     },
-    partial::HasPartial,
+    partial::{HasPartial, PartialBuildError},
     storage::Handle,
 };
 
@@ -16,14 +16,64 @@ use crate::{
 pub struct ShellBuilder<'a> {
     /// The stores that the created objects are put in
     pub objects: &'a Objects,
+
+    /// The faces that make up the [`Shell`]
+    pub faces: Vec<Face>,
+
+    /// The tolerance used to match the edges of the faces
+    ///
+    /// See [`find_edge_issues`].
+    pub tolerance: Scalar,
 }
 
 impl<'a> ShellBuilder<'a> {
+    /// Build the [`Shell`] with the provided faces
+    pub fn with_faces(
+        mut self,
+        faces: impl IntoIterator<Item = impl Into<Face>>,
+    ) -> Self {
+        self.faces.extend(faces.into_iter().map(Into::into));
+        self
+    }
+
+    /// Match the edges of the faces within the provided tolerance
+    pub fn with_tolerance(mut self, tolerance: impl Into<Scalar>) -> Self {
+        self.tolerance = tolerance.into();
+        self
+    }
+
+    /// Build a [`Shell`] from the faces provided to this builder
+    ///
+    /// Returns an error, if the faces don't form a closed shell. Every edge
+    /// that bounds a face must be shared by exactly two half-edges, and those
+    /// two half-edges must traverse the edge in opposite directions. Otherwise,
+    /// the faces would either leave a gap, or not be wound consistently.
+    ///
+    /// Uses [`find_edge_issues`], the same definition of a closed shell that
+    /// validation relies on.
+    pub fn build(self) -> Result<Shell, ShellBuilderError> {
+        let issues = find_edge_issues(&self.faces, self.tolerance);
+
+        let mut unpaired_edges = issues.open_edges;
+        unpaired_edges.extend(issues.over_shared_edges);
+
+        if !unpaired_edges.is_empty() {
+            return Err(ShellBuilderError::UnpairedEdges(unpaired_edges));
+        }
+        if !issues.inconsistent_edges.is_empty() {
+            return Err(ShellBuilderError::InconsistentWinding(
+                issues.inconsistent_edges,
+            ));
+        }
+
+        Ok(Shell::new().with_faces(self.faces))
+    }
+
     /// Create a cube from the length of its edges
     pub fn build_cube_from_edge_length(
         self,
         edge_length: impl Into<Scalar>,
-    ) -> Shell {
+    ) -> Result<Shell, PartialBuildError> {
         let edge_length = edge_length.into();
 
         // Let's define some short-hands. We're going to need them a lot.
@@ -35,7 +85,7 @@ impl<'a> ShellBuilder<'a> {
                 .objects
                 .surfaces
                 .insert(Surface::xy_plane())
-                .translate([Z, Z, -h], self.objects);
+                .translate([Z, Z, -h], self.objects)?;
 
             Face::builder(self.objects, surface)
                 .with_exterior_polygon_from_points([
@@ -44,7 +94,7 @@ impl<'a> ShellBuilder<'a> {
                     [h, h],
                     [-h, h],
                 ])
-                .build()
+                .build()?
         };
 
         let (sides, top_edges) = {
@@ -73,9 +123,9 @@ impl<'a> ShellBuilder<'a> {
                         .with_surface(Some(surface.clone()))
                         .with_global_form(Some(half_edge.global_form().clone()))
                         .as_line_segment_from_points([[Z, Z], [edge_length, Z]])
-                        .build(self.objects)
+                        .and_then(|half_edge| half_edge.build(self.objects))
                 })
-                .collect::<Vec<_>>();
+                .collect::<Result<Vec<_>, _>>()?;
 
             let sides_up = bottoms
                 .clone()
@@ -95,9 +145,9 @@ impl<'a> ShellBuilder<'a> {
                             Vertex::partial().with_surface_form(Some(to)),
                         ]))
                         .as_line_segment()
-                        .build(self.objects)
+                        .and_then(|half_edge| half_edge.build(self.objects))
                 })
-                .collect::<Vec<_>>();
+                .collect::<Result<Vec<_>, _>>()?;
 
             let sides_down = {
                 let mut sides_up_prev = sides_up.clone();
@@ -132,9 +182,9 @@ impl<'a> ShellBuilder<'a> {
                                 Vertex::partial().with_surface_form(Some(to)),
                             ]))
                             .as_line_segment()
-                            .build(self.objects)
+                            .and_then(|half_edge| half_edge.build(self.objects))
                     })
-                    .collect::<Vec<_>>()
+                    .collect::<Result<Vec<_>, _>>()?
             };
 
             let tops = sides_up
@@ -160,9 +210,9 @@ impl<'a> ShellBuilder<'a> {
                     HalfEdge::partial()
                         .with_vertices(Some([from, to]))
                         .as_line_segment()
-                        .build(self.objects)
+                        .and_then(|half_edge| half_edge.build(self.objects))
                 })
-                .collect::<Vec<_>>();
+                .collect::<Result<Vec<_>, _>>()?;
 
             let sides = bottoms
                 .into_iter()
@@ -174,10 +224,11 @@ impl<'a> ShellBuilder<'a> {
                     let cycle = Cycle::partial()
                         .with_surface(Some(surface))
                         .with_half_edges([bottom, side_up, top, side_down])
-                        .build(self.objects);
+                        .build(self.objects)?;
 
-                    Face::from_exterior(cycle)
-                });
+                    Ok(Face::from_exterior(cycle))
+                })
+                .collect::<Result<Vec<_>, PartialBuildError>>()?;
 
             (sides, tops)
         };
@@ -187,7 +238,7 @@ impl<'a> ShellBuilder<'a> {
                 .objects
                 .surfaces
                 .insert(Surface::xy_plane())
-                .translate([Z, Z, h], self.objects);
+                .translate([Z, Z, h], self.objects)?;
 
             let points = [[-h, -h], [-h, h], [h, h], [h, -h], [-h, -h]];
 
@@ -211,8 +262,7 @@ impl<'a> ShellBuilder<'a> {
                             .with_surface(Some(surface.clone()))
                             .with_global_form(Some(
                                 vertex.global_form().clone(),
-                            ))
-                            .build(self.objects);
+                            ));
                         Vertex::partial()
                             .with_position(Some(vertex.position()))
                             .with_surface_form(Some(surface_form))
@@ -223,8 +273,8 @@ impl<'a> ShellBuilder<'a> {
                     HalfEdge::partial()
                         .with_vertices(Some(vertices))
                         .with_global_form(Some(edge.global_form().clone()))
-                        .as_line_segment()
-                        .build(self.objects),
+                        .as_line_segment()?
+                        .build(self.objects)?,
                 );
             }
 
@@ -236,6 +286,93 @@ impl<'a> ShellBuilder<'a> {
         faces.extend(sides);
         faces.push(top);
 
-        Shell::new().with_faces(faces)
+        Ok(Shell::new().with_faces(faces))
+    }
+}
+
+/// Error building a [`Shell`] using [`ShellBuilder::build`]
+#[derive(Debug, thiserror::Error)]
+pub enum ShellBuilderError {
+    /// Half-edges whose edge is not shared by exactly two half-edges
+    ///
+    /// Contains one half-edge for each such edge.
+    #[error("Faces don't form a closed shell; unpaired edges: {0:#?}")]
+    UnpairedEdges(Vec<HalfEdge>),
+
+    /// Pairs of half-edges that traverse their shared edge in the same
+    /// direction
+    #[error("Faces of shell are not wound consistently: {0:#?}")]
+    InconsistentWinding(Vec<[HalfEdge; 2]>),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::{reverse::Reverse, sweep::Sweep},
+        objects::{Face, Objects, Shell, Surface},
+    };
+
+    use super::ShellBuilderError;
+
+    #[test]
+    fn build_from_closed_faces() {
+        let objects = Objects::new();
+
+        let faces = triangular_prism(&objects);
+        let shell = Shell::builder(&objects).with_faces(faces).build();
+
+        assert!(shell.is_ok());
+    }
+
+    #[test]
+    fn build_from_faces_with_gap() {
+        let objects = Objects::new();
+
+        let mut faces = triangular_prism(&objects);
+        faces.pop();
+
+        let shell = Shell::builder(&objects).with_faces(faces).build();
+
+        assert!(matches!(
+            shell,
+            Err(ShellBuilderError::UnpairedEdges(edges)) if edges.len() == 3
+        ));
+    }
+
+    #[test]
+    fn build_from_inconsistently_wound_faces() {
+        let objects = Objects::new();
+
+        let mut faces = triangular_prism(&objects);
+        faces[0] = faces[0].clone().reverse();
+
+        let shell = Shell::builder(&objects).with_faces(faces).build();
+
+        assert!(matches!(
+            shell,
+            Err(ShellBuilderError::InconsistentWinding(edges))
+                if edges.len() == 4
+        ));
+    }
+
+    fn triangular_prism(objects: &Objects) -> Vec<Face> {
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let face = Face::builder(objects, surface)
+            .with_exterior_polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])
+            .build()
+            .expect("Failed to build triangle");
+
+        let mut faces = face
+            .sweep([0., 0., 1.], objects)
+            .into_faces()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        // Make sure the face we're going to remove in one of the tests is the
+        // top or bottom face, which has three edges.
+        faces.sort_by_key(|face| face.exterior().half_edges().count());
+        faces.reverse();
+
+        faces
     }
 }
@@ -2,6 +2,7 @@ use fj_math::Point;
 
 use crate::{
     objects::{Face, Objects, Sketch, Surface},
+    partial::PartialBuildError,
     storage::Handle,
 };
 
@@ -21,10 +22,10 @@ impl<'a> SketchBuilder<'a> {
     pub fn build_polygon_from_points(
         self,
         points: impl IntoIterator<Item = impl Into<Point<2>>>,
-    ) -> Sketch {
+    ) -> Result<Sketch, PartialBuildError> {
         let face = Face::builder(self.objects, self.surface)
             .with_exterior_polygon_from_points(points)
-            .build();
-        Sketch::new().with_faces([face])
+            .build()?;
+        Ok(Sketch::new().with_faces([face]))
     }
 }
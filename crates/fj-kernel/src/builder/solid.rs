@@ -1,6 +1,9 @@
 use fj_math::Scalar;
 
-use crate::objects::{Objects, Shell, Solid};
+use crate::{
+    objects::{Objects, Shell, Solid},
+    partial::PartialBuildError,
+};
 
 /// API for building a [`Solid`]
 ///
@@ -15,9 +18,9 @@ impl<'a> SolidBuilder<'a> {
     pub fn build_cube_from_edge_length(
         self,
         edge_length: impl Into<Scalar>,
-    ) -> Solid {
+    ) -> Result<Solid, PartialBuildError> {
         let shell = Shell::builder(self.objects)
-            .build_cube_from_edge_length(edge_length);
-        Solid::new().with_shells([shell])
+            .build_cube_from_edge_length(edge_length)?;
+        Ok(Solid::new().with_shells([shell]))
     }
 }
@@ -0,0 +1,212 @@
+//! Human-readable dumps of the topology of a shape
+//!
+//! Use [`debug_dump`] to get an overview of a shape, while debugging the kernel,
+//! or to include one in a bug report.
+//!
+//! Objects that are managed in the centralized object storage are identified by
+//! their [`ObjectId`]. Objects that are shared between multiple parts of the
+//! shape show up once for each part, but with the same ID.
+//!
+//! [`ObjectId`]: crate::storage::ObjectId
+
+use std::fmt::Write;
+
+use crate::objects::{
+    Cycle, Face, Faces, HalfEdge, Shell, Sketch, Solid, Vertex,
+};
+
+/// Dump the topology of a shape
+///
+/// Returns the topology as a tree of objects, starting at `shape`, in the
+/// given format.
+pub fn debug_dump(shape: &impl Dump, format: DumpFormat) -> String {
+    let node = shape.dump();
+
+    let mut output = String::new();
+    match format {
+        DumpFormat::Tree => write_tree(&node, 0, &mut output),
+        DumpFormat::Dot => {
+            output.push_str("digraph {\n");
+            write_dot(&node, &mut 0, &mut output);
+            output.push_str("}\n");
+        }
+    }
+
+    output
+}
+
+/// The format of a dump
+///
+/// See [`debug_dump`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DumpFormat {
+    /// An indented tree of objects, one object per line
+    Tree,
+
+    /// A directed graph in the Graphviz DOT language
+    ///
+    /// Can be rendered using `dot -Tsvg dump.dot > dump.svg`, for example.
+    Dot,
+}
+
+/// Implemented for objects that can be dumped
+///
+/// See [`debug_dump`].
+pub trait Dump {
+    /// Dump this object and all objects it references
+    fn dump(&self) -> DumpNode;
+}
+
+/// An object in a dump, along with the objects it references
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DumpNode {
+    label: String,
+    children: Vec<DumpNode>,
+}
+
+impl DumpNode {
+    fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            children: Vec::new(),
+        }
+    }
+
+    fn with_children(
+        mut self,
+        children: impl IntoIterator<Item = DumpNode>,
+    ) -> Self {
+        self.children.extend(children);
+        self
+    }
+}
+
+impl Dump for Solid {
+    fn dump(&self) -> DumpNode {
+        DumpNode::new("Solid").with_children(self.shells().map(Dump::dump))
+    }
+}
+
+impl Dump for Shell {
+    fn dump(&self) -> DumpNode {
+        DumpNode::new("Shell").with_children(dump_faces(self.faces()))
+    }
+}
+
+impl Dump for Sketch {
+    fn dump(&self) -> DumpNode {
+        DumpNode::new("Sketch").with_children(dump_faces(self.faces()))
+    }
+}
+
+impl Dump for Face {
+    fn dump(&self) -> DumpNode {
+        let [r, g, b, a] = self.color().0;
+
+        DumpNode::new(format!(
+            "Face (surface: {:?}, color: [{r}, {g}, {b}, {a}])",
+            self.surface().id()
+        ))
+        .with_children(self.all_cycles().map(Dump::dump))
+    }
+}
+
+impl Dump for Cycle {
+    fn dump(&self) -> DumpNode {
+        // Don't include the winding here. Computing it panics for some invalid
+        // cycles, and a dump is most useful for debugging invalid shapes.
+        DumpNode::new(format!("Cycle (surface: {:?})", self.surface().id()))
+            .with_children(self.half_edges().map(Dump::dump))
+    }
+}
+
+impl Dump for HalfEdge {
+    fn dump(&self) -> DumpNode {
+        DumpNode::new(format!(
+            "HalfEdge (curve: {:?}, global curve: {:?})",
+            self.curve().id(),
+            self.global_form().curve().id()
+        ))
+        .with_children(self.vertices().iter().map(Dump::dump))
+    }
+}
+
+impl Dump for Vertex {
+    fn dump(&self) -> DumpNode {
+        let global_form = self.global_form();
+
+        DumpNode::new(format!(
+            "Vertex (curve: {:?}, surface: {:?}, global: {:?} at {:?})",
+            self.position(),
+            self.surface_form().position(),
+            global_form.id(),
+            global_form.position(),
+        ))
+    }
+}
+
+fn dump_faces(faces: &Faces) -> impl Iterator<Item = DumpNode> + '_ {
+    faces.into_iter().map(Dump::dump)
+}
+
+fn write_tree(node: &DumpNode, depth: usize, output: &mut String) {
+    let indent = "  ".repeat(depth);
+    // Writing into a `String` never fails.
+    let _ = writeln!(output, "{indent}{}", node.label);
+
+    for child in &node.children {
+        write_tree(child, depth + 1, output);
+    }
+}
+
+/// Write the node and its children, returning the index of the node
+fn write_dot(
+    node: &DumpNode,
+    next_index: &mut usize,
+    output: &mut String,
+) -> usize {
+    let index = *next_index;
+    *next_index += 1;
+
+    let label = node.label.replace('"', "\\\"");
+    let _ = writeln!(output, "    n{index} [label=\"{label}\"];");
+
+    for child in &node.children {
+        let child = write_dot(child, next_index, output);
+        let _ = writeln!(output, "    n{index} -> n{child};");
+    }
+
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::{Face, Objects, Surface};
+
+    use super::{debug_dump, DumpFormat};
+
+    #[test]
+    fn debug_dump_face() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let face = Face::builder(&objects, surface)
+            .with_exterior_polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])
+            .build()?;
+
+        // One face, one cycle, three half-edges, and six vertices.
+        let tree = debug_dump(&face, DumpFormat::Tree);
+        assert_eq!(tree.lines().count(), 11);
+        assert!(tree.starts_with("Face"));
+        let vertices = tree
+            .lines()
+            .filter(|line| line.trim_start().starts_with("Vertex"));
+        assert_eq!(vertices.count(), 6);
+
+        let dot = debug_dump(&face, DumpFormat::Dot);
+        assert!(dot.starts_with("digraph {"));
+        assert_eq!(dot.matches("->").count(), 10);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,637 @@
+//! The geometry of surfaces
+//!
+//! See [`SurfaceGeometry`].
+//!
+//! # Implementation Note
+//!
+//! Like the types in [`crate::path`], the types in this module are closely
+//! associated with objects (in this case, [`Surface`]), without being objects
+//! themselves.
+//!
+//! [`Surface`]: crate::objects::Surface
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use fj_math::{
+    Circle, Cone, Cylinder, Line, NurbsSurface, Point, Scalar, Sphere, Torus,
+    Transform, Vector,
+};
+
+use crate::path::GlobalPath;
+
+/// The geometry of a surface
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SurfaceGeometry {
+    /// A surface that is defined by sweeping a curve along a vector
+    SweptCurve(SweptCurve),
+
+    /// A surface that is defined by sweeping a curve along another curve
+    SweptPath(SweptPath),
+
+    /// A surface that is defined by straight lines between two curves
+    Ruled(RuledSurface),
+
+    /// A surface that is defined by revolving a curve around an axis
+    Revolution(RevolutionSurface),
+
+    /// A circular cylinder
+    ///
+    /// A cylinder could also be represented as a swept circle. Having a
+    /// dedicated representation makes it possible to compute exact
+    /// intersections with it.
+    Cylinder(Cylinder),
+
+    /// A circular cone
+    Cone(Cone),
+
+    /// A sphere
+    Sphere(Sphere),
+
+    /// A torus
+    Torus(Torus),
+
+    /// A NURBS surface
+    Nurbs(NurbsSurface),
+}
+
+impl SurfaceGeometry {
+    /// Convert a point in surface coordinates to model coordinates
+    pub fn point_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        match self {
+            Self::SweptCurve(swept) => swept.point_from_surface_coords(point),
+            Self::SweptPath(swept) => swept.point_from_surface_coords(point),
+            Self::Ruled(ruled) => ruled.point_from_surface_coords(point),
+            Self::Revolution(revolution) => {
+                revolution.point_from_surface_coords(point)
+            }
+            Self::Cylinder(cylinder) => {
+                cylinder.point_from_cylinder_coords(point)
+            }
+            Self::Cone(cone) => cone.point_from_cone_coords(point),
+            Self::Sphere(sphere) => sphere.point_from_sphere_coords(point),
+            Self::Torus(torus) => torus.point_from_torus_coords(point),
+            Self::Nurbs(nurbs) => nurbs.point_from_nurbs_coords(point),
+        }
+    }
+
+    /// Access the periods of the surface along its u- and v-directions
+    ///
+    /// A surface is periodic in a direction, if the surface coordinates repeat
+    /// after the returned interval along it. The line where one period ends
+    /// and the next one starts is a seam of the surface.
+    ///
+    /// Returns `None` for a direction, if the surface isn't periodic along it.
+    pub fn periods(&self) -> [Option<Scalar>; 2] {
+        match self {
+            Self::SweptCurve(swept) => [swept.u.period(), None],
+            Self::SweptPath(swept) => [swept.u.period(), swept.v.period()],
+            Self::Ruled(ruled) => {
+                let period = ruled
+                    .a
+                    .period()
+                    .filter(|&period| ruled.b.period() == Some(period));
+                [period, None]
+            }
+            Self::Revolution(revolution) => {
+                [Some(Scalar::TAU), revolution.profile.period()]
+            }
+            Self::Cylinder(_) | Self::Cone(_) | Self::Sphere(_) => {
+                [Some(Scalar::TAU), None]
+            }
+            Self::Torus(_) => [Some(Scalar::TAU), Some(Scalar::TAU)],
+            Self::Nurbs(_) => [None, None],
+        }
+    }
+
+    /// Compute the normal at the given surface coordinates
+    ///
+    /// The normal points in the direction of the cross product of the u- and
+    /// v-directions of the surface, and has unit length.
+    ///
+    /// Returns `None`, if the surface is degenerate at the given point, like a
+    /// sphere is at its poles.
+    ///
+    /// # Implementation Note
+    ///
+    /// Like those in [`Self::principal_curvatures_at`], the derivatives of the
+    /// surface are approximated numerically.
+    pub fn normal_at(&self, point: impl Into<Point<2>>) -> Option<Vector<3>> {
+        let point = point.into();
+
+        let h = Scalar::from(1e-6);
+        let p = |du: f64, dv: f64| {
+            self.point_from_surface_coords(Point::from([
+                point.u + h * du,
+                point.v + h * dv,
+            ]))
+        };
+
+        let d_u = p(1., 0.) - p(-1., 0.);
+        let d_v = p(0., 1.) - p(0., -1.);
+
+        // Where the surface is degenerate, one of the derivatives vanishes.
+        // What's left of it is just floating-point noise, which must not be
+        // mistaken for a direction.
+        let [a, b] = [d_u, d_v].map(|d| d.magnitude());
+        if a.min(b) <= a.max(b) * 1e-8 {
+            return None;
+        }
+
+        Some(d_u.cross(&d_v).normalize())
+    }
+
+    /// Compute the principal curvatures at the given surface coordinates
+    ///
+    /// The principal curvatures are the minimum and maximum curvature of all
+    /// curves on the surface that pass through the point, in the order
+    /// `[min, max]`. A curvature is positive, if the surface bends away from
+    /// its normal (see [`Self::offset`] for how the normal is defined), like a
+    /// sphere does, if its normal points outward.
+    ///
+    /// Returns zero for both curvatures, if the surface is degenerate at the
+    /// given point, like a sphere is at its poles.
+    ///
+    /// # Implementation Note
+    ///
+    /// The derivatives of the surface are approximated numerically, which
+    /// limits the accuracy of the result. Computing them exactly for each kind
+    /// of surface would be more accurate, but has not been done yet.
+    pub fn principal_curvatures_at(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> [Scalar; 2] {
+        let point = point.into();
+
+        // This step size is a compromise between the error of the finite
+        // differences and the error of floating-point arithmetic.
+        let h = Scalar::from(1e-4);
+        let p = |du: f64, dv: f64| {
+            self.point_from_surface_coords(Point::from([
+                point.u + h * du,
+                point.v + h * dv,
+            ]))
+            .coords
+        };
+
+        let center = p(0., 0.);
+
+        let d_u = (p(1., 0.) - p(-1., 0.)) / (h * 2.);
+        let d_v = (p(0., 1.) - p(0., -1.)) / (h * 2.);
+        let d_uu = (p(1., 0.) - center * 2. + p(-1., 0.)) / (h * h);
+        let d_vv = (p(0., 1.) - center * 2. + p(0., -1.)) / (h * h);
+        let d_uv =
+            (p(1., 1.) - p(1., -1.) - p(-1., 1.) + p(-1., -1.)) / (h * h * 4.);
+
+        let normal = d_u.cross(&d_v);
+        if normal.magnitude() == Scalar::ZERO {
+            return [Scalar::ZERO; 2];
+        }
+        let normal = normal.normalize();
+
+        // Coefficients of the first fundamental form
+        let e = d_u.dot(&d_u);
+        let f = d_u.dot(&d_v);
+        let g = d_v.dot(&d_v);
+
+        // Coefficients of the second fundamental form. They are negated, to
+        // make the curvature positive, if the surface bends away from the
+        // normal.
+        let l = -d_uu.dot(&normal);
+        let m = -d_uv.dot(&normal);
+        let n = -d_vv.dot(&normal);
+
+        let denominator = e * g - f * f;
+        let gaussian = (l * n - m * m) / denominator;
+        let mean = (e * n - f * m * 2. + g * l) / (denominator * 2.);
+
+        let discriminant = (mean * mean - gaussian).max(Scalar::ZERO).sqrt();
+        [mean - discriminant, mean + discriminant]
+    }
+
+    /// Compute the surface that is offset from this one along its normal
+    ///
+    /// The normal of the surface points in the direction of the cross product
+    /// of its u- and v-directions. A positive `distance` offsets the surface in
+    /// the direction of the normal, a negative one in the opposite direction.
+    ///
+    /// The offset surface has the same coordinate system as this one: The
+    /// point at given surface coordinates on it is the point at the same
+    /// surface coordinates on this surface, moved along the normal.
+    ///
+    /// Returns `None`, if the offset surface can't be represented that way,
+    /// for example because a sphere is offset inward by its radius or more.
+    pub fn offset(&self, distance: impl Into<Scalar>) -> Option<Self> {
+        let distance = distance.into();
+
+        let offset = match self {
+            Self::SweptCurve(SweptCurve {
+                u: GlobalPath::Line(line),
+                v,
+            }) => {
+                let normal = line.direction().cross(v).normalize();
+                let line = Line::from_origin_and_direction(
+                    line.origin() + normal * distance,
+                    line.direction(),
+                );
+
+                Self::SweptCurve(SweptCurve {
+                    u: GlobalPath::Line(line),
+                    v: *v,
+                })
+            }
+            Self::SweptCurve(SweptCurve {
+                u: GlobalPath::Circle(circle),
+                v,
+            }) => {
+                let circle_normal = circle.a().cross(&circle.b());
+                if circle_normal.normalize().cross(&v.normalize()).magnitude()
+                    > Scalar::from(1e-12)
+                {
+                    todo!(
+                        "Offsetting a circle that is swept at an oblique \
+                        angle is not supported yet."
+                    )
+                }
+
+                // Depending on the direction of the sweep, the normal points
+                // away from the center of the circle or towards it.
+                let radius = if circle_normal.dot(v) > Scalar::ZERO {
+                    circle.radius() + distance
+                } else {
+                    circle.radius() - distance
+                };
+                let scale = scale_radius(circle.radius(), radius)?;
+
+                Self::SweptCurve(SweptCurve {
+                    u: GlobalPath::Circle(Circle::new(
+                        circle.center(),
+                        circle.a() * scale,
+                        circle.b() * scale,
+                    )),
+                    v: *v,
+                })
+            }
+            Self::Cylinder(cylinder) => {
+                let scale = scale_radius(
+                    cylinder.radius(),
+                    cylinder.radius() + distance,
+                )?;
+
+                Self::Cylinder(Cylinder::new(
+                    cylinder.origin(),
+                    cylinder.a() * scale,
+                    cylinder.b() * scale,
+                    cylinder.axis(),
+                ))
+            }
+            Self::Cone(cone) => {
+                // The normal is tilted against the axis, so offsetting the
+                // cone moves it along the axis, in addition to changing its
+                // radius.
+                let length = cone.axis().magnitude();
+                let hypotenuse =
+                    (length * length + cone.slope() * cone.slope()).sqrt();
+
+                let radius =
+                    cone.base_radius() + distance * length / hypotenuse;
+                let scale = scale_radius(cone.base_radius(), radius)?;
+                let shift = cone.axis() / length
+                    * (-distance * cone.slope() / hypotenuse);
+
+                Self::Cone(Cone::new(
+                    cone.origin() + shift,
+                    cone.a() * scale,
+                    cone.b() * scale,
+                    cone.axis(),
+                    cone.slope(),
+                ))
+            }
+            Self::Sphere(sphere) => {
+                let scale =
+                    scale_radius(sphere.radius(), sphere.radius() + distance)?;
+
+                Self::Sphere(Sphere::new(
+                    sphere.center(),
+                    sphere.a() * scale,
+                    sphere.b() * scale,
+                    sphere.c() * scale,
+                ))
+            }
+            Self::Torus(torus) => {
+                let radius = torus.minor_radius() + distance;
+                let scale = scale_radius(torus.minor_radius(), radius)?;
+                if radius >= torus.major_radius() {
+                    return None;
+                }
+
+                Self::Torus(Torus::new(
+                    torus.center(),
+                    torus.axis(),
+                    torus.a(),
+                    torus.tube_a() * scale,
+                    torus.tube_b() * scale,
+                ))
+            }
+            _ => todo!(
+                "Offsetting this kind of surface is not supported yet: \
+                {self:?}"
+            ),
+        };
+
+        Some(offset)
+    }
+
+    /// Convert a vector in surface coordinates to model coordinates
+    pub fn vector_from_surface_coords(
+        &self,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        match self {
+            Self::SweptCurve(swept) => swept.vector_from_surface_coords(vector),
+            Self::SweptPath(swept) => swept.vector_from_surface_coords(vector),
+            Self::Ruled(ruled) => ruled.vector_from_surface_coords(vector),
+            Self::Revolution(revolution) => {
+                revolution.vector_from_surface_coords(vector)
+            }
+            Self::Cylinder(cylinder) => {
+                cylinder.vector_from_cylinder_coords(vector)
+            }
+            Self::Cone(cone) => cone.vector_from_cone_coords(vector),
+            Self::Sphere(sphere) => sphere.vector_from_sphere_coords(vector),
+            Self::Torus(torus) => torus.vector_from_torus_coords(vector),
+            Self::Nurbs(nurbs) => nurbs.vector_from_nurbs_coords(vector),
+        }
+    }
+}
+
+impl approx::AbsDiffEq for SurfaceGeometry {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        match (self, other) {
+            (Self::SweptCurve(a), Self::SweptCurve(b)) => {
+                a.abs_diff_eq(b, epsilon)
+            }
+            (Self::SweptPath(a), Self::SweptPath(b)) => {
+                a.abs_diff_eq(b, epsilon)
+            }
+            (Self::Ruled(a), Self::Ruled(b)) => a.abs_diff_eq(b, epsilon),
+            (Self::Revolution(a), Self::Revolution(b)) => {
+                a.abs_diff_eq(b, epsilon)
+            }
+            (Self::Cylinder(a), Self::Cylinder(b)) => a.abs_diff_eq(b, epsilon),
+            (Self::Cone(a), Self::Cone(b)) => a.abs_diff_eq(b, epsilon),
+            (Self::Sphere(a), Self::Sphere(b)) => a.abs_diff_eq(b, epsilon),
+            (Self::Torus(a), Self::Torus(b)) => a.abs_diff_eq(b, epsilon),
+            (Self::Nurbs(a), Self::Nurbs(b)) => a.abs_diff_eq(b, epsilon),
+            _ => false,
+        }
+    }
+}
+
+/// Compute the factor that scales a radius to a new one
+///
+/// Returns `None`, if the new radius is not positive.
+fn scale_radius(radius: Scalar, new_radius: Scalar) -> Option<Scalar> {
+    if new_radius <= Scalar::ZERO {
+        return None;
+    }
+
+    Some(new_radius / radius)
+}
+
+/// A surface that is defined by sweeping a curve along a vector
+///
+/// The curve defines the u-coordinate of the surface, the vector its
+/// v-coordinate. Planes are swept lines.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SweptCurve {
+    /// The path that defines the u-coordinate of the surface
+    pub u: GlobalPath,
+
+    /// The vector that defines the v-coordinate of the surface
+    pub v: Vector<3>,
+}
+
+impl SweptCurve {
+    /// Convert a point in surface coordinates to model coordinates
+    pub fn point_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let point = point.into();
+        self.u.point_from_path_coords([point.u])
+            + self.path_to_line().vector_from_line_coords([point.v])
+    }
+
+    /// Convert a vector in surface coordinates to model coordinates
+    pub fn vector_from_surface_coords(
+        &self,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let vector = vector.into();
+        self.u.vector_from_path_coords([vector.u])
+            + self.path_to_line().vector_from_line_coords([vector.v])
+    }
+
+    fn path_to_line(&self) -> Line<3> {
+        Line::from_origin_and_direction(self.u.origin(), self.v)
+    }
+}
+
+impl approx::AbsDiffEq for SweptCurve {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.u.abs_diff_eq(&other.u, epsilon)
+            && self.v.abs_diff_eq(&other.v, epsilon)
+    }
+}
+
+/// A surface that is defined by sweeping a curve along another curve
+///
+/// The curve that is swept defines the u-coordinate of the surface, the curve
+/// that it is swept along its v-coordinate. The swept curve is translated
+/// along the path, without changing its orientation, meaning it starts out at
+/// the origin of the path. If `v` is a line, this is the same surface as the
+/// respective [`SweptCurve`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SweptPath {
+    /// The path that defines the u-coordinate of the surface
+    pub u: GlobalPath,
+
+    /// The path that defines the v-coordinate of the surface
+    pub v: GlobalPath,
+}
+
+impl SweptPath {
+    /// Convert a point in surface coordinates to model coordinates
+    pub fn point_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let point = point.into();
+        self.u.point_from_path_coords([point.u])
+            + (self.v.point_from_path_coords([point.v]) - self.v.origin())
+    }
+
+    /// Convert a vector in surface coordinates to model coordinates
+    pub fn vector_from_surface_coords(
+        &self,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let vector = vector.into();
+        self.u.vector_from_path_coords([vector.u])
+            + self.v.vector_from_path_coords([vector.v])
+    }
+}
+
+impl approx::AbsDiffEq for SweptPath {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.u.abs_diff_eq(&other.u, epsilon)
+            && self.v.abs_diff_eq(&other.v, epsilon)
+    }
+}
+
+/// A surface that is defined by straight lines between two curves
+///
+/// The u-coordinate is the coordinate of both curves, which are connected by a
+/// straight line for each u-coordinate. The v-coordinate runs along those
+/// lines, from `0.` on curve `a` to `1.` on curve `b`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RuledSurface {
+    /// The curve at v-coordinate `0.`
+    pub a: GlobalPath,
+
+    /// The curve at v-coordinate `1.`
+    pub b: GlobalPath,
+}
+
+impl RuledSurface {
+    /// Convert a point in surface coordinates to model coordinates
+    pub fn point_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let point = point.into();
+
+        let a = self.a.point_from_path_coords([point.u]);
+        let b = self.b.point_from_path_coords([point.u]);
+
+        a + (b - a) * point.v
+    }
+
+    /// Convert a vector in surface coordinates to model coordinates
+    pub fn vector_from_surface_coords(
+        &self,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let vector = vector.into();
+
+        let a = self.a.vector_from_path_coords([vector.u]);
+        let b = self.b.vector_from_path_coords([vector.u]);
+
+        a + (b - a) * vector.v
+    }
+}
+
+impl approx::AbsDiffEq for RuledSurface {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+    }
+}
+
+/// A surface that is defined by revolving a curve around an axis
+///
+/// The u-coordinate is the angle of the revolution in radians, following the
+/// right-hand rule around the direction of the axis. The v-coordinate is the
+/// coordinate of the profile curve, which is located at u-coordinate `0.`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RevolutionSurface {
+    /// The curve that is revolved around the axis
+    pub profile: GlobalPath,
+
+    /// The axis that the curve is revolved around
+    pub axis: Line<3>,
+}
+
+impl RevolutionSurface {
+    /// Convert a point in surface coordinates to model coordinates
+    pub fn point_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        self.axis.origin()
+            + self.vector_from_surface_coords(point.into().coords)
+    }
+
+    /// Convert a vector in surface coordinates to model coordinates
+    ///
+    /// The result is the vector from the origin of the axis to the point at
+    /// the given surface coordinates.
+    pub fn vector_from_surface_coords(
+        &self,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let vector = vector.into();
+
+        let profile = self.profile.point_from_path_coords([vector.v])
+            - self.axis.origin();
+        let rotation =
+            Transform::rotation(self.axis.direction().normalize() * vector.u);
+
+        rotation.transform_vector(&profile)
+    }
+
+    /// Compute the distance of the profile curve from the axis
+    pub fn radius_at(&self, v: impl Into<Scalar>) -> Scalar {
+        let offset = self.profile.point_from_path_coords([v.into()])
+            - self.axis.origin();
+        let direction = self.axis.direction().normalize();
+
+        (offset - direction * offset.dot(&direction)).magnitude()
+    }
+}
+
+impl approx::AbsDiffEq for RevolutionSurface {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.profile.abs_diff_eq(&other.profile, epsilon)
+            && self.axis.abs_diff_eq(&other.axis, epsilon)
+    }
+}
@@ -371,14 +371,14 @@ mod tests {
     use super::ObjectIters as _;
 
     #[test]
-    fn curve() {
+    fn curve() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let object = Handle::<Curve>::partial()
             .with_surface(Some(surface))
             .as_u_axis()
-            .build(&objects);
+            .build(&objects)?;
 
         assert_eq!(1, object.curve_iter().count());
         assert_eq!(0, object.cycle_iter().count());
@@ -391,18 +391,20 @@ mod tests {
         assert_eq!(0, object.solid_iter().count());
         assert_eq!(0, object.surface_iter().count());
         assert_eq!(0, object.vertex_iter().count());
+
+        Ok(())
     }
 
     #[test]
-    fn cycle() {
+    fn cycle() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let object = Cycle::partial()
             .with_surface(Some(surface))
-            .with_poly_chain_from_points([[0., 0.], [1., 0.], [0., 1.]])
-            .close_with_line_segment()
-            .build(&objects);
+            .with_poly_chain_from_points([[0., 0.], [1., 0.], [0., 1.]])?
+            .close_with_line_segment()?
+            .build(&objects)?;
 
         assert_eq!(3, object.curve_iter().count());
         assert_eq!(1, object.cycle_iter().count());
@@ -415,16 +417,18 @@ mod tests {
         assert_eq!(0, object.solid_iter().count());
         assert_eq!(0, object.surface_iter().count());
         assert_eq!(6, object.vertex_iter().count());
+
+        Ok(())
     }
 
     #[test]
-    fn face() {
+    fn face() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let object = Face::builder(&objects, surface)
             .with_exterior_polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])
-            .build();
+            .build()?;
 
         assert_eq!(3, object.curve_iter().count());
         assert_eq!(1, object.cycle_iter().count());
@@ -437,6 +441,8 @@ mod tests {
         assert_eq!(0, object.solid_iter().count());
         assert_eq!(1, object.surface_iter().count());
         assert_eq!(6, object.vertex_iter().count());
+
+        Ok(())
     }
 
     #[test]
@@ -478,13 +484,13 @@ mod tests {
     }
 
     #[test]
-    fn half_edge() {
+    fn half_edge() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let object = HalfEdge::partial()
             .with_surface(Some(objects.surfaces.insert(Surface::xy_plane())))
-            .as_line_segment_from_points([[0., 0.], [1., 0.]])
-            .build(&objects);
+            .as_line_segment_from_points([[0., 0.], [1., 0.]])?
+            .build(&objects)?;
 
         assert_eq!(1, object.curve_iter().count());
         assert_eq!(0, object.cycle_iter().count());
@@ -497,13 +503,16 @@ mod tests {
         assert_eq!(0, object.solid_iter().count());
         assert_eq!(0, object.surface_iter().count());
         assert_eq!(2, object.vertex_iter().count());
+
+        Ok(())
     }
 
     #[test]
-    fn shell() {
+    fn shell() -> anyhow::Result<()> {
         let objects = Objects::new();
 
-        let object = Shell::builder(&objects).build_cube_from_edge_length(1.);
+        let object =
+            Shell::builder(&objects).build_cube_from_edge_length(1.)?;
 
         assert_eq!(24, object.curve_iter().count());
         assert_eq!(6, object.cycle_iter().count());
@@ -516,16 +525,18 @@ mod tests {
         assert_eq!(0, object.solid_iter().count());
         assert_eq!(6, object.surface_iter().count());
         assert_eq!(48, object.vertex_iter().count());
+
+        Ok(())
     }
 
     #[test]
-    fn sketch() {
+    fn sketch() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let face = Face::builder(&objects, surface)
             .with_exterior_polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])
-            .build();
+            .build()?;
         let object = Sketch::new().with_faces([face]);
 
         assert_eq!(3, object.curve_iter().count());
@@ -539,13 +550,16 @@ mod tests {
         assert_eq!(0, object.solid_iter().count());
         assert_eq!(1, object.surface_iter().count());
         assert_eq!(6, object.vertex_iter().count());
+
+        Ok(())
     }
 
     #[test]
-    fn solid() {
+    fn solid() -> anyhow::Result<()> {
         let objects = Objects::new();
 
-        let object = Solid::builder(&objects).build_cube_from_edge_length(1.);
+        let object =
+            Solid::builder(&objects).build_cube_from_edge_length(1.)?;
 
         assert_eq!(24, object.curve_iter().count());
         assert_eq!(6, object.cycle_iter().count());
@@ -558,6 +572,8 @@ mod tests {
         assert_eq!(1, object.solid_iter().count());
         assert_eq!(6, object.surface_iter().count());
         assert_eq!(48, object.vertex_iter().count());
+
+        Ok(())
     }
 
     #[test]
@@ -580,14 +596,14 @@ mod tests {
     }
 
     #[test]
-    fn vertex() {
+    fn vertex() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
         let curve = Handle::<Curve>::partial()
             .with_surface(Some(surface.clone()))
             .as_u_axis()
-            .build(&objects);
+            .build(&objects)?;
         let global_vertex = GlobalVertex::from_position([0., 0., 0.], &objects);
         let surface_vertex =
             SurfaceVertex::new([0., 0.], surface, global_vertex);
@@ -604,5 +620,7 @@ mod tests {
         assert_eq!(0, object.solid_iter().count());
         assert_eq!(0, object.surface_iter().count());
         assert_eq!(1, object.vertex_iter().count());
+
+        Ok(())
     }
 }
@@ -89,8 +89,11 @@
 
 pub mod algorithms;
 pub mod builder;
+pub mod dump;
+pub mod geometry;
 pub mod iter;
 pub mod objects;
 pub mod partial;
 pub mod path;
+pub mod query;
 pub mod storage;
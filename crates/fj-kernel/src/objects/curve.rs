@@ -1,3 +1,8 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use fj_math::{Point, Scalar};
+
 use crate::{
     path::SurfacePath,
     storage::{Handle, HandleWrapper},
@@ -7,6 +12,7 @@ use super::{Objects, Surface};
 
 /// A curve, defined in local surface coordinates
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Curve {
     path: SurfacePath,
     surface: Handle<Surface>,
@@ -29,8 +35,8 @@ impl Curve {
     }
 
     /// Access the path that defines this curve
-    pub fn path(&self) -> SurfacePath {
-        self.path
+    pub fn path(&self) -> &SurfacePath {
+        &self.path
     }
 
     /// Access the surface that this curve is defined in
@@ -38,14 +44,154 @@ impl Curve {
         &self.surface
     }
 
+    /// Compute the curvature of this curve at the given point
+    ///
+    /// The curvature is computed in surface coordinates. See
+    /// [`SurfacePath::curvature_at`] for details.
+    pub fn curvature_at(&self, point: impl Into<Point<1>>) -> Scalar {
+        self.path.curvature_at(point)
+    }
+
+    /// Access the period of this curve, if it is periodic
+    ///
+    /// See [`SurfacePath::period`] for details.
+    pub fn period(&self) -> Option<Scalar> {
+        self.path.period()
+    }
+
     /// Access the global form of this curve
     pub fn global_form(&self) -> &Handle<GlobalCurve> {
         &self.global_form
     }
 }
 
+/// Global curves don't carry any geometry, so global forms are compared by
+/// identity
+impl approx::AbsDiffEq for Curve {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.path.abs_diff_eq(&other.path, epsilon)
+            && self.surface.abs_diff_eq(&other.surface, epsilon)
+            && self.global_form == other.global_form
+    }
+}
+
+/// A curve that is bounded by an interval of curve coordinates
+///
+/// [`Curve`] itself is unbounded. `TrimmedCurve` stores the interval that
+/// bounds it explicitly, so code that works with a bounded piece of a curve
+/// (like approximation or intersection) doesn't have to derive the bounds from
+/// elsewhere.
+///
+/// The interval is directed: The curve starts at the first point of the
+/// boundary and ends at the second one.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrimmedCurve {
+    curve: Handle<Curve>,
+    boundary: [Point<1>; 2],
+}
+
+impl TrimmedCurve {
+    /// Construct a new instance of `TrimmedCurve`
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the points of the boundary are coincident.
+    pub fn new(
+        curve: Handle<Curve>,
+        boundary: [impl Into<Point<1>>; 2],
+    ) -> Self {
+        let boundary = boundary.map(Into::into);
+
+        let [a, b] = boundary;
+        assert_ne!(a, b, "Boundary of trimmed curve must not be coincident");
+
+        Self { curve, boundary }
+    }
+
+    /// Access the curve that is being trimmed
+    pub fn curve(&self) -> &Handle<Curve> {
+        &self.curve
+    }
+
+    /// Access the boundary of the curve, in curve coordinates
+    pub fn boundary(&self) -> [Point<1>; 2] {
+        self.boundary
+    }
+
+    /// Access the boundary of the curve, in surface coordinates
+    pub fn boundary_on_surface(&self) -> [Point<2>; 2] {
+        let path = self.curve.path();
+        self.boundary
+            .map(|point| path.point_from_path_coords(point))
+    }
+
+    /// Compute the length of the interval, in curve coordinates
+    pub fn length(&self) -> Scalar {
+        let [a, b] = self.boundary;
+        (b.t - a.t).abs()
+    }
+
+    /// Indicate whether the trimmed curve crosses the seam of its curve
+    ///
+    /// Always returns `false`, if the curve is not periodic.
+    pub fn crosses_seam(&self) -> bool {
+        let period = match self.curve.period() {
+            Some(period) => period,
+            None => return false,
+        };
+
+        let [a, b] = self.boundary.map(|point| point.t);
+        let (min, max) = if a < b { (a, b) } else { (b, a) };
+
+        // The seam is located at every multiple of the period. We're crossing
+        // it, if there is such a multiple strictly between the boundaries.
+        let next_seam = ((min / period).floor() + Scalar::ONE) * period;
+        next_seam < max
+    }
+
+    /// Indicate whether the given point lies within the boundary
+    ///
+    /// The boundary itself is considered to be part of the trimmed curve. If
+    /// the curve is periodic, all points that are equivalent to the given one
+    /// (meaning they are a multiple of the period apart) are considered.
+    pub fn contains(&self, point: impl Into<Point<1>>) -> bool {
+        let mut t = point.into().t;
+
+        let [a, b] = self.boundary.map(|point| point.t);
+        let (min, max) = if a < b { (a, b) } else { (b, a) };
+
+        if let Some(period) = self.curve.period() {
+            // Move the point into the period that starts at the lower
+            // boundary.
+            t = min + (t - min) % period;
+            if t < min {
+                t += period;
+            }
+        }
+
+        min <= t && t <= max
+    }
+
+    /// Reverse the direction of the trimmed curve
+    pub fn reverse(self) -> Self {
+        let [a, b] = self.boundary;
+        Self {
+            curve: self.curve,
+            boundary: [b, a],
+        }
+    }
+}
+
 /// A curve, defined in global (3D) coordinates
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GlobalCurve;
 
 impl GlobalCurve {
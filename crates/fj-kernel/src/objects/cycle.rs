@@ -1,4 +1,7 @@
-use fj_math::{Scalar, Winding};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use fj_math::{Point, Scalar, Winding};
 use pretty_assertions::assert_eq;
 
 use crate::{path::SurfacePath, storage::Handle};
@@ -7,6 +10,7 @@ use super::{HalfEdge, Surface};
 
 /// A cycle of connected half-edges
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Cycle {
     surface: Handle<Surface>,
     half_edges: Vec<HalfEdge>,
@@ -91,9 +95,9 @@ impl Cycle {
     /// two possible windings, depending on the direction you look at the
     /// surface that the cycle is defined on from.
     pub fn winding(&self) -> Winding {
-        // The cycle could be made up of one or two circles. If that is the
+        // The cycle could be made up of one or two curved edges. If that is the
         // case, the winding of the cycle is determined by the winding of the
-        // first circle.
+        // first one.
         if self.half_edges.len() < 3 {
             let first = self
                 .half_edges()
@@ -103,13 +107,36 @@ impl Cycle {
             let [a, b] = first.vertices();
             let edge_direction_positive = a.position() < b.position();
 
-            let circle = match first.curve().path() {
-                SurfacePath::Circle(circle) => circle,
+            let cross_positive = match first.curve().path() {
+                SurfacePath::Circle(circle) => {
+                    circle.a().cross2d(&circle.b()) > Scalar::ZERO
+                }
+                SurfacePath::Ellipse(ellipse) => {
+                    ellipse.a().cross2d(&ellipse.b()) > Scalar::ZERO
+                }
+                // Parabolas and hyperbolas bend away from `a`, so they wind
+                // the other way than circles and ellipses do.
+                SurfacePath::Parabola(parabola) => {
+                    parabola.a().cross2d(&parabola.b()) < Scalar::ZERO
+                }
+                SurfacePath::Hyperbola(hyperbola) => {
+                    hyperbola.a().cross2d(&hyperbola.b()) < Scalar::ZERO
+                }
+                // A free-form curve that is closed by the chord between its
+                // end points winds the same way as its control polygon.
+                SurfacePath::Bezier(bezier) => {
+                    control_polygon_is_ccw(&bezier.control_points())
+                }
+                SurfacePath::Nurbs(nurbs) => {
+                    control_polygon_is_ccw(nurbs.control_points())
+                }
+                SurfacePath::Polyline(polyline) => {
+                    control_polygon_is_ccw(polyline.points())
+                }
                 SurfacePath::Line(_) => unreachable!(
-                    "Invalid cycle: less than 3 edges, but not all are circles"
+                    "Invalid cycle: less than 3 edges, but not all are curved"
                 ),
             };
-            let cross_positive = circle.a().cross2d(&circle.b()) > Scalar::ZERO;
 
             if edge_direction_positive == cross_positive {
                 return Winding::Ccw;
@@ -124,13 +151,14 @@ impl Cycle {
 
         let mut sum = Scalar::ZERO;
 
-        for half_edge in self.half_edges.windows(2) {
-            // Can't panic, as we passed `2` to `windows`.
-            //
-            // Can be cleaned up, once `array_windows` is stable:
-            // https://doc.rust-lang.org/std/primitive.slice.html#method.array_windows
-            let [a, b] = [&half_edge[0], &half_edge[1]];
+        // The last half-edge connects to the first, so that pair needs to be
+        // taken into account too.
+        let pairs = self
+            .half_edges
+            .iter()
+            .zip(self.half_edges.iter().cycle().skip(1));
 
+        for (a, b) in pairs {
             let [a, b] = [a, b].map(|half_edge| {
                 let [vertex, _] = half_edge.vertices();
                 vertex.surface_form().position()
@@ -154,3 +182,14 @@ impl Cycle {
         self.half_edges.into_iter()
     }
 }
+
+fn control_polygon_is_ccw(points: &[Point<2>]) -> bool {
+    let mut sum = Scalar::ZERO;
+
+    for (i, a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        sum += (b.u - a.u) * (b.v + a.v);
+    }
+
+    sum < Scalar::ZERO
+}
@@ -1,15 +1,20 @@
 use std::fmt;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use pretty_assertions::{assert_eq, assert_ne};
 
 use crate::storage::{Handle, HandleWrapper};
 
-use super::{Curve, GlobalCurve, GlobalVertex, Vertex};
+use super::{Curve, GlobalCurve, GlobalVertex, TrimmedCurve, Vertex};
 
 /// A half-edge
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HalfEdge {
     vertices: [Vertex; 2],
+    trimmed_curve: TrimmedCurve,
     global_form: GlobalEdge,
 }
 
@@ -62,16 +67,24 @@ impl HalfEdge {
             "Vertices of an edge must not be coincident on curve"
         );
 
+        let trimmed_curve =
+            TrimmedCurve::new(curve.clone(), [&a, &b].map(|v| v.position()));
+
         Self {
             vertices: [a, b],
+            trimmed_curve,
             global_form,
         }
     }
 
     /// Access the curve that defines the half-edge's geometry
     pub fn curve(&self) -> &Handle<Curve> {
-        let [vertex, _] = self.vertices();
-        vertex.curve()
+        self.trimmed_curve.curve()
+    }
+
+    /// Access the curve of the half-edge, trimmed to its vertices
+    pub fn trimmed_curve(&self) -> &TrimmedCurve {
+        &self.trimmed_curve
     }
 
     /// Access the vertices that bound the half-edge on the curve
@@ -102,6 +115,7 @@ impl fmt::Display for HalfEdge {
 /// be used to determine whether two [`HalfEdge`]s map to the same `GlobalEdge`,
 /// regardless of their direction.
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GlobalEdge {
     curve: HandleWrapper<GlobalCurve>,
     vertices: VerticesInNormalizedOrder,
@@ -149,6 +163,7 @@ impl GlobalEdge {
 /// possible to construct two [`GlobalEdge`] instances that are meant to
 /// represent the same edge, but aren't equal.
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VerticesInNormalizedOrder([Handle<GlobalVertex>; 2]);
 
 impl VerticesInNormalizedOrder {
@@ -180,7 +195,7 @@ mod tests {
     use super::HalfEdge;
 
     #[test]
-    fn global_edge_equality() {
+    fn global_edge_equality() -> anyhow::Result<()> {
         let objects = Objects::new();
 
         let surface = objects.surfaces.insert(Surface::xy_plane());
@@ -190,13 +205,15 @@ mod tests {
 
         let a_to_b = HalfEdge::partial()
             .with_surface(Some(surface.clone()))
-            .as_line_segment_from_points([a, b])
-            .build(&objects);
+            .as_line_segment_from_points([a, b])?
+            .build(&objects)?;
         let b_to_a = HalfEdge::partial()
             .with_surface(Some(surface))
-            .as_line_segment_from_points([b, a])
-            .build(&objects);
+            .as_line_segment_from_points([b, a])?
+            .build(&objects)?;
 
         assert_eq!(a_to_b.global_form(), b_to_a.global_form());
+
+        Ok(())
     }
 }
@@ -1,9 +1,17 @@
 use std::collections::{btree_set, BTreeSet};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use fj_interop::mesh::Color;
 use fj_math::Winding;
 
-use crate::{builder::FaceBuilder, storage::Handle};
+use crate::{
+    builder::FaceBuilder,
+    geometry::{SurfaceGeometry, SweptCurve},
+    path::{GlobalPath, SurfacePath},
+    storage::Handle,
+};
 
 use super::{Cycle, Objects, Surface};
 
@@ -32,6 +40,7 @@ use super::{Cycle, Objects, Surface};
 /// [`HalfEdge`]: super::HalfEdge
 /// [`Shell`]: super::Shell
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Face {
     surface: Handle<Surface>,
     exterior: Cycle,
@@ -47,6 +56,7 @@ impl Face {
             surface,
             exterior: None,
             interiors: Vec::new(),
+            error: None,
         }
     }
 
@@ -130,6 +140,27 @@ impl Face {
         self.color
     }
 
+    /// Indicate whether the face is planar and bounded by straight edges
+    ///
+    /// Many algorithms only support faces like that so far. Operations that
+    /// take user input can use this to reject other faces with an error.
+    pub fn is_polygon(&self) -> bool {
+        let is_planar = matches!(
+            self.surface().geometry(),
+            SurfaceGeometry::SweptCurve(SweptCurve {
+                u: GlobalPath::Line(_),
+                ..
+            })
+        );
+
+        is_planar
+            && self.all_cycles().flat_map(|cycle| cycle.half_edges()).all(
+                |half_edge| {
+                    matches!(half_edge.curve().path(), SurfacePath::Line(_))
+                },
+            )
+    }
+
     /// Determine handed-ness of the face's front-side coordinate system
     ///
     /// A face is defined on a surface, which has a coordinate system. Since
@@ -150,6 +181,7 @@ impl Face {
 
 /// A collection of faces
 #[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Faces {
     inner: BTreeSet<Face>,
 }
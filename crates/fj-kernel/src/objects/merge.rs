@@ -0,0 +1,229 @@
+use std::collections::{BTreeSet, HashMap};
+
+use approx::AbsDiffEq;
+use fj_math::{Point, Scalar};
+
+use crate::storage::{Coincident, Handle, Merge, ObjectId, WeakHandle};
+
+use super::{Curve, GlobalVertex, Surface};
+
+/// Merges global vertices that are within a maximum distance of each other
+///
+/// The vertices are sorted into a grid of cubes, whose size is the maximum
+/// distance. Only the vertices in neighboring cubes need to be checked then.
+#[derive(Debug)]
+pub struct MergeGlobalVertices {
+    max_distance: Scalar,
+    cells: HashMap<[i64; 3], Vec<WeakHandle<GlobalVertex>>>,
+}
+
+impl MergeGlobalVertices {
+    pub fn new(max_distance: Scalar) -> Self {
+        Self {
+            max_distance,
+            cells: HashMap::new(),
+        }
+    }
+
+    fn cell(&self, position: Point<3>) -> [i64; 3] {
+        position
+            .coords
+            .components
+            .map(|c| (c / self.max_distance).floor().into_f64() as i64)
+    }
+}
+
+impl Merge<GlobalVertex> for MergeGlobalVertices {
+    fn find(&self, object: &GlobalVertex) -> Option<Handle<GlobalVertex>> {
+        let position = object.position();
+        let [x, y, z] = self.cell(position);
+
+        let mut neighbors = Vec::new();
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let cell = [x + dx, y + dy, z + dz];
+                    neighbors
+                        .extend(self.cells.get(&cell).into_iter().flatten());
+                }
+            }
+        }
+
+        neighbors
+            .into_iter()
+            .filter_map(WeakHandle::upgrade)
+            .map(|vertex| ((vertex.position() - position).magnitude(), vertex))
+            .filter(|(distance, _)| *distance <= self.max_distance)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, vertex)| vertex)
+    }
+
+    fn add(&mut self, handle: Handle<GlobalVertex>) {
+        let cell = self.cell(handle.position());
+        self.cells.entry(cell).or_default().push(handle.downgrade());
+    }
+
+    fn forget(&mut self, discarded: &BTreeSet<ObjectId>) {
+        for vertices in self.cells.values_mut() {
+            vertices.retain(|vertex| !discarded.contains(&vertex.id()));
+        }
+    }
+}
+
+/// Merges surfaces that are equal
+///
+/// Unlike vertices, surfaces are only merged, if they are exactly equal. Two
+/// surfaces that are only nearly equal have slightly different coordinate
+/// systems, and any objects that are defined in surface coordinates would
+/// shift, if one of them replaced the other.
+#[derive(Debug, Default)]
+pub struct MergeSurfaces {
+    surfaces: HashMap<Surface, WeakHandle<Surface>>,
+}
+
+impl Merge<Surface> for MergeSurfaces {
+    fn find(&self, object: &Surface) -> Option<Handle<Surface>> {
+        self.surfaces.get(object)?.upgrade()
+    }
+
+    fn add(&mut self, handle: Handle<Surface>) {
+        self.surfaces
+            .insert(handle.clone_object(), handle.downgrade());
+    }
+
+    fn forget(&mut self, discarded: &BTreeSet<ObjectId>) {
+        self.surfaces
+            .retain(|_, surface| !discarded.contains(&surface.id()));
+    }
+}
+
+impl Coincident for GlobalVertex {
+    fn is_coincident_with(&self, other: &Self, max_distance: Scalar) -> bool {
+        (self.position() - other.position()).magnitude() <= max_distance
+    }
+}
+
+/// Surfaces are coincident, if their geometry is equal within `max_distance`
+///
+/// Unlike [`MergeSurfaces`], which only merges exactly equal surfaces, this
+/// leaves the tolerance to the caller. Anything defined in surface coordinates
+/// shifts by up to `max_distance`, if a surface is replaced with a coincident
+/// one.
+impl Coincident for Surface {
+    fn is_coincident_with(&self, other: &Self, max_distance: Scalar) -> bool {
+        self.abs_diff_eq(other, max_distance)
+    }
+}
+
+/// Curves are coincident, if their paths are equal within `max_distance`
+///
+/// They must also be defined on the same surface and refer to the same global
+/// curve, as replacing either would change the topology.
+impl Coincident for Curve {
+    fn is_coincident_with(&self, other: &Self, max_distance: Scalar) -> bool {
+        self.surface().id() == other.surface().id()
+            && self.global_form().id() == other.global_form().id()
+            && self.path().abs_diff_eq(other.path(), max_distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        objects::{Curve, GlobalCurve, GlobalVertex, Objects, Surface},
+        path::{GlobalPath, SurfacePath},
+        storage::Handle,
+    };
+
+    #[test]
+    fn merge_coincident_objects() {
+        let objects = Objects::with_merge_distance(1e-6);
+
+        let a = GlobalVertex::from_position([0., 0., 0.], &objects);
+        let b = GlobalVertex::from_position([1e-7, 0., 0.], &objects);
+        let c = GlobalVertex::from_position([1e-5, 0., 0.], &objects);
+        assert_eq!(a.id(), b.id());
+        assert_ne!(a.id(), c.id());
+
+        let a = objects.surfaces.insert(Surface::xy_plane());
+        let b = objects.surfaces.insert(Surface::xy_plane());
+        let c = objects.surfaces.insert(Surface::xz_plane());
+        assert_eq!(a.id(), b.id());
+        assert_ne!(a.id(), c.id());
+
+        // Without merging, every object is distinct.
+        let objects = Objects::new();
+        let a = GlobalVertex::from_position([0., 0., 0.], &objects);
+        let b = GlobalVertex::from_position([0., 0., 0.], &objects);
+        assert_ne!(a.id(), b.id());
+    }
+
+    #[test]
+    fn rollback_forgets_discarded_objects() {
+        let objects = Objects::with_merge_distance(1e-6);
+
+        let marker = objects.marker();
+        let a = GlobalVertex::from_position([0., 0., 0.], &objects);
+        objects.rollback(&marker);
+
+        let b = GlobalVertex::from_position([0., 0., 0.], &objects);
+        assert_ne!(a.id(), b.id());
+        assert_eq!(objects.global_vertices.iter().collect::<Vec<_>>(), [b]);
+    }
+
+    #[test]
+    fn insert_or_get() {
+        let objects = Objects::new();
+
+        let a = objects.surfaces.insert_or_get(Surface::xy_plane(), 1e-6);
+        let b = objects.surfaces.insert_or_get(Surface::xy_plane(), 1e-6);
+        let c = objects.surfaces.insert_or_get(Surface::xz_plane(), 1e-6);
+        let d = objects.surfaces.insert_or_get(
+            Surface::new(GlobalPath::x_axis(), [0., 1e-7, 1.]),
+            1e-6,
+        );
+        assert_eq!(a.id(), b.id());
+        assert_ne!(a.id(), c.id());
+        assert_eq!(c.id(), d.id());
+
+        // Vertices and curves can only be constructed by inserting them into a
+        // store. Use a separate one to get objects to deduplicate.
+        let scratch = Objects::new();
+
+        let vertex = |position: [f64; 3]| {
+            GlobalVertex::from_position(position, &scratch).clone_object()
+        };
+        let a = objects
+            .global_vertices
+            .insert_or_get(vertex([0., 0., 0.]), 1e-6);
+        let b = objects
+            .global_vertices
+            .insert_or_get(vertex([1e-7, 0., 0.]), 1e-6);
+        let c = objects
+            .global_vertices
+            .insert_or_get(vertex([1e-5, 0., 0.]), 1e-6);
+        assert_eq!(a.id(), b.id());
+        assert_ne!(a.id(), c.id());
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let global_curve = GlobalCurve::new(&objects);
+        let curve = |global_curve: Handle<GlobalCurve>| {
+            Curve::new(
+                surface.clone(),
+                SurfacePath::line_from_points([[0., 0.], [1., 0.]]),
+                global_curve,
+                &scratch,
+            )
+            .clone_object()
+        };
+        let a = objects
+            .curves
+            .insert_or_get(curve(global_curve.clone()), 1e-6);
+        let b = objects.curves.insert_or_get(curve(global_curve), 1e-6);
+        let c = objects
+            .curves
+            .insert_or_get(curve(GlobalCurve::new(&objects)), 1e-6);
+        assert_eq!(a.id(), b.id());
+        assert_ne!(a.id(), c.id());
+    }
+}
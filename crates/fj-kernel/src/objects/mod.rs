@@ -77,16 +77,27 @@ mod curve;
 mod cycle;
 mod edge;
 mod face;
+mod merge;
 mod shell;
 mod sketch;
 mod solid;
 mod surface;
 mod vertex;
 
-use crate::storage::Store;
+#[cfg(feature = "serde")]
+mod serialize;
+
+use fj_math::Scalar;
+
+use crate::{
+    algorithms::validate::{Validate, ValidationConfig, ValidationError},
+    storage::{self, Store},
+};
+
+use self::merge::{MergeGlobalVertices, MergeSurfaces};
 
 pub use self::{
-    curve::{Curve, GlobalCurve},
+    curve::{Curve, GlobalCurve, TrimmedCurve},
     cycle::Cycle,
     edge::{GlobalEdge, HalfEdge, VerticesInNormalizedOrder},
     face::{Face, Faces, Handedness},
@@ -97,6 +108,9 @@ pub use self::{
     vertex::{GlobalVertex, SurfaceVertex, Vertex},
 };
 
+#[cfg(feature = "serde")]
+pub use self::serialize::Snapshot;
+
 /// The available object stores
 ///
 /// # Implementation Note
@@ -118,6 +132,8 @@ pub struct Objects {
 
     /// Store for surfaces
     pub surfaces: Store<Surface>,
+
+    build_validation: Option<ValidationConfig>,
 }
 
 impl Objects {
@@ -125,4 +141,148 @@ impl Objects {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Construct an instance of `Objects` that merges coincident objects
+    ///
+    /// Global vertices that are inserted within `max_distance` of an existing
+    /// one are merged into it, and so are surfaces that are equal to an
+    /// existing one. This saves memory, and prevents the validation failures
+    /// that nearly coincident vertices cause.
+    ///
+    /// Global curves don't carry any geometry that could be compared, and
+    /// curves are defined in terms of their global curve. Neither are merged.
+    /// Merging edges is what [`Sew`] does.
+    ///
+    /// [`Sew`]: crate::algorithms::sew::Sew
+    pub fn with_merge_distance(max_distance: impl Into<Scalar>) -> Self {
+        Self {
+            global_vertices: Store::with_merge(MergeGlobalVertices::new(
+                max_distance.into(),
+            )),
+            surfaces: Store::with_merge(MergeSurfaces::default()),
+            ..Self::default()
+        }
+    }
+
+    /// Validate objects right when they are built from partial objects
+    ///
+    /// Normally, only the finished shape is validated, and an error can be far
+    /// removed from the code that introduced the invalid data. With this
+    /// enabled, the `build` methods of partial vertices, half-edges, and cycles
+    /// validate the objects they build, using `config`, and return an error,
+    /// if validation fails.
+    pub fn with_build_validation(self, config: ValidationConfig) -> Self {
+        Self {
+            build_validation: Some(config),
+            ..self
+        }
+    }
+
+    /// Mark the current state of all stores
+    ///
+    /// The stores record all inserted objects in order, like a journal. The
+    /// returned [`Marker`] can be passed to [`Objects::rollback`], to discard
+    /// all objects that have been inserted since.
+    pub fn marker(&self) -> Marker {
+        Marker {
+            curves: self.curves.marker(),
+            global_curves: self.global_curves.marker(),
+            global_vertices: self.global_vertices.marker(),
+            surfaces: self.surfaces.marker(),
+        }
+    }
+
+    /// Roll all stores back to `marker`
+    ///
+    /// This is useful for retrying an operation that failed halfway through,
+    /// without leaving the objects it built so far in the stores. See
+    /// [`Store::rollback`] for details.
+    pub fn rollback(&self, marker: &Marker) {
+        let Marker {
+            curves,
+            global_curves,
+            global_vertices,
+            surfaces,
+        } = marker;
+
+        self.curves.rollback(curves);
+        self.global_curves.rollback(global_curves);
+        self.global_vertices.rollback(global_vertices);
+        self.surfaces.rollback(surfaces);
+    }
+
+    /// Drop all objects that are no longer referenced
+    ///
+    /// Call this after dropping a shape, to free the memory of all objects that
+    /// only that shape referenced. See [`Store::collect_garbage`] for details.
+    ///
+    /// Returns the number of objects that have been dropped.
+    pub fn collect_garbage(&self) -> usize {
+        // Curves refer to global curves and surfaces, so they must be collected
+        // first. Otherwise, the objects they refer to would still be
+        // referenced.
+        self.curves.collect_garbage()
+            + self.global_curves.collect_garbage()
+            + self.global_vertices.collect_garbage()
+            + self.surfaces.collect_garbage()
+    }
+
+    /// Validate an object that was just built from a partial object
+    ///
+    /// Does nothing, unless build validation has been enabled.
+    pub(crate) fn validate_built<T>(
+        &self,
+        object: T,
+    ) -> Result<T, ValidationError>
+    where
+        T: Validate,
+    {
+        let config = match &self.build_validation {
+            Some(config) => config,
+            None => return Ok(object),
+        };
+
+        Ok(object.validate_with_config(config)?.into_inner())
+    }
+}
+
+/// A position in the journal of [`Objects`]
+///
+/// See [`Objects::marker`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Marker {
+    curves: storage::Marker,
+    global_curves: storage::Marker,
+    global_vertices: storage::Marker,
+    surfaces: storage::Marker,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::path::SurfacePath;
+
+    use super::{Curve, GlobalCurve, Objects, Surface};
+
+    #[test]
+    fn collect_garbage() {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let curve = Curve::new(
+            surface.clone(),
+            SurfacePath::line_from_points([[0., 0.], [1., 0.]]),
+            GlobalCurve::new(&objects),
+            &objects,
+        );
+
+        // The surface is still referenced, so only the curve and its global
+        // form can be dropped.
+        drop(curve);
+        assert_eq!(objects.collect_garbage(), 2);
+        assert_eq!(objects.surfaces.iter().count(), 1);
+
+        drop(surface);
+        assert_eq!(objects.collect_garbage(), 1);
+        assert_eq!(objects.surfaces.iter().count(), 0);
+    }
 }
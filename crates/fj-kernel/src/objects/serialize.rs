@@ -0,0 +1,217 @@
+//! Serialization of [`Objects`], and of the shapes that reference them
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::storage::{DeserializeScope, Store};
+
+use super::{Curve, GlobalCurve, GlobalVertex, Objects, Surface};
+
+// The stores are serialized in order of their dependencies, so every handle
+// can be deserialized after the store it refers to.
+#[derive(Serialize)]
+#[serde(rename = "Objects")]
+struct SerializeObjects<'r> {
+    surfaces: &'r Store<Surface>,
+    global_curves: &'r Store<GlobalCurve>,
+    global_vertices: &'r Store<GlobalVertex>,
+    curves: &'r Store<Curve>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Objects")]
+struct DeserializeObjects {
+    surfaces: Store<Surface>,
+    global_curves: Store<GlobalCurve>,
+    global_vertices: Store<GlobalVertex>,
+    curves: Store<Curve>,
+}
+
+/// Only the objects and their labels are serialized
+///
+/// Neither the merge configuration of the stores, nor the build validation
+/// configuration are preserved. A deserialized instance of `Objects` behaves
+/// like one created with [`Objects::new`].
+impl Serialize for Objects {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        SerializeObjects {
+            surfaces: &self.surfaces,
+            global_curves: &self.global_curves,
+            global_vertices: &self.global_vertices,
+            curves: &self.curves,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Objects {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let _scope = DeserializeScope::enter();
+
+        let DeserializeObjects {
+            surfaces,
+            global_curves,
+            global_vertices,
+            curves,
+        } = DeserializeObjects::deserialize(deserializer)?;
+
+        Ok(Self {
+            curves,
+            global_curves,
+            global_vertices,
+            surfaces,
+            build_validation: None,
+        })
+    }
+}
+
+/// A shape, together with the objects it references
+///
+/// Objects refer to each other through [`Handle`]s, which are serialized as the
+/// index of the referenced object within its store. A serialized shape is only
+/// meaningful together with the [`Objects`] that it was built from. A
+/// `Snapshot` contains both, and can be written to disk and read back.
+///
+/// To serialize a shape, construct a `Snapshot` from references to the shape
+/// and its objects:
+///
+/// ``` rust
+/// # use fj_kernel::{
+/// #     algorithms::sweep::Sweep,
+/// #     objects::{Objects, Sketch, Snapshot, Solid, Surface},
+/// # };
+/// # let objects = Objects::new();
+/// # let surface = objects.surfaces.insert(Surface::xy_plane());
+/// # let solid = Sketch::builder(&objects, surface)
+/// #     .build_polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])?
+/// #     .sweep([0., 0., 1.], &objects);
+/// let json = serde_json::to_string(&Snapshot {
+///     shape: &solid,
+///     objects: &objects,
+/// })?;
+///
+/// let snapshot: Snapshot<Solid> = serde_json::from_str(&json)?;
+/// # let json_again = serde_json::to_string(&Snapshot {
+/// #     shape: &snapshot.shape,
+/// #     objects: &snapshot.objects,
+/// # })?;
+/// # assert_eq!(json, json_again);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+///
+/// Deserialized objects are not validated. The shape can only reference
+/// objects from the `Objects` it is serialized with.
+///
+/// [`Handle`]: crate::storage::Handle
+#[derive(Debug, Serialize)]
+pub struct Snapshot<T, O = Objects> {
+    /// The objects that the shape references
+    pub objects: O,
+
+    /// The shape
+    pub shape: T,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Snapshot")]
+struct DeserializeSnapshot<T> {
+    objects: Objects,
+    shape: T,
+}
+
+impl<'de, T> Deserialize<'de> for Snapshot<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // The scope must outlive the deserialization of the objects, so the
+        // handles within the shape can be deserialized too.
+        let _scope = DeserializeScope::enter();
+
+        let DeserializeSnapshot { objects, shape } =
+            DeserializeSnapshot::deserialize(deserializer)?;
+
+        Ok(Self { objects, shape })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{
+        algorithms::sweep::Sweep,
+        iter::ObjectIters,
+        objects::{Objects, Sketch, Solid, Surface},
+    };
+
+    use super::Snapshot;
+
+    #[test]
+    fn snapshot() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects
+            .surfaces
+            .insert(Surface::xy_plane())
+            .with_label(Some("bottom"));
+        let solid = Sketch::builder(&objects, surface)
+            .build_polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])?
+            .sweep([0., 0., 1.], &objects);
+
+        let json = serde_json::to_string(&Snapshot {
+            objects: &objects,
+            shape: &solid,
+        })?;
+        let snapshot: Snapshot<Solid> = serde_json::from_str(&json)?;
+
+        assert_eq!(snapshot.shape, solid);
+
+        // Serializing the same shape again must result in the same output, or
+        // snapshots couldn't be diffed.
+        let json_again = serde_json::to_string(&Snapshot {
+            objects: &snapshot.objects,
+            shape: &snapshot.shape,
+        })?;
+        assert_eq!(json, json_again);
+
+        // Objects that are shared within the original shape, must still be
+        // shared after deserialization.
+        let global_vertices = |solid: &Solid| {
+            solid
+                .half_edge_iter()
+                .flat_map(|half_edge| half_edge.vertices().clone())
+                .map(|vertex| vertex.global_form().id())
+                .collect::<HashSet<_>>()
+                .len()
+        };
+        assert_eq!(global_vertices(&snapshot.shape), global_vertices(&solid));
+        assert!(global_vertices(&solid) < solid.half_edge_iter().count() * 2);
+
+        let labeled = |objects: &Objects| {
+            objects.surfaces.find_by_label("bottom").count()
+        };
+        assert_eq!(labeled(&snapshot.objects), labeled(&objects));
+
+        Ok(())
+    }
+
+    #[test]
+    fn handle_without_store() {
+        let objects = Objects::new();
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+
+        let json = serde_json::to_string(&surface).unwrap();
+        let result = serde_json::from_str::<Surface>(&json);
+
+        assert!(result.is_err());
+    }
+}
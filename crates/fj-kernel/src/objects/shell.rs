@@ -1,4 +1,7 @@
-use crate::builder::ShellBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{algorithms::validate::ValidationConfig, builder::ShellBuilder};
 
 use super::{face::Faces, Face, Objects};
 
@@ -6,9 +9,11 @@ use super::{face::Faces, Face, Objects};
 ///
 /// # Implementation Note
 ///
-/// The faces that make up a shell should be closed ("watertight"). This is not
-/// currently validated.
+/// The faces that make up a shell should be closed ("watertight"). This is
+/// checked by [`ShellBuilder::build`] and by validation, but not enforced by
+/// [`Shell::new`].
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Shell {
     faces: Faces,
 }
@@ -16,7 +21,11 @@ pub struct Shell {
 impl Shell {
     /// Build a `Shell` using [`ShellBuilder`]
     pub fn builder(objects: &Objects) -> ShellBuilder {
-        ShellBuilder { objects }
+        ShellBuilder {
+            objects,
+            faces: Vec::new(),
+            tolerance: ValidationConfig::default().distinct_min_distance,
+        }
     }
 
     /// Construct an empty instance of `Shell`
@@ -1,3 +1,6 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::{builder::SketchBuilder, storage::Handle};
 
 use super::{face::Faces, Face, Objects, Surface};
@@ -9,6 +12,7 @@ use super::{face::Faces, Face, Objects, Surface};
 /// The faces that make up the sketch must be in the same surface. This is not
 /// currently validated.
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Sketch {
     faces: Faces,
 }
@@ -1,5 +1,8 @@
 use std::collections::BTreeSet;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::builder::SolidBuilder;
 
 use super::{Face, Objects, Shell};
@@ -11,6 +14,7 @@ use super::{Face, Objects, Shell};
 /// The shells that form the boundaries of the solid must not intersect. This is
 /// not currently validated.
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Solid {
     shells: BTreeSet<Shell>,
 }
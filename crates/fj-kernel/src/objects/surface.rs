@@ -1,45 +1,113 @@
-use fj_math::{Line, Point, Vector};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-use crate::path::GlobalPath;
+use fj_math::{
+    Cone, Cylinder, Line, NurbsSurface, Point, Scalar, Sphere, Torus, Vector,
+};
+
+use crate::{
+    geometry::{
+        RevolutionSurface, RuledSurface, SurfaceGeometry, SweptCurve, SweptPath,
+    },
+    path::GlobalPath,
+};
 
 /// A two-dimensional shape
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Surface {
-    u: GlobalPath,
-    v: Vector<3>,
+    geometry: SurfaceGeometry,
 }
 
 impl Surface {
     /// Construct a `Surface` from two paths that define its coordinate system
     pub fn new(u: GlobalPath, v: impl Into<Vector<3>>) -> Self {
         let v = v.into();
-        Self { u, v }
+        Self {
+            geometry: SurfaceGeometry::SweptCurve(SweptCurve { u, v }),
+        }
     }
 
-    /// Construct a `Surface` that represents the xy-plane
-    pub fn xy_plane() -> Self {
+    /// Construct a `Surface` by sweeping a path along another path
+    ///
+    /// See [`SweptPath`] for the resulting coordinate system.
+    pub fn swept_along_path(u: GlobalPath, v: GlobalPath) -> Self {
         Self {
-            u: GlobalPath::x_axis(),
-            v: Vector::unit_y(),
+            geometry: SurfaceGeometry::SweptPath(SweptPath { u, v }),
         }
     }
 
-    /// Construct a `Surface` that represents the xz-plane
-    pub fn xz_plane() -> Self {
+    /// Construct a ruled `Surface` between two paths
+    ///
+    /// See [`RuledSurface`] for the resulting coordinate system.
+    pub fn ruled(a: GlobalPath, b: GlobalPath) -> Self {
         Self {
-            u: GlobalPath::x_axis(),
-            v: Vector::unit_z(),
+            geometry: SurfaceGeometry::Ruled(RuledSurface { a, b }),
         }
     }
 
-    /// Construct a `Surface` that represents the yz-plane
-    pub fn yz_plane() -> Self {
+    /// Construct a `Surface` by revolving a curve around an axis
+    ///
+    /// See [`RevolutionSurface`] for the resulting coordinate system.
+    pub fn revolved(profile: GlobalPath, axis: Line<3>) -> Self {
+        Self {
+            geometry: SurfaceGeometry::Revolution(RevolutionSurface {
+                profile,
+                axis,
+            }),
+        }
+    }
+
+    /// Construct a `Surface` from a cylinder
+    pub fn from_cylinder(cylinder: Cylinder) -> Self {
+        Self {
+            geometry: SurfaceGeometry::Cylinder(cylinder),
+        }
+    }
+
+    /// Construct a `Surface` from a cone
+    pub fn from_cone(cone: Cone) -> Self {
+        Self {
+            geometry: SurfaceGeometry::Cone(cone),
+        }
+    }
+
+    /// Construct a `Surface` from a sphere
+    pub fn from_sphere(sphere: Sphere) -> Self {
+        Self {
+            geometry: SurfaceGeometry::Sphere(sphere),
+        }
+    }
+
+    /// Construct a `Surface` from a torus
+    pub fn from_torus(torus: Torus) -> Self {
+        Self {
+            geometry: SurfaceGeometry::Torus(torus),
+        }
+    }
+
+    /// Construct a `Surface` from a NURBS surface
+    pub fn from_nurbs(nurbs: NurbsSurface) -> Self {
         Self {
-            u: GlobalPath::y_axis(),
-            v: Vector::unit_z(),
+            geometry: SurfaceGeometry::Nurbs(nurbs),
         }
     }
 
+    /// Construct a `Surface` that represents the xy-plane
+    pub fn xy_plane() -> Self {
+        Self::new(GlobalPath::x_axis(), Vector::unit_y())
+    }
+
+    /// Construct a `Surface` that represents the xz-plane
+    pub fn xz_plane() -> Self {
+        Self::new(GlobalPath::x_axis(), Vector::unit_z())
+    }
+
+    /// Construct a `Surface` that represents the yz-plane
+    pub fn yz_plane() -> Self {
+        Self::new(GlobalPath::y_axis(), Vector::unit_z())
+    }
+
     /// Construct a plane from 3 points
     pub fn plane_from_points(points: [impl Into<Point<3>>; 3]) -> Self {
         let [a, b, c] = points.map(Into::into);
@@ -47,17 +115,44 @@ impl Surface {
         let u = GlobalPath::Line(Line::from_points([a, b]));
         let v = c - a;
 
-        Self { u, v }
+        Self::new(u, v)
     }
 
-    /// Access the path that defines the u-coordinate of this surface
-    pub fn u(&self) -> GlobalPath {
-        self.u
+    /// Compute the surface that is offset from this one along its normal
+    ///
+    /// See [`SurfaceGeometry::offset`] for details.
+    pub fn offset(&self, distance: impl Into<Scalar>) -> Option<Self> {
+        let geometry = self.geometry.offset(distance)?;
+        Some(Self { geometry })
     }
 
-    /// Access the path that defines the v-coordinate of this surface
-    pub fn v(&self) -> Vector<3> {
-        self.v
+    /// Access the periods of this surface along its u- and v-directions
+    ///
+    /// See [`SurfaceGeometry::periods`] for details.
+    pub fn periods(&self) -> [Option<Scalar>; 2] {
+        self.geometry.periods()
+    }
+
+    /// Compute the normal at the given surface coordinates
+    ///
+    /// See [`SurfaceGeometry::normal_at`] for details.
+    pub fn normal_at(&self, point: impl Into<Point<2>>) -> Option<Vector<3>> {
+        self.geometry.normal_at(point)
+    }
+
+    /// Compute the principal curvatures at the given surface coordinates
+    ///
+    /// See [`SurfaceGeometry::principal_curvatures_at`] for details.
+    pub fn principal_curvatures_at(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> [Scalar; 2] {
+        self.geometry.principal_curvatures_at(point)
+    }
+
+    /// Access the geometry of this surface
+    pub fn geometry(&self) -> &SurfaceGeometry {
+        &self.geometry
     }
 
     /// Convert a point in surface coordinates to model coordinates
@@ -65,9 +160,7 @@ impl Surface {
         &self,
         point: impl Into<Point<2>>,
     ) -> Point<3> {
-        let point = point.into();
-        self.u.point_from_path_coords([point.u])
-            + self.path_to_line().vector_from_line_coords([point.v])
+        self.geometry.point_from_surface_coords(point)
     }
 
     /// Convert a vector in surface coordinates to model coordinates
@@ -75,34 +168,46 @@ impl Surface {
         &self,
         vector: impl Into<Vector<2>>,
     ) -> Vector<3> {
-        let vector = vector.into();
-        self.u.vector_from_path_coords([vector.u])
-            + self.path_to_line().vector_from_line_coords([vector.v])
+        self.geometry.vector_from_surface_coords(vector)
+    }
+}
+
+impl approx::AbsDiffEq for Surface {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
     }
 
-    fn path_to_line(&self) -> Line<3> {
-        Line::from_origin_and_direction(self.u.origin(), self.v)
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.geometry.abs_diff_eq(&other.geometry, epsilon)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use fj_math::{Line, Point, Vector};
+    use std::f64::consts::FRAC_PI_3;
+
+    use approx::assert_abs_diff_eq;
+    use fj_math::{Cone, Cylinder, Line, Point, Scalar, Sphere, Torus, Vector};
     use pretty_assertions::assert_eq;
 
-    use crate::path::GlobalPath;
+    use crate::{
+        algorithms::transform::TransformObject, objects::Objects,
+        path::GlobalPath,
+    };
 
     use super::Surface;
 
     #[test]
     fn point_from_surface_coords() {
-        let swept = Surface {
-            u: GlobalPath::Line(Line::from_origin_and_direction(
+        let swept = Surface::new(
+            GlobalPath::Line(Line::from_origin_and_direction(
                 Point::from([1., 1., 1.]),
                 Vector::from([0., 2., 0.]),
             )),
-            v: Vector::from([0., 0., 2.]),
-        };
+            [0., 0., 2.],
+        );
 
         assert_eq!(
             swept.point_from_surface_coords([2., 4.]),
@@ -110,15 +215,153 @@ mod tests {
         );
     }
 
+    #[test]
+    fn offset() {
+        let surfaces = [
+            Surface::xy_plane(),
+            Surface::new(GlobalPath::circle_from_radius(2.), [0., 0., -1.]),
+            Surface::from_cylinder(Cylinder::new(
+                [0., 0., 0.],
+                [2., 0., 0.],
+                [0., 2., 0.],
+                [0., 0., 1.],
+            )),
+            Surface::from_cone(Cone::new(
+                [0., 0., 0.],
+                [2., 0., 0.],
+                [0., 2., 0.],
+                [0., 0., 1.],
+                -1.,
+            )),
+            Surface::from_sphere(Sphere::from_center_and_radius(
+                [0., 0., 0.],
+                2.,
+            )),
+            Surface::from_torus(Torus::new(
+                [0., 0., 0.],
+                [0., 0., 1.],
+                [3., 0., 0.],
+                [2., 0., 0.],
+                [0., 0., 2.],
+            )),
+        ];
+
+        for surface in surfaces {
+            let offset = surface.offset(0.5).unwrap();
+
+            for coords in [[0., 0.], [0.5, 0.5], [1., -0.5]] {
+                // The offset must be along the normal, which is perpendicular
+                // to the surface.
+                let point = surface.point_from_surface_coords(coords);
+                let offset_point = offset.point_from_surface_coords(coords);
+                let normal = surface.normal_at(coords).unwrap();
+
+                let expected = point + normal * 0.5;
+                assert!(
+                    (offset_point - expected).magnitude() < 1e-8.into(),
+                    "{surface:?}: {offset_point:?} != {expected:?}"
+                );
+            }
+        }
+
+        // Offsetting a sphere inward by its radius makes it degenerate.
+        let sphere = Surface::from_sphere(Sphere::from_center_and_radius(
+            [0., 0., 0.],
+            2.,
+        ));
+        assert_eq!(sphere.offset(-2.), None);
+    }
+
+    #[test]
+    fn abs_diff_eq_after_transform() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let cylinder = Cylinder::new(
+            [1., 2., 3.],
+            [2., 0., 0.],
+            [0., 2., 0.],
+            [0., 0., 1.],
+        );
+        let surface = objects.surfaces.insert(Surface::from_cylinder(cylinder));
+
+        // A full turn in several steps is not guaranteed to lead back to the
+        // exact same surface, due to floating-point errors.
+        let mut transformed = surface.clone();
+        for _ in 0..6 {
+            transformed = transformed.rotate([0., 0., FRAC_PI_3], &objects)?;
+        }
+
+        assert_abs_diff_eq!(
+            *transformed,
+            *surface,
+            epsilon = Scalar::from(1e-12)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn principal_curvatures_at() {
+        let cases = [
+            (Surface::xy_plane(), [0., 0.]),
+            (
+                Surface::from_cylinder(Cylinder::new(
+                    [0., 0., 0.],
+                    [2., 0., 0.],
+                    [0., 2., 0.],
+                    [0., 0., 1.],
+                )),
+                [0., 0.5],
+            ),
+            (
+                Surface::from_sphere(Sphere::from_center_and_radius(
+                    [0., 0., 0.],
+                    2.,
+                )),
+                [0.5, 0.5],
+            ),
+        ];
+
+        for (surface, expected) in cases {
+            let curvatures = surface.principal_curvatures_at([0.5, 0.5]);
+
+            for (curvature, expected) in curvatures.into_iter().zip(expected) {
+                assert!(
+                    (curvature - expected).abs() < 1e-6.into(),
+                    "{surface:?}: {curvatures:?} != {expected:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn normal_at() {
+        assert_eq!(
+            Surface::xy_plane().normal_at([1., 2.]),
+            Some(Vector::from([0., 0., 1.])),
+        );
+
+        let sphere = Surface::from_sphere(Sphere::from_center_and_radius(
+            [0., 0., 0.],
+            2.,
+        ));
+        let normal = sphere.normal_at([0.5, 0.5]).unwrap();
+        let expected = sphere.point_from_surface_coords([0.5, 0.5]).coords / 2.;
+        assert!((normal - expected).magnitude() < 1e-8.into());
+
+        // There's no normal at the poles of the sphere.
+        assert_eq!(sphere.normal_at([0., std::f64::consts::FRAC_PI_2]), None);
+    }
+
     #[test]
     fn vector_from_surface_coords() {
-        let swept = Surface {
-            u: GlobalPath::Line(Line::from_origin_and_direction(
+        let swept = Surface::new(
+            GlobalPath::Line(Line::from_origin_and_direction(
                 Point::from([1., 0., 0.]),
                 Vector::from([0., 2., 0.]),
             )),
-            v: Vector::from([0., 0., 2.]),
-        };
+            [0., 0., 2.],
+        );
 
         assert_eq!(
             swept.vector_from_surface_coords([2., 4.]),
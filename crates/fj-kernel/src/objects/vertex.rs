@@ -1,4 +1,7 @@
-use fj_math::Point;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use fj_math::{Point, Scalar};
 use pretty_assertions::assert_eq;
 
 use crate::storage::Handle;
@@ -11,6 +14,7 @@ use super::{Curve, Objects, Surface};
 /// need the 3D position of a vertex, you can use [`Vertex::global_form`], to
 /// get access of the global form of a vertex ([`GlobalVertex`]).
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vertex {
     position: Point<1>,
     curve: Handle<Curve>,
@@ -63,8 +67,23 @@ impl Vertex {
     }
 }
 
+impl approx::AbsDiffEq for Vertex {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.position.abs_diff_eq(&other.position, epsilon)
+            && self.curve.abs_diff_eq(&other.curve, epsilon)
+            && self.surface_form.abs_diff_eq(&other.surface_form, epsilon)
+    }
+}
+
 /// A vertex, defined in surface (2D) coordinates
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SurfaceVertex {
     position: Point<2>,
     surface: Handle<Surface>,
@@ -102,6 +121,20 @@ impl SurfaceVertex {
     }
 }
 
+impl approx::AbsDiffEq for SurfaceVertex {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.position.abs_diff_eq(&other.position, epsilon)
+            && self.surface.abs_diff_eq(&other.surface, epsilon)
+            && self.global_form.abs_diff_eq(&other.global_form, epsilon)
+    }
+}
+
 /// A vertex, defined in global (3D) coordinates
 ///
 /// This struct exists to distinguish between vertices and points at the type
@@ -121,6 +154,7 @@ impl SurfaceVertex {
 ///
 /// [`ValidationConfig`]: crate::algorithms::validate::ValidationConfig
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GlobalVertex {
     position: Point<3>,
 }
@@ -140,3 +174,15 @@ impl GlobalVertex {
         self.position
     }
 }
+
+impl approx::AbsDiffEq for GlobalVertex {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.position.abs_diff_eq(&other.position, epsilon)
+    }
+}
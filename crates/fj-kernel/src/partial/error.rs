@@ -0,0 +1,78 @@
+use crate::algorithms::validate::ValidationError;
+
+/// An error that can occur while building a full object from a partial one
+///
+/// Returned by [`Partial::build`], and by the methods of partial objects that
+/// infer some of their fields from other ones.
+///
+/// [`Partial::build`]: super::Partial::build
+#[derive(Debug, thiserror::Error)]
+pub enum PartialBuildError {
+    /// A field that is required to build the object has not been provided
+    #[error("Can't build `{object}` without {field}")]
+    MissingField {
+        /// The type of the object that could not be built
+        object: &'static str,
+
+        /// The field that is missing
+        field: &'static str,
+    },
+
+    /// A field can't be inferred, as the data it is inferred from is missing
+    #[error("Can't infer {inferred} without {missing}")]
+    CantInfer {
+        /// What could not be inferred
+        inferred: &'static str,
+
+        /// The data that is missing
+        missing: &'static str,
+    },
+
+    /// Two partial objects can't be merged, as one of their fields conflicts
+    ///
+    /// See [`MergeWith`].
+    ///
+    /// [`MergeWith`]: super::MergeWith
+    #[error("Can't merge `{object}`, as its {field} conflicts")]
+    Conflict {
+        /// The type of the object that could not be merged
+        object: &'static str,
+
+        /// The field that conflicts
+        field: &'static str,
+    },
+
+    /// The object was built, but failed build validation
+    ///
+    /// See [`Objects::with_build_validation`].
+    ///
+    /// [`Objects::with_build_validation`]: crate::objects::Objects::with_build_validation
+    #[error("Built invalid object")]
+    Invalid(#[source] Box<ValidationError>),
+}
+
+impl PartialBuildError {
+    pub(crate) fn missing_field(
+        object: &'static str,
+        field: &'static str,
+    ) -> Self {
+        Self::MissingField { object, field }
+    }
+
+    pub(crate) fn cant_infer(
+        inferred: &'static str,
+        missing: &'static str,
+    ) -> Self {
+        Self::CantInfer { inferred, missing }
+    }
+
+    pub(crate) fn conflict(object: &'static str, field: &'static str) -> Self {
+        Self::Conflict { object, field }
+    }
+}
+
+impl From<ValidationError> for PartialBuildError {
+    fn from(err: ValidationError) -> Self {
+        Self::Invalid(Box::new(err))
+    }
+}
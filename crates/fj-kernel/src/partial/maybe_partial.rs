@@ -8,7 +8,7 @@ use crate::{
     storage::Handle,
 };
 
-use super::{HasPartial, Partial};
+use super::{HasPartial, Partial, PartialBuildError};
 
 /// Can be used everywhere either a partial or full objects are accepted
 ///
@@ -48,10 +48,10 @@ impl<T: HasPartial> MaybePartial<T> {
     ///
     /// If this already is a full object, it is returned. If this is a partial
     /// object, the full object is built from it, using [`Partial::build`].
-    pub fn into_full(self, objects: &Objects) -> T {
+    pub fn into_full(self, objects: &Objects) -> Result<T, PartialBuildError> {
         match self {
             Self::Partial(partial) => partial.build(objects),
-            Self::Full(full) => full,
+            Self::Full(full) => Ok(full),
         }
     }
 
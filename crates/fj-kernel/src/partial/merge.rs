@@ -0,0 +1,134 @@
+use super::{HasPartial, MaybePartial, PartialBuildError};
+
+/// Implemented for partial objects that can be merged with each other
+///
+/// Merging is useful, if an algorithm learns about the same object from
+/// different directions, for example a global edge from both of the faces that
+/// are adjacent to it. Each direction provides a partial object, and merging
+/// them combines the information they provide.
+pub trait MergeWith: Sized {
+    /// Merge this partial object with another one
+    ///
+    /// The merged object has all fields that are available in either of the
+    /// objects. Returns an error, if a field is available in both, but with
+    /// different values.
+    fn merge_with(self, other: Self) -> Result<Self, PartialBuildError>;
+}
+
+impl<T> MergeWith for MaybePartial<T>
+where
+    T: HasPartial,
+    T::Partial: MergeWith,
+{
+    fn merge_with(self, other: Self) -> Result<Self, PartialBuildError> {
+        match (self, other) {
+            (Self::Partial(a), Self::Partial(b)) => {
+                Ok(Self::Partial(a.merge_with(b)?))
+            }
+            // A full object already provides all fields, so merging can't add
+            // anything to it. But the other object must still not conflict.
+            (Self::Full(full), other) | (other, Self::Full(full)) => {
+                full.to_partial().merge_with(other.into_partial())?;
+                Ok(Self::Full(full))
+            }
+        }
+    }
+}
+
+impl<T> MergeWith for [T; 2]
+where
+    T: MergeWith,
+{
+    fn merge_with(self, other: Self) -> Result<Self, PartialBuildError> {
+        let [a, b] = self;
+        let [c, d] = other;
+
+        Ok([a.merge_with(c)?, b.merge_with(d)?])
+    }
+}
+
+/// Merge a field that must be equal, if it is available in both objects
+pub(crate) fn merge_eq<T>(
+    object: &'static str,
+    field: &'static str,
+    a: Option<T>,
+    b: Option<T>,
+) -> Result<Option<T>, PartialBuildError>
+where
+    T: PartialEq,
+{
+    match (a, b) {
+        (Some(a), Some(b)) if a != b => {
+            Err(PartialBuildError::conflict(object, field))
+        }
+        (a, b) => Ok(a.or(b)),
+    }
+}
+
+/// Merge a field that can itself be merged, if it is available in both objects
+pub(crate) fn merge_nested<T>(
+    a: Option<T>,
+    b: Option<T>,
+) -> Result<Option<T>, PartialBuildError>
+where
+    T: MergeWith,
+{
+    match (a, b) {
+        (Some(a), Some(b)) => Ok(Some(a.merge_with(b)?)),
+        (a, b) => Ok(a.or(b)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        objects::{
+            GlobalCurve, GlobalEdge, GlobalVertex, Objects, Surface,
+            SurfaceVertex,
+        },
+        partial::{HasPartial, PartialBuildError},
+    };
+
+    use super::MergeWith;
+
+    #[test]
+    fn merge_with() -> anyhow::Result<()> {
+        let objects = Objects::new();
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+
+        let a = SurfaceVertex::partial().with_position(Some([1., 2.]));
+        let b = SurfaceVertex::partial().with_surface(Some(surface.clone()));
+        let merged = a.clone().merge_with(b)?;
+        assert_eq!(merged.position, Some(Point::from([1., 2.])));
+        assert_eq!(merged.surface, Some(surface));
+
+        let c = SurfaceVertex::partial().with_position(Some([2., 1.]));
+        assert!(matches!(
+            a.merge_with(c),
+            Err(PartialBuildError::Conflict { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_global_edges_from_both_directions() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let curve = GlobalCurve::new(&objects);
+        let [a, b] = [[0., 0., 0.], [1., 0., 0.]]
+            .map(|position| GlobalVertex::from_position(position, &objects));
+
+        let from_a = GlobalEdge::partial()
+            .with_curve(Some(curve.clone()))
+            .with_vertices(Some([a.clone(), b.clone()]));
+        let from_b = GlobalEdge::partial().with_vertices(Some([b, a]));
+
+        let global_edge = from_a.merge_with(from_b)?.build(&objects)?;
+        assert_eq!(global_edge.curve().id(), curve.id());
+
+        Ok(())
+    }
+}
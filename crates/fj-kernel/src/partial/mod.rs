@@ -22,7 +22,7 @@
 //! - Structs that represent partial objects. For example [`PartialHalfEdge`] is
 //!   the partial variant of [`HalfEdge`].
 //! - Infrastructure for abstracting over partial objects. See [`Partial`],
-//!   [`HasPartial`], and [`MaybePartial`].
+//!   [`HasPartial`], [`MaybePartial`], and [`MergeWith`].
 //!
 //! [`HalfEdge`]: crate::objects::HalfEdge
 //!
@@ -34,16 +34,23 @@
 //!
 //! [#1147]: https://github.com/hannobraun/Fornjot/issues/1147
 
+mod error;
 mod maybe_partial;
+mod merge;
 mod objects;
 mod traits;
 
 pub use self::{
+    error::PartialBuildError,
     maybe_partial::MaybePartial,
+    merge::MergeWith,
     objects::{
         curve::PartialCurve,
-        cycle::PartialCycle,
+        cycle::{CycleSegment, PartialCycle},
         edge::{PartialGlobalEdge, PartialHalfEdge},
+        shell::PartialShell,
+        sketch::PartialSketch,
+        solid::PartialSolid,
         vertex::{PartialGlobalVertex, PartialSurfaceVertex, PartialVertex},
     },
     traits::{HasPartial, Partial},
@@ -2,6 +2,7 @@ use fj_math::{Point, Scalar, Vector};
 
 use crate::{
     objects::{Curve, GlobalCurve, Objects, Surface},
+    partial::{merge::merge_eq, MergeWith, PartialBuildError},
     path::SurfacePath,
     storage::{Handle, HandleWrapper},
 };
@@ -26,6 +27,11 @@ pub struct PartialCurve {
     /// Will be computed from `path` and `surface` in [`PartialCurve::build`],
     /// if not provided.
     pub global_form: Option<HandleWrapper<GlobalCurve>>,
+
+    /// The label of the [`Curve`]
+    ///
+    /// See [`Handle::with_label`].
+    pub label: Option<String>,
 }
 
 impl PartialCurve {
@@ -56,6 +62,14 @@ impl PartialCurve {
         self
     }
 
+    /// Provide a label for the partial curve
+    pub fn with_label(mut self, label: Option<impl Into<String>>) -> Self {
+        if let Some(label) = label {
+            self.label = Some(label.into());
+        }
+        self
+    }
+
     /// Update partial curve to represent the u-axis
     pub fn as_u_axis(self) -> Self {
         let a = Point::origin();
@@ -82,26 +96,68 @@ impl PartialCurve {
         self.with_path(Some(SurfacePath::line_from_points(points)))
     }
 
+    /// Update partial curve as a polygonal chain, from the provided points
+    pub fn as_polyline_from_points(
+        self,
+        points: impl IntoIterator<Item = impl Into<Point<2>>>,
+    ) -> Self {
+        self.with_path(Some(SurfacePath::polyline_from_points(points)))
+    }
+
+    /// Update partial curve as a cubic Bézier curve, from its control points
+    pub fn as_bezier_from_control_points(
+        self,
+        control_points: [impl Into<Point<2>>; 4],
+    ) -> Self {
+        self.with_path(Some(SurfacePath::bezier_from_control_points(
+            control_points,
+        )))
+    }
+
     /// Build a full [`Curve`] from the partial curve
-    pub fn build(self, objects: &Objects) -> Handle<Curve> {
-        let path = self.path.expect("Can't build `Curve` without path");
-        let surface =
-            self.surface.expect("Can't build `Curve` without surface");
+    pub fn build(
+        self,
+        objects: &Objects,
+    ) -> Result<Handle<Curve>, PartialBuildError> {
+        let path = self
+            .path
+            .ok_or_else(|| PartialBuildError::missing_field("Curve", "path"))?;
+        let surface = self.surface.ok_or_else(|| {
+            PartialBuildError::missing_field("Curve", "surface")
+        })?;
 
         let global_form = self
             .global_form
             .unwrap_or_else(|| GlobalCurve::new(objects).into());
 
-        Curve::new(surface, path, global_form, objects)
+        Ok(Curve::new(surface, path, global_form, objects)
+            .with_label(self.label))
+    }
+}
+
+impl MergeWith for PartialCurve {
+    fn merge_with(self, other: Self) -> Result<Self, PartialBuildError> {
+        Ok(Self {
+            path: merge_eq("Curve", "path", self.path, other.path)?,
+            surface: merge_eq("Curve", "surface", self.surface, other.surface)?,
+            global_form: merge_eq(
+                "Curve",
+                "global form",
+                self.global_form,
+                other.global_form,
+            )?,
+            label: merge_eq("Curve", "label", self.label, other.label)?,
+        })
     }
 }
 
 impl From<&Handle<Curve>> for PartialCurve {
     fn from(curve: &Handle<Curve>) -> Self {
         Self {
-            path: Some(curve.path()),
+            path: Some(curve.path().clone()),
             surface: Some(curve.surface().clone()),
             global_form: Some(curve.global_form().clone().into()),
+            label: curve.label(),
         }
     }
 }
@@ -1,10 +1,10 @@
-use fj_math::Point;
+use fj_math::{Point, Scalar};
 
 use crate::{
     objects::{
         Curve, Cycle, HalfEdge, Objects, Surface, SurfaceVertex, Vertex,
     },
-    partial::{HasPartial, MaybePartial},
+    partial::{HasPartial, MaybePartial, PartialBuildError},
     storage::Handle,
 };
 
@@ -40,28 +40,42 @@ impl PartialCycle {
     }
 
     /// Update the partial cycle with a polygonal chain from the provided points
+    ///
+    /// Returns an error, if no surface has been provided, or if the last
+    /// half-edge of the cycle lacks the vertex that the chain would continue
+    /// from.
     pub fn with_poly_chain_from_points(
         mut self,
         points: impl IntoIterator<Item = impl Into<Point<2>>>,
-    ) -> Self {
-        let iter = self
-            .half_edges
-            .last()
-            .map(|half_edge| {
-                let [_, last] = half_edge.vertices().expect(
-                    "Need half-edge vertices to extend cycle with poly-chain",
-                );
-                let last = last.surface_form().expect(
-                    "Need surface vertex to extend cycle with poly-chain",
-                );
+    ) -> Result<Self, PartialBuildError> {
+        let last = match self.half_edges.last() {
+            Some(half_edge) => {
+                let [_, last] = half_edge.vertices().ok_or_else(|| {
+                    PartialBuildError::cant_infer(
+                        "poly-chain",
+                        "vertices of last half-edge",
+                    )
+                })?;
+                let last = last.surface_form().ok_or_else(|| {
+                    PartialBuildError::cant_infer(
+                        "poly-chain",
+                        "surface vertex of last half-edge",
+                    )
+                })?;
 
                 let vertex = last.clone();
-                let position = last.position().expect(
-                    "Need surface position to extend cycle with poly-chain",
-                );
+                let position = last.position().ok_or_else(|| {
+                    PartialBuildError::cant_infer(
+                        "poly-chain",
+                        "surface position of last half-edge",
+                    )
+                })?;
 
-                (position, Some(vertex))
-            })
+                Some((position, Some(vertex)))
+            }
+            None => None,
+        };
+        let iter = last
             .into_iter()
             .chain(points.into_iter().map(|point| (point.into(), None)));
 
@@ -72,10 +86,9 @@ impl PartialCycle {
 
         for (position, vertex) in iter {
             if let Some((previous_position, previous_vertex)) = previous {
-                let surface = self
-                    .surface
-                    .clone()
-                    .expect("Need surface to extend cycle with poly-chain");
+                let surface = self.surface.clone().ok_or_else(|| {
+                    PartialBuildError::cant_infer("poly-chain", "surface")
+                })?;
 
                 let from = previous_vertex.unwrap_or_else(|| {
                     SurfaceVertex::partial()
@@ -117,57 +130,225 @@ impl PartialCycle {
             previous = Some((position, vertex));
         }
 
-        self
+        Ok(self)
+    }
+
+    /// Update the partial cycle with a closed loop of segments
+    ///
+    /// Each segment runs from the end of the previous one to its own end. The
+    /// first segment starts at the end of the last one. Adjacent half-edges
+    /// share their vertices, regardless of the type of the segments.
+    ///
+    /// The segments form a closed loop by themselves. This method is meant to
+    /// be used with a partial cycle that doesn't have any half-edges yet.
+    ///
+    /// Returns an error, if no surface has been provided.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the ends of a [`CycleSegment::ArcTo`] or
+    /// [`CycleSegment::ClockwiseArcTo`] are further apart than the arc's
+    /// diameter.
+    pub fn with_segments(
+        mut self,
+        segments: impl IntoIterator<Item = CycleSegment>,
+    ) -> Result<Self, PartialBuildError> {
+        let surface = self.surface.clone().ok_or_else(|| {
+            PartialBuildError::cant_infer("segments", "surface")
+        })?;
+
+        let segments = segments.into_iter().collect::<Vec<_>>();
+        let vertices = segments
+            .iter()
+            .map(|segment| {
+                MaybePartial::from(
+                    SurfaceVertex::partial()
+                        .with_surface(Some(surface.clone()))
+                        .with_position(Some(segment.end())),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        for (i, segment) in segments.iter().enumerate() {
+            let start = (i + segments.len() - 1) % segments.len();
+            let start_point = segments[start].end();
+            let surface_forms = [vertices[start].clone(), vertices[i].clone()];
+
+            let half_edge =
+                HalfEdge::partial().with_surface(Some(surface.clone()));
+            let mut half_edge = match *segment {
+                CycleSegment::LineTo(end) => {
+                    half_edge.as_line_segment_from_points([start_point, end])?
+                }
+                CycleSegment::ArcTo { end, radius } => half_edge
+                    .as_arc_from_points_and_radius([start_point, end], radius),
+                CycleSegment::ClockwiseArcTo { end, radius } => half_edge
+                    .as_clockwise_arc_from_points_and_radius(
+                        [start_point, end],
+                        radius,
+                    ),
+                CycleSegment::BezierTo {
+                    control_points: [b, c],
+                    end,
+                } => half_edge.as_bezier_from_control_points([
+                    start_point,
+                    b,
+                    c,
+                    end,
+                ]),
+            };
+
+            // All of the above create their own surface vertices. Replace them
+            // with the shared ones, to connect adjacent half-edges.
+            if let Some(vertices) = &mut half_edge.vertices {
+                for (vertex, surface_form) in
+                    vertices.iter_mut().zip(surface_forms)
+                {
+                    *vertex = vertex.clone().update_partial(|vertex| {
+                        vertex.with_surface_form(Some(surface_form))
+                    });
+                }
+            }
+
+            self.half_edges.push(half_edge.into());
+        }
+
+        Ok(self)
     }
 
     /// Update the partial cycle by closing it with a line segment
     ///
     /// Builds a line segment from the last and first vertex, closing the cycle.
-    pub fn close_with_line_segment(mut self) -> Self {
-        let first = self.half_edges.first();
-        let last = self.half_edges.last();
+    ///
+    /// Returns an error, if no surface has been provided, or if the first or
+    /// last half-edge lack the vertices that the line segment connects.
+    pub fn close_with_line_segment(
+        mut self,
+    ) -> Result<Self, PartialBuildError> {
+        let (first, last) =
+            match (self.half_edges.first(), self.half_edges.last()) {
+                (Some(first), Some(last)) => (first, last),
+                _ => return Ok(self),
+            };
 
-        let vertices = [first, last].map(|option| {
-            option.map(|half_edge| {
-                half_edge.vertices().expect("Need vertices to close cycle")
+        let vertices = |half_edge: &MaybePartial<HalfEdge>| {
+            half_edge.vertices().ok_or_else(|| {
+                PartialBuildError::cant_infer(
+                    "closing line segment",
+                    "vertices",
+                )
             })
-        });
-
-        if let [Some([first, _]), Some([_, last])] = vertices {
-            let vertices = [last, first].map(|vertex| {
-                vertex
-                    .surface_form()
-                    .expect("Need surface vertex to close cycle")
-                    .position()
-                    .expect("Need surface position to close cycle")
-            });
-            let surface =
-                self.surface.clone().expect("Need surface to close cycle");
-
-            self.half_edges.push(
-                HalfEdge::partial()
-                    .with_surface(Some(surface))
-                    .as_line_segment_from_points(vertices)
-                    .into(),
-            );
-        }
+        };
+        let [first, _] = vertices(first)?;
+        let [_, last] = vertices(last)?;
 
-        self
+        let position = |vertex: MaybePartial<Vertex>| {
+            vertex
+                .surface_form()
+                .and_then(|surface_form| surface_form.position())
+                .ok_or_else(|| {
+                    PartialBuildError::cant_infer(
+                        "closing line segment",
+                        "surface positions",
+                    )
+                })
+        };
+        let vertices = [position(last)?, position(first)?];
+        let surface = self.surface.clone().ok_or_else(|| {
+            PartialBuildError::cant_infer("closing line segment", "surface")
+        })?;
+
+        self.half_edges.push(
+            HalfEdge::partial()
+                .with_surface(Some(surface))
+                .as_line_segment_from_points(vertices)?
+                .into(),
+        );
+
+        Ok(self)
     }
 
     /// Build a full [`Cycle`] from the partial cycle
-    pub fn build(self, objects: &Objects) -> Cycle {
-        let surface = self.surface.expect("Need surface to build `Cycle`");
-        let surface_for_edges = surface.clone();
-        let half_edges = self.half_edges.into_iter().map(|half_edge| {
-            half_edge
-                .update_partial(|half_edge| {
-                    half_edge.with_surface(Some(surface_for_edges.clone()))
-                })
-                .into_full(objects)
-        });
+    ///
+    /// Returns an error, if no surface has been provided.
+    ///
+    /// Returns an error, if build validation is enabled in `objects`, and the
+    /// cycle is invalid. See [`Objects::with_build_validation`].
+    pub fn build(self, objects: &Objects) -> Result<Cycle, PartialBuildError> {
+        let surface = self.surface.ok_or_else(|| {
+            PartialBuildError::missing_field("Cycle", "surface")
+        })?;
+        let half_edges = self
+            .half_edges
+            .into_iter()
+            .map(|half_edge| {
+                half_edge
+                    .update_partial(|half_edge| {
+                        half_edge.with_surface(Some(surface.clone()))
+                    })
+                    .into_full(objects)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Cycle::new(surface, half_edges)
+        let cycle = Cycle::new(surface, half_edges);
+        Ok(objects.validate_built(cycle)?)
+    }
+}
+
+/// A segment of a cycle, as used by [`PartialCycle::with_segments`]
+///
+/// Each segment ends at a point. It starts at the end of the previous segment.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum CycleSegment {
+    /// A line segment
+    LineTo(Point<2>),
+
+    /// A circular arc
+    ///
+    /// See [`PartialHalfEdge::as_arc_from_points_and_radius`], regarding the
+    /// sign of `radius`.
+    ///
+    /// [`PartialHalfEdge::as_arc_from_points_and_radius`]: crate::partial::PartialHalfEdge::as_arc_from_points_and_radius
+    ArcTo {
+        /// The point where the arc ends
+        end: Point<2>,
+
+        /// The radius of the arc
+        radius: Scalar,
+    },
+
+    /// A circular arc that goes clockwise
+    ///
+    /// See [`PartialHalfEdge::as_clockwise_arc_from_points_and_radius`].
+    ///
+    /// [`PartialHalfEdge::as_clockwise_arc_from_points_and_radius`]: crate::partial::PartialHalfEdge::as_clockwise_arc_from_points_and_radius
+    ClockwiseArcTo {
+        /// The point where the arc ends
+        end: Point<2>,
+
+        /// The radius of the arc
+        radius: Scalar,
+    },
+
+    /// A cubic Bézier curve
+    BezierTo {
+        /// The two control points between the start and the end
+        control_points: [Point<2>; 2],
+
+        /// The point where the curve ends
+        end: Point<2>,
+    },
+}
+
+impl CycleSegment {
+    /// Access the point where the segment ends
+    pub fn end(&self) -> Point<2> {
+        match self {
+            Self::LineTo(end)
+            | Self::ArcTo { end, .. }
+            | Self::ClockwiseArcTo { end, .. }
+            | Self::BezierTo { end, .. } => *end,
+        }
     }
 }
 
@@ -179,3 +360,91 @@ impl From<&Cycle> for PartialCycle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        objects::{Cycle, HalfEdge, Objects, Surface},
+        partial::{CycleSegment, HasPartial, PartialBuildError},
+    };
+
+    #[test]
+    fn poly_chain_without_surface() {
+        let result = Cycle::partial().with_poly_chain_from_points([
+            [0., 0.],
+            [1., 0.],
+            [0., 1.],
+        ]);
+
+        assert!(matches!(
+            result,
+            Err(PartialBuildError::CantInfer {
+                inferred: "poly-chain",
+                missing: "surface"
+            })
+        ));
+    }
+
+    #[test]
+    fn segments_without_surface() {
+        let result = Cycle::partial()
+            .with_segments([CycleSegment::LineTo([1., 0.].into())]);
+
+        assert!(matches!(
+            result,
+            Err(PartialBuildError::CantInfer {
+                inferred: "segments",
+                missing: "surface"
+            })
+        ));
+    }
+
+    #[test]
+    fn close_without_vertices() {
+        let result = Cycle::partial()
+            .with_half_edges([HalfEdge::partial()])
+            .close_with_line_segment();
+
+        assert!(matches!(
+            result,
+            Err(PartialBuildError::CantInfer {
+                inferred: "closing line segment",
+                missing: "vertices"
+            })
+        ));
+    }
+
+    #[test]
+    fn build_without_surface() {
+        let objects = Objects::new();
+
+        let result = Cycle::partial().build(&objects);
+
+        assert!(matches!(
+            result,
+            Err(PartialBuildError::MissingField {
+                object: "Cycle",
+                field: "surface"
+            })
+        ));
+    }
+
+    #[test]
+    fn build_with_incomplete_half_edge() {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let result = Cycle::partial()
+            .with_surface(Some(surface))
+            .with_half_edges([HalfEdge::partial()])
+            .build(&objects);
+
+        assert!(matches!(
+            result,
+            Err(PartialBuildError::MissingField {
+                object: "HalfEdge",
+                field: "curve"
+            })
+        ));
+    }
+}
@@ -1,11 +1,15 @@
-use fj_math::{Point, Scalar};
+use fj_math::{Circle, Point, Scalar, Vector};
 
 use crate::{
     objects::{
         Curve, GlobalCurve, GlobalEdge, GlobalVertex, HalfEdge, Objects,
-        Surface, SurfaceVertex, Vertex,
+        Surface, SurfaceVertex, Vertex, VerticesInNormalizedOrder,
     },
-    partial::{HasPartial, MaybePartial, PartialCurve},
+    partial::{
+        merge::{merge_eq, merge_nested},
+        HasPartial, MaybePartial, MergeWith, PartialBuildError, PartialCurve,
+    },
+    path::SurfacePath,
     storage::{Handle, HandleWrapper},
 };
 
@@ -72,7 +76,12 @@ impl PartialHalfEdge {
     }
 
     /// Update partial half-edge as a circle, from the given radius
-    pub fn as_circle_from_radius(mut self, radius: impl Into<Scalar>) -> Self {
+    ///
+    /// Returns an error, if no surface has been provided.
+    pub fn as_circle_from_radius(
+        mut self,
+        radius: impl Into<Scalar>,
+    ) -> Result<Self, PartialBuildError> {
         let curve = Handle::<Curve>::partial()
             .with_surface(self.surface.clone())
             .as_circle_from_radius(radius);
@@ -82,7 +91,7 @@ impl PartialHalfEdge {
                 [Scalar::ZERO, Scalar::TAU].map(|coord| Point::from([coord]));
 
             let global_vertex = Handle::<GlobalVertex>::partial()
-                .from_curve_and_position(curve.clone(), a_curve);
+                .from_curve_and_position(curve.clone(), a_curve)?;
 
             [a_curve, b_curve].map(|point_curve| {
                 Vertex::partial()
@@ -95,14 +104,158 @@ impl PartialHalfEdge {
         self.curve = Some(curve.into());
         self.vertices = Some(vertices.map(Into::into));
 
+        Ok(self)
+    }
+
+    /// Update partial half-edge as a circular arc, from the given points
+    ///
+    /// The arc goes counter-clockwise from the first point to the second. A
+    /// positive `radius` results in the shorter of the two possible arcs, a
+    /// negative one in the longer one.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the points are further apart than the arc's diameter.
+    pub fn as_arc_from_points_and_radius(
+        self,
+        points: [impl Into<Point<2>>; 2],
+        radius: impl Into<Scalar>,
+    ) -> Self {
+        self.with_arc(points.map(Into::into), radius.into(), false)
+    }
+
+    /// Update partial half-edge as a clockwise circular arc, from the given
+    /// points
+    ///
+    /// Like [`PartialHalfEdge::as_arc_from_points_and_radius`], except that
+    /// the arc goes clockwise from the first point to the second.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the points are further apart than the arc's diameter.
+    pub fn as_clockwise_arc_from_points_and_radius(
+        self,
+        points: [impl Into<Point<2>>; 2],
+        radius: impl Into<Scalar>,
+    ) -> Self {
+        self.with_arc(points.map(Into::into), radius.into(), true)
+    }
+
+    fn with_arc(
+        mut self,
+        points: [Point<2>; 2],
+        radius: Scalar,
+        clockwise: bool,
+    ) -> Self {
+        // A clockwise arc has the same center as the counter-clockwise one
+        // that goes the other way.
+        let [a, b] = if clockwise {
+            [points[1], points[0]]
+        } else {
+            points
+        };
+        let chord = b - a;
+        let half_chord = chord.magnitude() / 2.;
+
+        assert!(
+            half_chord <= radius.abs(),
+            "Points are too far apart for an arc of radius {radius:?}"
+        );
+
+        // The center lies on the perpendicular bisector of the chord. For the
+        // shorter counter-clockwise arc, it lies to the left of the chord.
+        let center = {
+            let direction = chord.normalize();
+            let left = Vector::from([-direction.v, direction.u]);
+            let distance = (radius * radius - half_chord * half_chord)
+                .max(Scalar::ZERO)
+                .sqrt();
+
+            a + chord / 2. + left * distance * radius.sign().to_scalar()
+        };
+
+        // The coordinates of a reversed circle run clockwise.
+        let circle = Circle::from_center_and_radius(center, radius.abs());
+        let circle = if clockwise { circle.reverse() } else { circle };
+
+        let curve = Handle::<Curve>::partial()
+            .with_surface(self.surface.clone())
+            .with_path(Some(SurfacePath::Circle(circle)));
+
+        let [a_curve, b_curve] = {
+            let [a_curve, b_curve] =
+                points.map(|point| circle.point_to_circle_coords(point));
+
+            // Make sure the arc runs along the direction of the circle, which
+            // means the second coordinate needs to be larger than the first.
+            let b_curve = if b_curve.t <= a_curve.t {
+                b_curve + Vector::from([Scalar::TAU])
+            } else {
+                b_curve
+            };
+
+            [a_curve, b_curve]
+        };
+
+        let surface = self.surface.clone();
+        let [a, b] = points;
+        let vertices =
+            [(a_curve, a), (b_curve, b)].map(|(point_curve, point)| {
+                Vertex::partial()
+                    .with_position(Some(point_curve))
+                    .with_curve(Some(curve.clone()))
+                    .with_surface_form(Some(
+                        SurfaceVertex::partial()
+                            .with_surface(surface.clone())
+                            .with_position(Some(point)),
+                    ))
+            });
+
+        self.curve = Some(curve.into());
+        self.vertices = Some(vertices.map(Into::into));
+
+        self
+    }
+
+    /// Update partial half-edge as a cubic Bézier curve
+    ///
+    /// The half-edge starts at the first control point and ends at the last.
+    pub fn as_bezier_from_control_points(
+        mut self,
+        control_points: [impl Into<Point<2>>; 4],
+    ) -> Self {
+        let control_points = control_points.map(Into::into);
+        let [start, .., end] = control_points;
+
+        let curve = Handle::<Curve>::partial()
+            .with_surface(self.surface.clone())
+            .as_bezier_from_control_points(control_points);
+
+        let surface = self.surface.clone();
+        let vertices = [(0., start), (1., end)].map(|(point_curve, point)| {
+            Vertex::partial()
+                .with_position(Some([point_curve]))
+                .with_curve(Some(curve.clone()))
+                .with_surface_form(Some(
+                    SurfaceVertex::partial()
+                        .with_surface(surface.clone())
+                        .with_position(Some(point)),
+                ))
+        });
+
+        self.curve = Some(curve.into());
+        self.vertices = Some(vertices.map(Into::into));
+
         self
     }
 
     /// Update partial half-edge as a line segment, from the given points
+    ///
+    /// Returns an error, if no surface has been provided.
     pub fn as_line_segment_from_points(
         self,
         points: [impl Into<Point<2>>; 2],
-    ) -> Self {
+    ) -> Result<Self, PartialBuildError> {
         let surface = self.surface.clone();
         self.with_vertices(Some(points.map(|point| {
             Vertex::partial().with_surface_form(Some(
@@ -115,7 +268,10 @@ impl PartialHalfEdge {
     }
 
     /// Update partial half-edge as a line segment, reusing existing vertices
-    pub fn as_line_segment(mut self) -> Self {
+    ///
+    /// Returns an error, if the vertices, their surface forms, or the positions
+    /// of those are missing, or if no surface has been provided.
+    pub fn as_line_segment(mut self) -> Result<Self, PartialBuildError> {
         fn extract_global_curve(
             partial: &PartialHalfEdge,
         ) -> Option<HandleWrapper<GlobalCurve>> {
@@ -136,28 +292,38 @@ impl PartialHalfEdge {
                 .map(Into::into)
         }
 
-        let [from, to] = self
-            .vertices
-            .clone()
-            .expect("Can't infer line segment without vertices");
-        let [from_surface, to_surface] = [&from, &to].map(|vertex| {
-            vertex
-                .surface_form()
-                .expect("Can't infer line segment without two surface vertices")
-        });
+        let [from, to] = self.vertices.clone().ok_or_else(|| {
+            PartialBuildError::cant_infer("line segment", "vertices")
+        })?;
+        let surface_form = |vertex: &MaybePartial<Vertex>| {
+            vertex.surface_form().ok_or_else(|| {
+                PartialBuildError::cant_infer(
+                    "line segment",
+                    "surface vertices",
+                )
+            })
+        };
+        let [from_surface, to_surface] =
+            [surface_form(&from)?, surface_form(&to)?];
 
         let surface = self
             .surface
             .as_ref()
             .or_else(|| from_surface.surface())
             .or_else(|| to_surface.surface())
-            .expect("Can't infer line segment without a surface")
+            .ok_or_else(|| {
+                PartialBuildError::cant_infer("line segment", "surface")
+            })?
             .clone();
-        let points = [&from_surface, &to_surface].map(|vertex| {
-            vertex
-                .position()
-                .expect("Can't infer line segment without surface position")
-        });
+        let position = |vertex: &MaybePartial<SurfaceVertex>| {
+            vertex.position().ok_or_else(|| {
+                PartialBuildError::cant_infer(
+                    "line segment",
+                    "surface positions",
+                )
+            })
+        };
+        let points = [position(&from_surface)?, position(&to_surface)?];
 
         let curve = PartialCurve {
             global_form: extract_global_curve(&self),
@@ -177,27 +343,36 @@ impl PartialHalfEdge {
         self.curve = Some(curve.into());
         self.vertices = Some(vertices);
 
-        self
+        Ok(self)
     }
 
     /// Build a full [`HalfEdge`] from the partial half-edge
-    pub fn build(self, objects: &Objects) -> HalfEdge {
+    ///
+    /// Returns an error, if no curve or no vertices have been provided.
+    ///
+    /// Returns an error, if build validation is enabled in `objects`, and the
+    /// half-edge is invalid. See [`Objects::with_build_validation`].
+    pub fn build(
+        self,
+        objects: &Objects,
+    ) -> Result<HalfEdge, PartialBuildError> {
         let surface = self.surface;
         let curve = self
             .curve
-            .expect("Can't build `HalfEdge` without curve")
+            .ok_or_else(|| {
+                PartialBuildError::missing_field("HalfEdge", "curve")
+            })?
             .update_partial(|curve| curve.with_surface(surface))
-            .into_full(objects);
-        let vertices = self
-            .vertices
-            .expect("Can't build `HalfEdge` without vertices")
-            .map(|vertex| {
-                vertex
-                    .update_partial(|vertex| {
-                        vertex.with_curve(Some(curve.clone()))
-                    })
-                    .into_full(objects)
-            });
+            .into_full(objects)?;
+        let [a, b] = self.vertices.ok_or_else(|| {
+            PartialBuildError::missing_field("HalfEdge", "vertices")
+        })?;
+        let vertex = |vertex: MaybePartial<Vertex>| {
+            vertex
+                .update_partial(|vertex| vertex.with_curve(Some(curve.clone())))
+                .into_full(objects)
+        };
+        let vertices = [vertex(a)?, vertex(b)?];
 
         let global_form = self
             .global_form
@@ -206,9 +381,26 @@ impl PartialHalfEdge {
                     .from_curve_and_vertices(&curve, &vertices)
                     .into()
             })
-            .into_full(objects);
+            .into_full(objects)?;
 
-        HalfEdge::new(vertices, global_form)
+        let half_edge = HalfEdge::new(vertices, global_form);
+        Ok(objects.validate_built(half_edge)?)
+    }
+}
+
+impl MergeWith for PartialHalfEdge {
+    fn merge_with(self, other: Self) -> Result<Self, PartialBuildError> {
+        Ok(Self {
+            surface: merge_eq(
+                "HalfEdge",
+                "surface",
+                self.surface,
+                other.surface,
+            )?,
+            curve: merge_nested(self.curve, other.curve)?,
+            vertices: merge_nested(self.vertices, other.vertices)?,
+            global_form: merge_nested(self.global_form, other.global_form)?,
+        })
     }
 }
 
@@ -272,15 +464,37 @@ impl PartialGlobalEdge {
     }
 
     /// Build a full [`GlobalEdge`] from the partial global edge
-    pub fn build(self, _: &Objects) -> GlobalEdge {
-        let curve = self
-            .curve
-            .expect("Can't build `GlobalEdge` without `GlobalCurve`");
-        let vertices = self
-            .vertices
-            .expect("Can't build `GlobalEdge` without vertices");
+    ///
+    /// Returns an error, if no curve or no vertices have been provided.
+    pub fn build(self, _: &Objects) -> Result<GlobalEdge, PartialBuildError> {
+        let curve = self.curve.ok_or_else(|| {
+            PartialBuildError::missing_field("GlobalEdge", "curve")
+        })?;
+        let vertices = self.vertices.ok_or_else(|| {
+            PartialBuildError::missing_field("GlobalEdge", "vertices")
+        })?;
+
+        Ok(GlobalEdge::new(curve, vertices))
+    }
+}
 
-        GlobalEdge::new(curve, vertices)
+impl MergeWith for PartialGlobalEdge {
+    fn merge_with(self, other: Self) -> Result<Self, PartialBuildError> {
+        // The vertices are normalized, once the global edge is built. Which
+        // means, two global edges that only differ in the order of their
+        // vertices are the same edge, seen from different directions.
+        let vertices = merge_eq(
+            "GlobalEdge",
+            "vertices",
+            self.vertices.map(VerticesInNormalizedOrder::new),
+            other.vertices.map(VerticesInNormalizedOrder::new),
+        )?;
+
+        Ok(Self {
+            curve: merge_eq("GlobalEdge", "curve", self.curve, other.curve)?,
+            vertices: vertices
+                .map(|vertices| vertices.access_in_normalized_order().clone()),
+        })
     }
 }
 
@@ -294,3 +508,86 @@ impl From<&GlobalEdge> for PartialGlobalEdge {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        objects::{Curve, HalfEdge, Objects, Surface},
+        partial::{HasPartial, PartialBuildError},
+        storage::Handle,
+    };
+
+    #[test]
+    fn build_without_curve() {
+        let objects = Objects::new();
+
+        let result = HalfEdge::partial().build(&objects);
+
+        assert!(matches!(
+            result,
+            Err(PartialBuildError::MissingField {
+                object: "HalfEdge",
+                field: "curve"
+            })
+        ));
+    }
+
+    #[test]
+    fn build_without_vertices() {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let result = HalfEdge::partial()
+            .with_surface(Some(surface))
+            .with_curve(Some(Handle::<Curve>::partial().as_u_axis()))
+            .build(&objects);
+
+        assert!(matches!(
+            result,
+            Err(PartialBuildError::MissingField {
+                object: "HalfEdge",
+                field: "vertices"
+            })
+        ));
+    }
+
+    #[test]
+    fn circle_without_surface() {
+        let result = HalfEdge::partial().as_circle_from_radius(1.);
+
+        assert!(matches!(
+            result,
+            Err(PartialBuildError::CantInfer {
+                inferred: "global vertex",
+                missing: "surface of curve"
+            })
+        ));
+    }
+
+    #[test]
+    fn line_segment_without_vertices() {
+        let result = HalfEdge::partial().as_line_segment();
+
+        assert!(matches!(
+            result,
+            Err(PartialBuildError::CantInfer {
+                inferred: "line segment",
+                missing: "vertices"
+            })
+        ));
+    }
+
+    #[test]
+    fn line_segment_without_surface() {
+        let result = HalfEdge::partial()
+            .as_line_segment_from_points([[0., 0.], [1., 0.]]);
+
+        assert!(matches!(
+            result,
+            Err(PartialBuildError::CantInfer {
+                inferred: "line segment",
+                missing: "surface"
+            })
+        ));
+    }
+}
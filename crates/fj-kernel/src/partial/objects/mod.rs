@@ -1,20 +1,24 @@
 pub mod curve;
 pub mod cycle;
 pub mod edge;
+pub mod shell;
+pub mod sketch;
+pub mod solid;
 pub mod vertex;
 
 use crate::{
     objects::{
-        Curve, Cycle, GlobalEdge, GlobalVertex, HalfEdge, Objects,
-        SurfaceVertex, Vertex,
+        Curve, Cycle, GlobalEdge, GlobalVertex, HalfEdge, Objects, Shell,
+        Sketch, Solid, SurfaceVertex, Vertex,
     },
     storage::Handle,
 };
 
 use super::{
-    HasPartial, MaybePartial, Partial, PartialCurve, PartialCycle,
-    PartialGlobalEdge, PartialGlobalVertex, PartialHalfEdge,
-    PartialSurfaceVertex, PartialVertex,
+    HasPartial, MaybePartial, Partial, PartialBuildError, PartialCurve,
+    PartialCycle, PartialGlobalEdge, PartialGlobalVertex, PartialHalfEdge,
+    PartialShell, PartialSketch, PartialSolid, PartialSurfaceVertex,
+    PartialVertex,
 };
 
 macro_rules! impl_traits {
@@ -27,7 +31,10 @@ macro_rules! impl_traits {
             impl Partial for $partial {
                 type Full = $full;
 
-                fn build(self, objects: &Objects) -> Self::Full {
+                fn build(
+                    self,
+                    objects: &Objects,
+                ) -> Result<Self::Full, PartialBuildError> {
                     self.build(objects)
                 }
             }
@@ -47,6 +54,9 @@ impl_traits!(
     GlobalEdge, PartialGlobalEdge;
     Handle<GlobalVertex>, PartialGlobalVertex;
     HalfEdge, PartialHalfEdge;
+    Shell, PartialShell;
+    Sketch, PartialSketch;
+    Solid, PartialSolid;
     SurfaceVertex, PartialSurfaceVertex;
     Vertex, PartialVertex;
 );
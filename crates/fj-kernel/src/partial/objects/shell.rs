@@ -0,0 +1,41 @@
+use crate::{
+    objects::{Face, Objects, Shell},
+    partial::PartialBuildError,
+};
+
+/// A partial [`Shell`]
+///
+/// See [`crate::partial`] for more information.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct PartialShell {
+    /// The faces that make up the [`Shell`]
+    pub faces: Vec<Face>,
+}
+
+impl PartialShell {
+    /// Update the partial shell with the given faces
+    pub fn with_faces(
+        mut self,
+        faces: impl IntoIterator<Item = impl Into<Face>>,
+    ) -> Self {
+        self.faces.extend(faces.into_iter().map(Into::into));
+        self
+    }
+
+    /// Build a full [`Shell`] from the partial shell
+    ///
+    /// Returns an error, if build validation is enabled in `objects`, and the
+    /// shell is invalid. See [`Objects::with_build_validation`].
+    pub fn build(self, objects: &Objects) -> Result<Shell, PartialBuildError> {
+        let shell = Shell::new().with_faces(self.faces);
+        Ok(objects.validate_built(shell)?)
+    }
+}
+
+impl From<&Shell> for PartialShell {
+    fn from(shell: &Shell) -> Self {
+        Self {
+            faces: shell.faces().into_iter().cloned().collect(),
+        }
+    }
+}
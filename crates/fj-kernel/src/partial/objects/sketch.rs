@@ -0,0 +1,41 @@
+use crate::{
+    objects::{Face, Objects, Sketch},
+    partial::PartialBuildError,
+};
+
+/// A partial [`Sketch`]
+///
+/// See [`crate::partial`] for more information.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct PartialSketch {
+    /// The faces that make up the [`Sketch`]
+    pub faces: Vec<Face>,
+}
+
+impl PartialSketch {
+    /// Update the partial sketch with the given faces
+    pub fn with_faces(
+        mut self,
+        faces: impl IntoIterator<Item = impl Into<Face>>,
+    ) -> Self {
+        self.faces.extend(faces.into_iter().map(Into::into));
+        self
+    }
+
+    /// Build a full [`Sketch`] from the partial sketch
+    ///
+    /// Returns an error, if build validation is enabled in `objects`, and the
+    /// sketch is invalid. See [`Objects::with_build_validation`].
+    pub fn build(self, objects: &Objects) -> Result<Sketch, PartialBuildError> {
+        let sketch = Sketch::new().with_faces(self.faces);
+        Ok(objects.validate_built(sketch)?)
+    }
+}
+
+impl From<&Sketch> for PartialSketch {
+    fn from(sketch: &Sketch) -> Self {
+        Self {
+            faces: sketch.faces().into_iter().cloned().collect(),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    objects::{Objects, Shell, Solid},
+    partial::{MaybePartial, PartialBuildError},
+};
+
+/// A partial [`Solid`]
+///
+/// See [`crate::partial`] for more information.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct PartialSolid {
+    /// The shells that make up the [`Solid`]
+    pub shells: Vec<MaybePartial<Shell>>,
+}
+
+impl PartialSolid {
+    /// Update the partial solid with the given shells
+    pub fn with_shells(
+        mut self,
+        shells: impl IntoIterator<Item = impl Into<MaybePartial<Shell>>>,
+    ) -> Self {
+        self.shells.extend(shells.into_iter().map(Into::into));
+        self
+    }
+
+    /// Build a full [`Solid`] from the partial solid
+    ///
+    /// Returns an error, if any of the partial shells can't be built.
+    ///
+    /// Returns an error, if build validation is enabled in `objects`, and the
+    /// solid is invalid. See [`Objects::with_build_validation`].
+    pub fn build(self, objects: &Objects) -> Result<Solid, PartialBuildError> {
+        let shells = self
+            .shells
+            .into_iter()
+            .map(|shell| shell.into_full(objects))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let solid = Solid::new().with_shells(shells);
+        Ok(objects.validate_built(solid)?)
+    }
+}
+
+impl From<&Solid> for PartialSolid {
+    fn from(solid: &Solid) -> Self {
+        Self {
+            shells: solid.shells().cloned().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::validate::ValidationConfig,
+        objects::{Objects, Shell, Solid},
+        partial::{HasPartial, PartialBuildError},
+    };
+
+    #[test]
+    fn build_from_partial_shells() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let cube = Solid::builder(&objects).build_cube_from_edge_length(1.)?;
+        let faces = cube.shells().flat_map(Shell::faces).cloned();
+
+        let solid = Solid::partial()
+            .with_shells([Shell::partial().with_faces(faces)])
+            .build(&objects)?;
+        assert_eq!(solid, cube);
+
+        Ok(())
+    }
+
+    #[test]
+    fn build_invalid_solid() -> anyhow::Result<()> {
+        let objects =
+            Objects::new().with_build_validation(ValidationConfig::default());
+
+        let cube = Solid::builder(&objects).build_cube_from_edge_length(1.)?;
+
+        // Without one of its faces, the shell of the cube is open.
+        let faces = cube.shells().flat_map(Shell::faces).skip(1).cloned();
+        let shell = Shell::partial().with_faces(faces);
+
+        let result = shell.clone().build(&objects);
+        assert!(matches!(result, Err(PartialBuildError::Invalid(_))));
+
+        let result = Solid::partial().with_shells([shell]).build(&objects);
+        assert!(matches!(result, Err(PartialBuildError::Invalid(_))));
+
+        Ok(())
+    }
+}
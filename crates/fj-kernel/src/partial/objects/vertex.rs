@@ -2,7 +2,10 @@ use fj_math::Point;
 
 use crate::{
     objects::{Curve, GlobalVertex, Objects, Surface, SurfaceVertex, Vertex},
-    partial::{HasPartial, MaybePartial},
+    partial::{
+        merge::{merge_eq, merge_nested},
+        HasPartial, MaybePartial, MergeWith, PartialBuildError,
+    },
     storage::Handle,
 };
 
@@ -81,19 +84,18 @@ impl PartialVertex {
 
     /// Build a full [`Vertex`] from the partial vertex
     ///
-    /// # Panics
+    /// Returns an error, if no position or no curve has been provided.
     ///
-    /// Panics, if no position has been provided.
-    ///
-    /// Panics, if no curve has been provided.
-    pub fn build(self, objects: &Objects) -> Vertex {
-        let position = self
-            .position
-            .expect("Cant' build `Vertex` without position");
+    /// Returns an error, if build validation is enabled in `objects`, and the
+    /// vertex is invalid. See [`Objects::with_build_validation`].
+    pub fn build(self, objects: &Objects) -> Result<Vertex, PartialBuildError> {
+        let position = self.position.ok_or_else(|| {
+            PartialBuildError::missing_field("Vertex", "position")
+        })?;
         let curve = self
             .curve
-            .expect("Can't build `Vertex` without `Curve`")
-            .into_full(objects);
+            .ok_or_else(|| PartialBuildError::missing_field("Vertex", "curve"))?
+            .into_full(objects)?;
 
         let surface_form = self
             .surface_form
@@ -108,9 +110,26 @@ impl PartialVertex {
                     .with_surface(Some(curve.surface().clone()))
                     .with_global_form(self.global_form)
             })
-            .into_full(objects);
+            .into_full(objects)?;
+
+        let vertex = Vertex::new(position, curve, surface_form);
+        Ok(objects.validate_built(vertex)?)
+    }
+}
 
-        Vertex::new(position, curve, surface_form)
+impl MergeWith for PartialVertex {
+    fn merge_with(self, other: Self) -> Result<Self, PartialBuildError> {
+        Ok(Self {
+            position: merge_eq(
+                "Vertex",
+                "position",
+                self.position,
+                other.position,
+            )?,
+            curve: merge_nested(self.curve, other.curve)?,
+            surface_form: merge_nested(self.surface_form, other.surface_form)?,
+            global_form: merge_nested(self.global_form, other.global_form)?,
+        })
     }
 }
 
@@ -180,18 +199,17 @@ impl PartialSurfaceVertex {
 
     /// Build a full [`SurfaceVertex`] from the partial surface vertex
     ///
-    /// # Panics
-    ///
-    /// Panics, if no position has been provided.
-    ///
-    /// Panics, if no surface has been provided.
-    pub fn build(self, objects: &Objects) -> SurfaceVertex {
-        let position = self
-            .position
-            .expect("Can't build `SurfaceVertex` without position");
-        let surface = self
-            .surface
-            .expect("Can't build `SurfaceVertex` without `Surface`");
+    /// Returns an error, if no position or no surface has been provided.
+    pub fn build(
+        self,
+        objects: &Objects,
+    ) -> Result<SurfaceVertex, PartialBuildError> {
+        let position = self.position.ok_or_else(|| {
+            PartialBuildError::missing_field("SurfaceVertex", "position")
+        })?;
+        let surface = self.surface.ok_or_else(|| {
+            PartialBuildError::missing_field("SurfaceVertex", "surface")
+        })?;
 
         let global_form = self
             .global_form
@@ -200,9 +218,29 @@ impl PartialSurfaceVertex {
                     .from_surface_and_position(&surface, position)
                     .into()
             })
-            .into_full(objects);
+            .into_full(objects)?;
 
-        SurfaceVertex::new(position, surface, global_form)
+        Ok(SurfaceVertex::new(position, surface, global_form))
+    }
+}
+
+impl MergeWith for PartialSurfaceVertex {
+    fn merge_with(self, other: Self) -> Result<Self, PartialBuildError> {
+        Ok(Self {
+            position: merge_eq(
+                "SurfaceVertex",
+                "position",
+                self.position,
+                other.position,
+            )?,
+            surface: merge_eq(
+                "SurfaceVertex",
+                "surface",
+                self.surface,
+                other.surface,
+            )?,
+            global_form: merge_nested(self.global_form, other.global_form)?,
+        })
     }
 }
 
@@ -225,6 +263,11 @@ pub struct PartialGlobalVertex {
     ///
     /// Must be provided before [`PartialGlobalVertex::build`] is called.
     pub position: Option<Point<3>>,
+
+    /// The label of the [`GlobalVertex`]
+    ///
+    /// See [`Handle::with_label`].
+    pub label: Option<String>,
 }
 
 impl PartialGlobalVertex {
@@ -239,23 +282,33 @@ impl PartialGlobalVertex {
         self
     }
 
+    /// Provide a label for the partial global vertex
+    pub fn with_label(mut self, label: Option<impl Into<String>>) -> Self {
+        if let Some(label) = label {
+            self.label = Some(label.into());
+        }
+        self
+    }
+
     /// Update partial global vertex from the given curve and position on it
+    ///
+    /// Returns an error, if the curve has no path or no surface.
     pub fn from_curve_and_position(
         self,
         curve: impl Into<MaybePartial<Handle<Curve>>>,
         position: impl Into<Point<1>>,
-    ) -> Self {
+    ) -> Result<Self, PartialBuildError> {
         let curve = curve.into().into_partial();
 
-        let path = curve.path.expect(
-            "Need path to create `GlobalVertex` from curve and position",
-        );
-        let surface = curve.surface.expect(
-            "Need surface to create `GlobalVertex` from curve and position",
-        );
+        let path = curve.path.ok_or_else(|| {
+            PartialBuildError::cant_infer("global vertex", "path of curve")
+        })?;
+        let surface = curve.surface.ok_or_else(|| {
+            PartialBuildError::cant_infer("global vertex", "surface of curve")
+        })?;
 
         let position_surface = path.point_from_path_coords(position);
-        self.from_surface_and_position(&surface, position_surface)
+        Ok(self.from_surface_and_position(&surface, position_surface))
     }
 
     /// Update partial global vertex from the given surface and position on it
@@ -269,12 +322,32 @@ impl PartialGlobalVertex {
     }
 
     /// Build a full [`GlobalVertex`] from the partial global vertex
-    pub fn build(self, objects: &Objects) -> Handle<GlobalVertex> {
-        let position = self
-            .position
-            .expect("Can't build a `GlobalVertex` without a position");
+    ///
+    /// Returns an error, if no position has been provided.
+    pub fn build(
+        self,
+        objects: &Objects,
+    ) -> Result<Handle<GlobalVertex>, PartialBuildError> {
+        let position = self.position.ok_or_else(|| {
+            PartialBuildError::missing_field("GlobalVertex", "position")
+        })?;
+
+        Ok(GlobalVertex::from_position(position, objects)
+            .with_label(self.label))
+    }
+}
 
-        GlobalVertex::from_position(position, objects)
+impl MergeWith for PartialGlobalVertex {
+    fn merge_with(self, other: Self) -> Result<Self, PartialBuildError> {
+        Ok(Self {
+            position: merge_eq(
+                "GlobalVertex",
+                "position",
+                self.position,
+                other.position,
+            )?,
+            label: merge_eq("GlobalVertex", "label", self.label, other.label)?,
+        })
     }
 }
 
@@ -282,6 +355,7 @@ impl From<&Handle<GlobalVertex>> for PartialGlobalVertex {
     fn from(global_vertex: &Handle<GlobalVertex>) -> Self {
         Self {
             position: Some(global_vertex.position()),
+            label: global_vertex.label(),
         }
     }
 }
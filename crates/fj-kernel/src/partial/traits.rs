@@ -1,5 +1,7 @@
 use crate::objects::Objects;
 
+use super::PartialBuildError;
+
 /// Implemented for objects that a partial object type exists for
 ///
 /// # Implementation Note
@@ -54,8 +56,11 @@ pub trait HasPartial {
 ///   `from_*`, or similar, if one or more of their fields can be initialized by
 ///   providing alternative data.
 /// - Partial object structs have a `build` method to build a full object.
+/// - Partial object structs that can be combined with each other implement
+///   [`MergeWith`].
 /// - All `with_*`, `as_*`, and `build` methods can be chained, to provide a
-///   convenient API.
+///   convenient API. Methods that need to infer data from other fields, and
+///   `build`, return a [`Result`], if that data might be missing.
 ///
 /// # Implementation Note
 ///
@@ -64,6 +69,8 @@ pub trait HasPartial {
 /// `where` clause on the associated type to specify that, which is unstable. It
 /// should become stable soon though, together with generic associated types:
 /// <https://github.com/rust-lang/rust/issues/44265>
+///
+/// [`MergeWith`]: super::MergeWith
 pub trait Partial: Default + for<'a> From<&'a Self::Full> {
     /// The type representing the full variant of this object
     type Full;
@@ -72,10 +79,6 @@ pub trait Partial: Default + for<'a> From<&'a Self::Full> {
     ///
     /// Implementations of this method will typically try to infer any missing
     /// parts of the partial object, but this is not possible in all cases. In
-    /// such cases, implementations of this method may panic.
-    ///
-    /// Calling `build` on a partial object that can't infer its missing parts
-    /// is considered a programmer error, hence why this method doesn't return a
-    /// [`Result`].
-    fn build(self, objects: &Objects) -> Self::Full;
+    /// such cases, an error is returned that names the missing part.
+    fn build(self, objects: &Objects) -> Result<Self::Full, PartialBuildError>;
 }
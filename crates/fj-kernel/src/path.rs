@@ -22,16 +22,44 @@
 //! [`Surface`]: crate::objects::Surface
 //! [#1021]: https://github.com/hannobraun/Fornjot/issues/1021
 
-use fj_math::{Circle, Line, Point, Scalar, Vector};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use fj_math::{
+    ArcLength, Circle, CubicBezier, Ellipse, Helix, Hyperbola, Line,
+    NurbsCurve, Parabola, Point, PolyChain, Scalar, Vector,
+};
 
 /// A path through surface (2D) space
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SurfacePath {
     /// A circle
     Circle(Circle<2>),
 
+    /// An ellipse
+    Ellipse(Ellipse<2>),
+
+    /// A parabola
+    Parabola(Parabola<2>),
+
+    /// One branch of a hyperbola
+    Hyperbola(Hyperbola<2>),
+
     /// A line
     Line(Line<2>),
+
+    /// A polygonal chain
+    ///
+    /// Consists of line segments between its points, which are located at
+    /// integer path coordinates, starting with `0.`.
+    Polyline(PolyChain<2>),
+
+    /// A cubic Bézier curve
+    Bezier(CubicBezier<2>),
+
+    /// A NURBS curve
+    Nurbs(NurbsCurve<2>),
 }
 
 impl SurfacePath {
@@ -45,11 +73,63 @@ impl SurfacePath {
         ))
     }
 
+    /// Build an ellipse from the given radii
+    pub fn ellipse_from_radii(radii: [impl Into<Scalar>; 2]) -> Self {
+        SurfacePath::Ellipse(Ellipse::from_center_and_radii(
+            Point::origin(),
+            radii,
+        ))
+    }
+
     /// Construct a line from two points
     pub fn line_from_points(points: [impl Into<Point<2>>; 2]) -> Self {
         Self::Line(Line::from_points(points))
     }
 
+    /// Construct a polygonal chain from its points
+    ///
+    /// # Panics
+    ///
+    /// Panics, if less than two points are provided.
+    pub fn polyline_from_points(
+        points: impl IntoIterator<Item = impl Into<Point<2>>>,
+    ) -> Self {
+        let polyline = PolyChain::from_points(points);
+        assert!(
+            polyline.points().len() >= 2,
+            "Polygonal chain must have at least two points"
+        );
+
+        Self::Polyline(polyline)
+    }
+
+    /// Construct a cubic Bézier curve from its control points
+    pub fn bezier_from_control_points(
+        control_points: [impl Into<Point<2>>; 4],
+    ) -> Self {
+        Self::Bezier(CubicBezier::from_control_points(control_points))
+    }
+
+    /// Access the period of the path, if it is periodic
+    ///
+    /// A periodic path repeats itself after the returned interval of path
+    /// coordinates, meaning the path coordinates `t` and `t + period` refer to
+    /// the same point. The point where one period ends and the next one starts
+    /// is the seam of the path.
+    ///
+    /// Returns `None`, if the path isn't periodic.
+    pub fn period(&self) -> Option<Scalar> {
+        match self {
+            Self::Circle(_) | Self::Ellipse(_) => Some(Scalar::TAU),
+            Self::Parabola(_)
+            | Self::Hyperbola(_)
+            | Self::Line(_)
+            | Self::Polyline(_)
+            | Self::Bezier(_)
+            | Self::Nurbs(_) => None,
+        }
+    }
+
     /// Convert a point on the path into global coordinates
     pub fn point_from_path_coords(
         &self,
@@ -57,19 +137,162 @@ impl SurfacePath {
     ) -> Point<2> {
         match self {
             Self::Circle(circle) => circle.point_from_circle_coords(point),
+            Self::Ellipse(ellipse) => ellipse.point_from_ellipse_coords(point),
+            Self::Parabola(parabola) => {
+                parabola.point_from_parabola_coords(point)
+            }
+            Self::Hyperbola(hyperbola) => {
+                hyperbola.point_from_hyperbola_coords(point)
+            }
             Self::Line(line) => line.point_from_line_coords(point),
+            Self::Polyline(polyline) => {
+                polyline.point_from_poly_chain_coords(point)
+            }
+            Self::Bezier(bezier) => bezier.point_from_bezier_coords(point),
+            Self::Nurbs(nurbs) => nurbs.point_from_nurbs_coords(point),
+        }
+    }
+
+    /// Compute the first and second derivatives of the path
+    ///
+    /// The derivatives are taken with respect to the path coordinate.
+    pub fn derivatives_at(&self, point: impl Into<Point<1>>) -> [Vector<2>; 2] {
+        let point = point.into();
+
+        match self {
+            Self::Circle(circle) => [
+                circle.derivative_at(point),
+                circle.second_derivative_at(point),
+            ],
+            Self::Ellipse(ellipse) => [
+                ellipse.derivative_at(point),
+                ellipse.second_derivative_at(point),
+            ],
+            Self::Parabola(parabola) => {
+                [parabola.derivative_at(point), parabola.second_derivative()]
+            }
+            Self::Hyperbola(hyperbola) => [
+                hyperbola.derivative_at(point),
+                hyperbola.second_derivative_at(point),
+            ],
+            Self::Line(line) => [line.direction(), Vector::from([0.; 2])],
+            Self::Polyline(polyline) => {
+                [polyline.derivative_at(point), Vector::from([0.; 2])]
+            }
+            Self::Bezier(bezier) => [
+                bezier.derivative_at(point),
+                bezier.second_derivative_at(point),
+            ],
+            Self::Nurbs(nurbs) => [
+                nurbs.derivative_at(point),
+                nurbs.second_derivative_at(point),
+            ],
+        }
+    }
+
+    /// Compute the curvature of the path at the given point
+    ///
+    /// The curvature is the inverse of the radius of the circle that best
+    /// approximates the path at that point. It is zero for straight paths.
+    /// Polylines are treated as straight, even at the points that connect
+    /// their segments.
+    ///
+    /// Returns zero, if the path is degenerate at the given point, meaning its
+    /// first derivative is zero there.
+    pub fn curvature_at(&self, point: impl Into<Point<1>>) -> Scalar {
+        let [d1, d2] = self.derivatives_at(point);
+        d1.curvature(&d2)
+    }
+
+    /// Compute the length of the path between the two given points
+    ///
+    /// The order of the points doesn't matter. The result is never negative.
+    pub fn length(&self, boundary: [impl Into<Point<1>>; 2]) -> Scalar {
+        self.arc_length().length(boundary)
+    }
+
+    /// Find the point that is the given distance along the path from `start`
+    ///
+    /// The distance is measured along the path. A negative distance refers to
+    /// a point before `start`. Returns the point in path coordinates.
+    pub fn point_at_arc_length(
+        &self,
+        start: impl Into<Point<1>>,
+        distance: impl Into<Scalar>,
+    ) -> Point<1> {
+        self.arc_length().point_at(start, distance)
+    }
+
+    /// Compute points that divide the path into pieces of equal length
+    ///
+    /// Returns `num_pieces + 1` points in path coordinates, including both
+    /// points of the boundary.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `num_pieces` is zero.
+    pub fn points_at_even_arc_length(
+        &self,
+        boundary: [impl Into<Point<1>>; 2],
+        num_pieces: usize,
+    ) -> Vec<Point<1>> {
+        self.arc_length().points_evenly_spaced(boundary, num_pieces)
+    }
+
+    fn arc_length(&self) -> ArcLength<impl Fn(Scalar) -> Scalar + '_> {
+        let arc_length = ArcLength::new(move |t| {
+            let [derivative, _] = self.derivatives_at([t]);
+            derivative.magnitude()
+        });
+
+        match self {
+            Self::Circle(circle) => {
+                arc_length.with_constant_speed(circle.radius())
+            }
+            Self::Line(line) => {
+                arc_length.with_constant_speed(line.direction().magnitude())
+            }
+            Self::Polyline(polyline) => {
+                arc_length.with_segments(polyline.joints())
+            }
+            _ => arc_length,
         }
     }
 }
 
 /// A path through global (3D) space
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GlobalPath {
     /// A circle
     Circle(Circle<3>),
 
+    /// An ellipse
+    Ellipse(Ellipse<3>),
+
+    /// A parabola
+    Parabola(Parabola<3>),
+
+    /// One branch of a hyperbola
+    Hyperbola(Hyperbola<3>),
+
     /// A line
     Line(Line<3>),
+
+    /// A polygonal chain
+    ///
+    /// Consists of line segments between its points, which are located at
+    /// integer path coordinates, starting with `0.`.
+    Polyline(PolyChain<3>),
+
+    /// A cubic Bézier curve
+    Bezier(CubicBezier<3>),
+
+    /// A NURBS curve
+    Nurbs(NurbsCurve<3>),
+
+    /// A helix
+    Helix(Helix),
 }
 
 impl GlobalPath {
@@ -107,16 +330,95 @@ impl GlobalPath {
         ))
     }
 
+    /// Build an ellipse from the given radii
+    pub fn ellipse_from_radii(radii: [impl Into<Scalar>; 2]) -> Self {
+        GlobalPath::Ellipse(Ellipse::from_center_and_radii(
+            Point::origin(),
+            radii,
+        ))
+    }
+
+    /// Build a helix around the z-axis, from the given radius and pitch
+    pub fn helix_from_radius_and_pitch(
+        radius: impl Into<Scalar>,
+        pitch: impl Into<Scalar>,
+        turns: impl Into<Scalar>,
+    ) -> Self {
+        GlobalPath::Helix(Helix::from_axis_radius_and_pitch(
+            Point::origin(),
+            Vector::unit_z(),
+            radius,
+            pitch,
+            turns,
+        ))
+    }
+
     /// Construct a line from two points
     pub fn line_from_points(points: [impl Into<Point<3>>; 2]) -> Self {
         Self::Line(Line::from_points(points))
     }
 
+    /// Construct a polygonal chain from its points
+    ///
+    /// # Panics
+    ///
+    /// Panics, if less than two points are provided.
+    pub fn polyline_from_points(
+        points: impl IntoIterator<Item = impl Into<Point<3>>>,
+    ) -> Self {
+        let polyline = PolyChain::from_points(points);
+        assert!(
+            polyline.points().len() >= 2,
+            "Polygonal chain must have at least two points"
+        );
+
+        Self::Polyline(polyline)
+    }
+
+    /// Construct a cubic Bézier curve from its control points
+    pub fn bezier_from_control_points(
+        control_points: [impl Into<Point<3>>; 4],
+    ) -> Self {
+        Self::Bezier(CubicBezier::from_control_points(control_points))
+    }
+
     /// Access the origin of the path's coordinate system
     pub fn origin(&self) -> Point<3> {
         match self {
             Self::Circle(circle) => circle.center() + circle.a(),
+            Self::Ellipse(ellipse) => ellipse.center() + ellipse.a(),
+            Self::Parabola(parabola) => parabola.vertex(),
+            Self::Hyperbola(hyperbola) => hyperbola.vertex(),
             Self::Line(line) => line.origin(),
+            Self::Polyline(polyline) => polyline.points()[0],
+            Self::Bezier(bezier) => bezier.point_from_bezier_coords([0.]),
+            Self::Nurbs(nurbs) => {
+                let [min, _] = nurbs.domain();
+                nurbs.point_from_nurbs_coords([min])
+            }
+            Self::Helix(helix) => helix.center() + helix.a(),
+        }
+    }
+
+    /// Access the period of the path, if it is periodic
+    ///
+    /// A periodic path repeats itself after the returned interval of path
+    /// coordinates, meaning the path coordinates `t` and `t + period` refer to
+    /// the same point. The point where one period ends and the next one starts
+    /// is the seam of the path.
+    ///
+    /// Returns `None`, if the path isn't periodic.
+    pub fn period(&self) -> Option<Scalar> {
+        match self {
+            Self::Circle(_) | Self::Ellipse(_) => Some(Scalar::TAU),
+            Self::Parabola(_)
+            | Self::Hyperbola(_)
+            | Self::Line(_)
+            | Self::Polyline(_)
+            | Self::Bezier(_)
+            | Self::Nurbs(_) => None,
+            // A helix winds around its axis, but it doesn't connect to itself.
+            Self::Helix(_) => None,
         }
     }
 
@@ -127,7 +429,20 @@ impl GlobalPath {
     ) -> Point<3> {
         match self {
             Self::Circle(circle) => circle.point_from_circle_coords(point),
+            Self::Ellipse(ellipse) => ellipse.point_from_ellipse_coords(point),
+            Self::Parabola(parabola) => {
+                parabola.point_from_parabola_coords(point)
+            }
+            Self::Hyperbola(hyperbola) => {
+                hyperbola.point_from_hyperbola_coords(point)
+            }
             Self::Line(line) => line.point_from_line_coords(point),
+            Self::Polyline(polyline) => {
+                polyline.point_from_poly_chain_coords(point)
+            }
+            Self::Bezier(bezier) => bezier.point_from_bezier_coords(point),
+            Self::Nurbs(nurbs) => nurbs.point_from_nurbs_coords(point),
+            Self::Helix(helix) => helix.point_from_helix_coords(point),
         }
     }
 
@@ -138,7 +453,273 @@ impl GlobalPath {
     ) -> Vector<3> {
         match self {
             Self::Circle(circle) => circle.vector_from_circle_coords(vector),
+            Self::Ellipse(ellipse) => {
+                ellipse.vector_from_ellipse_coords(vector)
+            }
+            Self::Parabola(parabola) => {
+                parabola.vector_from_parabola_coords(vector)
+            }
+            Self::Hyperbola(hyperbola) => {
+                hyperbola.vector_from_hyperbola_coords(vector)
+            }
             Self::Line(line) => line.vector_from_line_coords(vector),
+            Self::Polyline(polyline) => {
+                polyline.vector_from_poly_chain_coords(vector)
+            }
+            Self::Bezier(bezier) => bezier.vector_from_bezier_coords(vector),
+            Self::Nurbs(nurbs) => nurbs.vector_from_nurbs_coords(vector),
+            Self::Helix(helix) => helix.vector_from_helix_coords(vector),
+        }
+    }
+
+    /// Compute the first and second derivatives of the path
+    ///
+    /// The derivatives are taken with respect to the path coordinate.
+    pub fn derivatives_at(&self, point: impl Into<Point<1>>) -> [Vector<3>; 2] {
+        let point = point.into();
+
+        match self {
+            Self::Circle(circle) => [
+                circle.derivative_at(point),
+                circle.second_derivative_at(point),
+            ],
+            Self::Ellipse(ellipse) => [
+                ellipse.derivative_at(point),
+                ellipse.second_derivative_at(point),
+            ],
+            Self::Parabola(parabola) => {
+                [parabola.derivative_at(point), parabola.second_derivative()]
+            }
+            Self::Hyperbola(hyperbola) => [
+                hyperbola.derivative_at(point),
+                hyperbola.second_derivative_at(point),
+            ],
+            Self::Line(line) => [line.direction(), Vector::from([0.; 3])],
+            Self::Polyline(polyline) => {
+                [polyline.derivative_at(point), Vector::from([0.; 3])]
+            }
+            Self::Bezier(bezier) => [
+                bezier.derivative_at(point),
+                bezier.second_derivative_at(point),
+            ],
+            Self::Nurbs(nurbs) => [
+                nurbs.derivative_at(point),
+                nurbs.second_derivative_at(point),
+            ],
+            Self::Helix(helix) => [
+                helix.derivative_at(point),
+                helix.second_derivative_at(point),
+            ],
+        }
+    }
+
+    /// Compute the curvature of the path at the given point
+    ///
+    /// The curvature is the inverse of the radius of the circle that best
+    /// approximates the path at that point. It is zero for straight paths.
+    /// Polylines are treated as straight, even at the points that connect
+    /// their segments.
+    ///
+    /// Returns zero, if the path is degenerate at the given point, meaning its
+    /// first derivative is zero there.
+    pub fn curvature_at(&self, point: impl Into<Point<1>>) -> Scalar {
+        let [d1, d2] = self.derivatives_at(point);
+        d1.curvature(&d2)
+    }
+
+    /// Compute the length of the path between the two given points
+    ///
+    /// The order of the points doesn't matter. The result is never negative.
+    pub fn length(&self, boundary: [impl Into<Point<1>>; 2]) -> Scalar {
+        self.arc_length().length(boundary)
+    }
+
+    /// Find the point that is the given distance along the path from `start`
+    ///
+    /// The distance is measured along the path. A negative distance refers to
+    /// a point before `start`. Returns the point in path coordinates.
+    pub fn point_at_arc_length(
+        &self,
+        start: impl Into<Point<1>>,
+        distance: impl Into<Scalar>,
+    ) -> Point<1> {
+        self.arc_length().point_at(start, distance)
+    }
+
+    /// Compute points that divide the path into pieces of equal length
+    ///
+    /// Returns `num_pieces + 1` points in path coordinates, including both
+    /// points of the boundary.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `num_pieces` is zero.
+    pub fn points_at_even_arc_length(
+        &self,
+        boundary: [impl Into<Point<1>>; 2],
+        num_pieces: usize,
+    ) -> Vec<Point<1>> {
+        self.arc_length().points_evenly_spaced(boundary, num_pieces)
+    }
+
+    fn arc_length(&self) -> ArcLength<impl Fn(Scalar) -> Scalar + '_> {
+        let arc_length = ArcLength::new(move |t| {
+            let [derivative, _] = self.derivatives_at([t]);
+            derivative.magnitude()
+        });
+
+        match self {
+            Self::Circle(circle) => {
+                arc_length.with_constant_speed(circle.radius())
+            }
+            Self::Line(line) => {
+                arc_length.with_constant_speed(line.direction().magnitude())
+            }
+            Self::Helix(helix) => arc_length.with_constant_speed(
+                helix.derivative_at([Scalar::ZERO]).magnitude(),
+            ),
+            Self::Polyline(polyline) => {
+                arc_length.with_segments(polyline.joints())
+            }
+            _ => arc_length,
+        }
+    }
+}
+
+macro_rules! impl_abs_diff_eq {
+    ($ty:ident: $($variant:ident),*) => {
+        impl approx::AbsDiffEq for $ty {
+            type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+            fn default_epsilon() -> Self::Epsilon {
+                Scalar::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                match (self, other) {
+                    $(
+                        (Self::$variant(a), Self::$variant(b)) => {
+                            a.abs_diff_eq(b, epsilon)
+                        }
+                    )*
+                    _ => false,
+                }
+            }
+        }
+    };
+}
+
+impl_abs_diff_eq!(
+    SurfacePath: Circle,
+    Ellipse,
+    Parabola,
+    Hyperbola,
+    Line,
+    Polyline,
+    Bezier,
+    Nurbs
+);
+impl_abs_diff_eq!(
+    GlobalPath: Circle,
+    Ellipse,
+    Parabola,
+    Hyperbola,
+    Line,
+    Polyline,
+    Bezier,
+    Nurbs,
+    Helix
+);
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{PI, TAU};
+
+    use fj_math::{Parabola, Point, Scalar};
+
+    use super::{GlobalPath, SurfacePath};
+
+    #[test]
+    fn curvature_at() {
+        let circle = SurfacePath::circle_from_radius(2.);
+        let line = SurfacePath::line_from_points([[0., 0.], [1., 1.]]);
+        let helix = GlobalPath::helix_from_radius_and_pitch(1., 1., 2.);
+
+        assert_eq!(circle.curvature_at([1.]), Scalar::from(0.5));
+        assert_eq!(line.curvature_at([1.]), Scalar::ZERO);
+
+        // The curvature of a helix is `r / (r^2 + c^2)`, where `c` is the
+        // distance along the axis per radian.
+        let c = Scalar::ONE / Scalar::TAU;
+        let expected = Scalar::ONE / (Scalar::ONE + c * c);
+        assert!((helix.curvature_at([1.]) - expected).abs() < 1e-12.into());
+    }
+
+    #[test]
+    fn length() {
+        let circle = SurfacePath::circle_from_radius(2.);
+        assert_eq!(circle.length([[0.], [PI]]), Scalar::TAU);
+
+        let polyline =
+            SurfacePath::polyline_from_points([[0., 0.], [3., 4.], [3., 5.]]);
+        assert_eq!(polyline.length([[2.], [0.5]]), Scalar::from(3.5));
+
+        // The length of the parabola `y = x^2` between `x = 0` and `x = 1`.
+        let parabola = GlobalPath::Parabola(Parabola::new(
+            [0., 0., 0.],
+            [0., 1., 0.],
+            [1., 0., 0.],
+        ));
+        let sqrt_5 = 5_f64.sqrt();
+        let expected = (2. * sqrt_5 + (sqrt_5 + 2.).ln()) / 4.;
+        assert!(
+            (parabola.length([[0.], [1.]]) - expected).abs() < 1e-10.into()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "at least two points")]
+    fn polyline_with_single_point() {
+        SurfacePath::polyline_from_points([[0., 0.]]);
+    }
+
+    #[test]
+    fn point_at_arc_length() {
+        let bezier = SurfacePath::bezier_from_control_points([
+            [0., 0.],
+            [1., 2.],
+            [2., -2.],
+            [3., 0.],
+        ]);
+
+        for distance in [0.5, 1., 2.5] {
+            let start = Point::from([0.25]);
+            let end = bezier.point_at_arc_length(start, distance);
+
+            let length = bezier.length([start, end]);
+            assert!((length - distance).abs() < 1e-9.into());
+        }
+
+        // Going backwards works too.
+        let start = Point::from([0.5]);
+        let end = bezier.point_at_arc_length(start, -1.);
+        assert!(end < start);
+        assert!((bezier.length([start, end]) - 1.).abs() < 1e-9.into());
+    }
+
+    #[test]
+    fn points_at_even_arc_length() {
+        let ellipse = SurfacePath::ellipse_from_radii([3., 1.]);
+
+        let points = ellipse.points_at_even_arc_length([[0.], [TAU]], 4);
+        assert_eq!(points.len(), 5);
+
+        let lengths = points
+            .windows(2)
+            .map(|window| ellipse.length([window[0], window[1]]))
+            .collect::<Vec<_>>();
+        for length in &lengths {
+            assert!((*length - lengths[0]).abs() < 1e-9.into());
         }
     }
 }
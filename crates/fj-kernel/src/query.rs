@@ -0,0 +1,237 @@
+//! API for finding the objects of a shape that match a predicate
+//!
+//! Builds on [`ObjectIters`], and is available for everything that implements
+//! it. Higher-level operations and tests can use this to find the objects they
+//! are interested in, without walking the object graph by hand.
+//!
+//! ``` rust
+//! # use fj_kernel::{
+//! #     algorithms::sweep::Sweep,
+//! #     objects::{HalfEdge, Objects, Sketch, Surface},
+//! #     query::Query,
+//! # };
+//! # let objects = Objects::new();
+//! # let surface = objects.surfaces.insert(Surface::xy_plane());
+//! # let solid = Sketch::builder(&objects, surface)
+//! #     .build_polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])?
+//! #     .sweep([0., 0., 1.], &objects);
+//! // Find all half-edges that start at the origin
+//! let half_edges = solid.find_all(|half_edge: &HalfEdge| {
+//!     let [start, _] = half_edge.vertices();
+//!     start.global_form().position() == [0., 0., 0.].into()
+//! });
+//! # assert!(!half_edges.is_empty());
+//! # Ok::<(), fj_kernel::partial::PartialBuildError>(())
+//! ```
+
+use crate::{
+    iter::{Iter, ObjectIters},
+    objects::{
+        Curve, Cycle, Face, GlobalCurve, GlobalVertex, HalfEdge, Shell, Sketch,
+        Solid, Surface, Vertex,
+    },
+    storage::Handle,
+};
+
+/// Find the objects of a shape that match a predicate
+///
+/// Implemented for all types that implement [`ObjectIters`]. The type of the
+/// objects to find is selected through the type of the predicate's argument.
+/// See [`Select`] for the types that can be selected.
+pub trait Query<'r>: ObjectIters<'r> {
+    /// Find all objects of type `T` that match the predicate
+    fn find_all<T>(
+        &'r self,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> Vec<&'r T>
+    where
+        T: Select<'r>,
+    {
+        T::select(self).filter(|object| predicate(object)).collect()
+    }
+
+    /// Find the first object of type `T` that matches the predicate
+    fn find_first<T>(
+        &'r self,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> Option<&'r T>
+    where
+        T: Select<'r>,
+    {
+        T::select(self).find(|object| predicate(object))
+    }
+
+    /// Count the objects of type `T` that match the predicate
+    fn count_where<T>(&'r self, predicate: impl FnMut(&T) -> bool) -> usize
+    where
+        T: Select<'r>,
+    {
+        self.find_all(predicate).len()
+    }
+
+    /// Iterate over all objects of type `T`
+    ///
+    /// Like the `*_iter` methods of [`ObjectIters`], but generic over the type
+    /// of object. Every object is only returned once.
+    fn select_all<T>(&'r self) -> Iter<&'r T>
+    where
+        T: Select<'r>,
+    {
+        T::select(self)
+    }
+
+    /// Find all objects of type `A` that reference `object`
+    ///
+    /// This answers questions like "which faces reference this global curve?".
+    /// See [`Select::is_same_as`] for how `object` is matched.
+    fn find_ancestors<A, T>(&'r self, object: &T) -> Vec<&'r A>
+    where
+        A: Select<'r> + ObjectIters<'r>,
+        T: Select<'r>,
+    {
+        A::select(self)
+            .filter(|ancestor| {
+                T::select(*ancestor)
+                    .any(|descendant| descendant.is_same_as(object))
+            })
+            .collect()
+    }
+}
+
+impl<'r, S> Query<'r> for S where S: ObjectIters<'r> + ?Sized {}
+
+/// Implemented for object types that [`Query`] can select
+pub trait Select<'r>: 'r {
+    /// Iterate over all objects of this type in the shape
+    fn select<S>(shape: &'r S) -> Iter<&'r Self>
+    where
+        S: ObjectIters<'r> + ?Sized;
+
+    /// Indicate whether this is the same object as `other`
+    ///
+    /// Objects are compared by equality, as most of them are not managed in
+    /// the centralized object storage yet. Global curves don't carry any data,
+    /// and are compared by identity instead.
+    fn is_same_as(&self, other: &Self) -> bool;
+}
+
+macro_rules! impl_select {
+    ($($ty:ty, $iter:ident;)*) => {
+        $(
+            impl<'r> Select<'r> for $ty {
+                fn select<S>(shape: &'r S) -> Iter<&'r Self>
+                where
+                    S: ObjectIters<'r> + ?Sized,
+                {
+                    shape.$iter()
+                }
+
+                fn is_same_as(&self, other: &Self) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+}
+
+impl_select!(
+    Curve, curve_iter;
+    Cycle, cycle_iter;
+    Face, face_iter;
+    GlobalVertex, global_vertex_iter;
+    HalfEdge, half_edge_iter;
+    Shell, shell_iter;
+    Sketch, sketch_iter;
+    Solid, solid_iter;
+    Surface, surface_iter;
+    Vertex, vertex_iter;
+);
+
+impl<'r> Select<'r> for Handle<GlobalCurve> {
+    fn select<S>(shape: &'r S) -> Iter<&'r Self>
+    where
+        S: ObjectIters<'r> + ?Sized,
+    {
+        shape.global_curve_iter()
+    }
+
+    fn is_same_as(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Scalar, Vector};
+
+    use crate::{
+        algorithms::sweep::Sweep,
+        iter::ObjectIters as _,
+        objects::{
+            Face, GlobalCurve, GlobalVertex, HalfEdge, Objects, Shell, Sketch,
+            Solid, Surface,
+        },
+        storage::Handle,
+    };
+
+    use super::Query as _;
+
+    #[test]
+    fn find() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let surface = objects.surfaces.insert(Surface::xy_plane());
+        let solid = Sketch::builder(&objects, surface)
+            .build_polygon_from_points([[0., 0.], [2., 0.], [0., 1.]])?
+            .sweep([0., 0., 1.], &objects);
+
+        let parallel_to_xy =
+            |face: &Face| match face.surface().normal_at([0., 0.]) {
+                Some(normal) => {
+                    normal.cross(&Vector::unit_z()).magnitude() == Scalar::ZERO
+                }
+                None => false,
+            };
+        assert_eq!(solid.find_all(parallel_to_xy).len(), 2);
+
+        let longer_than = |length: f64| {
+            move |half_edge: &HalfEdge| {
+                let [a, b] = half_edge
+                    .vertices()
+                    .clone()
+                    .map(|vertex| vertex.global_form().position());
+                (b - a).magnitude() > Scalar::from(length)
+            }
+        };
+        // The two long sides of the triangle result in four edges, at the
+        // bottom and the top. Each of them consists of two half-edges.
+        assert_eq!(solid.count_where(longer_than(1.)), 8);
+
+        let above = |vertex: &GlobalVertex| vertex.position().z > Scalar::ZERO;
+        assert_eq!(solid.count_where(above), 3);
+        assert!(solid.find_first(|_: &GlobalVertex| false).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_ancestors() -> anyhow::Result<()> {
+        let objects = Objects::new();
+
+        let solid = Solid::builder(&objects).build_cube_from_edge_length(1.)?;
+
+        // Each edge of the cube is shared by two faces.
+        let global_curves = solid.select_all::<Handle<GlobalCurve>>();
+        assert_eq!(global_curves.count(), 12);
+        for global_curve in solid.select_all::<Handle<GlobalCurve>>() {
+            let faces = solid.find_ancestors::<Face, _>(global_curve);
+            assert_eq!(faces.len(), 2);
+        }
+
+        let face = solid.face_iter().next().unwrap();
+        assert_eq!(solid.find_ancestors::<Shell, _>(face).len(), 1);
+        assert!(solid.find_ancestors::<Sketch, _>(face).is_empty());
+
+        Ok(())
+    }
+}
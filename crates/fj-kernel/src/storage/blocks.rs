@@ -1,9 +1,27 @@
-use std::iter;
+use std::{
+    cell::UnsafeCell,
+    collections::{BTreeSet, HashMap},
+    iter,
+    ops::Range,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
 
+use parking_lot::Mutex;
+
+use super::handle::ObjectId;
+
+/// The blocks of a store
+///
+/// Objects are added while the blocks are only borrowed immutably, which makes
+/// it possible to add objects from multiple threads at once. Any operations
+/// that require a mutable borrow, like adding a new block, are comparatively
+/// rare.
 #[derive(Debug)]
 pub struct Blocks<T> {
     inner: Vec<Block<T>>,
     block_size: usize,
+    labels: Mutex<HashMap<ObjectId, String>>,
+    discarded: Vec<Range<usize>>,
 }
 
 impl<T> Blocks<T> {
@@ -11,45 +29,53 @@ impl<T> Blocks<T> {
         Self {
             inner: Vec::new(),
             block_size,
+            labels: Mutex::new(HashMap::new()),
+            discarded: Vec::new(),
         }
     }
 
-    pub fn push(&mut self, object: T) -> *const Option<T> {
-        let (index, _) = self.reserve();
+    #[cfg(test)]
+    pub fn push(&mut self, object: T) -> *const Slot<T> {
+        let index = loop {
+            match self.reserve() {
+                Some(reserved) => break reserved,
+                None => self.add_block(),
+            }
+        };
         self.insert(index, object)
     }
 
-    pub fn reserve(&mut self) -> ((usize, usize), *mut Option<T>) {
-        let mut current_block = match self.inner.pop() {
-            Some(block) => block,
-            None => Block::new(self.block_size),
-        };
-
-        let ret = loop {
-            match current_block.reserve() {
-                Ok((object_index, ptr)) => {
-                    let block_index = self.inner.len();
-                    break ((block_index, object_index), ptr);
-                }
-                Err(()) => {
-                    // Block is full. Need to create a new one and retry.
-                    self.inner.push(current_block);
-                    current_block = Block::new(self.block_size);
-                }
-            }
-        };
+    /// Reserve a slot in the last block
+    ///
+    /// Returns `None`, if there is no block yet, or if the last block is full.
+    /// Call [`Blocks::add_block`] then, and try again.
+    pub fn reserve(&self) -> Option<(usize, usize)> {
+        let block_index = self.inner.len().checked_sub(1)?;
+        let object_index = self.inner[block_index].reserve()?;
 
-        self.inner.push(current_block);
+        Some((block_index, object_index))
+    }
 
-        ret
+    /// Add a block, unless there is a last block that isn't full
+    ///
+    /// Multiple threads can find the last block full at the same time. The
+    /// check makes sure that only one of them adds a new block.
+    pub fn add_block(&mut self) {
+        if self.inner.last().map_or(true, Block::is_full) {
+            self.inner.push(Block::new(self.block_size));
+        }
     }
 
+    /// Initialize a reserved slot
+    ///
+    /// Must only be called once for each reserved slot, by whoever reserved
+    /// it.
     pub fn insert(
-        &mut self,
+        &self,
         (block_index, object_index): (usize, usize),
         object: T,
-    ) -> *const Option<T> {
-        let block = &mut self.inner[block_index];
+    ) -> *const Slot<T> {
+        let block = &self.inner[block_index];
         block.insert(object_index, object)
     }
 
@@ -57,6 +83,137 @@ impl<T> Blocks<T> {
         self.inner.get(index)
     }
 
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// The number of slots that have been reserved so far
+    pub fn len(&self) -> usize {
+        match self.inner.last() {
+            Some(block) => {
+                (self.inner.len() - 1) * self.block_size + block.len()
+            }
+            None => 0,
+        }
+    }
+
+    /// Discard all slots from `position` onward
+    ///
+    /// The objects in those slots are not dropped, as handles to them might
+    /// still exist. They are just no longer considered part of the store.
+    pub fn discard(&mut self, position: usize, ids: &BTreeSet<ObjectId>) {
+        let mut start = position;
+        let end = self.len();
+
+        // All previously discarded ranges end before the current end of the
+        // store. Any that overlap the new range are merged into it, which keeps
+        // the ranges sorted and disjoint.
+        while let Some(range) = self.discarded.last() {
+            if range.end < start {
+                break;
+            }
+
+            start = start.min(range.start);
+            self.discarded.pop();
+        }
+
+        if start < end {
+            self.discarded.push(start..end);
+        }
+
+        self.labels.get_mut().retain(|id, _| !ids.contains(id));
+    }
+
+    pub fn is_discarded(
+        &self,
+        (block_index, object_index): (usize, usize),
+    ) -> bool {
+        let position = block_index * self.block_size + object_index;
+
+        let i = self
+            .discarded
+            .partition_point(|range| range.end <= position);
+        self.discarded
+            .get(i)
+            .map_or(false, |range| range.contains(&position))
+    }
+
+    /// Take all objects that are no longer referenced by any handle
+    ///
+    /// Must only be called while the store is locked for writing, as that
+    /// prevents new handles from being created.
+    ///
+    /// The objects are returned instead of being dropped right away. Dropping
+    /// them drops the handles they contain, and that should happen after the
+    /// lock has been released.
+    pub fn collect_garbage(&self) -> Vec<(ObjectId, T)> {
+        let mut collected = Vec::new();
+
+        for block in &self.inner {
+            for slot in &block.objects[..block.len()] {
+                if let Some(object) = slot.collect() {
+                    collected.push((slot.id(), object));
+                }
+            }
+        }
+
+        let mut labels = self.labels.lock();
+        for (id, _) in &collected {
+            labels.remove(id);
+        }
+
+        collected
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn slots(
+        &self,
+    ) -> impl Iterator<Item = ((usize, usize), &Slot<T>)> + '_ {
+        self.inner
+            .iter()
+            .enumerate()
+            .flat_map(|(block_index, block)| {
+                block.objects[..block.len()].iter().enumerate().map(
+                    move |(object_index, slot)| {
+                        ((block_index, object_index), slot)
+                    },
+                )
+            })
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn index_of(&self, ptr: *const Slot<T>) -> Option<usize> {
+        let size = std::mem::size_of::<Slot<T>>();
+
+        self.inner
+            .iter()
+            .enumerate()
+            .find_map(|(i, block)| {
+                let offset = (ptr as usize)
+                    .checked_sub(block.objects.as_ptr() as usize)?;
+                let index = offset / size;
+                (index < block.len()).then_some((i, index))
+            })
+            .filter(|&index| !self.is_discarded(index))
+            .map(|(i, index)| i * self.block_size + index)
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn ptr_at(&self, index: usize) -> Option<*const Slot<T>> {
+        let block = self.inner.get(index / self.block_size)?;
+        let slot = block.get(index % self.block_size);
+
+        slot.object().is_some().then_some(slot as *const Slot<T>)
+    }
+
+    pub fn label(&self, id: ObjectId) -> Option<String> {
+        self.labels.lock().get(&id).cloned()
+    }
+
+    pub fn set_label(&self, id: ObjectId, label: String) {
+        self.labels.lock().insert(id, label);
+    }
+
     #[cfg(test)]
     pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
         self.inner.iter().flat_map(|block| block.iter())
@@ -65,43 +222,47 @@ impl<T> Blocks<T> {
 
 #[derive(Debug)]
 pub struct Block<T> {
-    objects: Box<[Option<T>]>,
-    next: usize,
+    objects: Box<[Slot<T>]>,
+
+    // Can exceed the number of slots, if multiple threads try to reserve a slot
+    // in a full block. See `Block::len`.
+    next: AtomicUsize,
 }
 
 impl<T> Block<T> {
     pub fn new(size: usize) -> Self {
-        let vec = iter::repeat_with(|| None)
+        let vec = iter::repeat_with(Slot::default)
             .take(size)
-            .collect::<Vec<Option<T>>>();
+            .collect::<Vec<Slot<T>>>();
         let objects = vec.into_boxed_slice();
 
-        Self { objects, next: 0 }
-    }
-
-    pub fn reserve(&mut self) -> Result<(usize, *mut Option<T>), ()> {
-        if self.next >= self.objects.len() {
-            return Err(());
+        Self {
+            objects,
+            next: AtomicUsize::new(0),
         }
+    }
 
-        let index = self.next;
-        let ptr = &mut self.objects[self.next];
-        self.next += 1;
-
-        Ok((index, ptr))
+    pub fn reserve(&self) -> Option<usize> {
+        let index = self.next.fetch_add(1, Ordering::Relaxed);
+        (index < self.objects.len()).then_some(index)
     }
 
-    pub fn insert(&mut self, index: usize, object: T) -> *const Option<T> {
-        self.objects[index] = Some(object);
-        &self.objects[index]
+    pub fn insert(&self, index: usize, object: T) -> *const Slot<T> {
+        let slot = &self.objects[index];
+        slot.initialize(object);
+        slot
     }
 
-    pub fn get(&self, index: usize) -> &Option<T> {
+    pub fn get(&self, index: usize) -> &Slot<T> {
         &self.objects[index]
     }
 
     pub fn len(&self) -> usize {
-        self.next
+        self.next.load(Ordering::Relaxed).min(self.objects.len())
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() >= self.objects.len()
     }
 
     #[cfg(test)]
@@ -112,7 +273,7 @@ impl<T> Block<T> {
                 return None;
             }
 
-            let object = self.get(i).as_ref()?;
+            let object = self.get(i).object()?;
             i += 1;
 
             Some(object)
@@ -120,6 +281,76 @@ impl<T> Block<T> {
     }
 }
 
+/// A slot for an object, which counts the handles that refer to it
+///
+/// A slot is initialized at most once, by whoever reserved it. The object
+/// becomes visible to other threads, once `initialized` is set.
+#[derive(Debug)]
+pub struct Slot<T> {
+    object: UnsafeCell<Option<T>>,
+    initialized: AtomicBool,
+    handles: AtomicUsize,
+}
+
+impl<T> Slot<T> {
+    pub fn id(&self) -> ObjectId {
+        ObjectId(self as *const Self as u64)
+    }
+
+    pub fn object(&self) -> Option<&T> {
+        if !self.initialized.load(Ordering::Acquire) {
+            return None;
+        }
+
+        // The object is never written to again after it has been initialized,
+        // except when it's collected. That only happens while the store is
+        // locked for writing, and no handles refer to it, which means no
+        // references to the object can exist.
+        unsafe { (*self.object.get()).as_ref() }
+    }
+
+    pub fn acquire(&self) {
+        self.handles.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn release(&self) {
+        self.handles.fetch_sub(1, Ordering::Release);
+    }
+
+    fn initialize(&self, object: T) {
+        // Only whoever reserved this slot initializes it, and only once. Until
+        // `initialized` is set, nobody else accesses the object.
+        unsafe { *self.object.get() = Some(object) };
+        self.initialized.store(true, Ordering::Release);
+    }
+
+    fn collect(&self) -> Option<T> {
+        if !self.initialized.load(Ordering::Acquire)
+            || self.handles.load(Ordering::Acquire) > 0
+        {
+            return None;
+        }
+
+        // See `Slot::object`.
+        self.initialized.store(false, Ordering::Relaxed);
+        unsafe { (*self.object.get()).take() }
+    }
+}
+
+impl<T> Default for Slot<T> {
+    fn default() -> Self {
+        Self {
+            object: UnsafeCell::new(None),
+            initialized: AtomicBool::new(false),
+            handles: AtomicUsize::new(0),
+        }
+    }
+}
+
+// Access to the object is synchronized through `initialized`, and the lock
+// that guards the store. See the methods of `Slot`.
+unsafe impl<T> Sync for Slot<T> where T: Send + Sync {}
+
 #[cfg(test)]
 mod tests {
     use super::Blocks;
@@ -1,6 +1,6 @@
 use std::{any::type_name, cmp::Ordering, fmt, hash::Hash, ops::Deref};
 
-use super::store::StoreInner;
+use super::{blocks::Slot, store::StoreInner};
 
 /// A handle for an object
 ///
@@ -19,14 +19,35 @@ use super::store::StoreInner;
 /// a relevant distinction when validating objects, as equal but not identical
 /// objects might be a sign of a bug.
 ///
+/// If the referenced objects implement [`approx::AbsDiffEq`], so do the
+/// `Handle`s, comparing the objects within a tolerance.
+///
 /// You can compare the identity of two objects through their `Handle`s, by
 /// comparing the values returned by [`Handle::id`].
+///
+/// # Lifetime of Objects
+///
+/// The store counts the `Handle`s that refer to each of its objects. Objects
+/// that are no longer referenced by any `Handle` are dropped, once
+/// [`Store::collect_garbage`] is called. Use [`WeakHandle`] to refer to an
+/// object without keeping it alive.
+///
+/// [`Store::collect_garbage`]: super::Store::collect_garbage
 pub struct Handle<T> {
+    // Don't construct `Handle` directly. Use `Handle::new`, so the handle is
+    // counted.
     pub(super) store: StoreInner<T>,
-    pub(super) ptr: *const Option<T>,
+    pub(super) ptr: *const Slot<T>,
 }
 
 impl<T> Handle<T> {
+    pub(super) fn new(store: StoreInner<T>, ptr: *const Slot<T>) -> Self {
+        // The caller makes sure, that the slot has been reserved in `store`.
+        // Reserved slots are never deallocated, as long as `store` is alive.
+        unsafe { &*ptr }.acquire();
+        Self { store, ptr }
+    }
+
     /// Access this pointer's unique id
     pub fn id(&self) -> ObjectId {
         ObjectId(self.ptr as u64)
@@ -39,6 +60,37 @@ impl<T> Handle<T> {
     {
         self.deref().clone()
     }
+
+    /// Access the label of the object this handle refers to, if it has one
+    ///
+    /// See [`Handle::with_label`].
+    pub fn label(&self) -> Option<String> {
+        self.store.read().label(self.id())
+    }
+
+    /// Create a [`WeakHandle`] that refers to the same object
+    pub fn downgrade(&self) -> WeakHandle<T> {
+        WeakHandle {
+            store: self.store.clone(),
+            ptr: self.ptr,
+        }
+    }
+
+    /// Attach a label to the object this handle refers to
+    ///
+    /// Labels are a way to address objects by name, in error messages, debug
+    /// output, or when selecting objects for further operations. They are not
+    /// part of the object, and don't affect equality.
+    ///
+    /// Only a `Some(...)` value has any effect, replacing any label the object
+    /// already has. This makes it easy to pass along the label of another
+    /// object, which might or might not have one.
+    pub fn with_label(self, label: Option<impl Into<String>>) -> Self {
+        if let Some(label) = label {
+            self.store.read().set_label(self.id(), label.into());
+        }
+        self
+    }
 }
 
 impl<T> Deref for Handle<T> {
@@ -50,14 +102,16 @@ impl<T> Deref for Handle<T> {
         // exists, the `StoreInner` its data lives in is still alive. Even if
         // the `Store` was dropped.
         //
-        // The `Store` API ensures two things:
+        // The `Store` API ensures three things:
         //
         // 1. That no `Handle` is ever created, until the object it references
         //    has at least been reserved.
         // 2. That the memory objects live in is never deallocated.
+        // 3. That objects are only dropped, if no `Handle` references them.
         //
         // That means that as long as a `Handle` exists, the object it
-        // references has at least been reserved, and has not been deallocated.
+        // references has at least been reserved, and has not been dropped or
+        // deallocated.
         //
         // Given all this, we know that the following must be true:
         //
@@ -72,21 +126,25 @@ impl<T> Deref for Handle<T> {
         //
         // Furthermore, all of the code mentioned here is covered by unit tests,
         // which I've run successfully under Miri.
-        let cell = unsafe { &*self.ptr };
+        let slot = unsafe { &*self.ptr };
 
         // Can only happen, if the object has been reserved, but the reservation
         // was never completed.
-        cell.as_ref()
+        slot.object()
             .expect("Handle references non-existing object")
     }
 }
 
 impl<T> Clone for Handle<T> {
     fn clone(&self) -> Self {
-        Self {
-            store: self.store.clone(),
-            ptr: self.ptr,
-        }
+        Self::new(self.store.clone(), self.ptr)
+    }
+}
+
+impl<T> Drop for Handle<T> {
+    fn drop(&mut self) {
+        // See `Handle::new`.
+        unsafe { &*self.ptr }.release();
     }
 }
 
@@ -101,6 +159,21 @@ where
     }
 }
 
+impl<T> approx::AbsDiffEq for Handle<T>
+where
+    T: approx::AbsDiffEq,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.deref().abs_diff_eq(other.deref(), epsilon)
+    }
+}
+
 impl<T> Hash for Handle<T>
 where
     T: Hash,
@@ -140,6 +213,9 @@ impl<T> fmt::Debug for Handle<T> {
         let id = self.id().0;
 
         write!(f, "{name} @ {id:#x}")?;
+        if let Some(label) = self.label() {
+            write!(f, " ({label:?})")?;
+        }
 
         Ok(())
     }
@@ -158,7 +234,7 @@ unsafe impl<T> Sync for Handle<T> {}
 ///
 /// See [`Handle::id`].
 #[derive(Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
-pub struct ObjectId(u64);
+pub struct ObjectId(pub(super) u64);
 
 impl fmt::Debug for ObjectId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -167,6 +243,59 @@ impl fmt::Debug for ObjectId {
     }
 }
 
+/// A handle that doesn't keep the object it refers to alive
+///
+/// A `WeakHandle` can be created from a [`Handle`] via [`Handle::downgrade`].
+/// Unlike a [`Handle`], it doesn't prevent the object it refers to from being
+/// dropped by [`Store::collect_garbage`].
+///
+/// [`Store::collect_garbage`]: super::Store::collect_garbage
+pub struct WeakHandle<T> {
+    store: StoreInner<T>,
+    ptr: *const Slot<T>,
+}
+
+impl<T> WeakHandle<T> {
+    /// Access the unique id of the object this handle refers to
+    ///
+    /// See [`Handle::id`].
+    pub fn id(&self) -> ObjectId {
+        ObjectId(self.ptr as u64)
+    }
+
+    /// Create a [`Handle`], if the object has not been dropped yet
+    pub fn upgrade(&self) -> Option<Handle<T>> {
+        // Objects are only dropped while the store is locked for writing.
+        // Holding a read lock makes sure, the object isn't dropped while the
+        // new handle is being created.
+        let _blocks = self.store.read();
+
+        let slot = unsafe { &*self.ptr };
+        slot.object()?;
+
+        Some(Handle::new(self.store.clone(), self.ptr))
+    }
+}
+
+impl<T> Clone for WeakHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            store: self.store.clone(),
+            ptr: self.ptr,
+        }
+    }
+}
+
+impl<T> fmt::Debug for WeakHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let id = self.id().0;
+        write!(f, "WeakHandle @ {id:#x}")
+    }
+}
+
+unsafe impl<T> Send for WeakHandle<T> {}
+unsafe impl<T> Sync for WeakHandle<T> {}
+
 /// A wrapper around [`Handle`] to define equality based on identity
 ///
 /// This is a utility type that implements [`Eq`]/[`PartialEq`] and other common
@@ -4,7 +4,13 @@ mod blocks;
 mod handle;
 mod store;
 
+#[cfg(feature = "serde")]
+mod serialize;
+
 pub use self::{
-    handle::{Handle, HandleWrapper, ObjectId},
-    store::{Iter, Reservation, Store},
+    handle::{Handle, HandleWrapper, ObjectId, WeakHandle},
+    store::{Coincident, Iter, Marker, Merge, Reservation, Store},
 };
+
+#[cfg(feature = "serde")]
+pub use self::serialize::DeserializeScope;
@@ -0,0 +1,242 @@
+//! Serialization of handles and stores
+//!
+//! A [`Handle`] is serialized as the index of the object it refers to within
+//! its [`Store`]. This preserves the sharing of objects between handles, but it
+//! means that handles can only be deserialized, after the store they refer to
+//! has been deserialized within the same [`DeserializeScope`].
+
+use std::{
+    any::{type_name, Any, TypeId},
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, HashMap},
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{store::StoreInner, Handle, HandleWrapper, Store};
+
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+    static STORES: RefCell<HashMap<TypeId, Box<dyn Any>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Makes deserialized stores available to the handles that refer to them
+///
+/// Stores that are deserialized while a scope is active, are available to all
+/// handles that are deserialized later within the same scope. Scopes can be
+/// nested, in which case the inner scopes share the outermost one.
+pub struct DeserializeScope {
+    _private: (),
+}
+
+impl DeserializeScope {
+    /// Enter a new scope, or the one that is already active
+    pub fn enter() -> Self {
+        DEPTH.with(|depth| depth.set(depth.get() + 1));
+        Self { _private: () }
+    }
+}
+
+impl Drop for DeserializeScope {
+    fn drop(&mut self) {
+        let depth = DEPTH.with(|depth| {
+            depth.set(depth.get() - 1);
+            depth.get()
+        });
+
+        if depth == 0 {
+            STORES.with(|stores| stores.borrow_mut().clear());
+        }
+    }
+}
+
+fn register<T: 'static>(store: &StoreInner<T>) {
+    if DEPTH.with(Cell::get) == 0 {
+        return;
+    }
+
+    STORES.with(|stores| {
+        stores
+            .borrow_mut()
+            .insert(TypeId::of::<T>(), Box::new(store.clone()));
+    });
+}
+
+fn lookup<T: 'static>() -> Option<StoreInner<T>> {
+    STORES.with(|stores| {
+        stores
+            .borrow()
+            .get(&TypeId::of::<T>())
+            .and_then(|store| store.downcast_ref::<StoreInner<T>>())
+            .cloned()
+    })
+}
+
+impl<T> Serialize for Handle<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let index = self
+            .store
+            .read()
+            .index_of(self.ptr)
+            .expect("Handle refers to object outside of its store");
+
+        index.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Handle<T>
+where
+    T: 'static,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let index = usize::deserialize(deserializer)?;
+
+        let store = lookup::<T>().ok_or_else(|| {
+            de::Error::custom(format!(
+                "Can't deserialize handle to `{}` before its store",
+                type_name::<T>()
+            ))
+        })?;
+        let ptr = store.read().ptr_at(index).ok_or_else(|| {
+            de::Error::custom(format!(
+                "No `{}` at index {index} in store",
+                type_name::<T>()
+            ))
+        })?;
+
+        Ok(Self::new(store, ptr))
+    }
+}
+
+impl<T> Serialize for HandleWrapper<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for HandleWrapper<T>
+where
+    T: 'static,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Handle::deserialize(deserializer).map(Self)
+    }
+}
+
+// Slots that don't contain an object are serialized as `Empty`. This keeps the
+// indices of all objects, which is what handles are serialized as.
+#[derive(Serialize)]
+#[serde(rename = "Store")]
+struct SerializeStore<'r, T> {
+    objects: Vec<SerializeSlot<'r, T>>,
+    labels: BTreeMap<usize, String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Store")]
+struct DeserializeStore<T> {
+    objects: Vec<DeserializeSlot<T>>,
+    labels: BTreeMap<usize, String>,
+}
+
+// An `Option` can't be used here. Some formats, like JSON, serialize both
+// `None` and unit structs like `GlobalCurve` as `null`, and would deserialize
+// the objects as `None`.
+#[derive(Serialize)]
+#[serde(rename = "Slot")]
+enum SerializeSlot<'r, T> {
+    Empty,
+    Object(&'r T),
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Slot")]
+enum DeserializeSlot<T> {
+    Empty,
+    Object(T),
+}
+
+impl<T> Serialize for Store<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let blocks = self.inner.read();
+
+        let slots = blocks
+            .slots()
+            .map(|(index, slot)| {
+                let object =
+                    slot.object().filter(|_| !blocks.is_discarded(index));
+                (slot, object)
+            })
+            .collect::<Vec<_>>();
+
+        let labels = slots
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, object))| object.is_some())
+            .filter_map(|(index, (slot, _))| {
+                Some((index, blocks.label(slot.id())?))
+            })
+            .collect();
+        let objects = slots
+            .into_iter()
+            .map(|(_, object)| match object {
+                Some(object) => SerializeSlot::Object(object),
+                None => SerializeSlot::Empty,
+            })
+            .collect();
+
+        SerializeStore { objects, labels }.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Store<T>
+where
+    T: Deserialize<'de> + 'static,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let DeserializeStore {
+            objects,
+            mut labels,
+        } = DeserializeStore::deserialize(deserializer)?;
+
+        let store = Self::new();
+        for (index, object) in objects.into_iter().enumerate() {
+            match object {
+                DeserializeSlot::Object(object) => {
+                    store.insert(object).with_label(labels.remove(&index));
+                }
+                DeserializeSlot::Empty => {
+                    // Leave the slot empty, so the indices of the following
+                    // objects stay the same.
+                    drop(store.reserve());
+                }
+            }
+        }
+
+        register(&store.inner);
+
+        Ok(store)
+    }
+}
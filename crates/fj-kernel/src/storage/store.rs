@@ -21,16 +21,22 @@
 //!
 //! But in any case, this was fun to write, and not that much work.
 
-use std::{marker::PhantomData, sync::Arc};
+use std::{collections::BTreeSet, fmt, marker::PhantomData, sync::Arc};
 
-use parking_lot::RwLock;
+use fj_math::Scalar;
+use parking_lot::{Mutex, RwLock, RwLockReadGuard};
 
-use super::{blocks::Blocks, Handle};
+use super::{
+    blocks::{Blocks, Slot},
+    Handle, ObjectId,
+};
 
 /// Append-only object storage
 #[derive(Debug)]
 pub struct Store<T> {
-    inner: StoreInner<T>,
+    pub(super) inner: StoreInner<T>,
+    merge: Option<Mutex<Box<dyn Merge<T>>>>,
+    deduplicate: Mutex<()>,
 }
 
 impl<T> Store<T> {
@@ -39,18 +45,85 @@ impl<T> Store<T> {
         let block_size = 16384;
         Self {
             inner: Arc::new(RwLock::new(Blocks::new(block_size))),
+            merge: None,
+            deduplicate: Mutex::new(()),
+        }
+    }
+
+    /// Construct a `Store` that merges objects on insertion
+    ///
+    /// Inserting an object that `merge` considers coincident with one that is
+    /// already in the store, returns the [`Handle`] of the existing object,
+    /// instead of adding the new one.
+    pub fn with_merge(merge: impl Merge<T> + 'static) -> Self {
+        Self {
+            merge: Some(Mutex::new(Box::new(merge))),
+            ..Self::new()
         }
     }
 
     /// Insert an object into the store
+    ///
+    /// Objects can be inserted from multiple threads at once. Unless the store
+    /// merges objects, those insertions don't block each other.
     pub fn insert(&self, object: T) -> Handle<T> {
-        let mut blocks = self.inner.write();
-        let ptr = blocks.push(object);
+        // Holding the lock until the object has been added makes sure, that
+        // coincident objects that are inserted concurrently are still merged.
+        let mut merge = self.merge.as_ref().map(|merge| merge.lock());
+        if let Some(merge) = &merge {
+            if let Some(existing) = merge.find(&object) {
+                return existing;
+            }
+        }
 
-        Handle {
-            store: self.inner.clone(),
-            ptr,
+        // The handle must be created while the lock is held. Otherwise, the
+        // object could be collected as garbage before it's referenced.
+        let handle = {
+            let (blocks, index) = reserve(&self.inner);
+            Handle::new(self.inner.clone(), blocks.insert(index, object))
+        };
+
+        if let Some(merge) = &mut merge {
+            merge.add(handle.clone());
         }
+
+        handle
+    }
+
+    /// Insert an object, unless a coincident one is already in the store
+    ///
+    /// Returns the [`Handle`] of an existing object, if it is coincident with
+    /// `object` within `max_distance`. Inserts `object` otherwise. See
+    /// [`Coincident`].
+    ///
+    /// Unlike [`Store::with_merge`], this works with any store.
+    ///
+    /// # Limitations
+    ///
+    /// There is no spatial index, so `object` is compared with every object in
+    /// the store. This happens while holding a lock, meaning concurrent calls
+    /// don't run in parallel. Inserting many objects this way takes quadratic
+    /// time. For large stores, use [`Store::with_merge`] with a [`Merge`]
+    /// implementation that can find coincident objects more efficiently.
+    pub fn insert_or_get(
+        &self,
+        object: T,
+        max_distance: impl Into<Scalar>,
+    ) -> Handle<T>
+    where
+        T: Coincident,
+    {
+        let max_distance = max_distance.into();
+
+        // Holding the lock makes sure, that coincident objects that are
+        // inserted concurrently don't end up in the store twice.
+        let _deduplicate = self.deduplicate.lock();
+
+        let existing = self.iter().find(|existing| {
+            existing.is_coincident_with(&object, max_distance)
+        });
+
+        existing.unwrap_or_else(|| self.insert(object))
     }
 
     /// Iterate over all objects in this store
@@ -63,6 +136,115 @@ impl<T> Store<T> {
         }
     }
 
+    /// Iterate over all objects in this store that have the given label
+    ///
+    /// See [`Handle::with_label`].
+    pub fn find_by_label<'a>(
+        &'a self,
+        label: &'a str,
+    ) -> impl Iterator<Item = Handle<T>> + 'a {
+        self.iter()
+            .filter(move |handle| handle.label().as_deref() == Some(label))
+    }
+
+    /// Mark the current end of the store
+    ///
+    /// Objects are added to the store in the order they are inserted, which
+    /// makes the store a journal of all insertions. The returned [`Marker`]
+    /// refers to the current position in that journal, and can be passed to
+    /// [`Store::inserted_since`] and [`Store::rollback`].
+    pub fn marker(&self) -> Marker {
+        Marker {
+            position: self.inner.read().len(),
+        }
+    }
+
+    /// Iterate over all objects that have been inserted since `marker`
+    ///
+    /// Inserting an object that is merged with an existing one doesn't add
+    /// anything to the store, and thus isn't included here.
+    pub fn inserted_since(&self, marker: &Marker) -> Iter<T> {
+        let block_size = self.inner.read().block_size();
+
+        Iter {
+            store: self.inner.clone(),
+            next_block: marker.position / block_size,
+            next_object: marker.position % block_size,
+            _a: PhantomData,
+        }
+    }
+
+    /// Roll the store back to `marker`
+    ///
+    /// Discards all objects that have been inserted since `marker`. Discarded
+    /// objects are no longer returned by [`Store::iter`], their labels are
+    /// removed, and they are no longer merged with newly inserted objects.
+    ///
+    /// Handles to discarded objects remain valid, as the memory that objects
+    /// live in is never deallocated. But those handles should no longer be
+    /// used as part of a shape, since their objects are no longer part of the
+    /// store.
+    pub fn rollback(&self, marker: &Marker) {
+        // Holding the lock prevents merging insertions, while objects are being
+        // discarded.
+        let mut merge = self.merge.as_ref().map(|merge| merge.lock());
+
+        let discarded = self
+            .inserted_since(marker)
+            .map(|handle| handle.id())
+            .collect::<BTreeSet<_>>();
+        self.inner.write().discard(marker.position, &discarded);
+
+        if let Some(merge) = &mut merge {
+            merge.forget(&discarded);
+        }
+    }
+
+    /// Drop all objects that are no longer referenced by a [`Handle`]
+    ///
+    /// Labels of the dropped objects are removed, and [`WeakHandle`]s that
+    /// refer to them can no longer be upgraded. Any memory that the objects
+    /// themselves own is freed.
+    ///
+    /// # Limitations
+    ///
+    /// The slots that the dropped objects occupied are never reused, so the
+    /// store itself doesn't shrink, and new objects are still appended at its
+    /// end. Reusing slots would give new objects the [`ObjectId`]s of dropped
+    /// ones, and make [`WeakHandle`]s to dropped objects upgrade to unrelated
+    /// objects.
+    ///
+    /// Dropping objects drops any handles that they contain, which might leave
+    /// objects in other stores unreferenced. Use [`Objects::collect_garbage`]
+    /// to collect the garbage in all stores.
+    ///
+    /// Returns the number of objects that have been dropped.
+    ///
+    /// [`WeakHandle`]: super::WeakHandle
+    /// [`Objects::collect_garbage`]: crate::objects::Objects::collect_garbage
+    pub fn collect_garbage(&self) -> usize {
+        let mut merge = self.merge.as_ref().map(|merge| merge.lock());
+        let mut num_collected = 0;
+
+        // Dropping objects might leave other objects in the same store
+        // unreferenced, so repeat until there's nothing left to collect.
+        loop {
+            let collected = self.inner.write().collect_garbage();
+            if collected.is_empty() {
+                break;
+            }
+
+            num_collected += collected.len();
+
+            if let Some(merge) = &mut merge {
+                let ids = collected.iter().map(|(id, _)| *id).collect();
+                merge.forget(&ids);
+            }
+        }
+
+        num_collected
+    }
+
     /// Reserve a slot for an object
     ///
     /// Returns a [`Reservation`], which can be used to access the [`Handle`] of
@@ -70,8 +252,8 @@ impl<T> Store<T> {
     /// [`Handle`]'s ID in the construction of the object, or to create groups
     /// of objects that reference each other through their [`Handle`]s.
     pub fn reserve(&self) -> Reservation<T> {
-        let mut blocks = self.inner.write();
-        let (index, ptr) = blocks.reserve();
+        let (blocks, index) = reserve(&self.inner);
+        let ptr = blocks.get(index.0).expect("Reserved block").get(index.1);
 
         Reservation {
             store: self.inner.clone(),
@@ -81,6 +263,25 @@ impl<T> Store<T> {
     }
 }
 
+/// Reserve a slot, adding a new block, if the last one is full
+///
+/// Returns the slot's index, together with the lock that was held while
+/// reserving it. Only blocks are added while the store is locked for writing,
+/// so concurrent reservations only block each other, when that happens.
+fn reserve<T>(
+    store: &StoreInner<T>,
+) -> (RwLockReadGuard<'_, Blocks<T>>, (usize, usize)) {
+    loop {
+        let blocks = store.read();
+        if let Some(index) = blocks.reserve() {
+            return (blocks, index);
+        }
+        drop(blocks);
+
+        store.write().add_block();
+    }
+}
+
 impl<T> Default for Store<T> {
     fn default() -> Self {
         Self::new()
@@ -110,27 +311,47 @@ impl<'a, T: 'a> Iterator for Iter<'a, T> {
     fn next(&mut self) -> Option<Self::Item> {
         let blocks = self.store.read();
 
-        let block = blocks.get(self.next_block)?;
-        let object = block.get(self.next_object);
-
-        self.next_object += 1;
-        if self.next_object >= block.len() {
-            self.next_block += 1;
+        loop {
+            let block = blocks.get(self.next_block)?;
+            if self.next_object >= block.len() {
+                // We've caught up with the last reserved slot.
+                return None;
+            }
+
+            let index = (self.next_block, self.next_object);
+            let slot = block.get(self.next_object);
+
+            self.next_object += 1;
+            if self.next_object >= blocks.block_size() {
+                self.next_block += 1;
+                self.next_object = 0;
+            }
+
+            // Skip objects that have been discarded, collected as garbage, or
+            // whose reservations haven't been completed yet.
+            if blocks.is_discarded(index) || slot.object().is_none() {
+                continue;
+            }
+
+            return Some(Handle::new(self.store.clone(), slot));
         }
-
-        Some(Handle {
-            store: self.store.clone(),
-            ptr: object,
-        })
     }
 }
 
+/// A position in the journal of a [`Store`]
+///
+/// See [`Store::marker`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Marker {
+    position: usize,
+}
+
 /// A reservation of a slot for an object within a [`Store`]
 ///
 /// See [`Store::reserve`].
 pub struct Reservation<T> {
     store: StoreInner<T>,
-    ptr: *mut Option<T>,
+    ptr: *const Slot<T>,
     index: (usize, usize),
 }
 
@@ -141,10 +362,7 @@ impl<T> Reservation<T> {
     /// references, until you initialized that object by calling
     /// [`Reservation::complete`]. Doing otherwise will lead to a panic.
     pub fn handle(&self) -> Handle<T> {
-        Handle {
-            store: self.store.clone(),
-            ptr: self.ptr,
-        }
+        Handle::new(self.store.clone(), self.ptr)
     }
 
     /// Complete the reservation by providing an object
@@ -153,21 +371,55 @@ impl<T> Reservation<T> {
     /// [`Handle`]s you acquired from [`Reservation::handle`] without
     /// limitations.
     pub fn complete(self, object: T) -> Handle<T> {
-        let mut blocks = self.store.write();
+        let blocks = self.store.read();
         let ptr = blocks.insert(self.index, object);
 
-        Handle {
-            store: self.store.clone(),
-            ptr,
-        }
+        Handle::new(self.store.clone(), ptr)
     }
 }
 
 pub type StoreInner<T> = Arc<RwLock<Blocks<T>>>;
 
+/// Objects that can be compared with each other, to prevent duplicates
+///
+/// See [`Store::insert_or_get`].
+pub trait Coincident {
+    /// Determine whether `self` is coincident with `other`
+    ///
+    /// Any geometry is compared within `max_distance`. References to other
+    /// objects are compared by identity.
+    fn is_coincident_with(&self, other: &Self, max_distance: Scalar) -> bool;
+}
+
+/// Finds the objects in a [`Store`] that inserted objects are merged with
+///
+/// See [`Store::with_merge`].
+pub trait Merge<T>: fmt::Debug + Send {
+    /// Find an object that `object` is coincident with
+    fn find(&self, object: &T) -> Option<Handle<T>>;
+
+    /// Remember an object that has been added to the store
+    ///
+    /// Implementations should keep a [`WeakHandle`] instead of `handle`.
+    /// Otherwise, none of the objects that have been added here can ever be
+    /// collected as garbage.
+    ///
+    /// [`WeakHandle`]: super::WeakHandle
+    fn add(&mut self, handle: Handle<T>);
+
+    /// Forget objects that have been discarded or collected from the store
+    ///
+    /// See [`Store::rollback`] and [`Store::collect_garbage`].
+    fn forget(&mut self, discarded: &BTreeSet<ObjectId>);
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Store;
+    use std::{collections::BTreeSet, thread};
+
+    use crate::storage::{Handle, ObjectId};
+
+    use super::{Merge, Store};
 
     #[test]
     fn insert_and_handle() {
@@ -210,4 +462,116 @@ mod tests {
         let objects = store.iter().collect::<Vec<_>>();
         assert_eq!(objects, [a, b]);
     }
+
+    #[test]
+    fn merge() {
+        #[derive(Debug, Default)]
+        struct MergeEqual(Vec<Handle<i32>>);
+
+        impl Merge<i32> for MergeEqual {
+            fn find(&self, object: &i32) -> Option<Handle<i32>> {
+                self.0.iter().find(|handle| ***handle == *object).cloned()
+            }
+
+            fn add(&mut self, handle: Handle<i32>) {
+                self.0.push(handle);
+            }
+
+            fn forget(&mut self, discarded: &BTreeSet<ObjectId>) {
+                self.0.retain(|handle| !discarded.contains(&handle.id()));
+            }
+        }
+
+        let store = Store::with_merge(MergeEqual::default());
+
+        let a = store.insert(0);
+        let b = store.insert(1);
+        let c = store.insert(0);
+
+        assert_eq!(a.id(), c.id());
+        assert_ne!(a.id(), b.id());
+
+        let objects = store.iter().collect::<Vec<_>>();
+        assert_eq!(objects, [a, b]);
+    }
+
+    #[test]
+    fn labels() {
+        let store = Store::new();
+
+        let a = store.insert(0).with_label(Some("a"));
+        let b = store.insert(1).with_label(None::<String>);
+        let c = store.insert(2).with_label(Some("a"));
+
+        assert_eq!(a.label().as_deref(), Some("a"));
+        assert_eq!(b.label(), None);
+
+        let labeled = store.find_by_label("a").collect::<Vec<_>>();
+        assert_eq!(labeled, [a, c]);
+    }
+
+    #[test]
+    fn rollback() {
+        let store = Store::new();
+
+        let a = store.insert(0);
+        let marker = store.marker();
+        let b = store.insert(1).with_label(Some("b"));
+        let c = store.insert(2);
+
+        let inserted = store.inserted_since(&marker).collect::<Vec<_>>();
+        assert_eq!(inserted, [b.clone(), c]);
+
+        store.rollback(&marker);
+        let d = store.insert(3);
+
+        let objects = store.iter().collect::<Vec<_>>();
+        assert_eq!(objects, [a, d]);
+        assert_eq!(b.label(), None);
+    }
+
+    #[test]
+    fn collect_garbage() {
+        let store = Store::new();
+
+        let a = store.insert(0);
+        let b = store.insert(1).with_label(Some("b"));
+
+        let weak_a = a.downgrade();
+        let weak_b = b.downgrade();
+        drop(b);
+
+        assert_eq!(store.collect_garbage(), 1);
+        assert_eq!(store.iter().collect::<Vec<_>>(), [a]);
+        assert_eq!(store.find_by_label("b").count(), 0);
+
+        assert!(weak_a.upgrade().is_some());
+        assert!(weak_b.upgrade().is_none());
+    }
+
+    #[test]
+    fn insert_from_multiple_threads() {
+        let store = Store::new();
+
+        // Enough objects to fill multiple blocks.
+        let num_threads = 4;
+        let num_objects = 10_000;
+
+        thread::scope(|scope| {
+            for i in 0..num_threads {
+                let store = &store;
+                scope.spawn(move || {
+                    for j in 0..num_objects {
+                        store.insert(i * num_objects + j);
+                    }
+                });
+            }
+        });
+
+        let mut objects =
+            store.iter().map(|handle| *handle).collect::<Vec<_>>();
+        objects.sort();
+
+        assert_eq!(objects, (0..num_threads * num_objects).collect::<Vec<_>>());
+    }
 }
@@ -1,9 +1,16 @@
-use parry3d_f64::bounding_volume::BoundingVolume as _;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-use super::{Point, Vector};
+use parry3d_f64::{
+    bounding_volume::BoundingVolume as _,
+    query::{Ray, RayCast as _},
+};
+
+use super::{Point, Scalar, Vector};
 
 /// An axis-aligned bounding box (AABB)
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(C)]
 pub struct Aabb<const D: usize> {
     /// The minimum coordinates of the AABB
@@ -126,6 +133,48 @@ impl Aabb<3> {
     pub fn merged(&self, other: &Self) -> Self {
         self.to_parry().merged(&other.to_parry()).into()
     }
+
+    /// Determine whether this AABB intersects another
+    ///
+    /// AABBs that only touch are considered to intersect.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.to_parry().intersects(&other.to_parry())
+    }
+
+    /// Compute the distance between this AABB and another
+    ///
+    /// Returns zero, if the AABBs intersect.
+    pub fn distance_to(&self, other: &Self) -> Scalar {
+        let [min_a, max_a, min_b, max_b] =
+            [self.min, self.max, other.min, other.max]
+                .map(|point| point.coords.components);
+
+        let gap = [0, 1, 2].map(|i| {
+            (min_b[i] - max_a[i])
+                .max(min_a[i] - max_b[i])
+                .max(Scalar::ZERO)
+        });
+
+        Vector::from(gap).magnitude()
+    }
+
+    /// Cast a ray against the AABB
+    pub fn cast_local_ray(
+        &self,
+        origin: Point<3>,
+        dir: Vector<3>,
+        max_toi: f64,
+        solid: bool,
+    ) -> Option<Scalar> {
+        let ray = Ray {
+            origin: origin.to_na(),
+            dir: dir.to_na(),
+        };
+
+        self.to_parry()
+            .cast_local_ray(&ray, max_toi, solid)
+            .map(|f| f.into())
+    }
 }
 
 impl From<parry2d_f64::bounding_volume::AABB> for Aabb<2> {
@@ -142,6 +191,8 @@ impl From<parry3d_f64::bounding_volume::AABB> for Aabb<3> {
 
 #[cfg(test)]
 mod tests {
+    use crate::{Point, Scalar, Vector};
+
     use super::Aabb;
 
     #[test]
@@ -160,4 +211,55 @@ mod tests {
         assert!(!aabb.contains([0., 2.]));
         assert!(!aabb.contains([4., 2.]));
     }
+
+    #[test]
+    fn intersects() {
+        let aabb = Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]]);
+
+        assert!(aabb.intersects(&Aabb::<3>::from_points([
+            [0.5, 0.5, 0.5],
+            [2., 2., 2.]
+        ])));
+        assert!(aabb
+            .intersects(&Aabb::<3>::from_points([[1., 0., 0.], [2., 1., 1.]])));
+        assert!(!aabb
+            .intersects(&Aabb::<3>::from_points([[2., 0., 0.], [3., 1., 1.]])));
+    }
+
+    #[test]
+    fn distance_to() {
+        let aabb = Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]]);
+
+        let overlapping =
+            Aabb::<3>::from_points([[0.5, 0.5, 0.5], [2., 2., 2.]]);
+        assert_eq!(aabb.distance_to(&overlapping), Scalar::ZERO);
+
+        let diagonal = Aabb::<3>::from_points([[4., 5., 0.], [5., 6., 1.]]);
+        assert_eq!(aabb.distance_to(&diagonal), Scalar::from_f64(5.));
+    }
+
+    #[test]
+    fn cast_local_ray() {
+        let aabb = Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]]);
+        let origin = Point::from([-1., 0.5, 0.5]);
+
+        assert_eq!(
+            aabb.cast_local_ray(
+                origin,
+                Vector::from([1., 0., 0.]),
+                f64::INFINITY,
+                true
+            ),
+            Some(Scalar::ONE)
+        );
+        assert_eq!(
+            aabb.cast_local_ray(
+                origin,
+                Vector::from([-1., 0., 0.]),
+                f64::INFINITY,
+                true
+            ),
+            None
+        );
+    }
 }
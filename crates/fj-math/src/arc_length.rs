@@ -0,0 +1,304 @@
+use crate::{Point, Scalar};
+
+/// Arc length computations for a path
+///
+/// A path is described by its speed, meaning the magnitude of its first
+/// derivative with respect to the path coordinate. The arc length is computed
+/// by numerically integrating the speed. For paths whose speed is constant, or
+/// constant within each of their segments, the result is computed exactly
+/// instead.
+pub struct ArcLength<F> {
+    speed: F,
+
+    /// The speed of the path, if it is the same everywhere
+    constant_speed: Option<Scalar>,
+
+    /// The coordinates where segments of constant speed connect
+    ///
+    /// Only available for paths that consist of such segments.
+    segments: Option<Vec<Scalar>>,
+}
+
+impl<F> ArcLength<F>
+where
+    F: Fn(Scalar) -> Scalar,
+{
+    /// Construct an instance of `ArcLength` from the speed of a path
+    pub fn new(speed: F) -> Self {
+        Self {
+            speed,
+            constant_speed: None,
+            segments: None,
+        }
+    }
+
+    /// Mark the speed of the path as being the same everywhere
+    ///
+    /// Consumes the instance and returns the updated instance.
+    pub fn with_constant_speed(mut self, speed: Scalar) -> Self {
+        self.constant_speed = Some(speed);
+        self
+    }
+
+    /// Mark the path as consisting of segments of constant speed
+    ///
+    /// `joints` are the coordinates where the segments connect. Consumes the
+    /// instance and returns the updated instance.
+    pub fn with_segments(
+        mut self,
+        joints: impl IntoIterator<Item = Scalar>,
+    ) -> Self {
+        self.segments = Some(joints.into_iter().collect());
+        self
+    }
+
+    /// Compute the length of the path between the two given points
+    ///
+    /// The order of the points doesn't matter. The result is never negative.
+    pub fn length(&self, boundary: [impl Into<Point<1>>; 2]) -> Scalar {
+        let [a, b] = boundary.map(|point| point.into().t);
+        self.between(a, b).abs()
+    }
+
+    /// Find the point that is the given distance along the path from `start`
+    ///
+    /// A negative distance refers to a point before `start`.
+    pub fn point_at(
+        &self,
+        start: impl Into<Point<1>>,
+        distance: impl Into<Scalar>,
+    ) -> Point<1> {
+        let t = self.coord_at(start.into().t, distance.into());
+        Point::from([t])
+    }
+
+    /// Compute points that divide the path into pieces of equal length
+    ///
+    /// Returns `num_pieces + 1` points, including both points of the boundary.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `num_pieces` is zero.
+    pub fn points_evenly_spaced(
+        &self,
+        boundary: [impl Into<Point<1>>; 2],
+        num_pieces: usize,
+    ) -> Vec<Point<1>> {
+        assert!(num_pieces > 0, "Can't divide path into zero pieces");
+
+        let [a, b] = boundary.map(|point| point.into().t);
+
+        let length = self.between(a, b);
+        let num_pieces_scalar = Scalar::from_u64(num_pieces as u64);
+
+        let mut coords = vec![a];
+        coords.extend((1..num_pieces).map(|i| {
+            let distance = length * Scalar::from_u64(i as u64);
+            self.coord_at(a, distance / num_pieces_scalar)
+        }));
+        coords.push(b);
+
+        coords.into_iter().map(|t| Point::from([t])).collect()
+    }
+
+    /// Compute the signed arc length from `a` to `b`
+    ///
+    /// The result is negative, if `b` comes before `a`.
+    fn between(&self, a: Scalar, b: Scalar) -> Scalar {
+        if let Some(speed) = self.constant_speed {
+            return speed * (b - a);
+        }
+
+        let (min, max, sign) = if a <= b {
+            (a, b, Scalar::ONE)
+        } else {
+            (b, a, -Scalar::ONE)
+        };
+
+        let length = match &self.segments {
+            Some(segments) => {
+                let mut points = vec![min];
+                points.extend(
+                    segments.iter().copied().filter(|&t| t > min && t < max),
+                );
+                points.push(max);
+
+                // The speed is constant within each piece, but might be
+                // different at its boundaries. Evaluate it in the middle.
+                points
+                    .windows(2)
+                    .map(|window| {
+                        let [a, b] = [window[0], window[1]];
+                        (self.speed)(a + (b - a) / 2.) * (b - a)
+                    })
+                    .fold(Scalar::ZERO, |sum, length| sum + length)
+            }
+            None => self.integrate(min, max),
+        };
+
+        length * sign
+    }
+
+    /// Find the coordinate that is `distance` along the path from `start`
+    fn coord_at(&self, start: Scalar, distance: Scalar) -> Scalar {
+        if distance == Scalar::ZERO {
+            return start;
+        }
+        if let Some(speed) = self.constant_speed {
+            if speed == Scalar::ZERO {
+                return start;
+            }
+            return start + distance / speed;
+        }
+
+        // The arc length increases with the coordinate, so we're looking for
+        // the root of a monotonic function. Find a bracket around it first.
+        let error = |t: Scalar| self.between(start, t) - distance;
+
+        let initial_speed = (self.speed)(start);
+        let mut step = if initial_speed > Scalar::ZERO {
+            distance / initial_speed
+        } else {
+            distance
+        };
+
+        let mut inner = start;
+        let mut outer = start + step;
+        for _ in 0..MAX_ITERATIONS {
+            if error(outer) * distance.sign().to_scalar() >= Scalar::ZERO {
+                break;
+            }
+
+            inner = outer;
+            step *= 2.;
+            outer = start + step;
+        }
+
+        let (mut lo, mut hi) = if inner < outer {
+            (inner, outer)
+        } else {
+            (outer, inner)
+        };
+
+        // Now narrow the bracket down, using Newton's method where it works
+        // and bisection where it doesn't.
+        let tolerance = distance.abs().max(Scalar::ONE) * 1e-12;
+
+        let mut t = lo + (hi - lo) / 2.;
+        for _ in 0..MAX_ITERATIONS {
+            let e = error(t);
+            if e.abs() < tolerance {
+                break;
+            }
+
+            if e < Scalar::ZERO {
+                lo = t;
+            } else {
+                hi = t;
+            }
+
+            let speed = (self.speed)(t);
+            let newton = if speed > Scalar::ZERO {
+                Some(t - e / speed)
+            } else {
+                None
+            };
+
+            t = match newton {
+                Some(newton) if newton > lo && newton < hi => newton,
+                _ => lo + (hi - lo) / 2.,
+            };
+        }
+
+        t
+    }
+
+    /// Integrate the speed from `a` to `b`, using adaptive Simpson's rule
+    fn integrate(&self, a: Scalar, b: Scalar) -> Scalar {
+        let m = a + (b - a) / 2.;
+        let [fa, fm, fb] = [a, m, b].map(&self.speed);
+        let whole = simpson(a, b, fa, fm, fb);
+
+        let tolerance = whole.abs().max(Scalar::ONE) * 1e-12;
+        self.integrate_recursive([a, b], [fa, fm, fb], whole, tolerance, 0)
+    }
+
+    fn integrate_recursive(
+        &self,
+        [a, b]: [Scalar; 2],
+        [fa, fm, fb]: [Scalar; 3],
+        whole: Scalar,
+        tolerance: Scalar,
+        depth: usize,
+    ) -> Scalar {
+        let m = a + (b - a) / 2.;
+        let lm = a + (m - a) / 2.;
+        let rm = m + (b - m) / 2.;
+
+        let flm = (self.speed)(lm);
+        let frm = (self.speed)(rm);
+
+        let left = simpson(a, m, fa, flm, fm);
+        let right = simpson(m, b, fm, frm, fb);
+        let difference = left + right - whole;
+
+        if depth >= MAX_DEPTH || difference.abs() <= tolerance * 15. {
+            // Richardson extrapolation improves the result for free.
+            return left + right + difference / 15.;
+        }
+
+        self.integrate_recursive(
+            [a, m],
+            [fa, flm, fm],
+            left,
+            tolerance / 2.,
+            depth + 1,
+        ) + self.integrate_recursive(
+            [m, b],
+            [fm, frm, fb],
+            right,
+            tolerance / 2.,
+            depth + 1,
+        )
+    }
+}
+
+/// The maximum number of iterations when searching for a coordinate
+const MAX_ITERATIONS: usize = 100;
+
+/// The maximum recursion depth of the numerical integration
+const MAX_DEPTH: usize = 40;
+
+fn simpson(a: Scalar, b: Scalar, fa: Scalar, fm: Scalar, fb: Scalar) -> Scalar {
+    (b - a) / 6. * (fa + fm * 4. + fb)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Scalar};
+
+    use super::ArcLength;
+
+    #[test]
+    fn length() {
+        // A path whose speed increases linearly, like `t^2`.
+        let arc_length = ArcLength::new(|t: Scalar| t * 2.);
+        assert!((arc_length.length([[1.], [3.]]) - 8.).abs() < 1e-9.into());
+
+        let segments = ArcLength::new(|t: Scalar| t.floor() + 1.)
+            .with_segments([Scalar::ONE]);
+        assert_eq!(segments.length([[0.5], [2.]]), Scalar::from(2.5));
+    }
+
+    #[test]
+    fn point_at() {
+        let arc_length = ArcLength::new(|t: Scalar| t * 2.);
+
+        let point = arc_length.point_at([1.], 8.);
+        assert!((point - Point::from([3.])).magnitude() < 1e-9.into());
+
+        let constant = ArcLength::new(|_| Scalar::ZERO)
+            .with_constant_speed(Scalar::from(2.));
+        assert_eq!(constant.point_at([1.], -1.), Point::from([0.5]));
+    }
+}
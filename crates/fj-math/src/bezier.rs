@@ -0,0 +1,159 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Point, Scalar, Vector};
+
+/// An n-dimensional cubic Bézier curve
+///
+/// The dimensionality of the curve is defined by the const generic `D`
+/// parameter.
+///
+/// The curve is defined by four control points. It starts at the first and
+/// ends at the last one, at curve coordinates `0.` and `1.` respectively. The
+/// curve is still defined outside of that range, but it doesn't have much use
+/// there.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CubicBezier<const D: usize> {
+    control_points: [Point<D>; 4],
+}
+
+impl<const D: usize> CubicBezier<D> {
+    /// Construct a `CubicBezier` from its control points
+    pub fn from_control_points(
+        control_points: [impl Into<Point<D>>; 4],
+    ) -> Self {
+        let control_points = control_points.map(Into::into);
+        Self { control_points }
+    }
+
+    /// Access the control points of the curve
+    pub fn control_points(&self) -> [Point<D>; 4] {
+        self.control_points
+    }
+
+    /// Create a new instance that is reversed
+    ///
+    /// Curve coordinate `t` of the reversed curve corresponds to curve
+    /// coordinate `1 - t` of the original one.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.control_points.reverse();
+        self
+    }
+
+    /// Convert a point in curve coordinates into a `D`-dimensional point
+    pub fn point_from_bezier_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let t = point.into().t;
+        let s = Scalar::ONE - t;
+
+        let [p0, p1, p2, p3] = self.control_points.map(|point| point.coords);
+
+        let coords = p0 * (s * s * s)
+            + p1 * (s * s * t * 3.)
+            + p2 * (s * t * t * 3.)
+            + p3 * (t * t * t);
+
+        Point { coords }
+    }
+
+    /// Convert a vector in curve coordinates into a `D`-dimensional vector
+    ///
+    /// The result is the vector from the start of the curve to the point at
+    /// the given curve coordinate.
+    pub fn vector_from_bezier_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<D> {
+        let [start, ..] = self.control_points;
+        self.point_from_bezier_coords([vector.into().t]) - start
+    }
+
+    /// Compute the first derivative of the curve at the given coordinate
+    pub fn derivative_at(&self, point: impl Into<Point<1>>) -> Vector<D> {
+        let t = point.into().t;
+        let s = Scalar::ONE - t;
+
+        let [p0, p1, p2, p3] = self.control_points;
+
+        (p1 - p0) * (s * s * 3.)
+            + (p2 - p1) * (s * t * 6.)
+            + (p3 - p2) * (t * t * 3.)
+    }
+
+    /// Compute the second derivative of the curve at the given coordinate
+    ///
+    /// This is a measure for how much the curve bends at that point.
+    pub fn second_derivative_at(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Vector<D> {
+        let t = point.into().t;
+        let s = Scalar::ONE - t;
+
+        let [p0, p1, p2, p3] = self.control_points;
+
+        ((p2 - p1) - (p1 - p0)) * (s * 6.) + ((p3 - p2) - (p2 - p1)) * (t * 6.)
+    }
+}
+
+impl<const D: usize> approx::AbsDiffEq for CubicBezier<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.control_points
+            .iter()
+            .zip(other.control_points.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Vector};
+
+    use super::CubicBezier;
+
+    #[test]
+    fn point_from_bezier_coords() {
+        let bezier = CubicBezier::from_control_points([
+            [0., 0.],
+            [0., 1.],
+            [1., 1.],
+            [1., 0.],
+        ]);
+
+        assert_eq!(
+            bezier.point_from_bezier_coords([0.]),
+            Point::from([0., 0.])
+        );
+        assert_eq!(
+            bezier.point_from_bezier_coords([0.5]),
+            Point::from([0.5, 0.75])
+        );
+        assert_eq!(
+            bezier.point_from_bezier_coords([1.]),
+            Point::from([1., 0.])
+        );
+    }
+
+    #[test]
+    fn second_derivative_of_straight_curve() {
+        let bezier = CubicBezier::from_control_points([
+            [0., 0.],
+            [1., 0.],
+            [2., 0.],
+            [3., 0.],
+        ]);
+
+        assert_eq!(bezier.second_derivative_at([0.]), Vector::from([0., 0.]));
+        assert_eq!(bezier.second_derivative_at([1.]), Vector::from([0., 0.]));
+    }
+}
@@ -0,0 +1,399 @@
+use super::{Aabb, Point, Scalar, Vector};
+
+/// A bounding volume hierarchy (BVH)
+///
+/// A binary tree of axis-aligned bounding boxes, that contains an item in each
+/// leaf. Queries use the tree to skip over items whose bounding boxes can't be
+/// relevant, instead of checking every item.
+#[derive(Clone, Debug)]
+pub struct Bvh<T> {
+    /// The nodes of the tree
+    ///
+    /// The root is the first node, if the tree is not empty. The children of a
+    /// node always come after it.
+    nodes: Vec<Node>,
+
+    /// The items in the tree, with their bounding boxes
+    items: Vec<(Aabb<3>, T)>,
+}
+
+impl<T> Bvh<T> {
+    /// Build a BVH from items and their bounding boxes
+    pub fn new(items: impl IntoIterator<Item = (Aabb<3>, T)>) -> Self {
+        let items = items.into_iter().collect::<Vec<_>>();
+
+        let mut nodes = Vec::new();
+        let mut indices = (0..items.len()).collect::<Vec<_>>();
+        if !indices.is_empty() {
+            build(&mut nodes, &items, &mut indices);
+        }
+
+        Self { nodes, items }
+    }
+
+    /// Indicate whether the BVH is empty
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Return the number of items in the BVH
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Iterate over the items in the BVH
+    pub fn items(&self) -> impl Iterator<Item = &T> {
+        self.items.iter().map(|(_, item)| item)
+    }
+
+    /// Access the bounding box of the whole BVH
+    ///
+    /// Returns `None`, if the BVH is empty.
+    pub fn aabb(&self) -> Option<Aabb<3>> {
+        self.nodes.first().map(|node| node.aabb)
+    }
+
+    /// Update the bounding boxes of all items
+    ///
+    /// Keeps the structure of the tree, and only updates its bounding boxes.
+    /// This is cheaper than building a new tree, but queries get slower, the
+    /// more the items have moved relative to each other.
+    pub fn refit(&mut self, mut aabb: impl FnMut(&T) -> Aabb<3>) {
+        for (item_aabb, item) in &mut self.items {
+            *item_aabb = aabb(item);
+        }
+
+        // Children come after their parents, so going backwards updates all
+        // children before their parent.
+        for i in (0..self.nodes.len()).rev() {
+            self.nodes[i].aabb = match self.nodes[i].kind {
+                NodeKind::Leaf { item } => self.items[item].0,
+                NodeKind::Branch { children: [a, b] } => {
+                    self.nodes[a].aabb.merged(&self.nodes[b].aabb)
+                }
+            };
+        }
+    }
+
+    /// Find all items whose bounding boxes intersect the given one
+    pub fn query_aabb(&self, aabb: &Aabb<3>) -> Vec<&T> {
+        self.query(|node| node.intersects(aabb))
+    }
+
+    /// Find all items whose bounding boxes are hit by a ray
+    pub fn query_ray(&self, origin: Point<3>, dir: Vector<3>) -> Vec<&T> {
+        self.query(|node| {
+            node.cast_local_ray(origin, dir, f64::INFINITY, true)
+                .is_some()
+        })
+    }
+
+    /// Find all pairs of items from both BVHs, whose bounding boxes intersect
+    pub fn overlapping_pairs<'r, U>(
+        &'r self,
+        other: &'r Bvh<U>,
+    ) -> Vec<(&'r T, &'r U)> {
+        let mut pairs = Vec::new();
+        if self.is_empty() || other.is_empty() {
+            return pairs;
+        }
+
+        let mut stack = vec![(0, 0)];
+        while let Some((a, b)) = stack.pop() {
+            let [node_a, node_b] = [&self.nodes[a], &other.nodes[b]];
+            if !node_a.aabb.intersects(&node_b.aabb) {
+                continue;
+            }
+
+            match (node_a.kind, node_b.kind) {
+                (NodeKind::Leaf { item: a }, NodeKind::Leaf { item: b }) => {
+                    pairs.push((&self.items[a].1, &other.items[b].1));
+                }
+                _ => stack.extend(child_pairs(node_a, a, node_b, b)),
+            }
+        }
+
+        pairs
+    }
+
+    /// Find the pair of items from both BVHs that are closest to each other
+    ///
+    /// `distance` computes the distance between two items, along with any
+    /// result of that computation, that the caller is interested in. The
+    /// distance must not be smaller than the distance between the bounding
+    /// boxes of the items. Pairs that can't be closer than the closest pair
+    /// found so far are skipped.
+    ///
+    /// Returns `None`, if either BVH is empty.
+    pub fn closest_pair<U, R>(
+        &self,
+        other: &Bvh<U>,
+        mut distance: impl FnMut(&T, &U) -> (Scalar, R),
+    ) -> Option<R> {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+
+        let mut closest: Option<(Scalar, R)> = None;
+
+        let mut stack = vec![(0, 0)];
+        while let Some((a, b)) = stack.pop() {
+            let [node_a, node_b] = [&self.nodes[a], &other.nodes[b]];
+
+            if let Some((closest, _)) = &closest {
+                if node_a.aabb.distance_to(&node_b.aabb) >= *closest {
+                    continue;
+                }
+            }
+
+            match (node_a.kind, node_b.kind) {
+                (NodeKind::Leaf { item: a }, NodeKind::Leaf { item: b }) => {
+                    let (d, result) =
+                        distance(&self.items[a].1, &other.items[b].1);
+
+                    let is_closer = match &closest {
+                        Some((closest, _)) => d < *closest,
+                        None => true,
+                    };
+                    if is_closer {
+                        closest = Some((d, result));
+                    }
+                }
+                _ => {
+                    // Check the closer pairs of children first. They're more
+                    // likely to contain the closest pair, which allows for
+                    // skipping more of the others.
+                    let mut pairs = child_pairs(node_a, a, node_b, b);
+                    pairs.sort_by_key(|&(a, b)| {
+                        self.nodes[a].aabb.distance_to(&other.nodes[b].aabb)
+                    });
+                    stack.extend(pairs.into_iter().rev());
+                }
+            }
+        }
+
+        closest.map(|(_, result)| result)
+    }
+
+    fn query(&self, mut predicate: impl FnMut(&Aabb<3>) -> bool) -> Vec<&T> {
+        let mut items = Vec::new();
+        if self.is_empty() {
+            return items;
+        }
+
+        let mut stack = vec![0];
+        while let Some(i) = stack.pop() {
+            let node = &self.nodes[i];
+            if !predicate(&node.aabb) {
+                continue;
+            }
+
+            match node.kind {
+                NodeKind::Leaf { item } => items.push(&self.items[item].1),
+                NodeKind::Branch { children } => stack.extend(children),
+            }
+        }
+
+        items
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    aabb: Aabb<3>,
+    kind: NodeKind,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NodeKind {
+    Leaf { item: usize },
+    Branch { children: [usize; 2] },
+}
+
+/// Build the subtree that contains the items at `indices`
+///
+/// Returns the index of the root of the subtree.
+fn build<T>(
+    nodes: &mut Vec<Node>,
+    items: &[(Aabb<3>, T)],
+    indices: &mut [usize],
+) -> usize {
+    let aabb = indices
+        .iter()
+        .map(|&i| items[i].0)
+        .reduce(|a, b| a.merged(&b))
+        .expect("Can't build subtree without items");
+
+    if let [item] = *indices {
+        nodes.push(Node {
+            aabb,
+            kind: NodeKind::Leaf { item },
+        });
+        return nodes.len() - 1;
+    }
+
+    // Split the items in half, along the axis in which their centers are
+    // spread out the most.
+    let centers = Aabb::<3>::from_points(
+        indices
+            .iter()
+            .map(|&i| items[i].0.center())
+            .collect::<Vec<_>>(),
+    );
+    let size = centers.size().components;
+    let axis = (0..3)
+        .max_by_key(|&axis| size[axis])
+        .expect("Range is not empty");
+    indices.sort_by_key(|&i| items[i].0.center().coords.components[axis]);
+
+    // Reserve the node, so it comes before its children.
+    let node = nodes.len();
+    nodes.push(Node {
+        aabb,
+        kind: NodeKind::Leaf { item: indices[0] },
+    });
+
+    let (left, right) = indices.split_at_mut(indices.len() / 2);
+    let children = [build(nodes, items, left), build(nodes, items, right)];
+    nodes[node].kind = NodeKind::Branch { children };
+
+    node
+}
+
+/// Determine the pairs of nodes to check after a pair of nodes
+///
+/// Descends into the larger node, or into the one that isn't a leaf.
+fn child_pairs(
+    node_a: &Node,
+    a: usize,
+    node_b: &Node,
+    b: usize,
+) -> Vec<(usize, usize)> {
+    let volume = |node: &Node| {
+        let [x, y, z] = node.aabb.size().components;
+        x * y * z
+    };
+
+    match (node_a.kind, node_b.kind) {
+        (NodeKind::Branch { children }, NodeKind::Leaf { .. }) => {
+            children.into_iter().map(|a| (a, b)).collect()
+        }
+        (NodeKind::Branch { children }, _)
+            if volume(node_a) >= volume(node_b) =>
+        {
+            children.into_iter().map(|a| (a, b)).collect()
+        }
+        (_, NodeKind::Branch { children }) => {
+            children.into_iter().map(|b| (a, b)).collect()
+        }
+        (NodeKind::Leaf { .. }, NodeKind::Leaf { .. }) => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Aabb, Point, Scalar, Vector};
+
+    use super::Bvh;
+
+    #[test]
+    fn query_aabb() {
+        let bvh = row_of_cubes(10);
+
+        let mut items = bvh.query_aabb(&Aabb::<3>::from_points([
+            [2.5, 0., 0.],
+            [4.5, 1., 1.],
+        ]));
+        items.sort();
+
+        assert_eq!(items, [&2, &3, &4]);
+    }
+
+    #[test]
+    fn query_ray() {
+        let bvh = row_of_cubes(10);
+
+        let mut items = bvh.query_ray(
+            Point::from([4.5, -1., 0.5]),
+            Vector::from([0., 1., 0.]),
+        );
+        items.sort();
+        assert_eq!(items, [&4]);
+
+        let items = bvh
+            .query_ray(Point::from([4.5, -1., 2.]), Vector::from([0., 1., 0.]));
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn refit() {
+        let mut bvh = row_of_cubes(10);
+
+        // Move the cubes upward, by a distance that depends on the cube.
+        bvh.refit(|&i| cube_at([i as f64, i as f64, 0.]));
+
+        let mut items = bvh.query_aabb(&Aabb::<3>::from_points([
+            [5.5, 5.5, 0.],
+            [6.5, 6.5, 1.],
+        ]));
+        items.sort();
+        assert_eq!(items, [&5, &6]);
+    }
+
+    #[test]
+    fn overlapping_pairs() {
+        let a = row_of_cubes(10);
+        let b = Bvh::new([
+            (cube_at([2.5, 0., 0.]), 'a'),
+            (cube_at([7.5, 0., 0.]), 'b'),
+        ]);
+
+        let mut pairs = a.overlapping_pairs(&b);
+        pairs.sort();
+
+        assert_eq!(pairs, [(&2, &'a'), (&3, &'a'), (&7, &'b'), (&8, &'b')]);
+    }
+
+    #[test]
+    fn closest_pair() {
+        let a = row_of_cubes(10);
+        let b = Bvh::new([
+            (cube_at([20., 1., 0.]), 20),
+            (cube_at([12., 1., 0.]), 12),
+        ]);
+
+        let mut checked = 0;
+        let closest = a.closest_pair(&b, |&a, &b| {
+            checked += 1;
+
+            let [a_aabb, b_aabb] =
+                [(a, 0.), (b, 1.)].map(|(i, y)| cube_at([i as f64, y, 0.]));
+            (a_aabb.distance_to(&b_aabb), (a, b))
+        });
+
+        assert_eq!(closest, Some((9, 12)));
+        assert!(checked < 20);
+    }
+
+    #[test]
+    fn empty() {
+        let bvh = Bvh::<()>::new([]);
+
+        assert!(bvh.is_empty());
+        assert!(bvh.aabb().is_none());
+        assert!(bvh
+            .query_aabb(&Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]]))
+            .is_empty());
+        assert_eq!(bvh.closest_pair(&bvh, |_, _| (Scalar::ZERO, ())), None);
+    }
+
+    /// Create a row of unit cubes along the x-axis, each labeled with its index
+    fn row_of_cubes(n: usize) -> Bvh<usize> {
+        Bvh::new((0..n).map(|i| (cube_at([i as f64, 0., 0.]), i)))
+    }
+
+    fn cube_at(min: [f64; 3]) -> Aabb<3> {
+        let [x, y, z] = min;
+        Aabb::<3>::from_points([[x, y, z], [x + 1., y + 1., z + 1.]])
+    }
+}
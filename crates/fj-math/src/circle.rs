@@ -1,3 +1,6 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use approx::AbsDiffEq;
 
 use crate::{Point, Scalar, Vector};
@@ -7,6 +10,7 @@ use crate::{Point, Scalar, Vector};
 /// The dimensionality of the circle is defined by the const generic `D`
 /// parameter.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Circle<const D: usize> {
     center: Point<D>,
     a: Vector<D>,
@@ -123,8 +127,10 @@ impl<const D: usize> Circle<D> {
         &self,
         point: impl Into<Point<D>>,
     ) -> Point<1> {
-        let vector = (point.into() - self.center).to_uv();
-        let atan = Scalar::atan2(vector.v, vector.u);
+        // Both axes have the same length, so the point's components along them
+        // are proportional to the cosine and sine of its angle.
+        let vector = point.into() - self.center;
+        let atan = Scalar::atan2(vector.dot(&self.b), vector.dot(&self.a));
         let coord = if atan >= Scalar::ZERO {
             atan
         } else {
@@ -151,6 +157,21 @@ impl<const D: usize> Circle<D> {
 
         self.a * cos + self.b * sin
     }
+
+    /// Compute the first derivative of the circle at the given coordinate
+    pub fn derivative_at(&self, point: impl Into<Point<1>>) -> Vector<D> {
+        let (sin, cos) = point.into().t.sin_cos();
+        self.b * cos - self.a * sin
+    }
+
+    /// Compute the second derivative of the circle at the given coordinate
+    pub fn second_derivative_at(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Vector<D> {
+        let (sin, cos) = point.into().t.sin_cos();
+        -(self.a * cos + self.b * sin)
+    }
 }
 
 impl<const D: usize> approx::AbsDiffEq for Circle<D> {
@@ -200,4 +221,15 @@ mod tests {
             Point::from([FRAC_PI_2 * 3.]),
         );
     }
+
+    #[test]
+    fn point_to_reversed_circle_coords() {
+        let circle = Circle::from_center_and_radius([0., 0.], 1.).reverse();
+
+        assert_eq!(circle.point_to_circle_coords([1., 0.]), Point::from([0.]));
+        assert_eq!(
+            circle.point_to_circle_coords([0., -1.]),
+            Point::from([FRAC_PI_2]),
+        );
+    }
 }
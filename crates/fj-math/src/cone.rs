@@ -0,0 +1,307 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+
+use crate::{Circle, Point, Scalar, Vector};
+
+/// A circular cone in 3D space
+///
+/// The cone is defined by a base circle, an axis, and a slope. Its coordinate
+/// system works like that of a [`Cylinder`](crate::Cylinder), except that the
+/// radius changes linearly along the axis: The u-coordinate is an angle in
+/// radians around the axis, as defined by the base [`Circle`]. The
+/// v-coordinate is the distance along the axis, in units of the axis vector's
+/// length.
+///
+/// The slope defines by how much the radius changes, per unit of the
+/// v-coordinate. A slope of zero results in a cylinder, but cylinders should
+/// be represented as such.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cone {
+    origin: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+    axis: Vector<3>,
+    slope: Scalar,
+}
+
+impl Cone {
+    /// Construct a cone
+    ///
+    /// `origin` is the center of the base circle, `a` and `b` define the base
+    /// circle, as they would for a [`Circle`]. `axis` defines the direction of
+    /// the cone, as well as the scale of its v-coordinate. `slope` is the
+    /// change in radius per unit of the v-coordinate.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - The radius of the base circle (defined by the length of `a` and `b`)
+    ///   must not be zero.
+    /// - `a` and `b` must be of equal length.
+    /// - `axis` must not be zero.
+    /// - `a`, `b`, and `axis` must be perpendicular to each other.
+    pub fn new(
+        origin: impl Into<Point<3>>,
+        a: impl Into<Vector<3>>,
+        b: impl Into<Vector<3>>,
+        axis: impl Into<Vector<3>>,
+        slope: impl Into<Scalar>,
+    ) -> Self {
+        let origin = origin.into();
+        let a = a.into();
+        let b = b.into();
+        let axis = axis.into();
+        let slope = slope.into();
+
+        assert_eq!(
+            a.magnitude(),
+            b.magnitude(),
+            "`a` and `b` must be of equal length"
+        );
+        assert_ne!(a.magnitude(), Scalar::ZERO, "base radius must not be zero");
+        assert_ne!(
+            axis.magnitude(),
+            Scalar::ZERO,
+            "cone axis must not be zero"
+        );
+        // See comment in `Circle::new` on why we're using an epsilon here.
+        assert!(
+            a.dot(&b).abs() < Scalar::default_epsilon()
+                && a.dot(&axis).abs() < Scalar::default_epsilon()
+                && b.dot(&axis).abs() < Scalar::default_epsilon(),
+            "`a`, `b`, and `axis` must be perpendicular to each other"
+        );
+
+        Self {
+            origin,
+            a,
+            b,
+            axis,
+            slope,
+        }
+    }
+
+    /// Construct a `Cone` from a base circle, an axis, and a half-angle
+    ///
+    /// The half-angle is the angle between the axis and the surface of the
+    /// cone, in radians. A positive angle results in a cone that widens along
+    /// the axis, a negative one in a cone that narrows.
+    ///
+    /// Returns `None`, if `axis` is not perpendicular to the plane of the
+    /// circle.
+    pub fn from_circle_and_half_angle(
+        circle: &Circle<3>,
+        axis: impl Into<Vector<3>>,
+        half_angle: impl Into<Scalar>,
+    ) -> Option<Self> {
+        let axis = axis.into();
+
+        // These are the same checks that `Self::new` does, so we can be sure
+        // it's not going to panic.
+        if axis.magnitude() == Scalar::ZERO
+            || circle.a().dot(&axis).abs() >= Scalar::default_epsilon()
+            || circle.b().dot(&axis).abs() >= Scalar::default_epsilon()
+        {
+            return None;
+        }
+
+        let (sin, cos) = half_angle.into().sin_cos();
+        let slope = axis.magnitude() * sin / cos;
+
+        Some(Self::new(
+            circle.center(),
+            circle.a(),
+            circle.b(),
+            axis,
+            slope,
+        ))
+    }
+
+    /// Access the center of the base circle
+    pub fn origin(&self) -> Point<3> {
+        self.origin
+    }
+
+    /// Access the vector that defines the start of the u-coordinate
+    ///
+    /// Please also refer to [`Self::b`].
+    pub fn a(&self) -> Vector<3> {
+        self.a
+    }
+
+    /// Access the vector that defines the direction of the u-coordinate
+    ///
+    /// Please also refer to [`Self::a`].
+    pub fn b(&self) -> Vector<3> {
+        self.b
+    }
+
+    /// Access the axis of the cone
+    pub fn axis(&self) -> Vector<3> {
+        self.axis
+    }
+
+    /// Access the change in radius per unit of the v-coordinate
+    pub fn slope(&self) -> Scalar {
+        self.slope
+    }
+
+    /// Access the radius of the base circle
+    pub fn base_radius(&self) -> Scalar {
+        self.a.magnitude()
+    }
+
+    /// Compute the radius of the cone at the given v-coordinate
+    ///
+    /// The radius is negative beyond the apex.
+    pub fn radius_at(&self, v: impl Into<Scalar>) -> Scalar {
+        self.base_radius() + self.slope * v.into()
+    }
+
+    /// Compute the v-coordinate of the apex
+    ///
+    /// Returns `None`, if the slope is zero, as the cone is a cylinder then.
+    pub fn apex_v(&self) -> Option<Scalar> {
+        if self.slope == Scalar::ZERO {
+            return None;
+        }
+
+        Some(-self.base_radius() / self.slope)
+    }
+
+    /// Compute the apex of the cone
+    ///
+    /// Returns `None`, if the slope is zero, as the cone is a cylinder then.
+    pub fn apex(&self) -> Option<Point<3>> {
+        self.apex_v().map(|v| self.origin + self.axis * v)
+    }
+
+    /// Convert a 3D point into cone coordinates
+    ///
+    /// The u-coordinate is between `0.` (inclusive) and `PI * 2.`
+    /// (exclusive). Beyond the apex, where the radius becomes negative, the
+    /// u-coordinate is offset by `PI`, compared to the direction of the point
+    /// from the axis. The point is projected onto the cone along the radius,
+    /// if it's not already on there.
+    pub fn point_to_cone_coords(&self, point: impl Into<Point<3>>) -> Point<2> {
+        let vector = point.into() - self.origin;
+
+        let v = vector.dot(&self.axis) / self.axis.dot(&self.axis);
+
+        let mut angle = Scalar::atan2(vector.dot(&self.b), vector.dot(&self.a));
+        if self.radius_at(v) < Scalar::ZERO {
+            angle += Scalar::PI;
+        }
+        let u = angle % Scalar::TAU;
+        let u = if u >= Scalar::ZERO {
+            u
+        } else {
+            u + Scalar::TAU
+        };
+
+        Point::from([u, v])
+    }
+
+    /// Convert a point in cone coordinates into a 3D point
+    pub fn point_from_cone_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        self.origin + self.vector_from_cone_coords(point.into().coords)
+    }
+
+    /// Convert a vector in cone coordinates into a 3D vector
+    ///
+    /// The result is the vector from the center of the base circle to the
+    /// point at the given cone coordinates.
+    pub fn vector_from_cone_coords(
+        &self,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let vector = vector.into();
+        let (sin, cos) = vector.u.sin_cos();
+
+        let scale = self.radius_at(vector.v) / self.base_radius();
+
+        (self.a * cos + self.b * sin) * scale + self.axis * vector.v
+    }
+}
+
+impl approx::AbsDiffEq for Cone {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.origin.abs_diff_eq(&other.origin, epsilon)
+            && self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+            && self.axis.abs_diff_eq(&other.axis, epsilon)
+            && self.slope.abs_diff_eq(&other.slope, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Circle, Point, Scalar};
+
+    use super::Cone;
+
+    #[test]
+    fn point_to_and_from_cone_coords() {
+        let cone = Cone::new(
+            [0., 0., 0.],
+            [2., 0., 0.],
+            [0., 2., 0.],
+            [0., 0., 1.],
+            -1.,
+        );
+
+        assert_eq!(cone.apex(), Some(Point::from([0., 0., 2.])));
+        assert_abs_diff_eq!(
+            cone.point_from_cone_coords([FRAC_PI_2, 1.]),
+            Point::from([0., 1., 1.]),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        for coords in [[FRAC_PI_4, 1.], [FRAC_PI_4, 3.]] {
+            let coords = Point::from(coords);
+            assert_abs_diff_eq!(
+                cone.point_to_cone_coords(cone.point_from_cone_coords(coords)),
+                coords,
+                epsilon = Scalar::from(1e-12),
+            );
+        }
+    }
+
+    #[test]
+    fn from_circle_and_half_angle() {
+        let circle = Circle::from_center_and_radius([0., 0., 0.], 1.);
+
+        let cone =
+            Cone::from_circle_and_half_angle(&circle, [0., 0., 2.], FRAC_PI_4)
+                .unwrap();
+        assert_abs_diff_eq!(
+            cone.radius_at(1.),
+            Scalar::from(3.),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        assert!(Cone::from_circle_and_half_angle(
+            &circle,
+            [0., 1., 1.],
+            FRAC_PI_4
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,231 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+
+use crate::{Circle, Point, Scalar, Vector};
+
+/// A circular cylinder in 3D space
+///
+/// The cylinder is defined by a base circle and an axis. Its coordinate system
+/// works like that of a circle that is swept along the axis: The
+/// u-coordinate is an angle in radians around the axis, as defined by the
+/// base [`Circle`]. The v-coordinate is the distance along the axis, in units
+/// of the axis vector's length.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cylinder {
+    origin: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+    axis: Vector<3>,
+}
+
+impl Cylinder {
+    /// Construct a cylinder
+    ///
+    /// `origin` is the center of the base circle, `a` and `b` define the base
+    /// circle, as they would for a [`Circle`]. `axis` defines the direction of
+    /// the cylinder, as well as the scale of its v-coordinate.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - The cylinder radius (defined by the length of `a` and `b`) must not
+    ///   be zero.
+    /// - `a` and `b` must be of equal length.
+    /// - `axis` must not be zero.
+    /// - `a`, `b`, and `axis` must be perpendicular to each other.
+    pub fn new(
+        origin: impl Into<Point<3>>,
+        a: impl Into<Vector<3>>,
+        b: impl Into<Vector<3>>,
+        axis: impl Into<Vector<3>>,
+    ) -> Self {
+        let origin = origin.into();
+        let a = a.into();
+        let b = b.into();
+        let axis = axis.into();
+
+        assert_eq!(
+            a.magnitude(),
+            b.magnitude(),
+            "`a` and `b` must be of equal length"
+        );
+        assert_ne!(
+            a.magnitude(),
+            Scalar::ZERO,
+            "cylinder radius must not be zero"
+        );
+        assert_ne!(
+            axis.magnitude(),
+            Scalar::ZERO,
+            "cylinder axis must not be zero"
+        );
+        // See comment in `Circle::new` on why we're using an epsilon here.
+        assert!(
+            a.dot(&b).abs() < Scalar::default_epsilon()
+                && a.dot(&axis).abs() < Scalar::default_epsilon()
+                && b.dot(&axis).abs() < Scalar::default_epsilon(),
+            "`a`, `b`, and `axis` must be perpendicular to each other"
+        );
+
+        Self { origin, a, b, axis }
+    }
+
+    /// Construct a `Cylinder` from a circle and an axis
+    ///
+    /// Returns `None`, if `axis` is not perpendicular to the plane of the
+    /// circle.
+    pub fn from_circle_and_axis(
+        circle: &Circle<3>,
+        axis: impl Into<Vector<3>>,
+    ) -> Option<Self> {
+        let axis = axis.into();
+
+        // These are the same checks that `Self::new` does, so we can be sure
+        // it's not going to panic.
+        if axis.magnitude() == Scalar::ZERO
+            || circle.a().dot(&axis).abs() >= Scalar::default_epsilon()
+            || circle.b().dot(&axis).abs() >= Scalar::default_epsilon()
+        {
+            return None;
+        }
+
+        Some(Self::new(circle.center(), circle.a(), circle.b(), axis))
+    }
+
+    /// Access the center of the base circle
+    pub fn origin(&self) -> Point<3> {
+        self.origin
+    }
+
+    /// Access the radius of the cylinder
+    pub fn radius(&self) -> Scalar {
+        self.a.magnitude()
+    }
+
+    /// Access the vector that defines the start of the u-coordinate
+    ///
+    /// Please also refer to [`Self::b`].
+    pub fn a(&self) -> Vector<3> {
+        self.a
+    }
+
+    /// Access the vector that defines the direction of the u-coordinate
+    ///
+    /// Please also refer to [`Self::a`].
+    pub fn b(&self) -> Vector<3> {
+        self.b
+    }
+
+    /// Access the axis of the cylinder
+    pub fn axis(&self) -> Vector<3> {
+        self.axis
+    }
+
+    /// Access the base circle of the cylinder
+    ///
+    /// This is the circle at v-coordinate `0.`.
+    pub fn base_circle(&self) -> Circle<3> {
+        Circle::new(self.origin, self.a, self.b)
+    }
+
+    /// Convert a 3D point into cylinder coordinates
+    ///
+    /// The u-coordinate is between `0.` (inclusive) and `PI * 2.`
+    /// (exclusive). The point is projected onto the cylinder, if it's not
+    /// already on there.
+    pub fn point_to_cylinder_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let vector = point.into() - self.origin;
+
+        let atan = Scalar::atan2(vector.dot(&self.b), vector.dot(&self.a));
+        let u = if atan >= Scalar::ZERO {
+            atan
+        } else {
+            atan + Scalar::TAU
+        };
+        let v = vector.dot(&self.axis) / self.axis.dot(&self.axis);
+
+        Point::from([u, v])
+    }
+
+    /// Convert a point in cylinder coordinates into a 3D point
+    pub fn point_from_cylinder_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        self.origin + self.vector_from_cylinder_coords(point.into().coords)
+    }
+
+    /// Convert a vector in cylinder coordinates into a 3D vector
+    pub fn vector_from_cylinder_coords(
+        &self,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let vector = vector.into();
+        let (sin, cos) = vector.u.sin_cos();
+
+        self.a * cos + self.b * sin + self.axis * vector.v
+    }
+}
+
+impl approx::AbsDiffEq for Cylinder {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.origin.abs_diff_eq(&other.origin, epsilon)
+            && self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+            && self.axis.abs_diff_eq(&other.axis, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Circle, Point, Scalar};
+
+    use super::Cylinder;
+
+    #[test]
+    fn point_to_and_from_cylinder_coords() {
+        let cylinder = Cylinder::new(
+            [1., 0., 0.],
+            [2., 0., 0.],
+            [0., 2., 0.],
+            [0., 0., 3.],
+        );
+
+        let point = cylinder.point_from_cylinder_coords([FRAC_PI_2, 1.]);
+        assert_abs_diff_eq!(
+            point,
+            Point::from([1., 2., 3.]),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            cylinder.point_to_cylinder_coords(point),
+            Point::from([FRAC_PI_2, 1.]),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+
+    #[test]
+    fn from_circle_and_axis() {
+        let circle = Circle::from_center_and_radius([0., 0., 0.], 1.);
+
+        assert!(Cylinder::from_circle_and_axis(&circle, [0., 0., 1.]).is_some());
+        assert!(Cylinder::from_circle_and_axis(&circle, [0., 1., 1.]).is_none());
+    }
+}
@@ -0,0 +1,301 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+
+use crate::{Circle, Point, Scalar, Vector};
+
+/// An n-dimensional ellipse
+///
+/// The dimensionality of the ellipse is defined by the const generic `D`
+/// parameter.
+///
+/// The ellipse is defined by its center and two conjugate semi-diameters, `a`
+/// and `b`. If they are perpendicular, as for ellipses constructed from radii,
+/// they are the semi-axes of the ellipse. An affine transformation of a circle,
+/// which preserves the coordinates of its points, generally results in
+/// semi-diameters that are not.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ellipse<const D: usize> {
+    center: Point<D>,
+    a: Vector<D>,
+    b: Vector<D>,
+}
+
+impl<const D: usize> Ellipse<D> {
+    /// Construct an ellipse
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - Neither `a` nor `b` must be of zero length.
+    /// - `a` and `b` must not be parallel to each other.
+    pub fn new(
+        center: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+
+        assert_ne!(
+            a.magnitude(),
+            Scalar::ZERO,
+            "ellipse radii must not be zero"
+        );
+        assert_ne!(
+            b.magnitude(),
+            Scalar::ZERO,
+            "ellipse radii must not be zero"
+        );
+        // See comment in `Circle::new` on why we're using an epsilon here.
+        let gram_determinant = a.dot(&a) * b.dot(&b) - a.dot(&b) * a.dot(&b);
+        assert!(
+            gram_determinant > Scalar::default_epsilon(),
+            "`a` and `b` must not be parallel to each other"
+        );
+
+        Self { center, a, b }
+    }
+
+    /// Construct an axis-aligned `Ellipse` from a center point and two radii
+    ///
+    /// The first radius is measured along the first axis, the second radius
+    /// along the second axis.
+    pub fn from_center_and_radii(
+        center: impl Into<Point<D>>,
+        radii: [impl Into<Scalar>; 2],
+    ) -> Self {
+        let [radius_a, radius_b] = radii.map(Into::into);
+
+        let mut a = [Scalar::ZERO; D];
+        let mut b = [Scalar::ZERO; D];
+
+        a[0] = radius_a;
+        b[1] = radius_b;
+
+        Self::new(center, a, b)
+    }
+
+    /// Access the center point of the ellipse
+    pub fn center(&self) -> Point<D> {
+        self.center
+    }
+
+    /// Access the vector that defines the starting point of the ellipse
+    ///
+    /// The point where this vector points from the ellipse center, is the zero
+    /// coordinate of the ellipse's coordinate system. The length of the vector
+    /// defines the ellipse's first radius.
+    ///
+    /// Please also refer to [`Self::b`].
+    pub fn a(&self) -> Vector<D> {
+        self.a
+    }
+
+    /// Access the vector that defines the plane of the ellipse
+    ///
+    /// Also defines the direction of the ellipse's coordinate system. If it is
+    /// perpendicular to [`Self::a`], its length defines the ellipse's second
+    /// radius.
+    pub fn b(&self) -> Vector<D> {
+        self.b
+    }
+
+    /// Access the larger of the ellipse's two radii
+    pub fn major_radius(&self) -> Scalar {
+        // The squared radii are the eigenvalues of the Gram matrix of `a` and
+        // `b`. If those are perpendicular, the larger one is simply the larger
+        // of their squared lengths.
+        let aa = self.a.dot(&self.a);
+        let ab = self.a.dot(&self.b);
+        let bb = self.b.dot(&self.b);
+
+        let mean = (aa + bb) / 2.;
+        let half_difference = (aa - bb) / 2.;
+        let deviation = (half_difference * half_difference + ab * ab).sqrt();
+
+        (mean + deviation).sqrt()
+    }
+
+    /// Create a new instance that is reversed
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.b = -self.b;
+        self
+    }
+
+    /// Express a vector as a combination of [`Self::a`] and [`Self::b`]
+    ///
+    /// Returns `[x, y]`, such that `a * x + b * y` is the vector. Vectors that
+    /// are not in the plane of the ellipse are projected into it.
+    pub fn vector_to_components(
+        &self,
+        vector: impl Into<Vector<D>>,
+    ) -> [Scalar; 2] {
+        let vector = vector.into();
+
+        let aa = self.a.dot(&self.a);
+        let ab = self.a.dot(&self.b);
+        let bb = self.b.dot(&self.b);
+        let va = vector.dot(&self.a);
+        let vb = vector.dot(&self.b);
+
+        let determinant = aa * bb - ab * ab;
+        [
+            (bb * va - ab * vb) / determinant,
+            (aa * vb - ab * va) / determinant,
+        ]
+    }
+
+    /// Convert a `D`-dimensional point to ellipse coordinates
+    ///
+    /// Converts the provided point into ellipse coordinates between `0.`
+    /// (inclusive) and `PI * 2.` (exclusive).
+    ///
+    /// Like [`Circle::point_to_circle_coords`], this projects the point onto
+    /// the ellipse before computing the coordinate. Points that are not on the
+    /// ellipse will not result in an error.
+    pub fn point_to_ellipse_coords(
+        &self,
+        point: impl Into<Point<D>>,
+    ) -> Point<1> {
+        let [cos, sin] = self.vector_to_components(point.into() - self.center);
+
+        let atan = Scalar::atan2(sin, cos);
+        let coord = if atan >= Scalar::ZERO {
+            atan
+        } else {
+            atan + Scalar::TAU
+        };
+        Point::from([coord])
+    }
+
+    /// Convert a point in ellipse coordinates into a `D`-dimensional point
+    pub fn point_from_ellipse_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        self.center + self.vector_from_ellipse_coords(point.into().coords)
+    }
+
+    /// Convert a vector in ellipse coordinates into a `D`-dimensional point
+    pub fn vector_from_ellipse_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<D> {
+        let angle = vector.into().t;
+        let (sin, cos) = angle.sin_cos();
+
+        self.a * cos + self.b * sin
+    }
+
+    /// Compute the first derivative of the ellipse at the given coordinate
+    pub fn derivative_at(&self, point: impl Into<Point<1>>) -> Vector<D> {
+        let (sin, cos) = point.into().t.sin_cos();
+        self.b * cos - self.a * sin
+    }
+
+    /// Compute the second derivative of the ellipse at the given coordinate
+    pub fn second_derivative_at(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Vector<D> {
+        let (sin, cos) = point.into().t.sin_cos();
+        -(self.a * cos + self.b * sin)
+    }
+}
+
+impl<const D: usize> From<Circle<D>> for Ellipse<D> {
+    fn from(circle: Circle<D>) -> Self {
+        Self {
+            center: circle.center(),
+            a: circle.a(),
+            b: circle.b(),
+        }
+    }
+}
+
+impl<const D: usize> approx::AbsDiffEq for Ellipse<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.abs_diff_eq(&other.center, epsilon)
+            && self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use crate::{Point, Scalar};
+
+    use super::Ellipse;
+
+    #[test]
+    fn point_to_ellipse_coords() {
+        let ellipse = Ellipse::from_center_and_radii([1., 2., 3.], [2., 1.]);
+
+        assert_eq!(
+            ellipse.point_to_ellipse_coords([3., 2., 3.]),
+            Point::from([0.]),
+        );
+        assert_eq!(
+            ellipse.point_to_ellipse_coords([1., 3., 3.]),
+            Point::from([FRAC_PI_2]),
+        );
+        assert_eq!(
+            ellipse.point_to_ellipse_coords([-1., 2., 3.]),
+            Point::from([PI]),
+        );
+        assert_eq!(
+            ellipse.point_to_ellipse_coords([1., 1., 3.]),
+            Point::from([FRAC_PI_2 * 3.]),
+        );
+    }
+
+    #[test]
+    fn point_from_ellipse_coords() {
+        let ellipse = Ellipse::from_center_and_radii([0., 0.], [2., 1.]);
+
+        assert_eq!(
+            ellipse.point_from_ellipse_coords([0.]),
+            Point::from([2., 0.]),
+        );
+
+        let coord = Point::from([1.]);
+        let point = ellipse.point_from_ellipse_coords(coord);
+        assert!(
+            (ellipse.point_to_ellipse_coords(point) - coord).magnitude()
+                < Scalar::from(1e-12)
+        );
+    }
+
+    #[test]
+    fn skewed_ellipse() {
+        // A circle, scaled along the x-axis after being rotated by 45 degrees.
+        let ellipse = Ellipse::new([0., 0.], [2., 1.], [-2., 1.]);
+
+        let coord = Point::from([1.]);
+        let point = ellipse.point_from_ellipse_coords(coord);
+        assert!(
+            (ellipse.point_to_ellipse_coords(point) - coord).magnitude()
+                < Scalar::from(1e-12)
+        );
+
+        // The semi-axes are along the x- and y-axis.
+        assert!(
+            (ellipse.major_radius() - Scalar::from(2. * 2f64.sqrt())).abs()
+                < Scalar::from(1e-12)
+        );
+    }
+}
@@ -0,0 +1,274 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+
+use crate::{Point, Scalar, Vector};
+
+/// A helix in 3D space
+///
+/// A helix winds around its axis like a circle, while advancing along the
+/// axis at a constant rate. Its coordinate system works like that of a
+/// [`Circle`], with the coordinate being an angle in radians: Every
+/// increase by `PI * 2.` completes one turn, during which the helix advances
+/// by its pitch.
+///
+/// [`Circle`]: crate::Circle
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Helix {
+    center: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+    axis: Vector<3>,
+    turns: Scalar,
+}
+
+impl Helix {
+    /// Construct a helix
+    ///
+    /// `center` is the point on the axis where the helix starts. `a` and `b`
+    /// define the circle that the helix winds around, as they would for a
+    /// [`Circle`](crate::Circle). `axis` is the distance that the helix
+    /// advances during one turn.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - The helix radius (defined by the length of `a` and `b`) must not be
+    ///   zero.
+    /// - `a` and `b` must be of equal length.
+    /// - `a`, `b`, and `axis` must be perpendicular to each other.
+    /// - The number of turns must be positive.
+    pub fn new(
+        center: impl Into<Point<3>>,
+        a: impl Into<Vector<3>>,
+        b: impl Into<Vector<3>>,
+        axis: impl Into<Vector<3>>,
+        turns: impl Into<Scalar>,
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+        let axis = axis.into();
+        let turns = turns.into();
+
+        assert_eq!(
+            a.magnitude(),
+            b.magnitude(),
+            "`a` and `b` must be of equal length"
+        );
+        assert_ne!(
+            a.magnitude(),
+            Scalar::ZERO,
+            "helix radius must not be zero"
+        );
+        // See comment in `Circle::new` on why we're using an epsilon here.
+        assert!(
+            a.dot(&b).abs() < Scalar::default_epsilon()
+                && a.dot(&axis).abs() < Scalar::default_epsilon()
+                && b.dot(&axis).abs() < Scalar::default_epsilon(),
+            "`a`, `b`, and `axis` must be perpendicular to each other"
+        );
+        assert!(turns > Scalar::ZERO, "number of turns must be positive");
+
+        Self {
+            center,
+            a,
+            b,
+            axis,
+            turns,
+        }
+    }
+
+    /// Construct a `Helix` around the given axis
+    ///
+    /// `origin` is the point on the axis where the helix starts, `direction`
+    /// the direction of the axis. A positive `pitch` results in a right-handed
+    /// helix that advances along `direction`, a negative one in a left-handed
+    /// helix that advances in the opposite direction.
+    pub fn from_axis_radius_and_pitch(
+        origin: impl Into<Point<3>>,
+        direction: impl Into<Vector<3>>,
+        radius: impl Into<Scalar>,
+        pitch: impl Into<Scalar>,
+        turns: impl Into<Scalar>,
+    ) -> Self {
+        let direction = direction.into().normalize();
+        let radius = radius.into();
+
+        // Any vector that is perpendicular to the axis will do as the start
+        // of the helix. Pick one that is reliably not parallel to it.
+        let helper = if direction.x.abs() < Scalar::from(0.9) {
+            Vector::unit_x()
+        } else {
+            Vector::unit_y()
+        };
+        let a = direction.cross(&helper).normalize() * radius;
+        let b = direction.cross(&a);
+
+        Self::new(origin, a, b, direction * pitch.into(), turns)
+    }
+
+    /// Access the point on the axis where the helix starts
+    pub fn center(&self) -> Point<3> {
+        self.center
+    }
+
+    /// Access the radius of the helix
+    pub fn radius(&self) -> Scalar {
+        self.a.magnitude()
+    }
+
+    /// Access the vector that defines the starting point of the helix
+    ///
+    /// Please also refer to [`Self::b`].
+    pub fn a(&self) -> Vector<3> {
+        self.a
+    }
+
+    /// Access the vector that defines the direction the helix winds in
+    ///
+    /// Please also refer to [`Self::a`].
+    pub fn b(&self) -> Vector<3> {
+        self.b
+    }
+
+    /// Access the distance that the helix advances during one turn
+    pub fn axis(&self) -> Vector<3> {
+        self.axis
+    }
+
+    /// Access the number of turns of the helix
+    pub fn turns(&self) -> Scalar {
+        self.turns
+    }
+
+    /// Access the range of helix coordinates that covers all turns
+    pub fn range(&self) -> [Point<1>; 2] {
+        [Scalar::ZERO, Scalar::TAU * self.turns].map(|t| Point::from([t]))
+    }
+
+    /// Create a new instance that is reversed
+    ///
+    /// The reversed helix winds and advances in the opposite direction, but
+    /// starts at the same point.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.b = -self.b;
+        self.axis = -self.axis;
+        self
+    }
+
+    /// Convert a point in helix coordinates into a 3D point
+    pub fn point_from_helix_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<3> {
+        self.center + self.vector_from_helix_coords(point.into().coords)
+    }
+
+    /// Convert a vector in helix coordinates into a 3D vector
+    pub fn vector_from_helix_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<3> {
+        let angle = vector.into().t;
+        let (sin, cos) = angle.sin_cos();
+
+        self.a * cos + self.b * sin + self.axis * (angle / Scalar::TAU)
+    }
+
+    /// Compute the first derivative of the helix at the given coordinate
+    pub fn derivative_at(&self, point: impl Into<Point<1>>) -> Vector<3> {
+        let (sin, cos) = point.into().t.sin_cos();
+        self.b * cos - self.a * sin + self.axis / Scalar::TAU
+    }
+
+    /// Compute the second derivative of the helix at the given coordinate
+    pub fn second_derivative_at(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Vector<3> {
+        let (sin, cos) = point.into().t.sin_cos();
+        -(self.a * cos + self.b * sin)
+    }
+}
+
+impl approx::AbsDiffEq for Helix {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.abs_diff_eq(&other.center, epsilon)
+            && self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+            && self.axis.abs_diff_eq(&other.axis, epsilon)
+            && self.turns.abs_diff_eq(&other.turns, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, TAU};
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar, Vector};
+
+    use super::Helix;
+
+    #[test]
+    fn point_from_helix_coords() {
+        let helix = Helix::new(
+            [0., 0., 0.],
+            [1., 0., 0.],
+            [0., 1., 0.],
+            [0., 0., 2.],
+            3.,
+        );
+
+        assert_eq!(
+            helix.point_from_helix_coords([0.]),
+            Point::from([1., 0., 0.])
+        );
+        assert_abs_diff_eq!(
+            helix.point_from_helix_coords([FRAC_PI_2]),
+            Point::from([0., 1., 0.5]),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            helix.point_from_helix_coords([TAU]),
+            Point::from([1., 0., 2.]),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        let [_, end] = helix.range();
+        assert_abs_diff_eq!(
+            helix.point_from_helix_coords(end),
+            Point::from([1., 0., 6.]),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+
+    #[test]
+    fn from_axis_radius_and_pitch() {
+        let helix = Helix::from_axis_radius_and_pitch(
+            [0., 0., 0.],
+            Vector::unit_z(),
+            2.,
+            1.,
+            1.,
+        );
+
+        assert_eq!(helix.radius(), Scalar::from(2.));
+        assert_eq!(helix.axis(), Vector::from([0., 0., 1.]));
+
+        // A positive pitch must result in a right-handed helix.
+        assert!(helix.a().cross(&helix.b()).dot(&helix.axis()) > Scalar::ZERO);
+    }
+}
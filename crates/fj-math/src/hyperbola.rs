@@ -0,0 +1,179 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+
+use crate::{Point, Scalar, Vector};
+
+/// One branch of an n-dimensional hyperbola
+///
+/// The dimensionality of the hyperbola is defined by the const generic `D`
+/// parameter.
+///
+/// The hyperbola is defined by its center and two vectors: `a` points from the
+/// center to the vertex of the branch, `b` defines the direction of the
+/// hyperbola's coordinate system. The point at hyperbola coordinate `t` is
+/// `center + a * cosh(t) + b * sinh(t)`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Hyperbola<const D: usize> {
+    center: Point<D>,
+    a: Vector<D>,
+    b: Vector<D>,
+}
+
+impl<const D: usize> Hyperbola<D> {
+    /// Construct a hyperbola
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - Neither `a` nor `b` must be of zero length.
+    /// - `a` and `b` must be perpendicular to each other.
+    pub fn new(
+        center: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+
+        assert_ne!(a.magnitude(), Scalar::ZERO, "`a` must not be zero");
+        assert_ne!(b.magnitude(), Scalar::ZERO, "`b` must not be zero");
+        // See comment in `Circle::new` on why we're using an epsilon here.
+        assert!(
+            a.dot(&b).abs() < Scalar::default_epsilon(),
+            "`a` and `b` must be perpendicular to each other"
+        );
+
+        Self { center, a, b }
+    }
+
+    /// Access the center of the hyperbola
+    ///
+    /// This is the point where the asymptotes intersect.
+    pub fn center(&self) -> Point<D> {
+        self.center
+    }
+
+    /// Access the vector that points from the center to the vertex
+    pub fn a(&self) -> Vector<D> {
+        self.a
+    }
+
+    /// Access the vector that defines the direction of the coordinate system
+    pub fn b(&self) -> Vector<D> {
+        self.b
+    }
+
+    /// Access the vertex of the hyperbola
+    ///
+    /// This is the point at hyperbola coordinate `0.`.
+    pub fn vertex(&self) -> Point<D> {
+        self.center + self.a
+    }
+
+    /// Create a new instance that is reversed
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.b = -self.b;
+        self
+    }
+
+    /// Convert a `D`-dimensional point to hyperbola coordinates
+    ///
+    /// The coordinate is computed from the component of the point along `b`,
+    /// which results in the exact coordinate, if the point is on the
+    /// hyperbola. Points that are not on the hyperbola will not result in an
+    /// error.
+    pub fn point_to_hyperbola_coords(
+        &self,
+        point: impl Into<Point<D>>,
+    ) -> Point<1> {
+        let vector = point.into() - self.center;
+        let sinh = vector.dot(&self.b) / self.b.dot(&self.b);
+        Point::from([sinh.asinh()])
+    }
+
+    /// Convert a point in hyperbola coordinates into a `D`-dimensional point
+    pub fn point_from_hyperbola_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        self.center + self.vector_from_hyperbola_coords(point.into().coords)
+    }
+
+    /// Convert a vector in hyperbola coordinates into a `D`-dimensional vector
+    ///
+    /// The result is the vector from the center of the hyperbola to the point
+    /// at the given hyperbola coordinate.
+    pub fn vector_from_hyperbola_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<D> {
+        let (sinh, cosh) = vector.into().t.sinh_cosh();
+        self.a * cosh + self.b * sinh
+    }
+
+    /// Compute the first derivative of the hyperbola at the given coordinate
+    pub fn derivative_at(&self, point: impl Into<Point<1>>) -> Vector<D> {
+        let (sinh, cosh) = point.into().t.sinh_cosh();
+        self.a * sinh + self.b * cosh
+    }
+
+    /// Compute the second derivative of the hyperbola at the given coordinate
+    pub fn second_derivative_at(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Vector<D> {
+        self.vector_from_hyperbola_coords(point.into().coords)
+    }
+}
+
+impl<const D: usize> approx::AbsDiffEq for Hyperbola<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.abs_diff_eq(&other.center, epsilon)
+            && self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar};
+
+    use super::Hyperbola;
+
+    #[test]
+    fn point_to_and_from_hyperbola_coords() {
+        let hyperbola = Hyperbola::new([0., 0.], [1., 0.], [0., 2.]);
+
+        assert_eq!(
+            hyperbola.point_from_hyperbola_coords([0.]),
+            Point::from([1., 0.]),
+        );
+
+        let coord = Point::from([1.5]);
+        let point = hyperbola.point_from_hyperbola_coords(coord);
+        assert_abs_diff_eq!(
+            point.u * point.u - point.v * point.v / 4.,
+            Scalar::ONE,
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            hyperbola.point_to_hyperbola_coords(point),
+            coord,
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+}
@@ -36,28 +36,52 @@
 #![warn(missing_docs)]
 
 mod aabb;
+mod arc_length;
+mod bezier;
+mod bvh;
 mod circle;
+mod cone;
 mod coordinates;
+mod cylinder;
+mod ellipse;
+mod helix;
+mod hyperbola;
 mod line;
+mod nurbs;
+mod parabola;
 mod plane;
 mod point;
 mod poly_chain;
 mod scalar;
 mod segment;
+mod sphere;
+mod torus;
 mod transform;
 mod triangle;
 mod vector;
 
 pub use self::{
     aabb::Aabb,
+    arc_length::ArcLength,
+    bezier::CubicBezier,
+    bvh::Bvh,
     circle::Circle,
+    cone::Cone,
     coordinates::{Uv, Xyz, T},
+    cylinder::Cylinder,
+    ellipse::Ellipse,
+    helix::Helix,
+    hyperbola::Hyperbola,
     line::Line,
+    nurbs::{NurbsCurve, NurbsSurface},
+    parabola::Parabola,
     plane::Plane,
     point::Point,
     poly_chain::PolyChain,
     scalar::{Scalar, Sign},
     segment::Segment,
+    sphere::Sphere,
+    torus::Torus,
     transform::Transform,
     triangle::{Triangle, Winding},
     vector::Vector,
@@ -1,3 +1,6 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::{Point, Scalar, Triangle, Vector};
 
 /// An n-dimensional line, defined by an origin and a direction
@@ -5,6 +8,7 @@ use crate::{Point, Scalar, Triangle, Vector};
 /// The dimensionality of the line is defined by the const generic `D`
 /// parameter.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(C)]
 pub struct Line<const D: usize> {
     origin: Point<D>,
@@ -0,0 +1,986 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Point, Scalar, Vector};
+
+/// An n-dimensional NURBS curve
+///
+/// The dimensionality of the curve is defined by the const generic `D`
+/// parameter.
+///
+/// A NURBS (non-uniform rational B-spline) curve is defined by its degree, a
+/// number of control points with associated weights, and a knot vector. The
+/// curve is only defined between the knots at index `degree` and
+/// `num_control_points` (see [`NurbsCurve::domain`]). Coordinates outside of
+/// that domain are clamped to it.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NurbsCurve<const D: usize> {
+    degree: usize,
+    control_points: Vec<Point<D>>,
+    weights: Vec<Scalar>,
+    knots: Vec<Scalar>,
+}
+
+impl<const D: usize> NurbsCurve<D> {
+    /// Construct a `NurbsCurve`
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - `degree` must be at least `1`.
+    /// - There must be more control points than `degree`.
+    /// - There must be one weight per control point, and all weights must be
+    ///   positive.
+    /// - There must be `degree + 1` more knots than control points.
+    /// - The knots must not be decreasing.
+    /// - The domain of the curve must not be empty.
+    pub fn new(
+        degree: usize,
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+        weights: impl IntoIterator<Item = impl Into<Scalar>>,
+        knots: impl IntoIterator<Item = impl Into<Scalar>>,
+    ) -> Self {
+        let control_points: Vec<_> =
+            control_points.into_iter().map(Into::into).collect();
+        let weights: Vec<_> = weights.into_iter().map(Into::into).collect();
+        let knots: Vec<_> = knots.into_iter().map(Into::into).collect();
+
+        assert!(degree >= 1, "NURBS degree must be at least 1");
+        assert!(
+            control_points.len() > degree,
+            "NURBS curve of degree {degree} needs at least {} control points",
+            degree + 1
+        );
+        assert_eq!(
+            weights.len(),
+            control_points.len(),
+            "NURBS curve needs one weight per control point"
+        );
+        assert!(
+            weights.iter().all(|&weight| weight > Scalar::ZERO),
+            "NURBS weights must be positive"
+        );
+        assert_eq!(
+            knots.len(),
+            control_points.len() + degree + 1,
+            "NURBS curve has wrong number of knots"
+        );
+        assert!(
+            knots.windows(2).all(|knots| knots[0] <= knots[1]),
+            "NURBS knots must not be decreasing"
+        );
+
+        let curve = Self {
+            degree,
+            control_points,
+            weights,
+            knots,
+        };
+
+        let [min, max] = curve.domain();
+        assert!(min < max, "NURBS curve must have a non-empty domain");
+
+        curve
+    }
+
+    /// Construct a non-rational `NurbsCurve` from its control points
+    ///
+    /// All weights are `1`. The knot vector is uniform, and clamped to the
+    /// first and last control point, which means the curve starts at the first
+    /// control point (at curve coordinate `0.`) and ends at the last one (at
+    /// curve coordinate `1.`).
+    pub fn from_control_points(
+        degree: usize,
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+    ) -> Self {
+        let control_points: Vec<Point<D>> =
+            control_points.into_iter().map(Into::into).collect();
+        let num_control_points = control_points.len();
+
+        let weights = vec![Scalar::ONE; num_control_points];
+
+        let num_spans = num_control_points.saturating_sub(degree).max(1);
+        let knots = clamped_uniform_knots(degree, num_spans);
+
+        Self::new(degree, control_points, weights, knots)
+    }
+
+    /// Access the degree of the curve
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Access the control points of the curve
+    pub fn control_points(&self) -> &[Point<D>] {
+        &self.control_points
+    }
+
+    /// Access the weights of the curve's control points
+    pub fn weights(&self) -> &[Scalar] {
+        &self.weights
+    }
+
+    /// Access the knot vector of the curve
+    pub fn knots(&self) -> &[Scalar] {
+        &self.knots
+    }
+
+    /// Access the range of curve coordinates in which the curve is defined
+    pub fn domain(&self) -> [Scalar; 2] {
+        [
+            self.knots[self.degree],
+            self.knots[self.control_points.len()],
+        ]
+    }
+
+    /// Map the control points to new ones, keeping weights and knots
+    ///
+    /// This can be used to apply an affine transformation to the curve, as
+    /// NURBS curves are invariant under those.
+    #[must_use]
+    pub fn map_control_points<const D2: usize>(
+        &self,
+        f: impl FnMut(Point<D>) -> Point<D2>,
+    ) -> NurbsCurve<D2> {
+        NurbsCurve {
+            degree: self.degree,
+            control_points: self
+                .control_points
+                .iter()
+                .copied()
+                .map(f)
+                .collect(),
+            weights: self.weights.clone(),
+            knots: self.knots.clone(),
+        }
+    }
+
+    /// Create a new instance that is reversed
+    ///
+    /// The reversed curve has the same domain as the original one. Curve
+    /// coordinate `t` of the reversed curve corresponds to `min + max - t` of
+    /// the original one, with `min` and `max` being the bounds of the domain.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        let [min, max] = self.domain();
+
+        self.control_points.reverse();
+        self.weights.reverse();
+        self.knots = self.knots.iter().rev().map(|&t| min + max - t).collect();
+
+        self
+    }
+
+    /// Insert a knot at the given curve coordinate
+    ///
+    /// Returns a new curve with one more control point, that has the exact
+    /// same shape as the original one.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the coordinate is outside of the curve's domain.
+    #[must_use]
+    pub fn insert_knot(&self, point: impl Into<Point<1>>) -> Self {
+        let t = point.into().t;
+
+        let [min, max] = self.domain();
+        assert!(
+            t >= min && t <= max,
+            "Can't insert knot outside of the curve's domain"
+        );
+
+        let p = self.degree;
+        let k = find_span(p, &self.knots, t);
+
+        let homogeneous = self.homogeneous_control_points();
+        let mut inserted = Vec::with_capacity(homogeneous.len() + 1);
+
+        inserted.extend_from_slice(&homogeneous[..=k - p]);
+        for i in k - p + 1..=k {
+            let alpha =
+                (t - self.knots[i]) / (self.knots[i + p] - self.knots[i]);
+
+            let (a, a_w) = homogeneous[i - 1];
+            let (b, b_w) = homogeneous[i];
+
+            inserted.push((
+                a * (Scalar::ONE - alpha) + b * alpha,
+                a_w * (Scalar::ONE - alpha) + b_w * alpha,
+            ));
+        }
+        inserted.extend_from_slice(&homogeneous[k..]);
+
+        let mut knots = self.knots.clone();
+        knots.insert(k + 1, t);
+
+        let (control_points, weights) = inserted
+            .into_iter()
+            .map(|(coords, weight)| {
+                (
+                    Point {
+                        coords: coords / weight,
+                    },
+                    weight,
+                )
+            })
+            .unzip();
+
+        Self {
+            degree: p,
+            control_points,
+            weights,
+            knots,
+        }
+    }
+
+    /// Convert a point in curve coordinates into a `D`-dimensional point
+    pub fn point_from_nurbs_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let [min, max] = self.domain();
+        let t = point.into().t.max(min).min(max);
+
+        let (coords, weight) = de_boor(
+            self.degree,
+            &self.knots,
+            &self.homogeneous_control_points(),
+            t,
+        );
+
+        Point {
+            coords: coords / weight,
+        }
+    }
+
+    /// Convert a vector in curve coordinates into a `D`-dimensional vector
+    ///
+    /// The result is the vector from the start of the curve's domain to the
+    /// point at the given curve coordinate.
+    pub fn vector_from_nurbs_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<D> {
+        let [min, _] = self.domain();
+
+        self.point_from_nurbs_coords([min + vector.into().t])
+            - self.point_from_nurbs_coords([min])
+    }
+
+    /// Compute the first derivative of the curve at the given coordinate
+    pub fn derivative_at(&self, point: impl Into<Point<1>>) -> Vector<D> {
+        let [derivative, _] = self.derivatives_at(point);
+        derivative
+    }
+
+    /// Compute the second derivative of the curve at the given coordinate
+    pub fn second_derivative_at(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Vector<D> {
+        let [_, second_derivative] = self.derivatives_at(point);
+        second_derivative
+    }
+
+    /// Compute the first and second derivatives at the given coordinate
+    fn derivatives_at(&self, point: impl Into<Point<1>>) -> [Vector<D>; 2] {
+        let [min, max] = self.domain();
+        let t = point.into().t.max(min).min(max);
+
+        let points = self.homogeneous_control_points();
+
+        let (coords, weight) = de_boor(self.degree, &self.knots, &points, t);
+        let (d1_coords, d1_weight) =
+            de_boor_derivative(self.degree, &self.knots, &points, t);
+        let (d2_coords, d2_weight) =
+            de_boor_second_derivative(self.degree, &self.knots, &points, t);
+
+        // Apply the quotient rule, to get from the derivatives of the
+        // homogeneous B-spline to those of the NURBS.
+        let point = coords / weight;
+        let d1 = (d1_coords - point * d1_weight) / weight;
+        let d2 =
+            (d2_coords - d1 * (d1_weight * 2.) - point * d2_weight) / weight;
+
+        [d1, d2]
+    }
+
+    fn homogeneous_control_points(&self) -> Vec<Homogeneous<D>> {
+        self.control_points
+            .iter()
+            .zip(&self.weights)
+            .map(|(point, &weight)| (point.coords * weight, weight))
+            .collect()
+    }
+}
+
+impl<const D: usize> approx::AbsDiffEq for NurbsCurve<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.degree == other.degree
+            && self.control_points.len() == other.control_points.len()
+            && self.knots.len() == other.knots.len()
+            && self
+                .control_points
+                .iter()
+                .zip(&other.control_points)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            && self
+                .weights
+                .iter()
+                .zip(&other.weights)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            && self
+                .knots
+                .iter()
+                .zip(&other.knots)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+/// A NURBS surface in 3D space
+///
+/// The tensor-product counterpart of [`NurbsCurve`]. It is defined by a degree
+/// and a knot vector for each of its two parameter directions, `u` and `v`,
+/// and by a grid of control points with associated weights.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NurbsSurface {
+    degrees: [usize; 2],
+    num_control_points: [usize; 2],
+    control_points: Vec<Point<3>>,
+    weights: Vec<Scalar>,
+    knots: [Vec<Scalar>; 2],
+}
+
+impl NurbsSurface {
+    /// Construct a `NurbsSurface`
+    ///
+    /// `control_points` and `weights` are given as rows, each of which runs
+    /// along the `v` direction. The rows themselves are ordered along `u`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the requirements documented on [`NurbsCurve::new`] are not
+    /// met in either direction, or if the rows are not all of the same length.
+    pub fn new(
+        degrees: [usize; 2],
+        control_points: impl IntoIterator<
+            Item = impl IntoIterator<Item = impl Into<Point<3>>>,
+        >,
+        weights: impl IntoIterator<
+            Item = impl IntoIterator<Item = impl Into<Scalar>>,
+        >,
+        knots: [Vec<Scalar>; 2],
+    ) -> Self {
+        let control_points: Vec<Vec<Point<3>>> = control_points
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect();
+        let weights: Vec<Vec<Scalar>> = weights
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect();
+
+        let num_u = control_points.len();
+        let num_v = control_points.first().map(Vec::len).unwrap_or(0);
+
+        assert!(
+            control_points.iter().all(|row| row.len() == num_v),
+            "All rows of NURBS control points must be of the same length"
+        );
+        assert!(
+            weights.len() == num_u
+                && weights.iter().all(|row| row.len() == num_v),
+            "NURBS surface needs one weight per control point"
+        );
+
+        // Re-use the validation of `NurbsCurve`, by constructing the curves
+        // along the edges of the surface.
+        let [knots_u, knots_v] = knots;
+        let _ = NurbsCurve::<3>::new(
+            degrees[0],
+            control_points.iter().map(|row| row[0]),
+            weights.iter().map(|row| row[0]),
+            knots_u.iter().copied(),
+        );
+        let _ = NurbsCurve::<3>::new(
+            degrees[1],
+            control_points[0].iter().copied(),
+            weights[0].iter().copied(),
+            knots_v.iter().copied(),
+        );
+        assert!(
+            weights
+                .iter()
+                .flatten()
+                .all(|&weight| weight > Scalar::ZERO),
+            "NURBS weights must be positive"
+        );
+
+        Self {
+            degrees,
+            num_control_points: [num_u, num_v],
+            control_points: control_points.into_iter().flatten().collect(),
+            weights: weights.into_iter().flatten().collect(),
+            knots: [knots_u, knots_v],
+        }
+    }
+
+    /// Construct a non-rational `NurbsSurface` from its control points
+    ///
+    /// All weights are `1`. Like for [`NurbsCurve::from_control_points`], the
+    /// knot vectors are uniform and clamped, resulting in a domain of `0.` to
+    /// `1.` in both directions.
+    pub fn from_control_points(
+        degrees: [usize; 2],
+        control_points: impl IntoIterator<
+            Item = impl IntoIterator<Item = impl Into<Point<3>>>,
+        >,
+    ) -> Self {
+        let control_points: Vec<Vec<Point<3>>> = control_points
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect();
+
+        let num_u = control_points.len();
+        let num_v = control_points.first().map(Vec::len).unwrap_or(0);
+
+        let weights = vec![vec![Scalar::ONE; num_v]; num_u];
+        let knots =
+            [(degrees[0], num_u), (degrees[1], num_v)].map(|(degree, num)| {
+                clamped_uniform_knots(degree, num.saturating_sub(degree).max(1))
+            });
+
+        Self::new(degrees, control_points, weights, knots)
+    }
+
+    /// Access the degrees of the surface, in `u` and `v` direction
+    pub fn degrees(&self) -> [usize; 2] {
+        self.degrees
+    }
+
+    /// Access the number of control points, in `u` and `v` direction
+    pub fn num_control_points(&self) -> [usize; 2] {
+        self.num_control_points
+    }
+
+    /// Access the control point at the given grid position
+    pub fn control_point(&self, [i, j]: [usize; 2]) -> Point<3> {
+        self.control_points[self.index([i, j])]
+    }
+
+    /// Access the weight of the control point at the given grid position
+    pub fn weight(&self, [i, j]: [usize; 2]) -> Scalar {
+        self.weights[self.index([i, j])]
+    }
+
+    /// Access the knot vectors of the surface, in `u` and `v` direction
+    pub fn knots(&self) -> [&[Scalar]; 2] {
+        [&self.knots[0], &self.knots[1]]
+    }
+
+    /// Access the range of surface coordinates in which the surface is defined
+    ///
+    /// Returns the minimum and maximum coordinates.
+    pub fn domain(&self) -> [Point<2>; 2] {
+        let [degree_u, degree_v] = self.degrees;
+        let [num_u, num_v] = self.num_control_points;
+        let [knots_u, knots_v] = &self.knots;
+
+        [
+            Point::from([knots_u[degree_u], knots_v[degree_v]]),
+            Point::from([knots_u[num_u], knots_v[num_v]]),
+        ]
+    }
+
+    /// Map the control points to new ones, keeping weights and knots
+    ///
+    /// This can be used to apply an affine transformation to the surface, as
+    /// NURBS surfaces are invariant under those.
+    #[must_use]
+    pub fn map_control_points(
+        &self,
+        f: impl FnMut(Point<3>) -> Point<3>,
+    ) -> Self {
+        Self {
+            control_points: self
+                .control_points
+                .iter()
+                .copied()
+                .map(f)
+                .collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Convert a point in surface coordinates into a 3D point
+    pub fn point_from_nurbs_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let [u, v] = self.clamp_to_domain(point.into());
+        let [degree_u, degree_v] = self.degrees;
+        let [knots_u, knots_v] = &self.knots;
+
+        let rows_at_v =
+            self.evaluate_rows(|row| de_boor(degree_v, knots_v, row, v));
+        let (coords, weight) = de_boor(degree_u, knots_u, &rows_at_v, u);
+
+        Point {
+            coords: coords / weight,
+        }
+    }
+
+    /// Convert a vector in surface coordinates into a 3D vector
+    ///
+    /// The result is the vector from the minimum of the surface's domain to the
+    /// point at the given surface coordinates.
+    pub fn vector_from_nurbs_coords(
+        &self,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let [min, _] = self.domain();
+
+        self.point_from_nurbs_coords(min + vector.into())
+            - self.point_from_nurbs_coords(min)
+    }
+
+    /// Compute the partial derivatives at the given surface coordinates
+    ///
+    /// Returns the derivatives in `u` and `v` direction.
+    pub fn derivatives_at(&self, point: impl Into<Point<2>>) -> [Vector<3>; 2] {
+        let [u, v] = self.clamp_to_domain(point.into());
+        let [degree_u, degree_v] = self.degrees;
+        let [knots_u, knots_v] = &self.knots;
+
+        let rows_at_v =
+            self.evaluate_rows(|row| de_boor(degree_v, knots_v, row, v));
+        let columns_at_u = self
+            .evaluate_columns(|column| de_boor(degree_u, knots_u, column, u));
+
+        let (coords, weight) = de_boor(degree_u, knots_u, &rows_at_v, u);
+        let point = coords / weight;
+
+        // Apply the quotient rule, to get from the derivatives of the
+        // homogeneous B-spline to those of the NURBS.
+        let derivative = |(d_coords, d_weight): Homogeneous<3>| {
+            (d_coords - point * d_weight) / weight
+        };
+
+        [
+            derivative(de_boor_derivative(degree_u, knots_u, &rows_at_v, u)),
+            derivative(de_boor_derivative(degree_v, knots_v, &columns_at_u, v)),
+        ]
+    }
+
+    /// Compute the normal of the surface at the given surface coordinates
+    pub fn normal_at(&self, point: impl Into<Point<2>>) -> Vector<3> {
+        let [du, dv] = self.derivatives_at(point);
+        du.cross(&dv).normalize()
+    }
+
+    fn index(&self, [i, j]: [usize; 2]) -> usize {
+        let [_, num_v] = self.num_control_points;
+        i * num_v + j
+    }
+
+    fn clamp_to_domain(&self, point: Point<2>) -> [Scalar; 2] {
+        let [min, max] = self.domain();
+
+        [point.u.max(min.u).min(max.u), point.v.max(min.v).min(max.v)]
+    }
+
+    /// Evaluate each row of control points, resulting in one point per row
+    fn evaluate_rows(
+        &self,
+        mut f: impl FnMut(&[Homogeneous<3>]) -> Homogeneous<3>,
+    ) -> Vec<Homogeneous<3>> {
+        let [num_u, _] = self.num_control_points;
+
+        (0..num_u)
+            .map(|i| {
+                let row = self.homogeneous_row(i);
+                f(&row)
+            })
+            .collect()
+    }
+
+    /// Evaluate each column of control points, resulting in one point per
+    /// column
+    fn evaluate_columns(
+        &self,
+        mut f: impl FnMut(&[Homogeneous<3>]) -> Homogeneous<3>,
+    ) -> Vec<Homogeneous<3>> {
+        let [num_u, num_v] = self.num_control_points;
+
+        (0..num_v)
+            .map(|j| {
+                let column: Vec<_> = (0..num_u)
+                    .map(|i| self.homogeneous_control_point([i, j]))
+                    .collect();
+                f(&column)
+            })
+            .collect()
+    }
+
+    fn homogeneous_row(&self, i: usize) -> Vec<Homogeneous<3>> {
+        let [_, num_v] = self.num_control_points;
+
+        (0..num_v)
+            .map(|j| self.homogeneous_control_point([i, j]))
+            .collect()
+    }
+
+    fn homogeneous_control_point(&self, [i, j]: [usize; 2]) -> Homogeneous<3> {
+        let weight = self.weight([i, j]);
+        (self.control_point([i, j]).coords * weight, weight)
+    }
+}
+
+impl approx::AbsDiffEq for NurbsSurface {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.degrees == other.degrees
+            && self.num_control_points == other.num_control_points
+            && self.knots[0].len() == other.knots[0].len()
+            && self.knots[1].len() == other.knots[1].len()
+            && self
+                .control_points
+                .iter()
+                .zip(&other.control_points)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            && self
+                .weights
+                .iter()
+                .zip(&other.weights)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            && self
+                .knots
+                .iter()
+                .flatten()
+                .zip(other.knots.iter().flatten())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+/// A control point in homogeneous coordinates
+///
+/// The coordinates are pre-multiplied with the weight.
+type Homogeneous<const D: usize> = (Vector<D>, Scalar);
+
+/// Find the index of the knot span that contains the given coordinate
+///
+/// The result `k` satisfies `knots[k] <= t < knots[k + 1]`, except at the end
+/// of the domain, where the last non-empty span is returned.
+fn find_span(degree: usize, knots: &[Scalar], t: Scalar) -> usize {
+    let p = degree;
+    let n = knots.len() - degree - 1;
+
+    if t >= knots[n] {
+        return (p..n)
+            .rev()
+            .find(|&k| knots[k] < knots[k + 1])
+            .unwrap_or(n - 1);
+    }
+
+    (p..n).find(|&k| t < knots[k + 1]).unwrap_or(n - 1)
+}
+
+/// Evaluate a B-spline with homogeneous control points
+///
+/// This is de Boor's algorithm. Evaluating a B-spline in homogeneous
+/// coordinates and dividing by the resulting weight afterwards, is the same as
+/// evaluating the respective NURBS.
+fn de_boor<const D: usize>(
+    degree: usize,
+    knots: &[Scalar],
+    points: &[Homogeneous<D>],
+    t: Scalar,
+) -> Homogeneous<D> {
+    let p = degree;
+    let k = find_span(p, knots, t);
+
+    let mut d = points[k - p..=k].to_vec();
+
+    for r in 1..=p {
+        for j in (r..=p).rev() {
+            let i = j + k - p;
+            let alpha = (t - knots[i]) / (knots[i + p + 1 - r] - knots[i]);
+
+            let (a, a_w) = d[j - 1];
+            let (b, b_w) = d[j];
+
+            d[j] = (
+                a * (Scalar::ONE - alpha) + b * alpha,
+                a_w * (Scalar::ONE - alpha) + b_w * alpha,
+            );
+        }
+    }
+
+    d[p]
+}
+
+/// Evaluate the first derivative of a B-spline with homogeneous control points
+fn de_boor_derivative<const D: usize>(
+    degree: usize,
+    knots: &[Scalar],
+    points: &[Homogeneous<D>],
+    t: Scalar,
+) -> Homogeneous<D> {
+    if degree == 0 {
+        return (Vector::from([Scalar::ZERO; D]), Scalar::ZERO);
+    }
+
+    let derivative_points = derivative_control_points(degree, knots, points);
+    de_boor(
+        degree - 1,
+        &knots[1..knots.len() - 1],
+        &derivative_points,
+        t,
+    )
+}
+
+/// Evaluate the second derivative of a B-spline with homogeneous control
+/// points
+fn de_boor_second_derivative<const D: usize>(
+    degree: usize,
+    knots: &[Scalar],
+    points: &[Homogeneous<D>],
+    t: Scalar,
+) -> Homogeneous<D> {
+    if degree < 2 {
+        return (Vector::from([Scalar::ZERO; D]), Scalar::ZERO);
+    }
+
+    let derivative_points = derivative_control_points(degree, knots, points);
+    de_boor_derivative(
+        degree - 1,
+        &knots[1..knots.len() - 1],
+        &derivative_points,
+        t,
+    )
+}
+
+/// Compute the control points of the derivative of a B-spline
+///
+/// The derivative of a B-spline is another B-spline, of one degree lower.
+fn derivative_control_points<const D: usize>(
+    degree: usize,
+    knots: &[Scalar],
+    points: &[Homogeneous<D>],
+) -> Vec<Homogeneous<D>> {
+    let p = degree;
+
+    points
+        .windows(2)
+        .enumerate()
+        .map(|(i, points)| {
+            let (a, a_w) = points[0];
+            let (b, b_w) = points[1];
+
+            let span = knots[i + p + 1] - knots[i + 1];
+            if span == Scalar::ZERO {
+                return (Vector::from([Scalar::ZERO; D]), Scalar::ZERO);
+            }
+
+            let factor = Scalar::from(p as f64) / span;
+            ((b - a) * factor, (b_w - a_w) * factor)
+        })
+        .collect()
+}
+
+fn clamped_uniform_knots(degree: usize, num_spans: usize) -> Vec<Scalar> {
+    let mut knots = vec![Scalar::ZERO; degree + 1];
+    knots.extend(
+        (1..num_spans)
+            .map(|i| Scalar::from(i as f64) / Scalar::from(num_spans as f64)),
+    );
+    knots.extend(vec![Scalar::ONE; degree + 1]);
+
+    knots
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar, Vector};
+
+    use super::{NurbsCurve, NurbsSurface};
+
+    fn quarter_circle() -> NurbsCurve<2> {
+        NurbsCurve::new(
+            2,
+            [[1., 0.], [1., 1.], [0., 1.]],
+            [1., FRAC_1_SQRT_2, 1.],
+            [0., 0., 0., 1., 1., 1.],
+        )
+    }
+
+    #[test]
+    fn point_from_nurbs_coords() {
+        let curve = quarter_circle();
+
+        assert_abs_diff_eq!(
+            curve.point_from_nurbs_coords([0.]),
+            Point::from([1., 0.])
+        );
+        assert_abs_diff_eq!(
+            curve.point_from_nurbs_coords([0.5]),
+            Point::from([FRAC_1_SQRT_2, FRAC_1_SQRT_2])
+        );
+        assert_abs_diff_eq!(
+            curve.point_from_nurbs_coords([1.]),
+            Point::from([0., 1.])
+        );
+
+        // Rational curves can represent conic sections exactly.
+        for t in [0.1, 0.3, 0.7, 0.9] {
+            let point = curve.point_from_nurbs_coords([t]);
+            assert_abs_diff_eq!(
+                point.coords.magnitude(),
+                Scalar::ONE,
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn curve_derivatives() {
+        let curve = quarter_circle();
+
+        for t in [0.1, 0.3, 0.5, 0.9] {
+            let d1 = curve.derivative_at([t]);
+            let d2 = curve.second_derivative_at([t]);
+
+            let h = 1e-6;
+            let expected_d1 = (curve.point_from_nurbs_coords([t + h])
+                - curve.point_from_nurbs_coords([t - h]))
+                / (h * 2.);
+            assert_abs_diff_eq!(d1, expected_d1, epsilon = 1e-6.into());
+
+            // The curvature of a unit circle is one everywhere.
+            let speed = d1.magnitude();
+            let curvature = d1.cross2d(&d2).abs() / (speed * speed * speed);
+            assert_abs_diff_eq!(
+                curvature,
+                Scalar::ONE,
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn from_control_points() {
+        let curve = NurbsCurve::from_control_points(
+            2,
+            [[0., 0.], [1., 1.], [2., 0.], [3., 1.]],
+        );
+
+        assert_eq!(
+            curve.knots(),
+            [0., 0., 0., 0.5, 1., 1., 1.].map(Scalar::from)
+        );
+        assert_eq!(curve.point_from_nurbs_coords([0.]), Point::from([0., 0.]));
+        assert_eq!(curve.point_from_nurbs_coords([1.]), Point::from([3., 1.]));
+    }
+
+    #[test]
+    fn insert_knot() {
+        let curve = quarter_circle();
+        let inserted = curve.insert_knot([0.25]);
+
+        assert_eq!(inserted.control_points().len(), 4);
+        assert_eq!(inserted.knots().len(), 7);
+
+        for t in [0., 0.1, 0.25, 0.5, 0.75, 1.] {
+            assert_abs_diff_eq!(
+                curve.point_from_nurbs_coords([t]),
+                inserted.point_from_nurbs_coords([t]),
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn reverse() {
+        let curve = quarter_circle();
+        let reversed = curve.clone().reverse();
+
+        for t in [0., 0.1, 0.25, 0.5, 0.75, 1.] {
+            assert_abs_diff_eq!(
+                curve.point_from_nurbs_coords([t]),
+                reversed.point_from_nurbs_coords([1. - t]),
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn surface_point_and_derivatives() {
+        let surface = NurbsSurface::from_control_points(
+            [1, 1],
+            [[[0., 0., 0.], [0., 2., 0.]], [[2., 0., 0.], [2., 2., 2.]]],
+        );
+
+        assert_eq!(
+            surface.point_from_nurbs_coords([0.5, 0.5]),
+            Point::from([1., 1., 0.5])
+        );
+        assert_eq!(
+            surface.derivatives_at([0.5, 0.5]),
+            [Vector::from([2., 0., 1.]), Vector::from([0., 2., 1.])]
+        );
+    }
+
+    #[test]
+    fn rational_surface() {
+        // A quarter of a cylinder with radius 1 and height 1.
+        let w = FRAC_1_SQRT_2;
+        let surface = NurbsSurface::new(
+            [2, 1],
+            [
+                [[1., 0., 0.], [1., 0., 1.]],
+                [[1., 1., 0.], [1., 1., 1.]],
+                [[0., 1., 0.], [0., 1., 1.]],
+            ],
+            [[1., 1.], [w, w], [1., 1.]],
+            [
+                [0., 0., 0., 1., 1., 1.].map(Scalar::from).to_vec(),
+                [0., 0., 1., 1.].map(Scalar::from).to_vec(),
+            ],
+        );
+
+        for [u, v] in [[0.1, 0.2], [0.5, 0.5], [0.8, 0.9]] {
+            let point = surface.point_from_nurbs_coords([u, v]);
+            let normal = surface.normal_at([u, v]);
+
+            let radial = Vector::from([point.x, point.y, Scalar::ZERO]);
+            assert_abs_diff_eq!(
+                radial.magnitude(),
+                Scalar::ONE,
+                epsilon = Scalar::from(1e-12)
+            );
+            assert_abs_diff_eq!(
+                normal.cross(&radial).magnitude(),
+                Scalar::ZERO,
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+
+use crate::{Point, Scalar, Vector};
+
+/// An n-dimensional parabola
+///
+/// The dimensionality of the parabola is defined by the const generic `D`
+/// parameter.
+///
+/// The parabola is defined by its vertex and two vectors: `a` points along the
+/// axis of the parabola, into the direction in which it opens. `b` is the
+/// tangent at the vertex. The point at parabola coordinate `t` is
+/// `vertex + a * t^2 + b * t`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Parabola<const D: usize> {
+    vertex: Point<D>,
+    a: Vector<D>,
+    b: Vector<D>,
+}
+
+impl<const D: usize> Parabola<D> {
+    /// Construct a parabola
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - Neither `a` nor `b` must be of zero length.
+    /// - `a` and `b` must be perpendicular to each other.
+    pub fn new(
+        vertex: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+    ) -> Self {
+        let vertex = vertex.into();
+        let a = a.into();
+        let b = b.into();
+
+        assert_ne!(a.magnitude(), Scalar::ZERO, "`a` must not be zero");
+        assert_ne!(b.magnitude(), Scalar::ZERO, "`b` must not be zero");
+        // See comment in `Circle::new` on why we're using an epsilon here.
+        assert!(
+            a.dot(&b).abs() < Scalar::default_epsilon(),
+            "`a` and `b` must be perpendicular to each other"
+        );
+
+        Self { vertex, a, b }
+    }
+
+    /// Access the vertex of the parabola
+    pub fn vertex(&self) -> Point<D> {
+        self.vertex
+    }
+
+    /// Access the vector that points along the axis of the parabola
+    pub fn a(&self) -> Vector<D> {
+        self.a
+    }
+
+    /// Access the vector that defines the tangent at the vertex
+    ///
+    /// Also defines the direction of the parabola's coordinate system.
+    pub fn b(&self) -> Vector<D> {
+        self.b
+    }
+
+    /// Create a new instance that is reversed
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.b = -self.b;
+        self
+    }
+
+    /// Convert a `D`-dimensional point to parabola coordinates
+    ///
+    /// The point is projected onto the tangent at the vertex, which results in
+    /// the exact coordinate, if the point is on the parabola. Points that are
+    /// not on the parabola will not result in an error.
+    pub fn point_to_parabola_coords(
+        &self,
+        point: impl Into<Point<D>>,
+    ) -> Point<1> {
+        let vector = point.into() - self.vertex;
+        let t = vector.dot(&self.b) / self.b.dot(&self.b);
+        Point::from([t])
+    }
+
+    /// Convert a point in parabola coordinates into a `D`-dimensional point
+    pub fn point_from_parabola_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        self.vertex + self.vector_from_parabola_coords(point.into().coords)
+    }
+
+    /// Convert a vector in parabola coordinates into a `D`-dimensional vector
+    pub fn vector_from_parabola_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<D> {
+        let t = vector.into().t;
+        self.a * (t * t) + self.b * t
+    }
+
+    /// Compute the first derivative of the parabola at the given coordinate
+    pub fn derivative_at(&self, point: impl Into<Point<1>>) -> Vector<D> {
+        let t = point.into().t;
+        self.a * (t * 2.) + self.b
+    }
+
+    /// Compute the second derivative of the parabola
+    ///
+    /// The second derivative of a parabola is the same everywhere.
+    pub fn second_derivative(&self) -> Vector<D> {
+        self.a * 2.
+    }
+}
+
+impl<const D: usize> approx::AbsDiffEq for Parabola<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.vertex.abs_diff_eq(&other.vertex, epsilon)
+            && self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Point;
+
+    use super::Parabola;
+
+    #[test]
+    fn point_to_and_from_parabola_coords() {
+        let parabola = Parabola::new([1., 1.], [0., 1.], [2., 0.]);
+
+        assert_eq!(
+            parabola.point_from_parabola_coords([2.]),
+            Point::from([5., 5.]),
+        );
+        assert_eq!(
+            parabola.point_to_parabola_coords([5., 5.]),
+            Point::from([2.]),
+        );
+    }
+}
@@ -1,7 +1,11 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::{Line, Point, Scalar, Vector};
 
 /// A plane
-#[derive(Clone, Copy, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(C)]
 pub struct Plane {
     origin: Point<3>,
@@ -69,6 +73,45 @@ impl Plane {
         ])
     }
 
+    /// Convert a point into plane coordinates
+    ///
+    /// Projects the point onto the plane, if it's not already on there. Unlike
+    /// [`Self::project_vector`], this doesn't require the u- and v-vectors of
+    /// the plane to be perpendicular or of unit length.
+    pub fn point_to_plane_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let vector = self.vector_to_plane_coords(point.into() - self.origin());
+        Point { coords: vector }
+    }
+
+    /// Convert a vector into plane coordinates
+    ///
+    /// Projects the vector onto the plane, if it's not already parallel to it.
+    /// Unlike [`Self::project_vector`], this doesn't require the u- and
+    /// v-vectors of the plane to be perpendicular or of unit length.
+    pub fn vector_to_plane_coords(
+        &self,
+        vector: impl Into<Vector<3>>,
+    ) -> Vector<2> {
+        let vector = vector.into();
+
+        // Solve the normal equations of `vector = u * s + v * t`.
+        let uu = self.u().dot(&self.u());
+        let uv = self.u().dot(&self.v());
+        let vv = self.v().dot(&self.v());
+        let pu = vector.dot(&self.u());
+        let pv = vector.dot(&self.v());
+
+        let determinant = uu * vv - uv * uv;
+
+        Vector::from([
+            (pu * vv - pv * uv) / determinant,
+            (pv * uu - pu * uv) / determinant,
+        ])
+    }
+
     /// Project a line into the plane
     pub fn project_line(&self, line: &Line<3>) -> Line<2> {
         let line_origin_relative_to_plane = line.origin() - self.origin();
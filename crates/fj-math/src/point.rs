@@ -1,5 +1,8 @@
 use std::{fmt, ops};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use super::{
     coordinates::{Uv, Xyz, T},
     Scalar, Vector,
@@ -10,6 +13,8 @@ use super::{
 /// The dimensionality of the point is defined by the const generic `D`
 /// parameter.
 #[derive(Clone, Copy, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[repr(C)]
 pub struct Point<const D: usize> {
     /// The coordinates of the point
@@ -1,10 +1,14 @@
-use crate::{Point, Segment};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Point, Scalar, Segment, Vector};
 
 /// A polygonal chain
 ///
 /// The dimensionality of the polygonal chain is defined by the const generic
 /// `D` parameter.
 #[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(C)]
 pub struct PolyChain<const D: usize> {
     points: Vec<Point<D>>,
@@ -38,6 +42,11 @@ impl<const D: usize> PolyChain<D> {
         Self { points }
     }
 
+    /// Access the points of the polygonal chain
+    pub fn points(&self) -> &[Point<D>] {
+        &self.points
+    }
+
     /// Access the segments of the polygonal chain
     pub fn segments(&self) -> Vec<Segment<D>> {
         let mut segments = Vec::new();
@@ -54,6 +63,14 @@ impl<const D: usize> PolyChain<D> {
         segments
     }
 
+    /// Access the coordinates of the points that connect segments
+    ///
+    /// These are all points of the chain, except the first and the last one.
+    pub fn joints(&self) -> impl Iterator<Item = Scalar> {
+        (1..self.points.len().saturating_sub(1))
+            .map(|i| Scalar::from_u64(i as u64))
+    }
+
     /// Close the polygonal chain
     ///
     /// Adds the first point of the chain as the last, closing the chain. This
@@ -71,6 +88,85 @@ impl<const D: usize> PolyChain<D> {
         self.points.reverse();
         self
     }
+
+    /// Create a new instance by mapping all points
+    pub fn map_points<const D2: usize>(
+        &self,
+        f: impl FnMut(Point<D>) -> Point<D2>,
+    ) -> PolyChain<D2> {
+        PolyChain::from_points(self.points.iter().copied().map(f))
+    }
+
+    /// Convert a point in polygonal chain coordinates into a `D`-dimensional
+    /// point
+    ///
+    /// The points of the chain are at integer coordinates, starting with `0.`
+    /// for the first point. In between, the coordinates are interpolated
+    /// linearly along each segment. Coordinates outside of the chain are
+    /// extrapolated along its first or last segment.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the chain has less than two points.
+    pub fn point_from_poly_chain_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        assert!(
+            self.points.len() >= 2,
+            "Polygonal chain must have at least two points"
+        );
+
+        let t = point.into().t;
+
+        let (segment, [a, b]) = self.segment_at(t);
+        a + (b - a) * (t - segment)
+    }
+
+    /// Compute the first derivative of the chain at the given coordinate
+    ///
+    /// Within a segment, this is the vector from its start to its end point.
+    /// At a point that connects two segments, the derivative of the following
+    /// segment is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the chain has less than two points.
+    pub fn derivative_at(&self, point: impl Into<Point<1>>) -> Vector<D> {
+        assert!(
+            self.points.len() >= 2,
+            "Polygonal chain must have at least two points"
+        );
+
+        let (_, [a, b]) = self.segment_at(point.into().t);
+        b - a
+    }
+
+    /// Find the segment at the given coordinate
+    ///
+    /// Returns the coordinate of the segment's start, along with its points.
+    fn segment_at(&self, t: Scalar) -> (Scalar, [Point<D>; 2]) {
+        let last_segment = Scalar::from_u64(self.points.len() as u64 - 2);
+        let segment = t.floor().max(Scalar::ZERO).min(last_segment);
+
+        let i = segment.into_u64() as usize;
+        (segment, [self.points[i], self.points[i + 1]])
+    }
+
+    /// Convert a vector in polygonal chain coordinates into a `D`-dimensional
+    /// vector
+    ///
+    /// The result is the vector from the first point of the chain to the point
+    /// at the given coordinate.
+    pub fn vector_from_poly_chain_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<D> {
+        let point = Point {
+            coords: vector.into(),
+        };
+        self.point_from_poly_chain_coords(point) - self.points[0]
+    }
 }
 
 impl<P, Ps, const D: usize> From<Ps> for PolyChain<D>
@@ -82,3 +178,60 @@ where
         Self::from_points(points)
     }
 }
+
+impl<const D: usize> approx::AbsDiffEq for PolyChain<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.points.len() == other.points.len()
+            && self
+                .points
+                .iter()
+                .zip(&other.points)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Scalar};
+
+    use super::PolyChain;
+
+    #[test]
+    fn point_from_poly_chain_coords() {
+        let chain = PolyChain::from_points([[0., 0.], [1., 0.], [1., 2.]]);
+
+        assert_eq!(
+            chain.point_from_poly_chain_coords([0.5]),
+            Point::from([0.5, 0.]),
+        );
+        assert_eq!(
+            chain.point_from_poly_chain_coords([1.5]),
+            Point::from([1., 1.]),
+        );
+
+        // Coordinates outside of the chain are extrapolated.
+        assert_eq!(
+            chain.point_from_poly_chain_coords([-1.]),
+            Point::from([-1., 0.]),
+        );
+        assert_eq!(
+            chain.point_from_poly_chain_coords([3.]),
+            Point::from([1., 4.]),
+        );
+    }
+
+    #[test]
+    fn joints() {
+        let chain = PolyChain::from_points([[0., 0.], [1., 0.], [1., 2.]]);
+        assert_eq!(chain.joints().collect::<Vec<_>>(), [Scalar::ONE]);
+
+        let empty = PolyChain::<2>::new();
+        assert_eq!(empty.joints().count(), 0);
+    }
+}
@@ -128,6 +128,11 @@ impl Scalar {
         self.0.round().into()
     }
 
+    /// Compute the square root
+    pub fn sqrt(self) -> Self {
+        self.0.sqrt().into()
+    }
+
     /// Compute the cosine
     pub fn cos(self) -> Self {
         self.0.cos().into()
@@ -144,6 +149,16 @@ impl Scalar {
         self.0.acos().into()
     }
 
+    /// Compute the hyperbolic sine and cosine
+    pub fn sinh_cosh(self) -> (Self, Self) {
+        (self.0.sinh().into(), self.0.cosh().into())
+    }
+
+    /// Compute the inverse hyperbolic sine
+    pub fn asinh(self) -> Self {
+        self.0.asinh().into()
+    }
+
     /// Compute the four-quadrant arctangent
     pub fn atan2(self, other: Self) -> Self {
         self.0.atan2(other.0).into()
@@ -565,6 +580,31 @@ impl num_traits::Float for Scalar {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Scalar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Scalar {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let scalar = f64::deserialize(deserializer)?;
+        if scalar.is_nan() {
+            return Err(serde::de::Error::custom("Invalid scalar value: NaN"));
+        }
+
+        Ok(Self(scalar))
+    }
+}
+
 impl fmt::Debug for Scalar {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.0.fmt(f)
@@ -1,5 +1,8 @@
 use std::fmt;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::Scalar;
 
 use super::Point;
@@ -9,6 +12,7 @@ use super::Point;
 /// The dimensionality of the segment is defined by the const generic `D`
 /// parameter.
 #[derive(Clone, Copy, Eq, Default, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(C)]
 pub struct Segment<const D: usize> {
     points: [Point<D>; 2],
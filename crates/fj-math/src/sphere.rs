@@ -0,0 +1,218 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+
+use crate::{Point, Scalar, Vector};
+
+/// A sphere in 3D space
+///
+/// The coordinate system of the sphere works like longitude and latitude:
+/// The u-coordinate is an angle in radians around the polar axis, starting at
+/// the vector `a` and moving towards the vector `b`. The v-coordinate is an
+/// angle in radians from the equator towards the pole that the vector `c`
+/// points to. It is within `-PI / 2.` and `PI / 2.` on the sphere.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sphere {
+    center: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+    c: Vector<3>,
+}
+
+impl Sphere {
+    /// Construct a sphere
+    ///
+    /// `a` and `b` define the equator of the sphere, as they would for a
+    /// [`Circle`](crate::Circle). `c` points to the pole at v-coordinate
+    /// `PI / 2.`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - The sphere radius (defined by the length of `a`, `b`, and `c`) must
+    ///   not be zero.
+    /// - `a`, `b`, and `c` must be of equal length.
+    /// - `a`, `b`, and `c` must be perpendicular to each other.
+    pub fn new(
+        center: impl Into<Point<3>>,
+        a: impl Into<Vector<3>>,
+        b: impl Into<Vector<3>>,
+        c: impl Into<Vector<3>>,
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+        let c = c.into();
+
+        assert!(
+            a.magnitude() == b.magnitude() && a.magnitude() == c.magnitude(),
+            "`a`, `b`, and `c` must be of equal length"
+        );
+        assert_ne!(
+            a.magnitude(),
+            Scalar::ZERO,
+            "sphere radius must not be zero"
+        );
+        // See comment in `Circle::new` on why we're using an epsilon here.
+        assert!(
+            a.dot(&b).abs() < Scalar::default_epsilon()
+                && a.dot(&c).abs() < Scalar::default_epsilon()
+                && b.dot(&c).abs() < Scalar::default_epsilon(),
+            "`a`, `b`, and `c` must be perpendicular to each other"
+        );
+
+        Self { center, a, b, c }
+    }
+
+    /// Construct a `Sphere` from a center point and a radius
+    ///
+    /// The poles of the sphere are on the z-axis through `center`.
+    pub fn from_center_and_radius(
+        center: impl Into<Point<3>>,
+        radius: impl Into<Scalar>,
+    ) -> Self {
+        let radius = radius.into();
+
+        Self::new(
+            center,
+            Vector::unit_x() * radius,
+            Vector::unit_y() * radius,
+            Vector::unit_z() * radius,
+        )
+    }
+
+    /// Access the center point of the sphere
+    pub fn center(&self) -> Point<3> {
+        self.center
+    }
+
+    /// Access the radius of the sphere
+    pub fn radius(&self) -> Scalar {
+        self.a.magnitude()
+    }
+
+    /// Access the vector that defines the start of the u-coordinate
+    ///
+    /// Please also refer to [`Self::b`].
+    pub fn a(&self) -> Vector<3> {
+        self.a
+    }
+
+    /// Access the vector that defines the direction of the u-coordinate
+    ///
+    /// Please also refer to [`Self::a`].
+    pub fn b(&self) -> Vector<3> {
+        self.b
+    }
+
+    /// Access the vector that points to the pole at v-coordinate `PI / 2.`
+    pub fn c(&self) -> Vector<3> {
+        self.c
+    }
+
+    /// Convert a 3D point into sphere coordinates
+    ///
+    /// The u-coordinate is between `0.` (inclusive) and `PI * 2.`
+    /// (exclusive), the v-coordinate between `-PI / 2.` and `PI / 2.`
+    /// (both inclusive). The point is projected onto the sphere, if it's not
+    /// already on there.
+    ///
+    /// The u-coordinate of the poles is ambiguous. `0.` is returned for them.
+    pub fn point_to_sphere_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let vector = point.into() - self.center;
+
+        let x = vector.dot(&self.a);
+        let y = vector.dot(&self.b);
+        let z = vector.dot(&self.c);
+
+        let atan = Scalar::atan2(y, x);
+        let u = if atan >= Scalar::ZERO {
+            atan
+        } else {
+            atan + Scalar::TAU
+        };
+        let v = Scalar::atan2(z, (x * x + y * y).sqrt());
+
+        Point::from([u, v])
+    }
+
+    /// Convert a point in sphere coordinates into a 3D point
+    pub fn point_from_sphere_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        self.center + self.vector_from_sphere_coords(point.into().coords)
+    }
+
+    /// Convert a vector in sphere coordinates into a 3D vector
+    ///
+    /// The result is the vector from the center of the sphere to the point at
+    /// the given sphere coordinates.
+    pub fn vector_from_sphere_coords(
+        &self,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let vector = vector.into();
+        let (sin_u, cos_u) = vector.u.sin_cos();
+        let (sin_v, cos_v) = vector.v.sin_cos();
+
+        (self.a * cos_u + self.b * sin_u) * cos_v + self.c * sin_v
+    }
+}
+
+impl approx::AbsDiffEq for Sphere {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.abs_diff_eq(&other.center, epsilon)
+            && self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+            && self.c.abs_diff_eq(&other.c, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar};
+
+    use super::Sphere;
+
+    #[test]
+    fn point_to_and_from_sphere_coords() {
+        let sphere = Sphere::from_center_and_radius([1., 1., 1.], 2.);
+
+        assert_abs_diff_eq!(
+            sphere.point_from_sphere_coords([FRAC_PI_2, 0.]),
+            Point::from([1., 3., 1.]),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            sphere.point_from_sphere_coords([0., FRAC_PI_2]),
+            Point::from([1., 1., 3.]),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        let coords = Point::from([FRAC_PI_4, FRAC_PI_4]);
+        assert_abs_diff_eq!(
+            sphere.point_to_sphere_coords(
+                sphere.point_from_sphere_coords(coords)
+            ),
+            coords,
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+}
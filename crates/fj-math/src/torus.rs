@@ -0,0 +1,335 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+
+use crate::{Circle, Point, Scalar, Vector};
+
+/// A torus in 3D space
+///
+/// A torus is the surface that results from revolving a circle, the tube
+/// circle, around an axis that lies in the same plane, but doesn't intersect
+/// it. Its coordinate system is made up of two angles in radians: The
+/// u-coordinate is the angle of the revolution around the axis. The
+/// v-coordinate is the angle on the tube circle, which works like the
+/// coordinate of a [`Circle`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Torus {
+    center: Point<3>,
+    axis: Vector<3>,
+    a: Vector<3>,
+    tube_a: Vector<3>,
+    tube_b: Vector<3>,
+}
+
+impl Torus {
+    /// Construct a torus
+    ///
+    /// `center` is the point on the axis that the torus is centered around,
+    /// `axis` the direction of the axis. `a` points from `center` to the center
+    /// of the tube circle at u-coordinate `0.`. `tube_a` and `tube_b` define
+    /// the tube circle at that u-coordinate, as they would for a [`Circle`].
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - `axis` must not be zero.
+    /// - `a` must be perpendicular to `axis`.
+    /// - `tube_a` and `tube_b` must be of equal length, and that length must
+    ///   not be zero.
+    /// - `tube_a` and `tube_b` must be perpendicular to each other, and lie in
+    ///   the plane spanned by `axis` and `a`.
+    /// - The radius of the tube circle must be less than the length of `a`.
+    pub fn new(
+        center: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        a: impl Into<Vector<3>>,
+        tube_a: impl Into<Vector<3>>,
+        tube_b: impl Into<Vector<3>>,
+    ) -> Self {
+        let center = center.into();
+        let axis = axis.into();
+        let a = a.into();
+        let tube_a = tube_a.into();
+        let tube_b = tube_b.into();
+
+        assert_ne!(
+            axis.magnitude(),
+            Scalar::ZERO,
+            "torus axis must not be zero"
+        );
+        let axis = axis.normalize();
+
+        assert_eq!(
+            tube_a.magnitude(),
+            tube_b.magnitude(),
+            "`tube_a` and `tube_b` must be of equal length"
+        );
+        assert_ne!(
+            tube_a.magnitude(),
+            Scalar::ZERO,
+            "tube radius must not be zero"
+        );
+        assert!(
+            tube_a.magnitude() < a.magnitude(),
+            "tube must not intersect the axis"
+        );
+
+        // See comment in `Circle::new` on why we're using an epsilon here.
+        let normal = axis.cross(&a);
+        assert!(
+            a.dot(&axis).abs() < Scalar::default_epsilon(),
+            "`a` must be perpendicular to `axis`"
+        );
+        assert!(
+            tube_a.dot(&tube_b).abs() < Scalar::default_epsilon()
+                && tube_a.dot(&normal).abs() < Scalar::default_epsilon()
+                && tube_b.dot(&normal).abs() < Scalar::default_epsilon(),
+            "`tube_a` and `tube_b` must be perpendicular to each other and \
+            lie in the plane of `axis` and `a`"
+        );
+
+        Self {
+            center,
+            axis,
+            a,
+            tube_a,
+            tube_b,
+        }
+    }
+
+    /// Construct the `Torus` that results from revolving a circle
+    ///
+    /// The circle is revolved around the axis defined by `origin` and
+    /// `direction`, in the direction defined by the right-hand rule. The circle
+    /// then becomes the tube circle at u-coordinate `0.`, and its coordinates
+    /// are identical to the v-coordinates of the torus.
+    ///
+    /// Returns `None`, if the axis doesn't lie in the plane of the circle, or
+    /// if it intersects the circle.
+    pub fn from_circle_and_axis(
+        circle: &Circle<3>,
+        origin: impl Into<Point<3>>,
+        direction: impl Into<Vector<3>>,
+    ) -> Option<Self> {
+        let origin = origin.into();
+        let direction = direction.into();
+
+        if direction.magnitude() == Scalar::ZERO {
+            return None;
+        }
+        let direction = direction.normalize();
+
+        let offset = circle.center() - origin;
+        let center = origin + direction * offset.dot(&direction);
+        let a = circle.center() - center;
+
+        if a.magnitude() <= circle.radius() {
+            return None;
+        }
+
+        let normal = direction.cross(&a);
+        if circle.a().dot(&normal).abs() >= Scalar::default_epsilon()
+            || circle.b().dot(&normal).abs() >= Scalar::default_epsilon()
+        {
+            return None;
+        }
+
+        Some(Self::new(center, direction, a, circle.a(), circle.b()))
+    }
+
+    /// Access the point on the axis that the torus is centered around
+    pub fn center(&self) -> Point<3> {
+        self.center
+    }
+
+    /// Access the direction of the axis, as a unit vector
+    pub fn axis(&self) -> Vector<3> {
+        self.axis
+    }
+
+    /// Access the vector from the center to the tube circle at u-coordinate
+    /// `0.`
+    pub fn a(&self) -> Vector<3> {
+        self.a
+    }
+
+    /// Access the vector that defines the start of the v-coordinate
+    pub fn tube_a(&self) -> Vector<3> {
+        self.tube_a
+    }
+
+    /// Access the vector that defines the direction of the v-coordinate
+    pub fn tube_b(&self) -> Vector<3> {
+        self.tube_b
+    }
+
+    /// Access the distance between the axis and the center of the tube
+    pub fn major_radius(&self) -> Scalar {
+        self.a.magnitude()
+    }
+
+    /// Access the radius of the tube
+    pub fn minor_radius(&self) -> Scalar {
+        self.tube_a.magnitude()
+    }
+
+    /// Convert a 3D point into torus coordinates
+    ///
+    /// Both coordinates are between `0.` (inclusive) and `PI * 2.`
+    /// (exclusive). The point is projected onto the torus, if it's not already
+    /// on there.
+    pub fn point_to_torus_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let vector = point.into() - self.center;
+
+        let b = self.axis.cross(&self.a);
+        let u = positive_angle(Scalar::atan2(
+            vector.dot(&b) / b.magnitude(),
+            vector.dot(&self.a) / self.a.magnitude(),
+        ));
+
+        // Rotate the point back to u-coordinate `0.`, where the tube circle is
+        // defined by `tube_a` and `tube_b`.
+        let vector = rotate(vector, self.axis, -u) - self.a;
+        let v = positive_angle(Scalar::atan2(
+            vector.dot(&self.tube_b),
+            vector.dot(&self.tube_a),
+        ));
+
+        Point::from([u, v])
+    }
+
+    /// Convert a point in torus coordinates into a 3D point
+    pub fn point_from_torus_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        self.center + self.vector_from_torus_coords(point.into().coords)
+    }
+
+    /// Convert a vector in torus coordinates into a 3D vector
+    ///
+    /// The result is the vector from the center of the torus to the point at
+    /// the given torus coordinates.
+    pub fn vector_from_torus_coords(
+        &self,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let vector = vector.into();
+        let (sin, cos) = vector.v.sin_cos();
+
+        let tube = self.a + self.tube_a * cos + self.tube_b * sin;
+        rotate(tube, self.axis, vector.u)
+    }
+}
+
+impl approx::AbsDiffEq for Torus {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.abs_diff_eq(&other.center, epsilon)
+            && self.axis.abs_diff_eq(&other.axis, epsilon)
+            && self.a.abs_diff_eq(&other.a, epsilon)
+            && self.tube_a.abs_diff_eq(&other.tube_a, epsilon)
+            && self.tube_b.abs_diff_eq(&other.tube_b, epsilon)
+    }
+}
+
+/// Rotate a vector around a unit-length axis, using Rodrigues' formula
+fn rotate(vector: Vector<3>, axis: Vector<3>, angle: Scalar) -> Vector<3> {
+    let (sin, cos) = angle.sin_cos();
+
+    vector * cos
+        + axis.cross(&vector) * sin
+        + axis * (axis.dot(&vector) * (Scalar::ONE - cos))
+}
+
+fn positive_angle(angle: Scalar) -> Scalar {
+    if angle >= Scalar::ZERO {
+        angle
+    } else {
+        angle + Scalar::TAU
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Circle, Point, Scalar, Vector};
+
+    use super::Torus;
+
+    #[test]
+    fn point_to_and_from_torus_coords() {
+        let torus = Torus::new(
+            [0., 0., 0.],
+            [0., 0., 1.],
+            [2., 0., 0.],
+            [1., 0., 0.],
+            [0., 0., 1.],
+        );
+
+        assert_abs_diff_eq!(
+            torus.point_from_torus_coords([0., 0.]),
+            Point::from([3., 0., 0.]),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            torus.point_from_torus_coords([FRAC_PI_2, FRAC_PI_2]),
+            Point::from([0., 2., 1.]),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        let coords = Point::from([FRAC_PI_4, FRAC_PI_2 * 3.]);
+        assert_abs_diff_eq!(
+            torus.point_to_torus_coords(torus.point_from_torus_coords(coords)),
+            coords,
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+
+    #[test]
+    fn from_circle_and_axis() {
+        let circle =
+            Circle::new([2., 0., 0.], [1., 0., 0.], Vector::from([0., 0., 1.]));
+
+        let torus =
+            Torus::from_circle_and_axis(&circle, [0., 0., 0.], [0., 0., 1.])
+                .unwrap();
+        for t in [0., 1., 2.] {
+            assert_abs_diff_eq!(
+                torus.point_from_torus_coords([0., t]),
+                circle.point_from_circle_coords([t]),
+                epsilon = Scalar::from(1e-12),
+            );
+        }
+
+        // The axis must not intersect the circle.
+        assert!(Torus::from_circle_and_axis(
+            &circle,
+            [1.5, 0., 0.],
+            [0., 0., 1.]
+        )
+        .is_none());
+
+        // The axis must be in the plane of the circle.
+        assert!(Torus::from_circle_and_axis(
+            &circle,
+            [0., 0., 0.],
+            [1., 1., 1.]
+        )
+        .is_none());
+    }
+}
@@ -2,7 +2,10 @@ use std::ops;
 
 use nalgebra::Perspective3;
 
-use crate::{Circle, Line, Scalar};
+use crate::{
+    Circle, Cone, CubicBezier, Cylinder, Ellipse, Helix, Hyperbola, Line,
+    NurbsCurve, NurbsSurface, Parabola, Scalar, Sphere, Torus,
+};
 
 use super::{Aabb, Point, Segment, Triangle, Vector};
 
@@ -40,6 +43,57 @@ impl Transform {
         ))
     }
 
+    /// Construct a scaling along the x-, y-, and z-axis
+    ///
+    /// Each component of `factors` is the factor along the respective axis.
+    pub fn scaling(factors: impl Into<Vector<3>>) -> Self {
+        let factors = factors.into();
+
+        Self(nalgebra::Transform::from_matrix_unchecked(
+            nalgebra::Matrix4::new_nonuniform_scaling(&factors.to_na()),
+        ))
+    }
+
+    /// Construct a reflection
+    ///
+    /// Reflects across the plane through the origin that is perpendicular to
+    /// `normal`. The length of `normal` doesn't matter.
+    pub fn reflection(normal: impl Into<Vector<3>>) -> Self {
+        let normal = normal.into().normalize().to_na();
+        let matrix =
+            nalgebra::Matrix3::identity() - normal * normal.transpose() * 2.;
+
+        Self(nalgebra::Transform::from_matrix_unchecked(
+            matrix.to_homogeneous(),
+        ))
+    }
+
+    /// Check whether the transform preserves angles
+    ///
+    /// This is the case for any combination of translations, rotations,
+    /// reflections, and uniform scaling. Such a transform maps circles to
+    /// circles, spheres to spheres, and so on. Other transforms, like a
+    /// non-uniform scaling, don't.
+    pub fn is_similarity(&self) -> bool {
+        let [x, y, z] = [Vector::unit_x(), Vector::unit_y(), Vector::unit_z()]
+            .map(|axis| self.transform_vector(&axis));
+
+        // The transformed axes must be perpendicular and of equal length. The
+        // allowed deviation is relative to the scale of the transform.
+        let scale = x.dot(&x);
+        let epsilon = scale * 1e-12;
+
+        [
+            y.dot(&y) - scale,
+            z.dot(&z) - scale,
+            x.dot(&y),
+            x.dot(&z),
+            y.dot(&z),
+        ]
+        .into_iter()
+        .all(|deviation| deviation.abs() <= epsilon)
+    }
+
     /// Transform the given point
     pub fn transform_point(&self, point: &Point<3>) -> Point<3> {
         Point::from(self.0.transform_point(&point.to_na()))
@@ -88,6 +142,119 @@ impl Transform {
         )
     }
 
+    /// Transform the given ellipse
+    pub fn transform_ellipse(&self, ellipse: &Ellipse<3>) -> Ellipse<3> {
+        Ellipse::new(
+            self.transform_point(&ellipse.center()),
+            self.transform_vector(&ellipse.a()),
+            self.transform_vector(&ellipse.b()),
+        )
+    }
+
+    /// Transform the given parabola
+    pub fn transform_parabola(&self, parabola: &Parabola<3>) -> Parabola<3> {
+        Parabola::new(
+            self.transform_point(&parabola.vertex()),
+            self.transform_vector(&parabola.a()),
+            self.transform_vector(&parabola.b()),
+        )
+    }
+
+    /// Transform the given hyperbola
+    pub fn transform_hyperbola(
+        &self,
+        hyperbola: &Hyperbola<3>,
+    ) -> Hyperbola<3> {
+        Hyperbola::new(
+            self.transform_point(&hyperbola.center()),
+            self.transform_vector(&hyperbola.a()),
+            self.transform_vector(&hyperbola.b()),
+        )
+    }
+
+    /// Transform the given cylinder
+    pub fn transform_cylinder(&self, cylinder: &Cylinder) -> Cylinder {
+        Cylinder::new(
+            self.transform_point(&cylinder.origin()),
+            self.transform_vector(&cylinder.a()),
+            self.transform_vector(&cylinder.b()),
+            self.transform_vector(&cylinder.axis()),
+        )
+    }
+
+    /// Transform the given cone
+    ///
+    /// The slope of the cone is scaled along with its base radius.
+    pub fn transform_cone(&self, cone: &Cone) -> Cone {
+        let a = self.transform_vector(&cone.a());
+        let slope = cone.slope() * a.magnitude() / cone.base_radius();
+
+        Cone::new(
+            self.transform_point(&cone.origin()),
+            a,
+            self.transform_vector(&cone.b()),
+            self.transform_vector(&cone.axis()),
+            slope,
+        )
+    }
+
+    /// Transform the given sphere
+    pub fn transform_sphere(&self, sphere: &Sphere) -> Sphere {
+        Sphere::new(
+            self.transform_point(&sphere.center()),
+            self.transform_vector(&sphere.a()),
+            self.transform_vector(&sphere.b()),
+            self.transform_vector(&sphere.c()),
+        )
+    }
+
+    /// Transform the given torus
+    pub fn transform_torus(&self, torus: &Torus) -> Torus {
+        Torus::new(
+            self.transform_point(&torus.center()),
+            self.transform_vector(&torus.axis()),
+            self.transform_vector(&torus.a()),
+            self.transform_vector(&torus.tube_a()),
+            self.transform_vector(&torus.tube_b()),
+        )
+    }
+
+    /// Transform the given NURBS surface
+    pub fn transform_nurbs_surface(
+        &self,
+        nurbs: &NurbsSurface,
+    ) -> NurbsSurface {
+        nurbs.map_control_points(|point| self.transform_point(&point))
+    }
+
+    /// Transform the given helix
+    pub fn transform_helix(&self, helix: &Helix) -> Helix {
+        Helix::new(
+            self.transform_point(&helix.center()),
+            self.transform_vector(&helix.a()),
+            self.transform_vector(&helix.b()),
+            self.transform_vector(&helix.axis()),
+            helix.turns(),
+        )
+    }
+
+    /// Transform the given cubic Bézier curve
+    pub fn transform_cubic_bezier(
+        &self,
+        bezier: &CubicBezier<3>,
+    ) -> CubicBezier<3> {
+        CubicBezier::from_control_points(
+            bezier
+                .control_points()
+                .map(|point| self.transform_point(&point)),
+        )
+    }
+
+    /// Transform the given NURBS curve
+    pub fn transform_nurbs(&self, nurbs: &NurbsCurve<3>) -> NurbsCurve<3> {
+        nurbs.map_control_points(|point| self.transform_point(&point))
+    }
+
     /// Inverse transform
     pub fn inverse(&self) -> Transform {
         Self(self.0.inverse())
@@ -161,6 +328,35 @@ mod tests {
 
     use super::Transform;
 
+    #[test]
+    fn reflection() {
+        let transform = Transform::reflection([0., 2., 0.]);
+
+        assert_eq!(
+            transform.transform_point(&Point::from([1., 2., 3.])),
+            Point::from([1., -2., 3.]),
+        );
+        assert_eq!(
+            transform.transform_vector(&Vector::from([1., 2., 3.])),
+            Vector::from([1., -2., 3.]),
+        );
+    }
+
+    #[test]
+    fn scaling() {
+        let transform = Transform::scaling([1., 2., 3.]);
+
+        assert_eq!(
+            transform.transform_point(&Point::from([1., 1., 1.])),
+            Point::from([1., 2., 3.]),
+        );
+        assert!(!transform.is_similarity());
+
+        let transform = Transform::rotation(Vector::unit_z() * 1.)
+            * Transform::scaling([2., 2., 2.]);
+        assert!(transform.is_similarity());
+    }
+
     #[test]
     fn transform() {
         let line = Line::from_origin_and_direction(
@@ -108,6 +108,28 @@ impl<const D: usize> Vector<D> {
 
         self.dot(&other.normalize())
     }
+
+    /// Compute the curvature of a path from its derivatives
+    ///
+    /// This vector is the first derivative of the path, `second_derivative`
+    /// the second one. Returns zero, if this vector is zero, meaning the path
+    /// is degenerate at that point.
+    pub fn curvature(&self, second_derivative: &Self) -> Scalar {
+        let [d1, d2] = [self, second_derivative];
+
+        let speed = d1.magnitude();
+        if speed == Scalar::ZERO {
+            return Scalar::ZERO;
+        }
+
+        // This is the magnitude of the cross product of both derivatives, in a
+        // form that works for any dimension.
+        let cross = (d1.dot(d1) * d2.dot(d2) - d1.dot(d2) * d1.dot(d2))
+            .max(Scalar::ZERO)
+            .sqrt();
+
+        cross / (speed * speed * speed)
+    }
 }
 
 impl Vector<1> {
@@ -334,6 +356,37 @@ where
     }
 }
 
+// Serde only implements its traits for arrays of specific sizes, not for arrays
+// of any size `D`. Vectors are serialized as sequences of their components.
+#[cfg(feature = "serde")]
+impl<const D: usize> serde::Serialize for Vector<D> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.components.as_slice().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const D: usize> serde::Deserialize<'de> for Vector<D> {
+    fn deserialize<Ds>(deserializer: Ds) -> Result<Self, Ds::Error>
+    where
+        Ds: serde::Deserializer<'de>,
+    {
+        let components = Vec::<Scalar>::deserialize(deserializer)?;
+        let len = components.len();
+        let components = components.try_into().map_err(|_| {
+            serde::de::Error::invalid_length(
+                len,
+                &format!("{D} components").as_str(),
+            )
+        })?;
+
+        Ok(Self { components })
+    }
+}
+
 impl<const D: usize> fmt::Debug for Vector<D> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.components.fmt(f)
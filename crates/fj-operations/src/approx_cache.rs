@@ -0,0 +1,585 @@
+//! On-disk cache for face approximations
+//!
+//! See [`ApproxCache`].
+
+use std::{
+    collections::{btree_map, BTreeMap},
+    fs,
+    path::PathBuf,
+};
+
+use fj_interop::mesh::{Color, Mesh};
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        triangulate::{Triangulate, Triangulator},
+    },
+    geometry::SurfaceGeometry,
+    objects::{Face, Faces},
+    path::SurfacePath,
+};
+use fj_math::{Point, Vector};
+use serde::Serialize;
+
+/// Caches the triangulated approximations of faces in a directory
+///
+/// Faces are grouped by the edges they share, and each group of faces is
+/// stored under a hash of its geometry, the tolerance, and the triangulator
+/// that was used. When a model is processed again, only the groups that
+/// changed since the last run need to be approximated and triangulated. For
+/// models that consist of multiple separate parts, like an assembly or a
+/// pattern, that means only the changed parts are processed again.
+///
+/// Faces that share an edge are approximated using the same points along that
+/// edge. Since those faces always end up in the same group, this is also true
+/// for faces that are loaded from the cache.
+///
+/// The cache is a pure optimization. Entries that can't be read or written are
+/// silently ignored, and the faces in question are approximated as usual. Once
+/// the entries take up more space than the maximum size, the oldest ones are
+/// removed.
+#[derive(Clone, Debug)]
+pub struct ApproxCache {
+    dir: PathBuf,
+    max_size: u64,
+}
+
+impl ApproxCache {
+    /// The default maximum size of the cache, in bytes
+    pub const DEFAULT_MAX_SIZE: u64 = 256 * 1024 * 1024;
+
+    /// Create a cache that stores its entries in the provided directory
+    ///
+    /// The directory is created, once the first entry is stored.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            max_size: Self::DEFAULT_MAX_SIZE,
+        }
+    }
+
+    /// Limit the size of the entries in the cache, in bytes
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Triangulate the faces, using cached results where available
+    pub fn triangulate(
+        &self,
+        faces: &Faces,
+        tolerance: Tolerance,
+        triangulator: Triangulator,
+    ) -> Mesh<Point<3>> {
+        let mut mesh = Mesh::new();
+        let mut stored_entries = false;
+
+        for faces in connected_faces(faces) {
+            let path = self.path(&faces, tolerance, triangulator);
+
+            let group_mesh =
+                match fs::read(&path).ok().and_then(|bytes| decode(&bytes)) {
+                    Some(group_mesh) => group_mesh,
+                    None => {
+                        let group_mesh =
+                            (&faces, tolerance).triangulate_with(triangulator);
+
+                        // Writing to a temporary file first makes sure that no
+                        // other process reads a partially written entry.
+                        let tmp = path.with_extension("tmp");
+                        stored_entries |= fs::create_dir_all(&self.dir)
+                            .and_then(|()| fs::write(&tmp, encode(&group_mesh)))
+                            .and_then(|()| fs::rename(&tmp, &path))
+                            .is_ok();
+
+                        group_mesh
+                    }
+                };
+
+            for triangle in group_mesh.triangles() {
+                mesh.push_triangle_with_attributes(
+                    triangle.inner,
+                    triangle.normals,
+                    triangle.uvs,
+                    triangle.color,
+                );
+            }
+        }
+
+        if stored_entries {
+            self.evict();
+        }
+
+        mesh
+    }
+
+    fn path(
+        &self,
+        faces: &Faces,
+        tolerance: Tolerance,
+        triangulator: Triangulator,
+    ) -> PathBuf {
+        let key = Key {
+            format: KEY_FORMAT,
+            // Changes to the kernel can change the approximation of the same
+            // geometry, so entries from other versions must not be used.
+            version: env!("CARGO_PKG_VERSION"),
+            tolerance: tolerance.inner().into_f64(),
+            max_angle: tolerance.max_angle().map(|angle| angle.into_f64()),
+            triangulator: match triangulator {
+                Triangulator::Delaunay => "delaunay",
+                Triangulator::ConstrainedDelaunay => "constrained-delaunay",
+            },
+            faces: faces.into_iter().map(FaceKey::new).collect(),
+        };
+
+        // Serializing the key can only fail for non-finite numbers, which
+        // valid geometry doesn't contain. Such faces would just share an
+        // entry that is never read successfully.
+        let bytes = serde_json::to_vec(&key).unwrap_or_default();
+
+        self.dir.join(format!("{:032x}.mesh", fnv1a(&bytes)))
+    }
+
+    /// Remove the oldest entries, until the cache fits into its maximum size
+    fn evict(&self) {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+
+        let mut entries = entries
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry.path().extension().map_or(false, |ext| ext == "mesh")
+            })
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+                Some((metadata.modified().ok()?, metadata.len(), entry.path()))
+            })
+            .collect::<Vec<_>>();
+        entries.sort();
+
+        let mut size = entries.iter().map(|&(_, len, _)| len).sum::<u64>();
+        for (_, len, path) in entries {
+            if size <= self.max_size {
+                break;
+            }
+
+            if fs::remove_file(path).is_ok() {
+                size -= len;
+            }
+        }
+    }
+}
+
+/// Split faces into groups, that are connected through the vertices they share
+///
+/// Faces that share an edge also share its vertices. Vertices are compared by
+/// position, as faces that touch don't necessarily refer to the same objects.
+fn connected_faces(faces: &Faces) -> Vec<Faces> {
+    let faces = faces.into_iter().collect::<Vec<_>>();
+
+    // Union-find over the indices of the faces. Each face starts out in a group
+    // of its own, and groups are merged wherever faces share a vertex.
+    let mut parents = (0..faces.len()).collect::<Vec<_>>();
+
+    let mut vertices = BTreeMap::new();
+    for (i, face) in faces.iter().enumerate() {
+        let positions = face
+            .all_cycles()
+            .flat_map(|cycle| cycle.half_edges())
+            .flat_map(|half_edge| half_edge.vertices())
+            .map(|vertex| vertex.global_form().position());
+
+        for position in positions {
+            match vertices.entry(position) {
+                btree_map::Entry::Vacant(entry) => {
+                    entry.insert(i);
+                }
+                btree_map::Entry::Occupied(entry) => {
+                    let [a, b] =
+                        [i, *entry.get()].map(|i| find_root(&mut parents, i));
+                    parents[a] = b;
+                }
+            }
+        }
+    }
+
+    let mut groups = BTreeMap::new();
+    for (i, face) in faces.into_iter().enumerate() {
+        groups
+            .entry(find_root(&mut parents, i))
+            .or_insert_with(Faces::new)
+            .extend([face.clone()]);
+    }
+
+    groups.into_values().collect()
+}
+
+/// Find the face that identifies the group of the face at index `i`
+fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// The version of the format of [`Key`]
+///
+/// Must be increased whenever the serialized form of the key changes, for
+/// example because kernel types have been changed.
+const KEY_FORMAT: u32 = 2;
+
+/// Identifies an entry of the cache
+///
+/// The key is serialized and hashed, to get the name of the entry. Global
+/// curves and vertices are only represented by their geometry, as their
+/// identity differs between runs.
+#[derive(Serialize)]
+struct Key<'r> {
+    format: u32,
+    version: &'static str,
+    tolerance: f64,
+    max_angle: Option<f64>,
+    triangulator: &'static str,
+    faces: Vec<FaceKey<'r>>,
+}
+
+#[derive(Serialize)]
+struct FaceKey<'r> {
+    surface: &'r SurfaceGeometry,
+    color: Color,
+    cycles: Vec<Vec<HalfEdgeKey<'r>>>,
+}
+
+impl<'r> FaceKey<'r> {
+    fn new(face: &'r Face) -> Self {
+        let cycles = face
+            .all_cycles()
+            .map(|cycle| {
+                cycle
+                    .half_edges()
+                    .map(|half_edge| HalfEdgeKey {
+                        path: half_edge.curve().path(),
+                        surface: half_edge.curve().surface().geometry(),
+                        vertices: half_edge.vertices().clone().map(|vertex| {
+                            (
+                                vertex.position(),
+                                vertex.surface_form().position(),
+                                vertex.global_form().position(),
+                            )
+                        }),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            surface: face.surface().geometry(),
+            color: face.color(),
+            cycles,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct HalfEdgeKey<'r> {
+    path: &'r SurfacePath,
+    surface: &'r SurfaceGeometry,
+    vertices: [(Point<1>, Point<2>, Point<3>); 2],
+}
+
+/// Compute the 128-bit FNV-1a hash of the bytes
+///
+/// Unlike the hashers from the standard library, the result is specified and
+/// doesn't change between Rust versions, which makes it suitable for naming
+/// entries on disk.
+fn fnv1a(bytes: &[u8]) -> u128 {
+    const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u128::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+const MAGIC: &[u8; 8] = b"fj-mesh1";
+
+/// Points, normals, and surface coordinates, plus a flag and a color
+const TRIANGLE_LEN: usize = 24 * 8 + 1 + 4;
+
+fn encode(mesh: &Mesh<Point<3>>) -> Vec<u8> {
+    let mut bytes = MAGIC.to_vec();
+
+    for triangle in mesh.triangles() {
+        let points = triangle.inner.points().map(|point| point.coords);
+        for vector in points.into_iter().chain(triangle.normals) {
+            for component in vector.components {
+                bytes.extend(component.into_f64().to_le_bytes());
+            }
+        }
+
+        let uvs = triangle.uvs.unwrap_or([Point::origin(); 3]);
+        for point in uvs {
+            for component in point.coords.components {
+                bytes.extend(component.into_f64().to_le_bytes());
+            }
+        }
+
+        bytes.push(triangle.uvs.is_some().into());
+        bytes.extend(triangle.color.0);
+    }
+
+    bytes
+}
+
+fn decode(bytes: &[u8]) -> Option<Mesh<Point<3>>> {
+    let bytes = bytes.strip_prefix(MAGIC)?;
+    if bytes.len() % TRIANGLE_LEN != 0 {
+        return None;
+    }
+
+    let mut mesh = Mesh::new();
+
+    for triangle in bytes.chunks(TRIANGLE_LEN) {
+        let mut numbers = [0.; 24];
+        for (number, bytes) in numbers.iter_mut().zip(triangle.chunks(8)) {
+            let mut buf = [0; 8];
+            buf.copy_from_slice(bytes);
+            *number = f64::from_le_bytes(buf);
+        }
+        if numbers.iter().any(|number| number.is_nan()) {
+            return None;
+        }
+
+        let triple = |i: usize| [numbers[i], numbers[i + 1], numbers[i + 2]];
+        let points = [0, 3, 6].map(|i| Point::from(triple(i)));
+        let normals = [9, 12, 15].map(|i| Vector::from(triple(i)));
+        let uvs =
+            [18, 20, 22].map(|i| Point::from([numbers[i], numbers[i + 1]]));
+
+        let has_uvs = triangle[24 * 8] != 0;
+        let mut color = [0; 4];
+        color.copy_from_slice(&triangle[24 * 8 + 1..]);
+
+        mesh.push_triangle_with_attributes(
+            points,
+            normals,
+            has_uvs.then_some(uvs),
+            Color(color),
+        );
+    }
+
+    Some(mesh)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs, io,
+        path::{Path, PathBuf},
+        thread,
+        time::Duration,
+    };
+
+    use fj_interop::mesh::{Color, Mesh, Triangle};
+    use fj_kernel::algorithms::{
+        approx::Tolerance,
+        triangulate::{Triangulate, Triangulator},
+    };
+    use fj_math::{Point, Vector};
+
+    use crate::test_helpers::{compute_brep, cube, translate};
+
+    use super::{decode, encode, ApproxCache};
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle_with_attributes(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]].map(Point::from),
+            [Vector::unit_z(); 3],
+            Some([[0., 0.], [1., 0.], [0., 1.]].map(Point::from)),
+            Color([255, 0, 0, 255]),
+        );
+        mesh.push_triangle_with_attributes(
+            [[0., 0., 1.], [0., 1., 1.], [1., 0., 1.]].map(Point::from),
+            [-Vector::unit_z(); 3],
+            None,
+            Color([0, 0, 255, 128]),
+        );
+
+        let decoded = decode(&encode(&mesh)).expect("Failed to decode mesh");
+        assert_eq!(
+            decoded.triangles().collect::<Vec<_>>(),
+            mesh.triangles().collect::<Vec<_>>()
+        );
+
+        assert!(decode(b"not a mesh").is_none());
+        assert!(decode(&encode(&mesh)[..20]).is_none());
+    }
+
+    #[test]
+    fn cached_mesh_matches_fresh_one() -> anyhow::Result<()> {
+        let dir = temp_dir("cached_mesh_matches_fresh_one");
+        let cache = ApproxCache::new(&dir);
+
+        // Two separate cubes, which end up in separate entries.
+        let shape: fj::Shape = fj::Group {
+            a: cube(1.),
+            b: translate(cube(1.), [2., 0., 0.]),
+        }
+        .into();
+        let faces = compute_brep(&shape)?.into_inner();
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let triangulator = Triangulator::default();
+
+        let fresh = (&faces, tolerance).triangulate_with(triangulator);
+
+        let stored = cache.triangulate(&faces, tolerance, triangulator);
+        assert_eq!(num_entries(&dir), 2);
+
+        let cached = cache.triangulate(&faces, tolerance, triangulator);
+        assert_eq!(num_entries(&dir), 2);
+
+        // The cache triangulates the faces group by group, so the order of the
+        // triangles can differ.
+        assert_eq!(sorted_triangles(&stored), sorted_triangles(&fresh));
+        assert_eq!(sorted_triangles(&cached), sorted_triangles(&fresh));
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn changed_parameters_miss_cache() -> anyhow::Result<()> {
+        let dir = temp_dir("changed_parameters_miss_cache");
+        let cache = ApproxCache::new(&dir);
+
+        let faces = compute_brep(&cube(1.))?.into_inner();
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        cache.triangulate(&faces, tolerance, Triangulator::Delaunay);
+        assert_eq!(num_entries(&dir), 1);
+
+        cache.triangulate(
+            &faces,
+            Tolerance::from_scalar(0.02)?,
+            Triangulator::Delaunay,
+        );
+        assert_eq!(num_entries(&dir), 2);
+
+        cache.triangulate(&faces, tolerance, Triangulator::ConstrainedDelaunay);
+        assert_eq!(num_entries(&dir), 3);
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn evict_entries() -> anyhow::Result<()> {
+        let dir = temp_dir("evict_entries");
+        let cache = ApproxCache::new(&dir).with_max_size(0);
+
+        let faces = compute_brep(&cube(1.))?.into_inner();
+        let mesh = cache.triangulate(
+            &faces,
+            Tolerance::from_scalar(0.01)?,
+            Triangulator::default(),
+        );
+
+        assert!(mesh.triangles().count() > 0);
+        assert_eq!(num_entries(&dir), 0);
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn evict_oldest_entries_first() -> anyhow::Result<()> {
+        let dir = temp_dir("evict_oldest_entries_first");
+        let cache = ApproxCache::new(&dir);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let triangulator = Triangulator::default();
+
+        // Cubes at different positions have the same number of triangles, so
+        // their entries have the same size.
+        let mut entries = Vec::new();
+        for x in [0., 2., 4.] {
+            let faces =
+                compute_brep(&translate(cube(1.), [x, 0., 0.]))?.into_inner();
+            let path = cache.path(&faces, tolerance, triangulator);
+            entries.push((faces, path));
+        }
+        let [a, b, c] = [&entries[0], &entries[1], &entries[2]];
+
+        cache.triangulate(&a.0, tolerance, triangulator);
+        wait_for_newer_mtime(&dir, &a.1)?;
+        cache.triangulate(&b.0, tolerance, triangulator);
+        wait_for_newer_mtime(&dir, &b.1)?;
+
+        // Only leave room for two of the entries.
+        let len = fs::metadata(&a.1)?.len();
+        cache
+            .with_max_size(2 * len)
+            .triangulate(&c.0, tolerance, triangulator);
+
+        assert!(!a.1.exists());
+        assert!(b.1.exists());
+        assert!(c.1.exists());
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join(format!("fj-approx-cache-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn num_entries(dir: &Path) -> usize {
+        fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .filter(|entry| {
+                        entry
+                            .path()
+                            .extension()
+                            .map_or(false, |ext| ext == "mesh")
+                    })
+                    .count()
+            })
+            .unwrap_or(0)
+    }
+
+    fn sorted_triangles(mesh: &Mesh<Point<3>>) -> Vec<Triangle> {
+        let mut triangles = mesh.triangles().collect::<Vec<_>>();
+        triangles.sort();
+        triangles
+    }
+
+    /// Wait until newly written files get a later modification time than
+    /// `path`
+    ///
+    /// Depending on the file system, modification times can be quite coarse.
+    fn wait_for_newer_mtime(dir: &Path, path: &Path) -> io::Result<()> {
+        let modified = fs::metadata(path)?.modified()?;
+        let probe = dir.join("probe");
+
+        loop {
+            fs::write(&probe, [])?;
+            if fs::metadata(&probe)?.modified()? > modified {
+                return fs::remove_file(probe);
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
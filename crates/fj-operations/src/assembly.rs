@@ -0,0 +1,54 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    objects::{Faces, Objects},
+};
+use fj_math::Aabb;
+
+use crate::planes::Planes;
+
+use super::Shape;
+
+impl Shape for fj::Assembly {
+    type Brep = Faces;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let mut faces = Faces::new();
+
+        for part in self.parts() {
+            let part = part
+                .shape()
+                .compute_brep(config, objects, planes, debug_info)
+                .map_err(|err| err.with_context(context(part)))?;
+
+            faces.extend(part.into_inner());
+        }
+
+        faces.validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        self.parts()
+            .iter()
+            .map(|part| part.shape().bounding_volume())
+            .reduce(|a, b| a.merged(&b))
+            .unwrap_or(Aabb {
+                min: [0., 0., 0.].into(),
+                max: [0., 0., 0.].into(),
+            })
+    }
+}
+
+/// Describe a part, for use in error messages
+pub fn context(part: &fj::Part) -> String {
+    format!("part `{}`", part.name())
+}
@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use fj_interop::{attributes::Attributes, debug::DebugInfo};
+use fj_kernel::{
+    algorithms::validate::{Validated, ValidationConfig, ValidationError},
+    objects::{Faces, Objects},
+};
+use fj_math::{Aabb, Scalar};
+
+use crate::planes::Planes;
+
+use super::Shape;
+
+impl Shape for fj::Attributed {
+    type Brep = Faces;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        // Attributes don't affect the geometry. They are collected separately,
+        // by `attributes`.
+        self.shape.compute_brep(config, objects, planes, debug_info)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        self.shape.bounding_volume()
+    }
+}
+
+/// Collect the attributes of a shape
+///
+/// Attributes attached to a shape apply to everything it's made from, unless
+/// those parts have attributes of their own. Where parts with different
+/// attributes are combined into one shape, only the attributes they agree on
+/// apply to that shape.
+///
+/// Shapes that are only used as tools don't contribute to the result, like the
+/// shape that is subtracted by a difference.
+pub fn attributes(shape: &fj::Shape) -> Attributes {
+    match shape {
+        fj::Shape::Assembly(shape) => shape
+            .parts()
+            .iter()
+            .map(|part| attributes(part.shape()))
+            .reduce(Attributes::combined)
+            .unwrap_or_default(),
+        fj::Shape::Attributed(shape) => {
+            attributes(&shape.shape).overridden_by(convert(&shape.attributes))
+        }
+        fj::Shape::CircularPattern(shape) => attributes(&shape.shape),
+        fj::Shape::Difference(shape) => attributes(&shape.a),
+        fj::Shape::Group(shape) => {
+            attributes(&shape.a).combined(attributes(&shape.b))
+        }
+        fj::Shape::Hull(shape) => shape
+            .shapes()
+            .iter()
+            .map(attributes)
+            .reduce(Attributes::combined)
+            .unwrap_or_default(),
+        fj::Shape::Intersection(shape) => {
+            attributes(&shape.a).combined(attributes(&shape.b))
+        }
+        fj::Shape::LinearPattern(shape) => attributes(&shape.shape),
+        fj::Shape::Minkowski(shape) => attributes(&shape.shape),
+        fj::Shape::Mirror(shape) => attributes(&shape.shape),
+        fj::Shape::Paint(shape) => attributes(&shape.shape),
+        fj::Shape::Shell(shape) => attributes(shape.shape()),
+        fj::Shape::Transform(shape) => attributes(&shape.shape),
+        fj::Shape::Union(shape) => {
+            attributes(&shape.a).combined(attributes(&shape.b))
+        }
+        fj::Shape::Loft(_)
+        | fj::Shape::Revolve(_)
+        | fj::Shape::Shape2d(_)
+        | fj::Shape::Sweep(_) => Attributes::default(),
+    }
+}
+
+fn convert(attributes: &fj::Attributes) -> Attributes {
+    Attributes {
+        material: attributes.material().map(Into::into),
+        density: attributes.density().map(Scalar::from),
+        metadata: attributes
+            .metadata()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect::<BTreeMap<_, _>>(),
+    }
+}
@@ -2,7 +2,8 @@ use fj_interop::{debug::DebugInfo, mesh::Color};
 use fj_kernel::{
     algorithms::{
         reverse::Reverse,
-        validate::{Validate, Validated, ValidationConfig, ValidationError},
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
     },
     iter::ObjectIters,
     objects::{Face, Objects, Sketch},
@@ -93,7 +94,7 @@ impl Shape for fj::Difference2d {
         }
 
         let difference = Sketch::new().with_faces(faces);
-        difference.validate_with_config(config)
+        difference.validate_or_snap(config, objects)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
@@ -1,7 +1,8 @@
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
-    algorithms::validate::{
-        Validate, Validated, ValidationConfig, ValidationError,
+    algorithms::{
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
     },
     objects::{Faces, Objects},
 };
@@ -29,7 +30,7 @@ impl Shape for fj::Group {
         faces.extend(a.into_inner());
         faces.extend(b.into_inner());
 
-        faces.validate_with_config(config)
+        faces.validate_or_snap(config, objects)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
@@ -0,0 +1,66 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        hull::Hull,
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    objects::{Faces, Objects, Solid},
+};
+use fj_math::{Aabb, Scalar};
+
+use crate::planes::Planes;
+
+use super::Shape;
+
+impl Shape for fj::Hull {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let mut faces = Faces::new();
+        for shape in self.shapes() {
+            let shape =
+                shape.compute_brep(config, objects, planes, debug_info)?;
+            faces.extend(shape.into_inner());
+        }
+
+        let tolerance = approximation_tolerance(&self.bounding_volume());
+        let solid = (&faces, tolerance).hull(objects);
+        solid.validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        self.shapes()
+            .iter()
+            .map(|shape| shape.bounding_volume())
+            .reduce(|a, b| a.merged(&b))
+            .unwrap_or(Aabb {
+                min: [0., 0., 0.].into(),
+                max: [0., 0., 0.].into(),
+            })
+    }
+}
+
+/// Compute the tolerance for approximating shapes that are operated on
+///
+/// Curved shapes are approximated by some operations. The result becomes part
+/// of the model, so the approximation needs to be more accurate than the one
+/// that is later used to display or export the model.
+pub(crate) fn approximation_tolerance(aabb: &Aabb<3>) -> Tolerance {
+    let mut min_extent = Scalar::MAX;
+    for extent in aabb.size().components {
+        if extent > Scalar::ZERO && extent < min_extent {
+            min_extent = extent;
+        }
+    }
+
+    Tolerance::from_scalar(min_extent / Scalar::from_f64(10_000.))
+        .expect("Can't approximate shapes without extent")
+}
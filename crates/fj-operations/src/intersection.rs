@@ -0,0 +1,83 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        boolean::Intersection,
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    objects::{Face, Faces, Objects, Shell},
+};
+use fj_math::Aabb;
+
+use crate::planes::Planes;
+
+use super::Shape;
+
+impl Shape for fj::Intersection {
+    type Brep = Faces;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let [a, b] = [&self.a, &self.b].map(|shape| {
+            shape
+                .compute_brep(config, objects, planes, debug_info)
+                .map(|faces| Shell::new().with_faces(faces.into_inner()))
+        });
+
+        let [a, b] = [a?, b?];
+        if !a.faces().into_iter().chain(b.faces()).all(Face::is_polygon) {
+            return Err(ValidationError::Unsupported(
+                "Boolean operations only support shapes with planar faces \
+                and straight edges"
+                    .into(),
+            ));
+        }
+
+        let faces = a.intersection(b, objects).into_faces();
+        faces.validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // This is a conservative estimate of the bounding box: The
+        // intersection can't be bigger than either of the shapes.
+        self.a.bounding_volume()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_kernel::algorithms::validate::ValidationError;
+
+    use crate::test_helpers::{compute_brep, cube, cylinder, translate};
+
+    #[test]
+    fn intersection_of_cubes() -> anyhow::Result<()> {
+        let intersection = fj::Intersection {
+            a: cube(1.),
+            b: translate(cube(1.), [0.5, 0.5, 0.5]),
+        };
+
+        let faces = compute_brep(&intersection)?.into_inner();
+        assert_eq!(faces.into_iter().count(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersection_with_cylinder() {
+        let intersection = fj::Intersection {
+            a: cube(1.),
+            b: cylinder(0.5, 1.),
+        };
+
+        assert!(matches!(
+            compute_brep(&intersection),
+            Err(ValidationError::Unsupported(_))
+        ));
+    }
+}
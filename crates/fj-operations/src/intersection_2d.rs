@@ -0,0 +1,68 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        boolean::Intersection,
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    objects::{Face, Objects, Sketch},
+};
+use fj_math::Aabb;
+
+use crate::planes::Planes;
+
+use super::Shape;
+
+impl Shape for fj::Intersection2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let [a, b] = self.shapes();
+        let [a, b] = [a, b].map(|shape| {
+            shape.compute_brep(config, objects, planes, debug_info)
+        });
+        let [a, b] = [a?, b?];
+
+        if !a.faces().into_iter().chain(b.faces()).all(Face::is_polygon) {
+            return Err(ValidationError::Unsupported(
+                "Boolean operations on sketches only support straight edges"
+                    .into(),
+            ));
+        }
+
+        let intersection = a.into_inner().intersection(b.into_inner(), objects);
+        intersection.validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // This is a conservative estimate of the bounding box: The
+        // intersection can't be bigger than either of the shapes.
+        self.shapes()[0].bounding_volume()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_kernel::algorithms::validate::ValidationError;
+
+    use crate::test_helpers::{circle, compute_brep, square};
+
+    #[test]
+    fn intersection_with_circle() {
+        let intersection = fj::Intersection2d::from_shapes([
+            square(1.).into(),
+            circle(1.).into(),
+        ]);
+
+        assert!(matches!(
+            compute_brep(&intersection),
+            Err(ValidationError::Unsupported(_))
+        ));
+    }
+}
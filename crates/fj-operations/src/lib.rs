@@ -16,23 +16,45 @@
 
 #![warn(missing_docs)]
 
+pub mod approx_cache;
 pub mod shape_processor;
 
+mod assembly;
+mod attributed;
+mod difference;
 mod difference_2d;
 mod group;
+mod hull;
+mod intersection;
+mod intersection_2d;
+mod loft;
+mod minkowski;
+mod mirror;
+mod offset_2d;
+mod paint;
+mod pattern;
 mod planes;
+mod revolve;
+mod shell;
 mod sketch;
 mod sweep;
+mod text;
 mod transform;
+mod union;
+mod union_2d;
+
+#[cfg(test)]
+mod test_helpers;
 
 pub use self::planes::Planes;
 
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
-    algorithms::validate::{
-        Validate, Validated, ValidationConfig, ValidationError,
+    algorithms::{
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
     },
-    objects::{Faces, Objects, Sketch},
+    objects::{Faces, Objects, Sketch, Solid},
 };
 use fj_math::Aabb;
 
@@ -67,38 +89,120 @@ impl Shape for fj::Shape {
         planes: &Planes,
         debug_info: &mut DebugInfo,
     ) -> Result<Validated<Self::Brep>, ValidationError> {
-        match self {
+        let result = match self {
+            Self::Assembly(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
+            Self::Attributed(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
+            Self::CircularPattern(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
+            Self::Difference(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
             Self::Shape2d(shape) => shape
-                .compute_brep(config, objects, planes, debug_info)?
-                .into_inner()
-                .into_faces()
-                .validate_with_config(config),
+                .compute_brep(config, objects, planes, debug_info)
+                .and_then(|brep| {
+                    brep.into_inner()
+                        .into_faces()
+                        .validate_or_snap(config, objects)
+                }),
+            Self::Shell(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
             Self::Group(shape) => {
                 shape.compute_brep(config, objects, planes, debug_info)
             }
+            Self::Hull(shape) => shape
+                .compute_brep(config, objects, planes, debug_info)
+                .and_then(|brep| {
+                    solid_to_faces(brep.into_inner(), config, objects)
+                }),
+            Self::Intersection(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
+            Self::LinearPattern(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
+            Self::Loft(shape) => shape
+                .compute_brep(config, objects, planes, debug_info)
+                .and_then(|brep| {
+                    solid_to_faces(brep.into_inner(), config, objects)
+                }),
+            Self::Minkowski(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
+            Self::Mirror(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
+            Self::Paint(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
+            Self::Revolve(shape) => shape
+                .compute_brep(config, objects, planes, debug_info)
+                .and_then(|brep| {
+                    solid_to_faces(brep.into_inner(), config, objects)
+                }),
             Self::Sweep(shape) => shape
-                .compute_brep(config, objects, planes, debug_info)?
-                .into_inner()
-                .into_shells()
-                .map(|shell| shell.into_faces())
-                .reduce(|mut a, b| {
-                    a.extend(b);
-                    a
-                })
-                .unwrap_or_default()
-                .validate_with_config(config),
+                .compute_brep(config, objects, planes, debug_info)
+                .and_then(|brep| {
+                    solid_to_faces(brep.into_inner(), config, objects)
+                }),
             Self::Transform(shape) => {
                 shape.compute_brep(config, objects, planes, debug_info)
             }
-        }
+            Self::Union(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
+        };
+
+        // 2D shapes describe themselves. All other operations are named here,
+        // so errors can be traced through the shape.
+        let context = match self {
+            Self::Assembly(_) => "assembly",
+            Self::Attributed(_) => "attributed shape",
+            Self::CircularPattern(_) => "circular pattern",
+            Self::Difference(_) => "difference",
+            Self::Shape2d(_) => return result,
+            Self::Shell(_) => "shell",
+            Self::Group(_) => "group",
+            Self::Hull(_) => "hull",
+            Self::Intersection(_) => "intersection",
+            Self::LinearPattern(_) => "linear pattern",
+            Self::Loft(_) => "loft",
+            Self::Minkowski(_) => "Minkowski sum",
+            Self::Mirror(_) => "mirror",
+            Self::Paint(_) => "paint",
+            Self::Revolve(_) => "revolution",
+            Self::Sweep(_) => "sweep",
+            Self::Transform(_) => "transform",
+            Self::Union(_) => "union",
+        };
+        result.map_err(|err| err.with_context(context))
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
         match self {
+            Self::Assembly(shape) => shape.bounding_volume(),
+            Self::Attributed(shape) => shape.bounding_volume(),
+            Self::CircularPattern(shape) => shape.bounding_volume(),
+            Self::Difference(shape) => shape.bounding_volume(),
             Self::Shape2d(shape) => shape.bounding_volume(),
+            Self::Shell(shape) => shape.bounding_volume(),
             Self::Group(shape) => shape.bounding_volume(),
+            Self::Hull(shape) => shape.bounding_volume(),
+            Self::Intersection(shape) => shape.bounding_volume(),
+            Self::LinearPattern(shape) => shape.bounding_volume(),
+            Self::Loft(shape) => shape.bounding_volume(),
+            Self::Minkowski(shape) => shape.bounding_volume(),
+            Self::Mirror(shape) => shape.bounding_volume(),
+            Self::Paint(shape) => shape.bounding_volume(),
+            Self::Revolve(shape) => shape.bounding_volume(),
             Self::Sweep(shape) => shape.bounding_volume(),
             Self::Transform(shape) => shape.bounding_volume(),
+            Self::Union(shape) => shape.bounding_volume(),
         }
     }
 }
@@ -113,20 +217,63 @@ impl Shape for fj::Shape2d {
         planes: &Planes,
         debug_info: &mut DebugInfo,
     ) -> Result<Validated<Self::Brep>, ValidationError> {
-        match self {
+        let result = match self {
             Self::Difference(shape) => {
                 shape.compute_brep(config, objects, planes, debug_info)
             }
+            Self::Intersection(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
+            Self::Offset(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
             Self::Sketch(shape) => {
                 shape.compute_brep(config, objects, planes, debug_info)
             }
-        }
+            Self::Text(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
+            Self::Union(shape) => {
+                shape.compute_brep(config, objects, planes, debug_info)
+            }
+        };
+
+        let context = match self {
+            Self::Difference(_) => "2D difference",
+            Self::Intersection(_) => "2D intersection",
+            Self::Offset(_) => "2D offset",
+            Self::Sketch(_) => return result,
+            Self::Text(_) => "text",
+            Self::Union(_) => "2D union",
+        };
+        result.map_err(|err| err.with_context(context))
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
         match self {
             Self::Difference(shape) => shape.bounding_volume(),
+            Self::Intersection(shape) => shape.bounding_volume(),
+            Self::Offset(shape) => shape.bounding_volume(),
             Self::Sketch(shape) => shape.bounding_volume(),
+            Self::Text(shape) => shape.bounding_volume(),
+            Self::Union(shape) => shape.bounding_volume(),
         }
     }
 }
+
+/// Collect the faces of all shells of a solid and validate them
+fn solid_to_faces(
+    solid: Solid,
+    config: &ValidationConfig,
+    objects: &Objects,
+) -> Result<Validated<Faces>, ValidationError> {
+    solid
+        .into_shells()
+        .map(|shell| shell.into_faces())
+        .reduce(|mut a, b| {
+            a.extend(b);
+            a
+        })
+        .unwrap_or_default()
+        .validate_or_snap(config, objects)
+}
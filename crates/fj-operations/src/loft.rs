@@ -0,0 +1,59 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        loft::Loft,
+        snap::Snap,
+        transform::TransformObject,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    objects::{Objects, Solid},
+};
+use fj_math::Aabb;
+
+use crate::{planes::Planes, transform::make_transform};
+
+use super::Shape;
+
+impl Shape for fj::Loft {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let sketches = self
+            .sections()
+            .iter()
+            .map(|section| {
+                let sketch = section
+                    .sketch
+                    .compute_brep(config, objects, planes, debug_info)?
+                    .into_inner();
+                let transform =
+                    make_transform(section.axis, section.angle, section.offset);
+
+                Ok(sketch.transform(&transform, objects)?)
+            })
+            .collect::<Result<Vec<_>, ValidationError>>()?;
+
+        let solid = sketches.loft(objects);
+        solid.validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        self.sections()
+            .iter()
+            .map(|section| {
+                make_transform(section.axis, section.angle, section.offset)
+                    .transform_aabb(&section.sketch.bounding_volume())
+            })
+            .reduce(|a, b| a.merged(&b))
+            .unwrap_or(Aabb {
+                min: [0., 0., 0.].into(),
+                max: [0., 0., 0.].into(),
+            })
+    }
+}
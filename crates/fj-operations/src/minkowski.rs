@@ -0,0 +1,70 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        minkowski::{Convex, MinkowskiSum},
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    objects::{Faces, Objects, Shell, Solid},
+};
+use fj_math::{Aabb, Point, Scalar};
+
+use crate::{hull::approximation_tolerance, planes::Planes};
+
+use super::Shape;
+
+impl Shape for fj::Minkowski {
+    type Brep = Faces;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let faces = self
+            .shape
+            .compute_brep(config, objects, planes, debug_info)?
+            .into_inner();
+        let solid = Solid::new().with_shells([Shell::new().with_faces(faces)]);
+
+        let tolerance = approximation_tolerance(&self.bounding_volume());
+        let sum =
+            (&solid, tolerance).minkowski_sum(&convex(&self.convex), objects);
+
+        let mut faces = Faces::new();
+        faces.extend(sum.into_shells().flat_map(Shell::into_faces));
+
+        faces.validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let aabb = self.shape.bounding_volume();
+        let convex = match &self.convex {
+            fj::Convex::Sphere { radius } => Aabb {
+                min: [-radius; 3].into(),
+                max: [*radius; 3].into(),
+            },
+            fj::Convex::Polyhedron { points } => {
+                Aabb::<3>::from_points(points.iter().copied())
+            }
+        };
+
+        Aabb {
+            min: aabb.min + convex.min.coords,
+            max: aabb.max + convex.max.coords,
+        }
+    }
+}
+
+fn convex(convex: &fj::Convex) -> Convex {
+    match convex {
+        fj::Convex::Sphere { radius } => Convex::Sphere {
+            radius: Scalar::from_f64(*radius),
+        },
+        fj::Convex::Polyhedron { points } => Convex::Polyhedron(
+            points.iter().copied().map(Point::from).collect(),
+        ),
+    }
+}
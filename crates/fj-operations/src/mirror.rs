@@ -0,0 +1,97 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        mirror::Mirror,
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    objects::{Faces, Objects},
+};
+use fj_math::{Aabb, Plane, Point, Scalar, Transform, Vector};
+
+use crate::planes::Planes;
+
+use super::Shape;
+
+impl Shape for fj::Mirror {
+    type Brep = Faces;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let faces = self
+            .shape
+            .compute_brep(config, objects, planes, debug_info)?
+            .into_inner()
+            .mirror(&plane(self.origin, self.normal), objects)?;
+
+        faces.validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let origin = Vector::from(self.origin);
+        let reflection = Transform::translation(origin)
+            * Transform::reflection(self.normal)
+            * Transform::translation(-origin);
+
+        Aabb::<3>::from_points(
+            self.shape
+                .bounding_volume()
+                .vertices()
+                .map(|vertex| reflection.transform_point(&vertex)),
+        )
+    }
+}
+
+/// Create the plane through `origin`, with the given normal
+fn plane(origin: [f64; 3], normal: [f64; 3]) -> Plane {
+    let normal = Vector::from(normal).normalize();
+
+    // Any vector that isn't parallel to the normal can be used to construct
+    // the plane's coordinate system.
+    let other = if normal.x.abs() < Scalar::from_f64(0.5) {
+        Vector::unit_x()
+    } else {
+        Vector::unit_y()
+    };
+
+    let u = normal.cross(&other).normalize();
+    let v = normal.cross(&u);
+
+    Plane::from_parametric(Point::from(origin), u, v)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_kernel::algorithms::{approx::Tolerance, measure::Measure};
+    use fj_math::{Point, Scalar};
+
+    use crate::test_helpers::{compute_brep, cube};
+
+    #[test]
+    fn mirror_cube() -> anyhow::Result<()> {
+        let mirror = fj::Mirror {
+            shape: cube(1.),
+            origin: [2., 0., 0.],
+            normal: [1., 0., 0.],
+        };
+
+        let faces = compute_brep(&mirror)?.into_inner();
+        let properties = (&faces, Tolerance::from_scalar(0.1)?).measure();
+
+        // If the mirrored faces were facing inward, the volume would be
+        // negative.
+        assert!((properties.volume - Scalar::ONE).abs() < Scalar::from(1e-9));
+        assert!(
+            (properties.center_of_mass - Point::from([3.5, 0.5, 0.5]))
+                .magnitude()
+                < Scalar::from(1e-9)
+        );
+
+        Ok(())
+    }
+}
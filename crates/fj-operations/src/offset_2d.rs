@@ -0,0 +1,310 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::validate::{Validated, ValidationConfig, ValidationError},
+    objects::{Objects, Sketch},
+};
+use fj_math::{Aabb, Point, Scalar, Vector};
+
+use crate::planes::Planes;
+
+use super::Shape;
+
+impl Shape for fj::Offset2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        offset_sketch(self)?.compute_brep(config, objects, planes, debug_info)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        match offset_sketch(self) {
+            Ok(sketch) => sketch.bounding_volume(),
+            Err(_) => {
+                // The offset can't be computed, but the shape still needs a
+                // bounding volume. Edges and arcs don't move further than the
+                // offset distance, so this is a conservative estimate.
+                let aabb = self.sketch().bounding_volume();
+                let margin = Scalar::from(self.distance()).max(Scalar::ZERO);
+                let margin = Vector::from([margin, margin, Scalar::ZERO]);
+
+                Aabb {
+                    min: aabb.min - margin,
+                    max: aabb.max + margin,
+                }
+            }
+        }
+    }
+}
+
+/// Compute the sketch that has the offset outline
+///
+/// Returns an error, if the sketch is made from segments, or if the offset
+/// makes it or any of its edges vanish.
+fn offset_sketch(offset: &fj::Offset2d) -> Result<fj::Sketch, ValidationError> {
+    let sketch = offset.sketch();
+    let distance = offset.distance();
+
+    let offset_sketch = match sketch.chain() {
+        fj::Chain::Circle(circle) => {
+            let radius = circle.radius() + distance;
+            if radius <= 0. {
+                return Err(ValidationError::InvalidParameters(format!(
+                    "Offset of {distance} makes circle vanish"
+                )));
+            }
+
+            fj::Sketch::from_circle(fj::Circle::from_radius(radius))
+        }
+        fj::Chain::PolyChain(poly_chain) => {
+            let (points, corners) = offset_polygon(
+                &poly_chain.to_points(),
+                sketch.corners(),
+                Scalar::from(distance),
+                offset.join(),
+            )?;
+
+            fj::Sketch::from_points(points).with_corners(corners)
+        }
+        fj::Chain::Segments(_) => {
+            return Err(ValidationError::Unsupported(
+                "Offsetting sketches made from segments is not supported"
+                    .into(),
+            ));
+        }
+    };
+
+    let offset_sketch = offset_sketch.with_color(sketch.color());
+    let offset_sketch = match sketch.label() {
+        Some(label) => offset_sketch.with_label(label),
+        None => offset_sketch,
+    };
+
+    Ok(offset_sketch)
+}
+
+/// Offset the points of a sketch, along with the shape of its corners
+///
+/// Each edge moves along its outward normal, and each corner moves to where
+/// the moved edges meet. A rounded corner stays at the same center, so its
+/// radius grows or shrinks with the offset. If it shrinks to nothing, the
+/// corner becomes sharp.
+///
+/// At sharp corners where the edges move apart, a round join is the same as a
+/// rounded corner with the offset distance as its radius.
+///
+/// Returns an error, if the offset makes any of the edges vanish.
+fn offset_polygon(
+    points: &[[f64; 2]],
+    corners: &[fj::Corner],
+    distance: Scalar,
+    join: fj::OffsetJoin,
+) -> Result<(Vec<[f64; 2]>, Vec<fj::Corner>), ValidationError> {
+    let n = points.len();
+
+    // A chamfer is an edge of its own. Turn chamfered corners into two points,
+    // so only the radius of each corner is left to track. Sharp corners have a
+    // radius of zero.
+    let mut polygon = Vec::new();
+    for (i, &point) in points.iter().enumerate() {
+        let point = Point::from(point);
+
+        match corners.get(i).copied().unwrap_or(fj::Corner::Sharp) {
+            fj::Corner::Sharp => polygon.push((point, Scalar::ZERO)),
+            fj::Corner::Round { radius } => {
+                polygon.push((point, Scalar::from(radius)));
+            }
+            fj::Corner::Chamfer { distance } => {
+                let previous = Point::from(points[(i + n - 1) % n]);
+                let next = Point::from(points[(i + 1) % n]);
+
+                for neighbor in [previous, next] {
+                    let trimmed =
+                        point + (neighbor - point).normalize() * distance;
+                    polygon.push((trimmed, Scalar::ZERO));
+                }
+            }
+        }
+    }
+
+    let n = polygon.len();
+    let edge = |i: usize| {
+        let direction = polygon[(i + 1) % n].0 - polygon[i].0;
+        direction.normalize()
+    };
+
+    // Positive, if the polygon winds counter-clockwise. The outside is then to
+    // the right of each edge.
+    let twice_area = (0..n).fold(Scalar::ZERO, |sum, i| {
+        let a = polygon[i].0;
+        let b = polygon[(i + 1) % n].0;
+        sum + a.u * b.v - b.u * a.v
+    });
+    let winding = if twice_area < Scalar::ZERO {
+        -Scalar::ONE
+    } else {
+        Scalar::ONE
+    };
+    let outward = |direction: Vector<2>| {
+        Vector::from([direction.v, -direction.u]) * winding
+    };
+
+    let mut offset_points = Vec::new();
+    let mut offset_corners = Vec::new();
+
+    for (i, &(point, radius)) in polygon.iter().enumerate() {
+        let incoming = edge((i + n - 1) % n);
+        let outgoing = edge(i);
+        let [a, b] = [outward(incoming), outward(outgoing)];
+
+        // The moved edges meet on the bisector of the corner.
+        let offset_point =
+            point + (a + b) * distance / (Scalar::ONE + a.dot(&b));
+
+        // The edges move apart where the corner is convex and the sketch
+        // grows, or where it is concave and the sketch shrinks.
+        let convex = incoming.cross2d(&outgoing) * winding > Scalar::ZERO;
+        let apart = if convex { distance } else { -distance };
+
+        let sharp = radius == Scalar::ZERO;
+        let radius = if sharp && join == fj::OffsetJoin::Miter {
+            Scalar::ZERO
+        } else {
+            radius + apart
+        };
+        let corner = if radius > Scalar::ZERO {
+            fj::Corner::Round {
+                radius: radius.into_f64(),
+            }
+        } else {
+            fj::Corner::Sharp
+        };
+
+        offset_points.push(offset_point);
+        offset_corners.push(corner);
+    }
+
+    for i in 0..n {
+        let moved = offset_points[(i + 1) % n] - offset_points[i];
+        if moved.dot(&edge(i)) <= Scalar::ZERO {
+            return Err(ValidationError::InvalidParameters(format!(
+                "Offset of {distance} makes an edge of the sketch vanish"
+            )));
+        }
+    }
+
+    let offset_points = offset_points.into_iter().map(Into::into).collect();
+    Ok((offset_points, offset_corners))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_kernel::algorithms::validate::ValidationError;
+    use fj_math::Scalar;
+
+    use crate::{test_helpers::compute_brep, Shape};
+
+    use super::offset_polygon;
+
+    const SQUARE: [[f64; 2]; 4] = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+
+    #[test]
+    fn offset_square_outward() -> anyhow::Result<()> {
+        let (points, corners) = offset_polygon(
+            &SQUARE,
+            &[],
+            Scalar::from(0.5),
+            fj::OffsetJoin::Miter,
+        )?;
+        assert_eq!(
+            points,
+            [[-0.5, -0.5], [1.5, -0.5], [1.5, 1.5], [-0.5, 1.5]]
+        );
+        assert_eq!(corners, [fj::Corner::Sharp; 4]);
+
+        // A round join rounds the corners, which move away from each other.
+        let (_, corners) = offset_polygon(
+            &SQUARE,
+            &[],
+            Scalar::from(0.5),
+            fj::OffsetJoin::Round,
+        )?;
+        assert_eq!(corners, [fj::Corner::Round { radius: 0.5 }; 4]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_square_inward() -> anyhow::Result<()> {
+        for join in [fj::OffsetJoin::Miter, fj::OffsetJoin::Round] {
+            let (points, corners) =
+                offset_polygon(&SQUARE, &[], Scalar::from(-0.25), join)?;
+
+            assert_eq!(
+                points,
+                [[0.25, 0.25], [0.75, 0.25], [0.75, 0.75], [0.25, 0.75]]
+            );
+            assert_eq!(corners, [fj::Corner::Sharp; 4]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_makes_edge_vanish() {
+        let result = offset_polygon(
+            &SQUARE,
+            &[],
+            Scalar::from(-0.6),
+            fj::OffsetJoin::Miter,
+        );
+        assert!(matches!(result, Err(ValidationError::InvalidParameters(_))));
+
+        // The bounding volume must still be available for such a shape.
+        let offset = fj::Offset2d::from_sketch(
+            fj::Sketch::from_points(SQUARE.to_vec()),
+            -0.6,
+        );
+        assert!(matches!(
+            compute_brep(&offset),
+            Err(ValidationError::InvalidParameters(_))
+        ));
+        offset.bounding_volume();
+    }
+
+    #[test]
+    fn offset_circle_vanishes() {
+        let offset = fj::Offset2d::from_sketch(
+            fj::Sketch::from_circle(fj::Circle::from_radius(1.)),
+            -1.,
+        );
+
+        assert!(matches!(
+            compute_brep(&offset),
+            Err(ValidationError::InvalidParameters(_))
+        ));
+    }
+
+    #[test]
+    fn offset_segments() {
+        let sketch = fj::Sketch::from_segments(vec![
+            fj::Segment::LineTo { end: [1., 0.] },
+            fj::Segment::ArcTo {
+                end: [0., 0.],
+                radius: 0.5,
+            },
+        ]);
+        let offset = fj::Offset2d::from_sketch(sketch, 0.1);
+
+        assert!(matches!(
+            compute_brep(&offset),
+            Err(ValidationError::Unsupported(_))
+        ));
+        offset.bounding_volume();
+    }
+}
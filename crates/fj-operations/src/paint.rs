@@ -0,0 +1,78 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    objects::{Faces, Objects},
+};
+use fj_math::Aabb;
+
+use crate::planes::Planes;
+
+use super::Shape;
+
+impl Shape for fj::Paint {
+    type Brep = Faces;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let shape = self
+            .shape
+            .compute_brep(config, objects, planes, debug_info)?;
+
+        let mut faces = Faces::new();
+        faces.extend(
+            shape
+                .into_inner()
+                .into_iter()
+                .map(|face| face.with_color(Color(self.color))),
+        );
+
+        faces.validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        self.shape.bounding_volume()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Color;
+
+    use crate::test_helpers::{compute_brep, cube, translate};
+
+    #[test]
+    fn paint_part_of_group() -> anyhow::Result<()> {
+        let blue = [0, 0, 255, 255];
+
+        // The color must survive further operations on the painted shape.
+        let painted = fj::Paint {
+            shape: cube(1.),
+            color: blue,
+        };
+        let group = fj::Group {
+            a: translate(painted.into(), [2., 0., 0.]),
+            b: cube(1.),
+        };
+
+        let faces = compute_brep(&group)?.into_inner();
+
+        let (painted, unpainted): (Vec<_>, Vec<_>) = faces
+            .into_iter()
+            .partition(|face| face.color() == Color(blue));
+        assert_eq!(painted.len(), 6);
+        assert_eq!(unpainted.len(), 6);
+        assert!(unpainted
+            .iter()
+            .all(|face| face.color() == Color::default()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        snap::Snap,
+        transform::TransformObject,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    objects::{Faces, Objects},
+    partial::PartialBuildError,
+};
+use fj_math::{Aabb, Transform, Vector};
+
+use crate::planes::Planes;
+
+use super::Shape;
+
+impl Shape for fj::LinearPattern {
+    type Brep = Faces;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let faces = self
+            .shape
+            .compute_brep(config, objects, planes, debug_info)?
+            .into_inner();
+
+        copies(faces, &linear_transforms(self), objects)?
+            .validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        bounding_volume(&self.shape, &linear_transforms(self))
+    }
+}
+
+impl Shape for fj::CircularPattern {
+    type Brep = Faces;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let faces = self
+            .shape
+            .compute_brep(config, objects, planes, debug_info)?
+            .into_inner();
+
+        copies(faces, &circular_transforms(self), objects)?
+            .validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        bounding_volume(&self.shape, &circular_transforms(self))
+    }
+}
+
+/// Compute the transform of each copy in a linear pattern
+fn linear_transforms(pattern: &fj::LinearPattern) -> Vec<Transform> {
+    let offset = Vector::from(pattern.offset);
+
+    (0..pattern.count)
+        .map(|i| Transform::translation(offset * f64::from(i)))
+        .collect()
+}
+
+/// Compute the transform of each copy in a circular pattern
+fn circular_transforms(pattern: &fj::CircularPattern) -> Vec<Transform> {
+    let axis = Vector::from(pattern.axis).normalize();
+
+    (0..pattern.count)
+        .map(|i| Transform::rotation(axis * pattern.angle.rad() * f64::from(i)))
+        .collect()
+}
+
+/// Transform a copy of the faces by each of the transforms
+///
+/// The shape is only computed once, and then copied, which is much cheaper
+/// than computing it again for every copy.
+fn copies(
+    faces: Faces,
+    transforms: &[Transform],
+    objects: &Objects,
+) -> Result<Faces, PartialBuildError> {
+    let mut copies = Faces::new();
+    for transform in transforms {
+        copies.extend(faces.clone().transform(transform, objects)?);
+    }
+    Ok(copies)
+}
+
+fn bounding_volume(shape: &fj::Shape, transforms: &[Transform]) -> Aabb<3> {
+    let aabb = shape.bounding_volume();
+
+    Aabb::<3>::from_points(transforms.iter().flat_map(|transform| {
+        aabb.vertices()
+            .map(|vertex| transform.transform_point(&vertex))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_kernel::algorithms::{approx::Tolerance, measure::Measure};
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        test_helpers::{compute_brep, cube},
+        Shape,
+    };
+
+    #[test]
+    fn linear_pattern() -> anyhow::Result<()> {
+        let pattern = fj::LinearPattern {
+            shape: cube(1.),
+            count: 3,
+            offset: [2., 0., 0.],
+        };
+
+        let faces = compute_brep(&pattern)?.into_inner();
+        assert_eq!(faces.into_iter().count(), 18);
+
+        let aabb = pattern.bounding_volume();
+        assert_eq!(aabb.min, Point::from([0., 0., 0.]));
+        assert_eq!(aabb.max, Point::from([5., 1., 1.]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn circular_pattern() -> anyhow::Result<()> {
+        // The copies must not touch, so the cube is placed away from the axis.
+        let square = fj::Sketch::from_points(vec![
+            [1., 1.],
+            [2., 1.],
+            [2., 2.],
+            [1., 2.],
+        ]);
+        let pattern = fj::CircularPattern {
+            shape: fj::Sweep::from_path(square.into(), [0., 0., 1.]).into(),
+            count: 4,
+            axis: [0., 0., 1.],
+            angle: fj::Angle::from_deg(90.),
+        };
+
+        let faces = compute_brep(&pattern)?.into_inner();
+        let properties = (&faces, Tolerance::from_scalar(0.1)?).measure();
+
+        // The copies surround the axis, so their center of mass lies on it.
+        assert!(
+            (properties.volume - Scalar::from(4.)).abs() < Scalar::from(1e-9)
+        );
+        assert!(
+            (properties.center_of_mass - Point::from([0., 0., 0.5]))
+                .magnitude()
+                < Scalar::from(1e-9)
+        );
+
+        Ok(())
+    }
+}
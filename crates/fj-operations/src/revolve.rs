@@ -0,0 +1,84 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        snap::Snap,
+        sweep::Revolve,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    objects::{Objects, Solid},
+};
+use fj_math::{Aabb, Line, Point, Scalar, Vector};
+
+use crate::{planes::Planes, sweep::around_axis};
+
+use super::Shape;
+
+impl Shape for fj::Revolve {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let sketch = self
+            .shape
+            .compute_brep(config, objects, planes, debug_info)?
+            .into_inner();
+        let axis = Line::from_origin_and_direction(
+            Point::origin(),
+            Vector::from(self.axis),
+        );
+
+        // `fj::Angle` wraps around, so a full turn arrives here as zero.
+        let mut angle = Scalar::from(self.angle.rad());
+        if angle == Scalar::ZERO {
+            angle = Scalar::TAU;
+        }
+
+        let solid = sketch.revolve(axis, angle, objects);
+        solid.validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        around_axis(&self.shape.bounding_volume(), Point::origin())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_kernel::objects::Shell;
+
+    use crate::test_helpers::compute_brep;
+
+    #[test]
+    fn full_and_partial_revolution() -> anyhow::Result<()> {
+        let square = fj::Sketch::from_points(vec![
+            [1., 0.],
+            [2., 0.],
+            [2., 1.],
+            [1., 1.],
+        ]);
+        let revolve = |degrees| fj::Revolve {
+            shape: square.clone().into(),
+            axis: [0., 1., 0.],
+            angle: fj::Angle::from_deg(degrees),
+        };
+        let num_faces = |revolve: &fj::Revolve| -> anyhow::Result<_> {
+            let solid = compute_brep(revolve)?.into_inner();
+            Ok(solid.shells().flat_map(Shell::faces).count())
+        };
+
+        // A full turn connects the end to the start, so there are no caps.
+        // Each edge of the square results in a side face for every third of
+        // the turn.
+        assert_eq!(num_faces(&revolve(360.))?, 12);
+
+        // Less than a third of a turn, with caps at the start and end.
+        assert_eq!(num_faces(&revolve(90.))?, 6);
+
+        Ok(())
+    }
+}
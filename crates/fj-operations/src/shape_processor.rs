@@ -1,22 +1,59 @@
 //! API for processing shapes
 
-use fj_interop::{debug::DebugInfo, processed_shape::ProcessedShape};
+use fj_interop::{
+    debug::DebugInfo,
+    mesh::Mesh,
+    processed_shape::{ProcessedPart, ProcessedShape},
+};
 use fj_kernel::{
     algorithms::{
         approx::{InvalidTolerance, Tolerance},
-        triangulate::Triangulate,
+        measure::mass_properties,
+        triangulate::{Triangulate, Triangulator},
         validate::{ValidationConfig, ValidationError},
     },
     objects::Objects,
 };
-use fj_math::Scalar;
+use fj_math::{Aabb, Bvh, Scalar};
 
-use crate::{planes::Planes, Shape as _};
+use crate::{
+    approx_cache::ApproxCache, assembly::context, attributed::attributes,
+    planes::Planes, Shape as _,
+};
 
 /// Processes an [`fj::Shape`] into a [`ProcessedShape`]
 pub struct ShapeProcessor {
     /// The tolerance value used for creating the triangle mesh
     pub tolerance: Option<Tolerance>,
+
+    /// The maximum angle between neighboring segments of the approximation
+    ///
+    /// The angle is in radians. If set, the density of the approximation
+    /// adapts to the curvature of the shape, in addition to being limited by
+    /// the tolerance value. See [`Tolerance::with_max_angle`].
+    pub max_angle: Option<Scalar>,
+
+    /// The algorithm used to triangulate the faces of the shape
+    pub triangulator: Triangulator,
+
+    /// The maximum angle between faces, at which the edge between them is
+    /// smoothed
+    ///
+    /// The angle is in radians. Within each face, the normals of the mesh
+    /// follow its surface, so curved faces look smooth. Between faces, edges
+    /// are kept hard, unless this is set. Then the normals are averaged across
+    /// edges where the faces meet at an angle no larger than this, while edges
+    /// where they meet at sharper angles stay hard.
+    pub smoothing_angle: Option<Scalar>,
+
+    /// The cache used to store the approximations of faces between runs
+    ///
+    /// If set, faces that haven't changed since they were last processed are
+    /// loaded from the cache, instead of being approximated again.
+    pub approx_cache: Option<ApproxCache>,
+
+    /// The configuration used to validate the shape
+    pub validation_config: ValidationConfig,
 }
 
 impl ShapeProcessor {
@@ -41,19 +78,91 @@ impl ShapeProcessor {
             }
             Some(user_defined_tolerance) => user_defined_tolerance,
         };
+        let tolerance = match self.max_angle {
+            Some(max_angle) => tolerance
+                .with_max_angle(max_angle)
+                .map_err(Error::MaxAngle)?,
+            None => tolerance,
+        };
 
-        let config = ValidationConfig::default();
-        let objects = Objects::new();
+        let config = &self.validation_config;
+        // Where snapping is enabled, coincident objects are merged as soon as
+        // they are created.
+        let objects = match config.snap_distance {
+            Some(snap_distance) => Objects::with_merge_distance(snap_distance),
+            None => Objects::new(),
+        };
         let planes = Planes::new(&objects);
         let mut debug_info = DebugInfo::new();
-        let shape =
-            shape.compute_brep(&config, &objects, &planes, &mut debug_info)?;
-        let mesh = (&shape.into_inner(), tolerance).triangulate();
+        let mut warnings = Vec::new();
+
+        let mut triangulate = |shape: &fj::Shape| {
+            let shape = shape.compute_brep(
+                config,
+                &objects,
+                &planes,
+                &mut debug_info,
+            )?;
+            warnings.extend(shape.warnings().iter().map(ToString::to_string));
+
+            let mut mesh = match &self.approx_cache {
+                Some(cache) => cache.triangulate(
+                    &shape.into_inner(),
+                    tolerance,
+                    self.triangulator,
+                ),
+                None => (&shape.into_inner(), tolerance)
+                    .triangulate_with(self.triangulator),
+            };
+            if let Some(smoothing_angle) = self.smoothing_angle {
+                mesh.smooth_normals(smoothing_angle);
+            }
+
+            Ok::<_, ValidationError>(mesh)
+        };
+
+        // The parts of an assembly are processed separately, so they can be
+        // told apart afterwards.
+        let (mesh, parts) = match shape {
+            fj::Shape::Assembly(assembly) => {
+                let mut mesh = Mesh::new();
+                let mut parts = Vec::new();
+
+                for part in assembly.parts() {
+                    let part_mesh = triangulate(part.shape())
+                        .map_err(|err| err.with_context(context(part)))?;
+                    mesh.extend(part_mesh.triangles());
+
+                    parts.push(ProcessedPart {
+                        name: part.name().to_string(),
+                        mass_properties: mass_properties(&part_mesh),
+                        attributes: attributes(part.shape()),
+                        mesh: part_mesh,
+                    });
+                }
+
+                (mesh, parts)
+            }
+            shape => (triangulate(shape)?, Vec::new()),
+        };
+
+        let mass_properties = mass_properties(&mesh);
+        let triangles = Bvh::new(mesh.triangles().map(|triangle| {
+            (
+                Aabb::<3>::from_points(triangle.inner.points()),
+                triangle.inner,
+            )
+        }));
 
         Ok(ProcessedShape {
             aabb,
             mesh,
+            triangles,
+            mass_properties,
+            attributes: attributes(shape),
+            parts,
             debug_info,
+            warnings,
         })
     }
 }
@@ -69,4 +178,8 @@ pub enum Error {
     /// Model has zero size
     #[error("Model has zero size")]
     Extent(#[from] InvalidTolerance),
+
+    /// Maximum angle is not valid
+    #[error("Invalid maximum angle")]
+    MaxAngle(#[source] InvalidTolerance),
 }
@@ -0,0 +1,88 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        hollow::Hollow,
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    geometry::{SurfaceGeometry, SweptCurve},
+    objects::{Face, Faces, Handedness, Objects, Shell, Solid},
+    path::GlobalPath,
+};
+use fj_math::{Aabb, Scalar, Vector};
+
+use crate::planes::Planes;
+
+use super::Shape;
+
+impl Shape for fj::Shell {
+    type Brep = Faces;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let faces = self
+            .shape()
+            .compute_brep(config, objects, planes, debug_info)?
+            .into_inner();
+
+        if !(&faces).into_iter().all(Face::is_polygon) {
+            return Err(ValidationError::Unsupported(
+                "Hollowing only supports shapes with planar faces and \
+                straight edges"
+                    .into(),
+            ));
+        }
+
+        let openings = faces
+            .clone()
+            .into_iter()
+            .filter(|face| {
+                self.openings().iter().any(|&direction| {
+                    let direction = Vector::from(direction).normalize();
+                    normal(face).map_or(false, |normal| {
+                        normal.dot(&direction)
+                            > Scalar::ONE - Scalar::from_f64(1e-9)
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let solid = Solid::new().with_shells([Shell::new().with_faces(faces)]);
+        let mut faces = Faces::new();
+        faces.extend(
+            solid
+                .hollow(self.thickness(), &openings, objects)
+                .into_shells()
+                .flat_map(Shell::into_faces),
+        );
+
+        faces.validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        self.shape().bounding_volume()
+    }
+}
+
+/// Compute the unit normal of a face's front side
+///
+/// Returns `None`, if the face is not planar.
+fn normal(face: &Face) -> Option<Vector<3>> {
+    let normal = match face.surface().geometry() {
+        SurfaceGeometry::SweptCurve(SweptCurve {
+            u: GlobalPath::Line(line),
+            v,
+        }) => line.direction().cross(v).normalize(),
+        _ => return None,
+    };
+
+    match face.coord_handedness() {
+        Handedness::RightHanded => Some(normal),
+        Handedness::LeftHanded => Some(-normal),
+    }
+}
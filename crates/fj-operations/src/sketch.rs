@@ -1,12 +1,15 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
 use fj_interop::{debug::DebugInfo, mesh::Color};
 use fj_kernel::{
-    algorithms::validate::{
-        Validate, Validated, ValidationConfig, ValidationError,
+    algorithms::{
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
     },
     objects::{Cycle, Face, HalfEdge, Objects, Sketch},
-    partial::HasPartial,
+    partial::{CycleSegment, HasPartial},
 };
-use fj_math::{Aabb, Point};
+use fj_math::{Aabb, Point, Scalar, Vector};
 
 use crate::planes::Planes;
 
@@ -24,32 +27,100 @@ impl Shape for fj::Sketch {
     ) -> Result<Validated<Self::Brep>, ValidationError> {
         let surface = planes.xy();
 
-        let face = match self.chain() {
+        // Along with the face, the names of its half-edges are computed, in
+        // the order of the half-edges. They are used to point to the part of
+        // the sketch that caused an error.
+        let (face, names) = match self.chain() {
             fj::Chain::Circle(circle) => {
                 // Circles have just a single round edge with no vertices. So
                 // none need to be added here.
 
                 let half_edge = HalfEdge::partial()
                     .with_surface(Some(surface.clone()))
-                    .as_circle_from_radius(circle.radius())
-                    .build(objects);
+                    .as_circle_from_radius(circle.radius())?
+                    .build(objects)?;
                 let cycle = Cycle::new(surface, [half_edge]);
 
-                Face::from_exterior(cycle).with_color(Color(self.color()))
+                (Face::from_exterior(cycle), edge_names(1))
             }
             fj::Chain::PolyChain(poly_chain) => {
-                let points =
-                    poly_chain.to_points().into_iter().map(Point::from);
+                let points = poly_chain
+                    .to_points()
+                    .into_iter()
+                    .map(Point::from)
+                    .collect::<Vec<_>>();
+                let corners = self.corners();
 
-                Face::builder(objects, surface)
-                    .with_exterior_polygon_from_points(points)
-                    .build()
-                    .with_color(Color(self.color()))
+                if corners.iter().all(|corner| *corner == fj::Corner::Sharp) {
+                    let names = edge_names(points.len());
+                    let face = Face::builder(objects, surface)
+                        .with_exterior_polygon_from_points(points)
+                        .build()?;
+
+                    (face, names)
+                } else {
+                    let (segments, names) =
+                        segments_with_corners(&points, corners);
+                    let face = Face::builder(objects, surface)
+                        .with_exterior_from_segments(segments)
+                        .build()?;
+
+                    (face, names)
+                }
+            }
+            fj::Chain::Segments(segment_chain) => {
+                let segments =
+                    segment_chain.segments().iter().map(
+                        |segment| match *segment {
+                            fj::Segment::LineTo { end } => {
+                                CycleSegment::LineTo(Point::from(end))
+                            }
+                            fj::Segment::ArcTo { end, radius } => {
+                                CycleSegment::ArcTo {
+                                    end: Point::from(end),
+                                    radius: radius.into(),
+                                }
+                            }
+                            fj::Segment::BezierTo {
+                                control_points,
+                                end,
+                            } => CycleSegment::BezierTo {
+                                control_points: control_points.map(Point::from),
+                                end: Point::from(end),
+                            },
+                        },
+                    );
+
+                let face = Face::builder(objects, surface)
+                    .with_exterior_from_segments(segments)
+                    .build()?;
+
+                (face, edge_names(segment_chain.segments().len()))
             }
         };
+        let face = face.with_color(Color(self.color()));
 
+        let half_edges =
+            face.exterior().half_edges().cloned().collect::<Vec<_>>();
         let sketch = Sketch::new().with_faces([face]);
-        sketch.validate_with_config(config)
+
+        sketch.validate_or_snap(config, objects).map_err(|err| {
+            let sketch = match self.label() {
+                Some(label) => format!("sketch '{label}'"),
+                None => "sketch".to_owned(),
+            };
+
+            let name = err.half_edges().into_iter().find_map(|half_edge| {
+                let i =
+                    half_edges.iter().position(|other| other == half_edge)?;
+                names.get(i)
+            });
+
+            match name {
+                Some(name) => err.with_context(format!("{name} of {sketch}")),
+                None => err.with_context(sketch),
+            }
+        })
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
@@ -65,6 +136,198 @@ impl Shape for fj::Sketch {
                     .map(Point::from)
                     .map(Point::to_xyz),
             ),
+            fj::Chain::Segments(segment_chain) => Aabb::<3>::from_points(
+                segment_chain_extreme_points(segment_chain.segments())
+                    .into_iter()
+                    .map(Point::to_xyz),
+            ),
+        }
+    }
+}
+
+/// Name the edges of a sketch, in order
+///
+/// Edge `i` runs from point `i` to the next one, or is segment `i`, depending
+/// on how the sketch is defined.
+fn edge_names(num_edges: usize) -> Vec<String> {
+    (0..num_edges).map(|i| format!("edge {i}")).collect()
+}
+
+/// Convert the points of a sketch into segments, shaping its corners
+///
+/// Returns the segments, along with the name of the part of the sketch that
+/// each of them belongs to.
+///
+/// # Panics
+///
+/// Panics, if the trimmed parts of an edge overlap, because the corners at its
+/// ends are too large.
+fn segments_with_corners(
+    points: &[Point<2>],
+    corners: &[fj::Corner],
+) -> (Vec<CycleSegment>, Vec<String>) {
+    let n = points.len();
+
+    // For each corner, compute where it starts and ends, and the segment that
+    // connects those.
+    let shaped = (0..n)
+        .map(|i| {
+            let point = points[i];
+            let previous = points[(i + n - 1) % n];
+            let next = points[(i + 1) % n];
+
+            let to_previous = (previous - point).normalize();
+            let to_next = (next - point).normalize();
+
+            // Positive, if the sketch turns left at the corner.
+            let turn = (point - previous).cross2d(&(next - point));
+
+            let corner = corners.get(i).copied().unwrap_or(fj::Corner::Sharp);
+            let (trim, segment) = match corner {
+                // A corner between collinear edges stays as it is, as there
+                // is nothing to round or cut off.
+                _ if turn == Scalar::ZERO => (Scalar::ZERO, None),
+                fj::Corner::Sharp => (Scalar::ZERO, None),
+                fj::Corner::Round { radius } => {
+                    let radius = Scalar::from(radius);
+
+                    // The arc touches both edges at the distance where the
+                    // center, which lies on the bisector of the corner, is
+                    // `radius` away from them.
+                    let cos = to_previous.dot(&to_next);
+                    let tan_half_angle =
+                        ((Scalar::ONE - cos) / (Scalar::ONE + cos)).sqrt();
+                    let trim = radius / tan_half_angle;
+
+                    let end = point + to_next * trim;
+                    let segment = if turn > Scalar::ZERO {
+                        CycleSegment::ArcTo { end, radius }
+                    } else {
+                        CycleSegment::ClockwiseArcTo { end, radius }
+                    };
+
+                    (trim, Some(segment))
+                }
+                fj::Corner::Chamfer { distance } => {
+                    let trim = Scalar::from(distance);
+                    let end = point + to_next * trim;
+
+                    (trim, Some(CycleSegment::LineTo(end)))
+                }
+            };
+
+            let start = point + to_previous * trim;
+            (trim, start, segment)
+        })
+        .collect::<Vec<_>>();
+
+    let mut segments = Vec::new();
+    let mut names = Vec::new();
+
+    for (i, &(trim, start, segment)) in shaped.iter().enumerate() {
+        // The edge that leads to this corner, from the previous one.
+        let edge = (i + n - 1) % n;
+        let (previous_trim, ..) = shaped[edge];
+
+        let length = (points[i] - points[edge]).magnitude();
+        let remaining = length - previous_trim - trim;
+        assert!(
+            remaining >= -Scalar::from_f64(1e-9),
+            "Corners {edge} and {i} of sketch don't fit on the edge between \
+            them"
+        );
+
+        // If the corners take up the whole edge, nothing is left of it.
+        if remaining > Scalar::from_f64(1e-9) {
+            segments.push(CycleSegment::LineTo(start));
+            names.push(format!("edge {edge}"));
+        }
+
+        if let Some(segment) = segment {
+            segments.push(segment);
+            names.push(format!("corner {i}"));
+        }
+    }
+
+    (segments, names)
+}
+
+/// Compute the points that bound a closed loop of segments
+///
+/// Those are the ends of all segments, plus the points where an arc reaches
+/// its extent along the x- or y-axis. A Bézier curve lies within the convex
+/// hull of its control points, so those are included as well.
+fn segment_chain_extreme_points(segments: &[fj::Segment]) -> Vec<Point<2>> {
+    let mut points = Vec::new();
+
+    for (i, segment) in segments.iter().enumerate() {
+        let start = segments[(i + segments.len() - 1) % segments.len()].end();
+        let start = Point::from(start);
+        let end = Point::from(segment.end());
+
+        points.push(end);
+
+        if let fj::Segment::BezierTo { control_points, .. } = *segment {
+            points.extend(control_points.map(Point::from));
         }
+
+        if let fj::Segment::ArcTo { radius, .. } = *segment {
+            // This mirrors how the kernel places the center of the arc. See
+            // `PartialHalfEdge::as_arc_from_points_and_radius`.
+            let chord = end - start;
+            let half_chord = chord.magnitude().into_f64() / 2.;
+            let direction = chord.normalize();
+            let left = Vector::from([-direction.v, direction.u]);
+            let distance =
+                (radius * radius - half_chord * half_chord).max(0.).sqrt();
+            let center = start + chord / 2. + left * distance * radius.signum();
+
+            let angle = |point: Point<2>| {
+                let v = point - center;
+                v.v.into_f64().atan2(v.u.into_f64())
+            };
+            let start_angle = angle(start);
+            let span = (angle(end) - start_angle).rem_euclid(TAU);
+
+            for k in 0..4 {
+                let theta = k as f64 * FRAC_PI_2;
+                if (theta - start_angle).rem_euclid(TAU) <= span {
+                    let offset = Vector::from([theta.cos(), theta.sin()]);
+                    points.push(center + offset * radius.abs());
+                }
+            }
+        }
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{test_helpers::compute_brep, Shape};
+
+    #[test]
+    fn sketch_with_bezier_segment() -> anyhow::Result<()> {
+        let sketch = fj::Sketch::from_segments(vec![
+            fj::Segment::LineTo { end: [2., 0.] },
+            fj::Segment::BezierTo {
+                control_points: [[2., 1.], [0., 1.]],
+                end: [0., 0.],
+            },
+        ]);
+
+        let brep = compute_brep(&sketch)?.into_inner();
+        let face = brep.faces().into_iter().next().expect("Sketch has a face");
+        assert_eq!(face.exterior().half_edges().count(), 2);
+
+        // The curve doesn't reach its control points, but the bounding volume
+        // includes them.
+        let aabb = sketch.bounding_volume();
+        assert_eq!(aabb.min, Point::from([0., 0., 0.]));
+        assert_eq!(aabb.max, Point::from([2., 1., 0.]));
+
+        Ok(())
     }
 }
@@ -1,12 +1,17 @@
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
     algorithms::{
-        sweep::Sweep,
-        validate::{Validate, Validated, ValidationConfig, ValidationError},
+        approx::Approx,
+        sew::Sew,
+        snap::Snap,
+        sweep::{Sweep, SweepAlongPath, SweepWithDraft},
+        transform::TransformObject,
+        validate::{Validated, ValidationConfig, ValidationError},
     },
-    objects::{Objects, Solid},
+    objects::{Face, Faces, Objects, Shell, Sketch, Solid},
+    path::GlobalPath,
 };
-use fj_math::{Aabb, Vector};
+use fj_math::{Aabb, Circle, Helix, Point, Scalar, Transform, Vector};
 
 use crate::planes::Planes;
 
@@ -24,21 +29,363 @@ impl Shape for fj::Sweep {
     ) -> Result<Validated<Self::Brep>, ValidationError> {
         let sketch = self
             .shape()
-            .compute_brep(config, objects, planes, debug_info)?;
-        let path = Vector::from(self.path());
+            .compute_brep(config, objects, planes, debug_info)?
+            .into_inner();
 
-        let solid = sketch.into_inner().sweep(path, objects);
-        solid.validate_with_config(config)
+        let draft = self.draft().rad();
+        if draft != 0. && !matches!(self.path(), fj::SweepPath::Line(_)) {
+            return Err(ValidationError::Unsupported(
+                "Draft angles are only supported for straight sweeps".into(),
+            ));
+        }
+        if draft != 0. && !sketch.faces().into_iter().all(Face::is_polygon) {
+            return Err(ValidationError::Unsupported(
+                "Draft angles are only supported for sketches with straight \
+                edges"
+                    .into(),
+            ));
+        }
+
+        let solid = match *self.path() {
+            fj::SweepPath::Line(path) if draft != 0. => {
+                sketch.sweep_with_draft(Vector::from(path), draft, objects)
+            }
+            fj::SweepPath::Line(path) => {
+                sweep_segment(sketch, fj::SweepSegment::Line(path), objects)
+            }
+            fj::SweepPath::Arc {
+                center,
+                axis,
+                angle,
+            } => sweep_segment(
+                sketch,
+                fj::SweepSegment::Arc {
+                    center,
+                    axis,
+                    angle,
+                },
+                objects,
+            ),
+            fj::SweepPath::Helix {
+                center,
+                axis,
+                pitch,
+                turns,
+            } => {
+                let helix = Helix::from_axis_radius_and_pitch(
+                    center, axis, 1., pitch, turns,
+                );
+                sketch.sweep_along_path(
+                    &GlobalPath::Helix(helix),
+                    helix.range(),
+                    objects,
+                )
+            }
+            fj::SweepPath::Segments(ref segments) => {
+                sweep_segments(sketch, segments.segments(), config, objects)?
+            }
+        };
+        solid.validate_or_snap(config, objects)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
-        self.shape()
-            .bounding_volume()
-            .merged(&Aabb::<3>::from_points(
-                self.shape()
-                    .bounding_volume()
-                    .vertices()
-                    .map(|v| v + self.path()),
-            ))
+        let shape = self.shape().bounding_volume();
+
+        match *self.path() {
+            fj::SweepPath::Line(path) => {
+                let top =
+                    Aabb::<3>::from_points(shape.vertices().map(|v| v + path));
+
+                // A negative draft angle widens the shape towards the top.
+                let margin = Vector::from(path).magnitude()
+                    * self.draft().rad().tan().abs();
+                let margin = Vector::from([margin, margin, margin]);
+                let top = Aabb {
+                    min: top.min - margin,
+                    max: top.max + margin,
+                };
+
+                shape.merged(&top)
+            }
+            fj::SweepPath::Arc { center, .. } => {
+                around_axis(&shape, center.into())
+            }
+            fj::SweepPath::Helix {
+                center,
+                axis,
+                pitch,
+                turns,
+            } => {
+                let advance = Vector::from(axis).normalize() * pitch * turns;
+                let start = around_axis(&shape, center.into());
+
+                start.merged(&Aabb::<3>::from_points(
+                    start.vertices().map(|v| v + advance),
+                ))
+            }
+            fj::SweepPath::Segments(ref segments) => {
+                let mut transform = Transform::identity();
+                let mut aabb = shape;
+
+                for &segment in segments.segments() {
+                    let swept = match segment {
+                        fj::SweepSegment::Line(path) => {
+                            shape.merged(&Aabb::<3>::from_points(
+                                shape.vertices().map(|v| v + path),
+                            ))
+                        }
+                        fj::SweepSegment::Arc { center, .. } => {
+                            around_axis(&shape, center.into())
+                        }
+                    };
+                    aabb = aabb.merged(&Aabb::<3>::from_points(
+                        swept.vertices().map(|v| transform.transform_point(&v)),
+                    ));
+
+                    transform = transform * segment_transform(segment);
+                }
+
+                aabb
+            }
+        }
+    }
+}
+
+/// Sweep a sketch along a single line or arc
+fn sweep_segment(
+    sketch: Sketch,
+    segment: fj::SweepSegment,
+    objects: &Objects,
+) -> Solid {
+    match segment {
+        fj::SweepSegment::Line(path) => {
+            sketch.sweep(Vector::from(path), objects)
+        }
+        fj::SweepSegment::Arc {
+            center,
+            axis,
+            angle,
+        } => {
+            let circle = circle_around_axis(center, axis);
+            sketch.sweep_along_path(
+                &GlobalPath::Circle(circle),
+                [[0.], [angle.rad()]],
+                objects,
+            )
+        }
+    }
+}
+
+/// Sweep a sketch along consecutive lines and arcs
+///
+/// Each segment is swept in the position and orientation that the shape has at
+/// the start of the segment, then moved to where the previous segments have
+/// left the shape.
+///
+/// Where two segments meet, the end cap of the one and the start cap of the
+/// other coincide. Both are left out, and the remaining faces are sewn into a
+/// single shell.
+fn sweep_segments(
+    sketch: Sketch,
+    segments: &[fj::SweepSegment],
+    config: &ValidationConfig,
+    objects: &Objects,
+) -> Result<Solid, ValidationError> {
+    // The sketch is defined in the xy-plane. This is where it is moved to at
+    // each joint between two segments.
+    let joint = |transform: &Transform| {
+        (
+            transform.transform_point(&Point::origin()),
+            transform.transform_vector(&Vector::unit_z()),
+        )
+    };
+    let is_cap_at = |face: &Face, (origin, normal): (Point<3>, Vector<3>)| {
+        face.all_cycles().all(|cycle| {
+            cycle
+                .approx(config.intersection_tolerance)
+                .points()
+                .into_iter()
+                .all(|point| {
+                    (point.global_form - origin).dot(&normal).abs()
+                        < config.distinct_min_distance
+                })
+        })
+    };
+
+    let mut transform = Transform::identity();
+    let mut faces = Faces::new();
+
+    for (i, &segment) in segments.iter().enumerate() {
+        let solid = sweep_segment(sketch.clone(), segment, objects)
+            .transform(&transform, objects)?;
+
+        let start = (i > 0).then(|| joint(&transform));
+        transform = transform * segment_transform(segment);
+        let end = (i + 1 < segments.len()).then(|| joint(&transform));
+
+        faces.extend(solid.into_shells().flat_map(Shell::into_faces).filter(
+            |face| {
+                ![start, end]
+                    .into_iter()
+                    .flatten()
+                    .any(|joint| is_cap_at(face, joint))
+            },
+        ));
+    }
+
+    let sewn = faces.sew(config.distinct_min_distance, objects);
+    Ok(Solid::new().with_shells(sewn.shells))
+}
+
+/// Compute how sweeping along the segment moves the shape
+fn segment_transform(segment: fj::SweepSegment) -> Transform {
+    match segment {
+        fj::SweepSegment::Line(path) => Transform::translation(path),
+        fj::SweepSegment::Arc {
+            center,
+            axis,
+            angle,
+        } => {
+            let center = Vector::from(center);
+            let axis = Vector::from(axis).normalize();
+
+            Transform::translation(center)
+                * Transform::rotation(axis * angle.rad())
+                * Transform::translation(-center)
+        }
+    }
+}
+
+/// Compute a bounding volume for anything that revolves around an axis
+///
+/// Anything that revolves around the axis stays within the distance of the
+/// shape's farthest point from the center.
+pub fn around_axis(shape: &Aabb<3>, center: Point<3>) -> Aabb<3> {
+    let radius = shape
+        .vertices()
+        .into_iter()
+        .map(|vertex| (vertex - center).magnitude())
+        .fold(Scalar::ZERO, Scalar::max);
+    let radius = Vector::from([radius, radius, radius]);
+
+    shape.merged(&Aabb::<3>::from_points([center - radius, center + radius]))
+}
+
+/// Create a unit circle around the given axis
+///
+/// The circle's coordinates follow the right-hand rule around the axis.
+fn circle_around_axis(center: [f64; 3], axis: [f64; 3]) -> Circle<3> {
+    let normal = Vector::from(axis).normalize();
+
+    // Any vector that isn't parallel to the axis can be used to construct
+    // the circle's coordinate system.
+    let other = if normal.x.abs() < Scalar::from_f64(0.5) {
+        Vector::unit_x()
+    } else {
+        Vector::unit_y()
+    };
+
+    let a = normal.cross(&other).normalize();
+    let b = normal.cross(&a);
+
+    Circle::new(center, a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_kernel::{algorithms::validate::ValidationError, iter::ObjectIters};
+    use fj_math::{Aabb, Point};
+
+    use crate::{
+        test_helpers::{circle, compute_brep, square},
+        Shape,
+    };
+
+    #[test]
+    fn drafted_sweep_of_square() -> anyhow::Result<()> {
+        let sweep = fj::Sweep::from_path(square(1.).into(), [0., 0., 1.])
+            .with_draft(fj::Angle::from_deg(5.));
+
+        let solid = compute_brep(&sweep)?.into_inner();
+        assert_eq!(solid.shells().count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn drafted_sweep_of_circle() {
+        let sweep = fj::Sweep::from_path(circle(1.).into(), [0., 0., 1.])
+            .with_draft(fj::Angle::from_deg(5.));
+
+        assert!(matches!(
+            compute_brep(&sweep),
+            Err(ValidationError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn sweep_along_segments() -> anyhow::Result<()> {
+        // Up along the z-axis, a quarter turn towards the x-axis, then along
+        // the x-axis, as that's where the quarter turn left the shape facing.
+        let sweep = fj::Sweep::from_path(
+            square(1.).into(),
+            vec![
+                fj::SweepSegment::Line([0., 0., 1.]),
+                fj::SweepSegment::Arc {
+                    center: [2., 0., 0.],
+                    axis: [0., 1., 0.],
+                    angle: fj::Angle::from_deg(90.),
+                },
+                fj::SweepSegment::Line([0., 0., 1.]),
+            ],
+        );
+
+        // The segments are joined into a single shell, without any faces
+        // between them.
+        let solid = compute_brep(&sweep)?.into_inner();
+        assert_eq!(solid.shells().count(), 1);
+        assert_eq!(
+            solid.shell_iter().flat_map(|shell| shell.faces()).count(),
+            14
+        );
+
+        let aabb = Aabb::<3>::from_points(
+            solid.global_vertex_iter().map(|vertex| vertex.position()),
+        );
+        for (actual, expected) in [
+            (aabb.min, Point::from([0., 0., 0.])),
+            (aabb.max, Point::from([3., 1., 3.])),
+        ] {
+            assert!((actual - expected).magnitude() < 1e-9.into());
+        }
+
+        // The bounding volume is allowed to be larger than the shape, but must
+        // contain it.
+        let bounding_volume = sweep.bounding_volume();
+        assert_eq!(bounding_volume.merged(&aabb), bounding_volume);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_circle_along_segments() -> anyhow::Result<()> {
+        let sweep = fj::Sweep::from_path(
+            circle(1.).into(),
+            vec![
+                fj::SweepSegment::Line([0., 0., 1.]),
+                fj::SweepSegment::Line([0., 0., 2.]),
+            ],
+        );
+
+        // The caps where the segments meet have no vertices, but are left out
+        // all the same.
+        let solid = compute_brep(&sweep)?.into_inner();
+        assert_eq!(solid.shells().count(), 1);
+        assert_eq!(
+            solid.shell_iter().flat_map(|shell| shell.faces()).count(),
+            4
+        );
+
+        Ok(())
     }
 }
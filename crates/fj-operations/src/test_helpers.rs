@@ -0,0 +1,61 @@
+//! Shapes and checks that are shared between the tests of the operations
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::validate::{Validated, ValidationConfig, ValidationError},
+    objects::Objects,
+};
+
+use crate::{planes::Planes, Shape};
+
+/// Compute the boundary representation of a shape with the default config
+pub fn compute_brep<S: Shape>(
+    shape: &S,
+) -> Result<Validated<S::Brep>, ValidationError> {
+    let objects = Objects::new();
+    let planes = Planes::new(&objects);
+
+    shape.compute_brep(
+        &ValidationConfig::default(),
+        &objects,
+        &planes,
+        &mut DebugInfo::new(),
+    )
+}
+
+/// Create a square with one corner at the origin
+pub fn square(size: f64) -> fj::Sketch {
+    fj::Sketch::from_points(vec![
+        [0., 0.],
+        [size, 0.],
+        [size, size],
+        [0., size],
+    ])
+}
+
+/// Create a cube with one corner at the origin
+pub fn cube(size: f64) -> fj::Shape {
+    fj::Sweep::from_path(square(size).into(), [0., 0., size]).into()
+}
+
+/// Create a circle around the origin
+pub fn circle(radius: f64) -> fj::Sketch {
+    fj::Sketch::from_circle(fj::Circle::from_radius(radius))
+}
+
+/// Create a cylinder with its base around the origin
+pub fn cylinder(radius: f64, height: f64) -> fj::Shape {
+    fj::Sweep::from_path(circle(radius).into(), [0., 0., height]).into()
+}
+
+/// Move a shape by the given offset
+pub fn translate(shape: fj::Shape, offset: [f64; 3]) -> fj::Shape {
+    fj::Transform {
+        shape,
+        axis: [0., 0., 1.],
+        angle: fj::Angle::from_rad(0.),
+        offset,
+        scale: [1.; 3],
+    }
+    .into()
+}
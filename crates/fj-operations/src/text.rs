@@ -0,0 +1,454 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    objects::{Face, Objects, Sketch},
+    partial::CycleSegment,
+};
+use fj_math::{Aabb, Point, Scalar};
+
+use crate::planes::Planes;
+
+use super::Shape;
+
+impl Shape for fj::Text {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        _: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let surface = planes.xy();
+
+        let mut faces = Vec::new();
+        for glyph in outlines(self)? {
+            for (exterior, interiors) in glyph.regions() {
+                let mut face = Face::builder(objects, surface.clone())
+                    .with_exterior_from_segments(exterior.segments());
+                for interior in interiors {
+                    face =
+                        face.with_interior_from_segments(interior.segments());
+                }
+
+                faces.push(face.build()?.with_color(Color(self.color())));
+            }
+        }
+
+        let sketch = Sketch::new().with_faces(faces);
+        sketch.validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // A font that can't be loaded is reported by `compute_brep`. Until
+        // then, the text is treated as empty.
+        let points = outlines(self)
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|glyph| glyph.contours)
+            .flat_map(|contour| contour.points())
+            .map(Point::to_xyz)
+            .collect::<Vec<_>>();
+
+        if points.is_empty() {
+            return Aabb {
+                min: Point::origin(),
+                max: Point::origin(),
+            };
+        }
+
+        Aabb::<3>::from_points(points)
+    }
+}
+
+/// Load the font and build the outlines of all glyphs of the text
+///
+/// Returns an error, if the font can't be read or parsed.
+fn outlines(text: &fj::Text) -> Result<Vec<Glyph>, ValidationError> {
+    let data = text.font().data().map_err(|err| {
+        ValidationError::InvalidParameters(format!(
+            "Failed to read font: {err}"
+        ))
+    })?;
+    let face = ttf_parser::Face::from_slice(&data, 0).map_err(|err| {
+        ValidationError::InvalidParameters(format!(
+            "Failed to parse font: {err}"
+        ))
+    })?;
+
+    let scale = text.size() / f64::from(face.units_per_em());
+    let line_height = f64::from(face.ascender()) - f64::from(face.descender())
+        + f64::from(face.line_gap());
+
+    let mut glyphs = Vec::new();
+    let mut cursor = [0., 0.];
+
+    for c in text.text().chars() {
+        if c == '\n' {
+            cursor = [0., cursor[1] - line_height];
+            continue;
+        }
+
+        // Characters the font doesn't cover are rendered as its "missing
+        // glyph", which is always the first one.
+        let id = face.glyph_index(c).unwrap_or(ttf_parser::GlyphId(0));
+
+        let mut collector = ContourCollector {
+            offset: cursor,
+            scale,
+            contours: Vec::new(),
+            current: None,
+        };
+        face.outline_glyph(id, &mut collector);
+        glyphs.push(Glyph {
+            contours: collector.contours,
+        });
+
+        cursor[0] += f64::from(face.glyph_hor_advance(id).unwrap_or(0));
+    }
+
+    Ok(glyphs)
+}
+
+/// The outline of a single glyph
+struct Glyph {
+    contours: Vec<Contour>,
+}
+
+impl Glyph {
+    /// Sort the contours into regions, each of which becomes a face
+    ///
+    /// Fonts don't agree on the winding of outer contours and holes. TrueType
+    /// fonts wind outer contours clockwise, while others do the opposite. So a
+    /// contour is considered to be a hole, if it is contained in an odd number
+    /// of other contours, regardless of its winding.
+    ///
+    /// Returns the exterior of each region, winding counter-clockwise, along
+    /// with its interiors, winding clockwise.
+    fn regions(self) -> Vec<(Contour, Vec<Contour>)> {
+        let contours = self
+            .contours
+            .into_iter()
+            .filter(|contour| contour.area() != Scalar::ZERO)
+            .collect::<Vec<_>>();
+
+        let mut regions = Vec::new();
+        let mut holes = Vec::new();
+
+        for i in 0..contours.len() {
+            if containers(&contours, i).len() % 2 == 0 {
+                regions.push(i);
+            } else {
+                holes.push(i);
+            }
+        }
+
+        let mut interiors = vec![Vec::new(); contours.len()];
+        for hole in holes {
+            // A hole belongs to the smallest region that contains it.
+            let region = containers(&contours, hole)
+                .into_iter()
+                .filter(|j| regions.contains(j))
+                .min_by_key(|&j| contours[j].area().abs());
+
+            if let Some(region) = region {
+                interiors[region].push(contours[hole].clone().clockwise());
+            }
+        }
+
+        regions
+            .into_iter()
+            .map(|i| {
+                let exterior = contours[i].clone().counter_clockwise();
+                (exterior, interiors[i].clone())
+            })
+            .collect()
+    }
+}
+
+/// Find the indices of all contours that contain contour `i`
+fn containers(contours: &[Contour], i: usize) -> Vec<usize> {
+    let point = contours[i].start();
+
+    contours
+        .iter()
+        .enumerate()
+        .filter(|(j, other)| *j != i && other.contains(point))
+        .map(|(j, _)| j)
+        .collect()
+}
+
+/// A closed contour of a glyph
+///
+/// Each segment runs from the end of the previous one to its own end. The
+/// first segment starts at the end of the last one.
+#[derive(Clone)]
+struct Contour {
+    segments: Vec<ContourSegment>,
+}
+
+impl Contour {
+    fn start(&self) -> Point<2> {
+        self.segments
+            .last()
+            .expect("Contour has at least one segment")
+            .end
+    }
+
+    fn segments(&self) -> Vec<CycleSegment> {
+        self.segments
+            .iter()
+            .map(|segment| match segment.control_points {
+                Some(control_points) => CycleSegment::BezierTo {
+                    control_points,
+                    end: segment.end,
+                },
+                None => CycleSegment::LineTo(segment.end),
+            })
+            .collect()
+    }
+
+    /// Access the ends and control points of all segments
+    ///
+    /// The contour lies within the polygon formed by these points, as each
+    /// Bézier curve lies within the convex hull of its control points.
+    fn points(&self) -> Vec<Point<2>> {
+        self.segments
+            .iter()
+            .flat_map(|segment| {
+                segment
+                    .control_points
+                    .into_iter()
+                    .flatten()
+                    .chain([segment.end])
+            })
+            .collect()
+    }
+
+    /// Compute the signed area of the polygon formed by the contour's points
+    ///
+    /// This approximates the area of the contour itself, which is good enough
+    /// to determine its winding.
+    fn area(&self) -> Scalar {
+        let points = self.points();
+
+        let twice_area =
+            points.iter().enumerate().fold(Scalar::ZERO, |sum, (i, a)| {
+                let b = points[(i + 1) % points.len()];
+                sum + a.u * b.v - b.u * a.v
+            });
+
+        twice_area / 2.
+    }
+
+    /// Check whether the point is within the polygon formed by the contour's
+    /// points
+    fn contains(&self, point: Point<2>) -> bool {
+        let points = self.points();
+
+        let mut inside = false;
+        for (i, &a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
+
+            if (a.v > point.v) != (b.v > point.v) {
+                let u = a.u + (point.v - a.v) / (b.v - a.v) * (b.u - a.u);
+                if point.u < u {
+                    inside = !inside;
+                }
+            }
+        }
+
+        inside
+    }
+
+    fn counter_clockwise(self) -> Self {
+        if self.area() < Scalar::ZERO {
+            self.reverse()
+        } else {
+            self
+        }
+    }
+
+    fn clockwise(self) -> Self {
+        if self.area() > Scalar::ZERO {
+            self.reverse()
+        } else {
+            self
+        }
+    }
+
+    fn reverse(self) -> Self {
+        let n = self.segments.len();
+
+        // Segment `i` runs from the end of segment `i - 1` to its own end.
+        // Reversed, it runs back to the end of segment `i - 1`.
+        let segments = (0..n)
+            .rev()
+            .map(|i| {
+                let segment = &self.segments[i];
+                let previous = &self.segments[(i + n - 1) % n];
+
+                ContourSegment {
+                    control_points: segment.control_points.map(|[a, b]| [b, a]),
+                    end: previous.end,
+                }
+            })
+            .collect();
+
+        Self { segments }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ContourSegment {
+    /// The control points of a cubic Bézier curve, or `None` for a line
+    control_points: Option<[Point<2>; 2]>,
+    end: Point<2>,
+}
+
+/// Collects the contours of a glyph from the font
+struct ContourCollector {
+    offset: [f64; 2],
+    scale: f64,
+    contours: Vec<Contour>,
+
+    // The start of the current contour, and its segments so far
+    current: Option<(Point<2>, Vec<ContourSegment>)>,
+}
+
+impl ContourCollector {
+    fn point(&self, x: f32, y: f32) -> Point<2> {
+        let [u, v] = self.offset;
+        Point::from([
+            (u + f64::from(x)) * self.scale,
+            (v + f64::from(y)) * self.scale,
+        ])
+    }
+
+    fn push(&mut self, control_points: Option<[Point<2>; 2]>, end: Point<2>) {
+        if let Some((_, segments)) = &mut self.current {
+            segments.push(ContourSegment {
+                control_points,
+                end,
+            });
+        }
+    }
+
+    fn previous(&self) -> Option<Point<2>> {
+        let (start, segments) = self.current.as_ref()?;
+        Some(segments.last().map_or(*start, |segment| segment.end))
+    }
+}
+
+impl ttf_parser::OutlineBuilder for ContourCollector {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.close();
+        self.current = Some((self.point(x, y), Vec::new()));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let end = self.point(x, y);
+        if self.previous() != Some(end) {
+            self.push(None, end);
+        }
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let start = match self.previous() {
+            Some(start) => start,
+            None => return,
+        };
+        let control = self.point(x1, y1);
+        let end = self.point(x, y);
+
+        // Every quadratic Bézier curve can be represented exactly as a cubic
+        // one.
+        let control_points = [
+            start + (control - start) * (2. / 3.),
+            end + (control - end) * (2. / 3.),
+        ];
+        self.push(Some(control_points), end);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let control_points = [self.point(x1, y1), self.point(x2, y2)];
+        let end = self.point(x, y);
+        self.push(Some(control_points), end);
+    }
+
+    fn close(&mut self) {
+        let (start, mut segments) = match self.current.take() {
+            Some(current) => current,
+            None => return,
+        };
+
+        // The contour implicitly closes with a line back to its start, unless
+        // it already ended there.
+        let end = segments.last().map(|segment| segment.end);
+        if end.is_some() && end != Some(start) {
+            segments.push(ContourSegment {
+                control_points: None,
+                end: start,
+            });
+        }
+
+        // A contour needs at least two segments to enclose anything.
+        if segments.len() >= 2 {
+            self.contours.push(Contour { segments });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use fj_kernel::algorithms::validate::ValidationError;
+
+    use crate::{test_helpers::compute_brep, Shape};
+
+    #[test]
+    fn missing_font() {
+        let text = fj::Text::new(
+            "Fornjot",
+            fj::Font::from_path("does/not/exist.ttf"),
+            1.,
+        );
+
+        assert!(matches!(
+            compute_brep(&text),
+            Err(ValidationError::InvalidParameters(_))
+        ));
+
+        let aabb = text.bounding_volume();
+        assert_eq!(aabb.min, aabb.max);
+    }
+
+    #[test]
+    fn garbage_font() -> anyhow::Result<()> {
+        let path = std::env::temp_dir()
+            .join(format!("fj-garbage-font-{}.ttf", std::process::id()));
+        fs::write(&path, b"not a font")?;
+
+        let text = fj::Text::new(
+            "Fornjot",
+            fj::Font::from_path(path.to_string_lossy()),
+            1.,
+        );
+
+        assert!(matches!(
+            compute_brep(&text),
+            Err(ValidationError::InvalidParameters(_))
+        ));
+
+        let aabb = text.bounding_volume();
+        assert_eq!(aabb.min, aabb.max);
+
+        fs::remove_file(path)?;
+        Ok(())
+    }
+}
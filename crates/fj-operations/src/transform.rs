@@ -1,8 +1,9 @@
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
     algorithms::{
-        transform::TransformObject,
-        validate::{Validate, Validated, ValidationConfig, ValidationError},
+        snap::Snap,
+        transform::{supports_transform, TransformObject},
+        validate::{Validated, ValidationConfig, ValidationError},
     },
     objects::{Faces, Objects},
 };
@@ -22,22 +23,100 @@ impl Shape for fj::Transform {
         planes: &Planes,
         debug_info: &mut DebugInfo,
     ) -> Result<Validated<Self::Brep>, ValidationError> {
+        if self
+            .scale
+            .iter()
+            .any(|&factor| factor <= 0. || !factor.is_finite())
+        {
+            return Err(ValidationError::InvalidParameters(format!(
+                "Scale factors must be positive, but are {:?}",
+                self.scale
+            )));
+        }
+
+        let transform = transform(self);
         let faces = self
             .shape
             .compute_brep(config, objects, planes, debug_info)?
-            .into_inner()
-            .transform(&make_transform(self), objects);
+            .into_inner();
+
+        if !supports_transform(&faces, &transform) {
+            return Err(ValidationError::Unsupported(
+                "Non-uniform scaling is not supported for shapes with \
+                helices, or cylindrical, conical, spherical, toroidal, or \
+                revolved surfaces"
+                    .into(),
+            ));
+        }
 
-        faces.validate_with_config(config)
+        let faces = faces.transform(&transform, objects)?;
+        faces.validate_or_snap(config, objects)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
-        make_transform(self).transform_aabb(&self.shape.bounding_volume())
+        let transform = transform(self);
+
+        Aabb::<3>::from_points(
+            self.shape
+                .bounding_volume()
+                .vertices()
+                .map(|vertex| transform.transform_point(&vertex)),
+        )
     }
 }
 
-fn make_transform(transform: &fj::Transform) -> Transform {
-    let axis = Vector::from(transform.axis).normalize();
-    Transform::translation(transform.offset)
-        * Transform::rotation(axis * transform.angle.rad())
+/// Create the transform of an `fj::Transform`, including its scaling
+fn transform(transform: &fj::Transform) -> Transform {
+    make_transform(transform.axis, transform.angle, transform.offset)
+        * Transform::scaling(transform.scale)
+}
+
+/// Create a rotation around `axis` by `angle`, followed by a translation
+pub fn make_transform(
+    axis: [f64; 3],
+    angle: fj::Angle,
+    offset: [f64; 3],
+) -> Transform {
+    let axis = Vector::from(axis).normalize();
+    Transform::translation(offset) * Transform::rotation(axis * angle.rad())
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_kernel::algorithms::validate::ValidationError;
+
+    use crate::test_helpers::{compute_brep, cube, cylinder};
+
+    fn scale(shape: fj::Shape, scale: [f64; 3]) -> fj::Transform {
+        fj::Transform {
+            shape,
+            axis: [0., 0., 1.],
+            angle: fj::Angle::from_rad(0.),
+            offset: [0.; 3],
+            scale,
+        }
+    }
+
+    #[test]
+    fn scale_non_uniformly() -> anyhow::Result<()> {
+        compute_brep(&scale(cube(1.), [1., 2., 3.]))?;
+        compute_brep(&scale(cylinder(1., 1.), [2., 2., 2.]))?;
+
+        assert!(matches!(
+            compute_brep(&scale(cylinder(1., 1.), [2., 1., 1.])),
+            Err(ValidationError::Unsupported(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn scale_by_invalid_factors() {
+        for factors in [[0., 1., 1.], [1., -1., 1.], [1., 1., f64::NAN]] {
+            assert!(matches!(
+                compute_brep(&scale(cube(1.), factors)),
+                Err(ValidationError::InvalidParameters(_))
+            ));
+        }
+    }
 }
@@ -0,0 +1,84 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        boolean::Union,
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    objects::{Face, Faces, Objects, Shell},
+};
+use fj_math::Aabb;
+
+use crate::planes::Planes;
+
+use super::Shape;
+
+impl Shape for fj::Union {
+    type Brep = Faces;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let [a, b] = [&self.a, &self.b].map(|shape| {
+            shape
+                .compute_brep(config, objects, planes, debug_info)
+                .map(|faces| Shell::new().with_faces(faces.into_inner()))
+        });
+
+        let [a, b] = [a?, b?];
+        if !a.faces().into_iter().chain(b.faces()).all(Face::is_polygon) {
+            return Err(ValidationError::Unsupported(
+                "Boolean operations only support shapes with planar faces \
+                and straight edges"
+                    .into(),
+            ));
+        }
+
+        let faces = a.union(b, objects).into_faces();
+        faces.validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let a = self.a.bounding_volume();
+        let b = self.b.bounding_volume();
+
+        a.merged(&b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_kernel::algorithms::validate::ValidationError;
+
+    use crate::test_helpers::{compute_brep, cube, cylinder, translate};
+
+    #[test]
+    fn union_of_cubes() -> anyhow::Result<()> {
+        let union = fj::Union {
+            a: cube(1.),
+            b: translate(cube(1.), [0.5, 0.5, 0.5]),
+        };
+
+        let faces = compute_brep(&union)?.into_inner();
+        assert_eq!(faces.into_iter().count(), 12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_with_cylinder() {
+        let union = fj::Union {
+            a: cube(1.),
+            b: cylinder(0.5, 2.),
+        };
+
+        assert!(matches!(
+            compute_brep(&union),
+            Err(ValidationError::Unsupported(_))
+        ));
+    }
+}
@@ -0,0 +1,96 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        boolean::Union,
+        snap::Snap,
+        validate::{Validated, ValidationConfig, ValidationError},
+    },
+    objects::{Face, Objects, Sketch},
+};
+use fj_math::Aabb;
+
+use crate::planes::Planes;
+
+use super::Shape;
+
+impl Shape for fj::Union2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        objects: &Objects,
+        planes: &Planes,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ValidationError> {
+        let [a, b] = self.shapes();
+        let [a, b] = [a, b].map(|shape| {
+            shape.compute_brep(config, objects, planes, debug_info)
+        });
+        let [a, b] = [a?, b?];
+
+        if !a.faces().into_iter().chain(b.faces()).all(Face::is_polygon) {
+            return Err(ValidationError::Unsupported(
+                "Boolean operations on sketches only support straight edges"
+                    .into(),
+            ));
+        }
+
+        let union = a.into_inner().union(b.into_inner(), objects);
+        union.validate_or_snap(config, objects)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [a, b] = self.shapes();
+        a.bounding_volume().merged(&b.bounding_volume())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_kernel::algorithms::validate::ValidationError;
+
+    use crate::test_helpers::{circle, compute_brep, square};
+
+    #[test]
+    fn union_of_squares() -> anyhow::Result<()> {
+        let union =
+            fj::Union2d::from_shapes([square(1.).into(), square(2.).into()]);
+
+        let sketch = compute_brep(&union)?.into_inner();
+        assert_eq!(sketch.faces().into_iter().count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_with_circle() {
+        let union =
+            fj::Union2d::from_shapes([square(1.).into(), circle(1.).into()]);
+
+        assert!(matches!(
+            compute_brep(&union),
+            Err(ValidationError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn union_with_arc() {
+        let rounded = fj::Sketch::from_segments(vec![
+            fj::Segment::LineTo { end: [1., 0.] },
+            fj::Segment::ArcTo {
+                end: [1., 1.],
+                radius: 0.5,
+            },
+            fj::Segment::LineTo { end: [0., 1.] },
+            fj::Segment::LineTo { end: [0., 0.] },
+        ]);
+        let union =
+            fj::Union2d::from_shapes([rounded.into(), square(2.).into()]);
+
+        assert!(matches!(
+            compute_brep(&union),
+            Err(ValidationError::Unsupported(_))
+        ));
+    }
+}
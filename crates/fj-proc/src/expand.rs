@@ -2,8 +2,8 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
 use crate::parse::{
-    ArgumentMetadata, Constraint, ConstraintKind, ExtractedArgument,
-    GeometryFunction, Initializer, Metadata, Model,
+    ArgumentKind, ArgumentMetadata, Constraint, ConstraintKind,
+    ExtractedArgument, GeometryFunction, Initializer, Metadata, Model,
 };
 
 impl Initializer {
@@ -76,16 +76,48 @@ impl ToTokens for ArgumentMetadata {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let ArgumentMetadata {
             name,
+            description,
             default_value,
+            minimum,
+            maximum,
+            kind,
         } = self;
 
         tokens.extend(quote! { fj::models::ArgumentMetadata::new(#name) });
 
+        if let Some(description) = description {
+            tokens.extend(quote! {
+                .with_description(#description)
+            });
+        }
         if let Some(default_value) = default_value {
             tokens.extend(quote! {
                 .with_default_value(stringify!(#default_value))
             });
         }
+        if let Some(minimum) = minimum {
+            tokens.extend(quote! {
+                .with_minimum(stringify!(#minimum))
+            });
+        }
+        if let Some(maximum) = maximum {
+            tokens.extend(quote! {
+                .with_maximum(stringify!(#maximum))
+            });
+        }
+
+        let kind = match kind {
+            ArgumentKind::Any => quote! { Any },
+            ArgumentKind::Bool => quote! { Bool },
+            ArgumentKind::Integer => quote! { Integer },
+            ArgumentKind::Number => quote! { Number },
+            ArgumentKind::Choice(choices) => quote! {
+                Choice(vec![ #( String::from(#choices) ),* ])
+            },
+        };
+        tokens.extend(quote! {
+            .with_kind(fj::models::ArgumentKind::#kind)
+        });
     }
 }
 
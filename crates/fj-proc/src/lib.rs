@@ -32,6 +32,22 @@ use syn::{parse_macro_input, FnArg, ItemFn};
 /// }
 /// ```
 ///
+/// This includes the results of operations, like the 3D boolean operations,
+/// which merge the geometry of other shapes.
+///
+/// ```rust
+/// # use fj_proc::model;
+/// use fj::syntax::*;
+/// #[model]
+/// fn model() -> fj::Intersection {
+///     let square =
+///         fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+///     let cube = square.sweep([0., 0., 1.]);
+///
+///     cube.intersection(&cube.translate([0.5, 0.5, 0.5]))
+/// }
+/// ```
+///
 /// The return type is checked at compile time. That means something like this
 /// won't work because `()` can't be converted into a `fj::Shape`.
 ///
@@ -72,6 +88,24 @@ use syn::{parse_macro_input, FnArg, ItemFn};
 /// }
 /// ```
 ///
+/// The host uses each argument's metadata to validate values before they reach
+/// the model, and to present them to the user. The kind of value an argument
+/// accepts (bool, integer, number, or anything else) is inferred from its type.
+/// Arguments that accept one of a fixed set of values, like the variants of an
+/// enum, can list them with `choices`. A `description` explains the argument.
+///
+/// ```rust
+/// #[fj::model]
+/// pub fn gear(
+///     #[param(default = 12, min = 3, description = "The number of teeth")]
+///     teeth: u32,
+///     #[param(default = "spur".to_string(), choices = ["spur", "helical"])]
+///     style: String,
+/// ) -> fj::Shape {
+///     todo!()
+/// }
+/// ```
+///
 /// For more complex situations, model functions are allowed to return any
 /// error type that converts into a model error.
 ///
@@ -28,7 +28,21 @@ pub(crate) struct Metadata {
 #[derive(Debug)]
 pub(crate) struct ArgumentMetadata {
     pub(crate) name: String,
+    pub(crate) description: Option<Expr>,
     pub(crate) default_value: Option<Expr>,
+    pub(crate) minimum: Option<Expr>,
+    pub(crate) maximum: Option<Expr>,
+    pub(crate) kind: ArgumentKind,
+}
+
+/// The kind of value an argument accepts, as reported in its metadata.
+#[derive(Debug)]
+pub(crate) enum ArgumentKind {
+    Any,
+    Bool,
+    Integer,
+    Number,
+    Choice(Vec<Expr>),
 }
 
 /// The `<_ as fj::Model>::shape()` function.
@@ -80,11 +94,17 @@ fn parse_model(item: &ItemFn) -> syn::Result<Model> {
         name: geometry_function.to_string(),
         arguments: args
             .iter()
-            .map(|a| ArgumentMetadata {
-                name: a.ident.to_string(),
-                default_value: a.default(),
+            .map(|a| {
+                Ok(ArgumentMetadata {
+                    name: a.ident.to_string(),
+                    description: a.description(),
+                    default_value: a.default(),
+                    minimum: a.minimum(),
+                    maximum: a.maximum(),
+                    kind: a.kind()?,
+                })
             })
-            .collect(),
+            .collect::<syn::Result<_>>()?,
     };
 
     let geometry = GeometryFunction {
@@ -163,6 +183,64 @@ impl Argument {
             .and_then(|attr| attr.get_default())
             .map(|param| param.val)
     }
+
+    fn minimum(&self) -> Option<Expr> {
+        self.attr
+            .as_ref()
+            .and_then(|attr| attr.get_minimum())
+            .map(|param| param.val)
+    }
+
+    fn maximum(&self) -> Option<Expr> {
+        self.attr
+            .as_ref()
+            .and_then(|attr| attr.get_maximum())
+            .map(|param| param.val)
+    }
+
+    fn description(&self) -> Option<Expr> {
+        self.attr
+            .as_ref()
+            .and_then(|attr| attr.get_description())
+            .map(|param| param.val)
+    }
+
+    /// Determine the kind of the argument
+    ///
+    /// Arguments with `choices` accept one of those. Otherwise, the kind is
+    /// inferred from the argument's type.
+    fn kind(&self) -> syn::Result<ArgumentKind> {
+        let choices = self.attr.as_ref().and_then(|attr| attr.get_choices());
+        if let Some(choices) = choices {
+            return match choices.val {
+                Expr::Array(array) => {
+                    Ok(ArgumentKind::Choice(array.elems.into_iter().collect()))
+                }
+                val => Err(syn::Error::new_spanned(
+                    val,
+                    "Expected an array of choices, like `[\"a\", \"b\"]`",
+                )),
+            };
+        }
+
+        let ident = match &self.ty {
+            Type::Path(p) => {
+                p.path.segments.last().map(|s| s.ident.to_string())
+            }
+            _ => None,
+        };
+        let kind = match ident.as_deref() {
+            Some("bool") => ArgumentKind::Bool,
+            Some(
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16"
+                | "u32" | "u64" | "u128" | "usize",
+            ) => ArgumentKind::Integer,
+            Some("f32" | "f64") => ArgumentKind::Number,
+            _ => ArgumentKind::Any,
+        };
+
+        Ok(kind)
+    }
 }
 
 impl Parse for Argument {
@@ -186,6 +264,8 @@ impl Parse for Argument {
 /// #[param(default=3, min=4)]
 ///         ^^^^^^^^^^^^^^^^
 /// ```
+///
+/// Supported are `default`, `min`, `max`, `description`, and `choices`.
 #[derive(Debug, Clone)]
 struct HelperAttribute {
     param: Option<syn::punctuated::Punctuated<DefaultParam, syn::Token![,]>>,
@@ -248,6 +328,14 @@ impl HelperAttribute {
     fn get_maximum(&self) -> Option<DefaultParam> {
         self.get_parameter("max")
     }
+
+    fn get_description(&self) -> Option<DefaultParam> {
+        self.get_parameter("description")
+    }
+
+    fn get_choices(&self) -> Option<DefaultParam> {
+        self.get_parameter("choices")
+    }
 }
 
 /// Represents one argument given to the `#[param]` attribute eg:
@@ -288,7 +376,7 @@ mod tests {
             pub fn spacer(
                 #[param(default = 1.0, min = inner * 1.01)] outer: f64,
                 #[param(default = 0.5, max = outer * 0.99)] inner: f64,
-                height: f64,
+                #[param(description = "The height of the spacer")] height: f64,
             ) -> fj::Shape {
                 let outer_edge = fj::Sketch::from_circle(fj::Circle::from_radius(outer));
                 let inner_edge = fj::Sketch::from_circle(fj::Circle::from_radius(inner));
@@ -327,6 +415,42 @@ mod tests {
             })
             .collect();
         assert_eq!(meta, expected_meta);
+        let bounds: Vec<_> = arguments
+            .iter()
+            .map(|arg| {
+                (
+                    arg.minimum
+                        .as_ref()
+                        .map(|v| v.to_token_stream().to_string()),
+                    arg.maximum
+                        .as_ref()
+                        .map(|v| v.to_token_stream().to_string()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            bounds,
+            &[
+                (Some("inner * 1.01".to_string()), None),
+                (None, Some("outer * 0.99".to_string())),
+                (None, None),
+            ]
+        );
+        let descriptions: Vec<_> = arguments
+            .iter()
+            .map(|arg| {
+                arg.description
+                    .as_ref()
+                    .map(|v| v.to_token_stream().to_string())
+            })
+            .collect();
+        assert_eq!(
+            descriptions,
+            &[None, None, Some("\"The height of the spacer\"".to_string())]
+        );
+        assert!(arguments
+            .iter()
+            .all(|arg| matches!(arg.kind, ArgumentKind::Number)));
 
         let GeometryFunction {
             geometry_function,
@@ -373,6 +497,43 @@ mod tests {
         assert_eq!(constraints, expected_constraints);
     }
 
+    #[test]
+    fn parse_argument_kinds() {
+        let tokens = quote! {
+            pub fn gear(
+                teeth: u32,
+                helical: bool,
+                #[param(default = "involute".to_string(), choices = ["involute", "cycloid"])]
+                profile: String,
+                label: String,
+            ) -> fj::Shape {
+                todo!()
+            }
+        };
+        let function: ItemFn = syn::parse2(tokens).unwrap();
+
+        let init = parse(&function).unwrap();
+        let kinds: Vec<_> = init
+            .model
+            .metadata
+            .arguments
+            .iter()
+            .map(|arg| match &arg.kind {
+                ArgumentKind::Choice(choices) => choices
+                    .iter()
+                    .map(|v| v.to_token_stream().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                kind => format!("{kind:?}"),
+            })
+            .collect();
+
+        assert_eq!(
+            kinds,
+            &["Integer", "Bool", "\"involute\", \"cycloid\"", "Any"]
+        );
+    }
+
     #[test]
     fn parse_fallible_function() {
         let tokens = quote! {
@@ -386,4 +547,27 @@ mod tests {
 
         assert!(init.model.geometry.fallible);
     }
+
+    #[test]
+    fn parse_boolean_operation() {
+        let tokens = quote! {
+            pub fn model() -> fj::Intersection {
+                todo!()
+            }
+        };
+        let function: ItemFn = syn::parse2(tokens).unwrap();
+
+        let init = parse(&function).unwrap();
+        assert!(!init.model.geometry.fallible);
+
+        let tokens = quote! {
+            pub fn model() -> Result<fj::Union, Whatever> {
+                todo!()
+            }
+        };
+        let function: ItemFn = syn::parse2(tokens).unwrap();
+
+        let init = parse(&function).unwrap();
+        assert!(init.model.geometry.fallible);
+    }
 }
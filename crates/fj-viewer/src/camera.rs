@@ -139,11 +139,8 @@ impl Camera {
 
         let mut min_t = None;
 
-        for triangle in shape.mesh.triangles() {
-            let t =
-                triangle
-                    .inner
-                    .cast_local_ray(origin, dir, f64::INFINITY, true);
+        for triangle in shape.triangles.query_ray(origin, dir) {
+            let t = triangle.cast_local_ray(origin, dir, f64::INFINITY, true);
 
             if let Some(t) = t {
                 if t <= min_t.unwrap_or(t) {
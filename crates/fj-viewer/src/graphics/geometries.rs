@@ -1,5 +1,6 @@
 use std::convert::TryInto;
 
+use fj_interop::mass_properties::MassProperties;
 use fj_math::Aabb;
 use wgpu::util::DeviceExt;
 
@@ -10,6 +11,7 @@ pub struct Geometries {
     pub mesh: Geometry,
     pub lines: Geometry,
     pub aabb: Aabb<3>,
+    pub mass_properties: MassProperties,
 }
 
 impl Geometries {
@@ -18,12 +20,18 @@ impl Geometries {
         mesh: &Vertices,
         debug_info: &Vertices,
         aabb: Aabb<3>,
+        mass_properties: MassProperties,
     ) -> Self {
         let mesh = Geometry::new(device, mesh.vertices(), mesh.indices());
         let lines =
             Geometry::new(device, debug_info.vertices(), debug_info.indices());
 
-        Self { mesh, lines, aabb }
+        Self {
+            mesh,
+            lines,
+            aabb,
+            mass_properties,
+        }
     }
 }
 
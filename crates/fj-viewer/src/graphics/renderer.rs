@@ -1,7 +1,9 @@
 use std::{io, mem::size_of};
 
 use egui_winit::winit::event_loop::EventLoop;
-use fj_interop::status_report::StatusReport;
+use fj_interop::{
+    mass_properties::MassProperties, status_report::StatusReport,
+};
 use fj_math::{Aabb, Point};
 use thiserror::Error;
 use tracing::debug;
@@ -10,6 +12,8 @@ use wgpu_glyph::ab_glyph::InvalidFont;
 
 use crate::{
     camera::Camera,
+    parameters::ParameterPanel,
+    parts::PartPanel,
     screen::{Screen, Size},
 };
 
@@ -228,6 +232,7 @@ impl Renderer {
                 min: Point::from([0.0, 0.0, 0.0]),
                 max: Point::from([0.0, 0.0, 0.0]),
             },
+            MassProperties::default(),
         );
         let pipelines =
             Pipelines::new(&device, &bind_group_layout, color_format);
@@ -284,8 +289,10 @@ impl Renderer {
         mesh: Vertices,
         lines: Vertices,
         aabb: Aabb<3>,
+        mass_properties: MassProperties,
     ) {
-        self.geometries = Geometries::new(&self.device, &mesh, &lines, aabb);
+        self.geometries =
+            Geometries::new(&self.device, &mesh, &lines, aabb, mass_properties);
     }
 
     /// Resizes the render surface.
@@ -308,6 +315,8 @@ impl Renderer {
         &mut self,
         camera: &Camera,
         config: &mut DrawConfig,
+        parameters: &mut ParameterPanel,
+        parts: &mut PartPanel,
         window: &egui_winit::winit::window::Window,
         status: &mut StatusReport,
     ) -> Result<(), DrawError> {
@@ -443,6 +452,18 @@ impl Renderer {
             info
         }
 
+        fn get_mass_properties_text(properties: &MassProperties) -> String {
+            let center = properties.center_of_mass.coords.components;
+            format!(
+                "Volume: {:0.1}\nSurface area: {:0.1}\nCenter of mass:\n{:0.1} {:0.1} {:0.1}",
+                properties.volume.into_f32(),
+                properties.surface_area.into_f32(),
+                center[0].into_f32(),
+                center[1].into_f32(),
+                center[2].into_f32()
+            )
+        }
+
         let line_drawing_available = self.is_line_drawing_available();
 
         egui::SidePanel::left("fj-left-panel").show(&self.egui.context, |ui| {
@@ -467,10 +488,32 @@ impl Renderer {
                 );
                 ui.add_space(16.0);
                 ui.strong(get_bbox_size_text(&self.geometries.aabb));
+                ui.add_space(16.0);
+                ui.strong(get_mass_properties_text(
+                    &self.geometries.mass_properties,
+                ));
             });
 
             ui.add_space(16.0);
 
+            if !parameters.parameters.is_empty() {
+                ui.group(|ui| {
+                    ui.strong("Model parameters");
+                    parameters.show(ui);
+                });
+
+                ui.add_space(16.0);
+            }
+
+            if !parts.parts.is_empty() {
+                ui.group(|ui| {
+                    ui.strong("Parts");
+                    parts.show(ui);
+                });
+
+                ui.add_space(16.0);
+            }
+
             {
                 ui.group(|ui| {
                     ui.checkbox(
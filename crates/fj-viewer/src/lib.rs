@@ -17,4 +17,6 @@
 pub mod camera;
 pub mod graphics;
 pub mod input;
+pub mod parameters;
+pub mod parts;
 pub mod screen;
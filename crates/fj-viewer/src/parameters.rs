@@ -0,0 +1,267 @@
+//! Model parameters that can be edited from within the viewer
+
+use std::{fmt, mem};
+
+/// The parameters of the model, as they are presented to the user
+#[derive(Debug, Default)]
+pub struct ParameterPanel {
+    /// The parameters that are shown in the panel
+    pub parameters: Vec<Parameter>,
+
+    modified: bool,
+}
+
+impl ParameterPanel {
+    /// Construct a panel from the given parameters
+    pub fn new(parameters: Vec<Parameter>) -> Self {
+        Self {
+            parameters,
+            modified: false,
+        }
+    }
+
+    /// Indicate whether the user modified a parameter
+    ///
+    /// Resets the flag, so subsequent calls return `false`, until the user
+    /// modifies a parameter again.
+    pub fn take_modified(&mut self) -> bool {
+        mem::take(&mut self.modified)
+    }
+
+    pub(crate) fn show(&mut self, ui: &mut egui::Ui) {
+        for parameter in &mut self.parameters {
+            let response = ui
+                .horizontal(|ui| {
+                    ui.label(parameter.name.as_str());
+
+                    // Some widgets need an ID that's unique to the parameter.
+                    ui.push_id(&parameter.name, |ui| parameter.value.show(ui))
+                        .inner
+                })
+                .inner;
+
+            // Reloading the model is expensive, so we only do that once the
+            // user is done dragging a slider.
+            let finished = match parameter.value {
+                ParameterValue::Text(_) => response.lost_focus(),
+                _ => {
+                    response.drag_released()
+                        || (response.changed() && !response.dragged())
+                }
+            };
+            if finished {
+                self.modified = true;
+            }
+
+            if let Some(description) = &parameter.description {
+                response.on_hover_text(description.as_str());
+            }
+        }
+    }
+}
+
+/// A single parameter of the model
+#[derive(Debug)]
+pub struct Parameter {
+    /// The name of the parameter
+    pub name: String,
+
+    /// A description of the parameter, shown when hovering over it
+    pub description: Option<String>,
+
+    /// The current value of the parameter
+    pub value: ParameterValue,
+}
+
+/// The value of a [`Parameter`]
+#[derive(Debug)]
+pub enum ParameterValue {
+    /// A numeric parameter, edited with a slider or a drag value
+    Number {
+        /// The current value
+        value: f64,
+
+        /// The smallest value the parameter accepts, if known
+        minimum: Option<f64>,
+
+        /// The largest value the parameter accepts, if known
+        maximum: Option<f64>,
+
+        /// Whether only integer values are accepted
+        integer: bool,
+    },
+
+    /// A boolean parameter, edited with a checkbox
+    Bool(bool),
+
+    /// A parameter that accepts one of a fixed set of values, edited with a
+    /// combo box
+    Choice {
+        /// The current value
+        value: String,
+
+        /// The values the parameter accepts
+        choices: Vec<String>,
+    },
+
+    /// Any other parameter, edited as text
+    Text(String),
+}
+
+impl ParameterValue {
+    /// Infer the kind of a parameter from its textual value
+    ///
+    /// Bounds that can't be parsed as numbers (for example, because they
+    /// depend on other parameters) are ignored.
+    pub fn parse(
+        value: &str,
+        minimum: Option<&str>,
+        maximum: Option<&str>,
+    ) -> Self {
+        // Values that come from the `#[fj::model]` macro are stringified
+        // expressions, so a negative number might show up as `- 1.0`.
+        fn number(value: &str) -> String {
+            value.split_whitespace().collect()
+        }
+
+        let value = value.trim();
+
+        if let Ok(value) = value.parse::<bool>() {
+            return Self::Bool(value);
+        }
+        if let Ok(number_value) = number(value).parse::<f64>() {
+            let bound = |bound: Option<&str>| {
+                bound.and_then(|bound| number(bound).parse::<f64>().ok())
+            };
+
+            return Self::Number {
+                value: number_value,
+                minimum: bound(minimum),
+                maximum: bound(maximum),
+                integer: number(value).parse::<i64>().is_ok(),
+            };
+        }
+
+        Self::Text(value.to_string())
+    }
+
+    fn show(&mut self, ui: &mut egui::Ui) -> egui::Response {
+        match self {
+            Self::Number {
+                value,
+                minimum: Some(minimum),
+                maximum: Some(maximum),
+                integer,
+            } => {
+                let slider = egui::Slider::new(value, *minimum..=*maximum);
+                let slider = if *integer { slider.integer() } else { slider };
+                ui.add(slider)
+            }
+            Self::Number {
+                value,
+                minimum,
+                maximum,
+                integer,
+            } => {
+                let minimum = minimum.unwrap_or(f64::NEG_INFINITY);
+                let maximum = maximum.unwrap_or(f64::INFINITY);
+
+                let drag_value = egui::DragValue::new(value)
+                    .clamp_range(minimum..=maximum)
+                    .speed(0.1);
+                let drag_value = if *integer {
+                    drag_value.speed(1.).fixed_decimals(0)
+                } else {
+                    drag_value
+                };
+                ui.add(drag_value)
+            }
+            Self::Bool(value) => ui.checkbox(value, ""),
+            Self::Choice { value, choices } => {
+                let mut changed = false;
+                let mut response = egui::ComboBox::from_id_source("choice")
+                    .selected_text(value.as_str())
+                    .show_ui(ui, |ui| {
+                        for choice in choices.iter() {
+                            changed |= ui
+                                .selectable_value(
+                                    value,
+                                    choice.clone(),
+                                    choice.as_str(),
+                                )
+                                .changed();
+                        }
+                    })
+                    .response;
+
+                // The combo box itself doesn't report changes made in its
+                // popup.
+                if changed {
+                    response.mark_changed();
+                }
+                response
+            }
+            Self::Text(value) => ui.text_edit_singleline(value),
+        }
+    }
+}
+
+impl fmt::Display for ParameterValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Number {
+                value,
+                integer: true,
+                ..
+            } => write!(f, "{}", value.round() as i64),
+            Self::Number { value, .. } => write!(f, "{value}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Choice { value, .. } => write!(f, "{value}"),
+            Self::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParameterValue;
+
+    #[test]
+    fn parse_number() {
+        let value = ParameterValue::parse("- 1.5", Some("- 2.0"), Some("x"));
+        assert!(matches!(
+            value,
+            ParameterValue::Number {
+                minimum: Some(minimum),
+                maximum: None,
+                integer: false,
+                ..
+            } if minimum == -2.
+        ));
+        assert_eq!(value.to_string(), "-1.5");
+
+        let value = ParameterValue::parse("5", None, Some("10"));
+        assert!(matches!(
+            value,
+            ParameterValue::Number {
+                minimum: None,
+                maximum: Some(maximum),
+                integer: true,
+                ..
+            } if maximum == 10.
+        ));
+        assert_eq!(value.to_string(), "5");
+    }
+
+    #[test]
+    fn parse_other() {
+        assert!(matches!(
+            ParameterValue::parse(" true ", None, None),
+            ParameterValue::Bool(true)
+        ));
+        assert!(matches!(
+            ParameterValue::parse("round", Some("1"), None),
+            ParameterValue::Text(value) if value == "round"
+        ));
+    }
+}
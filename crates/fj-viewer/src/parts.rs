@@ -0,0 +1,73 @@
+//! The parts of an assembly, which can be shown or hidden from within the
+//! viewer
+
+use std::mem;
+
+/// The parts of the model, as they are presented to the user
+#[derive(Debug, Default)]
+pub struct PartPanel {
+    /// The parts that are shown in the panel
+    pub parts: Vec<Part>,
+
+    modified: bool,
+}
+
+impl PartPanel {
+    /// Construct a panel from the names of the parts
+    ///
+    /// All parts are visible, except those that are named in `hidden`. Pass
+    /// the hidden parts of the previous panel, to keep them hidden after the
+    /// model has been reloaded.
+    pub fn new(
+        names: impl IntoIterator<Item = String>,
+        hidden: &[String],
+    ) -> Self {
+        let parts = names
+            .into_iter()
+            .map(|name| Part {
+                visible: !hidden.contains(&name),
+                name,
+            })
+            .collect();
+
+        Self {
+            parts,
+            modified: false,
+        }
+    }
+
+    /// Access the names of the parts that are hidden
+    pub fn hidden(&self) -> Vec<String> {
+        self.parts
+            .iter()
+            .filter(|part| !part.visible)
+            .map(|part| part.name.clone())
+            .collect()
+    }
+
+    /// Indicate whether the user showed or hid a part
+    ///
+    /// Resets the flag, so subsequent calls return `false`, until the user
+    /// shows or hides a part again.
+    pub fn take_modified(&mut self) -> bool {
+        mem::take(&mut self.modified)
+    }
+
+    pub(crate) fn show(&mut self, ui: &mut egui::Ui) {
+        for part in &mut self.parts {
+            if ui.checkbox(&mut part.visible, part.name.as_str()).changed() {
+                self.modified = true;
+            }
+        }
+    }
+}
+
+/// A single part of the model
+#[derive(Debug)]
+pub struct Part {
+    /// The name of the part
+    pub name: String,
+
+    /// Whether the part is visible
+    pub visible: bool,
+}
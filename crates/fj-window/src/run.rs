@@ -5,13 +5,18 @@
 
 use std::error;
 
-use fj_host::Watcher;
-use fj_interop::status_report::StatusReport;
+use fj::models::{ArgumentKind, ArgumentMetadata};
+use fj_host::{Parameters, Watcher};
+use fj_interop::{
+    mesh::Mesh, processed_shape::ProcessedShape, status_report::StatusReport,
+};
 use fj_operations::shape_processor::ShapeProcessor;
 use fj_viewer::{
     camera::Camera,
     graphics::{self, DrawConfig, Renderer},
     input,
+    parameters::{Parameter, ParameterPanel, ParameterValue},
+    parts::PartPanel,
     screen::{NormalizedPosition, Screen as _, Size},
 };
 use futures::executor::block_on;
@@ -29,7 +34,7 @@ use crate::window::{self, Window};
 
 /// Initializes a model viewer for a given model and enters its process loop.
 pub fn run(
-    watcher: Option<Watcher>,
+    mut watcher: Option<Watcher>,
     shape_processor: ShapeProcessor,
     mut status: StatusReport,
 ) -> Result<(), Error> {
@@ -44,6 +49,8 @@ pub fn run(
     let mut renderer = block_on(Renderer::new(&window, &event_loop))?;
 
     let mut draw_config = DrawConfig::default();
+    let mut parameter_panel = ParameterPanel::default();
+    let mut part_panel = PartPanel::default();
 
     let mut shape = None;
     let mut camera = Camera::new(&Default::default());
@@ -52,15 +59,32 @@ pub fn run(
     event_loop.run(move |event, _, control_flow| {
         trace!("Handling event: {:?}", event);
 
-        if let Some(watcher) = &watcher {
+        if let Some(watcher) = &mut watcher {
+            if parameter_panel.take_modified() {
+                watcher.set_parameters(parameters_from_panel(
+                    watcher.parameters(),
+                    &parameter_panel,
+                ));
+            }
+
             if let Some(new_shape) = watcher.receive(&mut status) {
+                parameter_panel = panel_from_watcher(watcher);
+
                 match shape_processor.process(&new_shape) {
                     Ok(new_shape) => {
-                        renderer.update_geometry(
-                            (&new_shape.mesh).into(),
-                            (&new_shape.debug_info).into(),
-                            new_shape.aabb,
+                        for warning in &new_shape.warnings {
+                            status
+                                .update_status(&format!("Warning: {warning}"));
+                        }
+
+                        part_panel = PartPanel::new(
+                            new_shape
+                                .parts
+                                .iter()
+                                .map(|part| part.name.clone()),
+                            &part_panel.hidden(),
                         );
+                        update_geometry(&mut renderer, &new_shape, &part_panel);
 
                         if camera_update_once {
                             camera_update_once = false;
@@ -88,6 +112,12 @@ pub fn run(
             }
         }
 
+        if part_panel.take_modified() {
+            if let Some(shape) = &shape {
+                update_geometry(&mut renderer, shape, &part_panel);
+            }
+        }
+
         //
 
         if let Event::WindowEvent {
@@ -184,6 +214,8 @@ pub fn run(
                 if let Err(err) = renderer.draw(
                     &camera,
                     &mut draw_config,
+                    &mut parameter_panel,
+                    &mut part_panel,
                     window.window(),
                     &mut status,
                 ) {
@@ -274,6 +306,130 @@ fn input_event(
     }
 }
 
+/// Pass the visible parts of the shape to the renderer
+fn update_geometry(
+    renderer: &mut Renderer,
+    shape: &ProcessedShape,
+    parts: &PartPanel,
+) {
+    let hidden = parts.hidden();
+
+    let mesh = if hidden.is_empty() {
+        (&shape.mesh).into()
+    } else {
+        let mut mesh = Mesh::new();
+        for part in &shape.parts {
+            if !hidden.contains(&part.name) {
+                mesh.extend(part.mesh.triangles());
+            }
+        }
+        (&mesh).into()
+    };
+
+    renderer.update_geometry(
+        mesh,
+        (&shape.debug_info).into(),
+        shape.aabb,
+        shape.mass_properties,
+    );
+}
+
+/// Create the parameter panel for the model that the watcher has loaded
+fn panel_from_watcher(watcher: &Watcher) -> ParameterPanel {
+    let arguments = match watcher.metadata() {
+        Some(metadata) => &metadata.arguments,
+        None => return ParameterPanel::default(),
+    };
+
+    let parameters = arguments
+        .iter()
+        .map(|argument| {
+            let value = watcher
+                .parameters()
+                .get(&argument.name)
+                .or(argument.default_value.as_ref())
+                .map(String::as_str)
+                .unwrap_or_default();
+
+            Parameter {
+                name: argument.name.clone(),
+                description: argument.description.clone(),
+                value: parameter_value(argument, value),
+            }
+        })
+        .collect();
+
+    ParameterPanel::new(parameters)
+}
+
+/// Create the value of a parameter, according to the kind of its argument
+///
+/// Falls back to inferring the kind from the value itself, if the argument
+/// doesn't declare one, or if the value doesn't match it.
+fn parameter_value(argument: &ArgumentMetadata, value: &str) -> ParameterValue {
+    let parsed = ParameterValue::parse(
+        value,
+        argument.minimum.as_deref(),
+        argument.maximum.as_deref(),
+    );
+
+    match (&argument.kind, parsed) {
+        (ArgumentKind::Choice(choices), _) => {
+            // Defaults are stringified expressions, which might not match any
+            // of the choices literally. Leave the value empty then, so the
+            // model falls back to its default.
+            let value = value.trim();
+            let value = if choices.iter().any(|choice| choice == value) {
+                value.to_string()
+            } else {
+                String::new()
+            };
+
+            ParameterValue::Choice {
+                value,
+                choices: choices.clone(),
+            }
+        }
+        (
+            ArgumentKind::Integer | ArgumentKind::Number,
+            ParameterValue::Number {
+                value,
+                minimum,
+                maximum,
+                ..
+            },
+        ) => ParameterValue::Number {
+            value,
+            minimum,
+            maximum,
+            integer: argument.kind == ArgumentKind::Integer,
+        },
+        (_, parsed) => parsed,
+    }
+}
+
+/// Update the model parameters with the values from the parameter panel
+fn parameters_from_panel(
+    parameters: &Parameters,
+    panel: &ParameterPanel,
+) -> Parameters {
+    let mut parameters = parameters.clone();
+
+    for parameter in &panel.parameters {
+        let value = parameter.value.to_string();
+
+        // An empty value means the parameter hasn't been provided, which
+        // leaves it to the model to fall back to its default.
+        if value.is_empty() {
+            parameters.remove(&parameter.name);
+        } else {
+            parameters.insert(parameter.name.as_str(), value);
+        }
+    }
+
+    parameters
+}
+
 /// Returns true/false if focus point point should be created/removed
 /// None means no change to focus point is needed
 fn focus_event(event: &Event<()>) -> Option<bool> {
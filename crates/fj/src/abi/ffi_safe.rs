@@ -11,7 +11,7 @@ use crate::models::Error;
 
 /// A FFI-safe version of `Vec<T>`.
 #[repr(C)]
-pub(crate) struct Vec<T> {
+pub struct Vec<T> {
     ptr: NonNull<T>,
     len: usize,
 }
@@ -85,6 +85,35 @@ impl<T> FromIterator<T> for Vec<T> {
     }
 }
 
+impl<T> Default for Vec<T> {
+    fn default() -> Self {
+        std::vec::Vec::new().into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Vec<T> {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        (**self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Vec<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        std::vec::Vec::deserialize(deserializer).map(Into::into)
+    }
+}
+
 impl<T> Deref for Vec<T> {
     type Target = [T];
 
@@ -138,6 +167,35 @@ impl From<std::string::String> for String {
     }
 }
 
+impl Default for String {
+    fn default() -> Self {
+        std::string::String::new().into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for String {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for String {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        std::string::String::deserialize(deserializer).map(Into::into)
+    }
+}
+
 impl From<String> for std::string::String {
     fn from(s: String) -> Self {
         s.to_string()
@@ -100,12 +100,17 @@ impl From<crate::models::Metadata> for Metadata {
     }
 }
 
+// Changing the fields of this struct changes its layout, which hosts and models
+// need to agree on. Models must be rebuilt against the matching version of `fj`.
 #[derive(Debug, Clone)]
 #[repr(C)]
 pub struct ArgumentMetadata {
     name: ffi_safe::String,
     description: ffi_safe::Option<ffi_safe::String>,
     default_value: ffi_safe::Option<ffi_safe::String>,
+    minimum: ffi_safe::Option<ffi_safe::String>,
+    maximum: ffi_safe::Option<ffi_safe::String>,
+    kind: ArgumentKind,
 }
 
 impl From<crate::models::ArgumentMetadata> for ArgumentMetadata {
@@ -114,12 +119,18 @@ impl From<crate::models::ArgumentMetadata> for ArgumentMetadata {
             name,
             description,
             default_value,
+            minimum,
+            maximum,
+            kind,
         } = meta;
 
         ArgumentMetadata {
             name: name.into(),
             description: description.into(),
             default_value: default_value.into(),
+            minimum: minimum.into(),
+            maximum: maximum.into(),
+            kind: kind.into(),
         }
     }
 }
@@ -130,12 +141,60 @@ impl From<ArgumentMetadata> for crate::models::ArgumentMetadata {
             name,
             description,
             default_value,
+            minimum,
+            maximum,
+            kind,
         } = meta;
 
         crate::models::ArgumentMetadata {
             name: name.into(),
             description: description.map(Into::into).into(),
             default_value: default_value.map(Into::into).into(),
+            minimum: minimum.map(Into::into).into(),
+            maximum: maximum.map(Into::into).into(),
+            kind: kind.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub enum ArgumentKind {
+    Any,
+    Bool,
+    Integer,
+    Number,
+    Choice(ffi_safe::Vec<ffi_safe::String>),
+}
+
+impl From<crate::models::ArgumentKind> for ArgumentKind {
+    fn from(kind: crate::models::ArgumentKind) -> Self {
+        match kind {
+            crate::models::ArgumentKind::Any => ArgumentKind::Any,
+            crate::models::ArgumentKind::Bool => ArgumentKind::Bool,
+            crate::models::ArgumentKind::Integer => ArgumentKind::Integer,
+            crate::models::ArgumentKind::Number => ArgumentKind::Number,
+            crate::models::ArgumentKind::Choice(choices) => {
+                ArgumentKind::Choice(
+                    choices.into_iter().map(Into::into).collect(),
+                )
+            }
+        }
+    }
+}
+
+impl From<ArgumentKind> for crate::models::ArgumentKind {
+    fn from(kind: ArgumentKind) -> Self {
+        match kind {
+            ArgumentKind::Any => crate::models::ArgumentKind::Any,
+            ArgumentKind::Bool => crate::models::ArgumentKind::Bool,
+            ArgumentKind::Integer => crate::models::ArgumentKind::Integer,
+            ArgumentKind::Number => crate::models::ArgumentKind::Number,
+            ArgumentKind::Choice(choices) => {
+                crate::models::ArgumentKind::Choice(
+                    choices.iter().cloned().map(Into::into).collect(),
+                )
+            }
         }
     }
 }
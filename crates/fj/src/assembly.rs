@@ -0,0 +1,138 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{abi::ffi_safe, Angle, Paint, Shape, Transform};
+
+/// An assembly of named parts
+///
+/// Unlike a [`Group`], an assembly keeps its parts separate. Each part becomes
+/// its own mesh, which allows the viewer to show and hide parts individually,
+/// and exporters to write files with multiple bodies.
+///
+/// [`Group`]: crate::Group
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Assembly {
+    parts: ffi_safe::Vec<Part>,
+}
+
+impl Assembly {
+    /// Create an empty assembly
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a part to the assembly
+    pub fn with_part(mut self, part: Part) -> Self {
+        let mut parts: Vec<_> = self.parts.into();
+        parts.push(part);
+        self.parts = parts.into();
+        self
+    }
+
+    /// Access the parts of the assembly
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+}
+
+impl From<Assembly> for Shape {
+    fn from(shape: Assembly) -> Self {
+        Self::Assembly(shape)
+    }
+}
+
+/// A named part of an [`Assembly`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Part {
+    name: ffi_safe::String,
+    shape: Shape,
+}
+
+impl Part {
+    /// Create a part from a name and a shape
+    pub fn new(name: impl Into<String>, shape: impl Into<Shape>) -> Self {
+        Self {
+            name: name.into().into(),
+            shape: shape.into(),
+        }
+    }
+
+    /// Place the part within the assembly
+    ///
+    /// Rotates the part by `angle` around an axis defined by `axis`, then
+    /// translates it by `offset`.
+    pub fn with_transform(
+        mut self,
+        axis: [f64; 3],
+        angle: Angle,
+        offset: [f64; 3],
+    ) -> Self {
+        self.shape = Transform {
+            shape: self.shape,
+            axis,
+            angle,
+            offset,
+            scale: [1.; 3],
+        }
+        .into();
+        self
+    }
+
+    /// Set the color of all faces of the part in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.shape = Paint {
+            shape: self.shape,
+            color,
+        }
+        .into();
+        self
+    }
+
+    /// Access the name of the part
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Access the shape of the part
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Angle, Shape, Sketch, Transform};
+
+    use super::{Assembly, Part};
+
+    #[test]
+    fn test_part_with_transform() {
+        let shape: Shape =
+            Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]).into();
+
+        let assembly = Assembly::new().with_part(
+            Part::new("lid", shape.clone()).with_transform(
+                [0., 0., 1.],
+                Angle::from_deg(90.),
+                [0., 0., 2.],
+            ),
+        );
+
+        let part = &assembly.parts()[0];
+        assert_eq!(part.name(), "lid");
+        assert_eq!(
+            part.shape(),
+            &Shape::from(Transform {
+                shape,
+                axis: [0., 0., 1.],
+                angle: Angle::from_deg(90.),
+                offset: [0., 0., 2.],
+                scale: [1.; 3],
+            })
+        );
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{abi::ffi_safe, Shape};
+
+/// A 3-dimensional shape, with attributes like its material
+///
+/// The attributes don't change the geometry of the shape. They are carried
+/// along into the processed shape, where exporters and the computation of mass
+/// properties can use them.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Attributed {
+    /// The shape the attributes are attached to
+    pub shape: Shape,
+
+    /// The attributes of the shape
+    pub attributes: Attributes,
+}
+
+impl From<Attributed> for Shape {
+    fn from(shape: Attributed) -> Self {
+        Self::Attributed(Box::new(shape))
+    }
+}
+
+/// Attributes of a shape, like its material
+///
+/// See [`Attributed`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Attributes {
+    // The name of the material; empty, if it has none
+    material: ffi_safe::String,
+
+    // The density of the material; zero, if it is unknown
+    density: f64,
+
+    metadata: ffi_safe::Vec<Attribute>,
+}
+
+impl Attributes {
+    /// Create an empty set of attributes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the name of the material
+    pub fn with_material(mut self, material: impl Into<String>) -> Self {
+        self.material = material.into().into();
+        self
+    }
+
+    /// Set the density of the material
+    ///
+    /// The density is the mass per cubic model unit, in whatever unit of mass
+    /// is convenient. The computed mass of the shape is in that unit.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `density` is not positive.
+    pub fn with_density(mut self, density: f64) -> Self {
+        assert!(density > 0., "Density must be positive");
+
+        self.density = density;
+        self
+    }
+
+    /// Set an arbitrary attribute, replacing any previous value
+    pub fn with_attribute(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        let key = key.into();
+
+        let mut metadata: Vec<_> = self
+            .metadata
+            .iter()
+            .filter(|attribute| *attribute.key != *key)
+            .cloned()
+            .collect();
+        metadata.push(Attribute {
+            key: key.into(),
+            value: value.into().into(),
+        });
+
+        self.metadata = metadata.into();
+        self
+    }
+
+    /// Access the name of the material, if it has one
+    pub fn material(&self) -> Option<&str> {
+        if self.material.is_empty() {
+            return None;
+        }
+
+        Some(&self.material)
+    }
+
+    /// Access the density of the material, if it is known
+    pub fn density(&self) -> Option<f64> {
+        if self.density == 0. {
+            return None;
+        }
+
+        Some(self.density)
+    }
+
+    /// Access the arbitrary attributes, as key-value pairs
+    pub fn metadata(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.metadata
+            .iter()
+            .map(|attribute| (&*attribute.key, &*attribute.value))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+struct Attribute {
+    key: ffi_safe::String,
+    value: ffi_safe::String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Attributes;
+
+    #[test]
+    fn test_attributes() {
+        let attributes = Attributes::new()
+            .with_material("PLA")
+            .with_density(1.24e-3)
+            .with_attribute("part number", "1234")
+            .with_attribute("supplier", "ACME")
+            .with_attribute("part number", "5678");
+
+        assert_eq!(attributes.material(), Some("PLA"));
+        assert_eq!(attributes.density(), Some(1.24e-3));
+        assert_eq!(
+            attributes.metadata().collect::<Vec<_>>(),
+            vec![("supplier", "ACME"), ("part number", "5678")]
+        );
+
+        let attributes = Attributes::new();
+        assert_eq!(attributes.material(), None);
+        assert_eq!(attributes.density(), None);
+    }
+}
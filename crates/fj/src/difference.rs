@@ -0,0 +1,30 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Shape;
+
+/// The difference of two 3-dimensional shapes
+///
+/// Contains everything of the first shape, that isn't also contained in the
+/// second one.
+///
+/// # Limitations
+///
+/// Only shapes that are bounded by planar faces with straight edges are
+/// currently supported. Processing other shapes results in an error.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Difference {
+    /// The shape to subtract from
+    pub a: Shape,
+
+    /// The shape that is subtracted
+    pub b: Shape,
+}
+
+impl From<Difference> for Shape {
+    fn from(shape: Difference) -> Self {
+        Self::Difference(Box::new(shape))
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{abi::ffi_safe, Group, Shape};
+
+/// The convex hull of a group of shapes
+///
+/// The hull is the smallest convex shape that contains all of the shapes. The
+/// shapes may be 2- or 3-dimensional, as long as they don't all lie in a
+/// common plane.
+///
+/// A [`Group`] can be turned into a hull of its shapes, like OpenSCAD's
+/// `hull()`, which hulls all of its children.
+///
+/// # Limitations
+///
+/// The hull is bounded by planar faces. Where the shapes are curved, the hull
+/// is based on an approximation of them.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Hull {
+    shapes: ffi_safe::Vec<Shape>,
+}
+
+impl Hull {
+    /// Create the hull of the provided shapes
+    pub fn from_shapes(shapes: Vec<Shape>) -> Self {
+        Self {
+            shapes: shapes.into(),
+        }
+    }
+
+    /// Access the shapes that are contained in the hull
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+}
+
+impl From<Hull> for Shape {
+    fn from(shape: Hull) -> Self {
+        Self::Hull(shape)
+    }
+}
+
+impl From<Group> for Hull {
+    fn from(group: Group) -> Self {
+        let mut shapes = Vec::new();
+        flatten(group.a, &mut shapes);
+        flatten(group.b, &mut shapes);
+
+        Self::from_shapes(shapes)
+    }
+}
+
+/// Add the shape to `shapes`, or all of its shapes, if it is a group
+///
+/// The hull of a group is the same as the hull of its shapes, but a flat list
+/// of shapes is easier to inspect.
+fn flatten(shape: Shape, shapes: &mut Vec<Shape>) {
+    match shape {
+        Shape::Group(group) => {
+            let Group { a, b } = *group;
+            flatten(a, shapes);
+            flatten(b, shapes);
+        }
+        shape => shapes.push(shape),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{syntax::*, Group, Shape, Sketch};
+
+    use super::Hull;
+
+    #[test]
+    fn test_hull_from_group() {
+        let a: Shape = Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]])
+            .sweep([0., 0., 1.])
+            .into();
+        let b: Shape = a.translate([2., 0., 0.]).into();
+        let c: Shape = a.translate([0., 2., 0.]).into();
+
+        let group = Group {
+            a: a.clone(),
+            b: Group {
+                a: b.clone(),
+                b: c.clone(),
+            }
+            .into(),
+        };
+        let hull = Hull::from(group);
+
+        assert_eq!(hull.shapes(), &[a, b, c]);
+    }
+}
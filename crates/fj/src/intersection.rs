@@ -0,0 +1,29 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Shape;
+
+/// The intersection of two 3-dimensional shapes
+///
+/// Contains everything that is contained in both of the shapes.
+///
+/// # Limitations
+///
+/// Only shapes that are bounded by planar faces with straight edges are
+/// currently supported. Processing other shapes results in an error.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Intersection {
+    /// The first of the shapes
+    pub a: Shape,
+
+    /// The second of the shapes
+    pub b: Shape,
+}
+
+impl From<Intersection> for Shape {
+    fn from(shape: Intersection) -> Self {
+        Self::Intersection(Box::new(shape))
+    }
+}
@@ -23,14 +23,46 @@ pub mod syntax;
 #[doc(hidden)]
 pub mod abi;
 mod angle;
+mod assembly;
+mod attributes;
+mod difference;
 mod group;
+mod hull;
+mod intersection;
+mod loft;
+mod minkowski;
+mod mirror;
 pub mod models;
+mod paint;
+mod pattern;
+mod revolve;
 mod shape_2d;
+mod shell;
 mod sweep;
+mod text;
 mod transform;
+mod union;
 
 pub use self::{
-    angle::*, group::Group, shape_2d::*, sweep::Sweep, transform::Transform,
+    angle::*,
+    assembly::{Assembly, Part},
+    attributes::{Attributed, Attributes},
+    difference::Difference,
+    group::Group,
+    hull::Hull,
+    intersection::Intersection,
+    loft::{Loft, LoftSection},
+    minkowski::{Convex, Minkowski},
+    mirror::Mirror,
+    paint::Paint,
+    pattern::{CircularPattern, LinearPattern},
+    revolve::Revolve,
+    shape_2d::*,
+    shell::Shell,
+    sweep::{Sweep, SweepPath, SweepSegment, SweepSegments},
+    text::{Font, Text},
+    transform::Transform,
+    union::Union,
 };
 pub use fj_proc::*;
 #[cfg(feature = "serde")]
@@ -42,15 +74,57 @@ use serde::{Deserialize, Serialize};
 #[repr(C)]
 #[allow(improper_ctypes)] // Box isn't FFI-safe
 pub enum Shape {
+    /// An assembly of named parts
+    Assembly(Assembly),
+
+    /// A 3-dimensional shape, with attributes like its material
+    Attributed(Box<Attributed>),
+
+    /// Copies of a 3-dimensional shape, arranged around an axis
+    CircularPattern(Box<CircularPattern>),
+
+    /// The difference of two 3-dimensional shapes
+    Difference(Box<Difference>),
+
     /// A group of two 3-dimensional shapes
     Group(Box<Group>),
 
+    /// The convex hull of a group of shapes
+    Hull(Hull),
+
+    /// The intersection of two 3-dimensional shapes
+    Intersection(Box<Intersection>),
+
+    /// Copies of a 3-dimensional shape, arranged along a straight line
+    LinearPattern(Box<LinearPattern>),
+
+    /// A loft between a sequence of sketches
+    Loft(Loft),
+
+    /// The Minkowski sum of a 3-dimensional shape and a convex shape
+    Minkowski(Box<Minkowski>),
+
+    /// A 3-dimensional shape, mirrored across a plane
+    Mirror(Box<Mirror>),
+
+    /// A 3-dimensional shape, with all of its faces in one color
+    Paint(Box<Paint>),
+
+    /// A revolution of a 2-dimensional shape around an axis
+    Revolve(Revolve),
+
     /// A 2D shape
     Shape2d(Shape2d),
 
-    /// A sweep of 2-dimensional shape along the z-axis
+    /// A hollowed 3-dimensional shape
+    Shell(Box<Shell>),
+
+    /// A sweep of 2-dimensional shape along a path
     Sweep(Sweep),
 
     /// A transformed 3-dimensional shape
     Transform(Box<Transform>),
+
+    /// The union of two 3-dimensional shapes
+    Union(Box<Union>),
 }
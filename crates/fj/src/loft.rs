@@ -0,0 +1,62 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{abi::ffi_safe, Angle, Shape, Sketch};
+
+/// A loft between a sequence of sketches
+///
+/// The sketches are placed in 3-dimensional space, and connected in order.
+/// The first and last of them cap the ends of the resulting shape.
+///
+/// # Limitations
+///
+/// Only sketches made from points are currently supported, and all of them
+/// need to have the same number of points. Points are connected in order,
+/// starting with the first point of each sketch.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Loft {
+    sections: ffi_safe::Vec<LoftSection>,
+}
+
+impl Loft {
+    /// Create a loft that connects the provided sections, in order
+    pub fn from_sections(sections: Vec<LoftSection>) -> Self {
+        Self {
+            sections: sections.into(),
+        }
+    }
+
+    /// Access the sketches that are connected, in order
+    pub fn sections(&self) -> &[LoftSection] {
+        &self.sections
+    }
+}
+
+impl From<Loft> for Shape {
+    fn from(shape: Loft) -> Self {
+        Self::Loft(shape)
+    }
+}
+
+/// A sketch that is part of a [`Loft`]
+///
+/// The sketch is placed in 3-dimensional space, by rotating it, followed by a
+/// translation.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct LoftSection {
+    /// The sketch
+    pub sketch: Sketch,
+
+    /// The axis of the rotation
+    pub axis: [f64; 3],
+
+    /// The angle of the rotation
+    pub angle: Angle,
+
+    /// The offset of the translation
+    pub offset: [f64; 3],
+}
@@ -0,0 +1,86 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{abi::ffi_safe, Shape};
+
+/// The Minkowski sum of a 3-dimensional shape and a convex shape
+///
+/// The result contains all points that can be reached by adding a point of
+/// the convex shape to a point of the shape. Adding a sphere rounds off all
+/// edges and corners of the shape, while growing it by the radius of the
+/// sphere.
+///
+/// # Limitations
+///
+/// The result is bounded by planar faces. Where the shapes are curved, it is
+/// based on an approximation of them. Shapes that aren't convex take much
+/// longer to compute than convex ones.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Minkowski {
+    /// The shape that the convex shape is added to
+    pub shape: Shape,
+
+    /// The convex shape that is added
+    pub convex: Convex,
+}
+
+impl From<Minkowski> for Shape {
+    fn from(shape: Minkowski) -> Self {
+        Self::Minkowski(Box::new(shape))
+    }
+}
+
+/// A convex shape that is added to a shape by [`Minkowski`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum Convex {
+    /// A sphere that is centered on the origin
+    Sphere {
+        /// The radius of the sphere
+        radius: f64,
+    },
+
+    /// The convex hull of a set of points
+    ///
+    /// See [`Convex::polyhedron`], for constructing this variant from a
+    /// regular `Vec`.
+    Polyhedron {
+        /// The points
+        points: ffi_safe::Vec<[f64; 3]>,
+    },
+}
+
+impl Convex {
+    /// Construct the convex hull of a set of points
+    pub fn polyhedron(points: Vec<[f64; 3]>) -> Self {
+        Self::Polyhedron {
+            points: points.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_convex_serialize_loopback() {
+        use serde_json::{from_str, to_string};
+
+        use super::Convex;
+
+        let convex = Convex::polyhedron(vec![[0., 0., 0.], [1., 2., 3.]]);
+
+        let json = to_string(&convex).expect("failed to serialize convex");
+        assert_eq!(
+            json,
+            r#"{"Polyhedron":{"points":[[0.0,0.0,0.0],[1.0,2.0,3.0]]}}"#
+        );
+
+        let convex_de: Convex =
+            from_str(&json).expect("failed to deserialize convex");
+        assert_eq!(convex, convex_de);
+    }
+}
@@ -0,0 +1,30 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Shape;
+
+/// A 3-dimensional shape, mirrored across a plane
+///
+/// The plane is defined by a point on it, and its normal. The faces of the
+/// mirrored shape keep facing outward, so it can be combined with other shapes
+/// like any other shape. To model a symmetric part, model one half of it, then
+/// combine it with its mirror image.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Mirror {
+    /// The shape being mirrored
+    pub shape: Shape,
+
+    /// A point on the plane
+    pub origin: [f64; 3],
+
+    /// The normal of the plane
+    pub normal: [f64; 3],
+}
+
+impl From<Mirror> for Shape {
+    fn from(shape: Mirror) -> Self {
+        Self::Mirror(Box::new(shape))
+    }
+}
@@ -1,3 +1,5 @@
+use super::Error;
+
 /// Information about a particular module that can be used by the host for
 /// things like introspection and search.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -176,6 +178,12 @@ pub struct ArgumentMetadata {
     pub description: Option<String>,
     /// Something that could be used as a default if no value was provided.
     pub default_value: Option<String>,
+    /// The smallest value this argument accepts, if the model declares one.
+    pub minimum: Option<String>,
+    /// The largest value this argument accepts, if the model declares one.
+    pub maximum: Option<String>,
+    /// The kind of value this argument accepts.
+    pub kind: ArgumentKind,
 }
 
 impl ArgumentMetadata {
@@ -191,6 +199,9 @@ impl ArgumentMetadata {
             name,
             description: None,
             default_value: None,
+            minimum: None,
+            maximum: None,
+            kind: ArgumentKind::Any,
         }
     }
 
@@ -213,6 +224,132 @@ impl ArgumentMetadata {
         self.default_value = Some(default_value.into());
         self
     }
+
+    /// Set the [`ArgumentMetadata::minimum`].
+    pub fn with_minimum(mut self, minimum: impl Into<String>) -> Self {
+        self.minimum = Some(minimum.into());
+        self
+    }
+
+    /// Set the [`ArgumentMetadata::maximum`].
+    pub fn with_maximum(mut self, maximum: impl Into<String>) -> Self {
+        self.maximum = Some(maximum.into());
+        self
+    }
+
+    /// Set the [`ArgumentMetadata::kind`].
+    pub fn with_kind(mut self, kind: ArgumentKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Check whether `value` is valid for this argument.
+    ///
+    /// The value must be of the [`ArgumentMetadata::kind`] of the argument,
+    /// and within its minimum and maximum. Bounds that aren't numbers (for
+    /// example, because they depend on other arguments) can only be checked by
+    /// the model itself, and are ignored.
+    pub fn validate(&self, value: &str) -> Result<(), Error> {
+        let value = value.trim();
+
+        match &self.kind {
+            ArgumentKind::Any => {}
+            ArgumentKind::Bool => {
+                value
+                    .parse::<bool>()
+                    .map_err(|_| "Expected `true` or `false`")?;
+            }
+            ArgumentKind::Integer => {
+                value.parse::<i64>().map_err(|_| "Expected an integer")?;
+            }
+            ArgumentKind::Number => {
+                value.parse::<f64>().map_err(|_| "Expected a number")?;
+            }
+            ArgumentKind::Choice(choices) => {
+                if !choices.iter().any(|choice| choice == value) {
+                    return Err(format!(
+                        "Expected one of {}",
+                        choices.join(", ")
+                    )
+                    .into());
+                }
+            }
+        }
+
+        let number = match value.parse::<f64>() {
+            Ok(number) => number,
+            Err(_) => return Ok(()),
+        };
+
+        if let Some(minimum) = parse_bound(&self.minimum) {
+            if number < minimum {
+                return Err(format!("Expected at least {minimum}").into());
+            }
+        }
+        if let Some(maximum) = parse_bound(&self.maximum) {
+            if number > maximum {
+                return Err(format!("Expected at most {maximum}").into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Move a numeric `value` into the range of this argument
+    ///
+    /// Returns the nearest bound, if `value` is outside of the minimum and
+    /// maximum. Values that aren't numbers, and bounds that can't be checked
+    /// (see [`ArgumentMetadata::validate`]), leave `value` unchanged.
+    pub fn clamp(&self, value: &str) -> String {
+        let number = match value.trim().parse::<f64>() {
+            Ok(number) => number,
+            Err(_) => return value.to_string(),
+        };
+
+        if let Some(minimum) = parse_bound(&self.minimum) {
+            if number < minimum {
+                return minimum.to_string();
+            }
+        }
+        if let Some(maximum) = parse_bound(&self.maximum) {
+            if number > maximum {
+                return maximum.to_string();
+            }
+        }
+
+        value.to_string()
+    }
+}
+
+fn parse_bound(bound: &Option<String>) -> Option<f64> {
+    // Bounds that come from the `#[fj::model]` macro are stringified
+    // expressions, so a negative number might show up as `- 1.0`.
+    let bound = bound.as_deref()?.split_whitespace().collect::<String>();
+    bound.parse::<f64>().ok()
+}
+
+/// The kind of value that a model's argument accepts.
+///
+/// Hosts can use this to present a suitable control for editing the argument,
+/// and to reject invalid values before passing them to the model.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ArgumentKind {
+    /// Any value that the model can parse.
+    Any,
+    /// Either `true` or `false`.
+    Bool,
+    /// A whole number, like `3` or `-1`.
+    Integer,
+    /// Any number, like `1.5`.
+    Number,
+    /// One of a fixed set of values, like the variants of an enum.
+    Choice(Vec<String>),
+}
+
+impl Default for ArgumentKind {
+    fn default() -> Self {
+        Self::Any
+    }
 }
 
 impl From<&str> for ArgumentMetadata {
@@ -220,3 +357,55 @@ impl From<&str> for ArgumentMetadata {
         ArgumentMetadata::new(name)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ArgumentKind, ArgumentMetadata};
+
+    #[test]
+    fn validate_argument() {
+        let argument = ArgumentMetadata::new("sides")
+            .with_kind(ArgumentKind::Integer)
+            .with_minimum("3")
+            .with_maximum("sides_max");
+
+        assert!(argument.validate("5").is_ok());
+        assert!(argument.validate("5.5").is_err());
+        assert!(argument.validate("2").is_err());
+
+        let argument = ArgumentMetadata::new("offset")
+            .with_kind(ArgumentKind::Number)
+            .with_minimum("- 1.0");
+
+        assert!(argument.validate("-0.5").is_ok());
+        assert!(argument.validate("-1.5").is_err());
+
+        let argument = ArgumentMetadata::new("style").with_kind(
+            ArgumentKind::Choice(vec!["round".into(), "square".into()]),
+        );
+
+        assert!(argument.validate("round").is_ok());
+        assert!(argument.validate("triangular").is_err());
+    }
+
+    #[test]
+    fn clamp_argument() {
+        let argument = ArgumentMetadata::new("sides")
+            .with_kind(ArgumentKind::Integer)
+            .with_minimum("3")
+            .with_maximum("sides_max");
+
+        assert_eq!(argument.clamp("5"), "5");
+        assert_eq!(argument.clamp("2"), "3");
+        assert_eq!(argument.clamp("100"), "100");
+
+        let argument = ArgumentMetadata::new("offset")
+            .with_kind(ArgumentKind::Number)
+            .with_minimum("- 1.0")
+            .with_maximum("0.5");
+
+        assert_eq!(argument.clamp("-1.5"), "-1");
+        assert_eq!(argument.clamp("0.75"), "0.5");
+        assert_eq!(argument.clamp("auto"), "auto");
+    }
+}
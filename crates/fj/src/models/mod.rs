@@ -10,7 +10,7 @@ pub use self::{
         Context, ContextError, ContextExt, MissingArgument, ParseFailed,
     },
     host::{Host, HostExt},
-    metadata::{ArgumentMetadata, Metadata, ModelMetadata},
+    metadata::{ArgumentKind, ArgumentMetadata, Metadata, ModelMetadata},
     model::Model,
 };
 
@@ -0,0 +1,28 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Shape;
+
+/// A 3-dimensional shape, with all of its faces in one color
+///
+/// Overrides the colors that the faces of the shape had before. The faces keep
+/// the new color through any further operations, so different parts of a model
+/// can be colored differently. In the difference of two shapes, for example,
+/// the faces that the second shape cuts out of the first one have the color of
+/// the second shape.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Paint {
+    /// The shape being painted
+    pub shape: Shape,
+
+    /// The color of the shape's faces in RGBA
+    pub color: [u8; 4],
+}
+
+impl From<Paint> for Shape {
+    fn from(shape: Paint) -> Self {
+        Self::Paint(Box::new(shape))
+    }
+}
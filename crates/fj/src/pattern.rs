@@ -0,0 +1,73 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Angle, Shape};
+
+/// Copies of a 3-dimensional shape, arranged along a straight line
+///
+/// The first copy is the shape itself. Each further copy is offset from the
+/// previous one by `offset`.
+///
+/// # Limitations
+///
+/// Like the shapes in a [`Group`], the copies are not allowed to touch or
+/// overlap. This is not currently checked.
+///
+/// [`Group`]: crate::Group
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct LinearPattern {
+    /// The shape being copied
+    pub shape: Shape,
+
+    /// The number of copies, including the original shape
+    pub count: u32,
+
+    /// The offset between neighboring copies
+    pub offset: [f64; 3],
+}
+
+impl From<LinearPattern> for Shape {
+    fn from(shape: LinearPattern) -> Self {
+        Self::LinearPattern(Box::new(shape))
+    }
+}
+
+/// Copies of a 3-dimensional shape, arranged around an axis
+///
+/// The axis passes through the origin. The first copy is the shape itself.
+/// Each further copy is rotated from the previous one by `angle`, following the
+/// right-hand rule around the direction of the axis.
+///
+/// To spread the copies evenly around the axis, as for a bolt circle, use an
+/// angle of a full turn divided by the number of copies.
+///
+/// # Limitations
+///
+/// Like the shapes in a [`Group`], the copies are not allowed to touch or
+/// overlap. This is not currently checked.
+///
+/// [`Group`]: crate::Group
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct CircularPattern {
+    /// The shape being copied
+    pub shape: Shape,
+
+    /// The number of copies, including the original shape
+    pub count: u32,
+
+    /// The direction of the axis
+    pub axis: [f64; 3],
+
+    /// The angle between neighboring copies
+    pub angle: Angle,
+}
+
+impl From<CircularPattern> for Shape {
+    fn from(shape: CircularPattern) -> Self {
+        Self::CircularPattern(Box::new(shape))
+    }
+}
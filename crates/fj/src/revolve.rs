@@ -0,0 +1,40 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Angle, Shape, Shape2d};
+
+/// A revolution of a 2-dimensional shape around an axis
+///
+/// The axis passes through the origin. The shape is revolved by `angle`,
+/// following the right-hand rule around the direction of the axis. Unless that
+/// is a full turn, the result is capped by the shape at the start and end
+/// angle.
+///
+/// [`Angle`] wraps around after a full turn, so an angle of zero results in a
+/// full turn. This makes `Angle::from_deg(360.)` work as expected.
+///
+/// # Limitations
+///
+/// Only sketches made from points are currently supported, and they must not
+/// touch the axis.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Revolve {
+    /// The 2-dimensional shape being revolved
+    pub shape: Shape2d,
+
+    /// The direction of the axis
+    pub axis: [f64; 3],
+
+    /// The angle of the revolution
+    ///
+    /// An angle of zero results in a full turn.
+    pub angle: Angle,
+}
+
+impl From<Revolve> for Shape {
+    fn from(shape: Revolve) -> Self {
+        Self::Revolve(shape)
+    }
+}
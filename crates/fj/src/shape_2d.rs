@@ -3,7 +3,7 @@ use serde::{de, ser, Deserialize, Serialize};
 use std::mem;
 use std::sync::atomic;
 
-use crate::Shape;
+use crate::{abi::ffi_safe, Angle, Shape, Text};
 
 /// A 2-dimensional shape
 #[derive(Clone, Debug, PartialEq)]
@@ -13,8 +13,20 @@ pub enum Shape2d {
     /// A difference between two shapes
     Difference(Box<Difference2d>),
 
+    /// An intersection of two shapes
+    Intersection(Box<Intersection2d>),
+
+    /// A sketch that is offset outward or inward
+    Offset(Box<Offset2d>),
+
     /// A sketch
     Sketch(Sketch),
+
+    /// A line of text
+    Text(Text),
+
+    /// A union of two shapes
+    Union(Box<Union2d>),
 }
 
 impl Shape2d {
@@ -23,6 +35,10 @@ impl Shape2d {
         match &self {
             Shape2d::Sketch(s) => s.color(),
             Shape2d::Difference(d) => d.color(),
+            Shape2d::Intersection(i) => i.color(),
+            Shape2d::Offset(o) => o.color(),
+            Shape2d::Text(t) => t.color(),
+            Shape2d::Union(u) => u.color(),
         }
     }
 }
@@ -64,11 +80,204 @@ impl From<Difference2d> for Shape2d {
     }
 }
 
+/// A union of two shapes
+///
+/// Where the shapes overlap or touch, they are merged into a single region.
+///
+/// # Limitations
+///
+/// Only shapes with straight edges are currently supported. Processing shapes
+/// with circles or arcs results in an error.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Union2d {
+    shapes: [Shape2d; 2],
+}
+
+impl Union2d {
+    /// Create a `Union2d` from two shapes
+    pub fn from_shapes(shapes: [Shape2d; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Get the rendering color of the first object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes[0].color()
+    }
+
+    /// Access the shapes that make up the union
+    pub fn shapes(&self) -> &[Shape2d; 2] {
+        &self.shapes
+    }
+}
+
+impl From<Union2d> for Shape {
+    fn from(shape: Union2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Union2d> for Shape2d {
+    fn from(shape: Union2d) -> Self {
+        Self::Union(Box::new(shape))
+    }
+}
+
+/// An intersection of two shapes
+///
+/// Only the regions where both shapes overlap are kept.
+///
+/// # Limitations
+///
+/// Only shapes with straight edges are currently supported. Processing shapes
+/// with circles or arcs results in an error.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Intersection2d {
+    shapes: [Shape2d; 2],
+}
+
+impl Intersection2d {
+    /// Create an `Intersection2d` from two shapes
+    pub fn from_shapes(shapes: [Shape2d; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Get the rendering color of the first object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes[0].color()
+    }
+
+    /// Access the shapes that make up the intersection
+    pub fn shapes(&self) -> &[Shape2d; 2] {
+        &self.shapes
+    }
+}
+
+impl From<Intersection2d> for Shape {
+    fn from(shape: Intersection2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Intersection2d> for Shape2d {
+    fn from(shape: Intersection2d) -> Self {
+        Self::Intersection(Box::new(shape))
+    }
+}
+
+/// A sketch that is offset outward or inward
+///
+/// Every point of the outline of the sketch moves along its normal by the
+/// offset distance. A positive distance grows the sketch, a negative one
+/// shrinks it. This is useful for clearances between parts, or to derive the
+/// inner outline of a wall from the outer one.
+///
+/// Where the outline moves away from a sharp corner, the gap is closed as
+/// specified by [`OffsetJoin`]. Rounded corners of the sketch stay rounded,
+/// with their radius adjusted by the offset distance.
+///
+/// # Limitations
+///
+/// Only sketches made from points and circles are currently supported.
+/// Processing an offset of a sketch made from segments results in an error.
+///
+/// The distance must not be so large that edges of the sketch vanish. This
+/// also results in an error when the offset is processed.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Offset2d {
+    sketch: Sketch,
+    distance: f64,
+    join: OffsetJoin,
+}
+
+impl Offset2d {
+    /// Create an `Offset2d` from a sketch and the offset distance
+    ///
+    /// Gaps at sharp corners are closed with arcs. Use
+    /// [`Offset2d::with_join`] to change that.
+    pub fn from_sketch(sketch: Sketch, distance: f64) -> Self {
+        Self {
+            sketch,
+            distance,
+            join: OffsetJoin::Round,
+        }
+    }
+
+    /// Set how gaps at sharp corners are closed
+    pub fn with_join(mut self, join: OffsetJoin) -> Self {
+        self.join = join;
+        self
+    }
+
+    /// Get the rendering color of the sketch in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.sketch.color()
+    }
+
+    /// Access the sketch that is offset
+    pub fn sketch(&self) -> &Sketch {
+        &self.sketch
+    }
+
+    /// Access the offset distance
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Access how gaps at sharp corners are closed
+    pub fn join(&self) -> OffsetJoin {
+        self.join
+    }
+}
+
+impl From<Offset2d> for Shape {
+    fn from(shape: Offset2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Offset2d> for Shape2d {
+    fn from(shape: Offset2d) -> Self {
+        Self::Offset(Box::new(shape))
+    }
+}
+
+/// How an [`Offset2d`] closes the gaps at sharp corners
+///
+/// Gaps only form where the outline moves away from a corner, which is at
+/// convex corners when growing a sketch, and at concave ones when shrinking it.
+/// Elsewhere, the offset edges are trimmed to where they meet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum OffsetJoin {
+    /// The gap is closed by an arc around the original corner
+    ///
+    /// Every point of the offset outline is at exactly the offset distance
+    /// from the sketch.
+    Round,
+
+    /// The offset edges are extended until they meet
+    ///
+    /// This keeps the corners sharp. Note that the corner moves farther than
+    /// the offset distance, the sharper it is.
+    Miter,
+}
+
 /// A sketch
 ///
-/// Sketches are currently limited to a single cycle of straight lines,
-/// represented by a number of points. For example, if the points a, b, and c
-/// are provided, the edges ab, bc, and ca are assumed.
+/// Sketches are currently limited to a single cycle. It can be a circle, a
+/// cycle of straight lines, or a cycle of [`Segment`]s, which can be lines,
+/// arcs, and Bézier curves.
+///
+/// A cycle of straight lines is represented by a number of points. For
+/// example, if the points a, b, and c are provided, the edges ab, bc, and ca
+/// are assumed.
 ///
 /// Nothing about these edges is checked right now, but algorithms might assume
 /// that the edges are non-overlapping. If you create a `Sketch` with
@@ -81,6 +290,15 @@ pub struct Sketch {
 
     // The color of the sketch in RGBA
     color: [u8; 4],
+
+    // The label of the sketch; empty, if it has none
+    #[cfg_attr(feature = "serde", serde(default))]
+    label: ffi_safe::String,
+
+    // The shape of the corners of a sketch made from points; empty, if all of
+    // them are sharp
+    #[cfg_attr(feature = "serde", serde(default))]
+    corners: ffi_safe::Vec<Corner>,
 }
 
 impl Sketch {
@@ -89,6 +307,20 @@ impl Sketch {
         Self {
             chain: Chain::PolyChain(PolyChain::from_points(points)),
             color: [255, 0, 0, 255],
+            label: ffi_safe::String::default(),
+            corners: ffi_safe::Vec::default(),
+        }
+    }
+
+    /// Create a sketch from a closed loop of segments
+    ///
+    /// See [`SegmentChain`].
+    pub fn from_segments(segments: Vec<Segment>) -> Self {
+        Self {
+            chain: Chain::Segments(SegmentChain::from_segments(segments)),
+            color: [255, 0, 0, 255],
+            label: ffi_safe::String::default(),
+            corners: ffi_safe::Vec::default(),
         }
     }
 
@@ -97,6 +329,8 @@ impl Sketch {
         Self {
             chain: Chain::Circle(circle),
             color: [255, 0, 0, 255],
+            label: ffi_safe::String::default(),
+            corners: ffi_safe::Vec::default(),
         }
     }
 
@@ -106,6 +340,42 @@ impl Sketch {
         self
     }
 
+    /// Set the label of the sketch
+    ///
+    /// The label identifies the sketch in error messages, for example if its
+    /// edges intersect each other.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into().into();
+        self
+    }
+
+    /// Set the shape of the corners of the sketch
+    ///
+    /// Only applies to sketches made from points. Corner `i` is at point `i`.
+    /// Corners for which no shape is provided stay sharp.
+    pub fn with_corners(mut self, corners: Vec<Corner>) -> Self {
+        self.corners = corners.into();
+        self
+    }
+
+    /// Round the corners of the sketch, using the given radii
+    ///
+    /// A radius of zero leaves the corner sharp. See [`Sketch::with_corners`].
+    pub fn with_corner_radius(self, radii: Vec<f64>) -> Self {
+        let corners = radii
+            .into_iter()
+            .map(|radius| {
+                if radius == 0. {
+                    Corner::Sharp
+                } else {
+                    Corner::Round { radius }
+                }
+            })
+            .collect();
+
+        self.with_corners(corners)
+    }
+
     /// Access the chain of the sketch
     pub fn chain(&self) -> &Chain {
         &self.chain
@@ -115,6 +385,46 @@ impl Sketch {
     pub fn color(&self) -> [u8; 4] {
         self.color
     }
+
+    /// Access the label of the sketch, if it has one
+    pub fn label(&self) -> Option<&str> {
+        if self.label.is_empty() {
+            return None;
+        }
+
+        Some(&self.label)
+    }
+
+    /// Access the shape of the corners of the sketch
+    ///
+    /// See [`Sketch::with_corners`].
+    pub fn corners(&self) -> &[Corner] {
+        &self.corners
+    }
+}
+
+/// The shape of a corner of a [`Sketch`]
+///
+/// The edges next to a corner are trimmed, to make room for the rounded or
+/// chamfered corner. They must be long enough for that.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum Corner {
+    /// The corner is left as it is
+    Sharp,
+
+    /// The corner is replaced by an arc that is tangent to both edges
+    Round {
+        /// The radius of the arc
+        radius: f64,
+    },
+
+    /// The corner is cut off by a straight line
+    Chamfer {
+        /// The distance from the corner, at which both edges are cut
+        distance: f64,
+    },
 }
 
 /// A chain of elements that is part of a [`Sketch`]
@@ -127,6 +437,9 @@ pub enum Chain {
 
     /// The chain is a polygonal chain
     PolyChain(PolyChain),
+
+    /// The chain is a closed loop of segments
+    Segments(SegmentChain),
 }
 
 /// A circle that is part of a [`Sketch`]
@@ -150,6 +463,134 @@ impl Circle {
     }
 }
 
+/// A closed loop of segments that is part of a [`Sketch`]
+///
+/// Each segment runs from the end of the previous one to its own end. The
+/// first segment starts at the end of the last one.
+#[derive(Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct SegmentChain {
+    segments: ffi_safe::Vec<Segment>,
+}
+
+impl SegmentChain {
+    /// Construct an instance from a list of segments
+    pub fn from_segments(segments: Vec<Segment>) -> Self {
+        Self {
+            segments: segments.into(),
+        }
+    }
+
+    /// Access the segments that make up the chain
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::Serialize for SegmentChain {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.segments().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> de::Deserialize<'de> for SegmentChain {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(SegmentChain::from_segments)
+    }
+}
+
+/// A segment of a [`SegmentChain`]
+///
+/// Each segment ends at a point. It starts at the end of the previous segment.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum Segment {
+    /// A straight line
+    LineTo {
+        /// The point where the line ends
+        end: [f64; 2],
+    },
+
+    /// A circular arc
+    ///
+    /// The arc runs counter-clockwise from its start to its end. A positive
+    /// `radius` results in the shorter of the two possible arcs, a negative
+    /// one in the longer one.
+    ///
+    /// The start and end of the arc must not be further apart than its
+    /// diameter.
+    ArcTo {
+        /// The point where the arc ends
+        end: [f64; 2],
+
+        /// The radius of the arc
+        radius: f64,
+    },
+
+    /// A cubic Bézier curve
+    ///
+    /// The curve starts and ends at its ends, and is pulled towards the two
+    /// control points in between, without passing through them. It is
+    /// tangent to the line towards the first control point at its start, and
+    /// to the line from the second control point at its end.
+    BezierTo {
+        /// The two control points between the start and the end
+        control_points: [[f64; 2]; 2],
+
+        /// The point where the curve ends
+        end: [f64; 2],
+    },
+}
+
+impl Segment {
+    /// Create a circular arc from its center and the angles of its ends
+    ///
+    /// The arc runs counter-clockwise from `start` to `end`, with the angles
+    /// measured counter-clockwise from the positive x-axis. The previous
+    /// segment must end at the start of the arc.
+    pub fn arc_from_center_and_angles(
+        center: [f64; 2],
+        radius: f64,
+        start: Angle,
+        end: Angle,
+    ) -> Self {
+        let [x, y] = center;
+        let end_point =
+            [x + radius * end.rad().cos(), y + radius * end.rad().sin()];
+
+        // `Angle` is always wrapped into the range of a single turn, so this is
+        // the counter-clockwise angle from start to end.
+        let radius = if (end - start).rad() <= std::f64::consts::PI {
+            radius
+        } else {
+            -radius
+        };
+
+        Self::ArcTo {
+            end: end_point,
+            radius,
+        }
+    }
+
+    /// Access the point where the segment ends
+    pub fn end(&self) -> [f64; 2] {
+        match self {
+            Self::LineTo { end }
+            | Self::ArcTo { end, .. }
+            | Self::BezierTo { end, .. } => *end,
+        }
+    }
+}
+
 /// A polygonal chain that is part of a [`Sketch`]
 #[derive(Debug)]
 #[repr(C)]
@@ -376,4 +817,90 @@ mod tests {
         // ensure same content
         assert_eq!(poly_chain.to_points(), poly_chain_de.to_points());
     }
+
+    #[test]
+    fn test_corner_radius() {
+        let sketch = Sketch::from_points(test_points())
+            .with_corner_radius(vec![0., 0.5]);
+
+        assert_eq!(
+            sketch.corners(),
+            [Corner::Sharp, Corner::Round { radius: 0.5 }]
+        );
+        assert!(Sketch::from_points(test_points()).corners().is_empty());
+    }
+
+    #[test]
+    fn test_arc_from_center_and_angles() {
+        let short = Segment::arc_from_center_and_angles(
+            [1., 1.],
+            2.,
+            Angle::from_deg(0.),
+            Angle::from_deg(90.),
+        );
+        let long = Segment::arc_from_center_and_angles(
+            [1., 1.],
+            2.,
+            Angle::from_deg(90.),
+            Angle::from_deg(0.),
+        );
+
+        match (short, long) {
+            (
+                Segment::ArcTo {
+                    end: short_end,
+                    radius: short_radius,
+                },
+                Segment::ArcTo {
+                    end: long_end,
+                    radius: long_radius,
+                },
+            ) => {
+                assert!((short_end[0] - 1.).abs() < 1e-12);
+                assert!((short_end[1] - 3.).abs() < 1e-12);
+                assert_eq!(short_radius, 2.);
+
+                assert!((long_end[0] - 3.).abs() < 1e-12);
+                assert!((long_end[1] - 1.).abs() < 1e-12);
+                assert_eq!(long_radius, -2.);
+            }
+            _ => panic!("Expected arcs"),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_segment_chain_serialize_loopback() {
+        use serde_json::{from_str, to_string};
+
+        let sketch = Sketch::from_segments(vec![
+            Segment::LineTo { end: [1., 0.] },
+            Segment::ArcTo {
+                end: [0., 1.],
+                radius: 1.,
+            },
+            Segment::LineTo { end: [0., 0.] },
+        ]);
+
+        let json = to_string(&sketch).expect("failed to serialize sketch");
+        let sketch_de: Sketch =
+            from_str(&json).expect("failed to deserialize sketch");
+
+        assert_eq!(sketch, sketch_de);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_sketch_label_serialize_loopback() {
+        use serde_json::{from_str, to_string};
+
+        let sketch = Sketch::from_points(test_points()).with_label("base");
+
+        let json = to_string(&sketch).expect("failed to serialize sketch");
+        let sketch_de: Sketch =
+            from_str(&json).expect("failed to deserialize sketch");
+
+        assert_eq!(sketch_de.label(), Some("base"));
+        assert_eq!(Sketch::from_points(test_points()).label(), None);
+    }
 }
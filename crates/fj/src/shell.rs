@@ -0,0 +1,68 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{abi::ffi_safe, Shape};
+
+/// A 3-dimensional shape that has been hollowed out
+///
+/// The walls of the hollowed shape have a uniform thickness and are created on
+/// its inside. Faces of the shape can be removed, to create openings into the
+/// cavity.
+///
+/// # Limitations
+///
+/// Only shapes that are bounded by planar faces with straight edges are
+/// currently supported.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Shell {
+    /// The shape being hollowed out
+    shape: Shape,
+
+    /// The thickness of the walls
+    thickness: f64,
+
+    /// The normals of the faces that are removed
+    openings: ffi_safe::Vec<[f64; 3]>,
+}
+
+impl Shell {
+    /// Create a `Shell` with walls of the given thickness
+    pub fn from_shape(shape: Shape, thickness: f64) -> Self {
+        Self {
+            shape,
+            thickness,
+            openings: ffi_safe::Vec::default(),
+        }
+    }
+
+    /// Remove all faces whose normals point in the given direction
+    pub fn with_opening(mut self, normal: [f64; 3]) -> Self {
+        let mut openings: Vec<_> = self.openings.into();
+        openings.push(normal);
+        self.openings = openings.into();
+        self
+    }
+
+    /// Access the shape being hollowed out
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the thickness of the walls
+    pub fn thickness(&self) -> f64 {
+        self.thickness
+    }
+
+    /// Access the normals of the faces that are removed
+    pub fn openings(&self) -> &[[f64; 3]] {
+        &self.openings
+    }
+}
+
+impl From<Shell> for Shape {
+    fn from(shape: Shell) -> Self {
+        Self::Shell(Box::new(shape))
+    }
+}
@@ -1,9 +1,9 @@
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{de, ser, Deserialize, Serialize};
 
-use crate::{Shape, Shape2d};
+use crate::{abi::ffi_safe, Angle, Shape, Shape2d};
 
-/// A sweep of a 2-dimensional shape along straight path
+/// A sweep of a 2-dimensional shape along a path
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(C)]
@@ -11,14 +11,38 @@ pub struct Sweep {
     /// The 2-dimensional shape being swept
     shape: Shape2d,
 
-    /// The length and direction of the sweep
-    path: [f64; 3],
+    /// The path of the sweep
+    path: SweepPath,
+
+    /// The draft angle of the side faces
+    draft: Angle,
 }
 
 impl Sweep {
-    /// Create a `Sweep` along a straight path
-    pub fn from_path(shape: Shape2d, path: [f64; 3]) -> Self {
-        Self { shape, path }
+    /// Create a `Sweep` along a path
+    ///
+    /// Passing an array creates a sweep along a straight path.
+    pub fn from_path(shape: Shape2d, path: impl Into<SweepPath>) -> Self {
+        let path = path.into();
+        Self {
+            shape,
+            path,
+            draft: Angle::from_rad(0.),
+        }
+    }
+
+    /// Taper the side faces by a draft angle
+    ///
+    /// The side faces are inclined towards the inside of the shape by the
+    /// given angle. A negative angle inclines them outward.
+    ///
+    /// Draft angles are only supported for sweeps along straight paths, made
+    /// from a single [`SweepPath::Line`], of sketches with straight edges.
+    /// Processing any other sweep with a draft angle, like one of a circle,
+    /// results in an error.
+    pub fn with_draft(mut self, angle: Angle) -> Self {
+        self.draft = angle;
+        self
     }
 
     /// Access the shape being swept
@@ -27,8 +51,13 @@ impl Sweep {
     }
 
     /// Access the path of the sweep
-    pub fn path(&self) -> [f64; 3] {
-        self.path
+    pub fn path(&self) -> &SweepPath {
+        &self.path
+    }
+
+    /// Access the draft angle of the side faces
+    pub fn draft(&self) -> Angle {
+        self.draft
     }
 }
 
@@ -37,3 +66,146 @@ impl From<Sweep> for Shape {
         Self::Sweep(shape)
     }
 }
+
+/// The path that a [`Sweep`] follows
+///
+/// The shape keeps its position and orientation relative to the path, while
+/// it is being swept.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum SweepPath {
+    /// A straight path, defined by its length and direction
+    Line([f64; 3]),
+
+    /// A circular arc around an axis
+    ///
+    /// The shape is rotated around the axis, following the right-hand rule
+    /// around its direction.
+    Arc {
+        /// A point on the axis
+        center: [f64; 3],
+
+        /// The direction of the axis
+        axis: [f64; 3],
+
+        /// The angle of the arc
+        angle: Angle,
+    },
+
+    /// A helix around an axis
+    ///
+    /// The shape is rotated around the axis like for [`SweepPath::Arc`], while
+    /// advancing along it. This can be used to model threads and springs.
+    Helix {
+        /// A point on the axis
+        center: [f64; 3],
+
+        /// The direction of the axis
+        axis: [f64; 3],
+
+        /// The distance the shape advances along the axis during one turn
+        pitch: f64,
+
+        /// The number of turns
+        turns: f64,
+    },
+
+    /// A path made from a number of lines and arcs, one after the other
+    ///
+    /// This can be used to model pipes, handles, and wire-like parts.
+    Segments(SweepSegments),
+}
+
+impl From<[f64; 3]> for SweepPath {
+    fn from(path: [f64; 3]) -> Self {
+        Self::Line(path)
+    }
+}
+
+impl From<Vec<SweepSegment>> for SweepPath {
+    fn from(segments: Vec<SweepSegment>) -> Self {
+        Self::Segments(SweepSegments::from_segments(segments))
+    }
+}
+
+/// The segments of a [`SweepPath::Segments`]
+///
+/// The shape is swept along each segment, starting where the previous one
+/// ended. Each segment is defined relative to the position and orientation
+/// that the shape has at its start, as if the shape hadn't been moved by the
+/// previous segments.
+///
+/// For example, a line along the z-axis that follows a quarter turn around the
+/// y-axis continues in the direction that the quarter turn ended in, which is
+/// along the x-axis.
+///
+/// # Limitations
+///
+/// Sketches with curved edges can only be swept along lines so far.
+#[derive(Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct SweepSegments {
+    segments: ffi_safe::Vec<SweepSegment>,
+}
+
+impl SweepSegments {
+    /// Construct an instance from a list of segments
+    pub fn from_segments(segments: Vec<SweepSegment>) -> Self {
+        Self {
+            segments: segments.into(),
+        }
+    }
+
+    /// Access the segments of the path
+    pub fn segments(&self) -> &[SweepSegment] {
+        &self.segments
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::Serialize for SweepSegments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.segments().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> de::Deserialize<'de> for SweepSegments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(SweepSegments::from_segments)
+    }
+}
+
+/// A segment of a [`SweepPath::Segments`]
+///
+/// See [`SweepSegments`], regarding what the segments are relative to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum SweepSegment {
+    /// A straight segment, defined by its length and direction
+    ///
+    /// See [`SweepPath::Line`].
+    Line([f64; 3]),
+
+    /// A circular arc around an axis
+    ///
+    /// See [`SweepPath::Arc`].
+    Arc {
+        /// A point on the axis
+        center: [f64; 3],
+
+        /// The direction of the axis
+        axis: [f64; 3],
+
+        /// The angle of the arc
+        angle: Angle,
+    },
+}
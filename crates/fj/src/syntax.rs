@@ -3,6 +3,30 @@
 //! This model defines extension traits, which provide convenient syntax for
 //! the various operations defined in this trait.
 
+/// Convenient syntax to create an [`fj::Attributed`]
+///
+/// [`fj::Attributed`]: crate::Attributed
+pub trait Attributed {
+    /// Attach attributes, like its material, to `self`
+    fn with_attributes(
+        &self,
+        attributes: crate::Attributes,
+    ) -> crate::Attributed;
+}
+
+impl<T> Attributed for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn with_attributes(
+        &self,
+        attributes: crate::Attributes,
+    ) -> crate::Attributed {
+        let shape = self.clone().into();
+        crate::Attributed { shape, attributes }
+    }
+}
+
 /// Convenient syntax to create an [`fj::Difference2d`]
 ///
 /// [`fj::Difference2d`]: crate::Difference2d
@@ -53,6 +77,187 @@ where
     }
 }
 
+/// Convenient syntax to create an [`fj::Hull`]
+///
+/// [`fj::Hull`]: crate::Hull
+pub trait Hull {
+    /// Create the convex hull of `self` and `other`
+    fn hull<Other>(&self, other: &Other) -> crate::Hull
+    where
+        Other: Clone + Into<crate::Shape>;
+}
+
+impl<T> Hull for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn hull<Other>(&self, other: &Other) -> crate::Hull
+    where
+        Other: Clone + Into<crate::Shape>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Hull::from_shapes(vec![a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::Intersection`]
+///
+/// [`fj::Intersection`]: crate::Intersection
+pub trait Intersection {
+    /// Create an intersection of `self` and `other`
+    fn intersection<Other>(&self, other: &Other) -> crate::Intersection
+    where
+        Other: Clone + Into<crate::Shape>;
+}
+
+impl<T> Intersection for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn intersection<Other>(&self, other: &Other) -> crate::Intersection
+    where
+        Other: Clone + Into<crate::Shape>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Intersection { a, b }
+    }
+}
+
+/// Convenient syntax to create an [`fj::Mirror`]
+///
+/// [`fj::Mirror`]: crate::Mirror
+pub trait Mirror {
+    /// Mirror `self` across the plane through `origin`, with the given normal
+    fn mirror(&self, origin: [f64; 3], normal: [f64; 3]) -> crate::Mirror;
+}
+
+impl<T> Mirror for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn mirror(&self, origin: [f64; 3], normal: [f64; 3]) -> crate::Mirror {
+        let shape = self.clone().into();
+        crate::Mirror {
+            shape,
+            origin,
+            normal,
+        }
+    }
+}
+
+/// Convenient syntax to create an [`fj::Offset2d`]
+///
+/// [`fj::Offset2d`]: crate::Offset2d
+pub trait Offset {
+    /// Offset the outline of `self` outward, or inward for negative distances
+    fn offset(&self, distance: f64) -> crate::Offset2d;
+}
+
+impl<T> Offset for T
+where
+    T: Clone + Into<crate::Sketch>,
+{
+    fn offset(&self, distance: f64) -> crate::Offset2d {
+        crate::Offset2d::from_sketch(self.clone().into(), distance)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Paint`]
+///
+/// [`fj::Paint`]: crate::Paint
+pub trait Paint {
+    /// Paint all faces of `self` in the given color, in RGBA
+    fn with_color(&self, color: [u8; 4]) -> crate::Paint;
+}
+
+impl<T> Paint for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn with_color(&self, color: [u8; 4]) -> crate::Paint {
+        let shape = self.clone().into();
+        crate::Paint { shape, color }
+    }
+}
+
+/// Convenient syntax to create an [`fj::LinearPattern`] or
+/// [`fj::CircularPattern`]
+///
+/// [`fj::LinearPattern`]: crate::LinearPattern
+/// [`fj::CircularPattern`]: crate::CircularPattern
+pub trait Pattern {
+    /// Create `count` copies of `self`, each offset from the previous one
+    fn linear_pattern(
+        &self,
+        count: u32,
+        offset: [f64; 3],
+    ) -> crate::LinearPattern;
+
+    /// Create `count` copies of `self`, each rotated from the previous one
+    /// around an axis through the origin
+    fn circular_pattern(
+        &self,
+        count: u32,
+        axis: [f64; 3],
+        angle: crate::Angle,
+    ) -> crate::CircularPattern;
+}
+
+impl<T> Pattern for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn linear_pattern(
+        &self,
+        count: u32,
+        offset: [f64; 3],
+    ) -> crate::LinearPattern {
+        let shape = self.clone().into();
+        crate::LinearPattern {
+            shape,
+            count,
+            offset,
+        }
+    }
+
+    fn circular_pattern(
+        &self,
+        count: u32,
+        axis: [f64; 3],
+        angle: crate::Angle,
+    ) -> crate::CircularPattern {
+        let shape = self.clone().into();
+        crate::CircularPattern {
+            shape,
+            count,
+            axis,
+            angle,
+        }
+    }
+}
+
+/// Convenient syntax to create an [`fj::Revolve`]
+///
+/// [`fj::Revolve`]: crate::Revolve
+pub trait Revolve {
+    /// Revolve `self` around an axis through the origin
+    fn revolve(&self, axis: [f64; 3], angle: crate::Angle) -> crate::Revolve;
+}
+
+impl<T> Revolve for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn revolve(&self, axis: [f64; 3], angle: crate::Angle) -> crate::Revolve {
+        let shape = self.clone().into();
+        crate::Revolve { shape, axis, angle }
+    }
+}
+
 /// Convenient syntax to create an [`fj::Sketch`]
 ///
 /// [`fj::Sketch`]: crate::Sketch
@@ -79,6 +284,33 @@ where
 pub trait Sweep {
     /// Sweep `self` along a straight path
     fn sweep(&self, path: [f64; 3]) -> crate::Sweep;
+
+    /// Sweep `self` along a circular arc around an axis
+    fn sweep_along_arc(
+        &self,
+        center: [f64; 3],
+        axis: [f64; 3],
+        angle: crate::Angle,
+    ) -> crate::Sweep;
+
+    /// Sweep `self` along a helix around an axis
+    fn sweep_along_helix(
+        &self,
+        center: [f64; 3],
+        axis: [f64; 3],
+        pitch: f64,
+        turns: f64,
+    ) -> crate::Sweep;
+
+    /// Sweep `self` along a path made from lines and arcs
+    ///
+    /// See [`fj::SweepSegments`], regarding how the segments are defined.
+    ///
+    /// [`fj::SweepSegments`]: crate::SweepSegments
+    fn sweep_along_segments(
+        &self,
+        segments: Vec<crate::SweepSegment>,
+    ) -> crate::Sweep;
 }
 
 impl<T> Sweep for T
@@ -89,6 +321,46 @@ where
         let shape = self.clone().into();
         crate::Sweep::from_path(shape, path)
     }
+
+    fn sweep_along_arc(
+        &self,
+        center: [f64; 3],
+        axis: [f64; 3],
+        angle: crate::Angle,
+    ) -> crate::Sweep {
+        let shape = self.clone().into();
+        let path = crate::SweepPath::Arc {
+            center,
+            axis,
+            angle,
+        };
+        crate::Sweep::from_path(shape, path)
+    }
+
+    fn sweep_along_helix(
+        &self,
+        center: [f64; 3],
+        axis: [f64; 3],
+        pitch: f64,
+        turns: f64,
+    ) -> crate::Sweep {
+        let shape = self.clone().into();
+        let path = crate::SweepPath::Helix {
+            center,
+            axis,
+            pitch,
+            turns,
+        };
+        crate::Sweep::from_path(shape, path)
+    }
+
+    fn sweep_along_segments(
+        &self,
+        segments: Vec<crate::SweepSegment>,
+    ) -> crate::Sweep {
+        let shape = self.clone().into();
+        crate::Sweep::from_path(shape, segments)
+    }
 }
 
 /// Convenient syntax to create an [`fj::Transform`]
@@ -105,6 +377,12 @@ pub trait Transform {
     ///
     /// Create a translation that translates `shape` by `offset`.
     fn translate(&self, offset: [f64; 3]) -> crate::Transform;
+
+    /// Create a scaling
+    ///
+    /// Create a scaling that scales `shape` by the factors in `scale`, along
+    /// the x-, y-, and z-axis.
+    fn scale(&self, scale: [f64; 3]) -> crate::Transform;
 }
 
 impl<T> Transform for T
@@ -118,6 +396,7 @@ where
             axis,
             angle,
             offset: [0.; 3],
+            scale: [1.; 3],
         }
     }
 
@@ -128,6 +407,43 @@ where
             axis: [1., 0., 0.],
             angle: crate::Angle::from_rad(0.),
             offset,
+            scale: [1.; 3],
+        }
+    }
+
+    fn scale(&self, scale: [f64; 3]) -> crate::Transform {
+        let shape = self.clone().into();
+        crate::Transform {
+            shape,
+            axis: [1., 0., 0.],
+            angle: crate::Angle::from_rad(0.),
+            offset: [0.; 3],
+            scale,
         }
     }
 }
+
+/// Convenient syntax to create an [`fj::Union`]
+///
+/// [`fj::Union`]: crate::Union
+pub trait Union {
+    /// Create a union of `self` and `other`
+    fn union<Other>(&self, other: &Other) -> crate::Union
+    where
+        Other: Clone + Into<crate::Shape>;
+}
+
+impl<T> Union for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn union<Other>(&self, other: &Other) -> crate::Union
+    where
+        Other: Clone + Into<crate::Shape>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Union { a, b }
+    }
+}
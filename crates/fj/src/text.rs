@@ -0,0 +1,196 @@
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Serialize};
+use std::{borrow::Cow, fs, io};
+
+use crate::{abi::ffi_safe, Shape, Shape2d};
+
+/// A line of text, as a 2-dimensional shape
+///
+/// The outlines of the glyphs become the edges of the shape. The text starts at
+/// the origin, on the baseline, and runs along the positive x-axis. Each line
+/// break moves the following text down by one line.
+///
+/// Sweep the text to emboss it onto a model, or subtract the sweep from a model
+/// to engrave the text.
+///
+/// # Limitations
+///
+/// The text is laid out one glyph after the other. Kerning, ligatures, and
+/// complex scripts are not supported.
+///
+/// Processing the text panics, if the font can't be loaded.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Text {
+    text: ffi_safe::String,
+    font: Font,
+    size: f64,
+
+    // The color of the text in RGBA
+    color: [u8; 4],
+}
+
+impl Text {
+    /// Create a `Text` from a string, a font, and a size
+    ///
+    /// The size is the height of an em, in model units.
+    pub fn new(text: impl Into<String>, font: Font, size: f64) -> Self {
+        Self {
+            text: text.into().into(),
+            font,
+            size,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set the rendering color of the text in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Access the font of the text
+    pub fn font(&self) -> &Font {
+        &self.font
+    }
+
+    /// Access the size of the text
+    pub fn size(&self) -> f64 {
+        self.size
+    }
+
+    /// Get the rendering color of the text in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Text> for Shape {
+    fn from(shape: Text) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Text> for Shape2d {
+    fn from(shape: Text) -> Self {
+        Self::Text(shape)
+    }
+}
+
+/// A TrueType or OpenType font that is used by [`Text`]
+#[derive(Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Font {
+    source: FontSource,
+}
+
+impl Font {
+    /// Refer to a font file by its path
+    ///
+    /// The file is read when the text is processed. A relative path is
+    /// resolved relative to the working directory of the process that does
+    /// that, not relative to the model. Use [`Font::from_bytes`] together with
+    /// `include_bytes!`, to make the model independent of where it's loaded
+    /// from.
+    pub fn from_path(path: impl Into<String>) -> Self {
+        Self {
+            source: FontSource::Path(path.into().into()),
+        }
+    }
+
+    /// Embed the data of a font file
+    pub fn from_bytes(data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            source: FontSource::Embedded(data.into().into()),
+        }
+    }
+
+    /// Access the data of the font file, reading it, if necessary
+    pub fn data(&self) -> io::Result<Cow<[u8]>> {
+        match &self.source {
+            FontSource::Path(path) => Ok(Cow::Owned(fs::read(&**path)?)),
+            FontSource::Embedded(data) => Ok(Cow::Borrowed(&**data)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[repr(C)]
+enum FontSource {
+    Path(ffi_safe::String),
+    Embedded(ffi_safe::Vec<u8>),
+}
+
+#[cfg(feature = "serde")]
+impl ser::Serialize for Font {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let font = match &self.source {
+            FontSource::Path(path) => FontSerde::Path(path.to_string()),
+            FontSource::Embedded(data) => FontSerde::Embedded(data.to_vec()),
+        };
+
+        font.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> de::Deserialize<'de> for Font {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        FontSerde::deserialize(deserializer).map(|font| match font {
+            FontSerde::Path(path) => Font::from_path(path),
+            FontSerde::Embedded(data) => Font::from_bytes(data),
+        })
+    }
+}
+
+/// An owned, non-repr-C [`Font`]
+///
+/// See `PolyChainSerde`, which takes the same detour.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Font")]
+enum FontSerde {
+    Path(String),
+    Embedded(Vec<u8>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_font_data() {
+        let data = vec![1, 2, 3];
+        let font = Font::from_bytes(data.clone());
+        assert_eq!(font.data().unwrap(), data.as_slice());
+
+        let font = Font::from_path("does/not/exist.ttf");
+        assert!(font.data().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_text_serialize_loopback() {
+        use serde_json::{from_str, to_string};
+
+        let text = Text::new("Fornjot", Font::from_bytes(vec![1, 2, 3]), 5.);
+
+        let json = to_string(&text).expect("failed to serialize text");
+        let text_de: Text =
+            from_str(&json).expect("failed to deserialize text");
+
+        assert_eq!(text, text_de);
+    }
+}
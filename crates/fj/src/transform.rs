@@ -5,10 +5,17 @@ use crate::{Angle, Shape};
 
 /// A transformed 3-dimensional shape
 ///
+/// The shape is scaled first, then rotated, then translated.
+///
 /// # Limitations
 ///
-/// Transformations are currently limited to a rotation, followed by a
-/// translation.
+/// Transformations are currently limited to a scaling along the axes, followed
+/// by a rotation and a translation.
+///
+/// Scaling by different factors along the axes turns circles into ellipses.
+/// That is supported for shapes made by sweeping sketches along straight paths,
+/// but not for ones with helices, or cylindrical, conical, spherical, toroidal,
+/// or revolved surfaces. Scaling those non-uniformly results in an error.
 ///
 /// See issue:
 /// <https://github.com/hannobraun/Fornjot/issues/101>
@@ -27,6 +34,12 @@ pub struct Transform {
 
     /// The offset of the translation
     pub offset: [f64; 3],
+
+    /// The factors of the scaling along the x-, y-, and z-axis
+    ///
+    /// All factors must be positive.
+    #[cfg_attr(feature = "serde", serde(default = "default_scale"))]
+    pub scale: [f64; 3],
 }
 
 impl From<Transform> for Shape {
@@ -34,3 +47,12 @@ impl From<Transform> for Shape {
         Self::Transform(Box::new(shape))
     }
 }
+
+/// The scale of a transform that doesn't scale
+///
+/// Transforms that have been serialized before scaling was supported, don't
+/// have a scale.
+#[cfg(feature = "serde")]
+fn default_scale() -> [f64; 3] {
+    [1.; 3]
+}
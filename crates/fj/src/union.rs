@@ -0,0 +1,32 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Shape;
+
+/// The union of two 3-dimensional shapes
+///
+/// Unlike a [`Group`], the shapes are allowed to touch or overlap. They are
+/// merged into a single shape.
+///
+/// # Limitations
+///
+/// Only shapes that are bounded by planar faces with straight edges are
+/// currently supported. Processing other shapes results in an error.
+///
+/// [`Group`]: crate::Group
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Union {
+    /// The first of the shapes
+    pub a: Shape,
+
+    /// The second of the shapes
+    pub b: Shape,
+}
+
+impl From<Union> for Shape {
+    fn from(shape: Union) -> Self {
+        Self::Union(Box::new(shape))
+    }
+}